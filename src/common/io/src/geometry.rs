@@ -17,6 +17,7 @@ use std::str::FromStr;
 
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use geo::BoundingRect;
 use geo::Geometry;
 use geozero::geo_types::GeoWriter;
 use geozero::geojson::GeoJson;
@@ -243,6 +244,40 @@ pub fn geo_to_ewkb(geo: Geometry, srid: Option<i32>) -> Result<Vec<u8>> {
         .map_err(|e| ErrorCode::GeometryError(e.to_string()))
 }
 
+/// Set the SRID of a WKB or EWKB value.
+pub fn ewkb_set_srid(ewkb: &[u8], srid: Option<i32>) -> Result<Vec<u8>> {
+    let (geo, _) = ewkb_to_geo(&mut Ewkb(ewkb))?;
+    geo_to_ewkb(geo, srid)
+}
+
+/// Return the bounding box `[xmin, ymin, xmax, ymax]` of Geometry object, [None] if it is empty.
+pub fn geo_bounding_box(geo: &Geometry) -> Option<[f64; 4]> {
+    geo.bounding_rect()
+        .map(|r| [r.min().x, r.min().y, r.max().x, r.max().y])
+}
+
+/// Return the bounding box `[xmin, ymin, xmax, ymax]` of EWKB, [None] if it is empty or invalid.
+pub fn ewkb_bounding_box(ewkb: &[u8]) -> Option<[f64; 4]> {
+    ewkb_to_geo(&mut Ewkb(ewkb))
+        .ok()
+        .and_then(|(geo, _)| geo_bounding_box(&geo))
+}
+
+/// Return the smallest bounding box that covers both bounding boxes.
+pub fn merge_bounding_box(l: [f64; 4], r: [f64; 4]) -> [f64; 4] {
+    [
+        l[0].min(r[0]),
+        l[1].min(r[1]),
+        l[2].max(r[2]),
+        l[3].max(r[3]),
+    ]
+}
+
+/// Return true if the two bounding boxes have at least one point in common.
+pub fn bounding_box_intersects(l: &[f64; 4], r: &[f64; 4]) -> bool {
+    l[0] <= r[2] && l[2] >= r[0] && l[1] <= r[3] && l[3] >= r[1]
+}
+
 /// Convert Geometry object to WKT format.
 pub fn geo_to_wkt(geo: Geometry) -> Result<String> {
     geo.to_wkt()
@@ -52,7 +52,11 @@ pub use decimal::display_decimal_128;
 pub use decimal::display_decimal_256;
 pub use escape::escape_string;
 pub use escape::escape_string_with_quote;
+pub use geometry::bounding_box_intersects;
+pub use geometry::ewkb_bounding_box;
+pub use geometry::ewkb_set_srid;
 pub use geometry::ewkb_to_geo;
+pub use geometry::geo_bounding_box;
 pub use geometry::geo_to_ewkb;
 pub use geometry::geo_to_ewkt;
 pub use geometry::geo_to_json;
@@ -61,6 +65,7 @@ pub use geometry::geo_to_wkt;
 pub use geometry::geometry_format;
 pub use geometry::geometry_from_ewkt;
 pub use geometry::geometry_type_name;
+pub use geometry::merge_bounding_box;
 pub use geometry::parse_bytes_to_ewkb;
 pub use geometry::read_srid;
 pub use geometry::Axis;
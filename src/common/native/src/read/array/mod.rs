@@ -33,5 +33,7 @@ mod list;
 pub use list::*;
 mod interval;
 mod map;
+mod vector;
 pub use interval::*;
 pub use map::*;
+pub use vector::*;
@@ -0,0 +1,156 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+
+use databend_common_expression::types::VectorColumn;
+use databend_common_expression::types::VectorDataType;
+use databend_common_expression::Column;
+use databend_common_expression::TableDataType;
+
+use crate::compression::double::decompress_double;
+use crate::compression::integer::decompress_integer;
+use crate::error::Result;
+use crate::nested::InitNested;
+use crate::nested::NestedState;
+use crate::read::read_basic::*;
+use crate::read::BufReader;
+use crate::read::NativeReadBuf;
+use crate::read::PageIterator;
+use crate::PageMeta;
+
+#[derive(Debug)]
+pub struct VectorNestedIter<I>
+where I: Iterator<Item = Result<(u64, Vec<u8>)>> + PageIterator + Send + Sync
+{
+    iter: I,
+    data_type: TableDataType,
+    vector_type: VectorDataType,
+    init: Vec<InitNested>,
+    scratch: Vec<u8>,
+}
+
+impl<I> VectorNestedIter<I>
+where I: Iterator<Item = Result<(u64, Vec<u8>)>> + PageIterator + Send + Sync
+{
+    pub fn new(
+        iter: I,
+        data_type: TableDataType,
+        vector_type: VectorDataType,
+        init: Vec<InitNested>,
+    ) -> Self {
+        Self {
+            iter,
+            data_type,
+            vector_type,
+            init,
+            scratch: vec![],
+        }
+    }
+}
+
+impl<I> VectorNestedIter<I>
+where I: Iterator<Item = Result<(u64, Vec<u8>)>> + PageIterator + Send + Sync
+{
+    fn deserialize(&mut self, num_values: u64, buffer: Vec<u8>) -> Result<(NestedState, Column)> {
+        let mut reader = BufReader::with_capacity(buffer.len(), Cursor::new(buffer));
+        let (nested, validity) = read_nested(&mut reader, &self.init, num_values as usize)?;
+        let column = read_vector_values(
+            &mut reader,
+            &self.vector_type,
+            num_values as usize,
+            &mut self.scratch,
+        )?;
+
+        let mut buffer = reader.into_inner().into_inner();
+        self.iter.swap_buffer(&mut buffer);
+
+        let mut col = Column::Vector(column);
+        if self.data_type.is_nullable() {
+            col = col.wrap_nullable(validity);
+        }
+        Ok((nested, col))
+    }
+}
+
+impl<I> Iterator for VectorNestedIter<I>
+where I: Iterator<Item = Result<(u64, Vec<u8>)>> + PageIterator + Send + Sync
+{
+    type Item = Result<(NestedState, Column)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some(Ok((num_values, buffer))) => Some(self.deserialize(num_values, buffer)),
+            Some(Err(err)) => Some(Result::Err(err)),
+            None => None,
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        match self.iter.nth(n) {
+            Some(Ok((num_values, buffer))) => Some(self.deserialize(num_values, buffer)),
+            Some(Err(err)) => Some(Result::Err(err)),
+            None => None,
+        }
+    }
+}
+
+pub fn read_nested_vector<R: NativeReadBuf>(
+    reader: &mut R,
+    data_type: TableDataType,
+    vector_type: VectorDataType,
+    init: Vec<InitNested>,
+    page_metas: Vec<PageMeta>,
+) -> Result<Vec<(NestedState, Column)>> {
+    let mut scratch = vec![];
+    let mut results = Vec::with_capacity(page_metas.len());
+    for page_meta in page_metas {
+        let num_values = page_meta.num_values as usize;
+        let (nested, validity) = read_nested(reader, &init, num_values)?;
+        let column = read_vector_values(reader, &vector_type, num_values, &mut scratch)?;
+
+        let mut col = Column::Vector(column);
+        if data_type.is_nullable() {
+            col = col.wrap_nullable(validity);
+        }
+        results.push((nested, col));
+    }
+    Ok(results)
+}
+
+/// The values of a vector page are stored flattened, `dimension` values per row.
+fn read_vector_values<R: NativeReadBuf>(
+    reader: &mut R,
+    vector_type: &VectorDataType,
+    num_rows: usize,
+    scratch: &mut Vec<u8>,
+) -> Result<VectorColumn> {
+    let dimension = vector_type.dimension() as usize;
+    let length = num_rows * dimension;
+    let column = match vector_type {
+        VectorDataType::Int8(_) => {
+            let mut values = Vec::with_capacity(length);
+            decompress_integer::<i8, _>(reader, length, &mut values, scratch)?;
+            assert_eq!(values.len(), length);
+            VectorColumn::Int8(values.into(), dimension)
+        }
+        VectorDataType::Float32(_) => {
+            let mut values = Vec::with_capacity(length);
+            decompress_double(reader, length, &mut values, scratch)?;
+            assert_eq!(values.len(), length);
+            VectorColumn::Float32(values.into(), dimension)
+        }
+    };
+    Ok(column)
+}
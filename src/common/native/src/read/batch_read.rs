@@ -102,6 +102,17 @@ pub fn read_nested_column<R: NativeReadBuf>(
                 page_metas.pop().unwrap(),
             )?
         }
+        Vector(vector_type) => {
+            init.push(InitNested::Primitive(is_nullable));
+
+            read_nested_vector::<_>(
+                &mut readers.pop().unwrap(),
+                data_type.clone(),
+                vector_type,
+                init,
+                page_metas.pop().unwrap(),
+            )?
+        }
         Timestamp => {
             init.push(InitNested::Primitive(is_nullable));
             read_nested_integer::<TimestampType, _, _>(
@@ -159,6 +159,15 @@ where
                 init,
             ))
         }
+        TableDataType::Vector(vector_type) => {
+            init.push(InitNested::Primitive(is_nullable));
+            DynIter::new(VectorNestedIter::<_>::new(
+                readers.pop().unwrap(),
+                data_type.clone(),
+                vector_type,
+                init,
+            ))
+        }
         TableDataType::Decimal(t) if t.precision() > MAX_DECIMAL128_PRECISION => {
             init.push(InitNested::Primitive(is_nullable));
             DynIter::new(DecimalNestedIter::<
@@ -19,6 +19,8 @@ use databend_common_column::types::i256;
 use databend_common_expression::types::DecimalColumn;
 use databend_common_expression::types::GeographyColumn;
 use databend_common_expression::types::NumberColumn;
+use databend_common_expression::types::VectorColumn;
+use databend_common_expression::types::F32;
 use databend_common_expression::with_decimal_mapped_type;
 use databend_common_expression::with_number_mapped_type;
 use databend_common_expression::Column;
@@ -71,6 +73,18 @@ pub fn write<W: Write>(
             let column: Buffer<i128> = unsafe { std::mem::transmute(column) };
             write_primitive::<i128, W>(w, &column, validity, write_options, scratch)
         }
+        Column::Vector(column) => {
+            // The row validity is already written in the nest info, the flattened
+            // values always keep `dimension` slots per row, so no validity here.
+            match column {
+                VectorColumn::Int8(values, _) => {
+                    write_primitive::<i8, W>(w, &values, None, write_options, scratch)
+                }
+                VectorColumn::Float32(values, _) => {
+                    write_primitive::<F32, W>(w, &values, None, write_options, scratch)
+                }
+            }
+        }
         Column::Binary(b)
         | Column::Bitmap(b)
         | Column::Variant(b)
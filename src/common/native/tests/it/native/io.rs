@@ -245,6 +245,9 @@ fn get_all_test_data_types() -> Vec<DataType> {
             precision: 55,
             scale: 3,
         })),
+        DataType::Vector(VectorDataType::Float32(4)),
+        DataType::Vector(VectorDataType::Int8(3)),
+        DataType::Nullable(Box::new(DataType::Vector(VectorDataType::Float32(2)))),
         DataType::Nullable(Box::new(DataType::Geography)),
         DataType::Nullable(Box::new(DataType::Geometry)),
         DataType::Nullable(Box::new(DataType::Number(NumberDataType::UInt32))),
@@ -0,0 +1,772 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Listing the members of zip and tar archives in stages.
+//!
+//! Each member of an archive is exposed as a logical file named
+//! `<archive_path>/<member_name>`, the data is unpacked later by the
+//! `Decompressor` of the row based read pipeline.
+
+use databend_common_compress::CompressAlgorithm;
+use databend_common_compress::DecompressDecoder;
+use databend_common_compress::DecompressState;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::StageFileCompression;
+use opendal::Operator;
+
+use crate::StageFileInfo;
+use crate::StageFileStatus;
+
+const ZIP_LOCAL_HEADER_SIGNATURE: u32 = 0x04034b50;
+const ZIP_CENTRAL_HEADER_SIGNATURE: u32 = 0x02014b50;
+const ZIP_EOCD_SIGNATURE: u32 = 0x06054b50;
+const ZIP64_EOCD_LOCATOR_SIGNATURE: u32 = 0x07064b50;
+const ZIP64_EOCD_SIGNATURE: u32 = 0x06064b50;
+const ZIP_EOCD_SIZE: usize = 22;
+const ZIP64_EOCD_LOCATOR_SIZE: usize = 20;
+const ZIP64_EOCD_SIZE: u64 = 56;
+const ZIP_MAX_COMMENT_SIZE: usize = u16::MAX as usize;
+/// Size of the fixed part of a zip local file header.
+pub const ZIP_LOCAL_HEADER_SIZE: usize = 30;
+
+const TAR_BLOCK_SIZE: usize = 512;
+const TAR_READ_SIZE: u64 = 1024 * 1024;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    Zip,
+    Tar,
+    TarGz,
+}
+
+impl ArchiveFormat {
+    pub fn from_path(path: &str) -> Option<ArchiveFormat> {
+        let path = path.to_ascii_lowercase();
+        if path.ends_with(".zip") {
+            Some(ArchiveFormat::Zip)
+        } else if path.ends_with(".tar") {
+            Some(ArchiveFormat::Tar)
+        } else if path.ends_with(".tar.gz") || path.ends_with(".tgz") {
+            Some(ArchiveFormat::TarGz)
+        } else {
+            None
+        }
+    }
+
+    /// The archive format of the file, `None` if it should be read as a plain file.
+    pub fn from_compression(compression: StageFileCompression, path: &str) -> Option<Self> {
+        match compression {
+            StageFileCompression::Auto => Self::from_path(path),
+            StageFileCompression::Zip => Some(ArchiveFormat::Zip),
+            StageFileCompression::Tar => {
+                if path.to_ascii_lowercase().ends_with("gz") {
+                    Some(ArchiveFormat::TarGz)
+                } else {
+                    Some(ArchiveFormat::Tar)
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether files may be archives under the compression option.
+    pub fn may_be_archive(compression: StageFileCompression) -> bool {
+        matches!(
+            compression,
+            StageFileCompression::Auto | StageFileCompression::Zip | StageFileCompression::Tar
+        )
+    }
+}
+
+/// Location of a member inside an archive.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ArchiveMember {
+    pub archive_path: String,
+    pub archive_size: u64,
+    pub format: ArchiveFormat,
+    /// Name of the member inside the archive.
+    pub name: String,
+    /// Offset of the local file header for zip, or of the member data for tar.
+    ///
+    /// For tar.gz it is the offset in the decompressed tar stream.
+    pub offset: u64,
+    /// Size of the member data stored in the archive.
+    pub compressed_size: u64,
+    /// Compression method of zip member, 0 for stored and 8 for deflated.
+    pub method: u16,
+}
+
+impl ArchiveMember {
+    pub fn path(&self) -> String {
+        format!("{}/{}", self.archive_path, self.name)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct ArchiveEntry {
+    name: String,
+    offset: u64,
+    compressed_size: u64,
+    size: u64,
+    method: u16,
+}
+
+/// List the members of the archive as logical stage files.
+///
+/// Members inherit `last_modified` and `etag` of the archive, so they are copied
+/// again only if the archive changes.
+#[async_backtrace::framed]
+pub async fn list_archive_members(
+    operator: &Operator,
+    archive: &StageFileInfo,
+    format: ArchiveFormat,
+) -> Result<Vec<StageFileInfo>> {
+    let entries = match format {
+        ArchiveFormat::Zip => list_zip_entries(operator, &archive.path, archive.size).await?,
+        ArchiveFormat::Tar | ArchiveFormat::TarGz => {
+            list_tar_entries(operator, &archive.path, archive.size, format).await?
+        }
+    };
+    Ok(entries
+        .into_iter()
+        .map(|entry| {
+            let member = ArchiveMember {
+                archive_path: archive.path.clone(),
+                archive_size: archive.size,
+                format,
+                name: entry.name,
+                offset: entry.offset,
+                compressed_size: entry.compressed_size,
+                method: entry.method,
+            };
+            StageFileInfo {
+                path: member.path(),
+                size: entry.size,
+                md5: None,
+                last_modified: archive.last_modified,
+                etag: archive.etag.clone(),
+                status: StageFileStatus::NeedCopy,
+                creator: archive.creator.clone(),
+                archive_member: Some(member),
+            }
+        })
+        .collect())
+}
+
+async fn read_range(operator: &Operator, path: &str, start: u64, end: u64) -> Result<Vec<u8>> {
+    Ok(operator.read_with(path).range(start..end).await?.to_vec())
+}
+
+fn read_u16(data: &[u8], pos: usize) -> u16 {
+    u16::from_le_bytes([data[pos], data[pos + 1]])
+}
+
+fn read_u32(data: &[u8], pos: usize) -> u32 {
+    u32::from_le_bytes(data[pos..pos + 4].try_into().unwrap())
+}
+
+fn read_u64(data: &[u8], pos: usize) -> u64 {
+    u64::from_le_bytes(data[pos..pos + 8].try_into().unwrap())
+}
+
+fn bad_archive(path: &str, msg: &str) -> ErrorCode {
+    ErrorCode::BadBytes(format!("invalid archive {path}: {msg}"))
+}
+
+async fn list_zip_entries(operator: &Operator, path: &str, size: u64) -> Result<Vec<ArchiveEntry>> {
+    let tail_len = size.min((ZIP_EOCD_SIZE + ZIP_MAX_COMMENT_SIZE) as u64);
+    let tail_start = size - tail_len;
+    let tail = read_range(operator, path, tail_start, size).await?;
+    let eocd_pos = find_zip_eocd(&tail)
+        .ok_or_else(|| bad_archive(path, "end of central directory record of zip not found"))?;
+    let eocd = &tail[eocd_pos..];
+    let mut num_entries = read_u16(eocd, 10) as u64;
+    let mut cd_size = read_u32(eocd, 12) as u64;
+    let mut cd_offset = read_u32(eocd, 16) as u64;
+
+    if num_entries == u16::MAX as u64 || cd_size == u32::MAX as u64 || cd_offset == u32::MAX as u64
+    {
+        if eocd_pos < ZIP64_EOCD_LOCATOR_SIZE {
+            return Err(bad_archive(
+                path,
+                "zip64 end of central directory not found",
+            ));
+        }
+        let locator = &tail[eocd_pos - ZIP64_EOCD_LOCATOR_SIZE..eocd_pos];
+        if read_u32(locator, 0) != ZIP64_EOCD_LOCATOR_SIGNATURE {
+            return Err(bad_archive(
+                path,
+                "zip64 end of central directory not found",
+            ));
+        }
+        let zip64_eocd_offset = read_u64(locator, 8);
+        let zip64_eocd_end = zip64_eocd_offset
+            .checked_add(ZIP64_EOCD_SIZE)
+            .filter(|end| *end <= size)
+            .ok_or_else(|| bad_archive(path, "zip64 end of central directory out of range"))?;
+        let zip64_eocd = read_range(operator, path, zip64_eocd_offset, zip64_eocd_end).await?;
+        if zip64_eocd.len() < ZIP64_EOCD_SIZE as usize
+            || read_u32(&zip64_eocd, 0) != ZIP64_EOCD_SIGNATURE
+        {
+            return Err(bad_archive(path, "invalid zip64 end of central directory"));
+        }
+        num_entries = read_u64(&zip64_eocd, 32);
+        cd_size = read_u64(&zip64_eocd, 40);
+        cd_offset = read_u64(&zip64_eocd, 48);
+    }
+
+    let cd_end = cd_offset
+        .checked_add(cd_size)
+        .filter(|end| *end <= size)
+        .ok_or_else(|| bad_archive(path, "central directory out of range"))?;
+    let cd = if cd_offset >= tail_start {
+        let start = (cd_offset - tail_start) as usize;
+        tail[start..start + cd_size as usize].to_vec()
+    } else {
+        read_range(operator, path, cd_offset, cd_end).await?
+    };
+    parse_zip_central_directory(&cd, num_entries).map_err(|msg| bad_archive(path, &msg))
+}
+
+fn find_zip_eocd(tail: &[u8]) -> Option<usize> {
+    if tail.len() < ZIP_EOCD_SIZE {
+        return None;
+    }
+    (0..=tail.len() - ZIP_EOCD_SIZE)
+        .rev()
+        .find(|pos| read_u32(tail, *pos) == ZIP_EOCD_SIGNATURE)
+}
+
+fn parse_zip_central_directory(
+    cd: &[u8],
+    num_entries: u64,
+) -> std::result::Result<Vec<ArchiveEntry>, String> {
+    let mut entries = vec![];
+    let mut pos = 0;
+    for _ in 0..num_entries {
+        if pos + 46 > cd.len() || read_u32(cd, pos) != ZIP_CENTRAL_HEADER_SIGNATURE {
+            return Err("invalid central directory file header".to_string());
+        }
+        let header = &cd[pos..];
+        let flags = read_u16(header, 8);
+        let method = read_u16(header, 10);
+        let mut compressed_size = read_u32(header, 20) as u64;
+        let mut size = read_u32(header, 24) as u64;
+        let name_len = read_u16(header, 28) as usize;
+        let extra_len = read_u16(header, 30) as usize;
+        let comment_len = read_u16(header, 32) as usize;
+        let mut offset = read_u32(header, 42) as u64;
+        let entry_len = 46 + name_len + extra_len + comment_len;
+        if pos + entry_len > cd.len() {
+            return Err("central directory file header out of range".to_string());
+        }
+        let name = String::from_utf8_lossy(&header[46..46 + name_len]).to_string();
+
+        // Zip64 extended information, only the fields that overflow are present.
+        let mut extra = &header[46 + name_len..46 + name_len + extra_len];
+        while extra.len() >= 4 {
+            let id = read_u16(extra, 0);
+            let len = (read_u16(extra, 2) as usize).min(extra.len() - 4);
+            if id == 0x0001 {
+                let mut field = &extra[4..4 + len];
+                for value in [&mut size, &mut compressed_size, &mut offset] {
+                    if *value == u32::MAX as u64 && field.len() >= 8 {
+                        *value = read_u64(field, 0);
+                        field = &field[8..];
+                    }
+                }
+            }
+            extra = &extra[4 + len..];
+        }
+        pos += entry_len;
+
+        if name.ends_with('/') {
+            continue;
+        }
+        if flags & 1 != 0 {
+            return Err(format!("encrypted member {name} is not supported"));
+        }
+        if method != 0 && method != 8 {
+            return Err(format!(
+                "compression method {method} of member {name} is not supported, must be stored or deflated"
+            ));
+        }
+        entries.push(ArchiveEntry {
+            name,
+            offset,
+            compressed_size,
+            size,
+            method,
+        });
+    }
+    Ok(entries)
+}
+
+async fn list_tar_entries(
+    operator: &Operator,
+    path: &str,
+    size: u64,
+    format: ArchiveFormat,
+) -> Result<Vec<ArchiveEntry>> {
+    let mut parser = TarParser::default();
+    let mut decoder = match format {
+        ArchiveFormat::TarGz => Some(DecompressDecoder::new(CompressAlgorithm::Gzip)),
+        _ => None,
+    };
+    let mut pos = 0;
+    loop {
+        if decoder.is_none() {
+            // The data of members is not needed, skip it without reading.
+            pos += parser.take_skip();
+        }
+        if parser.is_finished() || pos >= size {
+            break;
+        }
+        let end = size.min(pos + TAR_READ_SIZE);
+        let data = read_range(operator, path, pos, end).await?;
+        pos = end;
+        let res = match &mut decoder {
+            Some(decoder) => {
+                let mut data = decoder.decompress_batch(&data)?;
+                if pos == size {
+                    data.extend(decoder.decompress_batch(&[])?);
+                }
+                parser.feed(&data)
+            }
+            None => parser.feed(&data),
+        };
+        res.map_err(|msg| bad_archive(path, &msg))?;
+    }
+    if let Some(decoder) = &decoder {
+        if !parser.is_finished() && decoder.state() != DecompressState::Done {
+            return Err(bad_archive(path, "unexpected end of gzip stream"));
+        }
+    }
+    Ok(parser.entries)
+}
+
+/// Streaming parser of tar headers, supporting ustar, GNU long names and pax paths.
+#[derive(Default)]
+struct TarParser {
+    /// Offset in the tar stream of the next byte to feed.
+    pos: u64,
+    /// Number of bytes to discard before the next header.
+    skip: u64,
+    buf: Vec<u8>,
+    /// Type and size of the extended header being collected.
+    extended: Option<(u8, usize)>,
+    long_name: Option<String>,
+    finished: bool,
+    entries: Vec<ArchiveEntry>,
+}
+
+impl TarParser {
+    fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Take the bytes to be skipped, the caller should not feed them.
+    fn take_skip(&mut self) -> u64 {
+        let skip = self.skip;
+        self.pos += skip;
+        self.skip = 0;
+        skip
+    }
+
+    fn feed(&mut self, mut data: &[u8]) -> std::result::Result<(), String> {
+        while !data.is_empty() && !self.finished {
+            if self.skip > 0 {
+                let n = self.skip.min(data.len() as u64);
+                data = &data[n as usize..];
+                self.skip -= n;
+                self.pos += n;
+                continue;
+            }
+            let want = match self.extended {
+                Some((_, size)) => size,
+                None => TAR_BLOCK_SIZE,
+            };
+            let n = (want - self.buf.len()).min(data.len());
+            self.buf.extend_from_slice(&data[..n]);
+            data = &data[n..];
+            self.pos += n as u64;
+            if self.buf.len() < want {
+                break;
+            }
+            let block = std::mem::take(&mut self.buf);
+            match self.extended.take() {
+                Some((kind, size)) => {
+                    self.skip = padding(size as u64);
+                    if let Some(name) = parse_extended_name(kind, &block) {
+                        self.long_name = Some(name);
+                    }
+                }
+                None => self.parse_header(&block)?,
+            }
+        }
+        Ok(())
+    }
+
+    fn parse_header(&mut self, block: &[u8]) -> std::result::Result<(), String> {
+        if block.iter().all(|b| *b == 0) {
+            self.finished = true;
+            return Ok(());
+        }
+        let checksum = parse_tar_number(&block[148..156])?;
+        // The checksum field itself is taken as spaces.
+        let sum = block[..148].iter().map(|b| *b as u64).sum::<u64>()
+            + 8 * b' ' as u64
+            + block[156..].iter().map(|b| *b as u64).sum::<u64>();
+        if checksum != sum {
+            return Err("invalid tar header checksum".to_string());
+        }
+        let size = parse_tar_number(&block[124..136])?;
+        match block[156] {
+            b'0' | b'\0' | b'7' => {
+                let name = match self.long_name.take() {
+                    Some(name) => name,
+                    None => {
+                        let name = cstr(&block[0..100]);
+                        if &block[257..263] == b"ustar\0" {
+                            let prefix = cstr(&block[345..500]);
+                            if prefix.is_empty() {
+                                name
+                            } else {
+                                format!("{prefix}/{name}")
+                            }
+                        } else {
+                            name
+                        }
+                    }
+                };
+                let name = name.trim_start_matches("./").to_string();
+                if !name.is_empty() && !name.ends_with('/') {
+                    self.entries.push(ArchiveEntry {
+                        name,
+                        offset: self.pos,
+                        compressed_size: size,
+                        size,
+                        method: 0,
+                    });
+                }
+                self.skip = size + padding(size);
+            }
+            kind @ (b'L' | b'x') => {
+                self.extended = Some((kind, size as usize));
+            }
+            _ => {
+                self.long_name = None;
+                self.skip = size + padding(size);
+            }
+        }
+        Ok(())
+    }
+}
+
+fn padding(size: u64) -> u64 {
+    (TAR_BLOCK_SIZE as u64 - size % TAR_BLOCK_SIZE as u64) % TAR_BLOCK_SIZE as u64
+}
+
+fn cstr(data: &[u8]) -> String {
+    let end = data.iter().position(|b| *b == 0).unwrap_or(data.len());
+    String::from_utf8_lossy(&data[..end]).to_string()
+}
+
+fn parse_tar_number(field: &[u8]) -> std::result::Result<u64, String> {
+    if field[0] & 0x80 != 0 {
+        // base-256 encoding of GNU tar for large values.
+        let mut value = (field[0] & 0x7f) as u64;
+        for b in &field[1..] {
+            value = (value << 8) | *b as u64;
+        }
+        return Ok(value);
+    }
+    let s = cstr(field);
+    let s = s.trim_matches(' ');
+    if s.is_empty() {
+        return Ok(0);
+    }
+    u64::from_str_radix(s, 8).map_err(|_| format!("invalid number in tar header: {s}"))
+}
+
+/// Get the member name from a GNU long name (`L`) or pax (`x`) header.
+fn parse_extended_name(kind: u8, data: &[u8]) -> Option<String> {
+    if kind == b'L' {
+        return Some(cstr(data));
+    }
+    // pax records: "<length> <key>=<value>\n"
+    let mut data = data;
+    while !data.is_empty() {
+        let space = data.iter().position(|b| *b == b' ')?;
+        let len = std::str::from_utf8(&data[..space])
+            .ok()?
+            .parse::<usize>()
+            .ok()?;
+        if len <= space + 1 || len > data.len() {
+            return None;
+        }
+        let record = &data[space + 1..len - 1];
+        if let Some(path) = record.strip_prefix(b"path=") {
+            return Some(String::from_utf8_lossy(path).to_string());
+        }
+        data = &data[len..];
+    }
+    None
+}
+
+/// Parse the zip local file header at the beginning of `data`.
+///
+/// Return the length of the header including the name and extra field, `None`
+/// if more data is needed.
+pub fn parse_zip_local_header(data: &[u8]) -> Result<Option<usize>> {
+    if data.len() < ZIP_LOCAL_HEADER_SIZE {
+        return Ok(None);
+    }
+    if read_u32(data, 0) != ZIP_LOCAL_HEADER_SIGNATURE {
+        return Err(ErrorCode::BadBytes("invalid zip local file header"));
+    }
+    let len = ZIP_LOCAL_HEADER_SIZE + read_u16(data, 26) as usize + read_u16(data, 28) as usize;
+    if data.len() < len {
+        return Ok(None);
+    }
+    Ok(Some(len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tar_header(name: &str, size: u64, kind: u8) -> Vec<u8> {
+        let mut block = vec![0u8; TAR_BLOCK_SIZE];
+        block[..name.len()].copy_from_slice(name.as_bytes());
+        block[124..135].copy_from_slice(format!("{size:011o}").as_bytes());
+        block[156] = kind;
+        block[257..263].copy_from_slice(b"ustar\0");
+        block[148..156].copy_from_slice(b"        ");
+        let sum = block.iter().map(|b| *b as u64).sum::<u64>();
+        block[148..155].copy_from_slice(format!("{sum:06o}\0").as_bytes());
+        block
+    }
+
+    fn tar_member(name: &str, data: &[u8], kind: u8) -> Vec<u8> {
+        let mut res = tar_header(name, data.len() as u64, kind);
+        res.extend_from_slice(data);
+        res.resize(res.len() + padding(data.len() as u64) as usize, 0);
+        res
+    }
+
+    #[test]
+    fn test_tar_parser() {
+        let long_name = format!("{}/c.csv", "d".repeat(120));
+        let mut tar = vec![];
+        tar.extend(tar_member("./a.csv", b"1,2\n", b'0'));
+        tar.extend(tar_member("dir/", b"", b'5'));
+        tar.extend(tar_member("dir/b.csv", &[b'x'; 600], b'0'));
+        tar.extend(tar_member("././@LongLink", long_name.as_bytes(), b'L'));
+        tar.extend(tar_member("ignored", b"3\n", b'0'));
+        tar.extend(tar_member("pax", b"18 path=pax/e.csv\n", b'x'));
+        tar.extend(tar_member("ignored", b"", b'0'));
+        tar.extend(vec![0u8; TAR_BLOCK_SIZE * 2]);
+
+        // feed in small chunks to cover the partial headers.
+        let mut parser = TarParser::default();
+        for chunk in tar.chunks(100) {
+            parser.feed(chunk).unwrap();
+        }
+        assert!(parser.is_finished());
+        let names = parser
+            .entries
+            .iter()
+            .map(|e| (e.name.as_str(), e.size))
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec![
+            ("a.csv", 4),
+            ("dir/b.csv", 600),
+            (long_name.as_str(), 2),
+            ("pax/e.csv", 0)
+        ]);
+        for entry in &parser.entries {
+            let start = entry.offset as usize;
+            assert_eq!(tar[start - TAR_BLOCK_SIZE + 156], b'0');
+        }
+        assert_eq!(&tar[512..516], b"1,2\n");
+        assert_eq!(parser.entries[0].offset, 512);
+    }
+
+    #[test]
+    fn test_tar_parser_skip() {
+        let mut tar = vec![];
+        tar.extend(tar_member("a.csv", &[b'a'; 1000], b'0'));
+        tar.extend(tar_member("b.csv", b"b", b'0'));
+        tar.extend(vec![0u8; TAR_BLOCK_SIZE * 2]);
+
+        let mut parser = TarParser::default();
+        parser.feed(&tar[..TAR_BLOCK_SIZE]).unwrap();
+        assert_eq!(parser.take_skip(), 1024);
+        parser.feed(&tar[TAR_BLOCK_SIZE + 1024..]).unwrap();
+        assert!(parser.is_finished());
+        assert_eq!(parser.entries[1].name, "b.csv");
+        assert_eq!(parser.entries[1].offset, 512 * 4);
+    }
+
+    #[test]
+    fn test_tar_parser_bad_checksum() {
+        let mut block = tar_header("a.csv", 1, b'0');
+        block[0] = b'b';
+        let mut parser = TarParser::default();
+        assert!(parser.feed(&block).is_err());
+    }
+
+    fn zip_central_header(
+        name: &str,
+        method: u16,
+        compressed: u32,
+        size: u32,
+        offset: u32,
+    ) -> Vec<u8> {
+        let mut header = vec![];
+        header.extend(ZIP_CENTRAL_HEADER_SIGNATURE.to_le_bytes());
+        header.extend([20, 0, 20, 0, 0, 0]);
+        header.extend(method.to_le_bytes());
+        header.extend([0u8; 8]);
+        header.extend(compressed.to_le_bytes());
+        header.extend(size.to_le_bytes());
+        header.extend((name.len() as u16).to_le_bytes());
+        header.extend([0u8; 12]);
+        header.extend(offset.to_le_bytes());
+        header.extend(name.as_bytes());
+        header
+    }
+
+    #[test]
+    fn test_parse_zip_central_directory() {
+        let mut cd = vec![];
+        cd.extend(zip_central_header("a.csv", 8, 10, 20, 0));
+        cd.extend(zip_central_header("dir/", 0, 0, 0, 45));
+        cd.extend(zip_central_header("dir/b.csv", 0, 5, 5, 79));
+        let entries = parse_zip_central_directory(&cd, 3).unwrap();
+        assert_eq!(entries, vec![
+            ArchiveEntry {
+                name: "a.csv".to_string(),
+                offset: 0,
+                compressed_size: 10,
+                size: 20,
+                method: 8,
+            },
+            ArchiveEntry {
+                name: "dir/b.csv".to_string(),
+                offset: 79,
+                compressed_size: 5,
+                size: 5,
+                method: 0,
+            }
+        ]);
+
+        let cd = zip_central_header("a.csv", 12, 10, 20, 0);
+        assert!(parse_zip_central_directory(&cd, 1).is_err());
+        assert!(parse_zip_central_directory(&cd, 2).is_err());
+
+        let mut tail = vec![1u8; 10];
+        tail.extend(ZIP_EOCD_SIGNATURE.to_le_bytes());
+        tail.extend([0u8; 18]);
+        assert_eq!(find_zip_eocd(&tail), Some(10));
+    }
+
+    fn zip64_tail(zip64_eocd_offset: u64) -> Vec<u8> {
+        let mut tail = vec![];
+        tail.extend(ZIP64_EOCD_LOCATOR_SIGNATURE.to_le_bytes());
+        tail.extend([0u8; 4]);
+        tail.extend(zip64_eocd_offset.to_le_bytes());
+        tail.extend(1u32.to_le_bytes());
+        tail.extend(ZIP_EOCD_SIGNATURE.to_le_bytes());
+        tail.extend([0u8; 4]);
+        tail.extend(u16::MAX.to_le_bytes());
+        tail.extend(u16::MAX.to_le_bytes());
+        tail.extend(u32::MAX.to_le_bytes());
+        tail.extend(u32::MAX.to_le_bytes());
+        tail.extend([0u8; 2]);
+        tail
+    }
+
+    fn zip64_eocd(cd_size: u64, cd_offset: u64) -> Vec<u8> {
+        let mut eocd = vec![];
+        eocd.extend(ZIP64_EOCD_SIGNATURE.to_le_bytes());
+        eocd.extend((ZIP64_EOCD_SIZE - 12).to_le_bytes());
+        eocd.extend([0u8; 12]);
+        eocd.extend(1u64.to_le_bytes());
+        eocd.extend(1u64.to_le_bytes());
+        eocd.extend(cd_size.to_le_bytes());
+        eocd.extend(cd_offset.to_le_bytes());
+        eocd
+    }
+
+    async fn list_zip(data: Vec<u8>) -> Result<Vec<ArchiveEntry>> {
+        let operator = Operator::new(opendal::services::Memory::default())?.finish();
+        let size = data.len() as u64;
+        operator.write("a.zip", data).await?;
+        list_zip_entries(&operator, "a.zip", size).await
+    }
+
+    #[tokio::test]
+    async fn test_list_truncated_zip64() -> Result<()> {
+        let cd = zip_central_header("a.csv", 0, 1, 1, 0);
+        let mut zip = cd.clone();
+        zip.extend(zip64_eocd(cd.len() as u64, 0));
+        zip.extend(zip64_tail(cd.len() as u64));
+        let entries = list_zip(zip.clone()).await?;
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].name, "a.csv");
+
+        // The zip64 end of central directory is cut off.
+        let mut truncated = cd.clone();
+        truncated.extend(zip64_tail(cd.len() as u64));
+        assert!(list_zip(truncated).await.is_err());
+
+        // The locator points to the end of the u64 range.
+        let mut overflow = cd.clone();
+        overflow.extend(zip64_tail(u64::MAX - 8));
+        assert!(list_zip(overflow).await.is_err());
+
+        // The central directory is out of the archive.
+        for (cd_size, cd_offset) in [(u64::MAX, 1), (cd.len() as u64, u64::MAX)] {
+            let mut zip = cd.clone();
+            zip.extend(zip64_eocd(cd_size, cd_offset));
+            zip.extend(zip64_tail(cd.len() as u64));
+            assert!(list_zip(zip).await.is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_archive_format() {
+        assert_eq!(
+            ArchiveFormat::from_path("a/b.ZIP"),
+            Some(ArchiveFormat::Zip)
+        );
+        assert_eq!(
+            ArchiveFormat::from_path("b.tgz"),
+            Some(ArchiveFormat::TarGz)
+        );
+        assert_eq!(ArchiveFormat::from_path("b.csv.gz"), None);
+        assert_eq!(
+            ArchiveFormat::from_compression(StageFileCompression::Tar, "b.tar.gz"),
+            Some(ArchiveFormat::TarGz)
+        );
+        assert_eq!(
+            ArchiveFormat::from_compression(StageFileCompression::Gzip, "b.tar.gz"),
+            None
+        );
+    }
+}
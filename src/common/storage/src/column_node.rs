@@ -42,6 +42,15 @@ impl ColumnNodes {
             if let Some(ref leaf_column_ids) = leaf_column_ids {
                 column_node.build_leaf_column_ids(leaf_column_ids);
             }
+            if let Some(table_schema) = table_schema {
+                if let [column_id] = column_node.leaf_column_ids[..] {
+                    column_node.widened_from = table_schema
+                        .widened_column_sources(column_id)
+                        .iter()
+                        .map(|source| source.column_id)
+                        .collect();
+                }
+            }
             column_nodes.push(column_node);
         }
 
@@ -185,6 +194,9 @@ pub struct ColumnNode {
     // Optional children column for nested types.
     pub children: Option<Vec<ColumnNode>>,
     pub leaf_column_ids: Vec<ColumnId>,
+    // Column ids the column was stored under before its data type was widened,
+    // from the most recent one. Blocks written earlier hold the data under one of them.
+    pub widened_from: Vec<ColumnId>,
 }
 
 impl ColumnNode {
@@ -204,6 +216,7 @@ impl ColumnNode {
             leaf_indices,
             children,
             leaf_column_ids: vec![],
+            widened_from: vec![],
         }
     }
 
@@ -211,6 +224,19 @@ impl ColumnNode {
         self.children.is_some()
     }
 
+    /// Returns the id of the column that holds the data of this leaf column in a block,
+    /// following `widened_from` if the block was written before the column was widened.
+    pub fn stored_column_id(
+        &self,
+        column_id: ColumnId,
+        in_block: impl Fn(&ColumnId) -> bool,
+    ) -> Option<ColumnId> {
+        if in_block(&column_id) {
+            return Some(column_id);
+        }
+        self.widened_from.iter().find(|id| in_block(id)).copied()
+    }
+
     pub fn build_leaf_column_ids(&mut self, leaf_column_ids: &Vec<u32>) {
         let mut node_leaf_column_ids = Vec::with_capacity(self.leaf_indices.len());
         for index in &self.leaf_indices {
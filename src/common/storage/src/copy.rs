@@ -49,9 +49,20 @@ impl CopyStatus {
 pub struct FileStatus {
     pub num_rows_loaded: usize,
     pub error: Option<FileErrorsInfo>,
+    /// Rows rejected with `ON_ERROR = CONTINUE`, only kept when there is an `ERROR_TABLE`.
+    pub rejected_rows: Vec<RejectedRow>,
 }
 
 impl FileStatus {
+    pub fn add_rejected_row(&mut self, error: FileParseError, line: usize, record: Option<String>) {
+        self.rejected_rows.push(RejectedRow {
+            line,
+            error: error.clone(),
+            record,
+        });
+        self.add_error(error, line);
+    }
+
     pub fn add_error(&mut self, error: FileParseError, line: usize) {
         match &mut self.error {
             None => {
@@ -71,6 +82,7 @@ impl FileStatus {
 
     fn merge(&mut self, other: FileStatus) {
         self.num_rows_loaded += other.num_rows_loaded;
+        self.rejected_rows.extend(other.rejected_rows);
         match (&mut self.error, other.error) {
             (None, Some(e)) => self.error = Some(e),
             (Some(e1), Some(e2)) => e1.merge(e2),
@@ -100,6 +112,14 @@ pub struct FileErrorInfo {
     pub line: usize,
 }
 
+#[derive(Clone, Serialize, Deserialize)]
+pub struct RejectedRow {
+    pub line: usize,
+    pub error: FileParseError,
+    /// The raw record, if the format can provide it.
+    pub record: Option<String>,
+}
+
 #[derive(Error, Debug, Clone, Serialize, Deserialize)]
 pub enum FileParseError {
     #[error(
@@ -116,6 +136,8 @@ pub enum FileParseError {
         column_name: String,
         column_type: String,
         decode_error: String,
+        /// The code of the error raised by the decoder.
+        error_code: u16,
         column_data: String,
     },
     #[error("Missing value for column {column_index} ({column_name} {column_type})")]
@@ -151,6 +173,24 @@ pub enum FileParseError {
 }
 
 impl FileParseError {
+    pub fn column_name(&self) -> Option<&str> {
+        match self {
+            FileParseError::ColumnDecodeError { column_name, .. }
+            | FileParseError::ColumnMissingError { column_name, .. }
+            | FileParseError::ColumnEmptyError { column_name, .. }
+            | FileParseError::ColumnDataNotDrained { column_name, .. } => Some(column_name),
+            _ => None,
+        }
+    }
+
+    /// The code of the underlying error, `BAD_BYTES` if the row itself is malformed.
+    pub fn code(&self) -> u16 {
+        match self {
+            FileParseError::ColumnDecodeError { error_code, .. } => *error_code,
+            _ => ErrorCode::BAD_BYTES,
+        }
+    }
+
     pub fn to_error_code(&self, mode: &OnErrorMode, file_path: &str, line: usize) -> ErrorCode {
         let pos: String = format!("at file '{}', line {}", file_path, line);
         let message = match mode {
@@ -0,0 +1,233 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The `geo` metadata of [GeoParquet](https://geoparquet.org/releases/v1.1.0/) files.
+
+use std::collections::BTreeMap;
+
+use arrow_schema::Schema as ArrowSchema;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use parquet::format::KeyValue;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde_json::json;
+use serde_json::Value;
+
+/// The key of the GeoParquet metadata in the key-value metadata of parquet files.
+pub const GEO_PARQUET_KEY: &str = "geo";
+pub const GEO_PARQUET_VERSION: &str = "1.1.0";
+pub const GEO_PARQUET_ENCODING_WKB: &str = "WKB";
+
+/// The SRID of the default CRS `OGC:CRS84`, which is the longitude/latitude of WGS 84.
+const DEFAULT_SRID: i32 = 4326;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GeoParquetMetadata {
+    pub version: String,
+    pub primary_column: String,
+    pub columns: BTreeMap<String, GeoParquetColumn>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct GeoParquetColumn {
+    pub encoding: String,
+    #[serde(default)]
+    pub geometry_types: Vec<String>,
+    /// [None] if the key is missing, which means `OGC:CRS84`,
+    /// and `Some(Value::Null)` if the CRS is explicitly unknown.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_crs",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub crs: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<String>,
+    /// `[xmin, ymin, xmax, ymax]` of all the geometries in the file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bbox: Option<Vec<f64>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub covering: Option<GeoParquetCovering>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GeoParquetCovering {
+    pub bbox: GeoParquetBboxCovering,
+}
+
+/// Paths of the struct fields holding the bounding box of each geometry,
+/// e.g. `"xmin": ["bbox", "xmin"]`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GeoParquetBboxCovering {
+    pub xmin: Vec<String>,
+    pub ymin: Vec<String>,
+    pub xmax: Vec<String>,
+    pub ymax: Vec<String>,
+}
+
+fn deserialize_crs<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Option<Value>, D::Error> {
+    Value::deserialize(deserializer).map(Some)
+}
+
+impl GeoParquetMetadata {
+    pub fn from_json(json: &str) -> Result<Self> {
+        serde_json::from_str(json)
+            .map_err(|e| ErrorCode::BadBytes(format!("Invalid GeoParquet metadata {json:?}: {e}")))
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string(self).map_err(|e| ErrorCode::Internal(e.to_string()))
+    }
+
+    pub fn from_key_value_metadata(metas: Option<&Vec<KeyValue>>) -> Result<Option<Self>> {
+        metas
+            .and_then(|metas| metas.iter().find(|m| m.key == GEO_PARQUET_KEY))
+            .and_then(|m| m.value.as_ref())
+            .map(|v| Self::from_json(v))
+            .transpose()
+    }
+
+    pub fn from_arrow_schema(schema: &ArrowSchema) -> Result<Option<Self>> {
+        schema
+            .metadata()
+            .get(GEO_PARQUET_KEY)
+            .map(|v| Self::from_json(v))
+            .transpose()
+    }
+
+    pub fn to_key_value(&self) -> Result<KeyValue> {
+        Ok(KeyValue::new(GEO_PARQUET_KEY.to_string(), self.to_json()?))
+    }
+
+    /// Find the column by name, ignoring ASCII case as the column names may be lowercased.
+    pub fn column(&self, name: &str) -> Option<&GeoParquetColumn> {
+        self.columns
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, c)| c)
+    }
+}
+
+impl GeoParquetColumn {
+    pub fn is_wkb(&self) -> bool {
+        self.encoding.eq_ignore_ascii_case(GEO_PARQUET_ENCODING_WKB)
+    }
+
+    /// Edges are interpolated on the sphere, the column is read as `Geography`.
+    pub fn is_spherical(&self) -> bool {
+        self.edges
+            .as_ref()
+            .is_some_and(|e| e.eq_ignore_ascii_case("spherical"))
+    }
+
+    /// The SRID of the CRS, [None] if the CRS is unknown or not identified by an EPSG code.
+    pub fn srid(&self) -> Option<i32> {
+        match &self.crs {
+            None => Some(DEFAULT_SRID),
+            Some(crs) => crs_to_srid(crs),
+        }
+    }
+
+    /// The CRS of the SRID, an identifier is enough for readers to look up the full definition.
+    pub fn set_srid(&mut self, srid: Option<i32>) {
+        self.crs = match srid {
+            Some(DEFAULT_SRID) => None,
+            Some(srid) => Some(json!({"id": {"authority": "EPSG", "code": srid}})),
+            None => Some(Value::Null),
+        };
+    }
+}
+
+/// Get the SRID from a PROJJSON object like `{"id": {"authority": "EPSG", "code": 3857}}`,
+/// or an identifier string like `"EPSG:3857"`.
+fn crs_to_srid(crs: &Value) -> Option<i32> {
+    let (authority, code) = match crs {
+        Value::String(s) => {
+            let (authority, code) = s.split_once(':')?;
+            (authority.to_string(), code.to_string())
+        }
+        Value::Object(obj) => {
+            let id = obj.get("id")?;
+            let authority = id.get("authority")?.as_str()?.to_string();
+            let code = match id.get("code")? {
+                Value::Number(n) => n.to_string(),
+                Value::String(s) => s.clone(),
+                _ => return None,
+            };
+            (authority, code)
+        }
+        _ => return None,
+    };
+    match authority.to_uppercase().as_str() {
+        "EPSG" => code.parse().ok(),
+        "OGC" if code.eq_ignore_ascii_case("CRS84") => Some(DEFAULT_SRID),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geo_parquet::GeoParquetColumn;
+    use crate::geo_parquet::GeoParquetMetadata;
+    use crate::geo_parquet::GEO_PARQUET_ENCODING_WKB;
+
+    #[test]
+    fn test_geo_parquet_srid() {
+        let meta = GeoParquetMetadata::from_json(
+            r#"{
+                "version": "1.1.0",
+                "primary_column": "geometry",
+                "columns": {
+                    "geometry": {"encoding": "WKB", "geometry_types": ["Point"]},
+                    "unknown": {"encoding": "WKB", "crs": null},
+                    "mercator": {
+                        "encoding": "WKB",
+                        "geometry_types": [],
+                        "crs": {"type": "ProjectedCRS", "id": {"authority": "EPSG", "code": 3857}},
+                        "bbox": [0.0, 1.0, 2.0, 3.0],
+                        "covering": {"bbox": {
+                            "xmin": ["bbox", "xmin"],
+                            "ymin": ["bbox", "ymin"],
+                            "xmax": ["bbox", "xmax"],
+                            "ymax": ["bbox", "ymax"]
+                        }}
+                    },
+                    "ogc": {"encoding": "WKB", "crs": "OGC:CRS84", "edges": "spherical"}
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(meta.column("Geometry").unwrap().srid(), Some(4326));
+        assert_eq!(meta.column("unknown").unwrap().srid(), None);
+        assert_eq!(meta.column("mercator").unwrap().srid(), Some(3857));
+        assert!(meta.column("mercator").unwrap().covering.is_some());
+        assert_eq!(meta.column("ogc").unwrap().srid(), Some(4326));
+        assert!(meta.column("ogc").unwrap().is_spherical());
+
+        let mut column = GeoParquetColumn {
+            encoding: GEO_PARQUET_ENCODING_WKB.to_string(),
+            ..Default::default()
+        };
+        for srid in [Some(4326), Some(3857), None] {
+            column.set_srid(srid);
+            let json = serde_json::to_string(&column).unwrap();
+            let column: GeoParquetColumn = serde_json::from_str(&json).unwrap();
+            assert_eq!(column.srid(), srid);
+        }
+    }
+}
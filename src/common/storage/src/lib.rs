@@ -54,10 +54,18 @@ mod column_node;
 pub use column_node::ColumnNode;
 pub use column_node::ColumnNodes;
 
+pub mod geo_parquet;
 pub mod parquet_rs;
 pub use parquet_rs::read_metadata_async;
 pub use parquet_rs::read_parquet_schema_async_rs;
 
+mod archive;
+pub use archive::list_archive_members;
+pub use archive::parse_zip_local_header;
+pub use archive::ArchiveFormat;
+pub use archive::ArchiveMember;
+pub use archive::ZIP_LOCAL_HEADER_SIZE;
+
 mod stage;
 pub use stage::init_stage_operator;
 pub use stage::StageFileInfo;
@@ -76,6 +84,7 @@ mod statistics;
 pub use copy::CopyStatus;
 pub use copy::FileParseError;
 pub use copy::FileStatus;
+pub use copy::RejectedRow;
 pub use histogram::Histogram;
 pub use histogram::HistogramBucket;
 pub use histogram::DEFAULT_HISTOGRAM_BUCKETS;
@@ -15,9 +15,12 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use arrow_schema::DataType as ArrowDataType;
 use arrow_schema::Schema as ArrowSchema;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::converts::arrow::ARROW_EXT_TYPE_GEOGRAPHY;
+use databend_common_expression::converts::arrow::ARROW_EXT_TYPE_GEOMETRY;
 use databend_common_expression::converts::arrow::EXTENSION_KEY;
 use databend_common_expression::FieldIndex;
 use opendal::Operator;
@@ -30,6 +33,8 @@ use parquet::file::footer::decode_metadata;
 use parquet::file::metadata::FileMetaData;
 use parquet::file::metadata::ParquetMetaData;
 
+use crate::geo_parquet::GeoParquetMetadata;
+
 const FOOTER_SIZE: u64 = 8;
 /// The number of bytes read at the end of the parquet file on first read
 const DEFAULT_FOOTER_READ_SIZE: u64 = 64 * 1024;
@@ -85,6 +90,38 @@ pub fn infer_schema_with_extension(meta: &FileMetaData) -> Result<ArrowSchema> {
         arrow_schema = ArrowSchema::new_with_metadata(new_fields, arrow_schema.metadata);
     }
 
+    // WKB columns of GeoParquet files are read as geometries.
+    if let Some(geo) = GeoParquetMetadata::from_arrow_schema(&arrow_schema)? {
+        let mut new_fields = Vec::with_capacity(arrow_schema.fields.len());
+        for field in arrow_schema.fields.iter() {
+            let ext_type = match geo.column(field.name()) {
+                Some(column)
+                    if column.is_wkb()
+                        && matches!(
+                            field.data_type(),
+                            ArrowDataType::Binary | ArrowDataType::LargeBinary
+                        ) =>
+                {
+                    if column.is_spherical() {
+                        ARROW_EXT_TYPE_GEOGRAPHY
+                    } else {
+                        ARROW_EXT_TYPE_GEOMETRY
+                    }
+                }
+                _ => {
+                    new_fields.push(field.clone());
+                    continue;
+                }
+            };
+            let f = field.as_ref().clone().with_metadata(HashMap::from([(
+                EXTENSION_KEY.to_string(),
+                ext_type.to_string(),
+            )]));
+            new_fields.push(Arc::new(f));
+        }
+        arrow_schema = ArrowSchema::new_with_metadata(new_fields, arrow_schema.metadata);
+    }
+
     Ok(arrow_schema)
 }
 
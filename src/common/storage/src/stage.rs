@@ -21,6 +21,7 @@ use chrono::Utc;
 use databend_common_base::runtime::execute_futures_in_parallel;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal::StageFileCompression;
 use databend_common_meta_app::principal::StageInfo;
 use databend_common_meta_app::principal::StageType;
 use databend_common_meta_app::principal::UserIdentity;
@@ -33,6 +34,9 @@ use opendal::Metadata;
 use opendal::Operator;
 use regex::Regex;
 
+use crate::archive::list_archive_members;
+use crate::archive::ArchiveFormat;
+use crate::archive::ArchiveMember;
 use crate::init_operator;
 use crate::DataOperator;
 
@@ -51,6 +55,9 @@ pub struct StageFileInfo {
     pub etag: Option<String>,
     pub status: StageFileStatus,
     pub creator: Option<UserIdentity>,
+    /// Set if the file is a member of an archive in the stage.
+    #[serde(default)]
+    pub archive_member: Option<ArchiveMember>,
 }
 
 impl StageFileInfo {
@@ -63,6 +70,7 @@ impl StageFileInfo {
             etag: meta.etag().map(str::to_string),
             status: StageFileStatus::NeedCopy,
             creator: None,
+            archive_member: None,
         }
     }
 }
@@ -183,6 +191,83 @@ impl StageFilesInfo {
         }
     }
 
+    /// List files like [`Self::list_stream`], with the archives under the compression
+    /// replaced by their members, `PATTERN` is matched against the paths of the members.
+    #[async_backtrace::framed]
+    pub async fn list_stream_with_archives(
+        &self,
+        operator: &Operator,
+        thread_num: usize,
+        max_files: Option<usize>,
+        compression: StageFileCompression,
+    ) -> Result<StageFileInfoStream> {
+        if !ArchiveFormat::may_be_archive(compression) || self.path == STDIN_FD {
+            return self.list_stream(operator, thread_num, max_files).await;
+        }
+
+        // The archives may not match the pattern while their members do.
+        let pattern = match &self.files {
+            Some(_) => Arc::new(None),
+            None => Arc::new(self.get_pattern()?),
+        };
+        let files_info = StageFilesInfo {
+            path: self.path.clone(),
+            files: self.files.clone(),
+            pattern: None,
+        };
+        let files = files_info.list_stream(operator, thread_num, None).await?;
+
+        let prefix = self.path.clone();
+        let prefix_len = if prefix == "/" { 0 } else { prefix.len() };
+        let operator = operator.clone();
+        let files = files
+            .map_ok(move |file| {
+                let operator = operator.clone();
+                let pattern = pattern.clone();
+                let prefix = prefix.clone();
+                async move {
+                    let files = match ArchiveFormat::from_compression(compression, &file.path) {
+                        Some(format) => list_archive_members(&operator, &file, format).await?,
+                        None => vec![file],
+                    };
+                    let files = files.into_iter().filter(move |file| {
+                        // The file specified by the path exactly is not matched against the pattern.
+                        file.path == prefix
+                            || match pattern.as_ref() {
+                                Some(p) => {
+                                    p.is_match(file.path.get(prefix_len..).unwrap_or_default())
+                                }
+                                None => true,
+                            }
+                    });
+                    Ok::<_, ErrorCode>(stream::iter(files.map(Ok::<_, ErrorCode>)))
+                }
+            })
+            .try_buffered(thread_num.max(1))
+            .try_flatten();
+        match max_files {
+            Some(max_files) => Ok(Box::pin(files.take(max_files))),
+            None => Ok(Box::pin(files)),
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn list_with_archives(
+        &self,
+        operator: &Operator,
+        thread_num: usize,
+        max_files: Option<usize>,
+        compression: StageFileCompression,
+    ) -> Result<Vec<StageFileInfo>> {
+        if !ArchiveFormat::may_be_archive(compression) {
+            return self.list(operator, thread_num, max_files).await;
+        }
+        self.list_stream_with_archives(operator, thread_num, max_files, compression)
+            .await?
+            .try_collect::<Vec<_>>()
+            .await
+    }
+
     #[async_backtrace::framed]
     pub async fn first_file(&self, operator: &Operator) -> Result<StageFileInfo> {
         // We only fetch first file.
@@ -961,7 +961,20 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         self.crud_update_existing(
             &req.name_ident,
             |mut meta| {
-                meta.virtual_columns = req.virtual_columns.clone();
+                if req.append_only {
+                    let num_virtual_columns = meta.virtual_columns.len();
+                    for (name, data_type) in &req.virtual_columns {
+                        if !meta.virtual_columns.iter().any(|(n, _)| n == name) {
+                            meta.virtual_columns.push((name.clone(), data_type.clone()));
+                        }
+                    }
+                    if meta.virtual_columns.len() == num_virtual_columns {
+                        // Nothing to append, cancel the update.
+                        return None;
+                    }
+                } else {
+                    meta.virtual_columns = req.virtual_columns.clone();
+                }
                 meta.updated_on = Some(Utc::now());
                 Some((meta, None))
             },
@@ -1113,7 +1126,7 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         };
 
         if !req.table_meta.indexes.is_empty() {
-            // check the index column id exists and not be duplicated.
+            // check the index column id exists and not be duplicated in the same type of indexes.
             let mut index_column_ids = HashSet::new();
             for (_, index) in req.table_meta.indexes.iter() {
                 for column_id in &index.column_ids {
@@ -1122,12 +1135,12 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                             IndexColumnIdNotFound::new(*column_id, &index.name),
                         )));
                     }
-                    if index_column_ids.contains(column_id) {
+                    if index_column_ids.contains(&(index.index_type, column_id)) {
                         return Err(KVAppError::AppError(AppError::DuplicatedIndexColumnId(
                             DuplicatedIndexColumnId::new(*column_id, &index.name),
                         )));
                     }
-                    index_column_ids.insert(column_id);
+                    index_column_ids.insert((index.index_type, column_id));
                 }
             }
         }
@@ -2524,9 +2537,9 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                 }
             }
 
-            // column_id can not be duplicated
+            // column_id can not be duplicated in the indexes of the same type
             for (name, index) in indexes.iter() {
-                if *name == req.name {
+                if *name == req.name || index.index_type != req.index_type {
                     continue;
                 }
                 for column_id in &req.column_ids {
@@ -2543,7 +2556,10 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
             let mut old_version = None;
             let mut mark_delete_op = None;
             if let Some(old_index) = indexes.get(&req.name) {
-                if old_index.column_ids == req.column_ids && old_index.options == req.options {
+                if old_index.index_type == req.index_type
+                    && old_index.column_ids == req.column_ids
+                    && old_index.options == req.options
+                {
                     old_version = Some(old_index.version.clone());
                 } else {
                     let (m_key, m_value) = mark_table_index_as_deleted(
@@ -2558,6 +2574,7 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
             let version = old_version.unwrap_or(Uuid::new_v4().simple().to_string());
 
             let index = TableIndex {
+                index_type: req.index_type,
                 name: req.name.clone(),
                 column_ids: req.column_ids.clone(),
                 sync_creation: req.sync_creation,
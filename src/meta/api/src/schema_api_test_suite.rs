@@ -112,6 +112,7 @@ use databend_common_meta_app::schema::TableIdHistoryIdent;
 use databend_common_meta_app::schema::TableIdList;
 use databend_common_meta_app::schema::TableIdToName;
 use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::schema::TableNameIdent;
@@ -6005,6 +6006,7 @@ impl SchemaApiTestSuite {
             info!("--- create table index 1");
             let req = CreateTableIndexReq {
                 create_option: CreateOption::Create,
+                index_type: TableIndexType::Inverted,
                 tenant: tenant.clone(),
                 table_id,
                 name: index_name_1.clone(),
@@ -6024,6 +6026,7 @@ impl SchemaApiTestSuite {
             info!("--- create table index 2 with duplicate column id");
             let req = CreateTableIndexReq {
                 create_option: CreateOption::Create,
+                index_type: TableIndexType::Inverted,
                 table_id,
                 tenant: tenant.clone(),
                 name: index_name_2.clone(),
@@ -6037,6 +6040,7 @@ impl SchemaApiTestSuite {
             info!("--- create table index 2");
             let req = CreateTableIndexReq {
                 create_option: CreateOption::Create,
+                index_type: TableIndexType::Inverted,
                 table_id,
                 tenant: tenant.clone(),
                 name: index_name_2.clone(),
@@ -6058,6 +6062,7 @@ impl SchemaApiTestSuite {
             info!("--- create table index again with if_not_exists = false");
             let req = CreateTableIndexReq {
                 create_option: CreateOption::Create,
+                index_type: TableIndexType::Inverted,
                 table_id,
                 tenant: tenant.clone(),
                 name: index_name_1.clone(),
@@ -6078,6 +6083,7 @@ impl SchemaApiTestSuite {
             info!("--- create table index again with if_not_exists = true");
             let req = CreateTableIndexReq {
                 create_option: CreateOption::CreateIfNotExists,
+                index_type: TableIndexType::Inverted,
                 table_id,
                 tenant: tenant.clone(),
                 name: index_name_1.clone(),
@@ -6094,6 +6100,7 @@ impl SchemaApiTestSuite {
             info!("--- create table index with invalid column id");
             let req = CreateTableIndexReq {
                 create_option: CreateOption::Create,
+                index_type: TableIndexType::Inverted,
                 table_id,
                 tenant: tenant.clone(),
                 name: index_name_3.clone(),
@@ -6197,6 +6204,7 @@ impl SchemaApiTestSuite {
             info!("--- replace index_2");
             let req = CreateTableIndexReq {
                 create_option: CreateOption::CreateOrReplace,
+                index_type: TableIndexType::Inverted,
                 table_id,
                 tenant: tenant.clone(),
                 name: index_name_2.clone(),
@@ -6742,6 +6750,7 @@ impl SchemaApiTestSuite {
                         ))),
                     ),
                 ],
+                append_only: false,
             };
 
             mt.update_virtual_column(req).await?;
@@ -6775,6 +6784,47 @@ impl SchemaApiTestSuite {
             ]);
         }
 
+        {
+            info!("--- append virtual columns");
+            let req = UpdateVirtualColumnReq {
+                if_exists: false,
+                name_ident: name_ident.clone(),
+                virtual_columns: vec![
+                    (
+                        "variant:k2".to_string(),
+                        TableDataType::Nullable(Box::new(TableDataType::String)),
+                    ),
+                    (
+                        "variant:k5".to_string(),
+                        TableDataType::Nullable(Box::new(TableDataType::Variant)),
+                    ),
+                ],
+                append_only: true,
+            };
+
+            mt.update_virtual_column(req).await?;
+
+            let req = ListVirtualColumnsReq::new(&tenant, Some(table_id));
+            let res = mt.list_virtual_columns(req).await?;
+            assert_eq!(1, res.len());
+            // The existing virtual columns are kept.
+            assert_eq!(res[0].virtual_columns.len(), 5);
+            assert_eq!(
+                res[0].virtual_columns[0],
+                (
+                    "variant:k2".to_string(),
+                    TableDataType::Nullable(Box::new(TableDataType::Variant))
+                )
+            );
+            assert_eq!(
+                res[0].virtual_columns[4],
+                (
+                    "variant:k5".to_string(),
+                    TableDataType::Nullable(Box::new(TableDataType::Variant))
+                )
+            );
+        }
+
         {
             info!("--- drop virtual column");
             let req = DropVirtualColumnReq {
@@ -6818,6 +6868,7 @@ impl SchemaApiTestSuite {
                         ))),
                     ),
                 ],
+                append_only: false,
             };
 
             let res = mt.update_virtual_column(req).await;
@@ -49,6 +49,11 @@ const NULL_FIELD_AS: &str = "null_field_as";
 const NULL_IF: &str = "null_if";
 const OPT_EMPTY_FIELD_AS: &str = "empty_field_as";
 const OPT_BINARY_FORMAT: &str = "binary_format";
+const OPT_COMPRESSION_LEVEL: &str = "compression_level";
+const OPT_ROW_GROUP_SIZE: &str = "row_group_size";
+const OPT_ENABLE_PAGE_INDEX: &str = "enable_page_index";
+const OPT_BLOOM_FILTER_COLUMNS: &str = "bloom_filter_columns";
+const OPT_SORTING_COLUMNS: &str = "sorting_columns";
 
 /// File format parameters after checking and parsing.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -61,6 +66,7 @@ pub enum FileFormatParams {
     Xml(XmlFileFormatParams),
     Parquet(ParquetFileFormatParams),
     Orc(OrcFileFormatParams),
+    Avro(AvroFileFormatParams),
 }
 
 impl FileFormatParams {
@@ -73,6 +79,7 @@ impl FileFormatParams {
             FileFormatParams::Xml(_) => StageFileFormatType::Xml,
             FileFormatParams::Parquet(_) => StageFileFormatType::Parquet,
             FileFormatParams::Orc(_) => StageFileFormatType::Orc,
+            FileFormatParams::Avro(_) => StageFileFormatType::Avro,
         }
     }
 
@@ -89,7 +96,11 @@ impl FileFormatParams {
             StageFileFormatType::Json => {
                 Ok(FileFormatParams::Json(JsonFileFormatParams::default()))
             }
+            StageFileFormatType::Xml => Ok(FileFormatParams::Xml(XmlFileFormatParams::default())),
             StageFileFormatType::Orc => Ok(FileFormatParams::Orc(OrcFileFormatParams::default())),
+            StageFileFormatType::Avro => {
+                Ok(FileFormatParams::Avro(AvroFileFormatParams::default()))
+            }
             _ => Err(ErrorCode::IllegalFileFormat(format!(
                 "Unsupported file format type: {:?}",
                 format_type
@@ -106,6 +117,8 @@ impl FileFormatParams {
             FileFormatParams::Xml(v) => v.compression,
             FileFormatParams::Parquet(_) => StageFileCompression::None,
             FileFormatParams::Orc(_) => StageFileCompression::None,
+            // Avro object container files compress the data blocks by themselves.
+            FileFormatParams::Avro(_) => StageFileCompression::None,
         }
     }
 
@@ -132,6 +145,7 @@ impl FileFormatParams {
     pub fn need_field_default(&self) -> bool {
         match self {
             FileFormatParams::Parquet(v) => v.missing_field_as == NullAs::FieldDefault,
+            FileFormatParams::Avro(v) => v.missing_field_as == NullAs::FieldDefault,
             FileFormatParams::Csv(v) => v.empty_field_as == EmptyFieldAs::FieldDefault,
             FileFormatParams::NdJson(v) => {
                 v.null_field_as == NullAs::FieldDefault
@@ -172,17 +186,48 @@ impl FileFormatParams {
             StageFileFormatType::Parquet => {
                 let missing_field_as = reader.options.remove(MISSING_FIELD_AS);
                 let null_if = parse_null_if(reader.options.remove(NULL_IF))?;
-                FileFormatParams::Parquet(ParquetFileFormatParams::try_create(
-                    missing_field_as.as_deref(),
-                    null_if,
-                )?)
+                let mut params =
+                    ParquetFileFormatParams::try_create(missing_field_as.as_deref(), null_if)?;
+                // files are compressed with zstd by default when unloading.
+                if reader.options.contains_key("compression") {
+                    params.compression = reader.take_compression()?;
+                }
+                params.compression_level = reader
+                    .options
+                    .remove(OPT_COMPRESSION_LEVEL)
+                    .map(|v| u64::from_str(&v))
+                    .transpose()?;
+                params.row_group_size = reader.take_u64(OPT_ROW_GROUP_SIZE, 0)?;
+                params.enable_page_index = reader.take_bool(OPT_ENABLE_PAGE_INDEX, false)?;
+                params.bloom_filter_columns = parse_string_list(
+                    reader.options.remove(OPT_BLOOM_FILTER_COLUMNS),
+                    "BLOOM_FILTER_COLUMNS",
+                )?;
+                params.sorting_columns = parse_string_list(
+                    reader.options.remove(OPT_SORTING_COLUMNS),
+                    "SORTING_COLUMNS",
+                )?;
+                params.check_write_options()?;
+                FileFormatParams::Parquet(params)
             }
             StageFileFormatType::Orc => {
+                let compression = reader.take_compression()?;
                 let missing_field_as = reader.options.remove(MISSING_FIELD_AS);
                 FileFormatParams::Orc(OrcFileFormatParams::try_create(
+                    compression,
                     missing_field_as.as_deref(),
                 )?)
             }
+            StageFileFormatType::Avro => {
+                let compression = reader.take_compression()?;
+                let missing_field_as = reader.options.remove(MISSING_FIELD_AS);
+                let null_if = parse_null_if(reader.options.remove(NULL_IF))?;
+                FileFormatParams::Avro(AvroFileFormatParams::try_create(
+                    compression,
+                    missing_field_as.as_deref(),
+                    null_if,
+                )?)
+            }
             StageFileFormatType::Csv => {
                 let default = CsvFileFormatParams::default();
                 let compression = reader.take_compression()?;
@@ -312,10 +357,7 @@ impl FileFormatParams {
 
 impl Default for FileFormatParams {
     fn default() -> Self {
-        FileFormatParams::Parquet(ParquetFileFormatParams {
-            missing_field_as: NullAs::Error,
-            null_if: vec![],
-        })
+        FileFormatParams::Parquet(ParquetFileFormatParams::default())
     }
 }
 
@@ -690,10 +732,41 @@ impl NdJsonFileFormatParams {
     }
 }
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ParquetFileFormatParams {
     pub missing_field_as: NullAs,
     pub null_if: Vec<String>,
+
+    // The options below only take effect when unloading.
+    /// Codec of the column chunks, `Auto` means zstd.
+    pub compression: StageFileCompression,
+    /// Level of the codec, [None] for the default level of the codec.
+    pub compression_level: Option<u64>,
+    /// Max number of rows of a row group, 0 for the default size.
+    pub row_group_size: u64,
+    /// Write the column index and offset index, so that readers can prune pages.
+    pub enable_page_index: bool,
+    pub bloom_filter_columns: Vec<String>,
+    /// Columns the written data is sorted by, e.g. `a` or `b DESC NULLS FIRST`,
+    /// they are recorded in the row group metadata, the writer does not sort the data
+    /// but fails if it is not sorted by them.
+    pub sorting_columns: Vec<String>,
+}
+
+impl Default for ParquetFileFormatParams {
+    fn default() -> Self {
+        Self {
+            missing_field_as: NullAs::Error,
+            null_if: vec![],
+            compression: StageFileCompression::Auto,
+            compression_level: None,
+            row_group_size: 0,
+            enable_page_index: false,
+            bloom_filter_columns: vec![],
+            sorting_columns: vec![],
+        }
+    }
 }
 
 impl ParquetFileFormatParams {
@@ -702,19 +775,164 @@ impl ParquetFileFormatParams {
         Ok(Self {
             missing_field_as,
             null_if,
+            ..Default::default()
+        })
+    }
+
+    pub fn check_write_options(&self) -> Result<()> {
+        let levels = match self.compression {
+            StageFileCompression::Auto | StageFileCompression::Zstd => Some(1..=22),
+            StageFileCompression::Gzip => Some(0..=9),
+            StageFileCompression::Brotli => Some(0..=11),
+            StageFileCompression::None | StageFileCompression::Snappy => None,
+            compression => {
+                return Err(ErrorCode::IllegalFileFormat(format!(
+                    "compression {compression} is not supported by PARQUET, must one of {{ none | zstd | snappy | gzip | brotli }}"
+                )));
+            }
+        };
+        if let Some(level) = self.compression_level {
+            match levels {
+                Some(levels) if levels.contains(&level) => {}
+                Some(levels) => {
+                    return Err(ErrorCode::IllegalFileFormat(format!(
+                        "COMPRESSION_LEVEL {level} is out of range [{}, {}] of compression {}",
+                        levels.start(),
+                        levels.end(),
+                        self.compression
+                    )));
+                }
+                None => {
+                    return Err(ErrorCode::IllegalFileFormat(format!(
+                        "COMPRESSION_LEVEL is not supported by compression {}",
+                        self.compression
+                    )));
+                }
+            }
+        }
+        for column in &self.sorting_columns {
+            ParquetSortingColumn::parse(column)?;
+        }
+        Ok(())
+    }
+}
+
+/// A column of [`ParquetFileFormatParams::sorting_columns`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParquetSortingColumn {
+    pub name: String,
+    pub descending: bool,
+    pub nulls_first: bool,
+}
+
+impl ParquetSortingColumn {
+    /// Parse `<name> [ASC | DESC] [NULLS FIRST | NULLS LAST]`,
+    /// nulls are last for ascending order and first for descending order by default.
+    pub fn parse(s: &str) -> Result<Self> {
+        let invalid = || {
+            ErrorCode::IllegalFileFormat(format!(
+                "Invalid SORTING_COLUMNS {s:?}, must be like '<column> [ASC | DESC] [NULLS FIRST | NULLS LAST]'"
+            ))
+        };
+        let words = s.split_whitespace().collect::<Vec<_>>();
+        let (name, mut rest) = words.split_first().ok_or_else(invalid)?;
+        let mut descending = false;
+        if let Some((order, r)) = rest.split_first() {
+            if order.eq_ignore_ascii_case("asc") || order.eq_ignore_ascii_case("desc") {
+                descending = order.eq_ignore_ascii_case("desc");
+                rest = r;
+            }
+        }
+        let nulls_first = match rest {
+            [] => descending,
+            [nulls, first] if nulls.eq_ignore_ascii_case("nulls") => {
+                if first.eq_ignore_ascii_case("first") {
+                    true
+                } else if first.eq_ignore_ascii_case("last") {
+                    false
+                } else {
+                    return Err(invalid());
+                }
+            }
+            _ => return Err(invalid()),
+        };
+        Ok(Self {
+            name: name.to_string(),
+            descending,
+            nulls_first,
         })
     }
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct OrcFileFormatParams {
+    /// Codec of the compression chunks inside the ORC files written by unloading,
+    /// ORC files are never compressed as a whole.
+    pub compression: StageFileCompression,
     pub missing_field_as: NullAs,
 }
 
 impl OrcFileFormatParams {
-    pub fn try_create(missing_field_as: Option<&str>) -> Result<Self> {
+    pub fn try_create(
+        compression: StageFileCompression,
+        missing_field_as: Option<&str>,
+    ) -> Result<Self> {
+        match compression {
+            StageFileCompression::Auto
+            | StageFileCompression::None
+            | StageFileCompression::Zstd
+            | StageFileCompression::Snappy
+            | StageFileCompression::Deflate
+            | StageFileCompression::RawDeflate => {}
+            _ => {
+                return Err(ErrorCode::IllegalFileFormat(format!(
+                    "compression {compression} is not supported by ORC, must one of {{ none | zstd | snappy | deflate }}"
+                )));
+            }
+        }
         let missing_field_as = NullAs::parse(missing_field_as, MISSING_FIELD_AS, NullAs::Error)?;
-        Ok(Self { missing_field_as })
+        Ok(Self {
+            compression,
+            missing_field_as,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AvroFileFormatParams {
+    /// Codec of the data blocks inside the Avro files written by unloading.
+    pub compression: StageFileCompression,
+    pub missing_field_as: NullAs,
+    pub null_if: Vec<String>,
+}
+
+impl AvroFileFormatParams {
+    pub fn try_create(
+        compression: StageFileCompression,
+        missing_field_as: Option<&str>,
+        null_if: Vec<String>,
+    ) -> Result<Self> {
+        match compression {
+            StageFileCompression::Auto
+            | StageFileCompression::None
+            | StageFileCompression::Zstd
+            | StageFileCompression::Snappy
+            | StageFileCompression::Deflate
+            | StageFileCompression::RawDeflate
+            | StageFileCompression::Bz2
+            | StageFileCompression::Xz => {}
+            _ => {
+                return Err(ErrorCode::IllegalFileFormat(format!(
+                    "compression {compression} is not supported by AVRO, must one of {{ none | zstd | snappy | deflate | bz2 | xz }}"
+                )));
+            }
+        }
+        let missing_field_as = NullAs::parse(missing_field_as, MISSING_FIELD_AS, NullAs::Error)?;
+        Ok(Self {
+            compression,
+            missing_field_as,
+            null_if,
+        })
     }
 }
 
@@ -779,17 +997,52 @@ impl Display for FileFormatParams {
             FileFormatParams::Parquet(params) => {
                 write!(
                     f,
-                    "TYPE = PARQUET MISSING_FIELD_AS = {}",
-                    params.missing_field_as
-                )
+                    "TYPE = PARQUET COMPRESSION = {:?} MISSING_FIELD_AS = {}",
+                    params.compression, params.missing_field_as
+                )?;
+                if let Some(level) = params.compression_level {
+                    write!(f, " COMPRESSION_LEVEL = {level}")?;
+                }
+                if params.row_group_size != 0 {
+                    write!(f, " ROW_GROUP_SIZE = {}", params.row_group_size)?;
+                }
+                if params.enable_page_index {
+                    write!(f, " ENABLE_PAGE_INDEX = true")?;
+                }
+                if !params.bloom_filter_columns.is_empty() {
+                    write!(
+                        f,
+                        " BLOOM_FILTER_COLUMNS = ({})",
+                        quoted_string_list(&params.bloom_filter_columns)
+                    )?;
+                }
+                if !params.sorting_columns.is_empty() {
+                    write!(
+                        f,
+                        " SORTING_COLUMNS = ({})",
+                        quoted_string_list(&params.sorting_columns)
+                    )?;
+                }
+                Ok(())
             }
             FileFormatParams::Orc(params) => {
                 write!(
                     f,
-                    "TYPE = ORC MISSING_FIELD_AS = {}",
-                    params.missing_field_as
+                    "TYPE = ORC COMPRESSION = {} MISSING_FIELD_AS = {}",
+                    params.compression, params.missing_field_as
                 )
             }
+            FileFormatParams::Avro(params) => {
+                write!(
+                    f,
+                    "TYPE = AVRO COMPRESSION = {} MISSING_FIELD_AS = {}",
+                    params.compression, params.missing_field_as
+                )?;
+                if !params.null_if.is_empty() {
+                    write!(f, " NULL_IF = ({})", quoted_string_list(&params.null_if))?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -845,6 +1098,25 @@ pub fn check_choices(v: &str, choices: &[&str]) -> std::result::Result<(), Strin
     Ok(())
 }
 
+fn parse_string_list(value: Option<String>, name: &str) -> Result<Vec<String>> {
+    match value {
+        None => Ok(vec![]),
+        Some(s) => serde_json::from_str(&s).map_err(|_| {
+            ErrorCode::InvalidArgument(format!(
+                "Invalid option value: {name} is currently set to {s} (in JSON). The valid values are a list of strings."
+            ))
+        }),
+    }
+}
+
+fn quoted_string_list(values: &[String]) -> String {
+    values
+        .iter()
+        .map(|v| format!("'{}'", escape_string(v)))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 fn parse_null_if(null_if: Option<String>) -> Result<Vec<String>> {
     match null_if {
         None => Ok(vec![]),
@@ -108,6 +108,10 @@ pub enum StageFileCompression {
     Lzo,
     Snappy,
     Xz,
+    /// Zip archive, each member is loaded as a file.
+    Zip,
+    /// Tar archive (optionally gzipped), each member is loaded as a file.
+    Tar,
     None,
 }
 
@@ -137,8 +141,10 @@ impl FromStr for StageFileCompression {
             "lzo" => Ok(StageFileCompression::Lzo),
             "snappy" => Ok(StageFileCompression::Snappy),
             "xz" => Ok(StageFileCompression::Xz),
+            "zip" => Ok(StageFileCompression::Zip),
+            "tar" => Ok(StageFileCompression::Tar),
             "none" => Ok(StageFileCompression::None),
-            _ => Err("Unknown file compression type, must one of { auto | gzip | bz2 | brotli | zstd | deflate | raw_deflate | lzo | snappy | xz | none }"
+            _ => Err("Unknown file compression type, must one of { auto | gzip | bz2 | brotli | zstd | deflate | raw_deflate | lzo | snappy | xz | zip | tar | none }"
                 .to_string()),
         }
     }
@@ -157,6 +163,8 @@ impl Display for StageFileCompression {
             StageFileCompression::Lzo => write!(f, "lzo"),
             StageFileCompression::Snappy => write!(f, "snappy"),
             StageFileCompression::Xz => write!(f, "xz"),
+            StageFileCompression::Zip => write!(f, "zip"),
+            StageFileCompression::Tar => write!(f, "tar"),
             StageFileCompression::None => write!(f, "none"),
         }
     }
@@ -192,11 +200,9 @@ impl FromStr for StageFileFormatType {
             "XML" => Ok(StageFileFormatType::Xml),
             "JSON" => Ok(StageFileFormatType::Json),
             "ORC" => Ok(StageFileFormatType::Orc),
-            "AVRO" => Err(format!(
-                "File format type '{s}' not implemented yet', must be one of ( CSV | TSV | NDJSON | PARQUET | ORC)"
-            )),
+            "AVRO" => Ok(StageFileFormatType::Avro),
             _ => Err(format!(
-                "Unknown file format type '{s}', must be one of ( CSV | TSV | NDJSON | PARQUET | ORC)"
+                "Unknown file format type '{s}', must be one of ( CSV | TSV | NDJSON | PARQUET | ORC | AVRO)"
             )),
         }
     }
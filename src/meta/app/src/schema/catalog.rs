@@ -78,6 +78,7 @@ pub enum IcebergCatalogType {
     Rest = 1,
     Hms = 2,
     Glue = 3,
+    Fs = 4,
 }
 
 /// Option for creating a iceberg catalog
@@ -86,6 +87,7 @@ pub enum IcebergCatalogOption {
     Rest(IcebergRestCatalogOption),
     Hms(IcebergHmsCatalogOption),
     Glue(IcebergGlueCatalogOption),
+    Fs(IcebergFsCatalogOption),
 }
 
 impl IcebergCatalogOption {
@@ -95,6 +97,7 @@ impl IcebergCatalogOption {
             IcebergCatalogOption::Rest(_) => IcebergCatalogType::Rest,
             IcebergCatalogOption::Hms(_) => IcebergCatalogType::Hms,
             IcebergCatalogOption::Glue(_) => IcebergCatalogType::Glue,
+            IcebergCatalogOption::Fs(_) => IcebergCatalogType::Fs,
         }
     }
 }
@@ -126,6 +129,13 @@ pub struct IcebergGlueCatalogOption {
     pub props: HashMap<String, String>,
 }
 
+/// Option for a filesystem (hadoop-style) iceberg catalog, tables are discovered
+/// from the warehouse directory directly.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct IcebergFsCatalogOption {
+    pub storage_params: Box<StorageParams>,
+}
+
 /// Same as `CatalogNameIdent`, but with `serde` support,
 /// and can be used a s part of a value.
 // #[derive(Clone, Debug, PartialEq, Eq)]
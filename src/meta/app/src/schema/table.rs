@@ -281,8 +281,27 @@ pub struct TableMeta {
     pub indexes: BTreeMap<String, TableIndex>,
 }
 
+#[derive(
+    serde::Serialize, serde::Deserialize, Clone, Copy, Debug, Default, Eq, PartialEq, Hash,
+)]
+pub enum TableIndexType {
+    #[default]
+    Inverted,
+    Vector,
+}
+
+impl Display for TableIndexType {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            TableIndexType::Inverted => write!(f, "INVERTED"),
+            TableIndexType::Vector => write!(f, "VECTOR"),
+        }
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
 pub struct TableIndex {
+    pub index_type: TableIndexType,
     pub name: String,
     pub column_ids: Vec<u32>,
     // if true, index will create after data written to databend,
@@ -292,7 +311,7 @@ pub struct TableIndex {
     // the index data needs to be regenerated,
     // version is used to identify each change.
     pub version: String,
-    // index options specify the index configs, like tokenizer.
+    // index options specify the index configs, like tokenizer or metric.
     pub options: BTreeMap<String, String>,
 }
 
@@ -841,6 +860,7 @@ pub struct UpdateTableMetaReply {}
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CreateTableIndexReq {
     pub create_option: CreateOption,
+    pub index_type: TableIndexType,
     pub tenant: Tenant,
     pub table_id: u64,
     pub name: String,
@@ -859,8 +879,8 @@ impl Display for CreateTableIndexReq {
 
         write!(
             f,
-            "{}: {} ColumnIds: {:?}, SyncCreation: {:?}, Options: {:?}",
-            typ, self.name, self.column_ids, self.sync_creation, self.options,
+            "{}: {} IndexType: {}, ColumnIds: {:?}, SyncCreation: {:?}, Options: {:?}",
+            typ, self.name, self.index_type, self.column_ids, self.sync_creation, self.options,
         )
     }
 }
@@ -58,6 +58,9 @@ pub struct UpdateVirtualColumnReq {
     pub if_exists: bool,
     pub name_ident: VirtualColumnIdent,
     pub virtual_columns: Vec<(String, TableDataType)>,
+    // Only append the virtual columns that do not exist,
+    // instead of replacing all of the virtual columns.
+    pub append_only: bool,
 }
 
 impl Display for UpdateVirtualColumnReq {
@@ -129,6 +129,9 @@ impl FromToProto for mt::IcebergCatalogOption {
             pb::iceberg_catalog_option::IcebergCatalogOption::GlueCatalog(v) => {
                 mt::IcebergCatalogOption::Glue(mt::IcebergGlueCatalogOption::from_pb(v)?)
             }
+            pb::iceberg_catalog_option::IcebergCatalogOption::FsCatalog(v) => {
+                mt::IcebergCatalogOption::Fs(mt::IcebergFsCatalogOption::from_pb(v)?)
+            }
         })
     }
 
@@ -146,6 +149,9 @@ impl FromToProto for mt::IcebergCatalogOption {
                 mt::IcebergCatalogOption::Glue(v) => {
                     pb::iceberg_catalog_option::IcebergCatalogOption::GlueCatalog(v.to_pb()?)
                 }
+                mt::IcebergCatalogOption::Fs(v) => {
+                    pb::iceberg_catalog_option::IcebergCatalogOption::FsCatalog(v.to_pb()?)
+                }
             }),
         })
     }
@@ -254,6 +260,34 @@ impl FromToProto for mt::IcebergGlueCatalogOption {
     }
 }
 
+impl FromToProto for mt::IcebergFsCatalogOption {
+    type PB = pb::IcebergFsCatalogOption;
+
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: Self::PB) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let storage_params = p.storage_params.ok_or_else(|| {
+            Incompatible::new("IcebergFsCatalogOption.storage_params is None".to_string())
+        })?;
+        Ok(Self {
+            storage_params: Box::new(StorageParams::from_pb(storage_params)?),
+        })
+    }
+
+    fn to_pb(&self) -> Result<Self::PB, Incompatible> {
+        Ok(pb::IcebergFsCatalogOption {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            storage_params: Some(self.storage_params.to_pb()?),
+        })
+    }
+}
+
 impl FromToProto for mt::HiveCatalogOption {
     type PB = pb::HiveCatalogOption;
 
@@ -82,6 +82,8 @@ impl FromToProtoEnum for mt::principal::StageFileCompression {
             pb::StageFileCompression::Snappy => Ok(mt::principal::StageFileCompression::Snappy),
             pb::StageFileCompression::None => Ok(mt::principal::StageFileCompression::None),
             pb::StageFileCompression::Xz => Ok(mt::principal::StageFileCompression::Xz),
+            pb::StageFileCompression::Zip => Ok(mt::principal::StageFileCompression::Zip),
+            pb::StageFileCompression::Tar => Ok(mt::principal::StageFileCompression::Tar),
         }
     }
 
@@ -100,6 +102,8 @@ impl FromToProtoEnum for mt::principal::StageFileCompression {
             mt::principal::StageFileCompression::Snappy => Ok(pb::StageFileCompression::Snappy),
             mt::principal::StageFileCompression::None => Ok(pb::StageFileCompression::None),
             mt::principal::StageFileCompression::Xz => Ok(pb::StageFileCompression::Xz),
+            mt::principal::StageFileCompression::Zip => Ok(pb::StageFileCompression::Zip),
+            mt::principal::StageFileCompression::Tar => Ok(pb::StageFileCompression::Tar),
         }
     }
 }
@@ -217,6 +221,11 @@ impl FromToProto for mt::principal::FileFormatParams {
                     mt::principal::OrcFileFormatParams::from_pb(p)?,
                 ))
             }
+            Some(pb::file_format_params::Format::Avro(p)) => {
+                Ok(mt::principal::FileFormatParams::Avro(
+                    mt::principal::AvroFileFormatParams::from_pb(p)?,
+                ))
+            }
             Some(pb::file_format_params::Format::Parquet(p)) => {
                 Ok(mt::principal::FileFormatParams::Parquet(
                     mt::principal::ParquetFileFormatParams::from_pb(p)?,
@@ -290,6 +299,11 @@ impl FromToProto for mt::principal::FileFormatParams {
                     mt::principal::OrcFileFormatParams::to_pb(p)?,
                 )),
             }),
+            Self::Avro(p) => Ok(Self::PB {
+                format: Some(pb::file_format_params::Format::Avro(
+                    mt::principal::AvroFileFormatParams::to_pb(p)?,
+                )),
+            }),
         }
     }
 }
@@ -303,15 +317,50 @@ impl FromToProto for mt::principal::OrcFileFormatParams {
     fn from_pb(p: pb::OrcFileFormatParams) -> Result<Self, Incompatible>
     where Self: Sized {
         reader_check_msg(p.ver, p.min_reader_ver)?;
-        mt::principal::OrcFileFormatParams::try_create(p.missing_field_as.as_deref())
+        let compression = compression_from_pb(p.compression)?;
+        mt::principal::OrcFileFormatParams::try_create(compression, p.missing_field_as.as_deref())
             .map_err(|e| Incompatible::new(format!("{e}")))
     }
 
     fn to_pb(&self) -> Result<pb::OrcFileFormatParams, Incompatible> {
+        let compression =
+            mt::principal::StageFileCompression::to_pb_enum(&self.compression)? as i32;
         Ok(pb::OrcFileFormatParams {
             ver: VER,
             min_reader_ver: MIN_READER_VER,
             missing_field_as: Some(self.missing_field_as.to_string()),
+            compression: Some(compression),
+        })
+    }
+}
+
+impl FromToProto for mt::principal::AvroFileFormatParams {
+    type PB = pb::AvroFileFormatParams;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: pb::AvroFileFormatParams) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        let compression = compression_from_pb(p.compression)?;
+        mt::principal::AvroFileFormatParams::try_create(
+            compression,
+            p.missing_field_as.as_deref(),
+            p.null_if,
+        )
+        .map_err(|e| Incompatible::new(format!("{e}")))
+    }
+
+    fn to_pb(&self) -> Result<pb::AvroFileFormatParams, Incompatible> {
+        let compression =
+            mt::principal::StageFileCompression::to_pb_enum(&self.compression)? as i32;
+        Ok(pb::AvroFileFormatParams {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            missing_field_as: Some(self.missing_field_as.to_string()),
+            null_if: self.null_if.clone(),
+            compression: Some(compression),
         })
     }
 }
@@ -325,16 +374,39 @@ impl FromToProto for mt::principal::ParquetFileFormatParams {
     fn from_pb(p: pb::ParquetFileFormatParams) -> Result<Self, Incompatible>
     where Self: Sized {
         reader_check_msg(p.ver, p.min_reader_ver)?;
-        mt::principal::ParquetFileFormatParams::try_create(p.missing_field_as.as_deref(), p.null_if)
-            .map_err(|e| Incompatible::new(format!("{e}")))
+        let mut params = mt::principal::ParquetFileFormatParams::try_create(
+            p.missing_field_as.as_deref(),
+            p.null_if,
+        )
+        .map_err(|e| Incompatible::new(format!("{e}")))?;
+        if let Some(compression) = p.compression {
+            params.compression = compression_from_pb(Some(compression))?;
+        }
+        params.compression_level = p.compression_level;
+        params.row_group_size = p.row_group_size.unwrap_or_default();
+        params.enable_page_index = p.enable_page_index.unwrap_or_default();
+        params.bloom_filter_columns = p.bloom_filter_columns;
+        params.sorting_columns = p.sorting_columns;
+        params
+            .check_write_options()
+            .map_err(|e| Incompatible::new(format!("{e}")))?;
+        Ok(params)
     }
 
     fn to_pb(&self) -> Result<pb::ParquetFileFormatParams, Incompatible> {
+        let compression =
+            mt::principal::StageFileCompression::to_pb_enum(&self.compression)? as i32;
         Ok(pb::ParquetFileFormatParams {
             ver: VER,
             min_reader_ver: MIN_READER_VER,
             missing_field_as: Some(self.missing_field_as.to_string()),
             null_if: self.null_if.clone(),
+            compression: Some(compression),
+            compression_level: self.compression_level,
+            row_group_size: Some(self.row_group_size),
+            enable_page_index: Some(self.enable_page_index),
+            bloom_filter_columns: self.bloom_filter_columns.clone(),
+            sorting_columns: self.sorting_columns.clone(),
         })
     }
 }
@@ -557,3 +629,18 @@ impl FromToProto for mt::principal::TsvFileFormatParams {
         })
     }
 }
+
+/// Compression of ORC and AVRO params is optional, it is absent in the messages
+/// written before it is introduced, which means no compression.
+/// For PARQUET params, absence means the default compression `Auto` instead.
+fn compression_from_pb(
+    compression: Option<i32>,
+) -> Result<mt::principal::StageFileCompression, Incompatible> {
+    match compression {
+        None => Ok(mt::principal::StageFileCompression::None),
+        Some(c) => mt::principal::StageFileCompression::from_pb_enum(
+            FromPrimitive::from_i32(c)
+                .ok_or_else(|| Incompatible::new(format!("invalid StageFileCompression: {}", c)))?,
+        ),
+    }
+}
@@ -15,6 +15,8 @@
 //! This mod is the key point about compatibility.
 //! Everytime update anything in this file, update the `VER` and let the tests pass.
 
+use std::collections::BTreeMap;
+
 use databend_common_expression as ex;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::TableDataType;
@@ -42,7 +44,11 @@ impl FromToProto for ex::TableSchema {
             fs.push(ex::TableField::from_pb(f)?);
         }
 
-        let v = Self::new_from_column_ids(fs, p.metadata, p.next_column_id);
+        let mut v = Self::new_from_column_ids(fs, p.metadata, p.next_column_id);
+        for (column_id, f) in p.widened_columns {
+            v.widened_columns
+                .insert(column_id, ex::TableField::from_pb(f)?);
+        }
         Ok(v)
     }
 
@@ -52,12 +58,18 @@ impl FromToProto for ex::TableSchema {
             fs.push(f.to_pb()?);
         }
 
+        let mut widened_columns = BTreeMap::new();
+        for (column_id, f) in &self.widened_columns {
+            widened_columns.insert(*column_id, f.to_pb()?);
+        }
+
         let p = pb::DataSchema {
             ver: VER,
             min_reader_ver: MIN_READER_VER,
             fields: fs,
             metadata: self.meta().clone(),
             next_column_id: self.next_column_id(),
+            widened_columns,
         };
         Ok(p)
     }
@@ -264,6 +276,9 @@ impl FromToProto for ex::TableDataType {
                         ex::TableDataType::Decimal(ex::types::decimal::DecimalDataType::from_pb(x)?)
                     }
                     Dt24::EmptyMapT(_) => ex::TableDataType::EmptyMap,
+                    Dt24::VectorT(x) => {
+                        ex::TableDataType::Vector(ex::types::VectorDataType::from_pb(x)?)
+                    }
                 };
                 Ok(x)
             }
@@ -327,6 +342,10 @@ impl FromToProto for ex::TableDataType {
             TableDataType::Variant => new_pb_dt24(Dt24::VariantT(pb::Empty {})),
             TableDataType::Geometry => new_pb_dt24(Dt24::GeometryT(pb::Empty {})),
             TableDataType::Geography => new_pb_dt24(Dt24::GeographyT(pb::Empty {})),
+            TableDataType::Vector(v) => {
+                let x = v.to_pb()?;
+                new_pb_dt24(Dt24::VectorT(x))
+            }
         };
         Ok(x)
     }
@@ -452,6 +471,44 @@ impl FromToProto for ex::types::decimal::DecimalSize {
     }
 }
 
+impl FromToProto for ex::types::VectorDataType {
+    type PB = pb::Vector;
+
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: pb::Vector) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let element_type = p.element_type.ok_or_else(|| {
+            Incompatible::new("Invalid Vector: .element_type can not be None".to_string())
+        })?;
+
+        let x = match NumberDataType::from_pb(element_type)? {
+            NumberDataType::Int8 => ex::types::VectorDataType::Int8(p.dimension),
+            NumberDataType::Float32 => ex::types::VectorDataType::Float32(p.dimension),
+            ty => {
+                return Err(Incompatible::new(format!(
+                    "Invalid Vector: unsupported element type {}",
+                    ty
+                )));
+            }
+        };
+        Ok(x)
+    }
+
+    fn to_pb(&self) -> Result<pb::Vector, Incompatible> {
+        Ok(pb::Vector {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+
+            element_type: Some(self.inner_data_type().to_pb()?),
+            dimension: self.dimension(),
+        })
+    }
+}
+
 /// Create a pb::DataType with version-24 data type schema
 fn new_pb_dt24(dt24: Dt24) -> pb::DataType {
     pb::DataType {
@@ -27,9 +27,11 @@ use databend_common_meta_app::storage::StorageParams;
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_meta_types::NonEmptyString;
 use databend_common_protos::pb;
+use num::FromPrimitive;
 
 use crate::reader_check_msg;
 use crate::FromToProto;
+use crate::FromToProtoEnum;
 use crate::Incompatible;
 use crate::MIN_READER_VER;
 use crate::VER;
@@ -344,6 +346,11 @@ impl FromToProto for mt::TableIndex {
         reader_check_msg(p.ver, p.min_reader_ver)?;
 
         let v = Self {
+            index_type: mt::TableIndexType::from_pb_enum(
+                FromPrimitive::from_i32(p.index_type).ok_or_else(|| {
+                    Incompatible::new(format!("invalid TableIndexType: {}", p.index_type))
+                })?,
+            )?,
             name: p.name,
             column_ids: p.column_ids,
             sync_creation: p.sync_creation,
@@ -362,7 +369,26 @@ impl FromToProto for mt::TableIndex {
             sync_creation: self.sync_creation,
             version: self.version.clone(),
             options: self.options.clone(),
+            index_type: self.index_type.to_pb_enum()? as i32,
         };
         Ok(p)
     }
 }
+
+impl FromToProtoEnum for mt::TableIndexType {
+    type PBEnum = pb::table_index::TableIndexType;
+    fn from_pb_enum(p: pb::table_index::TableIndexType) -> Result<Self, Incompatible>
+    where Self: Sized {
+        match p {
+            pb::table_index::TableIndexType::Inverted => Ok(mt::TableIndexType::Inverted),
+            pb::table_index::TableIndexType::Vector => Ok(mt::TableIndexType::Vector),
+        }
+    }
+
+    fn to_pb_enum(&self) -> Result<pb::table_index::TableIndexType, Incompatible> {
+        match *self {
+            mt::TableIndexType::Inverted => Ok(pb::table_index::TableIndexType::Inverted),
+            mt::TableIndexType::Vector => Ok(pb::table_index::TableIndexType::Vector),
+        }
+    }
+}
@@ -147,7 +147,15 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (115, "2024-12-16: Add: udf.proto: add UDAFScript and UDAFServer"),
     (116, "2025-01-09: Add: MarkedDeletedIndexMeta"),
     (117, "2025-01-21: Add: config.proto: add disable_list_batch in WebhdfsConfig"),
-    (118, "2025-01-22: Add: config.proto: add user_name in WebhdfsConfig")
+    (118, "2025-01-22: Add: config.proto: add user_name in WebhdfsConfig"),
+    (119, "2025-01-23: Add: metadata.proto/DataSchema::widened_columns"),
+    (120, "2025-01-24: Add: catalog.proto: add IcebergFsCatalogOption"),
+    (121, "2025-01-25: Add: file_format.proto: add AvroFileFormatParams"),
+    (122, "2025-01-26: Add: file_format.proto: OrcFileFormatParams and AvroFileFormatParams add compression"),
+    (123, "2025-01-27: Add: file_format.proto: StageFileCompression add Zip and Tar"),
+    (124, "2025-01-28: Add: file_format.proto: ParquetFileFormatParams add options for unloading"),
+    (125, "2025-01-29: Add: table.proto: TableIndex add index_type"),
+    (126, "2025-01-30: Add: datatype.proto: add Vector data type"),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
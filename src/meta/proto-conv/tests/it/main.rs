@@ -116,3 +116,11 @@ mod v115_add_udaf_script;
 mod v116_marked_deleted_index_meta;
 mod v117_webhdfs_add_disable_list_batch;
 mod v118_webhdfs_add_user_name;
+mod v119_widened_columns;
+mod v120_iceberg_fs_catalog_option;
+mod v121_avro_format_params;
+mod v122_orc_avro_compression;
+mod v123_archive_compression;
+mod v124_parquet_unload_options;
+mod v125_table_index_type;
+mod v126_vector_datatype;
@@ -57,6 +57,7 @@ fn test_decode_v66_stage() -> anyhow::Result<()> {
             mt::principal::ParquetFileFormatParams {
                 missing_field_as: Default::default(),
                 null_if: vec![],
+                ..Default::default()
             },
         ),
         copy_options: mt::principal::CopyOptions {
@@ -90,6 +90,7 @@ fn test_decode_v85_table_meta() -> anyhow::Result<()> {
         shared_by: btreeset! {1},
         column_mask_policy: Some(btreemap! {s("a") => s("b")}),
         indexes: btreemap! {s("idx1") => mt::TableIndex {
+            index_type: mt::TableIndexType::Inverted,
             name: "idx1".to_string(),
             column_ids: vec![1, 2],
             sync_creation: true,
@@ -14,6 +14,7 @@
 
 use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::OrcFileFormatParams;
+use databend_common_meta_app::principal::StageFileCompression;
 use fastrace::func_name;
 
 use crate::common;
@@ -32,6 +33,7 @@ use crate::common;
 fn test_decode_v92_orc_file_format_params() -> anyhow::Result<()> {
     let orc_file_format_params_v92 = vec![160, 6, 92, 168, 6, 24];
     let want = || OrcFileFormatParams {
+        compression: StageFileCompression::None,
         missing_field_as: Default::default(),
     };
     common::test_load_old(
@@ -49,6 +51,7 @@ fn test_decode_v92_file_format_params() -> anyhow::Result<()> {
     let file_format_params_v92 = vec![58, 6, 160, 6, 92, 168, 6, 24];
     let want = || {
         FileFormatParams::Orc(OrcFileFormatParams {
+            compression: StageFileCompression::None,
             missing_field_as: Default::default(),
         })
     };
@@ -33,6 +33,7 @@ fn test_decode_v93_parquet_file_format_params() -> anyhow::Result<()> {
     let want = || ParquetFileFormatParams {
         missing_field_as: Default::default(),
         null_if: vec!["".to_string(), "a".to_string()],
+        ..Default::default()
     };
     common::test_load_old(
         func_name!(),
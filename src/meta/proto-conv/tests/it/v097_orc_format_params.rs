@@ -14,6 +14,7 @@
 
 use databend_common_meta_app::principal::NullAs;
 use databend_common_meta_app::principal::OrcFileFormatParams;
+use databend_common_meta_app::principal::StageFileCompression;
 use fastrace::func_name;
 
 use crate::common;
@@ -35,6 +36,7 @@ fn test_decode_v97_orc_file_format_params() -> anyhow::Result<()> {
     ];
 
     let want = || OrcFileFormatParams {
+        compression: StageFileCompression::None,
         missing_field_as: NullAs::FieldDefault,
     };
     common::test_load_old(
@@ -0,0 +1,58 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_meta_app::schema::CatalogOption;
+use databend_common_meta_app::schema::IcebergCatalogOption;
+use databend_common_meta_app::schema::IcebergFsCatalogOption;
+use databend_common_meta_app::storage::StorageFsConfig;
+use databend_common_meta_app::storage::StorageParams;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_v120_iceberg_fs_catalog_option() -> anyhow::Result<()> {
+    let catalog_meta_v120 = vec![
+        18, 42, 26, 40, 42, 32, 10, 24, 18, 22, 10, 14, 47, 116, 109, 112, 47, 119, 97, 114, 101,
+        104, 111, 117, 115, 101, 160, 6, 120, 168, 6, 24, 160, 6, 120, 168, 6, 24, 160, 6, 120,
+        168, 6, 24, 162, 1, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58,
+        48, 57, 32, 85, 84, 67, 160, 6, 120, 168, 6, 24,
+    ];
+
+    let want = || databend_common_meta_app::schema::CatalogMeta {
+        catalog_option: CatalogOption::Iceberg(IcebergCatalogOption::Fs(IcebergFsCatalogOption {
+            storage_params: Box::new(StorageParams::Fs(StorageFsConfig {
+                root: "/tmp/warehouse".to_string(),
+            })),
+        })),
+        created_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), catalog_meta_v120.as_slice(), 120, want())?;
+
+    Ok(())
+}
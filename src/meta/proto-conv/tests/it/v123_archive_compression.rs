@@ -0,0 +1,62 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_app::principal::JsonFileFormatParams;
+use databend_common_meta_app::principal::StageFileCompression;
+use databend_common_meta_app::principal::XmlFileFormatParams;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+#[test]
+fn test_decode_v123_json_file_format_params() -> anyhow::Result<()> {
+    let json_file_format_params_v123 = vec![8, 11, 160, 6, 123, 168, 6, 24];
+    let want = || JsonFileFormatParams {
+        compression: StageFileCompression::Zip,
+    };
+    common::test_load_old(
+        func_name!(),
+        json_file_format_params_v123.as_slice(),
+        123,
+        want(),
+    )?;
+    common::test_pb_from_to(func_name!(), want())?;
+    Ok(())
+}
+
+#[test]
+fn test_decode_v123_xml_file_format_params() -> anyhow::Result<()> {
+    let xml_file_format_params_v123 = vec![8, 12, 18, 3, 114, 111, 119, 160, 6, 123, 168, 6, 24];
+    let want = || XmlFileFormatParams {
+        compression: StageFileCompression::Tar,
+        row_tag: "row".to_string(),
+    };
+    common::test_load_old(
+        func_name!(),
+        xml_file_format_params_v123.as_slice(),
+        123,
+        want(),
+    )?;
+    common::test_pb_from_to(func_name!(), want())?;
+    Ok(())
+}
@@ -0,0 +1,71 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_app::principal::FileFormatParams;
+use databend_common_meta_app::principal::NullAs;
+use databend_common_meta_app::principal::ParquetFileFormatParams;
+use databend_common_meta_app::principal::StageFileCompression;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+#[test]
+fn test_decode_v124_parquet_file_format_params() -> anyhow::Result<()> {
+    let parquet_file_format_params_v124 = vec![
+        10, 13, 70, 73, 69, 76, 68, 95, 68, 69, 70, 65, 85, 76, 84, 34, 4, 78, 85, 76, 76, 40, 1,
+        48, 6, 56, 232, 7, 64, 1, 74, 1, 97, 74, 1, 99, 82, 6, 98, 32, 68, 69, 83, 67, 160, 6, 124,
+        168, 6, 24,
+    ];
+    let want = || ParquetFileFormatParams {
+        missing_field_as: NullAs::FieldDefault,
+        null_if: vec!["NULL".to_string()],
+        compression: StageFileCompression::Gzip,
+        compression_level: Some(6),
+        row_group_size: 1000,
+        enable_page_index: true,
+        bloom_filter_columns: vec!["a".to_string(), "c".to_string()],
+        sorting_columns: vec!["b DESC".to_string()],
+    };
+    common::test_load_old(
+        func_name!(),
+        parquet_file_format_params_v124.as_slice(),
+        124,
+        want(),
+    )?;
+    common::test_pb_from_to(func_name!(), want())?;
+    Ok(())
+}
+
+#[test]
+fn test_decode_v124_file_format_params() -> anyhow::Result<()> {
+    let file_format_params_v124 = vec![10, 8, 40, 8, 160, 6, 124, 168, 6, 24];
+    let want = || {
+        FileFormatParams::Parquet(ParquetFileFormatParams {
+            compression: StageFileCompression::Snappy,
+            ..Default::default()
+        })
+    };
+    common::test_load_old(func_name!(), file_format_params_v124.as_slice(), 0, want())?;
+    common::test_pb_from_to(func_name!(), want())?;
+    Ok(())
+}
@@ -0,0 +1,61 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::VectorDataType;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v126_schema() -> anyhow::Result<()> {
+    let table_schema_v126 = vec![
+        10, 28, 10, 1, 97, 26, 17, 154, 2, 8, 34, 0, 160, 6, 126, 168, 6, 24, 160, 6, 126, 168, 6,
+        24, 160, 6, 126, 168, 6, 24, 10, 41, 10, 1, 118, 26, 28, 146, 3, 19, 10, 8, 74, 0, 160, 6,
+        126, 168, 6, 24, 16, 128, 1, 160, 6, 126, 168, 6, 24, 160, 6, 126, 168, 6, 24, 32, 1, 160,
+        6, 126, 168, 6, 24, 10, 40, 10, 1, 113, 26, 27, 146, 3, 18, 10, 8, 42, 0, 160, 6, 126, 168,
+        6, 24, 16, 4, 160, 6, 126, 168, 6, 24, 160, 6, 126, 168, 6, 24, 32, 2, 160, 6, 126, 168, 6,
+        24, 10, 50, 10, 2, 110, 118, 26, 36, 178, 2, 27, 146, 3, 18, 10, 8, 74, 0, 160, 6, 126,
+        168, 6, 24, 16, 3, 160, 6, 126, 168, 6, 24, 160, 6, 126, 168, 6, 24, 160, 6, 126, 168, 6,
+        24, 32, 3, 160, 6, 126, 168, 6, 24, 24, 4, 160, 6, 126, 168, 6, 24,
+    ];
+
+    let fields = vec![
+        TableField::new("a", TableDataType::Number(NumberDataType::UInt64)),
+        TableField::new("v", TableDataType::Vector(VectorDataType::Float32(128))),
+        TableField::new("q", TableDataType::Vector(VectorDataType::Int8(4))),
+        TableField::new(
+            "nv",
+            TableDataType::Nullable(Box::new(TableDataType::Vector(VectorDataType::Float32(3)))),
+        ),
+    ];
+
+    let want = || TableSchema::new(fields.clone());
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), table_schema_v126.as_slice(), 126, want())?;
+    Ok(())
+}
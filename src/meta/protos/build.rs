@@ -96,5 +96,6 @@ fn build_proto() -> Result<()> {
             "#[derive(num_derive::FromPrimitive)]",
         )
         .type_attribute("StageType", "#[derive(num_derive::FromPrimitive)]")
+        .type_attribute("TableIndexType", "#[derive(num_derive::FromPrimitive)]")
         .compile_protos_with_config(config, &proto_defs, &[proto_path])
 }
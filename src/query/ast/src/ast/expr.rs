@@ -1033,6 +1033,10 @@ pub enum TypeName {
     Geometry,
     Geography,
     Interval,
+    Vector {
+        element_type: Box<TypeName>,
+        dimension: u64,
+    },
     Nullable(Box<TypeName>),
     NotNull(Box<TypeName>),
 }
@@ -1164,6 +1168,16 @@ impl Display for TypeName {
             TypeName::Interval => {
                 write!(f, "INTERVAL")?;
             }
+            TypeName::Vector {
+                element_type,
+                dimension,
+            } => {
+                if matches!(element_type.as_ref(), TypeName::Float32) {
+                    write!(f, "VECTOR({})", dimension)?;
+                } else {
+                    write!(f, "VECTOR({}, {})", element_type, dimension)?;
+                }
+            }
         }
         Ok(())
     }
@@ -1736,6 +1750,9 @@ impl ExprReplacer {
             TimeTravelPoint::Offset(offset) => {
                 self.replace_expr(offset);
             }
+            TimeTravelPoint::Version(version) => {
+                self.replace_expr(version);
+            }
             _ => (),
         }
     }
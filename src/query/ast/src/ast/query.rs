@@ -550,6 +550,7 @@ pub enum TimeTravelPoint {
     Snapshot(String),
     Timestamp(Box<Expr>),
     Offset(Box<Expr>),
+    Version(Box<Expr>),
     Stream {
         catalog: Option<Identifier>,
         database: Option<Identifier>,
@@ -569,6 +570,9 @@ impl Display for TimeTravelPoint {
             TimeTravelPoint::Offset(num) => {
                 write!(f, "(OFFSET => {num})")?;
             }
+            TimeTravelPoint::Version(version) => {
+                write!(f, "(VERSION => {version})")?;
+            }
             TimeTravelPoint::Stream {
                 catalog,
                 database,
@@ -26,9 +26,11 @@ use percent_encoding::percent_decode_str;
 use url::Url;
 
 use crate::ast::quote::QuotedString;
+use crate::ast::write_comma_separated_list;
 use crate::ast::write_comma_separated_map;
 use crate::ast::write_comma_separated_string_list;
 use crate::ast::write_comma_separated_string_map;
+use crate::ast::Expr;
 use crate::ast::Hint;
 use crate::ast::Identifier;
 use crate::ast::Query;
@@ -60,6 +62,8 @@ pub struct CopyIntoTableStmt {
     pub pattern: Option<LiteralStringOrVariable>,
 
     pub options: CopyIntoTableOptions,
+    /// The table to write rejected rows into, only valid with `ON_ERROR = CONTINUE`.
+    pub error_table: Option<TableRef>,
 }
 
 impl CopyIntoTableStmt {
@@ -82,6 +86,10 @@ impl CopyIntoTableStmt {
             CopyIntoTableOption::ColumnMatchMode(v) => {
                 self.options.column_match_mode = Some(ColumnMatchMode::from_str(&v)?)
             }
+            CopyIntoTableOption::ValidationMode(v) => {
+                self.options.validation_mode = ValidationMode::from_str(&v)?
+            }
+            CopyIntoTableOption::ErrorTable(v) => self.error_table = Some(v),
         }
         Ok(())
     }
@@ -116,6 +124,9 @@ impl Display for CopyIntoTableStmt {
             write!(f, " FILE_FORMAT = ({})", self.file_format)?;
         }
         write!(f, " {}", self.options)?;
+        if let Some(error_table) = &self.error_table {
+            write!(f, " ERROR_TABLE = {}", error_table)?;
+        }
         Ok(())
     }
 }
@@ -132,8 +143,10 @@ pub struct CopyIntoTableOptions {
     pub purge: bool,
     pub disable_variant_check: bool,
     pub return_failed_only: bool,
-    pub validation_mode: String,
+    pub validation_mode: ValidationMode,
     pub column_match_mode: Option<ColumnMatchMode>,
+    /// Keep the rejected rows in the copy status, set by the binder if there is an `ERROR_TABLE`.
+    pub keep_rejected_rows: bool,
 }
 
 impl CopyIntoTableOptions {
@@ -197,7 +210,7 @@ impl CopyIntoTableOptions {
 
 impl Display for CopyIntoTableOptions {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        if !self.validation_mode.is_empty() {
+        if self.validation_mode != ValidationMode::None {
             write!(f, "VALIDATION_MODE = {}", self.validation_mode)?;
         }
 
@@ -233,6 +246,9 @@ pub struct CopyIntoLocationOptions {
     pub use_raw_path: bool,
     pub include_query_id: bool,
     pub overwrite: bool,
+    /// Names of the `key=value` directories, resolved from `PARTITION BY` by the binder.
+    /// The partition values are the trailing columns of the data to unload.
+    pub partition_keys: Vec<String>,
 }
 
 impl Default for CopyIntoLocationOptions {
@@ -244,6 +260,7 @@ impl Default for CopyIntoLocationOptions {
             use_raw_path: false,
             include_query_id: true,
             overwrite: false,
+            partition_keys: vec![],
         }
     }
 }
@@ -255,10 +272,28 @@ pub struct CopyIntoLocationStmt {
     pub hints: Option<Hint>,
     pub src: CopyIntoLocationSource,
     pub dst: FileLocation,
+    pub partition_by: Vec<CopyIntoLocationPartition>,
     pub file_format: FileFormatOptions,
     pub options: CopyIntoLocationOptions,
 }
 
+/// An expression of `PARTITION BY`, the alias is the directory key of the partition.
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct CopyIntoLocationPartition {
+    pub expr: Box<Expr>,
+    pub alias: Option<Identifier>,
+}
+
+impl Display for CopyIntoLocationPartition {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.expr)?;
+        if let Some(alias) = &self.alias {
+            write!(f, " AS {alias}")?;
+        }
+        Ok(())
+    }
+}
+
 impl Display for CopyIntoLocationStmt {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         if let Some(cte) = &self.with {
@@ -270,6 +305,11 @@ impl Display for CopyIntoLocationStmt {
         }
         write!(f, " INTO {}", self.dst)?;
         write!(f, " FROM {}", self.src)?;
+        if !self.partition_by.is_empty() {
+            write!(f, " PARTITION BY (")?;
+            write_comma_separated_list(f, &self.partition_by)?;
+            write!(f, ")")?;
+        }
 
         if !self.file_format.is_empty() {
             write!(f, " FILE_FORMAT = ({})", self.file_format)?;
@@ -575,6 +615,8 @@ pub enum CopyIntoTableOption {
     ReturnFailedOnly(bool),
     OnError(String),
     ColumnMatchMode(String),
+    ValidationMode(String),
+    ErrorTable(TableRef),
 }
 
 pub enum CopyIntoLocationOption {
@@ -718,6 +760,56 @@ impl FromStr for OnErrorMode {
     }
 }
 
+/// Validate the files to copy without loading them.
+#[derive(
+    serde::Serialize, serde::Deserialize, Debug, Clone, Default, PartialEq, Drive, DriveMut, Eq,
+)]
+pub enum ValidationMode {
+    #[default]
+    None,
+    /// Return the first n rows decoded as the table, abort on error.
+    ReturnNRows(u64),
+    /// Return all the errors in the files to copy.
+    ReturnErrors,
+    /// Return all the errors, including the files already copied.
+    ReturnAllErrors,
+}
+
+impl Display for ValidationMode {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ValidationMode::None => Ok(()),
+            ValidationMode::ReturnNRows(n) => write!(f, "RETURN_{n}_ROWS"),
+            ValidationMode::ReturnErrors => write!(f, "RETURN_ERRORS"),
+            ValidationMode::ReturnAllErrors => write!(f, "RETURN_ALL_ERRORS"),
+        }
+    }
+}
+
+const VALIDATION_MODE_MSG: &str =
+    "ValidationMode must be one of { RETURN_<num>_ROWS | RETURN_ERRORS | RETURN_ALL_ERRORS }";
+impl FromStr for ValidationMode {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> std::result::Result<Self, &'static str> {
+        match s.to_uppercase().as_str() {
+            "" => Ok(ValidationMode::None),
+            "RETURN_ERRORS" => Ok(ValidationMode::ReturnErrors),
+            "RETURN_ALL_ERRORS" => Ok(ValidationMode::ReturnAllErrors),
+            v => {
+                let num_str = v
+                    .strip_prefix("RETURN_")
+                    .and_then(|v| v.strip_suffix("_ROWS"))
+                    .ok_or(VALIDATION_MODE_MSG)?;
+                match num_str.parse::<u64>() {
+                    Ok(n) if n >= 1 => Ok(ValidationMode::ReturnNRows(n)),
+                    _ => Err(VALIDATION_MODE_MSG),
+                }
+            }
+        }
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Drive, DriveMut, Eq)]
 pub enum ColumnMatchMode {
     CaseSensitive,
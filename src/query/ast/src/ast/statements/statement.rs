@@ -204,9 +204,9 @@ pub enum Statement {
     CreateIndex(CreateIndexStmt),
     DropIndex(DropIndexStmt),
     RefreshIndex(RefreshIndexStmt),
-    CreateInvertedIndex(CreateInvertedIndexStmt),
-    DropInvertedIndex(DropInvertedIndexStmt),
-    RefreshInvertedIndex(RefreshInvertedIndexStmt),
+    CreateTableIndex(CreateTableIndexStmt),
+    DropTableIndex(DropTableIndexStmt),
+    RefreshTableIndex(RefreshTableIndexStmt),
 
     // VirtualColumns
     CreateVirtualColumn(CreateVirtualColumnStmt),
@@ -454,7 +454,7 @@ impl Statement {
             | Statement::ShowStreams(..)
             | Statement::DescribeStream(..)
             | Statement::RefreshIndex(..)
-            | Statement::RefreshInvertedIndex(..)
+            | Statement::RefreshTableIndex(..)
             | Statement::RefreshVirtualColumn(..)
             | Statement::ShowVirtualColumns(..)
             | Statement::ShowUsers
@@ -520,8 +520,8 @@ impl Statement {
             | Statement::RenameDictionary(..)
             | Statement::CreateStream(..)
             | Statement::DropStream(..)
-            | Statement::CreateInvertedIndex(..)
-            | Statement::DropInvertedIndex(..)
+            | Statement::CreateTableIndex(..)
+            | Statement::DropTableIndex(..)
             | Statement::CreateVirtualColumn(..)
             | Statement::AlterVirtualColumn(..)
             | Statement::DropVirtualColumn(..)
@@ -797,9 +797,9 @@ impl Display for Statement {
             Statement::CreateIndex(stmt) => write!(f, "{stmt}")?,
             Statement::DropIndex(stmt) => write!(f, "{stmt}")?,
             Statement::RefreshIndex(stmt) => write!(f, "{stmt}")?,
-            Statement::CreateInvertedIndex(stmt) => write!(f, "{stmt}")?,
-            Statement::DropInvertedIndex(stmt) => write!(f, "{stmt}")?,
-            Statement::RefreshInvertedIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateTableIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::DropTableIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::RefreshTableIndex(stmt) => write!(f, "{stmt}")?,
             Statement::CreateVirtualColumn(stmt) => write!(f, "{stmt}")?,
             Statement::AlterVirtualColumn(stmt) => write!(f, "{stmt}")?,
             Statement::DropVirtualColumn(stmt) => write!(f, "{stmt}")?,
@@ -29,6 +29,7 @@ use crate::ast::CreateOption;
 use crate::ast::Expr;
 use crate::ast::Identifier;
 use crate::ast::Query;
+use crate::ast::TableIndexType;
 use crate::ast::TableReference;
 use crate::ast::TimeTravelPoint;
 use crate::ast::TypeName;
@@ -271,23 +272,25 @@ impl Display for AttachTableStmt {
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub enum CreateTableSource {
-    Columns(Vec<ColumnDefinition>, Option<Vec<InvertedIndexDefinition>>),
+    Columns(Vec<ColumnDefinition>, Option<Vec<TableIndexDefinition>>),
     Like {
         catalog: Option<Identifier>,
         database: Option<Identifier>,
         table: Identifier,
     },
+    /// The columns are the rows of the query, which has the same columns as `infer_schema`.
+    Template(Box<Query>),
 }
 
 impl Display for CreateTableSource {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match self {
-            CreateTableSource::Columns(columns, inverted_indexes) => {
+            CreateTableSource::Columns(columns, table_indexes) => {
                 write!(f, "(")?;
                 write_comma_separated_list(f, columns)?;
-                if let Some(inverted_indexes) = inverted_indexes {
+                if let Some(table_indexes) = table_indexes {
                     write!(f, ", ")?;
-                    write_comma_separated_list(f, inverted_indexes)?;
+                    write_comma_separated_list(f, table_indexes)?;
                 }
                 write!(f, ")")
             }
@@ -299,6 +302,7 @@ impl Display for CreateTableSource {
                 write!(f, "LIKE ")?;
                 write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))
             }
+            CreateTableSource::Template(query) => write!(f, "USING TEMPLATE ({query})"),
         }
     }
 }
@@ -684,6 +688,9 @@ pub struct AnalyzeTableStmt {
     pub catalog: Option<Identifier>,
     pub database: Option<Identifier>,
     pub table: Identifier,
+    /// Columns to collect the multi-column statistics for,
+    /// `COMPUTE STATISTICS FOR COLUMNS (a, b)`.
+    pub columns: Vec<Identifier>,
 }
 
 impl Display for AnalyzeTableStmt {
@@ -696,6 +703,11 @@ impl Display for AnalyzeTableStmt {
                 .chain(&self.database)
                 .chain(Some(&self.table)),
         )?;
+        if !self.columns.is_empty() {
+            write!(f, " COMPUTE STATISTICS FOR COLUMNS (")?;
+            write_comma_separated_list(f, &self.columns)?;
+            write!(f, ")")?;
+        }
 
         Ok(())
     }
@@ -892,19 +904,20 @@ impl Display for ColumnDefinition {
 }
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
-pub struct InvertedIndexDefinition {
+pub struct TableIndexDefinition {
+    pub index_type: TableIndexType,
     pub index_name: Identifier,
     pub columns: Vec<Identifier>,
     pub sync_creation: bool,
     pub index_options: BTreeMap<String, String>,
 }
 
-impl Display for InvertedIndexDefinition {
+impl Display for TableIndexDefinition {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         if !self.sync_creation {
             write!(f, "ASYNC ")?;
         }
-        write!(f, "INVERTED INDEX")?;
+        write!(f, "{} INDEX", self.index_type)?;
         write!(f, " {}", self.index_name)?;
         write!(f, " (")?;
         write_comma_separated_list(f, &self.columns)?;
@@ -922,7 +935,7 @@ impl Display for InvertedIndexDefinition {
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub enum CreateDefinition {
     Column(ColumnDefinition),
-    InvertedIndex(InvertedIndexDefinition),
+    TableIndex(TableIndexDefinition),
 }
 
 impl Display for CreateDefinition {
@@ -931,8 +944,8 @@ impl Display for CreateDefinition {
             CreateDefinition::Column(column_def) => {
                 write!(f, "{}", column_def)?;
             }
-            CreateDefinition::InvertedIndex(inverted_index_def) => {
-                write!(f, "{}", inverted_index_def)?;
+            CreateDefinition::TableIndex(table_index_def) => {
+                write!(f, "{}", table_index_def)?;
             }
         }
         Ok(())
@@ -18,6 +18,7 @@ use nom_rule::rule;
 
 use super::query::with;
 use crate::ast::CopyIntoLocationOption;
+use crate::ast::CopyIntoLocationPartition;
 use crate::ast::CopyIntoLocationSource;
 use crate::ast::CopyIntoLocationStmt;
 use crate::ast::CopyIntoTableOption;
@@ -32,6 +33,7 @@ use crate::parser::common::ident;
 use crate::parser::common::table_ref;
 use crate::parser::common::IResult;
 use crate::parser::common::*;
+use crate::parser::expr::expr;
 use crate::parser::expr::literal_bool;
 use crate::parser::expr::literal_string;
 use crate::parser::expr::literal_u64;
@@ -72,6 +74,7 @@ pub fn copy_into_table(i: Input) -> IResult<Statement> {
                 file_format: Default::default(),
 
                 options: Default::default(),
+                error_table: None,
             };
             for opt in opts {
                 copy_stmt
@@ -97,14 +100,18 @@ fn copy_into_location(i: Input) -> IResult<Statement> {
             ~ #hint?
             ~ INTO ~ #file_location
             ~ ^FROM ~ ^#copy_into_location_source
+            ~ ( PARTITION ~ ^BY ~ ^"(" ~ ^#comma_separated_list1(copy_into_location_partition) ~ ^")" )?
             ~ #copy_into_location_option*
         },
-        |(with, _copy, opt_hints, _into, dst, _from, src, opts)| {
+        |(with, _copy, opt_hints, _into, dst, _from, src, opt_partition_by, opts)| {
             let mut copy_stmt = CopyIntoLocationStmt {
                 with,
                 hints: opt_hints,
                 src,
                 dst,
+                partition_by: opt_partition_by
+                    .map(|(_, _, _, partition_by, _)| partition_by)
+                    .unwrap_or_default(),
                 file_format: Default::default(),
                 options: Default::default(),
             };
@@ -120,6 +127,7 @@ pub fn copy_into(i: Input) -> IResult<Statement> {
          #copy_into_location:"`COPY
                 INTO { internalStage | externalStage | externalLocation }
                 FROM { [<database_name>.]<table_name> | ( <query> ) }
+                [ PARTITION BY ( <expr> [ AS <key> ] [ , ... ] ) ]
                 [ FILE_FORMAT = ( { TYPE = { CSV | JSON | PARQUET | TSV } [ formatTypeOptions ] } ) ]
                 [ copyOptions ]`"
          | #copy_into_table: "`COPY
@@ -128,8 +136,9 @@ pub fn copy_into(i: Input) -> IResult<Statement> {
                 [ FILE_FORMAT = ( { TYPE = { CSV | JSON | PARQUET | TSV } [ formatTypeOptions ] } ) ]
                 [ FILES = ( '<file_name>' [ , '<file_name>' ] [ , ... ] ) ]
                 [ PATTERN = '<regex_pattern>' ]
-                [ VALIDATION_MODE = RETURN_ROWS ]
-                [ copyOptions ]`"
+                [ VALIDATION_MODE = { RETURN_<num>_ROWS | RETURN_ERRORS | RETURN_ALL_ERRORS } ]
+                [ copyOptions ]
+                [ ERROR_TABLE = [<database_name>.]<table_name> ]`"
     )(i)
 }
 
@@ -178,6 +187,9 @@ fn copy_into_table_option(i: Input) -> IResult<CopyIntoTableOption> {
             rule! { COLUMN_MATCH_MODE ~ "=" ~ #ident },
             |(_, _, mode)| CopyIntoTableOption::ColumnMatchMode(mode.to_string()),
         ),
+        map(rule! { VALIDATION_MODE ~ "=" ~ #ident }, |(_, _, mode)| {
+            CopyIntoTableOption::ValidationMode(mode.to_string())
+        }),
         map(
             rule! { DISABLE_VARIANT_CHECK ~ "=" ~ #literal_bool },
             |(_, _, disable_variant_check)| {
@@ -188,9 +200,22 @@ fn copy_into_table_option(i: Input) -> IResult<CopyIntoTableOption> {
             rule! { RETURN_FAILED_ONLY ~ "=" ~ #literal_bool },
             |(_, _, return_failed_only)| CopyIntoTableOption::ReturnFailedOnly(return_failed_only),
         ),
+        map(
+            rule! { ERROR_TABLE ~ ^"=" ~ ^#table_ref },
+            |(_, _, error_table)| CopyIntoTableOption::ErrorTable(error_table),
+        ),
     ))(i)
 }
 
+fn copy_into_location_partition(i: Input) -> IResult<CopyIntoLocationPartition> {
+    map(rule! { #expr ~ ( AS ~ ^#ident )? }, |(expr, opt_alias)| {
+        CopyIntoLocationPartition {
+            expr: Box::new(expr),
+            alias: opt_alias.map(|(_, alias)| alias),
+        }
+    })(i)
+}
+
 fn copy_into_location_option(i: Input) -> IResult<CopyIntoLocationOption> {
     alt((
         map(rule! { SINGLE ~ "=" ~ #literal_bool }, |(_, _, single)| {
@@ -1730,6 +1730,17 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
     let ty_variant = value(TypeName::Variant, rule! { VARIANT | JSON });
     let ty_geometry = value(TypeName::Geometry, rule! { GEOMETRY });
     let ty_geography = value(TypeName::Geography, rule! { GEOGRAPHY });
+    let ty_vector = map(
+        rule! { VECTOR ~ "(" ~ ( #type_name ~ "," )? ~ ^#literal_u64 ~ ^")" },
+        |(_, _, opt_element_type, dimension, _)| TypeName::Vector {
+            element_type: Box::new(
+                opt_element_type
+                    .map(|(element_type, _)| element_type)
+                    .unwrap_or(TypeName::Float32),
+            ),
+            dimension,
+        },
+    );
     map_res(
         alt((
             rule! {
@@ -1761,6 +1772,7 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
             | #ty_variant
             | #ty_geometry
             | #ty_geography
+            | #ty_vector : "VECTOR([<element_type>, ] <dimension>)"
             | #ty_nullable
             ) ~ #nullable? : "type name" },
         )),
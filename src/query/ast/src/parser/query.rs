@@ -544,9 +544,13 @@ pub fn at_snapshot_or_ts(i: Input) -> IResult<TimeTravelPoint> {
         rule! { "(" ~ OFFSET ~ "=>" ~ #expr ~ ")" },
         |(_, _, _, e, _)| TimeTravelPoint::Offset(Box::new(e)),
     );
+    let at_version = map(
+        rule! { "(" ~ VERSION ~ "=>" ~ #expr ~ ")" },
+        |(_, _, _, e, _)| TimeTravelPoint::Version(Box::new(e)),
+    );
 
     rule!(
-        #at_snapshot | #at_timestamp | #at_offset
+        #at_snapshot | #at_timestamp | #at_offset | #at_version
     )(i)
 }
 
@@ -90,7 +90,7 @@ pub fn format_options(i: Input) -> IResult<FileFormatOptions> {
 
     let option_compression = map(
         rule! {
-            COMPRESSION ~ "=" ~ ( AUTO | NONE | GZIP | BZ2 | BROTLI | ZSTD | DEFLATE | RAWDEFLATE | XZ )
+            COMPRESSION ~ "=" ~ ( AUTO | NONE | GZIP | BZ2 | BROTLI | ZSTD | DEFLATE | RAWDEFLATE | XZ | SNAPPY )
         },
         |(_, _, v)| {
             (
@@ -130,14 +130,14 @@ pub fn format_options(i: Input) -> IResult<FileFormatOptions> {
 
     let int_options = map(
         rule! {
-            SKIP_HEADER ~ ^"=" ~ ^#literal_u64
+            (SKIP_HEADER | COMPRESSION_LEVEL | ROW_GROUP_SIZE) ~ ^"=" ~ ^#literal_u64
         },
         |(k, _, v)| (k.text().to_string(), FileFormatValue::U64(v)),
     );
 
     let bool_options = map(
         rule! {
-            (ERROR_ON_COLUMN_COUNT_MISMATCH | OUTPUT_HEADER) ~ ^"=" ~ ^#literal_bool
+            (ERROR_ON_COLUMN_COUNT_MISMATCH | OUTPUT_HEADER | ENABLE_PAGE_INDEX) ~ ^"=" ~ ^#literal_bool
         },
         |(k, _, v)| (k.text().to_string(), FileFormatValue::Bool(v)),
     );
@@ -164,6 +164,13 @@ pub fn format_options(i: Input) -> IResult<FileFormatOptions> {
         |(_, _, _, values, _)| ("null_if".to_string(), FileFormatValue::StringList(values)),
     );
 
+    let column_list_options = map(
+        rule! {
+            (BLOOM_FILTER_COLUMNS | SORTING_COLUMNS) ~ ^"=" ~ ^"(" ~ ^#comma_separated_list0(literal_string) ~ ^")"
+        },
+        |(k, _, _, values, _)| (k.text().to_string(), FileFormatValue::StringList(values)),
+    );
+
     map(
         rule! { ((
         #option_type
@@ -174,6 +181,7 @@ pub fn format_options(i: Input) -> IResult<FileFormatOptions> {
         | #bool_options
         | #none_options
         | #null_if
+        | #column_list_options
         ) ~ ","?)* },
         |opts| FileFormatOptions {
             options: opts
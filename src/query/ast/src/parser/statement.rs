@@ -1061,12 +1061,16 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
     let analyze_table = map(
         rule! {
             ANALYZE ~ TABLE ~ #dot_separated_idents_1_to_3
+            ~ ( COMPUTE ~ STATISTICS ~ FOR ~ COLUMNS ~ "(" ~ #comma_separated_list1(ident) ~ ")" )?
         },
-        |(_, _, (catalog, database, table))| {
+        |(_, _, (catalog, database, table), opt_columns)| {
             Statement::AnalyzeTable(AnalyzeTableStmt {
                 catalog,
                 database,
                 table,
+                columns: opt_columns
+                    .map(|(_, _, _, _, _, columns, _)| columns)
+                    .unwrap_or_default(),
             })
         },
     );
@@ -1334,12 +1338,12 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         },
     );
 
-    let create_inverted_index = map_res(
+    let create_table_index = map_res(
         rule! {
             CREATE
             ~ ( OR ~ ^REPLACE )?
             ~ ASYNC?
-            ~ INVERTED ~ INDEX
+            ~ #table_index_type ~ INDEX
             ~ ( IF ~ ^NOT ~ ^EXISTS )?
             ~ #ident
             ~ ON ~ #dot_separated_idents_1_to_3
@@ -1350,7 +1354,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             _,
             opt_or_replace,
             opt_async,
-            _,
+            index_type,
             _,
             opt_if_not_exists,
             index_name,
@@ -1363,7 +1367,8 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         )| {
             let create_option =
                 parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
-            Ok(Statement::CreateInvertedIndex(CreateInvertedIndexStmt {
+            Ok(Statement::CreateTableIndex(CreateTableIndexStmt {
+                index_type,
                 create_option,
                 index_name,
                 catalog,
@@ -1376,13 +1381,14 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         },
     );
 
-    let drop_inverted_index = map(
+    let drop_table_index = map(
         rule! {
-            DROP ~ INVERTED ~ INDEX ~ ( IF ~ ^EXISTS )? ~ #ident
+            DROP ~ #table_index_type ~ INDEX ~ ( IF ~ ^EXISTS )? ~ #ident
             ~ ON ~ #dot_separated_idents_1_to_3
         },
-        |(_, _, _, opt_if_exists, index_name, _, (catalog, database, table))| {
-            Statement::DropInvertedIndex(DropInvertedIndexStmt {
+        |(_, index_type, _, opt_if_exists, index_name, _, (catalog, database, table))| {
+            Statement::DropTableIndex(DropTableIndexStmt {
+                index_type,
                 if_exists: opt_if_exists.is_some(),
                 index_name,
                 catalog,
@@ -1392,12 +1398,13 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         },
     );
 
-    let refresh_inverted_index = map(
+    let refresh_table_index = map(
         rule! {
-            REFRESH ~ INVERTED ~ INDEX ~ #ident ~ ON ~ #dot_separated_idents_1_to_3 ~ ( LIMIT ~ #literal_u64 )?
+            REFRESH ~ #table_index_type ~ INDEX ~ #ident ~ ON ~ #dot_separated_idents_1_to_3 ~ ( LIMIT ~ #literal_u64 )?
         },
-        |(_, _, _, index_name, _, (catalog, database, table), opt_limit)| {
-            Statement::RefreshInvertedIndex(RefreshInvertedIndexStmt {
+        |(_, index_type, _, index_name, _, (catalog, database, table), opt_limit)| {
+            Statement::RefreshTableIndex(RefreshTableIndexStmt {
+                index_type,
                 index_name,
                 catalog,
                 database,
@@ -2519,7 +2526,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #optimize_table : "`OPTIMIZE TABLE [<database>.]<table> (ALL | PURGE | COMPACT [SEGMENT])`"
             | #vacuum_table : "`VACUUM TABLE [<database>.]<table> [RETAIN number HOURS] [DRY RUN | DRY RUN SUMMARY]`"
             | #vacuum_drop_table : "`VACUUM DROP TABLE [FROM [<catalog>.]<database>] [RETAIN number HOURS] [DRY RUN | DRY RUN SUMMARY]`"
-            | #analyze_table : "`ANALYZE TABLE [<database>.]<table>`"
+            | #analyze_table : "`ANALYZE TABLE [<database>.]<table> [COMPUTE STATISTICS FOR COLUMNS (<column>, ...)]`"
             | #exists_table : "`EXISTS TABLE [<database>.]<table>`"
             | #show_table_functions : "`SHOW TABLE_FUNCTIONS [<show_limit>]`"
         ),
@@ -2540,9 +2547,9 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #create_index: "`CREATE [OR REPLACE] AGGREGATING INDEX [IF NOT EXISTS] <index> AS SELECT ...`"
             | #drop_index: "`DROP <index_type> INDEX [IF EXISTS] <index>`"
             | #refresh_index: "`REFRESH <index_type> INDEX <index> [LIMIT <limit>]`"
-            | #create_inverted_index: "`CREATE [OR REPLACE] INVERTED INDEX [IF NOT EXISTS] <index> ON [<database>.]<table>(<column>, ...)`"
-            | #drop_inverted_index: "`DROP INVERTED INDEX [IF EXISTS] <index> ON [<database>.]<table>`"
-            | #refresh_inverted_index: "`REFRESH INVERTED INDEX <index> ON [<database>.]<table> [LIMIT <limit>]`"
+            | #create_table_index: "`CREATE [OR REPLACE] {INVERTED | VECTOR} INDEX [IF NOT EXISTS] <index> ON [<database>.]<table>(<column>, ...)`"
+            | #drop_table_index: "`DROP {INVERTED | VECTOR} INDEX [IF EXISTS] <index> ON [<database>.]<table>`"
+            | #refresh_table_index: "`REFRESH {INVERTED | VECTOR} INDEX <index> ON [<database>.]<table> [LIMIT <limit>]`"
         ),
         rule!(
             #create_virtual_column: "`CREATE VIRTUAL COLUMN (expr, ...) FOR [<database>.]<table>`"
@@ -3140,17 +3147,18 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
     Ok((i, def))
 }
 
-pub fn inverted_index_def(i: Input) -> IResult<InvertedIndexDefinition> {
+pub fn table_index_def(i: Input) -> IResult<TableIndexDefinition> {
     map_res(
         rule! {
             ASYNC?
-            ~ INVERTED ~ ^INDEX
+            ~ #table_index_type ~ ^INDEX
             ~ #ident
             ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
             ~ ( #table_option )?
         },
-        |(opt_async, _, _, index_name, _, columns, _, opt_index_options)| {
-            Ok(InvertedIndexDefinition {
+        |(opt_async, index_type, _, index_name, _, columns, _, opt_index_options)| {
+            Ok(TableIndexDefinition {
+                index_type,
                 index_name,
                 columns,
                 sync_creation: opt_async.is_none(),
@@ -3163,10 +3171,7 @@ pub fn inverted_index_def(i: Input) -> IResult<InvertedIndexDefinition> {
 pub fn create_def(i: Input) -> IResult<CreateDefinition> {
     alt((
         map(rule! { #column_def }, CreateDefinition::Column),
-        map(
-            rule! { #inverted_index_def },
-            CreateDefinition::InvertedIndex,
-        ),
+        map(rule! { #table_index_def }, CreateDefinition::TableIndex),
     ))(i)
 }
 
@@ -3516,23 +3521,23 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
         },
         |(_, create_defs, _)| {
             let mut columns = Vec::with_capacity(create_defs.len());
-            let mut inverted_indexes = Vec::new();
+            let mut table_indexes = Vec::new();
             for create_def in create_defs {
                 match create_def {
                     CreateDefinition::Column(column) => {
                         columns.push(column);
                     }
-                    CreateDefinition::InvertedIndex(inverted_index) => {
-                        inverted_indexes.push(inverted_index);
+                    CreateDefinition::TableIndex(table_index) => {
+                        table_indexes.push(table_index);
                     }
                 }
             }
-            let opt_inverted_indexes = if !inverted_indexes.is_empty() {
-                Some(inverted_indexes)
+            let opt_table_indexes = if !table_indexes.is_empty() {
+                Some(table_indexes)
             } else {
                 None
             };
-            CreateTableSource::Columns(columns, opt_inverted_indexes)
+            CreateTableSource::Columns(columns, opt_table_indexes)
         },
     );
     let like = map(
@@ -3545,10 +3550,17 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
             table,
         },
     );
+    let template = map(
+        rule! {
+            USING ~ TEMPLATE ~ ^"(" ~ ^#query ~ ^")"
+        },
+        |(_, _, _, query, _)| CreateTableSource::Template(Box::new(query)),
+    );
 
     rule!(
         #columns
         | #like
+        | #template
     )(i)
 }
 
@@ -4235,6 +4247,13 @@ pub fn switch(i: Input) -> IResult<bool> {
     ))(i)
 }
 
+pub fn table_index_type(i: Input) -> IResult<TableIndexType> {
+    alt((
+        value(TableIndexType::Inverted, rule! { INVERTED }),
+        value(TableIndexType::Vector, rule! { VECTOR }),
+    ))(i)
+}
+
 pub fn cluster_type(i: Input) -> IResult<ClusterType> {
     alt((
         value(ClusterType::Linear, rule! { LINEAR }),
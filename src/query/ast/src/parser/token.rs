@@ -403,6 +403,8 @@ pub enum TokenKind {
     BINARY_FORMAT,
     #[token("BITMAP", ignore(ascii_case))]
     BITMAP,
+    #[token("BLOOM_FILTER_COLUMNS", ignore(ascii_case))]
+    BLOOM_FILTER_COLUMNS,
     #[token("BLOCKED_IP_LIST", ignore(ascii_case))]
     BLOCKED_IP_LIST,
     #[token("BOOL", ignore(ascii_case))]
@@ -443,6 +445,8 @@ pub enum TokenKind {
     COMMENTS,
     #[token("COMPACT", ignore(ascii_case))]
     COMPACT,
+    #[token("COMPUTE", ignore(ascii_case))]
+    COMPUTE,
     #[token("CONNECTION", ignore(ascii_case))]
     CONNECTION,
     #[token("CONNECTIONS", ignore(ascii_case))]
@@ -467,6 +471,8 @@ pub enum TokenKind {
     CONFLICT,
     #[token("COMPRESSION", ignore(ascii_case))]
     COMPRESSION,
+    #[token("COMPRESSION_LEVEL", ignore(ascii_case))]
+    COMPRESSION_LEVEL,
     #[token("COPY_OPTIONS", ignore(ascii_case))]
     COPY_OPTIONS,
     #[token("COPY", ignore(ascii_case))]
@@ -571,6 +577,8 @@ pub enum TokenKind {
     EMPTY_FIELD_AS,
     #[token("ENABLE", ignore(ascii_case))]
     ENABLE,
+    #[token("ENABLE_PAGE_INDEX", ignore(ascii_case))]
+    ENABLE_PAGE_INDEX,
     #[token("ENABLE_VIRTUAL_HOST_STYLE", ignore(ascii_case))]
     ENABLE_VIRTUAL_HOST_STYLE,
     #[token("END", ignore(ascii_case))]
@@ -585,6 +593,8 @@ pub enum TokenKind {
     EPOCH,
     #[token("ERROR_ON_COLUMN_COUNT_MISMATCH", ignore(ascii_case))]
     ERROR_ON_COLUMN_COUNT_MISMATCH,
+    #[token("ERROR_TABLE", ignore(ascii_case))]
+    ERROR_TABLE,
     #[token("ESCAPE", ignore(ascii_case))]
     ESCAPE,
     #[token("EXCEPTION_BACKTRACE", ignore(ascii_case))]
@@ -982,6 +992,8 @@ pub enum TokenKind {
     ROWS,
     #[token("ROW_TAG", ignore(ascii_case))]
     ROW_TAG,
+    #[token("ROW_GROUP_SIZE", ignore(ascii_case))]
+    ROW_GROUP_SIZE,
     #[token("GRANT", ignore(ascii_case))]
     GRANT,
     #[token("REPEAT", ignore(ascii_case))]
@@ -1060,6 +1072,8 @@ pub enum TokenKind {
     STAGES,
     #[token("STATISTIC", ignore(ascii_case))]
     STATISTIC,
+    #[token("STATISTICS", ignore(ascii_case))]
+    STATISTICS,
     #[token("SUMMARY", ignore(ascii_case))]
     SUMMARY,
     #[token("SHA256_PASSWORD", ignore(ascii_case))]
@@ -1124,6 +1138,8 @@ pub enum TokenKind {
     SUBSTR,
     #[token("SEMI", ignore(ascii_case))]
     SEMI,
+    #[token("SORTING_COLUMNS", ignore(ascii_case))]
+    SORTING_COLUMNS,
     #[token("SOUNDS", ignore(ascii_case))]
     SOUNDS,
     #[token("SYNC", ignore(ascii_case))]
@@ -1222,6 +1238,8 @@ pub enum TokenKind {
     USING,
     #[token("VACUUM", ignore(ascii_case))]
     VACUUM,
+    #[token("VALIDATION_MODE", ignore(ascii_case))]
+    VALIDATION_MODE,
     #[token("VALUES", ignore(ascii_case))]
     VALUES,
     #[token("VARBINARY", ignore(ascii_case))]
@@ -1232,8 +1250,12 @@ pub enum TokenKind {
     VARIANT,
     #[token("VARIABLE", ignore(ascii_case))]
     VARIABLE,
+    #[token("VECTOR", ignore(ascii_case))]
+    VECTOR,
     #[token("VERBOSE", ignore(ascii_case))]
     VERBOSE,
+    #[token("VERSION", ignore(ascii_case))]
+    VERSION,
     #[token("GRAPHICAL", ignore(ascii_case))]
     GRAPHICAL,
     #[token("VIEW", ignore(ascii_case))]
@@ -1362,6 +1384,8 @@ pub enum TokenKind {
     TEMPORARY,
     #[token("TEMP", ignore(ascii_case))]
     TEMP,
+    #[token("TEMPLATE", ignore(ascii_case))]
+    TEMPLATE,
     #[token("SECONDS", ignore(ascii_case))]
     SECONDS,
     #[token("DAYS", ignore(ascii_case))]
@@ -118,10 +118,13 @@ fn test_statement() {
         r#"CREATE AGGREGATING INDEX idx1 AS SELECT SUM(a), b FROM t1 WHERE b > 3 GROUP BY b;"#,
         r#"CREATE OR REPLACE AGGREGATING INDEX idx1 AS SELECT SUM(a), b FROM t1 WHERE b > 3 GROUP BY b;"#,
         r#"CREATE OR REPLACE INVERTED INDEX idx2 ON t1 (a, b);"#,
+        r#"CREATE VECTOR INDEX IF NOT EXISTS idx3 ON db.t1 (embedding) metric='cosine';"#,
+        r#"DROP VECTOR INDEX idx3 ON t1;"#,
         r#"create table a (c decimal(38, 0))"#,
         r#"create table a (c decimal(38))"#,
         r#"create or replace table a (c decimal(38))"#,
         r#"create or replace table a (c int(10) unsigned)"#,
+        r#"create table a (c vector(3), d vector(int8, 4))"#,
         r#"create table if not exists a.b (c integer not null default 1, b varchar);"#,
         r#"create table if not exists a.b (c integer default 1 not null, b varchar) as select * from t;"#,
         r#"create table if not exists a.b (c tuple(m integer, n string), d tuple(integer, string));"#,
@@ -441,6 +444,19 @@ fn test_statement() {
                     skip_header = 1
                 );
         "#,
+        r#"
+            COPY INTO @my_stage
+                FROM mytable
+                FILE_FORMAT = (
+                    type = PARQUET
+                    compression = GZIP
+                    compression_level = 6
+                    row_group_size = 100000
+                    enable_page_index = true
+                    bloom_filter_columns = ('a', 'b')
+                    sorting_columns = ('a', 'b DESC')
+                );
+        "#,
         r#"
             COPY INTO mytable
                 FROM 's3://mybucket/data.csv'
@@ -511,6 +527,8 @@ fn test_statement() {
                 )
                 FILE_FORMAT = (type = CSV);
         "#,
+        r#"COPY INTO mytable FROM @my_stage ON_ERROR = continue ERROR_TABLE = db1.mytable_errors;"#,
+        r#"COPY INTO mytable FROM @my_stage VALIDATION_MODE = return_10_rows;"#,
         // We used to support COPY FROM a quoted at string
         // r#"
         //     COPY INTO mytable
@@ -75,7 +75,12 @@ pub async fn list_stage_files(
 ) -> Result<Vec<StageFileInfo>> {
     let op = init_stage_operator(stage_info)?;
     let infos = files_info
-        .list(&op, thread_num, max_files)
+        .list_with_archives(
+            &op,
+            thread_num,
+            max_files,
+            stage_info.file_format_params.compression(),
+        )
         .await?
         .into_iter()
         .collect::<Vec<_>>();
@@ -19,6 +19,7 @@ use std::fmt::Debug;
 use databend_common_ast::ast::SampleConfig;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::F32;
+use databend_common_expression::ColumnId;
 use databend_common_expression::DataSchema;
 use databend_common_expression::RemoteExpr;
 use databend_common_expression::Scalar;
@@ -138,6 +139,25 @@ pub struct InvertedIndexInfo {
     pub inverted_index_option: Option<InvertedIndexOption>,
 }
 
+/// Information about vector index.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VectorIndexInfo {
+    /// The index name.
+    pub index_name: String,
+    /// The index version.
+    pub index_version: String,
+    /// The index options: metric, lists, etc.
+    pub index_options: BTreeMap<String, String>,
+    /// The id of the indexed column.
+    pub column_id: ColumnId,
+    /// The distance function used in the order by clause, `cosine_distance` or `l2_distance`.
+    pub func_name: String,
+    /// The query vector to search the nearest neighbors.
+    pub query_values: Vec<F32>,
+    /// The number of nearest neighbors to search.
+    pub limit: usize,
+}
+
 /// Extras is a wrapper for push down items.
 #[derive(serde::Serialize, serde::Deserialize, Clone, Default, Debug, PartialEq, Eq)]
 pub struct PushDownInfo {
@@ -168,6 +188,8 @@ pub struct PushDownInfo {
     pub change_type: Option<ChangeType>,
     /// Optional inverted index
     pub inverted_index: Option<InvertedIndexInfo>,
+    /// Optional vector index
+    pub vector_index: Option<VectorIndexInfo>,
     /// Used by table sample
     pub sample: Option<SampleConfig>,
 }
@@ -15,6 +15,8 @@
 use databend_common_base::base::tokio::sync::watch;
 use databend_common_base::base::tokio::sync::watch::Receiver;
 use databend_common_base::base::tokio::sync::watch::Sender;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
 use databend_common_expression::Expr;
 use xorf::BinaryFuse16;
 
@@ -23,6 +25,8 @@ pub struct RuntimeFilterInfo {
     inlist: Vec<Expr<String>>,
     min_max: Vec<Expr<String>>,
     bloom: Vec<(String, BinaryFuse16)>,
+    /// The bloom index digests of the build keys, used to prune the blocks of the probe side.
+    bloom_digest: Vec<(String, Vec<u64>)>,
 }
 
 impl RuntimeFilterInfo {
@@ -38,6 +42,10 @@ impl RuntimeFilterInfo {
         self.min_max.push(expr);
     }
 
+    pub fn add_bloom_digest(&mut self, digest: (String, Vec<u64>)) {
+        self.bloom_digest.push(digest);
+    }
+
     pub fn get_inlist(&self) -> &Vec<Expr<String>> {
         &self.inlist
     }
@@ -50,6 +58,10 @@ impl RuntimeFilterInfo {
         &self.min_max
     }
 
+    pub fn get_bloom_digest(&self) -> &Vec<(String, Vec<u64>)> {
+        &self.bloom_digest
+    }
+
     pub fn blooms(self) -> Vec<(String, BinaryFuse16)> {
         self.bloom
     }
@@ -62,8 +74,15 @@ impl RuntimeFilterInfo {
         self.min_max
     }
 
+    pub fn bloom_digests(self) -> Vec<(String, Vec<u64>)> {
+        self.bloom_digest
+    }
+
     pub fn is_empty(&self) -> bool {
-        self.inlist.is_empty() && self.bloom.is_empty() && self.min_max.is_empty()
+        self.inlist.is_empty()
+            && self.bloom.is_empty()
+            && self.min_max.is_empty()
+            && self.bloom_digest.is_empty()
     }
 
     pub fn is_blooms_empty(&self) -> bool {
@@ -86,3 +105,17 @@ impl Default for RuntimeFilterReady {
         }
     }
 }
+
+impl RuntimeFilterReady {
+    /// Waits until the build side of the join has set the runtime filters.
+    pub async fn wait(&self) -> Result<()> {
+        let mut rx = self.runtime_filter_watcher.subscribe();
+        if (*rx.borrow()).is_some() {
+            return Ok(());
+        }
+        rx.changed()
+            .await
+            .map_err(|_| ErrorCode::TokioError("watcher's sender is dropped"))?;
+        Ok(())
+    }
+}
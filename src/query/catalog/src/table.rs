@@ -43,6 +43,7 @@ use databend_common_pipeline_core::Pipeline;
 use databend_common_storage::Histogram;
 use databend_common_storage::StorageMetrics;
 use databend_storages_common_table_meta::meta::ClusterKey;
+use databend_storages_common_table_meta::meta::MultiColumnStatistics;
 use databend_storages_common_table_meta::meta::SnapshotId;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::table::ChangeType;
@@ -190,11 +191,22 @@ pub trait Table: Sync + Send {
         false
     }
 
+    /// Whether the scan of the table prunes its partitions with the runtime filters of joins
+    /// before reading them, so the scan waits for the runtime filters even without a cluster.
+    fn support_runtime_filter_partition_pruning(&self) -> bool {
+        false
+    }
+
     /// Whether the table engine supports virtual columns optimization.
     fn support_virtual_columns(&self) -> bool {
         false
     }
 
+    /// Whether the virtual columns are discovered from the inner fields of variant columns on write.
+    fn auto_virtual_columns_enabled(&self) -> bool {
+        false
+    }
+
     fn storage_format_as_parquet(&self) -> bool {
         false
     }
@@ -557,6 +569,8 @@ pub enum TimeNavigation {
 pub enum NavigationPoint {
     SnapshotID(String),
     TimePoint(DateTime<Utc>),
+    /// Version of the data lake table, like the version of delta table.
+    Version(u64),
     StreamInfo(TableInfo),
 }
 
@@ -597,6 +611,11 @@ pub trait ColumnStatisticsProvider: Send {
     fn histogram(&self, _column_id: ColumnId) -> Option<Histogram> {
         None
     }
+
+    // return the multi-column statistics of the table, if any
+    fn multi_column_statistics(&self) -> Vec<MultiColumnStatistics> {
+        vec![]
+    }
 }
 
 pub struct DummyColumnStatisticsProvider;
@@ -340,6 +340,8 @@ pub trait TableContext: Send + Sync {
 
     fn get_min_max_runtime_filter_with_id(&self, id: usize) -> Vec<Expr<String>>;
 
+    fn get_bloom_digest_runtime_filter_with_id(&self, id: usize) -> Vec<(String, Vec<u64>)>;
+
     fn has_bloom_runtime_filters(&self, id: usize) -> bool;
     fn txn_mgr(&self) -> TxnManagerRef;
 
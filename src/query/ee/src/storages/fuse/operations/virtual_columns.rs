@@ -51,7 +51,7 @@ use databend_common_pipeline_transforms::processors::AsyncTransform;
 use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
 use databend_common_sql::parse_computed_expr;
 use databend_common_storage::read_parquet_schema_async_rs;
-use databend_common_storages_fuse::io::serialize_block;
+use databend_common_storages_fuse::io::serialize_virtual_block;
 use databend_common_storages_fuse::io::write_data;
 use databend_common_storages_fuse::io::BlockReader;
 use databend_common_storages_fuse::io::MetaReaders;
@@ -358,7 +358,7 @@ impl AsyncTransform for VirtualColumnTransform {
 
         let mut buffer = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
 
-        let _ = serialize_block(
+        serialize_virtual_block(
             &self.write_settings,
             &self.virtual_schema,
             virtual_block,
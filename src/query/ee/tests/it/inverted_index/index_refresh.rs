@@ -26,6 +26,7 @@ use databend_common_expression::DataField;
 use databend_common_expression::DataSchema;
 use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::schema::CreateTableIndexReq;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_common_sql::plans::RefreshTableIndexPlan;
 use databend_common_storages_fuse::io::read::InvertedIndexReader;
 use databend_common_storages_fuse::io::MetaReaders;
@@ -77,6 +78,7 @@ async fn test_fuse_do_refresh_inverted_index() -> Result<()> {
 
     let req = CreateTableIndexReq {
         create_option: CreateOption::Create,
+        index_type: TableIndexType::Inverted,
         table_id,
         tenant,
         name: index_name.clone(),
@@ -89,6 +91,7 @@ async fn test_fuse_do_refresh_inverted_index() -> Result<()> {
     assert!(res.is_ok());
 
     let refresh_index_plan = RefreshTableIndexPlan {
+        index_type: TableIndexType::Inverted,
         catalog: fixture.default_catalog_name(),
         database: fixture.default_db_name(),
         table: fixture.default_table_name(),
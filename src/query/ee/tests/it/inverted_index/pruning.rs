@@ -35,6 +35,7 @@ use databend_common_expression::TableSchemaRef;
 use databend_common_expression::TableSchemaRefExt;
 use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::schema::CreateTableIndexReq;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_common_sql::plans::CreateTablePlan;
 use databend_common_sql::plans::RefreshTableIndexPlan;
 use databend_common_sql::BloomIndexColumns;
@@ -76,6 +77,8 @@ async fn apply_block_pruning(
         schema,
         push_down,
         bloom_index_cols,
+        BloomIndexColumns::None,
+        BloomIndexColumns::None,
         None,
         FuseStorageFormat::Parquet,
     )?
@@ -120,6 +123,7 @@ async fn test_block_pruner() -> Result<()> {
         .into(),
         field_comments: vec![],
         as_select: None,
+        template: None,
         cluster_key: None,
         inverted_indexes: None,
     };
@@ -511,6 +515,7 @@ async fn test_block_pruner() -> Result<()> {
 
     let req = CreateTableIndexReq {
         create_option: CreateOption::Create,
+        index_type: TableIndexType::Inverted,
         table_id,
         tenant,
         name: index_name.clone(),
@@ -529,6 +534,7 @@ async fn test_block_pruner() -> Result<()> {
     ]);
 
     let refresh_index_plan = RefreshTableIndexPlan {
+        index_type: TableIndexType::Inverted,
         catalog: fixture.default_catalog_name(),
         database: fixture.default_db_name(),
         table: test_tbl_name.to_string(),
@@ -45,6 +45,8 @@ use crate::types::GeographyColumn;
 use crate::types::NullableColumn;
 use crate::types::NumberColumn;
 use crate::types::NumberDataType;
+use crate::types::VectorColumn;
+use crate::types::VectorDataType;
 use crate::Column;
 use crate::DataBlock;
 use crate::DataField;
@@ -119,6 +121,19 @@ impl TryFrom<&Field> for TableField {
                     let inner_type = TableField::try_from(field.as_ref())?;
                     TableDataType::Array(Box::new(inner_type.data_type))
                 }
+                ArrowDataType::FixedSizeList(field, size) => {
+                    let inner_type = TableField::try_from(field.as_ref())?;
+                    let dimension = *size as u64;
+                    match inner_type.data_type {
+                        TableDataType::Number(NumberDataType::Int8) if dimension > 0 => {
+                            TableDataType::Vector(VectorDataType::Int8(dimension))
+                        }
+                        TableDataType::Number(NumberDataType::Float32) if dimension > 0 => {
+                            TableDataType::Vector(VectorDataType::Float32(dimension))
+                        }
+                        ty => TableDataType::Array(Box::new(ty)),
+                    }
+                }
                 ArrowDataType::Map(field, _) => {
                     if let ArrowDataType::Struct(fields) = field.data_type() {
                         let fields_name: Vec<String> =
@@ -297,6 +312,31 @@ impl Column {
                 let inner_col = ArrayColumn { values, offsets };
                 Column::Array(Box::new(inner_col))
             }
+            DataType::Vector(ty) => {
+                let array = array
+                    .as_any()
+                    .downcast_ref::<arrow_array::FixedSizeListArray>()
+                    .ok_or_else(|| {
+                        ErrorCode::Internal(format!(
+                            "Cannot downcast to FixedSizeListArray from array: {:?}",
+                            array
+                        ))
+                    })?;
+                if array.value_length() as u64 != ty.dimension() {
+                    return Err(ErrorCode::Internal(format!(
+                        "Invalid vector dimension {}, expect {}",
+                        array.value_length(),
+                        ty.dimension()
+                    )));
+                }
+                let inner_ty = ArrowDataType::from(&DataType::Number(ty.inner_data_type()));
+                let values = arrow_cast::cast(array.values().as_ref(), &inner_ty)?;
+                let values = NumberColumn::try_from_arrow_data(values.to_data())?;
+                Column::Vector(VectorColumn::try_from_values(
+                    values,
+                    ty.dimension() as usize,
+                )?)
+            }
             DataType::Map(inner) => {
                 let array = array
                     .as_any()
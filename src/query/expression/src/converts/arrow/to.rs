@@ -195,6 +195,11 @@ impl From<&TableField> for Field {
                 );
                 ArrowDataType::Decimal128(38, 0)
             }
+            TableDataType::Vector(ty) => {
+                let inner_ty = ArrowDataType::from(&DataType::Number(ty.inner_data_type()));
+                let item = Field::new("item", inner_ty, false);
+                ArrowDataType::FixedSizeList(Arc::new(item), ty.dimension() as i32)
+            }
         };
 
         Field::new(f.name(), ty, f.is_nullable()).with_metadata(metadata)
@@ -326,6 +331,16 @@ impl From<&Column> for ArrayData {
                     .child_data(vec![child_data]);
                 unsafe { builder.build_unchecked() }
             }
+            Column::Vector(col) => {
+                let values = col.values();
+                let child_type = ArrowDataType::from(&DataType::Number(values.data_type()));
+                let child_data = values.arrow_data(child_type);
+                let builder = ArrayDataBuilder::new(arrow_type)
+                    .len(value.len())
+                    .child_data(vec![child_data]);
+
+                unsafe { builder.build_unchecked() }
+            }
             Column::Tuple(fields) => {
                 let child_data = fields.iter().map(ArrayData::from).collect::<Vec<_>>();
                 let builder = ArrayDataBuilder::new(arrow_type)
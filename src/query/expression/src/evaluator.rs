@@ -20,6 +20,7 @@ use std::ops::Not;
 use databend_common_ast::Span;
 use databend_common_column::bitmap::Bitmap;
 use databend_common_column::bitmap::MutableBitmap;
+use databend_common_column::buffer::Buffer;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use itertools::Itertools;
@@ -46,6 +47,9 @@ use crate::types::NullableType;
 use crate::types::NumberScalar;
 use crate::types::StringType;
 use crate::types::VariantType;
+use crate::types::VectorColumn;
+use crate::types::VectorColumnBuilder;
+use crate::types::VectorDataType;
 use crate::values::Column;
 use crate::values::ColumnBuilder;
 use crate::values::Scalar;
@@ -506,6 +510,96 @@ impl<'a> Evaluator<'a> {
                 }
                 other => unreachable!("source: {}", other),
             },
+            (DataType::Array(inner_src_ty), DataType::Vector(vector_ty)) => {
+                let inner_dest_ty = DataType::Number(vector_ty.inner_data_type());
+                match value {
+                    Value::Scalar(Scalar::Array(array)) => {
+                        let is_valid = validity
+                            .map(|validity| validity.null_count() != validity.len())
+                            .unwrap_or(true);
+                        if !is_valid {
+                            return Ok(Value::Scalar(Scalar::default_value(dest_type)));
+                        }
+                        let len = array.len();
+                        let values = self
+                            .run_cast(
+                                span,
+                                inner_src_ty,
+                                &inner_dest_ty,
+                                Value::Column(array),
+                                None,
+                                options,
+                            )?
+                            .into_column()
+                            .unwrap();
+                        let column = cast_values_to_vector(
+                            span,
+                            vector_ty,
+                            &values,
+                            &[0, len as u64],
+                            None,
+                        )?;
+                        Ok(Value::Scalar(Scalar::Vector(
+                            column.index(0).unwrap().to_owned(),
+                        )))
+                    }
+                    Value::Column(Column::Array(col)) => {
+                        let inner_validity = validity.as_ref().map(|validity| {
+                            let mut inner_validity = MutableBitmap::with_capacity(col.len());
+                            for (index, offsets) in col.offsets.windows(2).enumerate() {
+                                inner_validity.extend_constant(
+                                    (offsets[1] - offsets[0]) as usize,
+                                    validity.get_bit(index),
+                                );
+                            }
+                            inner_validity.into()
+                        });
+                        let values = self
+                            .run_cast(
+                                span,
+                                inner_src_ty,
+                                &inner_dest_ty,
+                                Value::Column(col.values),
+                                inner_validity,
+                                options,
+                            )?
+                            .into_column()
+                            .unwrap();
+                        let column = cast_values_to_vector(
+                            span,
+                            vector_ty,
+                            &values,
+                            &col.offsets,
+                            validity.as_ref(),
+                        )?;
+                        Ok(Value::Column(Column::Vector(column)))
+                    }
+                    other => unreachable!("source: {}", other),
+                }
+            }
+            (DataType::Vector(src_vector_ty), DataType::Array(_)) => {
+                let array_ty =
+                    DataType::Array(Box::new(DataType::Number(src_vector_ty.inner_data_type())));
+                let value = match value {
+                    Value::Scalar(Scalar::Vector(vector)) => Value::Scalar(Scalar::Array(
+                        Column::Number(vector.as_ref().to_number_column()),
+                    )),
+                    Value::Column(Column::Vector(col)) => {
+                        Value::Column(Column::Array(Box::new(col.to_array_column())))
+                    }
+                    other => unreachable!("source: {}", other),
+                };
+                self.run_cast(span, &array_ty, dest_type, value, validity, options)
+            }
+            (DataType::Vector(src_vector_ty), DataType::Vector(dest_vector_ty))
+                if src_vector_ty.dimension() == dest_vector_ty.dimension() =>
+            {
+                let array_ty =
+                    DataType::Array(Box::new(DataType::Number(src_vector_ty.inner_data_type())));
+                let value =
+                    self.run_cast(span, src_type, &array_ty, value, validity.clone(), options)?;
+                self.run_cast(span, &array_ty, dest_type, value, validity, options)
+            }
             (DataType::Variant, DataType::Array(inner_dest_ty)) => {
                 let empty_vec = vec![];
                 let mut temp_array: jsonb::Value;
@@ -908,6 +1002,67 @@ impl<'a> Evaluator<'a> {
                 }
                 _ => unreachable!(),
             },
+            (DataType::Array(inner_src_ty), DataType::Vector(vector_ty)) => {
+                let inner_dest_ty = DataType::Number(vector_ty.inner_data_type());
+                let (values, offsets, is_scalar) = match value {
+                    Value::Scalar(Scalar::Array(array)) => {
+                        let len = array.len() as u64;
+                        (array, Buffer::from(vec![0, len]), true)
+                    }
+                    Value::Column(Column::Array(col)) => (col.values, col.offsets, false),
+                    other => unreachable!("source: {}", other),
+                };
+                let values = self
+                    .run_try_cast(span, inner_src_ty, &inner_dest_ty, Value::Column(values))?
+                    .into_column()
+                    .unwrap()
+                    .into_nullable()
+                    .unwrap();
+                let dimension = vector_ty.dimension();
+                let mut builder = VectorColumnBuilder::with_capacity(vector_ty, offsets.len() - 1);
+                let mut validity = MutableBitmap::with_capacity(offsets.len() - 1);
+                for window in offsets.windows(2) {
+                    let (start, end) = (window[0] as usize, window[1] as usize);
+                    let is_valid = (end - start) as u64 == dimension
+                        && (start..end).all(|i| values.validity.get_bit(i));
+                    if is_valid {
+                        builder.push_number_values(values.column.as_number().unwrap(), start..end);
+                    } else {
+                        builder.push_default();
+                    }
+                    validity.push(is_valid);
+                }
+                let validity: Bitmap = validity.into();
+                let column = builder.build();
+                if is_scalar {
+                    if validity.get_bit(0) {
+                        Ok(Value::Scalar(Scalar::Vector(
+                            column.index(0).unwrap().to_owned(),
+                        )))
+                    } else {
+                        Ok(Value::Scalar(Scalar::Null))
+                    }
+                } else {
+                    Ok(Value::Column(NullableColumn::new_column(
+                        Column::Vector(column),
+                        validity,
+                    )))
+                }
+            }
+            (DataType::Vector(src_vector_ty), DataType::Array(_)) => {
+                let array_ty =
+                    DataType::Array(Box::new(DataType::Number(src_vector_ty.inner_data_type())));
+                let value = match value {
+                    Value::Scalar(Scalar::Vector(vector)) => Value::Scalar(Scalar::Array(
+                        Column::Number(vector.as_ref().to_number_column()),
+                    )),
+                    Value::Column(Column::Vector(col)) => {
+                        Value::Column(Column::Array(Box::new(col.to_array_column())))
+                    }
+                    other => unreachable!("source: {}", other),
+                };
+                self.run_try_cast(span, &array_ty, dest_type, value)
+            }
             (DataType::EmptyMap, DataType::Map(inner_dest_ty)) => match value {
                 Value::Scalar(Scalar::EmptyMap) => {
                     let new_column = ColumnBuilder::with_capacity(inner_dest_ty, 0).build();
@@ -1784,6 +1939,36 @@ impl<'a> Evaluator<'a> {
     }
 }
 
+/// Build the vector column from the flattened values of the arrays, the values
+/// are already casted to the number type of the vector.
+fn cast_values_to_vector(
+    span: Span,
+    vector_ty: &VectorDataType,
+    values: &Column,
+    offsets: &[u64],
+    validity: Option<&Bitmap>,
+) -> Result<VectorColumn> {
+    let values = values.as_number().unwrap();
+    let dimension = vector_ty.dimension();
+    let mut builder = VectorColumnBuilder::with_capacity(vector_ty, offsets.len() - 1);
+    for (row, window) in offsets.windows(2).enumerate() {
+        if !validity.map(|v| v.get_bit(row)).unwrap_or(true) {
+            builder.push_default();
+            continue;
+        }
+        let len = window[1] - window[0];
+        if len != dimension {
+            return Err(ErrorCode::BadArguments(format!(
+                "unable to cast array with {len} elements to type `{}`, expect {dimension} elements",
+                DataType::Vector(*vector_ty)
+            ))
+            .set_span(span));
+        }
+        builder.push_number_values(values, window[0] as usize..window[1] as usize);
+    }
+    Ok(builder.build())
+}
+
 const MAX_FUNCTION_ARGS_TO_FOLD: usize = 4096;
 
 pub struct ConstantFolder<'a, Index: ColumnIndex> {
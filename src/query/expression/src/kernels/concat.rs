@@ -40,8 +40,10 @@ use crate::types::MapType;
 use crate::types::NumberType;
 use crate::types::TimestampType;
 use crate::types::ValueType;
+use crate::types::VectorColumn;
 use crate::with_decimal_mapped_type;
 use crate::with_number_mapped_type;
+use crate::with_vector_type;
 use crate::BlockEntry;
 use crate::Column;
 use crate::ColumnBuilder;
@@ -172,6 +174,21 @@ impl Column {
                 );
                 Column::Interval(buffer)
             }
+            Column::Vector(col) => {
+                let dimension = col.dimension();
+                with_vector_type!(|VECTOR_TYPE| match col {
+                    VectorColumn::VECTOR_TYPE(_, _) => {
+                        let buffer = Self::concat_primitive_types(
+                            columns.map(|col| match col {
+                                Column::Vector(VectorColumn::VECTOR_TYPE(values, _)) => values,
+                                _ => unreachable!(),
+                            }),
+                            capacity * dimension,
+                        );
+                        Column::Vector(VectorColumn::VECTOR_TYPE(buffer, dimension))
+                    }
+                })
+            }
             Column::Array(col) => {
                 let mut offsets = Vec::with_capacity(capacity + 1);
                 offsets.push(0);
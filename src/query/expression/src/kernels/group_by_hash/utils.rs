@@ -19,8 +19,10 @@ use crate::types::binary::BinaryColumnBuilder;
 use crate::types::decimal::DecimalColumn;
 use crate::types::BinaryColumn;
 use crate::types::NumberColumn;
+use crate::types::VectorColumn;
 use crate::with_decimal_mapped_type;
 use crate::with_number_mapped_type;
+use crate::with_vector_type;
 use crate::Column;
 use crate::InputColumns;
 
@@ -98,6 +100,13 @@ pub unsafe fn serialize_column_binary(column: &Column, row: usize, row_space: &m
                 serialize_column_binary(&data, i, row_space);
             }
         }
+        Column::Vector(v) => with_vector_type!(|VECTOR_TYPE| match v {
+            VectorColumn::VECTOR_TYPE(values, dimension) => {
+                for value in &values[row * dimension..(row + 1) * dimension] {
+                    row_space.store_value_uncheckd(value);
+                }
+            }
+        }),
         Column::Nullable(c) => {
             let valid = c.validity.get_bit(row);
 
@@ -404,6 +404,10 @@ impl Column {
                 let builder = GeographyType::create_builder(result_size, &[]);
                 Self::take_block_value_types::<GeographyType>(columns, builder, indices)
             }
+            Column::Vector(_) => {
+                let builder = ColumnBuilder::with_capacity(&datatype, result_size);
+                Self::take_block_value_types::<AnyType>(columns, builder, indices)
+            }
         }
     }
 
@@ -633,6 +637,13 @@ impl Column {
                     .collect_vec();
                 ColumnVec::Geography(columns)
             }
+            Column::Vector(_) => {
+                let columns = columns
+                    .iter()
+                    .map(|col| col.as_vector().unwrap().clone())
+                    .collect_vec();
+                ColumnVec::Vector(columns)
+            }
         }
     }
 
@@ -783,6 +794,19 @@ impl Column {
                     &columns, indices,
                 )))
             }
+            ColumnVec::Vector(columns) => {
+                let data_type = data_type.as_vector().unwrap();
+                let mut builder = VectorColumnBuilder::with_capacity(data_type, result_size);
+                for row_ptr in indices {
+                    let val = unsafe {
+                        columns
+                            .get_unchecked(row_ptr.chunk_index as usize)
+                            .index_unchecked(row_ptr.row_index as usize)
+                    };
+                    builder.push(val);
+                }
+                Column::Vector(builder.build())
+            }
         }
     }
 
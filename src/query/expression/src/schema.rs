@@ -28,6 +28,7 @@ use crate::display::display_tuple_field_name;
 use crate::types::decimal::DecimalDataType;
 use crate::types::DataType;
 use crate::types::NumberDataType;
+use crate::types::VectorDataType;
 use crate::BlockMetaInfo;
 use crate::BlockMetaInfoDowncast;
 use crate::Scalar;
@@ -170,6 +171,11 @@ pub struct TableSchema {
     // next column id that assign to TableField.column_id
     #[serde(default = "uninit_column_id")]
     pub next_column_id: ColumnId,
+    // Columns whose data type was widened in place, keyed by the column id assigned
+    // after the change. The value is the field as it was before, blocks written
+    // earlier still store the column under its column id and data type.
+    #[serde(default)]
+    pub widened_columns: BTreeMap<ColumnId, TableField>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
@@ -208,6 +214,7 @@ pub enum TableDataType {
     Geometry,
     Geography,
     Interval,
+    Vector(VectorDataType),
 }
 
 impl DataSchema {
@@ -349,6 +356,7 @@ impl TableSchema {
             fields: vec![],
             metadata: BTreeMap::new(),
             next_column_id: 0,
+            widened_columns: BTreeMap::new(),
         }
     }
 
@@ -395,6 +403,7 @@ impl TableSchema {
             fields: new_fields,
             metadata: BTreeMap::new(),
             next_column_id,
+            widened_columns: BTreeMap::new(),
         }
     }
 
@@ -404,6 +413,7 @@ impl TableSchema {
             fields: new_fields,
             metadata,
             next_column_id,
+            widened_columns: BTreeMap::new(),
         }
     }
 
@@ -417,6 +427,7 @@ impl TableSchema {
             fields: new_fields,
             metadata,
             next_column_id,
+            widened_columns: BTreeMap::new(),
         }
     }
 
@@ -505,11 +516,64 @@ impl TableSchema {
             ));
         }
         let i = self.index_of(column)?;
-        self.fields.remove(i);
+        let field = self.fields.remove(i);
+        // The data stored before widening is gone together with the column.
+        let mut column_id = field.column_id;
+        while let Some(source) = self.widened_columns.remove(&column_id) {
+            column_id = source.column_id;
+        }
 
         Ok(i)
     }
 
+    /// Changes the data type of a column without rewriting the data already stored.
+    ///
+    /// The column gets a new column id, and the replaced field is recorded in
+    /// `widened_columns`. Readers use it to decode blocks written before the change
+    /// with the previous data type, and cast them to the new one.
+    pub fn widen_column(&mut self, column: &str, data_type: TableDataType) -> Result<()> {
+        let i = self.index_of(column)?;
+        let source = self.fields[i].clone();
+        let is_nested = |ty: &TableDataType| {
+            matches!(
+                ty.remove_nullable(),
+                TableDataType::Tuple { .. } | TableDataType::Array(_) | TableDataType::Map(_)
+            )
+        };
+        if is_nested(&source.data_type) || is_nested(&data_type) {
+            return Err(ErrorCode::BadArguments(format!(
+                "cannot widen column {} from {} to {}, nested data types are not supported",
+                column, source.data_type, data_type
+            )));
+        }
+        let mut field = source.clone();
+        field.data_type = data_type;
+        let field = field.build_column_id(&mut self.next_column_id);
+        self.widened_columns.insert(field.column_id, source);
+        self.fields[i] = field;
+        Ok(())
+    }
+
+    /// Returns the fields a column was stored as before its data type was widened,
+    /// from the most recent one.
+    pub fn widened_column_sources(&self, column_id: ColumnId) -> Vec<&TableField> {
+        let mut sources = vec![];
+        let mut column_id = column_id;
+        while let Some(source) = self.widened_columns.get(&column_id) {
+            sources.push(source);
+            column_id = source.column_id;
+        }
+        sources
+    }
+
+    /// Returns true if blocks may still store a live column under `column_id`,
+    /// because the column's data type was widened after they were written.
+    pub fn is_widened_column_source(&self, column_id: ColumnId) -> bool {
+        self.widened_columns
+            .values()
+            .any(|source| source.column_id == column_id)
+    }
+
     pub fn to_leaf_column_id_set(&self) -> HashSet<ColumnId> {
         HashSet::from_iter(self.to_leaf_column_ids().iter().cloned())
     }
@@ -704,6 +768,7 @@ impl TableSchema {
             fields,
             metadata: self.metadata.clone(),
             next_column_id: self.next_column_id,
+            widened_columns: self.widened_columns.clone(),
         }
     }
 
@@ -721,6 +786,7 @@ impl TableSchema {
             fields,
             metadata: self.metadata.clone(),
             next_column_id: self.next_column_id,
+            widened_columns: self.widened_columns.clone(),
         }
     }
 
@@ -934,6 +1000,7 @@ impl TableSchema {
             fields: new_fields,
             metadata: self.metadata.clone(),
             next_column_id: self.next_column_id,
+            widened_columns: self.widened_columns.clone(),
         }
     }
 
@@ -950,6 +1017,7 @@ impl TableSchema {
             fields: new_fields,
             metadata: self.metadata.clone(),
             next_column_id: self.next_column_id,
+            widened_columns: self.widened_columns.clone(),
         }
     }
 
@@ -966,6 +1034,7 @@ impl TableSchema {
             fields: new_fields,
             metadata: self.metadata.clone(),
             next_column_id: self.next_column_id,
+            widened_columns: self.widened_columns.clone(),
         }
     }
 }
@@ -1194,6 +1263,7 @@ impl From<&TableDataType> for DataType {
             TableDataType::Variant => DataType::Variant,
             TableDataType::Geometry => DataType::Geometry,
             TableDataType::Geography => DataType::Geography,
+            TableDataType::Vector(ty) => DataType::Vector(*ty),
         }
     }
 }
@@ -1347,7 +1417,8 @@ impl TableDataType {
                 | TableDataType::Variant
                 | TableDataType::Geometry
                 | TableDataType::Geography
-                | TableDataType::Interval => ty.sql_name(),
+                | TableDataType::Interval
+                | TableDataType::Vector(_) => ty.sql_name(),
             };
             if is_null {
                 format!("{} NULL", s)
@@ -1502,6 +1573,7 @@ pub fn infer_schema_type(data_type: &DataType) -> Result<TableDataType> {
         DataType::Variant => Ok(TableDataType::Variant),
         DataType::Geometry => Ok(TableDataType::Geometry),
         DataType::Geography => Ok(TableDataType::Geography),
+        DataType::Vector(ty) => Ok(TableDataType::Vector(*ty)),
         DataType::Tuple(fields) => {
             let fields_type = fields
                 .iter()
@@ -563,6 +563,17 @@ fn can_cast_to(src_ty: &DataType, dest_ty: &DataType) -> bool {
             true
         }
 
+        (DataType::Array(box inner_src_ty), DataType::Vector(vector_ty)) => {
+            can_cast_to(inner_src_ty, &DataType::Number(vector_ty.inner_data_type()))
+        }
+        (DataType::Vector(vector_ty), DataType::Array(box inner_dest_ty)) => can_cast_to(
+            &DataType::Number(vector_ty.inner_data_type()),
+            inner_dest_ty,
+        ),
+        (DataType::Vector(src_vector_ty), DataType::Vector(dest_vector_ty)) => {
+            src_vector_ty.dimension() == dest_vector_ty.dimension()
+        }
+
         (DataType::Nullable(box inner_src_ty), DataType::Nullable(box inner_dest_ty))
         | (DataType::Nullable(box inner_src_ty), inner_dest_ty)
         | (inner_src_ty, DataType::Nullable(box inner_dest_ty))
@@ -612,6 +623,11 @@ pub fn can_auto_cast_to(
                     .zip(dest_tys)
                     .all(|(src_ty, dest_ty)| can_auto_cast_to(src_ty, dest_ty, auto_cast_rules))
         }
+        (DataType::Vector(vector_ty), DataType::Array(dest_ty)) => can_auto_cast_to(
+            &DataType::Number(vector_ty.inner_data_type()),
+            dest_ty,
+            auto_cast_rules,
+        ),
         (DataType::String, DataType::Decimal(_)) => true,
         (DataType::Decimal(x), DataType::Decimal(y)) => {
             x.scale() <= y.scale()
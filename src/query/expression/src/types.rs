@@ -33,6 +33,7 @@ pub mod number_class;
 pub mod string;
 pub mod timestamp;
 pub mod variant;
+pub mod vector;
 
 use std::cmp::Ordering;
 use std::fmt::Debug;
@@ -72,6 +73,7 @@ pub use self::string::StringColumn;
 pub use self::string::StringType;
 pub use self::timestamp::TimestampType;
 pub use self::variant::VariantType;
+pub use self::vector::*;
 use crate::property::Domain;
 use crate::values::Column;
 use crate::values::Scalar;
@@ -101,6 +103,7 @@ pub enum DataType {
     Geometry,
     Interval,
     Geography,
+    Vector(VectorDataType),
 
     // Used internally for generic types
     Generic(usize),
@@ -152,7 +155,8 @@ impl DataType {
             | DataType::Bitmap
             | DataType::Variant
             | DataType::Geometry
-            | DataType::Geography => false,
+            | DataType::Geography
+            | DataType::Vector(_) => false,
             DataType::Nullable(ty) => ty.has_generic(),
             DataType::Array(ty) => ty.has_generic(),
             DataType::Map(ty) => ty.has_generic(),
@@ -178,6 +182,7 @@ impl DataType {
             | DataType::Variant
             | DataType::Geometry
             | DataType::Geography
+            | DataType::Vector(_)
             | DataType::Generic(_) => false,
             DataType::Nullable(box DataType::Nullable(_) | box DataType::Null) => true,
             DataType::Nullable(ty) => ty.has_nested_nullable(),
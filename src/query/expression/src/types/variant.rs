@@ -37,6 +37,7 @@ use crate::types::DataType;
 use crate::types::DecimalSize;
 use crate::types::GenericMap;
 use crate::types::ValueType;
+use crate::types::VectorScalarRef;
 use crate::values::Column;
 use crate::values::Scalar;
 use crate::values::ScalarRef;
@@ -301,6 +302,14 @@ pub fn cast_scalar_to_variant(scalar: ScalarRef, tz: &TimeZone, buf: &mut Vec<u8
                 .write_to_vec(buf);
             return;
         }
+        ScalarRef::Vector(vector) => match vector {
+            VectorScalarRef::Int8(values) => {
+                jsonb::Value::Array(values.iter().map(|v| (*v).into()).collect())
+            }
+            VectorScalarRef::Float32(values) => {
+                jsonb::Value::Array(values.iter().map(|v| v.0.into()).collect())
+            }
+        },
     };
     value.write_to_vec(buf);
 }
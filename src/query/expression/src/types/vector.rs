@@ -0,0 +1,465 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::ops::Range;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_column::buffer::Buffer;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use enum_as_inner::EnumAsInner;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::types::array::ArrayColumn;
+use crate::types::number::NumberColumn;
+use crate::types::number::NumberDataType;
+use crate::types::number::F32;
+use crate::types::AnyType;
+use crate::utils::arrow::buffer_into_mut;
+use crate::Column;
+
+/// The max dimension of the vector type.
+pub const VECTOR_MAX_DIMENSION: u64 = 16384;
+
+/// Fixed-dimension vector type, the values of all the rows are stored contiguously
+/// in one buffer without per-row offsets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, EnumAsInner)]
+pub enum VectorDataType {
+    Int8(u64),
+    Float32(u64),
+}
+
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    EnumAsInner,
+    Serialize,
+    Deserialize,
+    BorshSerialize,
+    BorshDeserialize,
+)]
+pub enum VectorScalar {
+    Int8(Vec<i8>),
+    Float32(Vec<F32>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumAsInner)]
+pub enum VectorScalarRef<'a> {
+    Int8(&'a [i8]),
+    Float32(&'a [F32]),
+}
+
+#[derive(Debug, Clone, PartialEq, EnumAsInner)]
+pub enum VectorColumn {
+    Int8(Buffer<i8>, usize),
+    Float32(Buffer<F32>, usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, EnumAsInner)]
+pub enum VectorColumnBuilder {
+    Int8(Vec<i8>, usize),
+    Float32(Vec<F32>, usize),
+}
+
+#[macro_export]
+macro_rules! with_vector_type {
+    (| $t:tt | $($tail:tt)*) => {
+        match_template::match_template! {
+            $t = [Int8, Float32],
+            $($tail)*
+        }
+    }
+}
+
+impl VectorDataType {
+    pub fn dimension(&self) -> u64 {
+        match self {
+            VectorDataType::Int8(dimension) | VectorDataType::Float32(dimension) => *dimension,
+        }
+    }
+
+    pub fn inner_data_type(&self) -> NumberDataType {
+        match self {
+            VectorDataType::Int8(_) => NumberDataType::Int8,
+            VectorDataType::Float32(_) => NumberDataType::Float32,
+        }
+    }
+
+    pub fn default_scalar(&self) -> VectorScalar {
+        let dimension = self.dimension() as usize;
+        match self {
+            VectorDataType::Int8(_) => VectorScalar::Int8(vec![0; dimension]),
+            VectorDataType::Float32(_) => VectorScalar::Float32(vec![F32::from(0.0); dimension]),
+        }
+    }
+}
+
+impl VectorScalar {
+    pub fn as_ref(&self) -> VectorScalarRef<'_> {
+        match self {
+            VectorScalar::Int8(values) => VectorScalarRef::Int8(values),
+            VectorScalar::Float32(values) => VectorScalarRef::Float32(values),
+        }
+    }
+
+    pub fn data_type(&self) -> VectorDataType {
+        self.as_ref().data_type()
+    }
+}
+
+impl PartialOrd for VectorScalar {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.as_ref().partial_cmp(&other.as_ref())
+    }
+}
+
+impl<'a> VectorScalarRef<'a> {
+    pub fn to_owned(&self) -> VectorScalar {
+        crate::with_vector_type!(|VECTOR_TYPE| match self {
+            VectorScalarRef::VECTOR_TYPE(values) => VectorScalar::VECTOR_TYPE(values.to_vec()),
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        crate::with_vector_type!(|VECTOR_TYPE| match self {
+            VectorScalarRef::VECTOR_TYPE(values) => values.len(),
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn data_type(&self) -> VectorDataType {
+        crate::with_vector_type!(|VECTOR_TYPE| match self {
+            VectorScalarRef::VECTOR_TYPE(values) =>
+                VectorDataType::VECTOR_TYPE(values.len() as u64),
+        })
+    }
+
+    pub fn memory_size(&self) -> usize {
+        match self {
+            VectorScalarRef::Int8(values) => values.len(),
+            VectorScalarRef::Float32(values) => values.len() * 4,
+        }
+    }
+
+    /// Returns the values as `f32`, the distance functions are calculated in `f32`.
+    pub fn to_f32_values(&self) -> Vec<f32> {
+        match self {
+            VectorScalarRef::Int8(values) => values.iter().map(|v| *v as f32).collect(),
+            VectorScalarRef::Float32(values) => values.iter().map(|v| v.0).collect(),
+        }
+    }
+
+    pub fn to_number_column(&self) -> NumberColumn {
+        crate::with_vector_type!(|VECTOR_TYPE| match self {
+            VectorScalarRef::VECTOR_TYPE(values) =>
+                NumberColumn::VECTOR_TYPE(values.to_vec().into()),
+        })
+    }
+}
+
+impl PartialOrd for VectorScalarRef<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (VectorScalarRef::Int8(lhs), VectorScalarRef::Int8(rhs)) => lhs.partial_cmp(rhs),
+            (VectorScalarRef::Float32(lhs), VectorScalarRef::Float32(rhs)) => lhs.partial_cmp(rhs),
+            _ => None,
+        }
+    }
+}
+
+impl VectorColumn {
+    pub fn dimension(&self) -> usize {
+        match self {
+            VectorColumn::Int8(_, dimension) | VectorColumn::Float32(_, dimension) => *dimension,
+        }
+    }
+
+    pub fn data_type(&self) -> VectorDataType {
+        crate::with_vector_type!(|VECTOR_TYPE| match self {
+            VectorColumn::VECTOR_TYPE(_, dimension) =>
+                VectorDataType::VECTOR_TYPE(*dimension as u64),
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        crate::with_vector_type!(|VECTOR_TYPE| match self {
+            VectorColumn::VECTOR_TYPE(values, dimension) => values.len() / *dimension,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn index(&self, index: usize) -> Option<VectorScalarRef<'_>> {
+        if index >= self.len() {
+            return None;
+        }
+        Some(unsafe { self.index_unchecked(index) })
+    }
+
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is *[undefined behavior]*
+    pub unsafe fn index_unchecked(&self, index: usize) -> VectorScalarRef<'_> {
+        debug_assert!(index < self.len());
+        crate::with_vector_type!(|VECTOR_TYPE| match self {
+            VectorColumn::VECTOR_TYPE(values, dimension) => {
+                let start = index * *dimension;
+                VectorScalarRef::VECTOR_TYPE(values.get_unchecked(start..start + *dimension))
+            }
+        })
+    }
+
+    pub fn slice(&self, range: Range<usize>) -> Self {
+        assert!(
+            range.end <= self.len(),
+            "range {:?} out of len {}",
+            range,
+            self.len()
+        );
+        crate::with_vector_type!(|VECTOR_TYPE| match self {
+            VectorColumn::VECTOR_TYPE(values, dimension) => VectorColumn::VECTOR_TYPE(
+                values.clone().sliced(
+                    range.start * *dimension,
+                    (range.end - range.start) * *dimension
+                ),
+                *dimension,
+            ),
+        })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = VectorScalarRef<'_>> {
+        (0..self.len()).map(|index| unsafe { self.index_unchecked(index) })
+    }
+
+    pub fn memory_size(&self) -> usize {
+        match self {
+            VectorColumn::Int8(values, _) => values.len(),
+            VectorColumn::Float32(values, _) => values.len() * 4,
+        }
+    }
+
+    /// The flattened values of all the rows.
+    pub fn values(&self) -> NumberColumn {
+        match self {
+            VectorColumn::Int8(values, _) => NumberColumn::Int8(values.clone()),
+            VectorColumn::Float32(values, _) => NumberColumn::Float32(values.clone()),
+        }
+    }
+
+    /// Converts into an array column, the values buffer is shared.
+    pub fn to_array_column(&self) -> ArrayColumn<AnyType> {
+        let dimension = self.dimension() as u64;
+        let offsets = (0..=self.len() as u64).map(|i| i * dimension).collect();
+        ArrayColumn {
+            values: Column::Number(self.values()),
+            offsets,
+        }
+    }
+
+    pub fn try_from_values(values: NumberColumn, dimension: usize) -> Result<Self> {
+        if dimension == 0 || values.len() % dimension != 0 {
+            return Err(ErrorCode::Internal(format!(
+                "The number of vector values {} is not a multiple of the dimension {}",
+                values.len(),
+                dimension
+            )));
+        }
+        match values {
+            NumberColumn::Int8(values) => Ok(VectorColumn::Int8(values, dimension)),
+            NumberColumn::Float32(values) => Ok(VectorColumn::Float32(values, dimension)),
+            values => Err(ErrorCode::Internal(format!(
+                "Unsupported vector values type {}",
+                values.data_type()
+            ))),
+        }
+    }
+}
+
+impl PartialOrd for VectorColumn {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.data_type() != other.data_type() {
+            return None;
+        }
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl VectorColumnBuilder {
+    pub fn from_column(col: VectorColumn) -> Self {
+        crate::with_vector_type!(|VECTOR_TYPE| match col {
+            VectorColumn::VECTOR_TYPE(values, dimension) =>
+                VectorColumnBuilder::VECTOR_TYPE(buffer_into_mut(values), dimension),
+        })
+    }
+
+    pub fn with_capacity(ty: &VectorDataType, capacity: usize) -> Self {
+        crate::with_vector_type!(|VECTOR_TYPE| match ty {
+            VectorDataType::VECTOR_TYPE(dimension) => {
+                let dimension = *dimension as usize;
+                VectorColumnBuilder::VECTOR_TYPE(
+                    Vec::with_capacity(capacity * dimension),
+                    dimension,
+                )
+            }
+        })
+    }
+
+    pub fn repeat(scalar: VectorScalarRef<'_>, n: usize) -> Self {
+        let mut builder = Self::with_capacity(&scalar.data_type(), n);
+        builder.push_repeat(scalar, n);
+        builder
+    }
+
+    pub fn repeat_default(ty: &VectorDataType, n: usize) -> Self {
+        let mut builder = Self::with_capacity(ty, n);
+        for _ in 0..n {
+            builder.push_default();
+        }
+        builder
+    }
+
+    pub fn dimension(&self) -> usize {
+        match self {
+            VectorColumnBuilder::Int8(_, dimension)
+            | VectorColumnBuilder::Float32(_, dimension) => *dimension,
+        }
+    }
+
+    pub fn data_type(&self) -> VectorDataType {
+        crate::with_vector_type!(|VECTOR_TYPE| match self {
+            VectorColumnBuilder::VECTOR_TYPE(_, dimension) =>
+                VectorDataType::VECTOR_TYPE(*dimension as u64),
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        crate::with_vector_type!(|VECTOR_TYPE| match self {
+            VectorColumnBuilder::VECTOR_TYPE(values, dimension) => values.len() / *dimension,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn memory_size(&self) -> usize {
+        match self {
+            VectorColumnBuilder::Int8(values, _) => values.len(),
+            VectorColumnBuilder::Float32(values, _) => values.len() * 4,
+        }
+    }
+
+    pub fn push(&mut self, item: VectorScalarRef<'_>) {
+        self.push_repeat(item, 1)
+    }
+
+    pub fn push_repeat(&mut self, item: VectorScalarRef<'_>, n: usize) {
+        crate::with_vector_type!(|VECTOR_TYPE| match (self, item) {
+            (
+                VectorColumnBuilder::VECTOR_TYPE(builder, dimension),
+                VectorScalarRef::VECTOR_TYPE(values),
+            ) => {
+                debug_assert_eq!(*dimension, values.len());
+                for _ in 0..n {
+                    builder.extend_from_slice(values);
+                }
+            }
+            (builder, scalar) => unreachable!("unable to push {scalar:?} to {builder:?}"),
+        })
+    }
+
+    pub fn push_default(&mut self) {
+        match self {
+            VectorColumnBuilder::Int8(builder, dimension) => {
+                builder.resize(builder.len() + *dimension, 0)
+            }
+            VectorColumnBuilder::Float32(builder, dimension) => {
+                builder.resize(builder.len() + *dimension, F32::from(0.0))
+            }
+        }
+    }
+
+    /// Push the values in the range as one row, the number type of the values
+    /// must be the same as the vector.
+    pub fn push_number_values(&mut self, values: &NumberColumn, range: Range<usize>) {
+        crate::with_vector_type!(|VECTOR_TYPE| match (self, values) {
+            (
+                VectorColumnBuilder::VECTOR_TYPE(builder, dimension),
+                NumberColumn::VECTOR_TYPE(values),
+            ) => {
+                debug_assert_eq!(*dimension, range.len());
+                builder.extend_from_slice(&values[range]);
+            }
+            (builder, values) => unreachable!(
+                "unable push values(data type: {:?}) into builder(data type: {:?})",
+                values.data_type(),
+                builder.data_type()
+            ),
+        })
+    }
+
+    pub fn append_column(&mut self, other: &VectorColumn) {
+        crate::with_vector_type!(|VECTOR_TYPE| match (self, other) {
+            (
+                VectorColumnBuilder::VECTOR_TYPE(builder, builder_dimension),
+                VectorColumn::VECTOR_TYPE(other, other_dimension),
+            ) => {
+                debug_assert_eq!(builder_dimension, other_dimension);
+                builder.extend_from_slice(other);
+            }
+            (builder, other) => unreachable!(
+                "unable append column(data type: {:?}) into builder(data type: {:?})",
+                other.data_type(),
+                builder.data_type()
+            ),
+        })
+    }
+
+    pub fn build(self) -> VectorColumn {
+        crate::with_vector_type!(|VECTOR_TYPE| match self {
+            VectorColumnBuilder::VECTOR_TYPE(builder, dimension) =>
+                VectorColumn::VECTOR_TYPE(builder.into(), dimension),
+        })
+    }
+
+    pub fn build_scalar(self) -> VectorScalar {
+        assert_eq!(self.len(), 1);
+        crate::with_vector_type!(|VECTOR_TYPE| match self {
+            VectorColumnBuilder::VECTOR_TYPE(builder, _) => VectorScalar::VECTOR_TYPE(builder),
+        })
+    }
+
+    pub fn pop(&mut self) -> Option<VectorScalar> {
+        if self.is_empty() {
+            return None;
+        }
+        crate::with_vector_type!(|VECTOR_TYPE| match self {
+            VectorColumnBuilder::VECTOR_TYPE(builder, dimension) => {
+                let values = builder.split_off(builder.len() - *dimension);
+                Some(VectorScalar::VECTOR_TYPE(values))
+            }
+        })
+    }
+}
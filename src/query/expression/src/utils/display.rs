@@ -59,6 +59,8 @@ use crate::types::AnyType;
 use crate::types::DataType;
 use crate::types::NumberClass;
 use crate::types::ValueType;
+use crate::types::VectorDataType;
+use crate::types::VectorScalarRef;
 use crate::values::Scalar;
 use crate::values::ScalarRef;
 use crate::values::Value;
@@ -181,6 +183,7 @@ impl Debug for ScalarRef<'_> {
                     .unwrap_or_else(|e| format!("GeozeroError: {:?}", e));
                 write!(f, "{geog:?}")
             }
+            ScalarRef::Vector(v) => write!(f, "{v}"),
         }
     }
 }
@@ -207,6 +210,7 @@ impl Debug for Column {
             Column::Variant(col) => write!(f, "{col:?}"),
             Column::Geometry(col) => write!(f, "{col:?}"),
             Column::Geography(col) => write!(f, "{col:?}"),
+            Column::Vector(col) => write!(f, "{col:?}"),
         }
     }
 }
@@ -277,6 +281,7 @@ impl Display for ScalarRef<'_> {
                     .unwrap_or_else(|e| format!("GeozeroError: {:?}", e));
                 write!(f, "'{geog}'")
             }
+            ScalarRef::Vector(v) => write!(f, "{v}"),
         }
     }
 }
@@ -520,6 +525,7 @@ impl Display for DataType {
             DataType::Variant => write!(f, "Variant"),
             DataType::Geometry => write!(f, "Geometry"),
             DataType::Geography => write!(f, "Geography"),
+            DataType::Vector(vector) => write!(f, "{vector}"),
             DataType::Generic(index) => write!(f, "T{index}"),
         }
     }
@@ -570,6 +576,7 @@ impl Display for TableDataType {
             TableDataType::Interval => write!(f, "Interval"),
             TableDataType::Geometry => write!(f, "Geometry"),
             TableDataType::Geography => write!(f, "Geography"),
+            TableDataType::Vector(vector) => write!(f, "{vector}"),
         }
     }
 }
@@ -604,6 +611,28 @@ impl Display for DecimalDataType {
     }
 }
 
+impl Display for VectorDataType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        match &self {
+            VectorDataType::Int8(dimension) => write!(f, "Vector(Int8, {dimension})"),
+            VectorDataType::Float32(dimension) => write!(f, "Vector({dimension})"),
+        }
+    }
+}
+
+impl Display for VectorScalarRef<'_> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            VectorScalarRef::Int8(values) => write!(f, "[{}]", values.iter().join(", ")),
+            VectorScalarRef::Float32(values) => write!(
+                f,
+                "[{}]",
+                values.iter().map(|v| display_f32(v.0)).join(", ")
+            ),
+        }
+    }
+}
+
 impl Display for NumberClass {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
         match &self {
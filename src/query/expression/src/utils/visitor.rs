@@ -116,6 +116,10 @@ pub trait ValueVisitor {
         self.visit_typed_column::<GeographyType>(column)
     }
 
+    fn visit_vector(&mut self, column: VectorColumn) -> Result<()> {
+        self.visit_typed_column::<AnyType>(Column::Vector(column))
+    }
+
     fn visit_typed_column<T: ValueType>(&mut self, column: <T as ValueType>::Column) -> Result<()>;
 
     fn visit_value(&mut self, value: Value<AnyType>) -> Result<()> {
@@ -146,6 +150,7 @@ pub trait ValueVisitor {
             Column::Variant(column) => self.visit_variant(column),
             Column::Geometry(column) => self.visit_geometry(column),
             Column::Geography(column) => self.visit_geography(column),
+            Column::Vector(column) => self.visit_vector(column),
         }
     }
 }
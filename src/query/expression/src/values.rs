@@ -125,6 +125,7 @@ pub enum Scalar {
     Variant(Vec<u8>),
     Geometry(Vec<u8>),
     Geography(Geography),
+    Vector(VectorScalar),
 }
 
 #[derive(Clone, Default, Eq, EnumAsInner)]
@@ -148,6 +149,7 @@ pub enum ScalarRef<'a> {
     Variant(&'a [u8]),
     Geometry(&'a [u8]),
     Geography(GeographyRef<'a>),
+    Vector(VectorScalarRef<'a>),
 }
 
 #[derive(Clone, EnumAsInner)]
@@ -171,6 +173,7 @@ pub enum Column {
     Variant(BinaryColumn),
     Geometry(BinaryColumn),
     Geography(GeographyColumn),
+    Vector(VectorColumn),
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -213,6 +216,7 @@ pub enum ColumnVec {
     Variant(Vec<BinaryColumn>),
     Geometry(Vec<BinaryColumn>),
     Geography(Vec<GeographyColumn>),
+    Vector(Vec<VectorColumn>),
 }
 
 #[derive(Debug, Clone, EnumAsInner)]
@@ -236,6 +240,7 @@ pub enum ColumnBuilder {
     Variant(BinaryColumnBuilder),
     Geometry(BinaryColumnBuilder),
     Geography(BinaryColumnBuilder),
+    Vector(VectorColumnBuilder),
 }
 
 impl<T: ValueType> Value<T> {
@@ -374,6 +379,7 @@ impl Scalar {
             Scalar::Variant(s) => ScalarRef::Variant(s.as_slice()),
             Scalar::Geometry(s) => ScalarRef::Geometry(s.as_slice()),
             Scalar::Geography(g) => ScalarRef::Geography(g.as_ref()),
+            Scalar::Vector(v) => ScalarRef::Vector(v.as_ref()),
         }
     }
 
@@ -417,6 +423,7 @@ impl Scalar {
             DataType::Variant => Scalar::Variant(vec![]),
             DataType::Geometry => Scalar::Geometry(vec![]),
             DataType::Geography => Scalar::Geography(Geography::default()),
+            DataType::Vector(ty) => Scalar::Vector(ty.default_scalar()),
 
             _ => unimplemented!(),
         }
@@ -438,7 +445,8 @@ impl Scalar {
             | Scalar::Bitmap(_)
             | Scalar::Variant(_)
             | Scalar::Geometry(_)
-            | Scalar::Geography(_) => false,
+            | Scalar::Geography(_)
+            | Scalar::Vector(_) => false,
             Scalar::Array(_) | Scalar::Map(_) | Scalar::Tuple(_) => true,
         }
     }
@@ -495,6 +503,7 @@ impl ScalarRef<'_> {
             ScalarRef::Variant(s) => Scalar::Variant(s.to_vec()),
             ScalarRef::Geometry(s) => Scalar::Geometry(s.to_vec()),
             ScalarRef::Geography(s) => Scalar::Geography(s.to_owned()),
+            ScalarRef::Vector(s) => Scalar::Vector(s.to_owned()),
         }
     }
 
@@ -561,7 +570,8 @@ impl ScalarRef<'_> {
             | ScalarRef::Bitmap(_)
             | ScalarRef::Variant(_)
             | ScalarRef::Geometry(_)
-            | ScalarRef::Geography(_) => Domain::Undefined,
+            | ScalarRef::Geography(_)
+            | ScalarRef::Vector(_) => Domain::Undefined,
         }
     }
 
@@ -593,6 +603,7 @@ impl ScalarRef<'_> {
             ScalarRef::Variant(buf) => buf.len(),
             ScalarRef::Geometry(buf) => buf.len(),
             ScalarRef::Geography(s) => s.0.len(),
+            ScalarRef::Vector(s) => s.memory_size(),
         }
     }
 
@@ -628,6 +639,7 @@ impl ScalarRef<'_> {
             ScalarRef::Variant(_) => DataType::Variant,
             ScalarRef::Geometry(_) => DataType::Geometry,
             ScalarRef::Geography(_) => DataType::Geography,
+            ScalarRef::Vector(s) => DataType::Vector(s.data_type()),
         }
     }
 
@@ -695,6 +707,9 @@ impl ScalarRef<'_> {
             (ScalarRef::Geometry(_), ScalarRef::Geometry(_)) => Some(DataType::Geometry),
             (ScalarRef::Geography(_), ScalarRef::Geography(_)) => Some(DataType::Geography),
             (ScalarRef::Interval(_), ScalarRef::Interval(_)) => Some(DataType::Interval),
+            (ScalarRef::Vector(s1), ScalarRef::Vector(s2)) if s1.data_type() == s2.data_type() => {
+                Some(DataType::Vector(s1.data_type()))
+            }
             _ => None,
         }
     }
@@ -719,6 +734,7 @@ impl ScalarRef<'_> {
                 (ScalarRef::Variant(_), DataType::Variant) => true,
                 (ScalarRef::Geometry(_), DataType::Geometry) => true,
                 (ScalarRef::Geography(_), DataType::Geography) => true,
+                (ScalarRef::Vector(val), DataType::Vector(ty)) => val.data_type() == ty,
                 (ScalarRef::Array(val), DataType::Array(ty)) => val.data_type() == *ty,
                 (ScalarRef::Map(val), DataType::Map(ty)) => val.data_type() == *ty,
                 (ScalarRef::Tuple(val), DataType::Tuple(ty)) => {
@@ -758,6 +774,7 @@ impl PartialOrd for Scalar {
             }
             (Scalar::Geometry(g1), Scalar::Geometry(g2)) => compare_geometry(g1, g2),
             (Scalar::Geography(g1), Scalar::Geography(g2)) => g1.partial_cmp(g2),
+            (Scalar::Vector(v1), Scalar::Vector(v2)) => v1.partial_cmp(v2),
             _ => None,
         }
     }
@@ -796,6 +813,7 @@ impl<'b> PartialOrd<ScalarRef<'b>> for ScalarRef<'_> {
             (ScalarRef::Geometry(g1), ScalarRef::Geometry(g2)) => compare_geometry(g1, g2),
             (ScalarRef::Geography(g1), ScalarRef::Geography(g2)) => g1.partial_cmp(g2),
             (ScalarRef::Interval(i1), ScalarRef::Interval(i2)) => i1.partial_cmp(i2),
+            (ScalarRef::Vector(v1), ScalarRef::Vector(v2)) => v1.partial_cmp(v2),
 
             // By default, null is biggest in pgsql
             (ScalarRef::Null, _) => Some(Ordering::Greater),
@@ -852,6 +870,7 @@ impl Hash for ScalarRef<'_> {
             ScalarRef::Variant(v) => v.hash(state),
             ScalarRef::Geometry(v) => v.hash(state),
             ScalarRef::Geography(v) => v.hash(state),
+            ScalarRef::Vector(v) => v.hash(state),
         }
     }
 }
@@ -900,6 +919,7 @@ impl PartialOrd for Column {
             (Column::Geography(col1), Column::Geography(col2)) => {
                 col1.iter().partial_cmp(col2.iter())
             }
+            (Column::Vector(col1), Column::Vector(col2)) => col1.partial_cmp(col2),
             (a, b) => {
                 if a.len() != b.len() {
                     a.len().partial_cmp(&b.len())
@@ -951,6 +971,7 @@ impl Column {
             Column::Variant(col) => col.len(),
             Column::Geometry(col) => col.len(),
             Column::Geography(col) => col.len(),
+            Column::Vector(col) => col.len(),
         }
     }
 
@@ -980,6 +1001,7 @@ impl Column {
             Column::Variant(col) => Some(ScalarRef::Variant(col.index(index)?)),
             Column::Geometry(col) => Some(ScalarRef::Geometry(col.index(index)?)),
             Column::Geography(col) => Some(ScalarRef::Geography(col.index(index)?)),
+            Column::Vector(col) => Some(ScalarRef::Vector(col.index(index)?)),
         }
     }
 
@@ -1012,6 +1034,7 @@ impl Column {
             Column::Variant(col) => ScalarRef::Variant(col.index_unchecked(index)),
             Column::Geometry(col) => ScalarRef::Geometry(col.index_unchecked(index)),
             Column::Geography(col) => ScalarRef::Geography(col.index_unchecked(index)),
+            Column::Vector(col) => ScalarRef::Vector(col.index_unchecked(index)),
         }
     }
 
@@ -1069,6 +1092,7 @@ impl Column {
             Column::Variant(col) => Column::Variant(col.slice(range)),
             Column::Geometry(col) => Column::Geometry(col.slice(range)),
             Column::Geography(col) => Column::Geography(col.slice(range)),
+            Column::Vector(col) => Column::Vector(col.slice(range)),
         }
     }
 
@@ -1156,7 +1180,8 @@ impl Column {
             | Column::Bitmap(_)
             | Column::Variant(_)
             | Column::Geometry(_)
-            | Column::Geography(_) => Domain::Undefined,
+            | Column::Geography(_)
+            | Column::Vector(_) => Domain::Undefined,
         }
     }
 
@@ -1198,6 +1223,7 @@ impl Column {
             Column::Variant(_) => DataType::Variant,
             Column::Geometry(_) => DataType::Geometry,
             Column::Geography(_) => DataType::Geography,
+            Column::Vector(col) => DataType::Vector(col.data_type()),
         }
     }
 
@@ -1229,6 +1255,20 @@ impl Column {
                 }
                 Ok(())
             }
+            Column::Vector(x) => {
+                let values_len = match x {
+                    VectorColumn::Int8(values, _) => values.len(),
+                    VectorColumn::Float32(values, _) => values.len(),
+                };
+                if x.dimension() == 0 || values_len % x.dimension() != 0 {
+                    return Err(ErrorCode::Internal(format!(
+                        "vector values length {} is not a multiple of dimension {}",
+                        values_len,
+                        x.dimension()
+                    )));
+                }
+                Ok(())
+            }
             _ => Ok(()),
         }
     }
@@ -1422,6 +1462,23 @@ impl Column {
                 }
                 Column::Geography(GeographyColumn(builder.build()))
             }
+            DataType::Vector(vector_ty) => {
+                let dimension = vector_ty.dimension() as usize;
+                match vector_ty {
+                    VectorDataType::Int8(_) => {
+                        let values = (0..len * dimension)
+                            .map(|_| rng.gen::<i8>())
+                            .collect::<Vec<i8>>();
+                        Column::Vector(VectorColumn::Int8(values.into(), dimension))
+                    }
+                    VectorDataType::Float32(_) => {
+                        let values = (0..len * dimension)
+                            .map(|_| F32::from(rng.gen::<f32>()))
+                            .collect::<Vec<F32>>();
+                        Column::Vector(VectorColumn::Float32(values.into(), dimension))
+                    }
+                }
+            }
             DataType::Generic(_) => unreachable!(),
         }
     }
@@ -1481,6 +1538,7 @@ impl Column {
             Column::Variant(col) => col.memory_size(),
             Column::Geometry(col) => col.memory_size(),
             Column::Geography(col) => GeographyType::column_memory_size(col),
+            Column::Vector(col) => col.memory_size(),
         }
     }
 
@@ -1501,6 +1559,7 @@ impl Column {
             Column::Interval(col) => col.len() * 16,
             Column::Decimal(DecimalColumn::Decimal256(col, _)) => col.len() * 32,
             Column::Geography(col) => GeographyType::column_memory_size(col),
+            Column::Vector(col) => col.memory_size(),
             Column::Boolean(c) => c.len(),
             // 8 * len + size of bytes
             Column::Binary(col)
@@ -1619,6 +1678,7 @@ impl ColumnBuilder {
             Column::Geography(col) => {
                 ColumnBuilder::Geography(GeographyType::column_to_builder(col))
             }
+            Column::Vector(col) => ColumnBuilder::Vector(VectorColumnBuilder::from_column(col)),
         }
     }
 
@@ -1680,6 +1740,7 @@ impl ColumnBuilder {
             ScalarRef::Geography(s) => {
                 ColumnBuilder::Geography(BinaryColumnBuilder::repeat(s.0, n))
             }
+            ScalarRef::Vector(s) => ColumnBuilder::Vector(VectorColumnBuilder::repeat(*s, n)),
         }
     }
 
@@ -1704,6 +1765,7 @@ impl ColumnBuilder {
             ColumnBuilder::Variant(builder) => builder.len(),
             ColumnBuilder::Geometry(builder) => builder.len(),
             ColumnBuilder::Geography(builder) => builder.len(),
+            ColumnBuilder::Vector(builder) => builder.len(),
         }
     }
 
@@ -1742,6 +1804,7 @@ impl ColumnBuilder {
             ColumnBuilder::Variant(col) => col.data.len() + col.offsets.len() * 8,
             ColumnBuilder::Geometry(col) => col.data.len() + col.offsets.len() * 8,
             ColumnBuilder::Geography(builder) => builder.memory_size(),
+            ColumnBuilder::Vector(builder) => builder.memory_size(),
         }
     }
 
@@ -1779,6 +1842,7 @@ impl ColumnBuilder {
             ColumnBuilder::Variant(_) => DataType::Variant,
             ColumnBuilder::Geometry(_) => DataType::Geometry,
             ColumnBuilder::Geography(_) => DataType::Geography,
+            ColumnBuilder::Vector(builder) => DataType::Vector(builder.data_type()),
         }
     }
 
@@ -1862,6 +1926,9 @@ impl ColumnBuilder {
                     data_capacity,
                 ))
             }
+            DataType::Vector(vector_ty) => {
+                ColumnBuilder::Vector(VectorColumnBuilder::with_capacity(vector_ty, capacity))
+            }
             DataType::Generic(_) => {
                 unreachable!("unable to initialize column builder for generic type")
             }
@@ -1903,6 +1970,9 @@ impl ColumnBuilder {
             DataType::Geography => {
                 ColumnBuilder::Geography(BinaryColumnBuilder::repeat_default(len))
             }
+            DataType::Vector(vector_ty) => {
+                ColumnBuilder::Vector(VectorColumnBuilder::repeat_default(vector_ty, len))
+            }
 
             DataType::Array(ty) => ColumnBuilder::Array(Box::new(ArrayColumnBuilder {
                 builder: Self::with_capacity(ty, 0),
@@ -1983,6 +2053,9 @@ impl ColumnBuilder {
             (ColumnBuilder::Geography(builder), ScalarRef::Geography(value)) => {
                 GeographyType::push_item(builder, value);
             }
+            (ColumnBuilder::Vector(builder), ScalarRef::Vector(value)) => {
+                builder.push(value);
+            }
             (builder, scalar) => unreachable!("unable to push {scalar:?} to {builder:?}"),
         }
     }
@@ -2045,6 +2118,9 @@ impl ColumnBuilder {
             (ColumnBuilder::Geography(builder), ScalarRef::Geography(value)) => {
                 GeographyType::push_item_repeat(builder, *value, n);
             }
+            (ColumnBuilder::Vector(builder), ScalarRef::Vector(value)) => {
+                builder.push_repeat(*value, n);
+            }
             (builder, scalar) => unreachable!("unable to push {scalar:?} to {builder:?}"),
         };
     }
@@ -2077,6 +2153,7 @@ impl ColumnBuilder {
             }
             ColumnBuilder::Geometry(builder) => builder.commit_row(),
             ColumnBuilder::Geography(builder) => builder.commit_row(),
+            ColumnBuilder::Vector(builder) => builder.push_default(),
         }
     }
 
@@ -2174,6 +2251,7 @@ impl ColumnBuilder {
                     field.push_binary(reader)?;
                 }
             }
+            ColumnBuilder::Vector(builder) => push_vector_binary(builder, reader)?,
         };
 
         Ok(())
@@ -2288,6 +2366,12 @@ impl ColumnBuilder {
                     }
                 }
             }
+            ColumnBuilder::Vector(builder) => {
+                for row in 0..rows {
+                    let mut reader = &reader[step * row..];
+                    push_vector_binary(builder, &mut reader)?;
+                }
+            }
         }
 
         Ok(())
@@ -2348,6 +2432,7 @@ impl ColumnBuilder {
             ColumnBuilder::Geography(builder) => {
                 builder.pop().map(Geography).map(Scalar::Geography)
             }
+            ColumnBuilder::Vector(builder) => builder.pop().map(Scalar::Vector),
         }
     }
 
@@ -2395,6 +2480,9 @@ impl ColumnBuilder {
             (ColumnBuilder::Interval(builder), Column::Interval(other)) => {
                 builder.extend_from_slice(other);
             }
+            (ColumnBuilder::Vector(builder), Column::Vector(other)) => {
+                builder.append_column(other);
+            }
             (ColumnBuilder::Array(builder), Column::Array(other)) => {
                 builder.append_column(other.as_ref());
             }
@@ -2446,6 +2534,7 @@ impl ColumnBuilder {
             ColumnBuilder::Variant(b) => Column::Variant(VariantType::build_column(b)),
             ColumnBuilder::Geometry(b) => Column::Geometry(GeometryType::build_column(b)),
             ColumnBuilder::Geography(b) => Column::Geography(GeographyType::build_column(b)),
+            ColumnBuilder::Vector(b) => Column::Vector(b.build()),
         }
     }
 
@@ -2477,8 +2566,28 @@ impl ColumnBuilder {
             ColumnBuilder::Variant(b) => Scalar::Variant(VariantType::build_scalar(b)),
             ColumnBuilder::Geometry(b) => Scalar::Geometry(GeometryType::build_scalar(b)),
             ColumnBuilder::Geography(b) => Scalar::Geography(GeographyType::build_scalar(b)),
+            ColumnBuilder::Vector(b) => Scalar::Vector(b.build_scalar()),
+        }
+    }
+}
+
+// The values of the vector are serialized without length prefix, as the dimension is fixed.
+fn push_vector_binary(builder: &mut VectorColumnBuilder, reader: &mut &[u8]) -> Result<()> {
+    match builder {
+        VectorColumnBuilder::Int8(values, dimension) => {
+            for _ in 0..*dimension {
+                let value: i8 = reader.read_scalar()?;
+                values.push(value);
+            }
+        }
+        VectorColumnBuilder::Float32(values, dimension) => {
+            for _ in 0..*dimension {
+                let value: f32 = reader.read_scalar()?;
+                values.push(F32::from(value));
+            }
         }
     }
+    Ok(())
 }
 
 pub struct ColumnIterator<'a> {
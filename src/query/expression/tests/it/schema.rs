@@ -595,6 +595,66 @@ fn test_schema_modify_field() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_schema_widen_column() -> Result<()> {
+    let mut schema = TableSchema::new(vec![
+        TableField::new("a", TableDataType::Number(NumberDataType::Int32)),
+        TableField::new("b", TableDataType::String),
+    ]);
+    assert_eq!(schema.next_column_id(), 2);
+
+    // widen column a twice, each step assigns a new column id
+    schema.widen_column("a", TableDataType::Number(NumberDataType::Int64))?;
+    assert_eq!(schema.column_id_of("a").unwrap(), 2);
+    assert_eq!(schema.next_column_id(), 3);
+    schema.widen_column(
+        "a",
+        TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::Int64))),
+    )?;
+    assert_eq!(schema.column_id_of("a").unwrap(), 3);
+    assert_eq!(schema.to_leaf_column_ids(), vec![3, 1]);
+
+    let sources = schema.widened_column_sources(3);
+    assert_eq!(
+        sources
+            .iter()
+            .map(|f| (f.column_id, f.data_type.clone()))
+            .collect::<Vec<_>>(),
+        vec![
+            (2, TableDataType::Number(NumberDataType::Int64)),
+            (0, TableDataType::Number(NumberDataType::Int32)),
+        ]
+    );
+    assert!(schema.widened_column_sources(1).is_empty());
+    assert!(schema.is_widened_column_source(0));
+    assert!(schema.is_widened_column_source(2));
+    assert!(!schema.is_widened_column_source(1));
+    assert!(schema.is_column_deleted(0));
+
+    // nested types are not supported
+    schema.add_columns(&[TableField::new(
+        "c",
+        TableDataType::Array(Box::new(TableDataType::Number(NumberDataType::Int32))),
+    )])?;
+    let err = schema
+        .widen_column(
+            "c",
+            TableDataType::Array(Box::new(TableDataType::Number(NumberDataType::Int64))),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.message(),
+        "cannot widen column c from Array(Int32) to Array(Int64), nested data types are not supported"
+    );
+
+    // dropping the column drops the whole chain
+    schema.drop_column("a")?;
+    assert!(schema.widened_columns.is_empty());
+    assert!(!schema.is_widened_column_source(0));
+
+    Ok(())
+}
+
 #[test]
 fn test_leaf_columns_of() -> Result<()> {
     let fields = vec![
@@ -18,6 +18,9 @@ use bstr::ByteSlice;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::timestamp::clamp_timestamp;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::VectorColumnBuilder;
+use databend_common_expression::ColumnBuilder;
 use databend_common_io::cursor_ext::read_num_text_exact;
 use databend_common_io::cursor_ext::BufferReadDateTimeExt;
 use databend_common_io::cursor_ext::DateTimeResType;
@@ -57,3 +60,27 @@ pub(crate) fn read_timestamp(
     column.push(ts);
     Ok(())
 }
+
+/// Create a builder to hold the elements of one vector row before they are checked.
+pub(crate) fn new_vector_values_builder(column: &VectorColumnBuilder) -> ColumnBuilder {
+    let inner_type = DataType::Number(column.data_type().inner_data_type());
+    ColumnBuilder::with_capacity(&inner_type, column.dimension())
+}
+
+pub(crate) fn push_vector_values(
+    column: &mut VectorColumnBuilder,
+    values: ColumnBuilder,
+) -> Result<()> {
+    let values = values.build();
+    if values.len() != column.dimension() {
+        return Err(ErrorCode::BadBytes(format!(
+            "fail to deserialize vector, expect {} elements, but got {}",
+            column.dimension(),
+            values.len()
+        )));
+    }
+    let len = values.len();
+    let values = values.into_number().unwrap();
+    column.push_number_values(&values, 0..len);
+    Ok(())
+}
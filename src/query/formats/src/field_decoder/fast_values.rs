@@ -40,6 +40,7 @@ use databend_common_expression::types::string::StringColumnBuilder;
 use databend_common_expression::types::AnyType;
 use databend_common_expression::types::MutableBitmap;
 use databend_common_expression::types::NumberColumnBuilder;
+use databend_common_expression::types::VectorColumnBuilder;
 use databend_common_expression::with_decimal_type;
 use databend_common_expression::with_number_mapped_type;
 use databend_common_expression::ColumnBuilder;
@@ -62,6 +63,8 @@ use jsonb::parse_value;
 use lexical_core::FromLexical;
 use num_traits::NumCast;
 
+use crate::field_decoder::common::new_vector_values_builder;
+use crate::field_decoder::common::push_vector_values;
 use crate::field_decoder::common::read_timestamp;
 use crate::FieldDecoder;
 use crate::InputCommonSettings;
@@ -156,6 +159,7 @@ impl FastFieldDecoderValues {
             ColumnBuilder::Geography(c) => self.read_geography(c, reader, positions),
             ColumnBuilder::Binary(_) => Err(ErrorCode::Unimplemented("binary literal")),
             ColumnBuilder::Interval(c) => self.read_interval(c, reader, positions),
+            ColumnBuilder::Vector(c) => self.read_vector(c, reader, positions),
             ColumnBuilder::EmptyArray { .. } | ColumnBuilder::EmptyMap { .. } => {
                 Err(ErrorCode::Unimplemented("empty array/map literal"))
             }
@@ -352,6 +356,28 @@ impl FastFieldDecoderValues {
         Ok(())
     }
 
+    fn read_vector<R: AsRef<[u8]>>(
+        &self,
+        column: &mut VectorColumnBuilder,
+        reader: &mut Cursor<R>,
+        positions: &mut VecDeque<usize>,
+    ) -> Result<()> {
+        let mut values = new_vector_values_builder(column);
+        reader.must_ignore_byte(b'[')?;
+        for idx in 0.. {
+            let _ = reader.ignore_white_spaces_or_comments();
+            if reader.ignore_byte(b']') {
+                break;
+            }
+            if idx != 0 {
+                reader.must_ignore_byte(b',')?;
+            }
+            let _ = reader.ignore_white_spaces_or_comments();
+            self.read_field(&mut values, reader, positions)?;
+        }
+        push_vector_values(column, values)
+    }
+
     fn read_map<R: AsRef<[u8]>>(
         &self,
         column: &mut ArrayColumnBuilder<AnyType>,
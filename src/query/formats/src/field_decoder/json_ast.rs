@@ -32,6 +32,7 @@ use databend_common_expression::types::timestamp::clamp_timestamp;
 use databend_common_expression::types::AnyType;
 use databend_common_expression::types::MutableBitmap;
 use databend_common_expression::types::NumberColumnBuilder;
+use databend_common_expression::types::VectorColumnBuilder;
 use databend_common_expression::with_decimal_type;
 use databend_common_expression::with_number_mapped_type;
 use databend_common_expression::ColumnBuilder;
@@ -48,6 +49,8 @@ use num_traits::NumCast;
 use roaring::RoaringTreemap;
 use serde_json::Value;
 
+use crate::field_decoder::common::new_vector_values_builder;
+use crate::field_decoder::common::push_vector_values;
 use crate::FieldDecoder;
 use crate::FileFormatOptionsExt;
 
@@ -98,6 +101,7 @@ impl FieldJsonAstDecoder {
             ColumnBuilder::Binary(_c) => unimplemented!("binary literal is not supported"),
             ColumnBuilder::String(c) => self.read_string(c, value),
             ColumnBuilder::Array(c) => self.read_array(c, value),
+            ColumnBuilder::Vector(c) => self.read_vector(c, value),
             ColumnBuilder::Map(c) => self.read_map(c, value),
             ColumnBuilder::Tuple(fields) => self.read_tuple(fields, value),
             ColumnBuilder::Bitmap(c) => self.read_bitmap(c, value),
@@ -378,6 +382,19 @@ impl FieldJsonAstDecoder {
         }
     }
 
+    fn read_vector(&self, column: &mut VectorColumnBuilder, value: &Value) -> Result<()> {
+        match value {
+            Value::Array(vals) => {
+                let mut values = new_vector_values_builder(column);
+                for val in vals {
+                    self.read_field(&mut values, val)?;
+                }
+                push_vector_values(column, values)
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect json value, must be array")),
+        }
+    }
+
     fn read_map(&self, column: &mut ArrayColumnBuilder<AnyType>, value: &Value) -> Result<()> {
         const KEY: usize = 0;
         const VALUE: usize = 1;
@@ -35,6 +35,7 @@ use databend_common_expression::types::string::StringColumnBuilder;
 use databend_common_expression::types::AnyType;
 use databend_common_expression::types::MutableBitmap;
 use databend_common_expression::types::NumberColumnBuilder;
+use databend_common_expression::types::VectorColumnBuilder;
 use databend_common_expression::with_decimal_type;
 use databend_common_expression::with_number_mapped_type;
 use databend_common_expression::ColumnBuilder;
@@ -55,6 +56,8 @@ use jsonb::parse_value;
 use lexical_core::FromLexical;
 
 use crate::binary::decode_binary;
+use crate::field_decoder::common::new_vector_values_builder;
+use crate::field_decoder::common::push_vector_values;
 use crate::field_decoder::common::read_timestamp;
 use crate::FileFormatOptionsExt;
 use crate::InputCommonSettings;
@@ -135,6 +138,7 @@ impl NestedValues {
             ColumnBuilder::Binary(c) => self.read_binary(c, reader),
             ColumnBuilder::String(c) => self.read_string(c, reader),
             ColumnBuilder::Array(c) => self.read_array(c, reader),
+            ColumnBuilder::Vector(c) => self.read_vector(c, reader),
             ColumnBuilder::Map(c) => self.read_map(c, reader),
             ColumnBuilder::Bitmap(c) => self.read_bitmap(c, reader),
             ColumnBuilder::Tuple(fields) => self.read_tuple(fields, reader),
@@ -382,6 +386,27 @@ impl NestedValues {
         Ok(())
     }
 
+    pub(crate) fn read_vector<R: AsRef<[u8]>>(
+        &self,
+        column: &mut VectorColumnBuilder,
+        reader: &mut Cursor<R>,
+    ) -> Result<()> {
+        let mut values = new_vector_values_builder(column);
+        reader.must_ignore_byte(b'[')?;
+        for idx in 0.. {
+            let _ = reader.ignore_white_spaces_or_comments();
+            if reader.ignore_byte(b']') {
+                break;
+            }
+            if idx != 0 {
+                reader.must_ignore_byte(b',')?;
+            }
+            let _ = reader.ignore_white_spaces_or_comments();
+            self.read_field(&mut values, reader)?;
+        }
+        push_vector_values(column, values)
+    }
+
     pub(crate) fn read_map<R: AsRef<[u8]>>(
         &self,
         column: &mut ArrayColumnBuilder<AnyType>,
@@ -33,6 +33,7 @@ use databend_common_expression::types::AnyType;
 use databend_common_expression::types::MutableBitmap;
 use databend_common_expression::types::Number;
 use databend_common_expression::types::NumberColumnBuilder;
+use databend_common_expression::types::VectorColumnBuilder;
 use databend_common_expression::with_decimal_type;
 use databend_common_expression::with_number_mapped_type;
 use databend_common_expression::ColumnBuilder;
@@ -110,6 +111,24 @@ impl SeparatedTextDecoder {
         }
     }
 
+    /// For the text of XML elements and attributes, which have no representation for NULL.
+    pub fn create_xml(options_ext: &FileFormatOptionsExt) -> Self {
+        SeparatedTextDecoder {
+            common_settings: InputCommonSettings {
+                null_if: vec![],
+                true_bytes: TRUE_BYTES_LOWER.as_bytes().to_vec(),
+                false_bytes: FALSE_BYTES_LOWER.as_bytes().to_vec(),
+                timezone: options_ext.timezone,
+                jiff_timezone: options_ext.jiff_timezone.clone(),
+                disable_variant_check: options_ext.disable_variant_check,
+                binary_format: Default::default(),
+                is_rounding_mode: options_ext.is_rounding_mode,
+                enable_dst_hour_fix: options_ext.enable_dst_hour_fix,
+            },
+            nested_decoder: NestedValues::create(options_ext),
+        }
+    }
+
     fn common_settings(&self) -> &InputCommonSettings {
         &self.common_settings
     }
@@ -148,6 +167,7 @@ impl SeparatedTextDecoder {
             ColumnBuilder::Interval(c) => self.read_interval(c, data),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, data),
             ColumnBuilder::Array(c) => self.read_array(c, data),
+            ColumnBuilder::Vector(c) => self.read_vector(c, data),
             ColumnBuilder::Map(c) => self.read_map(c, data),
             ColumnBuilder::Bitmap(c) => self.read_bitmap(c, data),
             ColumnBuilder::Tuple(fields) => self.read_tuple(fields, data),
@@ -320,6 +340,11 @@ impl SeparatedTextDecoder {
         self.nested_decoder.read_array(column, &mut cursor)
     }
 
+    fn read_vector(&self, column: &mut VectorColumnBuilder, data: &[u8]) -> Result<()> {
+        let mut cursor = Cursor::new(data);
+        self.nested_decoder.read_vector(column, &mut cursor)
+    }
+
     fn read_map(&self, column: &mut ArrayColumnBuilder<AnyType>, data: &[u8]) -> Result<()> {
         let mut cursor = Cursor::new(data);
         self.nested_decoder.read_map(column, &mut cursor)
@@ -101,7 +101,8 @@ impl FieldEncoderJSON {
             | Column::EmptyMap { .. }
             | Column::Number(_)
             | Column::Decimal(_)
-            | Column::Boolean(_) => self.simple.write_field(column, row_index, out_buf, false),
+            | Column::Boolean(_)
+            | Column::Vector(_) => self.simple.write_field(column, row_index, out_buf, false),
         }
     }
 
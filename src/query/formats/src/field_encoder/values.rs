@@ -28,6 +28,7 @@ use databend_common_expression::types::Bitmap;
 use databend_common_expression::types::Buffer;
 use databend_common_expression::types::NumberColumn;
 use databend_common_expression::types::ValueType;
+use databend_common_expression::types::VectorColumn;
 use databend_common_expression::Column;
 use databend_common_io::constants::FALSE_BYTES_NUM;
 use databend_common_io::constants::INF_BYTES_LONG;
@@ -161,6 +162,7 @@ impl FieldEncoderValues {
             Column::Geometry(c) => self.write_geometry(c, row_index, out_buf, in_nested),
             Column::Geography(c) => self.write_geography(c, row_index, out_buf, in_nested),
 
+            Column::Vector(c) => self.write_vector(c, row_index, out_buf),
             Column::Array(box c) => self.write_array(c, row_index, out_buf),
             Column::Map(box c) => self.write_map(c, row_index, out_buf),
             Column::Tuple(fields) => self.write_tuple(fields, row_index, out_buf),
@@ -391,6 +393,21 @@ impl FieldEncoderValues {
         out_buf.push(b']');
     }
 
+    fn write_vector(&self, column: &VectorColumn, row_index: usize, out_buf: &mut Vec<u8>) {
+        let dimension = column.dimension();
+        let start = row_index * dimension;
+        let end = start + dimension;
+        out_buf.push(b'[');
+        let inner = &Column::Number(column.values());
+        for i in start..end {
+            if i != start {
+                out_buf.extend_from_slice(b",");
+            }
+            self.write_field(inner, i, out_buf, true);
+        }
+        out_buf.push(b']');
+    }
+
     fn write_map<T: ValueType>(
         &self,
         column: &ArrayColumn<T>,
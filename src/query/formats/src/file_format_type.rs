@@ -191,7 +191,9 @@ impl FileFormatOptionsExt {
                     _ => unreachable!(),
                 }
             }
-            FileFormatParams::Parquet(_) => Box::new(ParquetOutputFormat::create(schema, self)),
+            FileFormatParams::Parquet(params) => {
+                Box::new(ParquetOutputFormat::create(schema, params, self))
+            }
             FileFormatParams::Json(_) => Box::new(JSONOutputFormat::create(schema, self)),
             others => {
                 return Err(ErrorCode::InvalidArgument(format!(
@@ -15,6 +15,7 @@
 use databend_common_expression::date_helper::DateConverter;
 use databend_common_expression::types::interval::interval_to_string;
 use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::VectorScalarRef;
 use databend_common_expression::DataBlock;
 use databend_common_expression::ScalarRef;
 use databend_common_expression::TableSchemaRef;
@@ -116,6 +117,23 @@ fn scalar_to_json(s: ScalarRef<'_>, format: &FormatSettings) -> JsonValue {
                 .collect();
             JsonValue::Array(vals)
         }
+        ScalarRef::Vector(x) => {
+            let vals = match x {
+                VectorScalarRef::Int8(vals) => vals
+                    .iter()
+                    .map(|v| JsonValue::Number((*v).into()))
+                    .collect(),
+                VectorScalarRef::Float32(vals) => vals
+                    .iter()
+                    .map(|v| {
+                        JsonValue::Number(
+                            serde_json::Number::from_f64(f32::from(*v) as f64).unwrap(),
+                        )
+                    })
+                    .collect(),
+            };
+            JsonValue::Array(vals)
+        }
         ScalarRef::Map(x) => {
             let vals = x
                 .iter()
@@ -25,6 +25,7 @@ pub use csv::CSVWithNamesAndTypesOutputFormat;
 pub use csv::CSVWithNamesOutputFormat;
 pub use json::JSONOutputFormat;
 pub use ndjson::NDJSONOutputFormatBase;
+pub use parquet::set_parquet_write_options;
 pub use parquet::ParquetOutputFormat;
 pub use tsv::TSVOutputFormat;
 pub use tsv::TSVWithNamesAndTypesOutputFormat;
@@ -12,12 +12,27 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRef;
 use databend_common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
-use databend_storages_common_blocks::blocks_to_parquet;
-use databend_storages_common_table_meta::table::TableCompression;
+use databend_common_meta_app::principal::ParquetFileFormatParams;
+use databend_common_meta_app::principal::ParquetSortingColumn;
+use databend_common_meta_app::principal::StageFileCompression;
+use databend_storages_common_blocks::blocks_to_parquet_with_properties;
+use parquet::basic::BrotliLevel;
+use parquet::basic::Compression;
+use parquet::basic::Encoding;
+use parquet::basic::GzipLevel;
+use parquet::basic::ZstdLevel;
+use parquet::file::properties::EnabledStatistics;
+use parquet::file::properties::WriterProperties;
+use parquet::file::properties::WriterPropertiesBuilder;
+use parquet::format::SortingColumn;
+use parquet::schema::types::ColumnPath;
 
 use crate::output_format::OutputFormat;
 use crate::FileFormatOptionsExt;
@@ -25,18 +40,122 @@ use crate::FileFormatOptionsExt;
 #[derive(Default)]
 pub struct ParquetOutputFormat {
     schema: TableSchemaRef,
+    params: ParquetFileFormatParams,
     data_blocks: Vec<DataBlock>,
 }
 
 impl ParquetOutputFormat {
-    pub fn create(schema: TableSchemaRef, _options: &FileFormatOptionsExt) -> Self {
+    pub fn create(
+        schema: TableSchemaRef,
+        params: &ParquetFileFormatParams,
+        _options: &FileFormatOptionsExt,
+    ) -> Self {
         Self {
             schema,
+            params: params.clone(),
             data_blocks: vec![],
         }
     }
 }
 
+/// Apply the unloading options of `params` to the writer properties,
+/// options not specified in `params` are left as they are in `builder`.
+pub fn set_parquet_write_options(
+    mut builder: WriterPropertiesBuilder,
+    params: &ParquetFileFormatParams,
+    schema: &TableSchema,
+) -> Result<WriterPropertiesBuilder> {
+    let compression = match params.compression {
+        StageFileCompression::Auto | StageFileCompression::Zstd => {
+            let level = match params.compression_level {
+                Some(level) => ZstdLevel::try_new(level as i32)?,
+                None => ZstdLevel::default(),
+            };
+            Compression::ZSTD(level)
+        }
+        StageFileCompression::Gzip => {
+            let level = match params.compression_level {
+                Some(level) => GzipLevel::try_new(level as u32)?,
+                None => GzipLevel::default(),
+            };
+            Compression::GZIP(level)
+        }
+        StageFileCompression::Brotli => {
+            let level = match params.compression_level {
+                Some(level) => BrotliLevel::try_new(level as u32)?,
+                None => BrotliLevel::default(),
+            };
+            Compression::BROTLI(level)
+        }
+        StageFileCompression::Snappy => Compression::SNAPPY,
+        StageFileCompression::None => Compression::UNCOMPRESSED,
+        other => {
+            return Err(ErrorCode::IllegalFileFormat(format!(
+                "compression {other} is not supported by PARQUET"
+            )));
+        }
+    };
+    builder = builder.set_compression(compression);
+
+    if params.row_group_size > 0 {
+        builder = builder.set_max_row_group_size(params.row_group_size as usize);
+    }
+    if params.enable_page_index {
+        // page statistics are written to the column index, along with the offset index.
+        builder = builder.set_statistics_enabled(EnabledStatistics::Page);
+    }
+    for name in &params.bloom_filter_columns {
+        let (_, field_name) = primitive_leaf_column(schema, name, "BLOOM_FILTER_COLUMNS")?;
+        builder = builder.set_column_bloom_filter_enabled(ColumnPath::new(vec![field_name]), true);
+    }
+    if !params.sorting_columns.is_empty() {
+        let sorting_columns = params
+            .sorting_columns
+            .iter()
+            .map(|s| {
+                let column = ParquetSortingColumn::parse(s)?;
+                let (column_idx, _) =
+                    primitive_leaf_column(schema, &column.name, "SORTING_COLUMNS")?;
+                Ok(SortingColumn {
+                    column_idx: column_idx as i32,
+                    descending: column.descending,
+                    nulls_first: column.nulls_first,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        builder = builder.set_sorting_columns(Some(sorting_columns));
+    }
+    Ok(builder)
+}
+
+/// Find the top level column of primitive type,
+/// return the index of its leaf column in the parquet schema and its name.
+fn primitive_leaf_column(
+    schema: &TableSchema,
+    name: &str,
+    option: &str,
+) -> Result<(usize, String)> {
+    let mut leaf_index = 0;
+    for field in schema.fields() {
+        if field.name().eq_ignore_ascii_case(name) {
+            let data_type = field.data_type().remove_nullable();
+            if matches!(
+                data_type,
+                TableDataType::Array(_) | TableDataType::Map(_) | TableDataType::Tuple { .. }
+            ) {
+                return Err(ErrorCode::IllegalFileFormat(format!(
+                    "column {name} of {option} must be of primitive type, but got {data_type}"
+                )));
+            }
+            return Ok((leaf_index, field.name().clone()));
+        }
+        leaf_index += field.data_type().num_leaf_columns();
+    }
+    Err(ErrorCode::IllegalFileFormat(format!(
+        "column {name} of {option} not found in the unloaded columns"
+    )))
+}
+
 impl OutputFormat for ParquetOutputFormat {
     fn serialize_block(&mut self, block: &DataBlock) -> Result<Vec<u8>> {
         self.data_blocks.push(block.clone());
@@ -53,7 +172,15 @@ impl OutputFormat for ParquetOutputFormat {
             return Ok(vec![]);
         }
         let mut buf = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
-        let _ = blocks_to_parquet(&self.schema, blocks, &mut buf, TableCompression::Zstd)?;
+        let builder = WriterProperties::builder()
+            // use `usize::MAX` to effectively limit the number of row groups to 1
+            .set_max_row_group_size(usize::MAX)
+            .set_encoding(Encoding::PLAIN)
+            .set_dictionary_enabled(false)
+            .set_statistics_enabled(EnabledStatistics::None)
+            .set_bloom_filter_enabled(false);
+        let props = set_parquet_write_options(builder, &self.params, &self.schema)?.build();
+        let _ = blocks_to_parquet_with_properties(&self.schema, blocks, &mut buf, props)?;
         Ok(buf)
     }
 }
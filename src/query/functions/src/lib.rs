@@ -97,7 +97,8 @@ pub const GENERAL_LAMBDA_FUNCTIONS: [Ascii<&str>; 16] = [
     Ascii::new("json_map_transform_values"),
 ];
 
-pub const GENERAL_SEARCH_FUNCTIONS: [Ascii<&str>; 3] = [
+pub const GENERAL_SEARCH_FUNCTIONS: [Ascii<&str>; 4] = [
+    Ascii::new("highlight"),
     Ascii::new("match"),
     Ascii::new("query"),
     Ascii::new("score"),
@@ -38,6 +38,7 @@ pub mod dt_func {
 mod map;
 
 mod other;
+mod search;
 mod string;
 mod string_multi_args;
 mod tuple;
@@ -47,6 +48,10 @@ mod vector;
 pub use comparison::ALL_COMP_FUNC_NAMES;
 use databend_functions_scalar_arithmetic::arithmetic;
 use databend_functions_scalar_numeric_basic_arithmetic::register_numeric_basic_arithmetic;
+pub use search::register_search_analyzer;
+pub use search::AnalyzedTerm;
+pub use search::SearchAnalyzer;
+pub use search::SearchAnalyzerFactory;
 pub use string::ALL_STRING_FUNC_NAMES;
 
 pub fn register(registry: &mut FunctionRegistry) {
@@ -75,6 +80,7 @@ pub fn register(registry: &mut FunctionRegistry) {
     other::register(registry);
     databend_functions_scalar_decimal::register_to_decimal(registry);
     vector::register(registry);
+    search::register(registry);
     bitmap::register(registry);
     geo_func::geometry::register(registry);
     geo_func::geography::register(registry);
@@ -0,0 +1,207 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+use databend_common_expression::types::binary::BinaryColumnBuilder;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::VariantType;
+use databend_common_expression::EvalContext;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionRegistry;
+use databend_common_expression::Value;
+
+/// The max number of characters in the snippet of `highlight` function.
+const MAX_SNIPPET_CHARS: usize = 150;
+
+/// A term of the text tokenized by the analyzer of inverted index,
+/// with the byte offsets in the text.
+pub struct AnalyzedTerm {
+    pub text: String,
+    pub offset_from: usize,
+    pub offset_to: usize,
+}
+
+/// Tokenizes the text in the same way as the inverted index.
+pub type SearchAnalyzer = Box<dyn FnMut(&str) -> Vec<AnalyzedTerm>>;
+
+/// Creates the analyzer from the options of inverted index,
+/// returns None if the tokenizer of the options is unknown.
+pub type SearchAnalyzerFactory = fn(&BTreeMap<String, String>) -> Option<SearchAnalyzer>;
+
+// The tokenizers of inverted index live in the storage,
+// which registers the factory when the query node starts.
+static SEARCH_ANALYZER_FACTORY: OnceLock<SearchAnalyzerFactory> = OnceLock::new();
+
+pub fn register_search_analyzer(factory: SearchAnalyzerFactory) {
+    let _ = SEARCH_ANALYZER_FACTORY.set(factory);
+}
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // The arguments are the text, the query text and the options of the inverted index
+    // encoded as a JSON object, the options are filled by binder from the index of the column.
+    registry.register_passthrough_nullable_3_arg::<StringType, StringType, StringType, VariantType, _, _>(
+        "highlight",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        eval_highlight,
+    );
+}
+
+struct Highlighter {
+    analyzer: SearchAnalyzer,
+    query_terms: HashSet<String>,
+}
+
+impl Highlighter {
+    fn try_create(query: &str, options: &str) -> std::result::Result<Self, String> {
+        let index_options: BTreeMap<String, String> = serde_json::from_str(options)
+            .map_err(|e| format!("invalid inverted index options `{options}`: {e}"))?;
+        let tokenizer_name = index_options
+            .get("tokenizer")
+            .map(|v| v.as_str())
+            .unwrap_or("english");
+        let Some(factory) = SEARCH_ANALYZER_FACTORY.get() else {
+            return Err("the analyzer of inverted index is not registered".to_string());
+        };
+        let Some(mut analyzer) = factory(&index_options) else {
+            return Err(format!(
+                "invalid inverted index tokenizer `{tokenizer_name}`"
+            ));
+        };
+
+        let query_terms = analyzer(query).into_iter().map(|term| term.text).collect();
+
+        Ok(Self {
+            analyzer,
+            query_terms,
+        })
+    }
+
+    // Returns a JSON object with the snippet that has most matched terms,
+    // the matched terms are wrapped with `<b>` and `</b>` in the snippet,
+    // and the character offsets of all the matched terms in the text.
+    fn highlight(&mut self, text: &str) -> jsonb::Value<'static> {
+        // collect the byte ranges of matched terms, overlapped ranges are merged.
+        let mut ranges: Vec<(usize, usize)> = vec![];
+        for term in (self.analyzer)(text) {
+            if self.query_terms.contains(&term.text) {
+                ranges.push((term.offset_from, term.offset_to));
+            }
+        }
+        ranges.sort();
+        let mut merged_ranges: Vec<(usize, usize)> = Vec::with_capacity(ranges.len());
+        for (start, end) in ranges {
+            match merged_ranges.last_mut() {
+                Some(last) if start <= last.1 => last.1 = last.1.max(end),
+                _ => merged_ranges.push((start, end)),
+            }
+        }
+
+        // convert the byte offsets to the character offsets.
+        let mut char_offsets: Vec<usize> = text.char_indices().map(|(i, _)| i).collect();
+        char_offsets.push(text.len());
+        let to_char_offset = |offset: usize| char_offsets.partition_point(|v| *v < offset);
+        let matches: Vec<(usize, usize)> = merged_ranges
+            .iter()
+            .map(|(start, end)| (to_char_offset(*start), to_char_offset(*end)))
+            .collect();
+
+        // choose the fragment starts from a matched term that contains most matched terms.
+        let num_chars = char_offsets.len() - 1;
+        let mut fragment_start = 0;
+        let mut max_count = 0;
+        for (i, (start, _)) in matches.iter().enumerate() {
+            let count = matches[i..]
+                .iter()
+                .take_while(|(_, end)| *end <= start + MAX_SNIPPET_CHARS)
+                .count();
+            if count > max_count {
+                max_count = count;
+                fragment_start = *start;
+            }
+        }
+        let fragment_end = (fragment_start + MAX_SNIPPET_CHARS).min(num_chars);
+        let fragment_start = fragment_end.saturating_sub(MAX_SNIPPET_CHARS);
+
+        let mut snippet = String::new();
+        let mut pos = fragment_start;
+        for (start, end) in matches.iter() {
+            if *start < fragment_start || *end > fragment_end {
+                continue;
+            }
+            snippet.push_str(&text[char_offsets[pos]..char_offsets[*start]]);
+            snippet.push_str("<b>");
+            snippet.push_str(&text[char_offsets[*start]..char_offsets[*end]]);
+            snippet.push_str("</b>");
+            pos = *end;
+        }
+        snippet.push_str(&text[char_offsets[pos]..char_offsets[fragment_end]]);
+
+        let offsets = matches
+            .into_iter()
+            .map(|(start, end)| {
+                jsonb::Value::Array(vec![(start as u64).into(), (end as u64).into()])
+            })
+            .collect();
+        let mut object = jsonb::Object::new();
+        object.insert("snippet".to_string(), jsonb::Value::String(snippet.into()));
+        object.insert("offsets".to_string(), jsonb::Value::Array(offsets));
+        jsonb::Value::Object(object)
+    }
+}
+
+fn eval_highlight(
+    text: Value<StringType>,
+    query: Value<StringType>,
+    options: Value<StringType>,
+    ctx: &mut EvalContext,
+) -> Value<VariantType> {
+    let input_all_scalars =
+        text.as_scalar().is_some() && query.as_scalar().is_some() && options.as_scalar().is_some();
+    let process_rows = if input_all_scalars { 1 } else { ctx.num_rows };
+
+    // the query text and options are usually constants,
+    // so the highlighter is only created again if they are changed.
+    let mut highlighter: Option<(String, String, Highlighter)> = None;
+    let mut builder = BinaryColumnBuilder::with_capacity(process_rows, 0);
+    for index in 0..process_rows {
+        let text = unsafe { text.index_unchecked(index) };
+        let query = unsafe { query.index_unchecked(index) };
+        let options = unsafe { options.index_unchecked(index) };
+
+        let reusable = matches!(&highlighter, Some((q, o, _)) if q == query && o == options);
+        if !reusable {
+            match Highlighter::try_create(query, options) {
+                Ok(h) => highlighter = Some((query.to_string(), options.to_string(), h)),
+                Err(err) => {
+                    highlighter = None;
+                    ctx.set_error(builder.len(), err);
+                    builder.commit_row();
+                    continue;
+                }
+            }
+        }
+        let (_, _, h) = highlighter.as_mut().unwrap();
+        h.highlight(text).write_to_vec(&mut builder.data);
+        builder.commit_row();
+    }
+
+    if input_all_scalars {
+        Value::Scalar(builder.build_scalar())
+    } else {
+        Value::Column(builder.build())
+    }
+}
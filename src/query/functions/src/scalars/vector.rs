@@ -12,17 +12,34 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::AnyType;
 use databend_common_expression::types::ArrayType;
 use databend_common_expression::types::Buffer;
+use databend_common_expression::types::DataType;
 use databend_common_expression::types::Float32Type;
 use databend_common_expression::types::Float64Type;
+use databend_common_expression::types::NumberColumn;
+use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::StringType;
+use databend_common_expression::types::VectorColumn;
+use databend_common_expression::types::VectorScalarRef;
 use databend_common_expression::types::F32;
 use databend_common_expression::types::F64;
 use databend_common_expression::vectorize_with_builder_1_arg;
 use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::Column;
+use databend_common_expression::EvalContext;
+use databend_common_expression::Function;
 use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionEval;
 use databend_common_expression::FunctionRegistry;
+use databend_common_expression::FunctionSignature;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::Value;
 use databend_common_openai::OpenAI;
 use databend_common_vector::cosine_distance;
 use databend_common_vector::cosine_distance_64;
@@ -30,6 +47,11 @@ use databend_common_vector::l2_distance;
 use databend_common_vector::l2_distance_64;
 
 pub fn register(registry: &mut FunctionRegistry) {
+    // The vector overloads are registered first, so that they are preferred over
+    // the array overloads which a vector can also be auto casted to.
+    register_vector_distance(registry, "cosine_distance", cosine_distance);
+    register_vector_distance(registry, "l2_distance", l2_distance);
+
     // cosine_distance
     // This function takes two Float32 arrays as input and computes the cosine distance between them.
     registry.register_passthrough_nullable_2_arg::<ArrayType<Float32Type>, ArrayType<Float32Type>, Float32Type, _, _>(
@@ -227,3 +249,174 @@ pub fn register(registry: &mut FunctionRegistry) {
         }),
     );
 }
+
+/// Register a distance function for `VECTOR` arguments, the other argument can
+/// also be an array, e.g. `cosine_distance(embedding, [0.1, 0.2, 0.3])`.
+fn register_vector_distance(
+    registry: &mut FunctionRegistry,
+    name: &'static str,
+    distance: fn(&[f32], &[f32]) -> Result<f32>,
+) {
+    registry.register_function_factory(name, move |_, args_type| {
+        if args_type.len() != 2 {
+            return None;
+        }
+        let has_null = args_type.iter().any(|t| t.is_nullable_or_null());
+        let args_type = match (
+            args_type[0].remove_nullable(),
+            args_type[1].remove_nullable(),
+        ) {
+            (DataType::Vector(lhs), DataType::Vector(rhs))
+                if lhs.dimension() == rhs.dimension() =>
+            {
+                vec![DataType::Vector(lhs), DataType::Vector(rhs)]
+            }
+            (DataType::Vector(lhs), DataType::Array(_)) => vec![
+                DataType::Vector(lhs),
+                DataType::Array(Box::new(DataType::Number(NumberDataType::Float32))),
+            ],
+            (DataType::Array(_), DataType::Vector(rhs)) => vec![
+                DataType::Array(Box::new(DataType::Number(NumberDataType::Float32))),
+                DataType::Vector(rhs),
+            ],
+            _ => return None,
+        };
+
+        let f = Function {
+            signature: FunctionSignature {
+                name: name.to_string(),
+                args_type,
+                return_type: DataType::Number(NumberDataType::Float32),
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::MayThrow),
+                eval: Box::new(move |args, ctx| vector_distance_fn(args, ctx, distance)),
+            },
+        };
+        if has_null {
+            Some(Arc::new(f.passthrough_nullable()))
+        } else {
+            Some(Arc::new(f))
+        }
+    });
+}
+
+fn vector_distance_fn(
+    args: &[Value<AnyType>],
+    ctx: &mut EvalContext,
+    distance: fn(&[f32], &[f32]) -> Result<f32>,
+) -> Value<AnyType> {
+    let len = args.iter().find_map(|arg| match arg {
+        Value::Column(col) => Some(col.len()),
+        _ => None,
+    });
+
+    let lhs = VectorArg::new(&args[0]);
+    let rhs = VectorArg::new(&args[1]);
+    let mut lhs_buf = Vec::new();
+    let mut rhs_buf = Vec::new();
+
+    let size = len.unwrap_or(1);
+    let mut builder = Vec::with_capacity(size);
+    for idx in 0..size {
+        let lhs = lhs.row(idx, &mut lhs_buf);
+        let rhs = rhs.row(idx, &mut rhs_buf);
+        match distance(lhs, rhs) {
+            Ok(dist) => builder.push(F32::from(dist)),
+            Err(err) => {
+                ctx.set_error(builder.len(), err.to_string());
+                builder.push(F32::from(0.0));
+            }
+        }
+    }
+
+    let column = Column::Number(NumberColumn::Float32(builder.into()));
+    match len {
+        Some(_) => Value::Column(column),
+        None => Value::Scalar(column.index(0).unwrap().to_owned()),
+    }
+}
+
+/// An argument of the vector distance functions, the rows of the columns are
+/// sliced from the values buffer, and a constant vector is converted only once.
+enum VectorArg<'a> {
+    Scalar(Cow<'a, [f32]>),
+    Float32 {
+        values: &'a [f32],
+        dimension: usize,
+    },
+    Int8 {
+        values: &'a [i8],
+        dimension: usize,
+    },
+    Array {
+        values: &'a [f32],
+        offsets: &'a [u64],
+    },
+}
+
+impl<'a> VectorArg<'a> {
+    fn new(arg: &'a Value<AnyType>) -> Self {
+        match arg {
+            Value::Scalar(scalar) => VectorArg::Scalar(vector_values(scalar.as_ref())),
+            Value::Column(Column::Vector(VectorColumn::Float32(values, dimension))) => {
+                VectorArg::Float32 {
+                    values: unsafe { std::mem::transmute::<&[F32], &[f32]>(values.as_slice()) },
+                    dimension: *dimension,
+                }
+            }
+            Value::Column(Column::Vector(VectorColumn::Int8(values, dimension))) => {
+                VectorArg::Int8 {
+                    values: values.as_slice(),
+                    dimension: *dimension,
+                }
+            }
+            Value::Column(Column::Array(array)) => match &array.values {
+                Column::Number(NumberColumn::Float32(values)) => VectorArg::Array {
+                    values: unsafe { std::mem::transmute::<&[F32], &[f32]>(values.as_slice()) },
+                    offsets: array.offsets.as_slice(),
+                },
+                values => unreachable!("unexpected array values {values:?} of vector distance"),
+            },
+            Value::Column(column) => {
+                unreachable!("unexpected argument {column:?} of vector distance")
+            }
+        }
+    }
+
+    /// The values of the row, `buf` holds the converted values of an `Int8` vector.
+    fn row<'b>(&'b self, row: usize, buf: &'b mut Vec<f32>) -> &'b [f32] {
+        match self {
+            VectorArg::Scalar(values) => values.as_ref(),
+            VectorArg::Float32 { values, dimension } => {
+                &values[row * dimension..(row + 1) * dimension]
+            }
+            VectorArg::Int8 { values, dimension } => {
+                buf.clear();
+                buf.extend(
+                    values[row * dimension..(row + 1) * dimension]
+                        .iter()
+                        .map(|v| *v as f32),
+                );
+                buf.as_slice()
+            }
+            VectorArg::Array { values, offsets } => {
+                &values[offsets[row] as usize..offsets[row + 1] as usize]
+            }
+        }
+    }
+}
+
+/// Float32 values are borrowed directly, other element types are converted to `f32`.
+fn vector_values(scalar: ScalarRef<'_>) -> Cow<'_, [f32]> {
+    match scalar {
+        ScalarRef::Vector(VectorScalarRef::Float32(values)) => {
+            Cow::Borrowed(unsafe { std::mem::transmute::<&[F32], &[f32]>(values) })
+        }
+        ScalarRef::Vector(vector) => Cow::Owned(vector.to_f32_values()),
+        ScalarRef::Array(Column::Number(NumberColumn::Float32(values))) => {
+            Cow::Owned(values.iter().map(|v| v.0).collect())
+        }
+        scalar => unreachable!("unexpected argument {scalar:?} of vector distance"),
+    }
+}
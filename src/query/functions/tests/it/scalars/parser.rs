@@ -33,6 +33,7 @@ use databend_common_expression::types::decimal::DecimalSize;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::NumberScalar;
+use databend_common_expression::types::VectorDataType;
 use databend_common_expression::ConstantFolder;
 use databend_common_expression::FunctionContext;
 use databend_common_expression::RawExpr;
@@ -627,6 +628,15 @@ fn transform_data_type(target_type: databend_common_ast::ast::TypeName) -> DataT
         databend_common_ast::ast::TypeName::Variant => DataType::Variant,
         databend_common_ast::ast::TypeName::Geometry => DataType::Geometry,
         databend_common_ast::ast::TypeName::Geography => DataType::Geography,
+        databend_common_ast::ast::TypeName::Vector {
+            element_type,
+            dimension,
+        } => match *element_type {
+            databend_common_ast::ast::TypeName::Int8 => {
+                DataType::Vector(VectorDataType::Int8(dimension))
+            }
+            _ => DataType::Vector(VectorDataType::Float32(dimension)),
+        },
         databend_common_ast::ast::TypeName::NotNull(inner_type) => transform_data_type(*inner_type),
     }
 }
@@ -25,9 +25,11 @@ use databend_common_config::GlobalConfig;
 use databend_common_config::InnerConfig;
 use databend_common_exception::Result;
 use databend_common_exception::StackTrace;
+use databend_common_functions::scalars::register_search_analyzer;
 use databend_common_meta_app::schema::CatalogType;
 use databend_common_storage::DataOperator;
 use databend_common_storage::ShareTableConfig;
+use databend_common_storages_fuse::io::create_search_analyzer;
 use databend_common_storages_hive::HiveCreator;
 use databend_common_storages_iceberg::IcebergCreator;
 use databend_common_storages_system::ProfilesLogQueue;
@@ -159,6 +161,9 @@ impl GlobalServices {
 
         ProfilesLogQueue::init(config.query.max_cached_queries_profiles);
 
+        // The `highlight` function tokenizes the text with the analyzer of the inverted index.
+        register_search_analyzer(create_search_analyzer);
+
         #[cfg(feature = "enable_queries_executor")]
         {
             GlobalQueriesExecutor::init()?;
@@ -1182,6 +1182,9 @@ impl AccessChecker for PrivilegeAccess {
             Plan::CopyIntoTable(plan) => {
                 self.validate_stage_access(&plan.stage_table_info.stage_info, UserPrivilegeType::Read).await?;
                 self.validate_table_access(plan.catalog_info.catalog_name(), &plan.database_name, &plan.table_name, UserPrivilegeType::Insert, false, false).await?;
+                if let Some(error_table) = &plan.error_table {
+                    self.validate_table_access(&error_table.catalog, &error_table.database, &error_table.table, UserPrivilegeType::Insert, false, false).await?;
+                }
                 if let Some(query) = &plan.query {
                     self.check(ctx, query).await?;
                 }
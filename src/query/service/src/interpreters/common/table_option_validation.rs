@@ -19,14 +19,20 @@ use std::sync::LazyLock;
 use chrono::Duration;
 use databend_common_ast::ast::Engine;
 use databend_common_exception::ErrorCode;
+use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRef;
 use databend_common_io::constants::DEFAULT_BLOCK_MAX_ROWS;
 use databend_common_io::constants::DEFAULT_MIN_TABLE_LEVEL_DATA_RETENTION_PERIOD_IN_HOURS;
+use databend_common_meta_app::storage::StorageParams;
 use databend_common_settings::Settings;
 use databend_common_sql::BloomIndexColumns;
+use databend_common_storages_fuse::check_iceberg_export;
+use databend_common_storages_fuse::FuseStorageFormat;
+use databend_common_storages_fuse::FUSE_OPT_KEY_AUTO_VIRTUAL_COLUMNS;
 use databend_common_storages_fuse::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use databend_common_storages_fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 use databend_common_storages_fuse::FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS;
+use databend_common_storages_fuse::FUSE_OPT_KEY_ICEBERG_EXPORT;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_PER_PAGE;
@@ -39,10 +45,13 @@ use databend_storages_common_table_meta::table::OPT_KEY_CONNECTION_NAME;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_ENGINE;
 use databend_storages_common_table_meta::table::OPT_KEY_LOCATION;
+use databend_storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
 use databend_storages_common_table_meta::table::OPT_KEY_RANDOM_MAX_ARRAY_LEN;
 use databend_storages_common_table_meta::table::OPT_KEY_RANDOM_MAX_STRING_LEN;
 use databend_storages_common_table_meta::table::OPT_KEY_RANDOM_MIN_STRING_LEN;
 use databend_storages_common_table_meta::table::OPT_KEY_RANDOM_SEED;
+use databend_storages_common_table_meta::table::OPT_KEY_SET_INDEX_COLUMNS;
+use databend_storages_common_table_meta::table::OPT_KEY_SET_INDEX_MAX_VALUES;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
@@ -57,8 +66,13 @@ pub static CREATE_FUSE_OPTIONS: LazyLock<HashSet<&'static str>> = LazyLock::new(
     r.insert(FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD);
     r.insert(FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD);
     r.insert(FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS);
+    r.insert(FUSE_OPT_KEY_ICEBERG_EXPORT);
+    r.insert(FUSE_OPT_KEY_AUTO_VIRTUAL_COLUMNS);
 
     r.insert(OPT_KEY_BLOOM_INDEX_COLUMNS);
+    r.insert(OPT_KEY_NGRAM_INDEX_COLUMNS);
+    r.insert(OPT_KEY_SET_INDEX_COLUMNS);
+    r.insert(OPT_KEY_SET_INDEX_MAX_VALUES);
     r.insert(OPT_KEY_TABLE_COMPRESSION);
     r.insert(OPT_KEY_STORAGE_FORMAT);
     r.insert(OPT_KEY_DATABASE_ID);
@@ -109,6 +123,9 @@ pub static UNSET_TABLE_OPTIONS_WHITE_LIST: LazyLock<HashSet<&'static str>> = Laz
     r.insert(FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD);
     r.insert(FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD);
     r.insert(FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS);
+    r.insert(FUSE_OPT_KEY_ICEBERG_EXPORT);
+    r.insert(FUSE_OPT_KEY_AUTO_VIRTUAL_COLUMNS);
+    r.insert(OPT_KEY_SET_INDEX_MAX_VALUES);
     r
 });
 
@@ -189,7 +206,25 @@ pub fn is_valid_bloom_index_columns(
     schema: TableSchemaRef,
 ) -> databend_common_exception::Result<()> {
     if let Some(value) = options.get(OPT_KEY_BLOOM_INDEX_COLUMNS) {
-        BloomIndexColumns::verify_definition(value, schema, BloomIndex::supported_type)?;
+        BloomIndexColumns::verify_definition(value, schema.clone(), BloomIndex::supported_type)?;
+    }
+    if let Some(value) = options.get(OPT_KEY_NGRAM_INDEX_COLUMNS) {
+        BloomIndexColumns::verify_definition(
+            value,
+            schema.clone(),
+            BloomIndex::supported_ngram_type,
+        )?;
+    }
+    if let Some(value) = options.get(OPT_KEY_SET_INDEX_COLUMNS) {
+        BloomIndexColumns::verify_definition(value, schema, BloomIndex::supported_set_type)?;
+    }
+    if let Some(value) = options.get(OPT_KEY_SET_INDEX_MAX_VALUES) {
+        if value.parse::<usize>()? == 0 {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "invalid {} option, must be greater than 0",
+                OPT_KEY_SET_INDEX_MAX_VALUES
+            )));
+        }
     }
     Ok(())
 }
@@ -203,6 +238,36 @@ pub fn is_valid_change_tracking(
     Ok(())
 }
 
+pub fn is_valid_iceberg_export(
+    options: &BTreeMap<String, String>,
+    schema: &TableSchema,
+    storage_format: FuseStorageFormat,
+    storage_params: Option<&StorageParams>,
+) -> databend_common_exception::Result<()> {
+    if let Some(value) = options.get(FUSE_OPT_KEY_ICEBERG_EXPORT) {
+        if !value.to_lowercase().parse::<bool>()? {
+            return Ok(());
+        }
+        if !matches!(storage_format, FuseStorageFormat::Parquet) {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "{} is only supported by tables of parquet storage format",
+                FUSE_OPT_KEY_ICEBERG_EXPORT
+            )));
+        }
+        check_iceberg_export(schema, storage_params)?;
+    }
+    Ok(())
+}
+
+pub fn is_valid_auto_virtual_columns(
+    options: &BTreeMap<String, String>,
+) -> databend_common_exception::Result<()> {
+    if let Some(value) = options.get(FUSE_OPT_KEY_AUTO_VIRTUAL_COLUMNS) {
+        value.to_lowercase().parse::<bool>()?;
+    }
+    Ok(())
+}
+
 pub fn is_valid_random_seed(
     options: &BTreeMap<String, String>,
 ) -> databend_common_exception::Result<()> {
@@ -33,6 +33,7 @@ use databend_common_sql::BindContext;
 use databend_common_sql::Binder;
 use databend_common_sql::Metadata;
 use databend_common_sql::NameResolutionContext;
+use databend_common_storages_fuse::FuseTable;
 use databend_storages_common_table_meta::meta::Location;
 use log::info;
 use parking_lot::RwLock;
@@ -80,6 +81,14 @@ async fn do_refresh(ctx: Arc<QueryContext>, desc: RefreshDesc) -> Result<()> {
         .await?;
     let table_id = table.get_id();
 
+    // Register the virtual columns discovered by the write,
+    // before the virtual columns are refreshed.
+    if table.auto_virtual_columns_enabled() {
+        if let Err(e) = register_auto_virtual_columns(ctx.clone(), table.clone()).await {
+            info!("register auto virtual columns failed. {:?}", e);
+        }
+    }
+
     let mut plans = Vec::new();
 
     // Generate sync aggregating indexes.
@@ -93,8 +102,7 @@ async fn do_refresh(ctx: Arc<QueryContext>, desc: RefreshDesc) -> Result<()> {
     }
 
     // Generate sync inverted indexes.
-    let inverted_index_plans =
-        generate_refresh_inverted_index_plan(ctx.clone(), &desc, table).await?;
+    let inverted_index_plans = generate_refresh_table_index_plan(ctx.clone(), &desc, table).await?;
     plans.extend_from_slice(&inverted_index_plans);
 
     // Generate virtual columns.
@@ -267,7 +275,7 @@ async fn build_refresh_index_plan(
         .await
 }
 
-async fn generate_refresh_inverted_index_plan(
+async fn generate_refresh_table_index_plan(
     ctx: Arc<QueryContext>,
     desc: &RefreshDesc,
     table: Arc<dyn Table>,
@@ -281,6 +289,7 @@ async fn generate_refresh_inverted_index_plan(
             continue;
         }
         let plan = RefreshTableIndexPlan {
+            index_type: index.index_type,
             catalog: desc.catalog.clone(),
             database: desc.database.clone(),
             table: desc.table.clone(),
@@ -318,3 +327,18 @@ async fn generate_refresh_virtual_column_plan(
 
     Ok(Some(Plan::RefreshVirtualColumn(Box::new(plan))))
 }
+
+async fn register_auto_virtual_columns(
+    ctx: Arc<QueryContext>,
+    table: Arc<dyn Table>,
+) -> Result<()> {
+    let segment_locs = ctx.get_written_segment_locations()?;
+    if segment_locs.is_empty() {
+        return Ok(());
+    }
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let discovered = fuse_table.read_virtual_columns(segment_locs).await?;
+    fuse_table
+        .register_auto_virtual_columns(ctx, discovered)
+        .await
+}
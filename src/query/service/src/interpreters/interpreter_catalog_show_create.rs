@@ -81,6 +81,9 @@ impl Interpreter for ShowCreateCatalogInterpreter {
                 IcebergCatalogOption::Glue(cfg) => {
                     format!("WAREHOUSE\n{}", cfg.warehouse)
                 }
+                IcebergCatalogOption::Fs(cfg) => {
+                    format!("WAREHOUSE\n{}", cfg.storage_params)
+                }
             }),
         };
 
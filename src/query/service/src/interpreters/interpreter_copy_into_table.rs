@@ -15,15 +15,23 @@
 use std::collections::BTreeMap;
 use std::sync::Arc;
 
+use databend_common_ast::ast::ValidationMode;
+use databend_common_base::runtime::GlobalIORuntime;
 use databend_common_catalog::lock::LockTableOption;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::Int32Type;
+use databend_common_expression::types::Int64Type;
 use databend_common_expression::types::StringType;
+use databend_common_expression::Column;
 use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchemaRef;
 use databend_common_expression::FromData;
 use databend_common_expression::SendableDataBlockStream;
 use databend_common_meta_app::schema::UpdateStreamMetaReq;
+use databend_common_pipeline_core::ExecutionInfo;
 use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sources::OneBlockSource;
 use databend_common_sql::executor::physical_plans::CopyIntoTable;
 use databend_common_sql::executor::physical_plans::CopyIntoTableSource;
 use databend_common_sql::executor::physical_plans::Exchange;
@@ -32,16 +40,22 @@ use databend_common_sql::executor::physical_plans::MutationKind;
 use databend_common_sql::executor::physical_plans::TableScan;
 use databend_common_sql::executor::table_read_plan::ToReadDataSourcePlan;
 use databend_common_sql::executor::PhysicalPlan;
+use databend_common_sql::plans::CopyErrorTable;
+use databend_common_storage::RejectedRow;
 use databend_common_storage::StageFileInfo;
 use databend_common_storages_stage::StageTable;
+use itertools::Itertools;
 use log::debug;
 use log::info;
+use log::warn;
 
 use crate::interpreters::common::check_deduplicate_label;
 use crate::interpreters::common::dml_build_update_stream_req;
 use crate::interpreters::HookOperator;
 use crate::interpreters::Interpreter;
 use crate::interpreters::SelectInterpreter;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
 use crate::pipelines::PipelineBuildResult;
 use crate::pipelines::PipelineBuilder;
 use crate::schedulers::build_query_pipeline_without_render_result_set;
@@ -223,6 +237,16 @@ impl CopyIntoTableInterpreter {
         Ok(blocks)
     }
 
+    fn get_validation_errors_result(&self) -> Result<Vec<DataBlock>> {
+        let rejected_rows = rejected_rows(&self.ctx);
+        let columns = CopyIntoTablePlan::rejected_rows_schema()
+            .fields()
+            .iter()
+            .map(|f| rejected_rows_column(f.name(), &rejected_rows).unwrap())
+            .collect();
+        Ok(vec![DataBlock::new_from_columns(columns)])
+    }
+
     /// Build commit insertion pipeline.
     async fn commit_insertion(
         &self,
@@ -270,10 +294,15 @@ impl CopyIntoTableInterpreter {
                 duplicated_files_detected.len()
             );
 
+            // Archives are purged as a whole once their members are copied.
             let files_to_be_deleted = files_to_copy
                 .into_iter()
-                .map(|v| v.path)
+                .map(|v| match v.archive_member {
+                    Some(member) => member.archive_path,
+                    None => v.path,
+                })
                 .chain(duplicated_files_detected)
+                .unique()
                 .collect::<Vec<_>>();
             // set on_finished callback.
             PipelineBuilder::set_purge_files_on_finished(
@@ -287,6 +316,40 @@ impl CopyIntoTableInterpreter {
         Ok(())
     }
 
+    /// Append the rows rejected with `ON_ERROR = CONTINUE` to the `ERROR_TABLE` once the copy is finished.
+    ///
+    /// The copy is already committed at that time, so a failed write is reported as a warning.
+    fn set_write_error_table_on_finished(
+        &self,
+        main_pipeline: &mut Pipeline,
+        error_table: CopyErrorTable,
+    ) {
+        let ctx = self.ctx.clone();
+        main_pipeline.set_on_finished(move |info: &ExecutionInfo| {
+            if info.res.is_ok() {
+                // keep the original progress value
+                let write_progress = ctx.get_write_progress();
+                let write_progress_value = write_progress.as_ref().get_values();
+
+                if let Err(e) = GlobalIORuntime::instance()
+                    .block_on(write_error_table(ctx.clone(), error_table.clone()))
+                {
+                    warn!(
+                        "failed to write rejected rows to error table {}.{}: {}",
+                        error_table.database, error_table.table, e
+                    );
+                    ctx.push_warning(format!(
+                        "failed to write rejected rows to error table {}.{}: {}",
+                        error_table.database, error_table.table, e
+                    ));
+                }
+
+                write_progress.set(&write_progress_value);
+            }
+            Ok(())
+        });
+    }
+
     async fn on_no_files_to_copy(&self) -> Result<PipelineBuildResult> {
         // currently, there is only one thing that we care about:
         //
@@ -352,6 +415,11 @@ impl Interpreter for CopyIntoTableInterpreter {
         let mut build_res =
             build_query_pipeline_without_render_result_set(&self.ctx, &physical_plan).await?;
 
+        // Validation is a dry run: nothing is committed, purged or recorded as copied.
+        if self.plan.validation_mode != ValidationMode::None {
+            return Ok(build_res);
+        }
+
         // Build commit insertion pipeline.
         {
             let files_to_copy = self
@@ -388,12 +456,22 @@ impl Interpreter for CopyIntoTableInterpreter {
             hook_operator.execute(&mut build_res.main_pipeline).await;
         }
 
+        // Write rejected rows after the hooks of the copied table, which only care about its own writes.
+        if let Some(error_table) = &self.plan.error_table {
+            self.set_write_error_table_on_finished(
+                &mut build_res.main_pipeline,
+                error_table.clone(),
+            );
+        }
+
         Ok(build_res)
     }
 
     fn inject_result(&self) -> Result<SendableDataBlockStream> {
         let blocks = if self.plan.no_file_to_copy {
             vec![DataBlock::empty_with_schema(self.plan.schema())]
+        } else if self.plan.validation_mode != ValidationMode::None {
+            self.get_validation_errors_result()?
         } else {
             self.get_copy_into_table_result()?
         };
@@ -401,3 +479,118 @@ impl Interpreter for CopyIntoTableInterpreter {
         Ok(Box::pin(DataBlockStream::create(None, blocks)))
     }
 }
+
+/// The rows rejected by the copy, ordered by file and line.
+fn rejected_rows(ctx: &QueryContext) -> Vec<(String, RejectedRow)> {
+    let mut rejected_rows = vec![];
+    for entry in ctx.get_copy_status().files.iter() {
+        for row in &entry.value().rejected_rows {
+            rejected_rows.push((entry.key().clone(), row.clone()));
+        }
+    }
+    rejected_rows.sort_by(|a, b| (&a.0, a.1.line).cmp(&(&b.0, b.1.line)));
+    rejected_rows
+}
+
+/// Build the column `name` of [`CopyIntoTablePlan::rejected_rows_schema`].
+fn rejected_rows_column(name: &str, rejected_rows: &[(String, RejectedRow)]) -> Option<Column> {
+    let column = match name {
+        "file_name" => {
+            StringType::from_data(rejected_rows.iter().map(|(f, _)| f.clone()).collect())
+        }
+        // 1-based, the same as `First_error_line` of the copy result.
+        "line_number" => Int64Type::from_data(
+            rejected_rows
+                .iter()
+                .map(|(_, r)| r.line as i64 + 1)
+                .collect(),
+        ),
+        "column_name" => StringType::from_opt_data(
+            rejected_rows
+                .iter()
+                .map(|(_, r)| r.error.column_name().map(|c| c.to_string()))
+                .collect(),
+        ),
+        "error_code" => Int32Type::from_data(
+            rejected_rows
+                .iter()
+                .map(|(_, r)| r.error.code() as i32)
+                .collect(),
+        ),
+        "error_message" => StringType::from_data(
+            rejected_rows
+                .iter()
+                .map(|(_, r)| r.error.to_string())
+                .collect(),
+        ),
+        "raw_record" => StringType::from_opt_data(
+            rejected_rows
+                .iter()
+                .map(|(_, r)| r.record.clone())
+                .collect(),
+        ),
+        _ => return None,
+    };
+    Some(column)
+}
+
+async fn write_error_table(ctx: Arc<QueryContext>, error_table: CopyErrorTable) -> Result<()> {
+    let rejected_rows = rejected_rows(&ctx);
+    if rejected_rows.is_empty() {
+        return Ok(());
+    }
+    info!(
+        "write {} rejected rows to error table {}.{}",
+        rejected_rows.len(),
+        error_table.database,
+        error_table.table
+    );
+
+    let table = ctx
+        .get_table(
+            &error_table.catalog,
+            &error_table.database,
+            &error_table.table,
+        )
+        .await?;
+    let schema = table.schema().remove_computed_fields();
+    let mut columns = Vec::with_capacity(schema.num_fields());
+    for field in schema.fields() {
+        let Some(column) = rejected_rows_column(field.name(), &rejected_rows) else {
+            return Err(ErrorCode::BadArguments(format!(
+                "column `{}` of ERROR_TABLE {}.{} is not supported",
+                field.name(),
+                error_table.database,
+                error_table.table
+            )));
+        };
+        let column = if field.is_nullable() && !column.data_type().is_nullable() {
+            column.wrap_nullable(None)
+        } else {
+            column
+        };
+        columns.push(column);
+    }
+    let block = DataBlock::new_from_columns(columns);
+    let source_schema: DataSchemaRef = Arc::new((&schema).into());
+
+    let mut pipeline = Pipeline::create();
+    pipeline.add_source(|output| OneBlockSource::create(output, block.clone()), 1)?;
+    PipelineBuilder::build_append2table_with_commit_pipeline(
+        ctx.clone(),
+        &mut pipeline,
+        table,
+        source_schema,
+        None,
+        vec![],
+        false,
+        None,
+    )?;
+    pipeline.set_max_threads(1);
+
+    let executor_settings = ExecutorSettings::try_create(ctx.clone())?;
+    let complete_executor =
+        PipelineCompleteExecutor::from_pipelines(vec![pipeline], executor_settings)?;
+    ctx.set_executor(complete_executor.get_inner())?;
+    complete_executor.execute()
+}
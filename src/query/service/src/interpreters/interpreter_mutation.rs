@@ -44,6 +44,7 @@ use databend_common_storages_factory::Table;
 use databend_common_storages_fuse::operations::TruncateMode;
 use databend_common_storages_fuse::FuseTable;
 use databend_common_storages_fuse::TableContext;
+use databend_common_storages_iceberg::IcebergTable;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use log::info;
 
@@ -107,6 +108,9 @@ impl Interpreter for MutationInterpreter {
             .await?;
         // Check if the table supports mutation.
         table.check_mutable()?;
+        if let Some(iceberg_table) = table.as_any().downcast_ref::<IcebergTable>() {
+            return self.iceberg_mutation(&mutation, iceberg_table).await;
+        }
         let fuse_table = table.as_any().downcast_ref::<FuseTable>().ok_or_else(|| {
             ErrorCode::Unimplemented(format!(
                 "table {}, engine type {}, does not support {}",
@@ -338,6 +342,35 @@ impl MutationInterpreter {
         }
     }
 
+    /// Only deletes with simple filters are supported by iceberg tables, the matched rows
+    /// are deleted by position delete files.
+    async fn iceberg_mutation(
+        &self,
+        mutation: &Mutation,
+        iceberg_table: &IcebergTable,
+    ) -> Result<PipelineBuildResult> {
+        if mutation.mutation_type != MutationType::Delete
+            || mutation.strategy != MutationStrategy::Direct
+        {
+            return Err(ErrorCode::Unimplemented(format!(
+                "table {}, engine type {}, does not support {}",
+                iceberg_table.name(),
+                iceberg_table.get_table_info().engine(),
+                mutation.mutation_type,
+            )));
+        }
+
+        let filter = match &mutation.direct_filter {
+            Some(filter) => Some(create_push_down_filters(filter)?.filter),
+            None => None,
+        };
+        let mut build_res = PipelineBuildResult::create();
+        iceberg_table
+            .delete(self.ctx.clone(), filter, &mut build_res.main_pipeline)
+            .await?;
+        Ok(build_res)
+    }
+
     fn no_effect_mutation(&self) -> Result<Option<PipelineBuildResult>> {
         let mut build_res = PipelineBuildResult::create();
         build_res.main_pipeline.add_source(EmptySource::create, 1)?;
@@ -18,6 +18,7 @@ use std::sync::Arc;
 use chrono::Utc;
 use databend_common_catalog::table::TableExt;
 use databend_common_exception::Result;
+use databend_common_expression::ColumnId;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_sql::executor::physical_plans::AggregateExpand;
 use databend_common_sql::executor::physical_plans::AggregateFinal;
@@ -168,6 +169,8 @@ impl Interpreter for AnalyzeTableInterpreter {
             // 0.01625 --> 12 buckets --> 4K size per column
             // 1.04 / math.sqrt(1<<12) --> 0.01625
             const DISTINCT_ERROR_RATE: f64 = 0.01625;
+            // The number of the most common value combinations kept by multi-column statistics.
+            const MOST_COMMON_VALUES_NUM: usize = 100;
             let ndv_select_expr = snapshot
                 .schema
                 .fields()
@@ -252,6 +255,81 @@ impl Interpreter for AnalyzeTableInterpreter {
                     histogram_info_receivers.insert(col_id, rx);
                 }
             }
+
+            // Collect the multi-column statistics, the column groups analyzed before are refreshed.
+            let schema = table.schema();
+            let mut column_groups: Vec<Vec<ColumnId>> = table_statistics
+                .as_ref()
+                .map(|s| {
+                    s.multi_column_stats
+                        .iter()
+                        .map(|s| s.column_ids.clone())
+                        .filter(|ids| {
+                            ids.iter()
+                                .all(|id| schema.fields().iter().any(|f| f.column_id() == *id))
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+            if !plan.columns.is_empty() {
+                let column_ids = plan
+                    .columns
+                    .iter()
+                    .map(|name| Ok(schema.field_with_name(name)?.column_id()))
+                    .collect::<Result<Vec<_>>>()?;
+                column_groups.retain(|ids| {
+                    ids.len() != column_ids.len() || !ids.iter().all(|id| column_ids.contains(id))
+                });
+                column_groups.push(column_ids);
+            }
+            let mut multi_column_info_receivers = Vec::with_capacity(column_groups.len());
+            for column_ids in column_groups.into_iter() {
+                let col_names = column_ids
+                    .iter()
+                    .filter_map(|id| schema.fields().iter().find(|f| f.column_id() == *id))
+                    .map(|f| format!("{quote}{}{quote}", f.name))
+                    .join(", ");
+                let sql = format!(
+                    "SELECT {col_names}, _count, \
+                        COUNT() OVER () AS _ndv, \
+                        SUM(_count) OVER () AS _rows \
+                    FROM ( \
+                        SELECT {col_names}, COUNT() AS _count \
+                        FROM {}.{} GROUP BY {col_names} \
+                    ) \
+                    ORDER BY _count DESC LIMIT {MOST_COMMON_VALUES_NUM}",
+                    plan.database, plan.table,
+                );
+                info!("Analyze multi-column statistics via sql: {sql}");
+                let (mut multi_column_plan, bind_context) = self.plan_sql(sql).await?;
+                if !self.ctx.get_cluster().is_empty() {
+                    multi_column_plan = remove_exchange(multi_column_plan);
+                }
+                let mut multi_column_build_res = build_query_pipeline(
+                    &QueryContext::create_from(self.ctx.as_ref()),
+                    &bind_context.columns,
+                    &multi_column_plan,
+                    false,
+                )
+                .await?;
+                let (tx, rx) = async_channel::unbounded();
+                multi_column_build_res
+                    .main_pipeline
+                    .add_sink(|input_port| {
+                        Ok(ProcessorPtr::create(HistogramInfoSink::create(
+                            Some(tx.clone()),
+                            input_port.clone(),
+                        )))
+                    })?;
+
+                build_res
+                    .sources_pipelines
+                    .push(multi_column_build_res.main_pipeline.finalize());
+                build_res
+                    .sources_pipelines
+                    .extend(multi_column_build_res.sources_pipelines);
+                multi_column_info_receivers.push((column_ids, rx));
+            }
             FuseTable::do_analyze(
                 self.ctx.clone(),
                 bind_context.output_schema(),
@@ -261,6 +339,7 @@ impl Interpreter for AnalyzeTableInterpreter {
                 snapshot.snapshot_id,
                 &mut build_res.main_pipeline,
                 histogram_info_receivers,
+                multi_column_info_receivers,
             )?;
             return Ok(build_res);
         }
@@ -12,26 +12,34 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::str::FromStr;
 use std::sync::Arc;
 
 use chrono::Utc;
+use databend_common_ast::ast::Engine;
 use databend_common_base::runtime::GlobalIORuntime;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::is_internal_column;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
 use databend_common_expression::TableSchemaRefExt;
 use databend_common_license::license::Feature;
 use databend_common_license::license::Feature::ComputedColumn;
 use databend_common_license::license::Feature::InvertedIndex;
+use databend_common_license::license::Feature::VirtualColumn;
 use databend_common_license::license_manager::LicenseManagerSwitch;
 use databend_common_management::RoleApi;
 use databend_common_meta_app::principal::OwnershipObject;
+use databend_common_meta_app::schema::CatalogType;
 use databend_common_meta_app::schema::CommitTableMetaReq;
 use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::schema::CreateTableReq;
 use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::schema::TableNameIdent;
@@ -40,8 +48,10 @@ use databend_common_meta_types::MatchSeq;
 use databend_common_pipeline_core::ExecutionInfo;
 use databend_common_sql::field_default_value;
 use databend_common_sql::plans::CreateTablePlan;
+use databend_common_sql::resolve_type_name_by_str;
 use databend_common_storages_fuse::io::MetaReaders;
 use databend_common_storages_fuse::FuseStorageFormat;
+use databend_common_storages_fuse::FuseTable;
 use databend_common_users::RoleCacheManager;
 use databend_common_users::UserApiProvider;
 use databend_enterprise_attach_table::get_attach_table_handler;
@@ -53,18 +63,22 @@ use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
+use futures_util::TryStreamExt;
 use log::error;
 use log::info;
 
+use crate::interpreters::common::table_option_validation::is_valid_auto_virtual_columns;
 use crate::interpreters::common::table_option_validation::is_valid_block_per_segment;
 use crate::interpreters::common::table_option_validation::is_valid_bloom_index_columns;
 use crate::interpreters::common::table_option_validation::is_valid_change_tracking;
 use crate::interpreters::common::table_option_validation::is_valid_create_opt;
 use crate::interpreters::common::table_option_validation::is_valid_data_retention_period;
+use crate::interpreters::common::table_option_validation::is_valid_iceberg_export;
 use crate::interpreters::common::table_option_validation::is_valid_random_seed;
 use crate::interpreters::common::table_option_validation::is_valid_row_per_block;
 use crate::interpreters::InsertInterpreter;
 use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
@@ -97,6 +111,17 @@ impl Interpreter for CreateTableInterpreter {
 
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
+        if let Some(template) = &self.plan.template {
+            let plan = CreateTablePlan {
+                schema: self.schema_from_template(template).await?,
+                template: None,
+                ..self.plan.clone()
+            };
+            return CreateTableInterpreter::try_create(self.ctx.clone(), plan)?
+                .execute2()
+                .await;
+        }
+
         let tenant = &self.plan.tenant;
 
         let has_computed_column = self
@@ -109,10 +134,18 @@ impl Interpreter for CreateTableInterpreter {
             LicenseManagerSwitch::instance()
                 .check_enterprise_enabled(self.ctx.get_license_key(), ComputedColumn)?;
         }
-        if self.plan.inverted_indexes.is_some() {
+        if self.plan.inverted_indexes.as_ref().is_some_and(|indexes| {
+            indexes
+                .values()
+                .any(|index| index.index_type == TableIndexType::Inverted)
+        }) {
             LicenseManagerSwitch::instance()
                 .check_enterprise_enabled(self.ctx.get_license_key(), InvertedIndex)?;
         }
+        if FuseTable::is_auto_virtual_columns_enabled(&self.plan.options) {
+            LicenseManagerSwitch::instance()
+                .check_enterprise_enabled(self.ctx.get_license_key(), VirtualColumn)?;
+        }
 
         let quota_api = UserApiProvider::instance().tenant_quota_api(tenant);
         let quota = quota_api.get_quota(MatchSeq::GE(0)).await?.data;
@@ -159,6 +192,61 @@ impl Interpreter for CreateTableInterpreter {
 }
 
 impl CreateTableInterpreter {
+    /// Build the schema from the rows of the template query, which are in the form of the
+    /// result of `infer_schema`: `column_name`, `type` and `nullable`.
+    #[async_backtrace::framed]
+    async fn schema_from_template(&self, template: &Plan) -> Result<TableSchemaRef> {
+        let interpreter = InterpreterFactory::get(self.ctx.clone(), template).await?;
+        let stream = interpreter.execute(self.ctx.clone()).await?;
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+
+        let invalid = || {
+            ErrorCode::BadArguments(
+                "The query of USING TEMPLATE must return the columns (column_name, type, nullable) like infer_schema",
+            )
+        };
+        let mut fields = vec![];
+        for block in blocks {
+            if block.num_columns() < 3 {
+                return Err(invalid());
+            }
+            for row in 0..block.num_rows() {
+                let value = |i: usize| block.get_by_offset(i).value.index(row);
+                let (Some(ScalarRef::String(name)), Some(ScalarRef::String(ty))) =
+                    (value(0), value(1))
+                else {
+                    return Err(invalid());
+                };
+                let Some(ScalarRef::Boolean(nullable)) = value(2) else {
+                    return Err(invalid());
+                };
+                let data_type = resolve_type_name_by_str(ty, !nullable)?;
+                fields.push(TableField::new(name, data_type));
+            }
+        }
+        if fields.is_empty() {
+            return Err(ErrorCode::BadArguments(
+                "The query of USING TEMPLATE returns no columns",
+            ));
+        }
+        let schema = TableSchemaRefExt::create(fields);
+        Self::check_duplicated_columns(&schema)?;
+        Ok(schema)
+    }
+
+    fn check_duplicated_columns(schema: &TableSchemaRef) -> Result<()> {
+        let mut names = HashSet::new();
+        for field in schema.fields() {
+            if !names.insert(field.name()) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Duplicated column name: {}",
+                    field.name()
+                )));
+            }
+        }
+        Ok(())
+    }
+
     #[async_backtrace::framed]
     async fn create_table_as_select(&self, select_plan: Box<Plan>) -> Result<PipelineBuildResult> {
         assert!(
@@ -218,12 +306,22 @@ impl CreateTableInterpreter {
         // For the situation above, we implicitly cast the data type when inserting data.
         // The casting and schema checking is in interpreter_insert.rs, function check_schema_cast.
 
-        let table_info = TableInfo::new(
-            &self.plan.database,
-            &self.plan.table,
-            TableIdent::new(table_id, table_id_seq),
-            table_meta,
-        );
+        let table_info = if catalog.info().catalog_type() == CatalogType::Default {
+            TableInfo::new(
+                &self.plan.database,
+                &self.plan.table,
+                TableIdent::new(table_id, table_id_seq),
+                table_meta,
+            )
+        } else {
+            // Tables of external catalogs are visible once created, load it back to get
+            // the table info that the catalog needs to write into it.
+            catalog
+                .get_table(&tenant, &self.plan.database, &self.plan.table)
+                .await?
+                .get_table_info()
+                .clone()
+        };
 
         let insert_plan = Insert {
             catalog: self.plan.catalog.clone(),
@@ -392,9 +490,24 @@ impl CreateTableInterpreter {
 
         is_valid_block_per_segment(&table_meta.options)?;
         is_valid_row_per_block(&table_meta.options)?;
-        // check bloom_index_columns.
+        // check bloom_index_columns, ngram_index_columns and set_index_columns.
         is_valid_bloom_index_columns(&table_meta.options, schema)?;
         is_valid_change_tracking(&table_meta.options)?;
+        is_valid_auto_virtual_columns(&table_meta.options)?;
+        if self.plan.engine == Engine::Fuse {
+            let storage_format = table_meta
+                .options
+                .get(OPT_KEY_STORAGE_FORMAT)
+                .map_or(Ok(FuseStorageFormat::Parquet), |v| {
+                    FuseStorageFormat::from_str(v)
+                })?;
+            is_valid_iceberg_export(
+                &table_meta.options,
+                &table_meta.schema,
+                storage_format,
+                table_meta.storage_params.as_ref(),
+            )?;
+        }
         // check random seed
         is_valid_random_seed(&table_meta.options)?;
         // check table level data_retention_period_in_hours
@@ -23,6 +23,7 @@ use databend_common_meta_app::schema::DropTableByIdReq;
 use databend_common_sql::plans::DropTablePlan;
 use databend_common_storages_fuse::operations::TruncateMode;
 use databend_common_storages_fuse::FuseTable;
+use databend_common_storages_iceberg::IcebergCatalog;
 use databend_common_storages_stream::stream_table::STREAM_ENGINE;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_common_users::RoleCacheManager;
@@ -103,6 +104,10 @@ impl Interpreter for DropTableInterpreter {
             )));
         }
         let catalog = self.ctx.get_catalog(catalog_name).await?;
+        if let Some(catalog) = catalog.as_any().downcast_ref::<IcebergCatalog>() {
+            catalog.drop_table(db_name, tbl_name).await?;
+            return Ok(PipelineBuildResult::create());
+        }
 
         // Although even if data is in READ_ONLY mode,
         // as a catalog object, the table itself is allowed to be dropped (and undropped later),
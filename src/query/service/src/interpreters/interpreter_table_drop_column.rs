@@ -26,6 +26,8 @@ use databend_common_sql::BloomIndexColumns;
 use databend_common_storages_stream::stream_table::STREAM_ENGINE;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+use databend_storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
+use databend_storages_common_table_meta::table::OPT_KEY_SET_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
 use crate::interpreters::interpreter_table_add_column::generate_new_snapshot;
@@ -97,13 +99,16 @@ impl Interpreter for DropTableColumnInterpreter {
                 self.plan.column.as_str(),
             )?;
         }
-        // If the column is inverted index column, the column can't be dropped.
+        // If the column is table index column, the column can't be dropped.
         if !table_info.meta.indexes.is_empty() {
             for (index_name, index) in &table_info.meta.indexes {
                 if index.column_ids.contains(&field.column_id) {
                     return Err(ErrorCode::ColumnReferencedByInvertedIndex(format!(
-                        "column `{}` is referenced by inverted index, drop inverted index `{}` first",
-                        field.name, index_name,
+                        "column `{}` is referenced by {} index, drop {} index `{}` first",
+                        field.name,
+                        index.index_type.to_string().to_lowercase(),
+                        index.index_type.to_string().to_lowercase(),
+                        index_name,
                     )));
                 }
             }
@@ -115,13 +120,19 @@ impl Interpreter for DropTableColumnInterpreter {
 
         // update table options
         let opts = &mut new_table_meta.options;
-        if let Some(value) = opts.get_mut(OPT_KEY_BLOOM_INDEX_COLUMNS) {
-            let bloom_index_cols = value.parse::<BloomIndexColumns>()?;
-            if let BloomIndexColumns::Specify(mut cols) = bloom_index_cols {
-                if let Some(pos) = cols.iter().position(|x| *x == self.plan.column) {
-                    // remove from the bloom index columns.
-                    cols.remove(pos);
-                    *value = cols.join(",");
+        for key in [
+            OPT_KEY_BLOOM_INDEX_COLUMNS,
+            OPT_KEY_NGRAM_INDEX_COLUMNS,
+            OPT_KEY_SET_INDEX_COLUMNS,
+        ] {
+            if let Some(value) = opts.get_mut(key) {
+                let bloom_index_cols = value.parse::<BloomIndexColumns>()?;
+                if let BloomIndexColumns::Specify(mut cols) = bloom_index_cols {
+                    if let Some(pos) = cols.iter().position(|x| *x == self.plan.column) {
+                        // remove from the bloom index columns.
+                        cols.remove(pos);
+                        *value = cols.join(",");
+                    }
                 }
             }
         }
@@ -18,6 +18,7 @@ use databend_common_exception::Result;
 use databend_common_license::license::Feature;
 use databend_common_license::license_manager::LicenseManagerSwitch;
 use databend_common_meta_app::schema::CreateTableIndexReq;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_common_sql::plans::CreateTableIndexPlan;
 use databend_common_storages_fuse::TableContext;
 use databend_enterprise_inverted_index::get_inverted_index_handler;
@@ -49,8 +50,11 @@ impl Interpreter for CreateTableIndexInterpreter {
 
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
-        LicenseManagerSwitch::instance()
-            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::InvertedIndex)?;
+        let index_type = self.plan.index_type;
+        if index_type == TableIndexType::Inverted {
+            LicenseManagerSwitch::instance()
+                .check_enterprise_enabled(self.ctx.get_license_key(), Feature::InvertedIndex)?;
+        }
 
         let index_name = self.plan.index_name.clone();
         let column_ids = self.plan.column_ids.clone();
@@ -61,6 +65,7 @@ impl Interpreter for CreateTableIndexInterpreter {
 
         let create_index_req = CreateTableIndexReq {
             create_option: self.plan.create_option,
+            index_type,
             tenant,
             table_id,
             name: index_name,
@@ -69,10 +74,17 @@ impl Interpreter for CreateTableIndexInterpreter {
             options: self.plan.index_options.clone(),
         };
 
-        let handler = get_inverted_index_handler();
-        let _ = handler
-            .do_create_table_index(catalog, create_index_req)
-            .await?;
+        match index_type {
+            TableIndexType::Inverted => {
+                let handler = get_inverted_index_handler();
+                let _ = handler
+                    .do_create_table_index(catalog, create_index_req)
+                    .await?;
+            }
+            TableIndexType::Vector => {
+                catalog.create_table_index(create_index_req).await?;
+            }
+        }
 
         Ok(PipelineBuildResult::create())
     }
@@ -20,7 +20,9 @@ use databend_common_exception::Result;
 use databend_common_expression::TableSchemaRefExt;
 use databend_common_license::license::Feature;
 use databend_common_license::license_manager::LicenseManagerSwitch;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_common_sql::plans::RefreshTableIndexPlan;
+use databend_common_storages_fuse::io::VectorIndexBuilder;
 use databend_common_storages_fuse::FuseTable;
 use databend_common_storages_fuse::TableContext;
 
@@ -51,8 +53,11 @@ impl Interpreter for RefreshTableIndexInterpreter {
 
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
-        LicenseManagerSwitch::instance()
-            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::InvertedIndex)?;
+        let index_type = self.plan.index_type;
+        if index_type == TableIndexType::Inverted {
+            LicenseManagerSwitch::instance()
+                .check_enterprise_enabled(self.ctx.get_license_key(), Feature::InvertedIndex)?;
+        }
 
         let table = self
             .ctx
@@ -65,11 +70,46 @@ impl Interpreter for RefreshTableIndexInterpreter {
         let segment_locs = self.plan.segment_locs.clone();
         let table_meta = &table.get_table_info().meta;
         let Some(index) = table_meta.indexes.get(&index_name) else {
+            let type_name = match index_type {
+                TableIndexType::Inverted => "Inverted",
+                TableIndexType::Vector => "Vector",
+            };
             return Err(ErrorCode::RefreshIndexError(format!(
-                "Inverted index {} does not exist",
-                index_name
+                "{} index {} does not exist",
+                type_name, index_name
             )));
         };
+        if index.index_type != index_type {
+            return Err(ErrorCode::RefreshIndexError(format!(
+                "Index {} is a {} index, not a {} index",
+                index_name,
+                index.index_type.to_string().to_lowercase(),
+                index_type.to_string().to_lowercase()
+            )));
+        }
+
+        let mut build_res = PipelineBuildResult::create();
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+
+        if index_type == TableIndexType::Vector {
+            let Some(vector_index_builder) = VectorIndexBuilder::create(index, &table_meta.schema)
+            else {
+                return Err(ErrorCode::RefreshIndexError(format!(
+                    "Vector index {} is invalid",
+                    index_name
+                )));
+            };
+            fuse_table
+                .do_refresh_vector_index(
+                    self.ctx.clone(),
+                    vector_index_builder,
+                    segment_locs,
+                    &mut build_res.main_pipeline,
+                )
+                .await?;
+            return Ok(build_res);
+        }
+
         let mut index_fields = Vec::with_capacity(index.column_ids.len());
         for column_id in &index.column_ids {
             for field in &table_meta.schema.fields {
@@ -88,9 +128,6 @@ impl Interpreter for RefreshTableIndexInterpreter {
         let index_version = index.version.clone();
         let index_schema = TableSchemaRefExt::create(index_fields);
 
-        let mut build_res = PipelineBuildResult::create();
-
-        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
         fuse_table
             .do_refresh_inverted_index(
                 self.ctx.clone(),
@@ -28,6 +28,7 @@ use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRef;
+use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_license::license::Feature::ComputedColumn;
 use databend_common_license::license::Feature::DataMask;
 use databend_common_license::license_manager::LicenseManagerSwitch;
@@ -55,6 +56,8 @@ use databend_enterprise_data_mask_feature::get_datamask_handler;
 use databend_storages_common_index::BloomIndex;
 use databend_storages_common_table_meta::meta::SnapshotId;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+use databend_storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
+use databend_storages_common_table_meta::table::OPT_KEY_SET_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
 use crate::interpreters::Interpreter;
@@ -147,6 +150,17 @@ impl ModifyTableColumnInterpreter {
         let schema = table.schema().as_ref().clone();
         let table_info = table.get_table_info();
         let mut new_schema = schema.clone();
+
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let cluster_key_columns = fuse_table
+            .linear_cluster_keys(self.ctx.clone())
+            .iter()
+            .flat_map(|expr| expr.as_expr(&BUILTIN_FUNCTIONS).column_refs().into_keys())
+            .collect::<HashSet<_>>();
+        // columns widened without rewriting data, blocks written earlier are read
+        // as the previous data type and cast on the fly.
+        let mut widened_columns = HashSet::new();
+
         // first check default expr before lock table
         for (field, _comment) in field_and_comments {
             if let Some((i, old_field)) = schema.column_with_name(&field.name) {
@@ -155,6 +169,15 @@ impl ModifyTableColumnInterpreter {
                 if old_field.data_type.num_leaf_columns() != field.data_type.num_leaf_columns() {
                     let _ = new_schema.drop_column(&field.name);
                     let _ = new_schema.add_column(field, i);
+                } else if table.storage_format_as_parquet()
+                    && is_lossless_widening(&old_field.data_type, &field.data_type)
+                    && old_field.default_expr == field.default_expr
+                    && old_field.computed_expr.is_none()
+                    && field.computed_expr.is_none()
+                    && !cluster_key_columns.contains(&field.name)
+                {
+                    new_schema.widen_column(&field.name, field.data_type.clone())?;
+                    widened_columns.insert(field.name.clone());
                 } else {
                     // new field don't have `column_id`, assign field directly will cause `column_id` lost.
                     new_schema.fields[i].data_type = field.data_type.clone();
@@ -186,7 +209,6 @@ impl ModifyTableColumnInterpreter {
         let catalog_name = table_info.catalog();
         let catalog = self.ctx.get_catalog(catalog_name).await?;
 
-        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
         let prev_snapshot_id = fuse_table
             .read_table_snapshot()
             .await
@@ -198,6 +220,18 @@ impl ModifyTableColumnInterpreter {
                 bloom_index_cols = cols;
             }
         }
+        let mut ngram_index_cols = vec![];
+        if let Some(v) = table_info.options().get(OPT_KEY_NGRAM_INDEX_COLUMNS) {
+            if let BloomIndexColumns::Specify(cols) = v.parse::<BloomIndexColumns>()? {
+                ngram_index_cols = cols;
+            }
+        }
+        let mut set_index_cols = vec![];
+        if let Some(v) = table_info.options().get(OPT_KEY_SET_INDEX_COLUMNS) {
+            if let BloomIndexColumns::Specify(cols) = v.parse::<BloomIndexColumns>()? {
+                set_index_cols = cols;
+            }
+        }
 
         let mut table_info = table.get_table_info().clone();
         table_info.meta.fill_field_comments();
@@ -215,7 +249,23 @@ impl ModifyTableColumnInterpreter {
                             field.data_type
                         )));
                     }
-                    // If the column is inverted index column, the type can't be changed.
+                    if ngram_index_cols.iter().any(|v| v.as_str() == field.name)
+                        && !BloomIndex::supported_ngram_type(&field.data_type)
+                    {
+                        return Err(ErrorCode::TableOptionInvalid(format!(
+                            "Unsupported data type '{}' for ngram index",
+                            field.data_type
+                        )));
+                    }
+                    if set_index_cols.iter().any(|v| v.as_str() == field.name)
+                        && !BloomIndex::supported_set_type(&field.data_type)
+                    {
+                        return Err(ErrorCode::TableOptionInvalid(format!(
+                            "Unsupported data type '{}' for set index",
+                            field.data_type
+                        )));
+                    }
+                    // If the column is table index column, the type can't be changed.
                     if !table_info.meta.indexes.is_empty() {
                         for (index_name, index) in &table_info.meta.indexes {
                             if index.column_ids.contains(&old_field.column_id)
@@ -223,8 +273,11 @@ impl ModifyTableColumnInterpreter {
                                     != field.data_type.remove_nullable()
                             {
                                 return Err(ErrorCode::ColumnReferencedByInvertedIndex(format!(
-                                    "column `{}` is referenced by inverted index, drop inverted index `{}` first",
-                                    field.name, index_name,
+                                    "column `{}` is referenced by {} index, drop {} index `{}` first",
+                                    field.name,
+                                    index.index_type.to_string().to_lowercase(),
+                                    index.index_type.to_string().to_lowercase(),
+                                    index_name,
                                 )));
                             }
                         }
@@ -251,6 +304,9 @@ impl ModifyTableColumnInterpreter {
         let new_schema_without_computed_fields = new_schema.remove_computed_fields();
         if schema != new_schema {
             for (field, _) in field_and_comments {
+                if widened_columns.contains(&field.name) {
+                    continue;
+                }
                 let field_index = new_schema_without_computed_fields.index_of(&field.name)?;
                 let old_field = schema.field_with_name(&field.name)?;
                 let is_alter_column_string_to_binary =
@@ -634,6 +690,32 @@ fn is_string_to_binary(old_ty: &TableDataType, new_ty: &TableDataType) -> bool {
     }
 }
 
+// Whether every value of `old_ty` can be represented by `new_ty` exactly,
+// so that the column can be read as `old_ty` and cast to `new_ty` on the fly.
+fn is_lossless_widening(old_ty: &TableDataType, new_ty: &TableDataType) -> bool {
+    match (old_ty, new_ty) {
+        (TableDataType::Nullable(old_ty), TableDataType::Nullable(new_ty)) => {
+            is_lossless_widening(old_ty, new_ty)
+        }
+        (old_ty, TableDataType::Nullable(new_ty)) => is_lossless_widening(old_ty, new_ty),
+        (TableDataType::Number(old_ty), TableDataType::Number(new_ty)) => {
+            if old_ty.is_float() || new_ty.is_float() {
+                old_ty.is_float() && new_ty.is_float() && old_ty.bit_width() < new_ty.bit_width()
+            } else if old_ty.is_signed() {
+                new_ty.is_signed() && old_ty.bit_width() < new_ty.bit_width()
+            } else {
+                old_ty.bit_width() < new_ty.bit_width()
+            }
+        }
+        (TableDataType::Decimal(old_ty), TableDataType::Decimal(new_ty)) => {
+            old_ty != new_ty
+                && new_ty.scale() >= old_ty.scale()
+                && new_ty.leading_digits() >= old_ty.leading_digits()
+        }
+        _ => false,
+    }
+}
+
 pub(crate) async fn build_select_insert_plan(
     ctx: Arc<QueryContext>,
     sql: String,
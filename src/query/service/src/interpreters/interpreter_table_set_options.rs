@@ -19,9 +19,13 @@ use databend_common_ast::ast::Engine;
 use databend_common_catalog::table::TableExt;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_license::license::Feature::VirtualColumn;
+use databend_common_license::license_manager::LicenseManagerSwitch;
 use databend_common_meta_app::schema::UpsertTableOptionReq;
 use databend_common_meta_types::MatchSeq;
 use databend_common_sql::plans::SetOptionsPlan;
+use databend_common_storages_fuse::FuseStorageFormat;
+use databend_common_storages_fuse::FuseTable;
 use databend_common_storages_fuse::TableContext;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING_BEGIN_VER;
@@ -31,10 +35,12 @@ use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
 use log::error;
 
+use crate::interpreters::common::table_option_validation::is_valid_auto_virtual_columns;
 use crate::interpreters::common::table_option_validation::is_valid_block_per_segment;
 use crate::interpreters::common::table_option_validation::is_valid_bloom_index_columns;
 use crate::interpreters::common::table_option_validation::is_valid_create_opt;
 use crate::interpreters::common::table_option_validation::is_valid_data_retention_period;
+use crate::interpreters::common::table_option_validation::is_valid_iceberg_export;
 use crate::interpreters::common::table_option_validation::is_valid_row_per_block;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -137,9 +143,33 @@ impl Interpreter for SetOptionsInterpreter {
         // check mutability
         table.check_mutable()?;
 
-        // check bloom_index_columns.
+        // check bloom_index_columns, ngram_index_columns and set_index_columns.
         is_valid_bloom_index_columns(&self.plan.set_options, table.schema())?;
 
+        // check auto_virtual_columns.
+        is_valid_auto_virtual_columns(&self.plan.set_options)?;
+        if FuseTable::is_auto_virtual_columns_enabled(&self.plan.set_options) {
+            LicenseManagerSwitch::instance()
+                .check_enterprise_enabled(self.ctx.get_license_key(), VirtualColumn)?;
+        }
+
+        // check iceberg_export.
+        let export_iceberg = FuseTable::is_iceberg_export_enabled(&self.plan.set_options)
+            && !FuseTable::is_iceberg_export_enabled(table.options());
+        if export_iceberg {
+            let storage_format = if table.storage_format_as_parquet() {
+                FuseStorageFormat::Parquet
+            } else {
+                FuseStorageFormat::Native
+            };
+            is_valid_iceberg_export(
+                &self.plan.set_options,
+                table.schema().as_ref(),
+                storage_format,
+                table.get_table_info().meta.storage_params.as_ref(),
+            )?;
+        }
+
         let req = UpsertTableOptionReq {
             table_id: table.get_id(),
             seq: MatchSeq::Exact(table_version),
@@ -149,6 +179,14 @@ impl Interpreter for SetOptionsInterpreter {
         let _resp = catalog
             .upsert_table_option(&self.ctx.get_tenant(), database, req)
             .await?;
+
+        // export the current snapshot, later commits keep it in sync.
+        if export_iceberg {
+            let table = table.refresh(self.ctx.as_ref()).await?;
+            FuseTable::try_from_table(table.as_ref())?
+                .export_iceberg_metadata()
+                .await?;
+        }
         Ok(PipelineBuildResult::create())
     }
 }
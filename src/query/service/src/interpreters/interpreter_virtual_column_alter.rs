@@ -73,6 +73,7 @@ impl Interpreter for AlterVirtualColumnInterpreter {
             if_exists: self.plan.if_exists,
             name_ident: VirtualColumnIdent::new(&tenant, table_id),
             virtual_columns: self.plan.virtual_columns.clone(),
+            append_only: false,
         };
 
         let handler = get_virtual_column_handler();
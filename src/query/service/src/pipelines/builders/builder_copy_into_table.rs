@@ -17,6 +17,7 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use databend_common_ast::ast::CopyIntoTableOptions;
+use databend_common_ast::ast::ValidationMode;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
@@ -29,8 +30,12 @@ use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::ParquetFileFormatParams;
 use databend_common_meta_app::schema::TableCopiedFileInfo;
 use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
+use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sinks::EmptySink;
 use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
+use databend_common_sql::evaluator::BlockOperator;
+use databend_common_sql::evaluator::CompoundBlockOperator;
 use databend_common_sql::executor::physical_plans::CopyIntoTable;
 use databend_common_sql::executor::physical_plans::CopyIntoTableSource;
 use databend_common_sql::plans::CopyIntoTableMode;
@@ -40,6 +45,7 @@ use log::info;
 
 use crate::pipelines::processors::transforms::TransformAddConstColumns;
 use crate::pipelines::processors::TransformCastSchema;
+use crate::pipelines::processors::TransformLimit;
 use crate::pipelines::processors::TransformNullIf;
 use crate::pipelines::PipelineBuilder;
 use crate::sessions::QueryContext;
@@ -171,6 +177,52 @@ impl PipelineBuilder {
             )?;
         }
 
+        // validation only decodes the files, nothing is written to the table.
+        match plan.validation_mode {
+            ValidationMode::None => {}
+            ValidationMode::ReturnNRows(n) => {
+                // fill and cast the rows to the table schema, as loading does,
+                // so the errors of them are reported, but only return the columns of the copy.
+                Self::fill_and_reorder_columns(
+                    ctx.clone(),
+                    main_pipeline,
+                    to_table.clone(),
+                    plan_required_values_schema.clone(),
+                )?;
+                let table_schema =
+                    DataSchema::from(&to_table.schema().remove_virtual_computed_fields());
+                let projection = plan_required_values_schema
+                    .fields()
+                    .iter()
+                    .map(|f| table_schema.index_of(f.name()))
+                    .collect::<Result<Vec<_>>>()?;
+                let num_input_columns = table_schema.num_fields();
+                let func_ctx = ctx.get_function_context()?;
+                main_pipeline.add_transformer(|| {
+                    CompoundBlockOperator::new(
+                        vec![BlockOperator::Project {
+                            projection: projection.clone(),
+                        }],
+                        func_ctx.clone(),
+                        num_input_columns,
+                    )
+                });
+                main_pipeline.try_resize(1)?;
+                return main_pipeline.add_transform(|input, output| {
+                    Ok(ProcessorPtr::create(TransformLimit::try_create(
+                        Some(n as usize),
+                        0,
+                        input,
+                        output,
+                    )?))
+                });
+            }
+            ValidationMode::ReturnErrors | ValidationMode::ReturnAllErrors => {
+                return main_pipeline
+                    .add_sink(|input| Ok(ProcessorPtr::create(EmptySink::create(input))));
+            }
+        }
+
         // append data without commit.
         match plan_write_mode {
             CopyIntoTableMode::Insert { overwrite: _ } => {
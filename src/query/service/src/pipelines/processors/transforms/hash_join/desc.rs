@@ -51,6 +51,8 @@ pub struct HashJoinDesc {
     pub broadcast: bool,
     // If enable bloom runtime filter
     pub enable_bloom_runtime_filter: bool,
+    // If the probe side prunes its partitions with the runtime filters.
+    pub runtime_filter_partition_pruning: bool,
 }
 
 impl HashJoinDesc {
@@ -93,6 +95,7 @@ impl HashJoinDesc {
             broadcast: join.broadcast,
             single_to_inner: join.single_to_inner.clone(),
             enable_bloom_runtime_filter: join.enable_bloom_runtime_filter,
+            runtime_filter_partition_pruning: join.runtime_filter_partition_pruning,
         })
     }
 
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::collections::VecDeque;
 use std::ops::ControlFlow;
@@ -21,6 +22,7 @@ use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use databend_common_base::base::tokio::sync::Barrier;
+use databend_common_base::runtime::GlobalIORuntime;
 use databend_common_catalog::runtime_filter_info::RuntimeFilterInfo;
 use databend_common_catalog::runtime_filter_info::RuntimeFilterReady;
 use databend_common_catalog::table_context::TableContext;
@@ -56,13 +58,18 @@ use databend_common_hashtable::StringRawEntry;
 use databend_common_hashtable::STRING_EARLY_SIZE;
 use databend_common_sql::plans::JoinType;
 use databend_common_sql::ColumnSet;
+use databend_common_sql::IndexType;
+use databend_storages_common_index::BloomIndex;
 use ethnum::U256;
 use itertools::Itertools;
 use log::info;
+use log::warn;
 use parking_lot::Mutex;
 use parking_lot::RwLock;
 use xorf::BinaryFuse16;
 
+use crate::clusters::ClusterHelper;
+use crate::clusters::FlightParams;
 use crate::pipelines::processors::transforms::hash_join::common::wrap_true_validity;
 use crate::pipelines::processors::transforms::hash_join::desc::MARKER_KIND_FALSE;
 use crate::pipelines::processors::transforms::hash_join::transform_hash_join_build::HashTableType;
@@ -75,9 +82,15 @@ use crate::pipelines::processors::transforms::hash_join::HashJoinHashTable;
 use crate::pipelines::processors::transforms::hash_join::SerializerHashJoinHashTable;
 use crate::pipelines::processors::transforms::hash_join::SingleBinaryHashJoinHashTable;
 use crate::pipelines::processors::HashJoinState;
+use crate::servers::flight::v1::actions::RuntimeFilterDigests;
+use crate::servers::flight::v1::actions::SET_RUNTIME_FILTER_DIGESTS;
 use crate::sessions::QueryContext;
 
 pub(crate) const INLIST_RUNTIME_FILTER_THRESHOLD: usize = 1024;
+/// The max number of distinct build keys to prune the blocks of the probe side with
+/// their bloom index digests, a block is kept if any of the keys is a false positive
+/// of its xor filter, too many keys make the pruning useless.
+pub(crate) const BLOOM_DIGEST_RUNTIME_FILTER_THRESHOLD: usize = 64;
 
 /// Define some shared states for all hash join build threads.
 pub struct HashJoinBuildState {
@@ -122,6 +135,9 @@ pub struct HashJoinBuildState {
     pub(crate) enable_min_max_runtime_filter: bool,
     /// Need to open runtime filter setting.
     pub(crate) enable_bloom_runtime_filter: bool,
+    /// Prune the blocks of the probe side with the bloom index digests of the build keys,
+    /// it's also supported by shuffle join, the digests are sent to all the nodes.
+    pub(crate) enable_bloom_digest_runtime_filter: bool,
 }
 
 impl HashJoinBuildState {
@@ -147,7 +163,10 @@ impl HashJoinBuildState {
         let mut enable_bloom_runtime_filter = false;
         let mut enable_inlist_runtime_filter = false;
         let mut enable_min_max_runtime_filter = false;
+        let mut enable_bloom_digest_runtime_filter = false;
+        let settings = ctx.get_settings();
         if supported_join_type_for_runtime_filter(&hash_join_state.hash_join_desc.join_type) {
+            enable_bloom_digest_runtime_filter = settings.get_bloom_digest_runtime_filter()?;
             let is_cluster = !ctx.get_cluster().is_empty();
             // For cluster, only support runtime filter for broadcast join.
             let is_broadcast_join = hash_join_state.hash_join_desc.broadcast;
@@ -159,7 +178,6 @@ impl HashJoinBuildState {
             }
         }
 
-        let settings = ctx.get_settings();
         let chunk_size_limit = settings.get_max_block_size()? as usize * 16;
         let (global_memory_threshold, processor_memory_threshold) =
             Self::get_memory_threshold(ctx.clone(), num_threads)?;
@@ -185,6 +203,7 @@ impl HashJoinBuildState {
             enable_bloom_runtime_filter,
             enable_inlist_runtime_filter,
             enable_min_max_runtime_filter,
+            enable_bloom_digest_runtime_filter,
         }))
     }
 
@@ -852,7 +871,14 @@ impl HashJoinBuildState {
     }
 
     pub fn add_runtime_filter_ready(&self) {
-        if self.ctx.get_cluster().is_empty() {
+        // The fuse scans only wait for the runtime filters under cluster, while the scans of
+        // the external tables wait to prune their partitions before listing their files.
+        if self.ctx.get_cluster().is_empty()
+            && !self
+                .hash_join_state
+                .hash_join_desc
+                .runtime_filter_partition_pruning
+        {
             return;
         }
 
@@ -926,6 +952,21 @@ impl HashJoinBuildState {
                     probe_key,
                 )?;
             }
+            if self.enable_bloom_digest_runtime_filter {
+                if let Expr::ColumnRef { id, .. } = probe_key {
+                    let digests =
+                        self.bloom_digest_runtime_filter(build_chunks, build_key, probe_key)?;
+                    if self.ctx.get_cluster().is_empty()
+                        || self.hash_join_state.hash_join_desc.broadcast
+                    {
+                        if let Some(digests) = digests.filter(|digests| !digests.is_empty()) {
+                            runtime_filter.add_bloom_digest((id.to_string(), digests));
+                        }
+                    } else {
+                        self.send_runtime_filter_digests(*table_index, id.to_string(), digests)?;
+                    }
+                }
+            }
             if !runtime_filter.is_empty() {
                 bloom_filter_ready |= !runtime_filter.is_blooms_empty();
                 self.ctx.set_runtime_filter((*table_index, runtime_filter));
@@ -980,6 +1021,97 @@ impl HashJoinBuildState {
         Ok(())
     }
 
+    /// Calculate the bloom index digests of the distinct build keys,
+    /// returns None if there are too many keys to prune the blocks of the probe side.
+    fn bloom_digest_runtime_filter(
+        &self,
+        data_blocks: &[DataBlock],
+        build_key: &Expr,
+        probe_key: &Expr<String>,
+    ) -> Result<Option<Vec<u64>>> {
+        let data_type = build_key.data_type();
+        if !data_type.remove_nullable().is_number() && !data_type.remove_nullable().is_string() {
+            return Ok(None);
+        }
+        // The digests must be calculated with the data type of the probe column.
+        if probe_key.data_type().remove_nullable() != data_type.remove_nullable() {
+            return Ok(None);
+        }
+        let mut digests = HashSet::new();
+        for block in data_blocks.iter() {
+            if block.num_columns() == 0 {
+                continue;
+            }
+            let evaluator = Evaluator::new(block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+            let column = evaluator
+                .run(build_key)?
+                .convert_to_full_column(data_type, block.num_rows());
+            let (column, validity) =
+                BloomIndex::calculate_nullable_column_digest(&self.func_ctx, &column, data_type)?;
+            for (idx, digest) in column.iter().enumerate() {
+                // Null keys match nothing.
+                if validity.as_ref().is_some_and(|v| !v.get_bit(idx)) {
+                    continue;
+                }
+                digests.insert(*digest);
+                if digests.len() > BLOOM_DIGEST_RUNTIME_FILTER_THRESHOLD {
+                    return Ok(None);
+                }
+            }
+        }
+        Ok(Some(digests.into_iter().collect()))
+    }
+
+    /// Each node of a shuffle join only builds the keys of its own partition,
+    /// send the digests to the other nodes, the probe side is pruned after
+    /// the digests of all the nodes are merged.
+    fn send_runtime_filter_digests(
+        &self,
+        table_index: IndexType,
+        column: String,
+        digests: Option<Vec<u64>>,
+    ) -> Result<()> {
+        let cluster = self.ctx.get_cluster();
+        let packet = RuntimeFilterDigests {
+            query_id: self.ctx.get_id(),
+            table_index,
+            column,
+            node_id: cluster.local_id.clone(),
+            num_nodes: cluster.nodes.len(),
+            digests,
+        };
+        let mut message = HashMap::with_capacity(cluster.nodes.len());
+        for node in &cluster.nodes {
+            if node.id != cluster.local_id {
+                message.insert(node.id.clone(), packet.clone());
+            }
+        }
+        self.ctx.merge_runtime_filter_digests(
+            packet.table_index,
+            packet.column,
+            packet.node_id,
+            packet.digests,
+            packet.num_nodes,
+        );
+
+        let settings = self.ctx.get_settings();
+        let flight_params = FlightParams {
+            timeout: settings.get_flight_client_timeout()?,
+            retry_times: settings.get_flight_max_retry_times()?,
+            retry_interval: settings.get_flight_retry_interval()?,
+        };
+        GlobalIORuntime::instance().spawn(async move {
+            // The digests are only used to prune, failing to send them is not an error.
+            if let Err(cause) = cluster
+                .do_action::<_, bool>(SET_RUNTIME_FILTER_DIGESTS, message, flight_params)
+                .await
+            {
+                warn!("Failed to send runtime filter digests: {:?}", cause);
+            }
+        });
+        Ok(())
+    }
+
     fn inlist_runtime_filter(
         &self,
         runtime_filter: &mut RuntimeFilterInfo,
@@ -36,6 +36,7 @@ use parking_lot::RwLock;
 
 use crate::pipelines::executor::WatchNotify;
 use crate::pipelines::processors::transforms::range_join::IEJoinState;
+use crate::pipelines::processors::transforms::range_join::SpatialJoinState;
 use crate::sessions::QueryContext;
 
 pub struct RangeJoinState {
@@ -61,6 +62,8 @@ pub struct RangeJoinState {
     pub(crate) finished_tasks: AtomicU64,
     // IEJoin state
     pub(crate) ie_join_state: Option<IEJoinState>,
+    // Spatial join state
+    pub(crate) spatial_join_state: Option<SpatialJoinState>,
 }
 
 impl RangeJoinState {
@@ -70,6 +73,11 @@ impl RangeJoinState {
         } else {
             None
         };
+        let spatial_join_state = if matches!(range_join.range_join_type, RangeJoinType::Spatial) {
+            Some(SpatialJoinState::new())
+        } else {
+            None
+        };
 
         Self {
             ctx,
@@ -88,6 +96,7 @@ impl RangeJoinState {
             row_offset: RwLock::new(vec![]),
             finished_tasks: AtomicU64::new(0),
             ie_join_state,
+            spatial_join_state,
         }
     }
 
@@ -258,6 +267,9 @@ impl RangeJoinState {
             right_sorted_blocks.push(keys_block);
             current_rows += right_block.num_rows();
         }
+        if let Some(spatial_join_state) = &self.spatial_join_state {
+            spatial_join_state.build(&right_sorted_blocks);
+        }
         // Add tasks
         let mut row_offset = self.row_offset.write();
         let mut left_offset = 0;
@@ -0,0 +1,119 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::ScalarRef;
+use databend_common_io::ewkb_bounding_box;
+use parking_lot::RwLock;
+use rstar::primitives::GeomWithData;
+use rstar::primitives::Rectangle;
+use rstar::RTree;
+use rstar::AABB;
+
+use crate::pipelines::processors::transforms::range_join::filter_block;
+use crate::pipelines::processors::transforms::range_join::RangeJoinState;
+
+// The bounding box of a geometry, with the row index in the block
+type RowBoundingBox = GeomWithData<Rectangle<[f64; 2]>, u32>;
+
+pub struct SpatialJoinState {
+    // R-tree of the bounding boxes of the right join keys for each right block
+    rtrees: RwLock<Vec<RTree<RowBoundingBox>>>,
+}
+
+impl SpatialJoinState {
+    pub(crate) fn new() -> Self {
+        Self {
+            rtrees: RwLock::new(vec![]),
+        }
+    }
+
+    // Bulk load the R-trees after the right blocks are partitioned
+    pub(crate) fn build(&self, right_sorted_blocks: &[DataBlock]) {
+        let mut rtrees = self.rtrees.write();
+        for block in right_sorted_blocks.iter() {
+            let rows = block_bounding_boxes(block)
+                .enumerate()
+                .filter_map(|(idx, bbox)| {
+                    let [xmin, ymin, xmax, ymax] = bbox?;
+                    let rect = Rectangle::from_corners([xmin, ymin], [xmax, ymax]);
+                    Some(RowBoundingBox::new(rect, idx as u32))
+                })
+                .collect();
+            rtrees.push(RTree::bulk_load(rows));
+        }
+    }
+}
+
+impl RangeJoinState {
+    pub fn spatial_join(&self, task_id: usize) -> Result<Vec<DataBlock>> {
+        let spatial_join_state = self.spatial_join_state.as_ref().unwrap();
+        let tasks = self.tasks.read();
+        let (left_idx, right_idx) = tasks[task_id];
+        let left_sorted_blocks = self.left_sorted_blocks.read();
+        let rtrees = spatial_join_state.rtrees.read();
+        let rtree = &rtrees[right_idx];
+
+        // Probe the R-tree with the bounding boxes of the left join keys,
+        // the exact spatial predicate is evaluated with the other conditions.
+        let mut left_indices = vec![];
+        let mut right_indices = vec![];
+        for (idx, bbox) in block_bounding_boxes(&left_sorted_blocks[left_idx]).enumerate() {
+            let Some([xmin, ymin, xmax, ymax]) = bbox else {
+                continue;
+            };
+            let envelope = AABB::from_corners([xmin, ymin], [xmax, ymax]);
+            for row in rtree.locate_in_envelope_intersecting(&envelope) {
+                left_indices.push(idx as u32);
+                right_indices.push(row.data);
+            }
+        }
+
+        let max_block_size = self.ctx.get_settings().get_max_block_size()? as usize;
+        let left_table = self.left_table.read();
+        let right_table = self.right_table.read();
+        let mut result_blocks = Vec::with_capacity(left_indices.len() / max_block_size + 1);
+        for (left_chunk, right_chunk) in left_indices
+            .chunks(max_block_size)
+            .zip(right_indices.chunks(max_block_size))
+        {
+            let mut result_block = left_table[left_idx].take(left_chunk)?;
+            let right_result_block = right_table[right_idx].take(right_chunk)?;
+            // Merge left_result_block and right_result_block
+            for col in right_result_block.columns() {
+                result_block.add_column(col.clone());
+            }
+            for filter in self.other_conditions.iter() {
+                result_block = filter_block(result_block, filter)?;
+            }
+            result_blocks.push(result_block);
+        }
+        Ok(result_blocks)
+    }
+}
+
+// Bounding boxes of the geometries in the first column of the key block,
+// `None` for NULL and empty geometries.
+fn block_bounding_boxes(block: &DataBlock) -> impl Iterator<Item = Option<[f64; 4]>> + '_ {
+    let entry = &block.columns()[0];
+    let column = entry
+        .value
+        .convert_to_full_column(&entry.data_type, block.num_rows());
+    (0..column.len()).map(move |idx| match column.index(idx) {
+        Some(ScalarRef::Geometry(ewkb)) => ewkb_bounding_box(ewkb),
+        _ => None,
+    })
+}
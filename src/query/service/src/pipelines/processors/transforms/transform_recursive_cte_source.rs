@@ -305,6 +305,7 @@ async fn create_memory_table_for_cte_scan(
                 field_comments: vec![],
                 cluster_key: None,
                 as_select: None,
+                template: None,
                 inverted_indexes: None,
             };
             let create_table_interpreter =
@@ -236,6 +236,7 @@ impl PhysicalPlanReplacer for Fragmenter {
             stat_info: plan.stat_info.clone(),
             probe_keys_rt: plan.probe_keys_rt.clone(),
             enable_bloom_runtime_filter: plan.enable_bloom_runtime_filter,
+            runtime_filter_partition_pruning: plan.runtime_filter_partition_pruning,
             broadcast: plan.broadcast,
             single_to_inner: plan.single_to_inner.clone(),
             build_side_cache_info: plan.build_side_cache_info.clone(),
@@ -32,6 +32,7 @@ use crate::servers::flight::v1::actions::init_query_fragments::init_query_fragme
 use crate::servers::flight::v1::actions::kill_query::kill_query;
 use crate::servers::flight::v1::actions::set_priority::set_priority;
 use crate::servers::flight::v1::actions::set_priority::SET_PRIORITY;
+use crate::servers::flight::v1::actions::set_runtime_filter_digests::set_runtime_filter_digests;
 use crate::servers::flight::v1::actions::start_prepared_query::start_prepared_query;
 use crate::servers::flight::v1::actions::system_action::system_action;
 use crate::servers::flight::v1::actions::truncate_table::truncate_table;
@@ -39,6 +40,7 @@ use crate::servers::flight::v1::actions::truncate_table::TRUNCATE_TABLE;
 use crate::servers::flight::v1::actions::GET_PROFILE;
 use crate::servers::flight::v1::actions::INIT_QUERY_FRAGMENTS;
 use crate::servers::flight::v1::actions::KILL_QUERY;
+use crate::servers::flight::v1::actions::SET_RUNTIME_FILTER_DIGESTS;
 use crate::servers::flight::v1::actions::START_PREPARED_QUERY;
 use crate::servers::flight::v1::actions::SYSTEM_ACTION;
 
@@ -135,4 +137,5 @@ pub fn flight_actions() -> FlightActions {
         .action(SET_PRIORITY, set_priority)
         .action(SYSTEM_ACTION, system_action)
         .action(GET_PROFILE, get_profile)
+        .action(SET_RUNTIME_FILTER_DIGESTS, set_runtime_filter_digests)
 }
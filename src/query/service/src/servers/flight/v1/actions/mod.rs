@@ -18,6 +18,7 @@ mod init_query_env;
 mod init_query_fragments;
 mod kill_query;
 mod set_priority;
+mod set_runtime_filter_digests;
 mod start_prepared_query;
 mod system_action;
 mod truncate_table;
@@ -35,6 +36,8 @@ pub use init_query_fragments::init_query_fragments;
 pub use init_query_fragments::INIT_QUERY_FRAGMENTS;
 pub use kill_query::KILL_QUERY;
 pub use set_priority::SET_PRIORITY;
+pub use set_runtime_filter_digests::RuntimeFilterDigests;
+pub use set_runtime_filter_digests::SET_RUNTIME_FILTER_DIGESTS;
 pub use start_prepared_query::START_PREPARED_QUERY;
 pub use system_action::SYSTEM_ACTION;
 pub use truncate_table::TRUNCATE_TABLE;
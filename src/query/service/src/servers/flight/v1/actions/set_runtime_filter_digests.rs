@@ -0,0 +1,48 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_sql::IndexType;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::servers::flight::v1::exchange::DataExchangeManager;
+
+pub static SET_RUNTIME_FILTER_DIGESTS: &str = "/actions/set_runtime_filter_digests";
+
+/// The build key digests of a shuffle join built by a cluster node.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RuntimeFilterDigests {
+    pub query_id: String,
+    pub table_index: IndexType,
+    pub column: String,
+    pub node_id: String,
+    pub num_nodes: usize,
+    pub digests: Option<Vec<u64>>,
+}
+
+pub async fn set_runtime_filter_digests(packet: RuntimeFilterDigests) -> Result<bool> {
+    // The query may have finished on this node, the digests are only used for pruning.
+    let Ok(ctx) = DataExchangeManager::instance().get_query_ctx(&packet.query_id) else {
+        return Ok(false);
+    };
+    ctx.merge_runtime_filter_digests(
+        packet.table_index,
+        packet.column,
+        packet.node_id,
+        packet.digests,
+        packet.num_nodes,
+    );
+    Ok(true)
+}
@@ -530,6 +530,42 @@ impl QueryContext {
             log::error!("create spill meta file error: {}", e);
         }
     }
+
+    /// Merge the build key digests of a shuffle join sent by a cluster node.
+    ///
+    /// Each node only builds the keys of its own partition, so the digests can prune
+    /// the probe side only after all the nodes have sent theirs. `None` means the node
+    /// has too many distinct keys, and no digest runtime filter will be set.
+    pub fn merge_runtime_filter_digests(
+        &self,
+        table_index: IndexType,
+        column: String,
+        node_id: String,
+        digests: Option<Vec<u64>>,
+        num_nodes: usize,
+    ) {
+        let node_digests = {
+            let mut runtime_filter_digests = self.shared.runtime_filter_digests.write();
+            let node_digests = runtime_filter_digests
+                .entry((table_index, column.clone()))
+                .or_default();
+            node_digests.insert(node_id, digests);
+            if node_digests.len() < num_nodes {
+                return;
+            }
+            node_digests.values().cloned().collect::<Option<Vec<_>>>()
+        };
+
+        if let Some(node_digests) = node_digests {
+            let digests = node_digests.into_iter().flatten().collect::<HashSet<_>>();
+            if digests.is_empty() {
+                return;
+            }
+            let mut runtime_filter = RuntimeFilterInfo::default();
+            runtime_filter.add_bloom_digest((column, digests.into_iter().collect()));
+            self.set_runtime_filter((table_index, runtime_filter));
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -1345,6 +1381,8 @@ impl TableContext for QueryContext {
     fn clear_runtime_filter(&self) {
         let mut runtime_filters = self.shared.runtime_filters.write();
         runtime_filters.clear();
+        let mut runtime_filter_digests = self.shared.runtime_filter_digests.write();
+        runtime_filter_digests.clear();
     }
 
     fn set_runtime_filter(&self, filters: (IndexType, RuntimeFilterInfo)) {
@@ -1360,6 +1398,9 @@ impl TableContext for QueryContext {
                 for filter in filters.1.get_min_max() {
                     v.get_mut().add_min_max(filter.clone());
                 }
+                for filter in filters.1.get_bloom_digest() {
+                    v.get_mut().add_bloom_digest(filter.clone());
+                }
                 for filter in filters.1.blooms() {
                     v.get_mut().add_bloom(filter);
                 }
@@ -1433,6 +1474,14 @@ impl TableContext for QueryContext {
         }
     }
 
+    fn get_bloom_digest_runtime_filter_with_id(&self, id: IndexType) -> Vec<(String, Vec<u64>)> {
+        let runtime_filters = self.shared.runtime_filters.read();
+        match runtime_filters.get(&id) {
+            Some(v) => (v.get_bloom_digest()).clone(),
+            None => vec![],
+        }
+    }
+
     fn has_bloom_runtime_filters(&self, id: usize) -> bool {
         if let Some(runtime_filter) = self.shared.runtime_filters.read().get(&id) {
             return !runtime_filter.get_bloom().is_empty();
@@ -1546,7 +1595,9 @@ impl TableContext for QueryContext {
                 };
                 OrcTable::try_create(info).await
             }
-            FileFormatParams::NdJson(..) => {
+            FileFormatParams::NdJson(..)
+            | FileFormatParams::Avro(..)
+            | FileFormatParams::Xml(..) => {
                 let schema = Arc::new(TableSchema::new(vec![TableField::new(
                     "_$1", // TODO: this name should be in visible
                     TableDataType::Variant,
@@ -1602,7 +1653,7 @@ impl TableContext for QueryContext {
             }
             _ => {
                 return Err(ErrorCode::Unimplemented(format!(
-                    "The file format in the query stage is not supported. Currently supported formats are: Parquet, NDJson, CSV, and TSV. Provided format: '{}'.",
+                    "The file format in the query stage is not supported. Currently supported formats are: Parquet, ORC, NDJson, Avro, XML, CSV, and TSV. Provided format: '{}'.",
                     stage_info.file_format_params
                 )));
             }
@@ -140,6 +140,11 @@ pub struct QueryContextShared {
 
     pub(in crate::sessions) wait_runtime_filter: Arc<RwLock<HashMap<IndexType, bool>>>,
 
+    // The build key digests of shuffle joins sent by the cluster nodes,
+    // keyed by the probe table index and column name, then by the node id.
+    pub(in crate::sessions) runtime_filter_digests:
+        Arc<RwLock<HashMap<(IndexType, String), HashMap<String, Option<Vec<u64>>>>>>,
+
     pub(in crate::sessions) merge_into_join: Arc<RwLock<MergeIntoJoin>>,
 
     // Records query level data cache metrics
@@ -204,6 +209,7 @@ impl QueryContextShared {
             runtime_filters: Default::default(),
             runtime_filter_ready: Default::default(),
             wait_runtime_filter: Default::default(),
+            runtime_filter_digests: Default::default(),
             merge_into_join: Default::default(),
             multi_table_insert_status: Default::default(),
             query_queued_duration: Arc::new(RwLock::new(Duration::from_secs(0))),
@@ -13,7 +13,8 @@
 // limitations under the License.
 
 mod infer_schema_table;
-mod parquet;
+mod row_based;
+mod source;
 mod table_args;
 
 pub use infer_schema_table::InferSchemaTable;
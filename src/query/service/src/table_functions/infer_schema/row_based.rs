@@ -0,0 +1,640 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use csv_core::ReadRecordResult;
+use csv_core::Terminator;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::decimal::DecimalSize;
+use databend_common_expression::types::DecimalDataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_meta_app::principal::CsvFileFormatParams;
+use databend_common_meta_app::principal::TsvFileFormatParams;
+
+const MAX_DECIMAL_PRECISION: u8 = 38;
+const CANDIDATE_DELIMITERS: [u8; 4] = [b',', b'\t', b';', b'|'];
+const TIMESTAMP_FORMATS: [&str; 2] = ["%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M:%S%.f"];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum InferredType {
+    Null,
+    Boolean,
+    Integer { digits: u8 },
+    Decimal { integer_digits: u8, scale: u8 },
+    Float,
+    Date,
+    Timestamp,
+    String,
+    Variant,
+}
+
+impl InferredType {
+    /// The least common type of two values, `fallback` is used if they have nothing in common.
+    fn merge(self, other: InferredType, fallback: InferredType) -> InferredType {
+        use InferredType::*;
+        match (self, other) {
+            (Null, t) | (t, Null) => t,
+            (a, b) if a == b => a,
+            (Variant, _) | (_, Variant) => Variant,
+            (Integer { digits: d1 }, Integer { digits: d2 }) => Integer { digits: d1.max(d2) },
+            (
+                Integer { digits },
+                Decimal {
+                    integer_digits,
+                    scale,
+                },
+            )
+            | (
+                Decimal {
+                    integer_digits,
+                    scale,
+                },
+                Integer { digits },
+            ) => Self::decimal(integer_digits.max(digits), scale),
+            (
+                Decimal {
+                    integer_digits: d1,
+                    scale: s1,
+                },
+                Decimal {
+                    integer_digits: d2,
+                    scale: s2,
+                },
+            ) => Self::decimal(d1.max(d2), s1.max(s2)),
+            (Integer { .. } | Decimal { .. } | Float, Integer { .. } | Decimal { .. } | Float) => {
+                Float
+            }
+            (Date | Timestamp, Date | Timestamp) => Timestamp,
+            (Date | Timestamp, String) | (String, Date | Timestamp) => String,
+            _ => fallback,
+        }
+    }
+
+    fn decimal(integer_digits: u8, scale: u8) -> InferredType {
+        if integer_digits as usize + scale as usize > MAX_DECIMAL_PRECISION as usize {
+            InferredType::Float
+        } else {
+            InferredType::Decimal {
+                integer_digits,
+                scale,
+            }
+        }
+    }
+
+    /// Infer the type of a text field of CSV or TSV.
+    fn from_text(text: &str) -> InferredType {
+        if text.eq_ignore_ascii_case("true") || text.eq_ignore_ascii_case("false") {
+            return InferredType::Boolean;
+        }
+        if let Some(t) = Self::from_number(text) {
+            return t;
+        }
+        Self::from_datetime(text).unwrap_or(InferredType::String)
+    }
+
+    fn from_number(text: &str) -> Option<InferredType> {
+        let unsigned = text.strip_prefix(['+', '-']).unwrap_or(text);
+        let (mantissa, has_exponent) = match unsigned.split_once(['e', 'E']) {
+            Some((mantissa, exponent)) => {
+                let exponent = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+                if exponent.is_empty() || !exponent.bytes().all(|c| c.is_ascii_digit()) {
+                    return None;
+                }
+                (mantissa, true)
+            }
+            None => (unsigned, false),
+        };
+        let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        if integer.is_empty()
+            || !integer.bytes().all(|c| c.is_ascii_digit())
+            || !fraction.bytes().all(|c| c.is_ascii_digit())
+            || (mantissa.contains('.') && fraction.is_empty())
+        {
+            return None;
+        }
+        // keep the leading zeros of codes like `007`
+        if integer.len() > 1 && integer.starts_with('0') {
+            return None;
+        }
+        if has_exponent {
+            return Some(InferredType::Float);
+        }
+        if fraction.is_empty() {
+            if text.parse::<i64>().is_ok() {
+                return Some(InferredType::Integer {
+                    digits: integer.len() as u8,
+                });
+            }
+            return match u8::try_from(integer.len()) {
+                Ok(digits) if digits <= MAX_DECIMAL_PRECISION => Some(Self::decimal(digits, 0)),
+                _ => None,
+            };
+        }
+        match (u8::try_from(integer.len()), u8::try_from(fraction.len())) {
+            (Ok(integer_digits), Ok(scale)) => Some(Self::decimal(integer_digits, scale)),
+            _ => Some(InferredType::Float),
+        }
+    }
+
+    fn from_datetime(text: &str) -> Option<InferredType> {
+        // all the supported formats start with `YYYY-MM-DD`
+        if text.len() < 10 || !text.is_char_boundary(10) {
+            return None;
+        }
+        NaiveDate::parse_from_str(&text[..10], "%Y-%m-%d").ok()?;
+        if text.len() == 10 {
+            return Some(InferredType::Date);
+        }
+        if DateTime::parse_from_rfc3339(text).is_ok()
+            || TIMESTAMP_FORMATS
+                .iter()
+                .any(|f| NaiveDateTime::parse_from_str(text, f).is_ok())
+        {
+            return Some(InferredType::Timestamp);
+        }
+        None
+    }
+
+    /// Infer the type of a json value of NDJSON.
+    fn from_json(value: &serde_json::Value) -> InferredType {
+        match value {
+            serde_json::Value::Null => InferredType::Null,
+            serde_json::Value::Bool(_) => InferredType::Boolean,
+            serde_json::Value::Number(n) if n.is_i64() => InferredType::Integer {
+                digits: n.to_string().trim_start_matches('-').len() as u8,
+            },
+            serde_json::Value::Number(n) if n.is_u64() => InferredType::decimal(20, 0),
+            serde_json::Value::Number(_) => InferredType::Float,
+            serde_json::Value::String(s) => Self::from_datetime(s).unwrap_or(InferredType::String),
+            serde_json::Value::Array(_) | serde_json::Value::Object(_) => InferredType::Variant,
+        }
+    }
+
+    fn to_data_type(self) -> TableDataType {
+        match self {
+            InferredType::Null | InferredType::String => TableDataType::String,
+            InferredType::Boolean => TableDataType::Boolean,
+            InferredType::Integer { .. } => TableDataType::Number(NumberDataType::Int64),
+            InferredType::Decimal {
+                integer_digits,
+                scale,
+            } => {
+                let size = DecimalSize {
+                    precision: (integer_digits + scale).max(1),
+                    scale,
+                };
+                // the precision never exceeds MAX_DECIMAL_PRECISION
+                TableDataType::Decimal(DecimalDataType::from_size(size).unwrap())
+            }
+            InferredType::Float => TableDataType::Number(NumberDataType::Float64),
+            InferredType::Date => TableDataType::Date,
+            InferredType::Timestamp => TableDataType::Timestamp,
+            InferredType::Variant => TableDataType::Variant,
+        }
+    }
+}
+
+/// Infer the schema from the records of the sampled files, all the inferred columns are nullable.
+pub(crate) struct SchemaInferrer {
+    names: Vec<Option<String>>,
+    types: Vec<InferredType>,
+    fallback: InferredType,
+    /// Whether to detect the header of text records if `headers` is 0.
+    detect_header: bool,
+}
+
+impl SchemaInferrer {
+    pub fn new_for_text(detect_header: bool) -> Self {
+        Self {
+            names: vec![],
+            types: vec![],
+            fallback: InferredType::String,
+            detect_header,
+        }
+    }
+
+    pub fn new_for_json() -> Self {
+        Self {
+            names: vec![],
+            types: vec![],
+            fallback: InferredType::Variant,
+            detect_header: false,
+        }
+    }
+
+    fn add_text_record(&mut self, record: &[String], null_if: &[&str]) {
+        for (i, field) in record.iter().enumerate() {
+            let ty = if field.is_empty() || null_if.contains(&field.as_str()) {
+                InferredType::Null
+            } else {
+                InferredType::from_text(field)
+            };
+            self.merge_column(i, None, ty);
+        }
+    }
+
+    fn merge_column(&mut self, i: usize, name: Option<&str>, ty: InferredType) {
+        if i >= self.types.len() {
+            self.types.resize(i + 1, InferredType::Null);
+            self.names.resize(i + 1, None);
+        }
+        if self.names[i].is_none() {
+            self.names[i] = name.map(|n| n.to_string());
+        }
+        self.types[i] = self.types[i].merge(ty, self.fallback);
+    }
+
+    fn set_header(&mut self, header: &[String]) {
+        for (i, name) in header.iter().enumerate() {
+            let name = name.trim();
+            if !name.is_empty() {
+                self.merge_column(i, Some(name), InferredType::Null);
+            }
+        }
+    }
+
+    /// The records of CSV, the first record is the header if `headers` is set, or it looks like
+    /// one and the header is to be detected.
+    pub fn add_csv(
+        &mut self,
+        data: &[u8],
+        truncated: bool,
+        params: &CsvFileFormatParams,
+        max_records: usize,
+    ) -> Result<()> {
+        let mut delimiter = single_byte(&params.field_delimiter, "field_delimiter")?;
+        let read = |delimiter: u8, max_records: usize| {
+            let mut records = read_csv_records(data, params, delimiter, max_records)?;
+            if truncated && records.len() < max_records {
+                // the last record may be cut by the end of sample
+                records.pop();
+            }
+            Ok::<_, ErrorCode>(records)
+        };
+        let limit = max_records.saturating_add(params.headers.max(1) as usize);
+        let mut records = read(delimiter, limit)?;
+        // sniff the delimiter if the records can not be split by the one specified
+        if records.iter().all(|r| r.len() <= 1) {
+            if let Some(d) = sniff_delimiter(|d| read(d, 20)) {
+                delimiter = d;
+                records = read(delimiter, limit)?;
+            }
+        }
+        self.add_text_records(records, params.headers, max_records, &[params
+            .null_display
+            .as_str()]);
+        Ok(())
+    }
+
+    pub fn add_tsv(
+        &mut self,
+        data: &[u8],
+        truncated: bool,
+        params: &TsvFileFormatParams,
+        max_records: usize,
+    ) -> Result<()> {
+        let delimiter = single_byte(&params.field_delimiter, "field_delimiter")?;
+        let record_delimiter = single_byte(&params.record_delimiter, "record_delimiter")?;
+        let mut lines = data.split(|c| *c == record_delimiter).collect::<Vec<_>>();
+        if truncated || lines.last().is_some_and(|l| l.is_empty()) {
+            lines.pop();
+        }
+        let records = lines
+            .into_iter()
+            .take(max_records.saturating_add(params.headers.max(1) as usize))
+            .map(|line| {
+                let line = line.strip_suffix(b"\r").unwrap_or(line);
+                line.split(|c| *c == delimiter)
+                    .map(|f| String::from_utf8_lossy(f).to_string())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        self.add_text_records(records, params.headers, max_records, &["\\N"]);
+        Ok(())
+    }
+
+    /// `records` starts with the header, which is detected if `headers` is 0 and `detect_header`
+    /// is set.
+    fn add_text_records(
+        &mut self,
+        mut records: Vec<Vec<String>>,
+        headers: u64,
+        max_records: usize,
+        null_if: &[&str],
+    ) {
+        records.retain(|r| !(r.len() == 1 && r[0].is_empty()));
+        let skip = if headers > 0 {
+            headers as usize
+        } else if self.detect_header && has_header(&records, null_if) {
+            1
+        } else {
+            0
+        };
+        if skip > 0 {
+            if let Some(header) = records.first() {
+                self.set_header(header);
+            }
+        }
+        for record in records.iter().skip(skip).take(max_records) {
+            self.add_text_record(record, null_if);
+        }
+    }
+
+    pub fn add_ndjson(
+        &mut self,
+        data: &[u8],
+        truncated: bool,
+        path: &str,
+        max_records: usize,
+    ) -> Result<()> {
+        let mut lines = data.split(|c| *c == b'\n').collect::<Vec<_>>();
+        if truncated {
+            lines.pop();
+        }
+        let lines = lines
+            .into_iter()
+            .enumerate()
+            .filter(|(_, line)| !line.trim_ascii().is_empty())
+            .take(max_records);
+        for (line_number, line) in lines {
+            let value: serde_json::Value = serde_json::from_slice(line).map_err(|e| {
+                ErrorCode::BadBytes(format!(
+                    "Invalid json at line {} of file '{path}': {e}",
+                    line_number + 1
+                ))
+            })?;
+            let serde_json::Value::Object(object) = value else {
+                return Err(ErrorCode::BadBytes(format!(
+                    "Expect a json object at line {} of file '{path}'",
+                    line_number + 1
+                )));
+            };
+            for (key, value) in object.iter() {
+                let ty = InferredType::from_json(value);
+                let i = self
+                    .names
+                    .iter()
+                    .position(|n| n.as_deref() == Some(key.as_str()))
+                    .unwrap_or(self.names.len());
+                self.merge_column(i, Some(key), ty);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn finish(self) -> TableSchema {
+        let fields = self
+            .names
+            .into_iter()
+            .zip(self.types)
+            .enumerate()
+            .map(|(i, (name, ty))| {
+                let name = name.unwrap_or_else(|| format!("c{}", i + 1));
+                TableField::new(&name, ty.to_data_type().wrap_nullable())
+            })
+            .collect();
+        TableSchema::new(fields)
+    }
+}
+
+/// The first record is a header if all its fields are strings, while some of the columns
+/// have other types in the rest records.
+fn has_header(records: &[Vec<String>], null_if: &[&str]) -> bool {
+    let Some((header, rest)) = records.split_first() else {
+        return false;
+    };
+    if rest.is_empty()
+        || header.iter().any(|f| {
+            f.trim().is_empty()
+                || null_if.contains(&f.as_str())
+                || InferredType::from_text(f) != InferredType::String
+        })
+    {
+        return false;
+    }
+    let mut body = SchemaInferrer::new_for_text(false);
+    for record in rest {
+        body.add_text_record(record, null_if);
+    }
+    body.types
+        .iter()
+        .any(|t| !matches!(t, InferredType::String | InferredType::Null))
+}
+
+/// Choose the delimiter which splits the sample into the same number of fields, the more the better.
+fn sniff_delimiter(read: impl Fn(u8) -> Result<Vec<Vec<String>>>) -> Option<u8> {
+    CANDIDATE_DELIMITERS
+        .iter()
+        .filter_map(|d| {
+            let records = read(*d).ok()?;
+            let num_fields = records.first()?.len();
+            (num_fields > 1 && records.iter().all(|r| r.len() == num_fields))
+                .then_some((num_fields, *d))
+        })
+        .max_by_key(|(num_fields, _)| *num_fields)
+        .map(|(_, d)| d)
+}
+
+fn single_byte(option: &str, name: &str) -> Result<u8> {
+    match option.as_bytes() {
+        [b] => Ok(*b),
+        b"\r\n" => Ok(b'\n'),
+        _ => Err(ErrorCode::BadArguments(format!(
+            "infer_schema only supports single byte {name}, but got {option:?}"
+        ))),
+    }
+}
+
+fn read_csv_records(
+    data: &[u8],
+    params: &CsvFileFormatParams,
+    delimiter: u8,
+    max_records: usize,
+) -> Result<Vec<Vec<String>>> {
+    let terminator = match params.record_delimiter.as_str() {
+        "\n" | "\r\n" => Terminator::CRLF,
+        other => Terminator::Any(single_byte(other, "record_delimiter")?),
+    };
+    let mut builder = csv_core::ReaderBuilder::new();
+    builder.delimiter(delimiter).terminator(terminator);
+    if let Some(quote) = params.quote.as_bytes().first() {
+        builder.quote(*quote);
+    }
+    if let Some(escape) = params.escape.as_bytes().first() {
+        builder.escape(Some(*escape));
+    }
+    let mut reader = builder.build();
+
+    let mut records = vec![];
+    let mut input = data;
+    let mut output = vec![0u8; 4096];
+    let mut ends = vec![0usize; 64];
+    let (mut out_len, mut ends_len) = (0, 0);
+    while records.len() < max_records {
+        let (result, n_in, n_out, n_ends) =
+            reader.read_record(input, &mut output[out_len..], &mut ends[ends_len..]);
+        input = &input[n_in..];
+        out_len += n_out;
+        ends_len += n_ends;
+        match result {
+            // an empty input tells the reader the end of data
+            ReadRecordResult::InputEmpty => {}
+            ReadRecordResult::OutputFull => output.resize(output.len() * 2, 0),
+            ReadRecordResult::OutputEndsFull => ends.resize(ends.len() * 2, 0),
+            ReadRecordResult::Record => {
+                let mut start = 0;
+                let record = ends[..ends_len]
+                    .iter()
+                    .map(|end| {
+                        let field = String::from_utf8_lossy(&output[start..*end]).to_string();
+                        start = *end;
+                        field
+                    })
+                    .collect();
+                records.push(record);
+                (out_len, ends_len) = (0, 0);
+            }
+            ReadRecordResult::End => break,
+        }
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_infer_text_type() {
+        use InferredType::*;
+        for (text, expected) in [
+            ("123", Integer { digits: 3 }),
+            ("-9223372036854775808", Integer { digits: 19 }),
+            ("92233720368547758080", Decimal {
+                integer_digits: 20,
+                scale: 0,
+            }),
+            ("3.14", Decimal {
+                integer_digits: 1,
+                scale: 2,
+            }),
+            ("1e10", Float),
+            ("-1.5E-3", Float),
+            ("007", String),
+            ("1.", String),
+            (".5", String),
+            ("TRUE", Boolean),
+            ("2024-02-29", Date),
+            ("2023-02-29", String),
+            ("2024-01-02 03:04:05", Timestamp),
+            ("2024-01-02T03:04:05.123", Timestamp),
+            ("2024-01-02T03:04:05+08:00", Timestamp),
+            ("2024-01-02 03:04", String),
+            ("2024/01/02", String),
+            ("hello", String),
+        ] {
+            assert_eq!(InferredType::from_text(text), expected, "{text}");
+        }
+    }
+
+    #[test]
+    fn test_merge_type() {
+        use InferredType::*;
+        let d = |integer_digits, scale| Decimal {
+            integer_digits,
+            scale,
+        };
+        let i = |digits| Integer { digits };
+        assert_eq!(Null.merge(i(1), String), i(1));
+        assert_eq!(i(1).merge(i(3), String), i(3));
+        assert_eq!(i(4).merge(d(2, 3), String), d(4, 3));
+        assert_eq!(d(5, 1).merge(d(2, 3), String), d(5, 3));
+        assert_eq!(d(30, 0).merge(d(2, 10), String), Float);
+        assert_eq!(i(1).merge(Float, String), Float);
+        assert_eq!(Date.merge(Timestamp, String), Timestamp);
+        assert_eq!(Date.merge(String, Variant), String);
+        assert_eq!(Date.merge(i(1), String), String);
+        assert_eq!(Date.merge(i(1), Variant), Variant);
+        assert_eq!(String.merge(Variant, String), Variant);
+    }
+
+    fn schema_of(inferrer: SchemaInferrer) -> Vec<(String, String)> {
+        inferrer
+            .finish()
+            .fields()
+            .iter()
+            .map(|f| (f.name().clone(), f.data_type().remove_nullable().sql_name()))
+            .collect()
+    }
+
+    #[test]
+    fn test_infer_csv() -> Result<()> {
+        let params = CsvFileFormatParams::default();
+        let data = b"id,name,score,day\n1,a,1.5,2024-01-01\n2,\"b,c\",,2024-01-02 00:00:00\n";
+        let mut inferrer = SchemaInferrer::new_for_text(true);
+        inferrer.add_csv(data, false, &params, 100)?;
+        assert_eq!(schema_of(inferrer), vec![
+            ("id".to_string(), "BIGINT".to_string()),
+            ("name".to_string(), "VARCHAR".to_string()),
+            ("score".to_string(), "DECIMAL(2, 1)".to_string()),
+            ("day".to_string(), "TIMESTAMP".to_string()),
+        ]);
+
+        // no header, sniff the delimiter, the last record is cut by the sample
+        let data = b"1;x;true\n2;y;false\n3;z;tr";
+        let mut inferrer = SchemaInferrer::new_for_text(true);
+        inferrer.add_csv(data, true, &params, 100)?;
+        assert_eq!(schema_of(inferrer), vec![
+            ("c1".to_string(), "BIGINT".to_string()),
+            ("c2".to_string(), "VARCHAR".to_string()),
+            ("c3".to_string(), "BOOLEAN".to_string()),
+        ]);
+
+        // the header is not detected, it is a record
+        let data = b"id,flag\n1,true\n2,false\n";
+        let mut inferrer = SchemaInferrer::new_for_text(false);
+        inferrer.add_csv(data, false, &params, 100)?;
+        assert_eq!(schema_of(inferrer), vec![
+            ("c1".to_string(), "VARCHAR".to_string()),
+            ("c2".to_string(), "VARCHAR".to_string()),
+        ]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_infer_ndjson() -> Result<()> {
+        let data = br#"{"a": 1, "b": "2024-01-01", "c": {"x": 1}}
+{"a": 1.5, "b": null, "d": [1]}
+
+{"a": 2, "b": "x", "c": null}"#;
+        let mut inferrer = SchemaInferrer::new_for_json();
+        inferrer.add_ndjson(data, false, "test", 100)?;
+        assert_eq!(schema_of(inferrer), vec![
+            ("a".to_string(), "DOUBLE".to_string()),
+            ("b".to_string(), "VARCHAR".to_string()),
+            ("c".to_string(), "VARIANT".to_string()),
+            ("d".to_string(), "VARIANT".to_string()),
+        ]);
+
+        let mut inferrer = SchemaInferrer::new_for_json();
+        assert!(inferrer.add_ndjson(b"[1]", false, "test", 100).is_err());
+        Ok(())
+    }
+}
@@ -13,11 +13,14 @@
 // limitations under the License.
 
 use std::collections::BTreeMap;
+use std::str::FromStr;
 use std::sync::Arc;
 
 use databend_common_ast::ast::FileLocation;
 use databend_common_ast::ast::UriLocation;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_compress::CompressAlgorithm;
+use databend_common_compress::DecompressDecoder;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::BooleanType;
@@ -26,6 +29,7 @@ use databend_common_expression::types::UInt64Type;
 use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
 use databend_common_expression::TableSchema;
+use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::StageFileFormatType;
 use databend_common_meta_app::principal::StageType;
 use databend_common_pipeline_core::processors::OutputPort;
@@ -36,24 +40,34 @@ use databend_common_sql::binder::resolve_file_location;
 use databend_common_storage::init_stage_operator;
 use databend_common_storage::read_parquet_schema_async_rs;
 use databend_common_storage::StageFilesInfo;
+use databend_common_storages_stage::get_compression_alg_copy;
+use opendal::Operator;
 use opendal::Scheme;
 
 use crate::table_functions::infer_schema::infer_schema_table::INFER_SCHEMA;
+use crate::table_functions::infer_schema::row_based::SchemaInferrer;
 use crate::table_functions::infer_schema::table_args::InferSchemaArgsParsed;
 
-pub(crate) struct ParquetInferSchemaSource {
+const DEFAULT_MAX_FILE_COUNT: usize = 1;
+const DEFAULT_MAX_RECORDS_PRE_FILE: usize = 1000;
+// the size to read from the head of files, after decompressed
+const MAX_SAMPLE_BYTES: u64 = 16 * 1024 * 1024;
+// the size of each read of compressed files
+const READ_CHUNK_BYTES: u64 = 1024 * 1024;
+
+pub(crate) struct InferSchemaSource {
     is_finished: bool,
     ctx: Arc<dyn TableContext>,
     args_parsed: InferSchemaArgsParsed,
 }
 
-impl ParquetInferSchemaSource {
+impl InferSchemaSource {
     pub fn create(
         ctx: Arc<dyn TableContext>,
         output: Arc<OutputPort>,
         args_parsed: InferSchemaArgsParsed,
     ) -> Result<ProcessorPtr> {
-        AsyncSourcer::create(ctx.clone(), output, ParquetInferSchemaSource {
+        AsyncSourcer::create(ctx.clone(), output, InferSchemaSource {
             is_finished: false,
             ctx,
             args_parsed,
@@ -62,7 +76,7 @@ impl ParquetInferSchemaSource {
 }
 
 #[async_trait::async_trait]
-impl AsyncSource for ParquetInferSchemaSource {
+impl AsyncSource for InferSchemaSource {
     const NAME: &'static str = INFER_SCHEMA;
 
     #[async_backtrace::framed]
@@ -118,13 +132,13 @@ impl AsyncSource for ParquetInferSchemaSource {
         };
         let operator = init_stage_operator(&stage_info)?;
 
-        let first_file = files_info.first_file(&operator).await?;
         let file_format_params = match &self.args_parsed.file_format {
             Some(f) => self.ctx.get_file_format(f).await?,
             None => stage_info.file_format_params.clone(),
         };
-        let schema = match file_format_params.get_type() {
-            StageFileFormatType::Parquet => {
+        let schema = match &file_format_params {
+            FileFormatParams::Parquet(_) => {
+                let first_file = files_info.first_file(&operator).await?;
                 let arrow_schema = read_parquet_schema_async_rs(
                     &operator,
                     &first_file.path,
@@ -133,9 +147,13 @@ impl AsyncSource for ParquetInferSchemaSource {
                 .await?;
                 TableSchema::try_from(&arrow_schema)?
             }
+            FileFormatParams::Csv(_) | FileFormatParams::Tsv(_) | FileFormatParams::NdJson(_) => {
+                self.infer_row_based_schema(&operator, &files_info, &file_format_params)
+                    .await?
+            }
             _ => {
                 return Err(ErrorCode::BadArguments(
-                    "infer_schema is currently limited to format Parquet",
+                    "infer_schema is currently limited to format Parquet, CSV, TSV and NDJSON",
                 ));
             }
         };
@@ -163,3 +181,95 @@ impl AsyncSource for ParquetInferSchemaSource {
         Ok(Some(block))
     }
 }
+
+impl InferSchemaSource {
+    /// Sample the first records of the first files.
+    #[async_backtrace::framed]
+    async fn infer_row_based_schema(
+        &self,
+        operator: &Operator,
+        files_info: &StageFilesInfo,
+        params: &FileFormatParams,
+    ) -> Result<TableSchema> {
+        let max_files = self
+            .args_parsed
+            .max_file_count
+            .unwrap_or(DEFAULT_MAX_FILE_COUNT);
+        let max_records = self
+            .args_parsed
+            .max_records_pre_file
+            .unwrap_or(DEFAULT_MAX_RECORDS_PRE_FILE);
+        let files = files_info
+            .list(
+                operator,
+                self.ctx.get_settings().get_max_threads()? as usize,
+                Some(max_files),
+            )
+            .await?;
+        if files.is_empty() {
+            return Err(ErrorCode::BadArguments(format!(
+                "infer_schema: no file found in {}",
+                self.args_parsed.location
+            )));
+        }
+
+        // The header is detected only if the format is given by its type, the `headers` of
+        // named file formats are respected even if it is 0.
+        let detect_header = self
+            .args_parsed
+            .file_format
+            .as_ref()
+            .is_some_and(|f| StageFileFormatType::from_str(f).is_ok());
+        let mut inferrer = match params {
+            FileFormatParams::NdJson(_) => SchemaInferrer::new_for_json(),
+            _ => SchemaInferrer::new_for_text(detect_header),
+        };
+        for file in files {
+            let algo = get_compression_alg_copy(params.compression(), &file.path)?;
+            let (data, truncated) = read_sample(operator, &file.path, file.size, algo).await?;
+            match params {
+                FileFormatParams::Csv(p) => inferrer.add_csv(&data, truncated, p, max_records)?,
+                FileFormatParams::Tsv(p) => inferrer.add_tsv(&data, truncated, p, max_records)?,
+                FileFormatParams::NdJson(_) => {
+                    inferrer.add_ndjson(&data, truncated, &file.path, max_records)?
+                }
+                _ => unreachable!("infer_row_based_schema only supports CSV, TSV and NDJSON"),
+            }
+        }
+        Ok(inferrer.finish())
+    }
+}
+
+/// Read at most [`MAX_SAMPLE_BYTES`] from the head of the file, decompressed if `algo` is set.
+///
+/// Returns the sample and whether the file is longer than it.
+#[async_backtrace::framed]
+async fn read_sample(
+    operator: &Operator,
+    path: &str,
+    size: u64,
+    algo: Option<CompressAlgorithm>,
+) -> Result<(Vec<u8>, bool)> {
+    let Some(algo) = algo else {
+        let end = size.min(MAX_SAMPLE_BYTES);
+        let data = operator.read_with(path).range(0..end).await?;
+        return Ok((data.to_vec(), size > MAX_SAMPLE_BYTES));
+    };
+
+    // Decompress chunk by chunk, stop once the sample is large enough.
+    let mut decoder = DecompressDecoder::new(algo);
+    let mut data = vec![];
+    let mut offset = 0;
+    while offset < size && (data.len() as u64) < MAX_SAMPLE_BYTES {
+        let end = (offset + READ_CHUNK_BYTES).min(size);
+        let chunk = operator.read_with(path).range(offset..end).await?;
+        data.extend(decoder.decompress_batch(&chunk.to_vec())?);
+        offset = end;
+    }
+    if offset >= size {
+        data.extend(decoder.decompress_batch(&[])?);
+    }
+    let truncated = offset < size || data.len() as u64 > MAX_SAMPLE_BYTES;
+    data.truncate(MAX_SAMPLE_BYTES as usize);
+    Ok((data, truncated))
+}
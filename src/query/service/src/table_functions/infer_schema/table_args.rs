@@ -15,6 +15,7 @@
 use databend_common_catalog::table_args::TableArgs;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::Scalar;
 use databend_common_storage::StageFilesInfo;
 use databend_common_storages_fuse::table_functions::string_value;
 
@@ -24,6 +25,8 @@ pub(crate) struct InferSchemaArgsParsed {
     pub(crate) connection_name: Option<String>,
     pub(crate) file_format: Option<String>,
     pub(crate) files_info: StageFilesInfo,
+    pub(crate) max_records_pre_file: Option<usize>,
+    pub(crate) max_file_count: Option<usize>,
 }
 
 impl InferSchemaArgsParsed {
@@ -33,6 +36,8 @@ impl InferSchemaArgsParsed {
         let mut location = None;
         let mut connection_name = None;
         let mut file_format = None;
+        let mut max_records_pre_file = None;
+        let mut max_file_count = None;
         let mut files_info = StageFilesInfo {
             path: "".to_string(),
             files: None,
@@ -53,6 +58,12 @@ impl InferSchemaArgsParsed {
                 "file_format" => {
                     file_format = Some(string_value(v)?);
                 }
+                "max_records_pre_file" => {
+                    max_records_pre_file = Some(positive_value(k, v)?);
+                }
+                "max_file_count" => {
+                    max_file_count = Some(positive_value(k, v)?);
+                }
                 _ => {
                     return Err(ErrorCode::BadArguments(format!(
                         "unknown param {} for infer_schema",
@@ -70,6 +81,17 @@ impl InferSchemaArgsParsed {
             connection_name,
             file_format,
             files_info,
+            max_records_pre_file,
+            max_file_count,
         })
     }
 }
+
+fn positive_value(key: &str, value: &Scalar) -> Result<usize> {
+    match value.get_i64() {
+        Some(v) if v > 0 => Ok(v as usize),
+        _ => Err(ErrorCode::BadArguments(format!(
+            "{key} of infer_schema must be a positive integer, but got {value}"
+        ))),
+    }
+}
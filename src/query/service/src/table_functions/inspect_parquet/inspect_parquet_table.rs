@@ -24,6 +24,7 @@ use databend_common_catalog::table::Table;
 use databend_common_catalog::table_args::TableArgs;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::BooleanType;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::Int64Type;
 use databend_common_expression::types::NumberDataType;
@@ -50,6 +51,8 @@ use databend_common_storage::init_stage_operator;
 use databend_common_storage::read_metadata_async;
 use databend_common_storage::StageFilesInfo;
 use databend_common_storages_fuse::table_functions::string_literal;
+use parquet::basic::Compression;
+use parquet::file::metadata::ParquetMetaData;
 
 use crate::pipelines::processors::OutputPort;
 use crate::sessions::TableContext;
@@ -120,6 +123,10 @@ impl InspectParquetTable {
                 "max_row_groups_size_uncompressed",
                 TableDataType::Number(NumberDataType::Int64),
             ),
+            TableField::new("compression", TableDataType::String),
+            TableField::new("sorting_columns", TableDataType::String),
+            TableField::new("bloom_filter_columns", TableDataType::String),
+            TableField::new("page_index", TableDataType::Boolean),
         ])
     }
 }
@@ -261,6 +268,12 @@ impl AsyncSource for InspectParquetSource {
             max_compressed = max(max_compressed, grp_compressed_size);
             max_uncompressed = max(max_uncompressed, grp_uncompressed_size);
         }
+        let WriterOptions {
+            compression,
+            sorting_columns,
+            bloom_filter_columns,
+            page_index,
+        } = WriterOptions::from_metadata(&parquet_schema);
         let block = DataBlock::new(
             vec![
                 BlockEntry::new(
@@ -295,9 +308,106 @@ impl AsyncSource for InspectParquetSource {
                     DataType::Number(NumberDataType::Int64),
                     Value::Scalar(Int64Type::upcast_scalar(max_uncompressed)),
                 ),
+                BlockEntry::new(
+                    DataType::String,
+                    Value::Scalar(StringType::upcast_scalar(compression)),
+                ),
+                BlockEntry::new(
+                    DataType::String,
+                    Value::Scalar(StringType::upcast_scalar(sorting_columns)),
+                ),
+                BlockEntry::new(
+                    DataType::String,
+                    Value::Scalar(StringType::upcast_scalar(bloom_filter_columns)),
+                ),
+                BlockEntry::new(
+                    DataType::Boolean,
+                    Value::Scalar(BooleanType::upcast_scalar(page_index)),
+                ),
             ],
             1,
         );
         Ok(Some(block))
     }
 }
+
+/// Options the file was written with, as recorded in the metadata of its first row group.
+struct WriterOptions {
+    /// Distinct codecs of the column chunks.
+    compression: String,
+    sorting_columns: String,
+    /// Columns which have a bloom filter.
+    bloom_filter_columns: String,
+    /// Whether all the column chunks have a column index and an offset index.
+    page_index: bool,
+}
+
+impl WriterOptions {
+    fn from_metadata(meta: &ParquetMetaData) -> Self {
+        let Some(row_group) = meta.row_groups().first() else {
+            return WriterOptions {
+                compression: String::new(),
+                sorting_columns: String::new(),
+                bloom_filter_columns: String::new(),
+                page_index: false,
+            };
+        };
+        let schema = meta.file_metadata().schema_descr();
+
+        let mut codecs: Vec<&str> = vec![];
+        for col in row_group.columns() {
+            let codec = compression_name(col.compression());
+            if !codecs.contains(&codec) {
+                codecs.push(codec);
+            }
+        }
+        let sorting_columns = row_group
+            .sorting_columns()
+            .map(|columns| {
+                columns
+                    .iter()
+                    .map(|c| {
+                        format!(
+                            "{} {} NULLS {}",
+                            schema.column(c.column_idx as usize).path().string(),
+                            if c.descending { "DESC" } else { "ASC" },
+                            if c.nulls_first { "FIRST" } else { "LAST" }
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .unwrap_or_default();
+        let bloom_filter_columns = row_group
+            .columns()
+            .iter()
+            .filter(|col| col.bloom_filter_offset().is_some())
+            .map(|col| col.column_path().string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let page_index = row_group
+            .columns()
+            .iter()
+            .all(|col| col.column_index_offset().is_some() && col.offset_index_offset().is_some());
+
+        WriterOptions {
+            compression: codecs.join(", "),
+            sorting_columns,
+            bloom_filter_columns,
+            page_index,
+        }
+    }
+}
+
+fn compression_name(compression: Compression) -> &'static str {
+    match compression {
+        Compression::UNCOMPRESSED => "UNCOMPRESSED",
+        Compression::SNAPPY => "SNAPPY",
+        Compression::GZIP(_) => "GZIP",
+        Compression::LZO => "LZO",
+        Compression::BROTLI(_) => "BROTLI",
+        Compression::LZ4 => "LZ4",
+        Compression::ZSTD(_) => "ZSTD",
+        Compression::LZ4_RAW => "LZ4_RAW",
+    }
+}
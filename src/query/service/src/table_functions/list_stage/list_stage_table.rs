@@ -208,7 +208,14 @@ impl ListStagesSource {
             files: self.args_parsed.files_info.files.clone(),
             pattern: self.args_parsed.files_info.pattern.clone(),
         };
-        let files = files_info.list_stream(&op, thread_num, None).await?;
+        let files = files_info
+            .list_stream_with_archives(
+                &op,
+                thread_num,
+                None,
+                stage_info.file_format_params.compression(),
+            )
+            .await?;
         Ok(files)
     }
 }
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use chrono::Utc;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
@@ -25,6 +27,7 @@ use databend_common_storages_fuse::io::TableMetaLocationGenerator;
 use databend_common_storages_fuse::io::WriteSettings;
 use databend_common_storages_fuse::FuseStorageFormat;
 use databend_storages_common_blocks::blocks_to_parquet;
+use databend_storages_common_index::filters::DEFAULT_SET_FILTER_MAX_VALUES;
 use databend_storages_common_index::BloomIndex;
 use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::meta::ClusterStatistics;
@@ -116,6 +119,9 @@ impl<'a> BlockWriter<'a> {
             location.1,
             block,
             bloom_columns_map,
+            BTreeMap::new(),
+            BTreeMap::new(),
+            DEFAULT_SET_FILTER_MAX_VALUES,
         )?;
         if let Some(bloom_index) = maybe_bloom_index {
             let index_block = bloom_index.serialize_to_data_block()?;
@@ -346,6 +346,7 @@ impl TestFixture {
             .into(),
             field_comments: vec!["number".to_string(), "tuple".to_string()],
             as_select: None,
+            template: None,
             cluster_key: Some("(id)".to_string()),
             inverted_indexes: None,
         }
@@ -370,6 +371,7 @@ impl TestFixture {
             .into(),
             field_comments: vec!["number".to_string(), "tuple".to_string()],
             as_select: None,
+            template: None,
             cluster_key: None,
             inverted_indexes: None,
         }
@@ -405,6 +407,7 @@ impl TestFixture {
             .into(),
             field_comments: vec![],
             as_select: None,
+            template: None,
             cluster_key: None,
             inverted_indexes: None,
         }
@@ -440,6 +443,7 @@ impl TestFixture {
             .into(),
             field_comments: vec![],
             as_select: None,
+            template: None,
             cluster_key: None,
             inverted_indexes: None,
         }
@@ -484,6 +488,7 @@ impl TestFixture {
             .into(),
             field_comments: vec![],
             as_select: None,
+            template: None,
             cluster_key: None,
             inverted_indexes: None,
         }
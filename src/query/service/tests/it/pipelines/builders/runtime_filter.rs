@@ -132,3 +132,42 @@ async fn test_generate_runtime_filter() -> Result<()> {
     assert!(join_build_state.get_enable_min_max_runtime_filter());
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_runtime_filter_ready_without_cluster() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let _ = execute_sql(
+        fixture.new_query_ctx().await?,
+        "CREATE TABLE probe (number int) as select number from numbers(1000)",
+    )
+    .await?;
+
+    let _ = execute_sql(
+        fixture.new_query_ctx().await?,
+        "CREATE TABLE build (number int) as select number from numbers(10)",
+    )
+    .await?;
+
+    let plan = physical_plan(
+        fixture.new_query_ctx().await?,
+        "SELECT * FROM probe JOIN build ON probe.number = build.number",
+    )
+    .await?;
+    let mut join = find_join(&plan)?;
+    let (_, scan_id) = join.probe_keys_rt.iter().flatten().next().unwrap().clone();
+
+    // The fuse scans only wait for the runtime filters under cluster.
+    assert!(!join.runtime_filter_partition_pruning);
+    let ctx = fixture.new_query_ctx().await?;
+    let join_build_state = join_build_state(&ctx, &join).await?;
+    join_build_state.add_runtime_filter_ready();
+    assert!(ctx.get_runtime_filter_ready(scan_id).is_empty());
+
+    // The scans pruning their partitions with the runtime filters always wait for them.
+    join.runtime_filter_partition_pruning = true;
+    let ctx = fixture.new_query_ctx().await?;
+    let join_build_state = join_build_state(&ctx, &join).await?;
+    join_build_state.add_runtime_filter_ready();
+    assert_eq!(ctx.get_runtime_filter_ready(scan_id).len(), 1);
+    Ok(())
+}
@@ -51,6 +51,7 @@ async fn test_fuse_alter_table_cluster_key() -> databend_common_exception::Resul
         .into(),
         field_comments: vec![],
         as_select: None,
+        template: None,
         cluster_key: None,
         inverted_indexes: None,
     };
@@ -826,6 +826,10 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn get_bloom_digest_runtime_filter_with_id(&self, _id: usize) -> Vec<(String, Vec<u64>)> {
+        todo!()
+    }
+
     fn has_bloom_runtime_filters(&self, _id: usize) -> bool {
         todo!()
     }
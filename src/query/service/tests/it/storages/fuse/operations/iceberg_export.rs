@@ -0,0 +1,185 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_storages_fuse::FuseTable;
+use databend_common_storages_fuse::TableContext;
+use databend_query::test_kits::*;
+use serde_json::Value;
+
+async fn read_exported_metadata(fixture: &TestFixture, tbl: &str) -> Result<(u64, Value)> {
+    let table = fixture
+        .new_query_ctx()
+        .await?
+        .get_table("default", &fixture.default_db_name(), tbl)
+        .await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let operator = fuse_table.get_operator();
+    let location_generator = fuse_table.meta_location_generator();
+
+    let hint = operator
+        .read(&location_generator.gen_iceberg_version_hint_location())
+        .await?;
+    let version = String::from_utf8(hint.to_vec())?.parse::<u64>()?;
+    let metadata = operator
+        .read(&location_generator.gen_iceberg_metadata_location(version))
+        .await?;
+    Ok((version, serde_json::from_slice(&metadata.to_vec())?))
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_table_iceberg_export() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let db = fixture.default_db_name();
+    fixture.create_default_database().await?;
+
+    fixture
+        .execute_command(&format!(
+            "create table {db}.t(a int not null, b string) iceberg_export = 'true'"
+        ))
+        .await?;
+    fixture
+        .execute_command(&format!("insert into {db}.t values(1, 'x'), (3, 'z')"))
+        .await?;
+
+    let (version, metadata) = read_exported_metadata(&fixture, "t").await?;
+    assert_eq!(version, 1);
+    assert_eq!(metadata["format-version"], 2);
+    assert_eq!(metadata["current-schema-id"], 0);
+    let fields = metadata["schemas"][0]["fields"].as_array().unwrap();
+    assert_eq!(fields.len(), 2);
+    assert_eq!(fields[0]["id"], 1);
+    assert_eq!(fields[0]["name"], "a");
+    assert_eq!(fields[0]["required"], true);
+    assert_eq!(fields[0]["type"], "int");
+    assert_eq!(fields[1]["id"], 2);
+    assert_eq!(fields[1]["type"], "string");
+    assert_eq!(fields[1]["required"], false);
+    let snapshots = metadata["snapshots"].as_array().unwrap();
+    assert_eq!(snapshots.len(), 1);
+    assert_eq!(snapshots[0]["summary"]["total-records"], "2");
+    assert_eq!(snapshots[0]["summary"]["total-data-files"], "1");
+    let table_uuid = metadata["table-uuid"].clone();
+    let stale_table = fixture
+        .new_query_ctx()
+        .await?
+        .get_table("default", &db, "t")
+        .await?;
+
+    // each commit writes a new version, the table uuid is kept
+    fixture
+        .execute_command(&format!("insert into {db}.t values(2, 'y')"))
+        .await?;
+    let (version, metadata) = read_exported_metadata(&fixture, "t").await?;
+    assert_eq!(version, 2);
+    assert_eq!(metadata["table-uuid"], table_uuid);
+    assert_eq!(metadata["last-sequence-number"], 2);
+    assert_eq!(metadata["metadata-log"].as_array().unwrap().len(), 1);
+    let snapshots = metadata["snapshots"].as_array().unwrap();
+    assert_eq!(snapshots[0]["summary"]["total-records"], "3");
+    assert_eq!(snapshots[0]["summary"]["total-data-files"], "2");
+    assert_eq!(
+        metadata["current-snapshot-id"],
+        metadata["refs"]["main"]["snapshot-id"]
+    );
+
+    // each segment is listed by a manifest named after it, which is reused by later exports
+    let table = fixture
+        .new_query_ctx()
+        .await?
+        .get_table("default", &db, "t")
+        .await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let snapshot = fuse_table.read_table_snapshot().await?.unwrap();
+    let location_generator = fuse_table.meta_location_generator();
+    let manifests = fuse_table
+        .get_operator()
+        .list(&location_generator.iceberg_metadata_location_prefix())
+        .await?
+        .into_iter()
+        .filter(|entry| entry.name().ends_with("-m0.avro"))
+        .map(|entry| entry.path().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(manifests.len(), 2);
+    for segment in &snapshot.segments {
+        let manifest = location_generator.gen_iceberg_manifest_location(&segment.0);
+        assert!(manifests.contains(&manifest));
+    }
+
+    // exporting an elder version of the table is skipped
+    let stale_table = FuseTable::try_from_table(stale_table.as_ref())?;
+    stale_table.export_iceberg_metadata().await?;
+    let (version, metadata) = read_exported_metadata(&fixture, "t").await?;
+    assert_eq!(version, 2);
+    assert_eq!(metadata["snapshots"][0]["summary"]["total-records"], "3");
+
+    // the names of renamed columns are kept in the name mapping
+    fixture
+        .execute_command(&format!("alter table {db}.t rename column b to c"))
+        .await?;
+    fixture
+        .execute_command(&format!("insert into {db}.t values(4, 'w')"))
+        .await?;
+    let (_, metadata) = read_exported_metadata(&fixture, "t").await?;
+    let name_mapping: Value = serde_json::from_str(
+        metadata["properties"]["schema.name-mapping.default"]
+            .as_str()
+            .unwrap(),
+    )?;
+    assert_eq!(name_mapping[1]["field-id"], 2);
+    assert_eq!(name_mapping[1]["names"], serde_json::json!(["c", "b"]));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_table_iceberg_export_on_alter() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let db = fixture.default_db_name();
+    fixture.create_default_database().await?;
+
+    fixture
+        .execute_command(&format!("create table {db}.t(a bigint, c date)"))
+        .await?;
+    fixture
+        .execute_command(&format!("insert into {db}.t values(1, '2024-01-01')"))
+        .await?;
+
+    // enabling the option exports the current snapshot
+    fixture
+        .execute_command(&format!(
+            "alter table {db}.t set options(iceberg_export = 'true')"
+        ))
+        .await?;
+    let (version, metadata) = read_exported_metadata(&fixture, "t").await?;
+    assert_eq!(version, 1);
+    let fields = metadata["schemas"][0]["fields"].as_array().unwrap();
+    assert_eq!(fields[0]["type"], "long");
+    assert_eq!(fields[1]["type"], "date");
+    assert_eq!(metadata["snapshots"][0]["summary"]["total-records"], "1");
+
+    // columns without iceberg type can not be exported
+    fixture
+        .execute_command(&format!("create table {db}.t1(a variant)"))
+        .await?;
+    let res = fixture
+        .execute_command(&format!(
+            "alter table {db}.t1 set options(iceberg_export = 'true')"
+        ))
+        .await;
+    assert!(res.is_err());
+
+    Ok(())
+}
@@ -70,6 +70,8 @@ async fn apply_block_pruning(
         schema,
         push_down,
         bloom_index_cols,
+        BloomIndexColumns::None,
+        BloomIndexColumns::None,
         None,
         FuseStorageFormat::Parquet,
     )?
@@ -114,6 +116,7 @@ async fn test_block_pruner() -> Result<()> {
         .into(),
         field_comments: vec![],
         as_select: None,
+        template: None,
         cluster_key: None,
         inverted_indexes: None,
     };
@@ -81,6 +81,8 @@ async fn apply_snapshot_pruning(
         schema,
         push_down,
         bloom_index_cols,
+        BloomIndexColumns::None,
+        BloomIndexColumns::None,
         None,
         FuseStorageFormat::Parquet,
     )?);
@@ -172,6 +174,7 @@ async fn test_snapshot_pruner() -> Result<()> {
         .into(),
         field_comments: vec![],
         as_select: None,
+        template: None,
         cluster_key: None,
         inverted_indexes: None,
     };
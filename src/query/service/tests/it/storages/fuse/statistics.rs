@@ -21,6 +21,7 @@ use databend_common_expression::type_check::check;
 use databend_common_expression::types::number::Int32Type;
 use databend_common_expression::types::number::NumberScalar;
 use databend_common_expression::types::DataType;
+use databend_common_expression::types::GeometryType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::StringType;
 use databend_common_expression::BlockThresholds;
@@ -37,6 +38,7 @@ use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
 use databend_common_functions::aggregates::eval_aggr;
 use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_io::geometry_from_ewkt;
 use databend_common_sql::evaluator::BlockOperator;
 use databend_common_storages_fuse::statistics::reducers::reduce_block_metas;
 use databend_common_storages_fuse::statistics::Trim;
@@ -141,6 +143,38 @@ fn test_ft_tuple_stats_block_stats() -> databend_common_exception::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_ft_geometry_stats_block_stats() -> databend_common_exception::Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![TableField::new(
+        "g",
+        TableDataType::Nullable(Box::new(TableDataType::Geometry)),
+    )]));
+
+    let geometry = |wkt: &str| geometry_from_ewkt(wkt, None).unwrap();
+    let block = DataBlock::new_from_columns(vec![GeometryType::from_opt_data(vec![
+        Some(geometry("POINT(1 2)")),
+        None,
+        Some(geometry("LINESTRING(-1 0, 3 5)")),
+    ])]);
+    let r = gen_columns_statistics(&block, None, &schema)?;
+    let col_stats = r.get(&0).unwrap();
+    assert_eq!(col_stats.bbox(), Some([-1.0, 0.0, 3.0, 5.0]));
+    assert_eq!(col_stats.null_count, 1);
+
+    let block = DataBlock::new_from_columns(vec![GeometryType::from_opt_data(vec![Some(
+        geometry("POLYGON((0 0, 4 0, 4 -2, 0 0))"),
+    )])]);
+    let other = gen_columns_statistics(&block, None, &schema)?;
+    assert_eq!(other.get(&0).unwrap().bbox(), Some([0.0, -2.0, 4.0, 0.0]));
+
+    // bounding boxes are merged componentwise
+    let r = reducers::reduce_block_statistics(&[r, other]);
+    let col_stats = r.get(&0).unwrap();
+    assert_eq!(col_stats.bbox(), Some([-1.0, -2.0, 4.0, 5.0]));
+    assert_eq!(col_stats.null_count, 1);
+    Ok(())
+}
+
 #[test]
 fn test_ft_stats_col_stats_reduce() -> databend_common_exception::Result<()> {
     let num_of_blocks = 10;
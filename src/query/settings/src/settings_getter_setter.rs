@@ -277,6 +277,10 @@ impl Settings {
         Ok(self.try_get_u64("purge_duplicated_files_in_copy")? != 0)
     }
 
+    pub fn get_max_copy_rejected_rows(&self) -> Result<u64> {
+        self.try_get_u64("max_copy_rejected_rows")
+    }
+
     pub fn get_timezone(&self) -> Result<String> {
         self.try_get_string("timezone")
     }
@@ -355,6 +359,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_bloom_runtime_filter")? != 0)
     }
 
+    pub fn get_bloom_digest_runtime_filter(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_bloom_digest_runtime_filter")? != 0)
+    }
+
     pub fn get_prefer_broadcast_join(&self) -> Result<bool> {
         Ok(self.try_get_u64("prefer_broadcast_join")? != 0)
     }
@@ -816,6 +816,19 @@ fn table_scan_to_format_tree(
         children.push(FormatTreeNode::new(text));
     }
 
+    // Vector index
+    if let Some(vector_index) = plan
+        .source
+        .push_downs
+        .as_ref()
+        .and_then(|extras| extras.vector_index.as_ref())
+    {
+        children.push(FormatTreeNode::new(format!(
+            "vector index: [name: {}, distance: {}, limit: {}]",
+            vector_index.index_name, vector_index.func_name, vector_index.limit
+        )));
+    }
+
     if let Some(info) = &plan.stat_info {
         let items = plan_stats_info_to_format_tree(info);
         children.extend(items);
@@ -1400,6 +1413,7 @@ fn range_join_to_format_tree(
         match plan.range_join_type {
             RangeJoinType::IEJoin => "IEJoin".to_string(),
             RangeJoinType::Merge => "MergeJoin".to_string(),
+            RangeJoinType::Spatial => "SpatialJoin".to_string(),
         },
         children,
     ))
@@ -1575,6 +1589,18 @@ fn part_stats_info_to_format_tree(info: &PartStatistics) -> Vec<FormatTreeNode<S
         );
     }
 
+    // vector index pruning status.
+    if info.pruning_stats.blocks_vector_index_pruning_before > 0 {
+        if !blocks_pruning_description.is_empty() {
+            blocks_pruning_description += ", ";
+        }
+        blocks_pruning_description += &format!(
+            "vector pruning: {} to {}",
+            info.pruning_stats.blocks_vector_index_pruning_before,
+            info.pruning_stats.blocks_vector_index_pruning_after
+        );
+    }
+
     // Combine segment pruning and blocks pruning descriptions if any
     if info.pruning_stats.segments_range_pruning_before > 0
         || !blocks_pruning_description.is_empty()
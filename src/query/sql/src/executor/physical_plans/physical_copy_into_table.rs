@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_ast::ast::ValidationMode;
 use databend_common_catalog::plan::StageTableInfo;
 use databend_common_exception::Result;
 use databend_common_expression::DataSchemaRef;
@@ -22,7 +23,6 @@ use enum_as_inner::EnumAsInner;
 
 use crate::executor::physical_plan::PhysicalPlan;
 use crate::plans::CopyIntoTableMode;
-use crate::plans::ValidationMode;
 use crate::ColumnBinding;
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
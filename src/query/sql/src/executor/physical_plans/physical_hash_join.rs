@@ -83,6 +83,9 @@ pub struct HashJoin {
     pub probe_keys_rt: Vec<Option<(RemoteExpr<String>, IndexType)>>,
     // If enable bloom runtime filter
     pub enable_bloom_runtime_filter: bool,
+    // If the probe side prunes its partitions with the runtime filters, it waits for the
+    // runtime filters even without a cluster.
+    pub runtime_filter_partition_pruning: bool,
     // Under cluster, mark if the join is broadcast join.
     pub broadcast: bool,
     // When left/right single join converted to inner join, record the original join type
@@ -549,6 +552,13 @@ impl PhysicalPlanBuilder {
             stat_info: Some(stat_info),
             broadcast: is_broadcast,
             single_to_inner: join.single_to_inner.clone(),
+            runtime_filter_partition_pruning: table_index.is_some_and(|table_index| {
+                self.metadata
+                    .read()
+                    .table(table_index)
+                    .table()
+                    .support_runtime_filter_partition_pruning()
+            }),
             enable_bloom_runtime_filter: adjust_bloom_runtime_filter(
                 self.ctx.clone(),
                 &self.metadata,
@@ -89,8 +89,12 @@ fn check_condition(
     other_conditions: &mut Vec<ScalarExpr>,
 ) {
     if let ScalarExpr::FunctionCall(func) = expr {
+        // Spatial predicates are executed by the R-tree based spatial join.
         if func.arguments.len() != 2
-            || !matches!(func.func_name.as_str(), "gt" | "lt" | "gte" | "lte")
+            || !matches!(
+                func.func_name.as_str(),
+                "gt" | "lt" | "gte" | "lte" | "st_intersects" | "st_contains" | "st_within"
+            )
         {
             other_conditions.push(expr.clone());
             return;
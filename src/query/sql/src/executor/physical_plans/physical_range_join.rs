@@ -40,6 +40,7 @@ pub struct RangeJoin {
     pub left: Box<PhysicalPlan>,
     pub right: Box<PhysicalPlan>,
     // The first two conditions: (>, >=, <, <=)
+    // or a spatial condition: (st_intersects, st_contains, st_within)
     // Condition's left/right side only contains one table's column
     pub conditions: Vec<RangeJoinCondition>,
     // The other conditions
@@ -64,13 +65,15 @@ impl RangeJoin {
 pub enum RangeJoinType {
     IEJoin,
     Merge,
+    // Match the bounding boxes of geometries with an R-tree
+    Spatial,
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct RangeJoinCondition {
     pub left_expr: RemoteExpr,
     pub right_expr: RemoteExpr,
-    // "gt" | "lt" | "gte" | "lte"
+    // "gt" | "lt" | "gte" | "lte" | "st_intersects" | "st_contains" | "st_within"
     pub operator: String,
 }
 
@@ -88,15 +91,24 @@ impl PhysicalPlanBuilder {
 
         debug_assert!(!range_conditions.is_empty());
 
-        let range_join_type = if range_conditions.len() >= 2 {
-            // Contain more than 2 ie conditions, use ie join
-            while range_conditions.len() > 2 {
-                other_conditions.push(range_conditions.pop().unwrap());
-            }
-            RangeJoinType::IEJoin
-        } else {
-            RangeJoinType::Merge
-        };
+        let range_join_type =
+            if let Some(idx) = range_conditions.iter().position(is_spatial_condition) {
+                // The spatial join only matches the bounding boxes of geometries,
+                // so the spatial condition is also checked exactly with the other conditions.
+                let spatial_condition = range_conditions.remove(idx);
+                other_conditions.append(&mut range_conditions);
+                other_conditions.push(spatial_condition.clone());
+                range_conditions.push(spatial_condition);
+                RangeJoinType::Spatial
+            } else if range_conditions.len() >= 2 {
+                // Contain more than 2 ie conditions, use ie join
+                while range_conditions.len() > 2 {
+                    other_conditions.push(range_conditions.pop().unwrap());
+                }
+                RangeJoinType::IEJoin
+            } else {
+                RangeJoinType::Merge
+            };
 
         // Construct IEJoin
         let left_side = self.build(s_expr.child(1)?, left_required).await?;
@@ -200,6 +212,9 @@ fn resolve_range_condition(
                     "lt" => "gt",
                     "gte" => "lte",
                     "lte" => "gte",
+                    "st_intersects" => "st_intersects",
+                    "st_contains" => "st_within",
+                    "st_within" => "st_contains",
                     _ => unreachable!(),
                 }
             } else {
@@ -215,6 +230,13 @@ fn resolve_range_condition(
     }
 }
 
+fn is_spatial_condition(expr: &ScalarExpr) -> bool {
+    matches!(expr, ScalarExpr::FunctionCall(func) if matches!(
+        func.func_name.as_str(),
+        "st_intersects" | "st_contains" | "st_within"
+    ))
+}
+
 fn resolve_scalar(scalar: &ScalarExpr, schema: &DataSchemaRef) -> Result<RemoteExpr> {
     let expr = scalar
         .type_check(schema.as_ref())?
@@ -478,10 +478,12 @@ impl PhysicalPlanBuilder {
             })
             .transpose()?;
 
+        // Only the sort items before the first derived column can be pushed down,
+        // the following items are meaningless without it.
         let order_by = scan.order_by.clone().map(|items| {
             items
                 .into_iter()
-                .filter_map(|item| {
+                .map_while(|item| {
                     let metadata = self.metadata.read();
                     let column = metadata.column(item.index);
                     let (name, data_type) = match column {
@@ -520,6 +522,12 @@ impl PhysicalPlanBuilder {
                 .collect::<Vec<_>>()
         });
 
+        // The limit can't be used to skip read if the order by items can't be pushed down.
+        let limit = match (&scan.order_by, &order_by) {
+            (Some(items), Some(order_by)) if !items.is_empty() && order_by.is_empty() => None,
+            _ => scan.limit,
+        };
+
         let virtual_column = self.build_virtual_column(&scan.columns);
 
         Ok(PushDownInfo {
@@ -528,13 +536,14 @@ impl PhysicalPlanBuilder {
             filters: push_down_filter,
             is_deterministic,
             prewhere: prewhere_info,
-            limit: scan.limit,
+            limit,
             order_by: order_by.unwrap_or_default(),
             virtual_column,
             lazy_materialization: !metadata.lazy_columns().is_empty(),
             agg_index: None,
             change_type: scan.change_type.clone(),
             inverted_index: scan.inverted_index.clone(),
+            vector_index: scan.vector_index.clone(),
             sample: scan.sample.clone(),
         })
     }
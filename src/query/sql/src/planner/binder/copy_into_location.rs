@@ -13,8 +13,12 @@
 // limitations under the License.
 
 use databend_common_ast::ast::quote::display_ident;
+use databend_common_ast::ast::ColumnID;
+use databend_common_ast::ast::ColumnRef;
+use databend_common_ast::ast::CopyIntoLocationPartition;
 use databend_common_ast::ast::CopyIntoLocationSource;
 use databend_common_ast::ast::CopyIntoLocationStmt;
+use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::Statement;
 use databend_common_ast::parser::parse_sql;
 use databend_common_ast::parser::tokenize_sql;
@@ -51,6 +55,28 @@ impl Binder {
                 "include_query_id=false can only be set when use_raw_path=true",
             ));
         }
+        if !stmt.partition_by.is_empty() && stmt.options.single {
+            return Err(ErrorCode::InvalidArgument(
+                "PARTITION BY can not be used when single=true",
+            ));
+        }
+
+        let mut partition_keys = Vec::with_capacity(stmt.partition_by.len());
+        for partition in &stmt.partition_by {
+            let key = self.partition_key(partition)?;
+            if partition_keys.contains(&key) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "duplicated partition key `{key}` in PARTITION BY"
+                )));
+            }
+            partition_keys.push(key);
+        }
+        // partition values are appended to the unloaded columns as strings
+        let partition_columns = stmt
+            .partition_by
+            .iter()
+            .map(|partition| format!(", CAST(({}) AS STRING)", partition.expr))
+            .collect::<String>();
 
         let query = match &stmt.src {
             CopyIntoLocationSource::Table(table) => {
@@ -68,7 +94,7 @@ impl Binder {
                 let quoted_ident_case_sensitive =
                     self.ctx.get_settings().get_quoted_ident_case_sensitive()?;
                 let subquery = format!(
-                    "SELECT * FROM {}.{}.{}{with_options_str}",
+                    "SELECT *{partition_columns} FROM {}.{}.{}{with_options_str}",
                     display_ident(&catalog_name, quoted_ident_case_sensitive, self.dialect),
                     display_ident(&database_name, quoted_ident_case_sensitive, self.dialect),
                     display_ident(&table_name, quoted_ident_case_sensitive, self.dialect),
@@ -90,8 +116,15 @@ impl Binder {
             }
             CopyIntoLocationSource::Query(query) => {
                 self.init_cte(bind_context, &stmt.with)?;
-                self.bind_statement(bind_context, &Statement::Query(query.clone()))
-                    .await
+                if partition_columns.is_empty() {
+                    self.bind_statement(bind_context, &Statement::Query(query.clone()))
+                        .await
+                } else {
+                    let subquery = format!("SELECT *{partition_columns} FROM ({query})");
+                    let tokens = tokenize_sql(&subquery)?;
+                    let (sub_stmt, _) = parse_sql(&tokens, self.dialect)?;
+                    self.bind_statement(bind_context, &sub_stmt).await
+                }
             }
         }?;
 
@@ -120,11 +153,35 @@ impl Binder {
             stage_info.file_format_params = self.try_resolve_file_format(&stmt.file_format).await?;
         }
 
+        let mut options = stmt.options.clone();
+        options.partition_keys = partition_keys;
+
         Ok(Plan::CopyIntoLocation(CopyIntoLocationPlan {
             stage: Box::new(stage_info),
             path,
             from: Box::new(query),
-            options: stmt.options.clone(),
+            options,
         }))
     }
+
+    /// The directory key of a partition is its alias, or the column name if not aliased.
+    fn partition_key(&self, partition: &CopyIntoLocationPartition) -> Result<String> {
+        match (&partition.alias, partition.expr.as_ref()) {
+            (Some(alias), _) => Ok(self.normalize_identifier(alias).name),
+            (
+                None,
+                Expr::ColumnRef {
+                    column:
+                        ColumnRef {
+                            column: ColumnID::Name(name),
+                            ..
+                        },
+                    ..
+                },
+            ) => Ok(self.normalize_identifier(name).name),
+            (None, expr) => Err(ErrorCode::SemanticError(format!(
+                "PARTITION BY expression `{expr}` must have an alias as the partition key"
+            ))),
+        }
+    }
 }
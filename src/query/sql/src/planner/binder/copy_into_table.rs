@@ -12,7 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::str::FromStr;
 use std::sync::Arc;
 
 use databend_common_ast::ast::ColumnID as AstColumnID;
@@ -28,12 +27,15 @@ use databend_common_ast::ast::HintItem;
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::Literal;
 use databend_common_ast::ast::LiteralStringOrVariable;
+use databend_common_ast::ast::OnErrorMode;
 use databend_common_ast::ast::Query;
 use databend_common_ast::ast::SelectTarget;
 use databend_common_ast::ast::SetExpr;
 use databend_common_ast::ast::TableAlias;
+use databend_common_ast::ast::TableRef;
 use databend_common_ast::ast::TableReference;
 use databend_common_ast::ast::TypeName;
+use databend_common_ast::ast::ValidationMode;
 use databend_common_ast::parser::parse_values_with_placeholder;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_ast::Span;
@@ -67,10 +69,10 @@ use parking_lot::RwLock;
 use crate::binder::bind_query::MaxColumnPosition;
 use crate::binder::location::parse_uri_location;
 use crate::binder::Binder;
+use crate::plans::CopyErrorTable;
 use crate::plans::CopyIntoTableMode;
 use crate::plans::CopyIntoTablePlan;
 use crate::plans::Plan;
-use crate::plans::ValidationMode;
 use crate::BindContext;
 use crate::Metadata;
 use crate::NameResolutionContext;
@@ -152,8 +154,36 @@ impl Binder {
             .get_table(&catalog_name, &database_name, &table_name)
             .await?;
 
-        let validation_mode = ValidationMode::from_str(stmt.options.validation_mode.as_str())
-            .map_err(ErrorCode::SyntaxException)?;
+        let validation_mode = stmt.options.validation_mode.clone();
+        if validation_mode != ValidationMode::None && stmt.error_table.is_some() {
+            return Err(ErrorCode::BadArguments(
+                "ERROR_TABLE can not be used with VALIDATION_MODE",
+            ));
+        }
+
+        let error_table = match &stmt.error_table {
+            Some(error_table) => Some(
+                self.bind_copy_error_table(
+                    error_table,
+                    &stmt.options.on_error,
+                    (&catalog_name, &database_name, &table_name),
+                )
+                .await?,
+            ),
+            None => None,
+        };
+        let mut copy_into_table_options = stmt.options.clone();
+        copy_into_table_options.keep_rejected_rows = error_table.is_some();
+        match validation_mode {
+            ValidationMode::None | ValidationMode::ReturnNRows(_) => {}
+            ValidationMode::ReturnErrors | ValidationMode::ReturnAllErrors => {
+                // decode all the rows and keep every error instead of stopping at the first one.
+                copy_into_table_options.on_error = OnErrorMode::Continue;
+                copy_into_table_options.keep_rejected_rows = true;
+                // RETURN_ALL_ERRORS also validates the files already loaded.
+                copy_into_table_options.force |= validation_mode == ValidationMode::ReturnAllErrors;
+            }
+        }
 
         let (mut stage_info, path) = resolve_file_location(self.ctx.as_ref(), location).await?;
         if !stmt.file_format.is_empty() {
@@ -215,7 +245,7 @@ impl Binder {
                 is_select: false,
                 default_values,
                 copy_into_location_options: Default::default(),
-                copy_into_table_options: stmt.options.clone(),
+                copy_into_table_options,
             },
             values_consts: vec![],
             required_source_schema: required_values_schema.clone(),
@@ -224,6 +254,64 @@ impl Binder {
             query: None,
             enable_distributed: false,
             files_collected: false,
+            error_table,
+        })
+    }
+
+    async fn bind_copy_error_table(
+        &self,
+        error_table: &TableRef,
+        on_error: &OnErrorMode,
+        dst: (&str, &str, &str),
+    ) -> Result<CopyErrorTable> {
+        if on_error != &OnErrorMode::Continue {
+            return Err(ErrorCode::BadArguments(
+                "ERROR_TABLE can only be used with ON_ERROR = CONTINUE",
+            ));
+        }
+        let (catalog, database, table) = self.normalize_object_identifier_triple(
+            &error_table.catalog,
+            &error_table.database,
+            &error_table.table,
+        );
+        if (catalog.as_str(), database.as_str(), table.as_str()) == dst {
+            return Err(ErrorCode::BadArguments(
+                "ERROR_TABLE can not be the table to copy into",
+            ));
+        }
+
+        let expected = CopyIntoTablePlan::rejected_rows_schema();
+        let schema = self
+            .ctx
+            .get_table(&catalog, &database, &table)
+            .await?
+            .schema()
+            .remove_computed_fields();
+        for field in schema.fields() {
+            // Columns of the error table may be nullable even if the value is never NULL.
+            let valid = expected.field_with_name(field.name()).is_ok_and(|f| {
+                f.data_type().remove_nullable() == field.data_type().remove_nullable()
+                    && (field.is_nullable() || !f.is_nullable())
+            });
+            if !valid {
+                let columns = expected
+                    .fields()
+                    .iter()
+                    .map(|f| format!("{} {}", f.name(), f.data_type().sql_name()))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                return Err(ErrorCode::BadArguments(format!(
+                    "column `{}` {} of ERROR_TABLE {database}.{table} is not supported, the columns can be any of ({columns})",
+                    field.name(),
+                    field.data_type().sql_name(),
+                )));
+            }
+        }
+
+        Ok(CopyErrorTable {
+            catalog,
+            database,
+            table,
         })
     }
 
@@ -402,6 +490,7 @@ impl Binder {
             enable_distributed: false,
             is_transform: false,
             files_collected: true,
+            error_table: None,
         };
 
         self.bind_copy_into_table_from_location(bind_context, plan)
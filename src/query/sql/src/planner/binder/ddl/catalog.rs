@@ -35,6 +35,7 @@ use databend_common_meta_app::schema::CatalogOption;
 use databend_common_meta_app::schema::CatalogType;
 use databend_common_meta_app::schema::HiveCatalogOption;
 use databend_common_meta_app::schema::IcebergCatalogOption;
+use databend_common_meta_app::schema::IcebergFsCatalogOption;
 use databend_common_meta_app::schema::IcebergGlueCatalogOption;
 use databend_common_meta_app::schema::IcebergHmsCatalogOption;
 use databend_common_meta_app::schema::IcebergRestCatalogOption;
@@ -164,7 +165,7 @@ impl Binder {
                 })
             }
             CatalogType::Iceberg => {
-                let opt = parse_iceberg_rest_catalog(options.clone())?;
+                let opt = parse_iceberg_catalog(ctx, options.clone()).await?;
                 CatalogOption::Iceberg(opt)
             }
         };
@@ -204,7 +205,8 @@ async fn parse_hive_catalog_url(
     Ok(Some(sp))
 }
 
-fn parse_iceberg_rest_catalog(
+async fn parse_iceberg_catalog(
+    ctx: &Arc<dyn TableContext>,
     mut options: BTreeMap<String, String>,
 ) -> Result<IcebergCatalogOption> {
     let typ = options
@@ -212,6 +214,30 @@ fn parse_iceberg_rest_catalog(
         .ok_or_else(|| ErrorCode::InvalidArgument("type for iceberg catalog is not specified"))?
         .to_lowercase();
 
+    // Filesystem catalog discovers tables from the warehouse directly, the rest
+    // of options are the connection of the warehouse storage.
+    if typ == "fs" {
+        let warehouse = options.remove("warehouse").ok_or_else(|| {
+            ErrorCode::InvalidArgument("warehouse for iceberg catalog is not specified")
+        })?;
+        let options = options
+            .into_iter()
+            .map(|(k, v)| (k.to_lowercase(), v))
+            .collect::<BTreeMap<_, _>>();
+        // The warehouse is always a directory.
+        let warehouse = format!("{}/", warehouse.trim_end_matches('/'));
+        let mut location = UriLocation::from_uri(warehouse, options)?;
+        let sp = parse_storage_params_from_uri(
+            &mut location,
+            Some(ctx.as_ref()),
+            "when create Iceberg Catalog",
+        )
+        .await?;
+        return Ok(IcebergCatalogOption::Fs(IcebergFsCatalogOption {
+            storage_params: Box::new(sp),
+        }));
+    }
+
     let address = options
         .remove("address")
         .ok_or_else(|| ErrorCode::InvalidArgument("address for iceberg catalog is not specified"))?
@@ -18,21 +18,23 @@ use std::collections::HashSet;
 use std::sync::LazyLock;
 
 use databend_common_ast::ast::CreateIndexStmt;
-use databend_common_ast::ast::CreateInvertedIndexStmt;
+use databend_common_ast::ast::CreateTableIndexStmt;
 use databend_common_ast::ast::DropIndexStmt;
-use databend_common_ast::ast::DropInvertedIndexStmt;
+use databend_common_ast::ast::DropTableIndexStmt;
 use databend_common_ast::ast::ExplainKind;
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::Query;
 use databend_common_ast::ast::RefreshIndexStmt;
-use databend_common_ast::ast::RefreshInvertedIndexStmt;
+use databend_common_ast::ast::RefreshTableIndexStmt;
 use databend_common_ast::ast::SetExpr;
 use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TableIndexType as AstTableIndexType;
 use databend_common_ast::ast::TableReference;
 use databend_common_ast::parser::parse_sql;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
 use databend_common_expression::ColumnId;
 use databend_common_expression::TableDataType;
 use databend_common_expression::TableSchemaRef;
@@ -41,6 +43,8 @@ use databend_common_license::license_manager::LicenseManagerSwitch;
 use databend_common_meta_app::schema::GetIndexReq;
 use databend_common_meta_app::schema::IndexMeta;
 use databend_common_meta_app::schema::IndexNameIdent;
+use databend_common_meta_app::schema::TableIndex;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_storages_common_table_meta::meta::Location;
 use derive_visitor::Drive;
 use derive_visitor::DriveMut;
@@ -67,9 +71,14 @@ static INDEX_TOKENIZER_VALUES: LazyLock<HashSet<&'static str>> = LazyLock::new(|
     let mut r = HashSet::new();
     r.insert("english");
     r.insert("chinese");
+    r.insert("ngram");
+    r.insert("unicode");
     r
 });
 
+// the max number of characters in a n-gram of ngram tokenizer
+const MAX_NGRAM_LENGTH: u64 = 16;
+
 // valid values for inverted index option filter
 static INDEX_FILTER_VALUES: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
     let mut r = HashSet::new();
@@ -410,12 +419,13 @@ impl Binder {
     }
 
     #[async_backtrace::framed]
-    pub(in crate::planner::binder) async fn bind_create_inverted_index(
+    pub(in crate::planner::binder) async fn bind_create_table_index(
         &mut self,
         _bind_context: &mut BindContext,
-        stmt: &CreateInvertedIndexStmt,
+        stmt: &CreateTableIndexStmt,
     ) -> Result<Plan> {
-        let CreateInvertedIndexStmt {
+        let CreateTableIndexStmt {
+            index_type,
             create_option,
             index_name,
             catalog,
@@ -433,32 +443,35 @@ impl Binder {
 
         if table.is_read_only() {
             return Err(ErrorCode::UnsupportedIndex(format!(
-                "Table {} is read-only, creating inverted index not allowed",
-                table.name()
+                "Table {} is read-only, creating {} index not allowed",
+                table.name(),
+                index_type.to_string().to_lowercase()
             )));
         }
 
         if !table.support_index() {
             return Err(ErrorCode::UnsupportedIndex(format!(
-                "Table engine {} does not support create inverted index",
-                table.engine()
+                "Table engine {} does not support create {} index",
+                table.engine(),
+                index_type.to_string().to_lowercase()
             )));
         }
         if table.is_temp() {
             return Err(ErrorCode::UnsupportedIndex(format!(
-                "Table {} is temporary table, creating inverted index not allowed",
-                table.name()
+                "Table {} is temporary table, creating {} index not allowed",
+                table.name(),
+                index_type.to_string().to_lowercase()
             )));
         }
         let table_schema = table.schema();
         let table_id = table.get_id();
         let index_name = self.normalize_object_identifier(index_name);
-        let column_ids = self
-            .validate_inverted_index_columns(table_schema, columns)
+        let (index_type, column_ids, index_options) = self
+            .validate_table_index(index_type, table_schema, columns, index_options)
             .await?;
-        let index_options = self.validate_inverted_index_options(index_options).await?;
 
         let plan = CreateTableIndexPlan {
+            index_type,
             create_option: create_option.clone().into(),
             catalog,
             index_name,
@@ -470,6 +483,32 @@ impl Binder {
         Ok(Plan::CreateTableIndex(Box::new(plan)))
     }
 
+    pub(in crate::planner::binder) async fn validate_table_index(
+        &self,
+        index_type: &AstTableIndexType,
+        table_schema: TableSchemaRef,
+        columns: &[Identifier],
+        index_options: &BTreeMap<String, String>,
+    ) -> Result<(TableIndexType, Vec<ColumnId>, BTreeMap<String, String>)> {
+        match index_type {
+            AstTableIndexType::Inverted => {
+                let column_ids = self
+                    .validate_inverted_index_columns(table_schema, columns)
+                    .await?;
+                let index_options = self.validate_inverted_index_options(index_options).await?;
+                Ok((TableIndexType::Inverted, column_ids, index_options))
+            }
+            AstTableIndexType::Vector => {
+                let column_ids = self.validate_vector_index_columns(table_schema, columns)?;
+                let index_options = self.validate_vector_index_options(index_options)?;
+                Ok((TableIndexType::Vector, column_ids, index_options))
+            }
+            AstTableIndexType::Aggregating => Err(ErrorCode::UnsupportedIndex(
+                "Aggregating index can not be created on table columns",
+            )),
+        }
+    }
+
     pub(in crate::planner::binder) async fn validate_inverted_index_columns(
         &self,
         table_schema: TableSchemaRef,
@@ -548,6 +587,29 @@ impl Binder {
                     let index_record_val = format!("\"{}\"", value);
                     options.insert("index_record".to_string(), index_record_val);
                 }
+                "ngram_min" | "ngram_max" => match value.parse::<u64>() {
+                    Ok(len) if (1..=MAX_NGRAM_LENGTH).contains(&len) => {
+                        options.insert(key.clone(), len.to_string());
+                    }
+                    _ => {
+                        return Err(ErrorCode::IndexOptionInvalid(format!(
+                            "value `{value}` is invalid index {key}, must be an integer between 1 and {MAX_NGRAM_LENGTH}",
+                        )));
+                    }
+                },
+                "stopwords" => {
+                    let stop_words: Vec<&str> = value
+                        .split(',')
+                        .map(|v| v.trim())
+                        .filter(|v| !v.is_empty())
+                        .collect();
+                    if stop_words.is_empty() {
+                        return Err(ErrorCode::IndexOptionInvalid(format!(
+                            "value `{value}` is invalid index stopwords",
+                        )));
+                    }
+                    options.insert("stopwords".to_string(), stop_words.join(","));
+                }
                 _ => {
                     return Err(ErrorCode::IndexOptionInvalid(format!(
                         "index option `{key}` is invalid key for create inverted index statement",
@@ -555,16 +617,104 @@ impl Binder {
                 }
             }
         }
+
+        let ngram_min = options.get("ngram_min").map(|v| v.parse::<u64>().unwrap());
+        let ngram_max = options.get("ngram_max").map(|v| v.parse::<u64>().unwrap());
+        if ngram_min.is_some() || ngram_max.is_some() {
+            if options.get("tokenizer").map(|v| v.as_str()) != Some("ngram") {
+                return Err(ErrorCode::IndexOptionInvalid(
+                    "index option `ngram_min` and `ngram_max` can only be used with `ngram` tokenizer",
+                ));
+            }
+            if let (Some(ngram_min), Some(ngram_max)) = (ngram_min, ngram_max) {
+                if ngram_min > ngram_max {
+                    return Err(ErrorCode::IndexOptionInvalid(format!(
+                        "index option `ngram_min` {ngram_min} must not be greater than `ngram_max` {ngram_max}",
+                    )));
+                }
+            }
+        }
+        Ok(options)
+    }
+
+    pub(in crate::planner::binder) fn validate_vector_index_columns(
+        &self,
+        table_schema: TableSchemaRef,
+        columns: &[Identifier],
+    ) -> Result<Vec<ColumnId>> {
+        if columns.len() != 1 {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Vector index must be created on exactly one column, but got {} columns",
+                columns.len()
+            )));
+        }
+        let column = &columns[0];
+        let field = table_schema.field_with_name(&column.name).map_err(|_| {
+            ErrorCode::UnsupportedIndex(format!("Table does not have column {}", column))
+        })?;
+        let is_vector = match field.data_type.remove_nullable() {
+            TableDataType::Array(box inner) => matches!(
+                inner.remove_nullable(),
+                TableDataType::Number(NumberDataType::Float32 | NumberDataType::Float64)
+            ),
+            TableDataType::Vector(_) => true,
+            _ => false,
+        };
+        if !is_vector {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Vector index currently only support Array(Float32), Array(Float64) and VECTOR type, but the type of column {} is {}",
+                column, field.data_type
+            )));
+        }
+        Ok(vec![field.column_id])
+    }
+
+    pub(in crate::planner::binder) fn validate_vector_index_options(
+        &self,
+        index_options: &BTreeMap<String, String>,
+    ) -> Result<BTreeMap<String, String>> {
+        let mut options = BTreeMap::new();
+        for (opt, val) in index_options.iter() {
+            let key = opt.to_lowercase();
+            let value = val.to_lowercase();
+            match key.as_str() {
+                "metric" => {
+                    if value != "cosine" && value != "l2" {
+                        return Err(ErrorCode::IndexOptionInvalid(format!(
+                            "value `{value}` is invalid vector index metric, must be `cosine` or `l2`",
+                        )));
+                    }
+                    options.insert("metric".to_string(), value);
+                }
+                "lists" => {
+                    if !value.parse::<usize>().is_ok_and(|v| v > 0) {
+                        return Err(ErrorCode::IndexOptionInvalid(format!(
+                            "value `{value}` is invalid vector index lists, must be a positive integer",
+                        )));
+                    }
+                    options.insert("lists".to_string(), value);
+                }
+                _ => {
+                    return Err(ErrorCode::IndexOptionInvalid(format!(
+                        "index option `{key}` is invalid key for create vector index statement",
+                    )));
+                }
+            }
+        }
+        options
+            .entry("metric".to_string())
+            .or_insert_with(|| "cosine".to_string());
         Ok(options)
     }
 
     #[async_backtrace::framed]
-    pub(in crate::planner::binder) async fn bind_drop_inverted_index(
+    pub(in crate::planner::binder) async fn bind_drop_table_index(
         &mut self,
         _bind_context: &mut BindContext,
-        stmt: &DropInvertedIndexStmt,
+        stmt: &DropTableIndexStmt,
     ) -> Result<Plan> {
-        let DropInvertedIndexStmt {
+        let DropTableIndexStmt {
+            index_type,
             if_exists,
             index_name,
             catalog,
@@ -578,14 +728,20 @@ impl Binder {
         let table = self.ctx.get_table(&catalog, &database, &table).await?;
         if !table.support_index() {
             return Err(ErrorCode::UnsupportedIndex(format!(
-                "Table engine {} does not support create inverted index",
-                table.engine()
+                "Table engine {} does not support create {} index",
+                table.engine(),
+                index_type.to_string().to_lowercase()
             )));
         }
         let table_id = table.get_id();
         let index_name = self.normalize_object_identifier(index_name);
+        let index_type = Self::check_table_index_type(
+            index_type,
+            table.get_table_info().meta.indexes.get(&index_name),
+        )?;
 
         let plan = DropTableIndexPlan {
+            index_type,
             if_exists: *if_exists,
             catalog,
             index_name,
@@ -595,12 +751,13 @@ impl Binder {
     }
 
     #[async_backtrace::framed]
-    pub(in crate::planner::binder) async fn bind_refresh_inverted_index(
+    pub(in crate::planner::binder) async fn bind_refresh_table_index(
         &mut self,
         _bind_context: &mut BindContext,
-        stmt: &RefreshInvertedIndexStmt,
+        stmt: &RefreshTableIndexStmt,
     ) -> Result<Plan> {
-        let RefreshInvertedIndexStmt {
+        let RefreshTableIndexStmt {
+            index_type,
             index_name,
             catalog,
             database,
@@ -611,8 +768,11 @@ impl Binder {
         let (catalog, database, table) =
             self.normalize_object_identifier_triple(catalog, database, table);
         let index_name = self.normalize_object_identifier(index_name);
+        // The index type is checked when the index is refreshed.
+        let index_type = Self::check_table_index_type(index_type, None)?;
 
         let plan = RefreshTableIndexPlan {
+            index_type,
             catalog,
             database,
             table,
@@ -621,4 +781,31 @@ impl Binder {
         };
         Ok(Plan::RefreshTableIndex(Box::new(plan)))
     }
+
+    // The index type in statement must be the same as the existing index.
+    fn check_table_index_type(
+        index_type: &AstTableIndexType,
+        index: Option<&TableIndex>,
+    ) -> Result<TableIndexType> {
+        let index_type = match index_type {
+            AstTableIndexType::Inverted => TableIndexType::Inverted,
+            AstTableIndexType::Vector => TableIndexType::Vector,
+            AstTableIndexType::Aggregating => {
+                return Err(ErrorCode::UnsupportedIndex(
+                    "Aggregating index is not a table index",
+                ));
+            }
+        };
+        if let Some(index) = index {
+            if index.index_type != index_type {
+                return Err(ErrorCode::UnsupportedIndex(format!(
+                    "Index {} is a {} index, not a {} index",
+                    index.name,
+                    index.index_type.to_string().to_lowercase(),
+                    index_type.to_string().to_lowercase()
+                )));
+            }
+        }
+        Ok(index_type)
+    }
 }
@@ -34,7 +34,6 @@ use databend_common_ast::ast::Engine;
 use databend_common_ast::ast::ExistsTableStmt;
 use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::Identifier;
-use databend_common_ast::ast::InvertedIndexDefinition;
 use databend_common_ast::ast::ModifyColumnAction;
 use databend_common_ast::ast::OptimizeTableAction as AstOptimizeTableAction;
 use databend_common_ast::ast::OptimizeTableStmt;
@@ -46,6 +45,7 @@ use databend_common_ast::ast::ShowLimit;
 use databend_common_ast::ast::ShowTablesStatusStmt;
 use databend_common_ast::ast::ShowTablesStmt;
 use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TableIndexDefinition;
 use databend_common_ast::ast::TableReference;
 use databend_common_ast::ast::TableType;
 use databend_common_ast::ast::TruncateTableStmt;
@@ -530,7 +530,18 @@ impl Binder {
         }
 
         // Build table schema
+        let mut template_plan = None;
         let (schema, field_comments, inverted_indexes, as_query_plan) = match (&source, &as_query) {
+            (Some(CreateTableSource::Template(query)), None) => {
+                // The schema is built from the result of the query when the table is created.
+                if cluster_by.is_some() {
+                    return Err(ErrorCode::BadArguments(
+                        "Incorrect CREATE query: CLUSTER BY is not supported with USING TEMPLATE",
+                    ));
+                }
+                template_plan = Some(Box::new(self.as_query_plan(query).await?));
+                (Arc::new(TableSchema::default()), vec![], None, None)
+            }
             (Some(source), None) => {
                 // `CREATE TABLE` without `AS SELECT ...`
                 let (schema, field_comments, inverted_indexes) =
@@ -723,6 +734,7 @@ impl Binder {
             field_comments,
             cluster_key,
             as_select: as_query_plan,
+            template: template_plan,
             inverted_indexes,
         };
         Ok(Plan::CreateTable(Box::new(plan)))
@@ -788,6 +800,7 @@ impl Binder {
             field_comments: vec![],
             cluster_key: None,
             as_select: None,
+            template: None,
             inverted_indexes: None,
         })))
     }
@@ -1436,15 +1449,38 @@ impl Binder {
             catalog,
             database,
             table,
+            columns,
         } = stmt;
 
         let (catalog, database, table) =
             self.normalize_object_identifier_triple(catalog, database, table);
 
+        let mut column_names = Vec::with_capacity(columns.len());
+        if !columns.is_empty() {
+            let schema = self
+                .ctx
+                .get_table(&catalog, &database, &table)
+                .await?
+                .schema();
+            for column in columns {
+                let name = normalize_identifier(column, &self.name_resolution_ctx).name;
+                schema.field_with_name(&name)?;
+                if !column_names.contains(&name) {
+                    column_names.push(name);
+                }
+            }
+            if column_names.len() < 2 {
+                return Err(ErrorCode::BadArguments(
+                    "Multi-column statistics require at least two distinct columns",
+                ));
+            }
+        }
+
         Ok(Plan::AnalyzeTable(Box::new(AnalyzeTablePlan {
             catalog,
             database,
             table,
+            columns: column_names,
         })))
     }
 
@@ -1627,37 +1663,41 @@ impl Binder {
     }
 
     #[async_backtrace::framed]
-    async fn analyze_inverted_indexes(
+    async fn analyze_table_indexes(
         &self,
         table_schema: TableSchemaRef,
-        inverted_index_defs: &[InvertedIndexDefinition],
+        table_index_defs: &[TableIndexDefinition],
     ) -> Result<BTreeMap<String, TableIndex>> {
-        let mut inverted_indexes = BTreeMap::new();
-        for inverted_index_def in inverted_index_defs {
-            let name = self.normalize_object_identifier(&inverted_index_def.index_name);
-            if inverted_indexes.contains_key(&name) {
+        let mut table_indexes = BTreeMap::new();
+        for table_index_def in table_index_defs {
+            let name = self.normalize_object_identifier(&table_index_def.index_name);
+            if table_indexes.contains_key(&name) {
                 return Err(ErrorCode::BadArguments(format!(
-                    "Duplicated inverted index name: {}",
+                    "Duplicated {} index name: {}",
+                    table_index_def.index_type.to_string().to_lowercase(),
                     name
                 )));
             }
-            let column_ids = self
-                .validate_inverted_index_columns(table_schema.clone(), &inverted_index_def.columns)
-                .await?;
-            let options = self
-                .validate_inverted_index_options(&inverted_index_def.index_options)
+            let (index_type, column_ids, options) = self
+                .validate_table_index(
+                    &table_index_def.index_type,
+                    table_schema.clone(),
+                    &table_index_def.columns,
+                    &table_index_def.index_options,
+                )
                 .await?;
 
-            let inverted_index = TableIndex {
+            let table_index = TableIndex {
+                index_type,
                 name: name.clone(),
                 column_ids,
-                sync_creation: inverted_index_def.sync_creation,
+                sync_creation: table_index_def.sync_creation,
                 version: Uuid::new_v4().simple().to_string(),
                 options,
             };
-            inverted_indexes.insert(name, inverted_index);
+            table_indexes.insert(name, table_index);
         }
-        Ok(inverted_indexes)
+        Ok(table_indexes)
     }
 
     #[async_backtrace::framed]
@@ -1670,18 +1710,18 @@ impl Binder {
         Option<BTreeMap<String, TableIndex>>,
     )> {
         match source {
-            CreateTableSource::Columns(columns, inverted_index_defs) => {
+            CreateTableSource::Columns(columns, table_index_defs) => {
                 let (schema, comments) =
                     self.analyze_create_table_schema_by_columns(columns).await?;
-                let inverted_indexes = if let Some(inverted_index_defs) = inverted_index_defs {
-                    let inverted_indexes = self
-                        .analyze_inverted_indexes(schema.clone(), inverted_index_defs)
+                let table_indexes = if let Some(table_index_defs) = table_index_defs {
+                    let table_indexes = self
+                        .analyze_table_indexes(schema.clone(), table_index_defs)
                         .await?;
-                    Some(inverted_indexes)
+                    Some(table_indexes)
                 } else {
                     None
                 };
-                Ok((schema, comments, inverted_indexes))
+                Ok((schema, comments, table_indexes))
             }
             CreateTableSource::Like {
                 catalog,
@@ -1706,6 +1746,9 @@ impl Binder {
                     Ok((table.schema(), table.field_comments().clone(), None))
                 }
             }
+            CreateTableSource::Template(_) => Err(ErrorCode::BadArguments(
+                "Incorrect CREATE query: USING TEMPLATE can not be used with AS SELECT",
+            )),
         }
     }
 
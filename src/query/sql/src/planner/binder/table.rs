@@ -560,6 +560,39 @@ impl Binder {
                     Utc.timestamp_nanos(micros * 1000),
                 ))
             }
+            TimeTravelPoint::Version(expr) => {
+                let mut type_checker = TypeChecker::try_create(
+                    bind_context,
+                    self.ctx.clone(),
+                    &self.name_resolution_ctx,
+                    self.metadata.clone(),
+                    &[],
+                    false,
+                )?;
+                let box (scalar, _) = type_checker.resolve(expr)?;
+                let scalar_expr = scalar.as_expr()?;
+
+                let (new_expr, _) = ConstantFolder::fold(
+                    &scalar_expr,
+                    &self.ctx.get_function_context()?,
+                    &BUILTIN_FUNCTIONS,
+                );
+
+                let v = check_number::<_, u64>(
+                    None,
+                    &FunctionContext::default(),
+                    &new_expr,
+                    &BUILTIN_FUNCTIONS,
+                )
+                .map_err(|_| {
+                    ErrorCode::InvalidArgument(format!(
+                        "TimeTravelPoint for 'Version' must resolve to a constant non-negative integer. \
+                        Provided expression '{}' does not meet this requirement",
+                        expr
+                    ))
+                })?;
+                Ok(NavigationPoint::Version(v))
+            }
             TimeTravelPoint::Stream {
                 catalog,
                 database,
@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use async_recursion::async_recursion;
 use databend_common_ast::ast::ExplainKind;
+use databend_common_ast::ast::ValidationMode;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -295,7 +296,9 @@ pub async fn optimize(mut opt_ctx: OptimizerContext, plan: Plan) -> Result<Plan>
             options,
         })),
         Plan::CopyIntoTable(mut plan) if !plan.no_file_to_copy => {
+            // validation collects the sample rows and errors on the local node.
             plan.enable_distributed = opt_ctx.enable_distributed_optimization
+                && plan.validation_mode == ValidationMode::None
                 && opt_ctx
                     .table_ctx
                     .get_settings()
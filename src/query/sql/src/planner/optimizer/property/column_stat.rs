@@ -21,6 +21,8 @@ use crate::IndexType;
 
 pub type ColumnStatSet = HashMap<IndexType, ColumnStat>;
 
+pub type MultiColumnStatSet = Vec<MultiColumnStat>;
+
 #[derive(Debug, Clone)]
 /// Statistics information of a column
 pub struct ColumnStat {
@@ -40,6 +42,20 @@ pub struct ColumnStat {
     pub histogram: Option<Histogram>,
 }
 
+#[derive(Debug, Clone)]
+/// Statistics information of a group of correlated columns
+pub struct MultiColumnStat {
+    /// Indexes of the columns
+    pub columns: Vec<IndexType>,
+
+    /// Number of distinct value combinations of the columns
+    pub ndv: f64,
+
+    /// The most common value combinations (in the order of `columns`)
+    /// and their frequencies, the fraction of rows holding the combination
+    pub most_common_values: Vec<(Vec<Datum>, f64)>,
+}
+
 #[derive(Debug, Clone)]
 pub struct NewStatistic {
     pub min: Option<Datum>,
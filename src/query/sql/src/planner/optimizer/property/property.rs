@@ -17,6 +17,7 @@ use std::fmt::Display;
 use std::fmt::Formatter;
 
 use super::column_stat::ColumnStatSet;
+use super::column_stat::MultiColumnStatSet;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
 use crate::plans::SortItem;
@@ -49,6 +50,8 @@ pub struct Statistics {
     pub precise_cardinality: Option<u64>,
     /// Statistics of columns, column index -> column stat
     pub column_stats: ColumnStatSet,
+    /// Statistics of groups of correlated columns
+    pub multi_column_stats: MultiColumnStatSet,
 }
 
 #[derive(Default, Clone, Debug)]
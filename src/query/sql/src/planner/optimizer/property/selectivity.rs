@@ -14,6 +14,7 @@
 
 use std::cmp::max;
 use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::collections::HashSet;
 
 use databend_common_exception::ErrorCode;
@@ -73,6 +74,94 @@ impl<'a> SelectivityEstimator<'a> {
         }
     }
 
+    /// Compute the selectivity of the conjunction of predicates.
+    pub fn compute_conjunction_selectivity(&mut self, predicates: &[ScalarExpr]) -> Result<f64> {
+        // The equality predicates on a group of correlated columns are estimated
+        // together by the multi-column statistics, instead of one by one.
+        let multi_column_selectivity = self.compute_multi_column_selectivity(predicates);
+        let mut selectivity = MAX_SELECTIVITY;
+        for (idx, pred) in predicates.iter().enumerate() {
+            // Compute selectivity for each conjunction
+            let pred_selectivity = self.compute_selectivity(pred, true)?;
+            if let Some((_, covered)) = &multi_column_selectivity
+                && covered.contains(&idx)
+            {
+                continue;
+            }
+            selectivity = selectivity.min(pred_selectivity);
+        }
+        if let Some((multi_column_selectivity, _)) = multi_column_selectivity {
+            selectivity = selectivity.min(multi_column_selectivity);
+        }
+        Ok(selectivity)
+    }
+
+    // Estimate the selectivity of the equality predicates with constants by the multi-column
+    // statistics covering their columns, returns the selectivity and the indexes of the
+    // covered predicates.
+    fn compute_multi_column_selectivity(
+        &self,
+        predicates: &[ScalarExpr],
+    ) -> Option<(f64, Vec<usize>)> {
+        if self.input_stat.multi_column_stats.is_empty() {
+            return None;
+        }
+        let mut equal_predicates: HashMap<IndexType, (usize, Datum)> = HashMap::new();
+        for (idx, pred) in predicates.iter().enumerate() {
+            let ScalarExpr::FunctionCall(func) = pred else {
+                continue;
+            };
+            if func.func_name != "eq" || func.arguments.len() != 2 {
+                continue;
+            }
+            let (column_ref, constant) = match (&func.arguments[0], &func.arguments[1]) {
+                (ScalarExpr::BoundColumnRef(column_ref), ScalarExpr::ConstantExpr(constant))
+                | (ScalarExpr::ConstantExpr(constant), ScalarExpr::BoundColumnRef(column_ref)) => {
+                    (column_ref, constant)
+                }
+                _ => continue,
+            };
+            if let Some(datum) = Datum::from_scalar(constant.value.clone()) {
+                equal_predicates
+                    .entry(column_ref.column.index)
+                    .or_insert((idx, datum));
+            }
+        }
+
+        // Use the statistics covering the most columns.
+        let stat = self
+            .input_stat
+            .multi_column_stats
+            .iter()
+            .filter(|stat| {
+                stat.columns
+                    .iter()
+                    .all(|col| equal_predicates.contains_key(col))
+            })
+            .max_by_key(|stat| stat.columns.len())?;
+        let (covered, values): (Vec<_>, Vec<_>) = stat
+            .columns
+            .iter()
+            .map(|col| equal_predicates[col].clone())
+            .unzip();
+
+        let most_common_value = stat.most_common_values.iter().find(|(mcv, _)| {
+            mcv.iter()
+                .zip(values.iter())
+                .all(|(left, right)| datum_equal(left, right))
+        });
+        let selectivity = match most_common_value {
+            Some((_, frequency)) => *frequency,
+            None => {
+                // Assume the other combinations are in a uniform distribution.
+                let mcv_frequency: f64 = stat.most_common_values.iter().map(|(_, f)| f).sum();
+                let other_ndv = stat.ndv - stat.most_common_values.len() as f64;
+                (1.0 - mcv_frequency).max(0.0) / other_ndv.max(1.0)
+            }
+        };
+        Some((selectivity, covered))
+    }
+
     /// Compute the selectivity of a predicate.
     pub fn compute_selectivity(&mut self, predicate: &ScalarExpr, update: bool) -> Result<f64> {
         Ok(match predicate {
@@ -320,6 +409,17 @@ impl<'a> SelectivityEstimator<'a> {
 
     // Update other columns' statistic according to selectivity.
     pub fn update_other_statistic_by_selectivity(&mut self, selectivity: f64) {
+        // The multi-column statistics are inaccurate if any of the columns is updated.
+        let updated_column_indexes = &self.updated_column_indexes;
+        self.input_stat.multi_column_stats.retain(|stat| {
+            !stat
+                .columns
+                .iter()
+                .any(|col| updated_column_indexes.contains(col))
+        });
+        for stat in self.input_stat.multi_column_stats.iter_mut() {
+            stat.ndv = (stat.ndv * selectivity).ceil();
+        }
         for (index, column_stat) in self.input_stat.column_stats.iter_mut() {
             if !self.updated_column_indexes.contains(index) {
                 let new_ndv = (column_stat.ndv * selectivity).ceil();
@@ -633,3 +733,11 @@ fn update_statistic(
 
     Ok(())
 }
+
+// The numeric datums of different types (e.g. Int and UInt) are compared by their values.
+fn datum_equal(left: &Datum, right: &Datum) -> bool {
+    if left.is_numeric() && right.is_numeric() {
+        return matches!((left.to_double(), right.to_double()), (Ok(l), Ok(r)) if l == r);
+    }
+    left == right
+}
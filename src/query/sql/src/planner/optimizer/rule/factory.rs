@@ -73,7 +73,7 @@ impl RuleFactory {
             RuleID::PushDownLimit => Ok(Box::new(RulePushDownLimit::new(ctx.metadata))),
             RuleID::PushDownLimitUnion => Ok(Box::new(RulePushDownLimitUnion::new())),
             RuleID::PushDownLimitScan => Ok(Box::new(RulePushDownLimitScan::new())),
-            RuleID::PushDownSortScan => Ok(Box::new(RulePushDownSortScan::new())),
+            RuleID::PushDownSortScan => Ok(Box::new(RulePushDownSortScan::new(ctx.metadata))),
             RuleID::PushDownSortEvalScalar => {
                 Ok(Box::new(RulePushDownSortEvalScalar::new(ctx.metadata)))
             }
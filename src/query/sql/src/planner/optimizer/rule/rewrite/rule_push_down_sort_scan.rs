@@ -15,16 +15,27 @@
 use std::cmp;
 use std::sync::Arc;
 
+use databend_common_catalog::plan::VectorIndexInfo;
 use databend_common_exception::Result;
+use databend_common_expression::types::NumberColumn;
+use databend_common_expression::types::F32;
+use databend_common_expression::Column;
+use databend_common_expression::Scalar;
+use databend_common_meta_app::schema::TableIndexType;
 
 use crate::optimizer::extract::Matcher;
 use crate::optimizer::rule::Rule;
 use crate::optimizer::rule::TransformResult;
 use crate::optimizer::RuleID;
 use crate::optimizer::SExpr;
+use crate::plans::EvalScalar;
 use crate::plans::RelOp;
 use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::plans::Scan;
 use crate::plans::Sort;
+use crate::ColumnEntry;
+use crate::MetadataRef;
 
 /// Input:  Sort
 ///           \
@@ -34,15 +45,20 @@ use crate::plans::Sort;
 ///         Sort
 ///           \
 ///           Scan(padding order_by and limit)
+///
+/// If the sort key is the distance between a vector column and a constant vector,
+/// and the column has a vector index, the vector index info is also padded into the scan.
 pub struct RulePushDownSortScan {
     id: RuleID,
     matchers: Vec<Matcher>,
+    metadata: MetadataRef,
 }
 
 impl RulePushDownSortScan {
-    pub fn new() -> Self {
+    pub fn new(metadata: MetadataRef) -> Self {
         Self {
             id: RuleID::PushDownSortScan,
+            metadata,
             matchers: vec![
                 Matcher::MatchOp {
                     op_type: RelOp::Sort,
@@ -64,6 +80,127 @@ impl RulePushDownSortScan {
             ],
         }
     }
+
+    // Try to use the vector index for the query like
+    // `ORDER BY cosine_distance(col, [1.0, 2.0, ...]) LIMIT n`.
+    fn try_find_vector_index(
+        &self,
+        sort: &Sort,
+        eval_scalar: &EvalScalar,
+        scan: &Scan,
+    ) -> Option<VectorIndexInfo> {
+        let limit = sort.limit.filter(|limit| *limit > 0)?;
+        if sort.items.len() != 1 || sort.window_partition.is_some() {
+            return None;
+        }
+        let sort_item = &sort.items[0];
+        if !sort_item.asc || sort_item.nulls_first {
+            return None;
+        }
+        if scan.push_down_predicates.is_some()
+            || scan.prewhere.is_some()
+            || scan.agg_index.is_some()
+            || scan.inverted_index.is_some()
+            || scan.change_type.is_some()
+            || scan.sample.is_some()
+        {
+            return None;
+        }
+
+        let item = eval_scalar
+            .items
+            .iter()
+            .find(|item| item.index == sort_item.index)?;
+        let ScalarExpr::FunctionCall(func) = &item.scalar else {
+            return None;
+        };
+        if func.func_name != "cosine_distance" && func.func_name != "l2_distance" {
+            return None;
+        }
+        if func.arguments.len() != 2 {
+            return None;
+        }
+        let (column_index, query_values) = match (&func.arguments[0], &func.arguments[1]) {
+            (arg, ScalarExpr::ConstantExpr(constant))
+            | (ScalarExpr::ConstantExpr(constant), arg) => (
+                Self::column_ref_index(arg)?,
+                Self::vector_values(&constant.value)?,
+            ),
+            _ => return None,
+        };
+
+        let metadata = self.metadata.read();
+        let column_id = match metadata.column(column_index) {
+            ColumnEntry::BaseTableColumn(column)
+                if column.table_index == scan.table_index && column.path_indices.is_none() =>
+            {
+                column.column_id?
+            }
+            _ => return None,
+        };
+        let table = metadata.table(scan.table_index).table();
+        let table_info = table.get_table_info();
+        let index = table_info.meta.indexes.values().find(|index| {
+            index.index_type == TableIndexType::Vector && index.column_ids == vec![column_id]
+        })?;
+        let metric = index
+            .options
+            .get("metric")
+            .map(|metric| metric.as_str())
+            .unwrap_or("cosine");
+        let expected_func_name = match metric {
+            "cosine" => "cosine_distance",
+            "l2" => "l2_distance",
+            _ => return None,
+        };
+        if func.func_name != expected_func_name {
+            return None;
+        }
+
+        Some(VectorIndexInfo {
+            index_name: index.name.clone(),
+            index_version: index.version.clone(),
+            index_options: index.options.clone(),
+            column_id,
+            func_name: func.func_name.clone(),
+            query_values,
+            limit,
+        })
+    }
+
+    fn column_ref_index(arg: &ScalarExpr) -> Option<usize> {
+        match arg {
+            ScalarExpr::BoundColumnRef(column_ref) => Some(column_ref.column.index),
+            ScalarExpr::CastExpr(cast) => Self::column_ref_index(&cast.argument),
+            _ => None,
+        }
+    }
+
+    fn vector_values(value: &Scalar) -> Option<Vec<F32>> {
+        let column = match value {
+            Scalar::Array(column) => column,
+            Scalar::Vector(vector) => {
+                let values = vector.as_ref().to_f32_values();
+                return Some(values.into_iter().map(F32::from).collect());
+            }
+            _ => return None,
+        };
+        let column = match column {
+            Column::Nullable(box nullable) if nullable.validity.null_count() == 0 => {
+                &nullable.column
+            }
+            Column::Nullable(_) => return None,
+            column => column,
+        };
+        let values = match column {
+            Column::Number(NumberColumn::Float32(values)) => values.iter().copied().collect(),
+            Column::Number(NumberColumn::Float64(values)) => {
+                values.iter().map(|v| F32::from(v.0 as f32)).collect()
+            }
+            _ => return None,
+        };
+        Some(values)
+    }
 }
 
 impl Rule for RulePushDownSortScan {
@@ -74,11 +211,19 @@ impl Rule for RulePushDownSortScan {
     fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
         let sort: Sort = s_expr.plan().clone().try_into()?;
         let child = s_expr.child(0)?;
-        let mut get = match child.plan() {
+        let mut get: Scan = match child.plan() {
             RelOperator::Scan(scan) => scan.clone(),
-            RelOperator::EvalScalar(_) => {
-                let child = child.child(0)?;
-                child.plan().clone().try_into()?
+            RelOperator::EvalScalar(eval_scalar) => {
+                let scan: Scan = child.child(0)?.plan().clone().try_into()?;
+                if scan.vector_index.is_none() {
+                    let vector_index = self.try_find_vector_index(&sort, eval_scalar, &scan);
+                    Scan {
+                        vector_index,
+                        ..scan
+                    }
+                } else {
+                    scan
+                }
             }
             _ => unreachable!(),
         };
@@ -22,6 +22,7 @@ use databend_common_expression::types::F64;
 use databend_common_expression::ColumnId;
 use databend_common_expression::Scalar;
 
+use crate::optimizer::MultiColumnStat;
 use crate::optimizer::SExpr;
 use crate::plans::ConstantExpr;
 use crate::plans::Filter;
@@ -71,6 +72,7 @@ impl CollectStatisticsOptimizer {
 
                 let mut column_stats = HashMap::new();
                 let mut histograms = HashMap::new();
+                let mut column_indexes = HashMap::new();
                 for column in columns.iter() {
                     if let ColumnEntry::BaseTableColumn(BaseTableColumn {
                         column_index,
@@ -87,16 +89,42 @@ impl CollectStatisticsOptimizer {
                                 let histogram =
                                     column_statistics_provider.histogram(column_id as ColumnId);
                                 histograms.insert(*column_index, histogram);
+                                column_indexes.insert(column_id as ColumnId, *column_index);
                             }
                         }
                     }
                 }
 
+                // Map the column ids of multi-column statistics to the column indexes.
+                let multi_column_stats = column_statistics_provider
+                    .multi_column_statistics()
+                    .into_iter()
+                    .filter(|stat| stat.row_count > 0)
+                    .filter_map(|stat| {
+                        let columns = stat
+                            .column_ids
+                            .iter()
+                            .map(|id| column_indexes.get(id).copied())
+                            .collect::<Option<Vec<_>>>()?;
+                        let row_count = stat.row_count as f64;
+                        Some(MultiColumnStat {
+                            columns,
+                            ndv: stat.ndv as f64,
+                            most_common_values: stat
+                                .most_common_values
+                                .into_iter()
+                                .map(|(values, count)| (values, count as f64 / row_count))
+                                .collect(),
+                        })
+                    })
+                    .collect();
+
                 let mut scan = scan.clone();
                 scan.statistics = Arc::new(Statistics {
                     table_stats,
                     column_stats,
                     histograms,
+                    multi_column_stats,
                 });
                 let mut s_expr = s_expr.replace_plan(Arc::new(RelOperator::Scan(scan.clone())));
                 if let Some(sample) = &scan.sample {
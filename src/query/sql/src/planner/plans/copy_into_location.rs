@@ -36,11 +36,15 @@ pub struct CopyIntoLocationPlan {
 impl CopyIntoLocationPlan {
     pub fn schema(&self) -> DataSchemaRef {
         if self.options.detailed_output {
-            DataSchemaRefExt::create(vec![
+            let mut fields = vec![
                 DataField::new("file_name", DataType::String),
                 DataField::new("file_size", DataType::Number(NumberDataType::UInt64)),
                 DataField::new("row_count", DataType::Number(NumberDataType::UInt64)),
-            ])
+            ];
+            if !self.options.partition_keys.is_empty() {
+                fields.push(DataField::new("partition", DataType::String));
+            }
+            DataSchemaRefExt::create(fields)
         } else {
             DataSchemaRefExt::create(vec![
                 DataField::new("rows_unloaded", DataType::Number(NumberDataType::UInt64)),
@@ -15,10 +15,10 @@
 use std::fmt::Debug;
 use std::fmt::Display;
 use std::fmt::Formatter;
-use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Instant;
 
+use databend_common_ast::ast::ValidationMode;
 use databend_common_catalog::plan::StageTableInfo;
 use databend_common_catalog::table_context::FilteredCopyFiles;
 use databend_common_catalog::table_context::TableContext;
@@ -31,55 +31,20 @@ use databend_common_expression::DataSchema;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::DataSchemaRefExt;
 use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
 use databend_common_meta_app::principal::COPY_MAX_FILES_COMMIT_MSG;
 use databend_common_meta_app::principal::COPY_MAX_FILES_PER_COMMIT;
 use databend_common_meta_app::schema::CatalogInfo;
 use databend_common_metrics::storage::*;
 use databend_common_storage::init_stage_operator;
+use databend_common_storage::ArchiveFormat;
 use log::info;
 
 use crate::plans::Plan;
 
-#[derive(PartialEq, Eq, Clone, Debug, serde::Serialize, serde::Deserialize)]
-pub enum ValidationMode {
-    None,
-    ReturnNRows(u64),
-    ReturnErrors,
-    ReturnAllErrors,
-}
-
-impl Display for ValidationMode {
-    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
-        match self {
-            ValidationMode::None => write!(f, ""),
-            ValidationMode::ReturnNRows(v) => write!(f, "RETURN_ROWS={v}"),
-            ValidationMode::ReturnErrors => write!(f, "RETURN_ERRORS"),
-            ValidationMode::ReturnAllErrors => write!(f, "RETURN_ALL_ERRORS"),
-        }
-    }
-}
-
-impl FromStr for ValidationMode {
-    type Err = String;
-    fn from_str(s: &str) -> std::result::Result<Self, String> {
-        match s.to_uppercase().as_str() {
-            "" => Ok(ValidationMode::None),
-            "RETURN_ERRORS" => Ok(ValidationMode::ReturnErrors),
-            "RETURN_ALL_ERRORS" => Ok(ValidationMode::ReturnAllErrors),
-            v => {
-                let rows_str = v.replace("RETURN_", "").replace("_ROWS", "");
-                let rows = rows_str.parse::<u64>();
-                match rows {
-                    Ok(v) => Ok(ValidationMode::ReturnNRows(v)),
-                    Err(_) => Err(format!(
-                        "Unknown validation mode:{v:?}, must one of {{ RETURN_<n>_ROWS | RETURN_ERRORS | RETURN_ALL_ERRORS}}"
-                    )),
-                }
-            }
-        }
-    }
-}
-
 #[derive(Clone, Copy, Eq, PartialEq, Debug, serde::Serialize, serde::Deserialize)]
 pub enum CopyIntoTableMode {
     Insert { overwrite: bool },
@@ -113,6 +78,14 @@ impl CopyIntoTableMode {
     }
 }
 
+/// The table to write the rows rejected with `ON_ERROR = CONTINUE` into.
+#[derive(Clone, Debug)]
+pub struct CopyErrorTable {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+}
+
 #[derive(Clone)]
 pub struct CopyIntoTablePlan {
     pub no_file_to_copy: bool,
@@ -139,6 +112,8 @@ pub struct CopyIntoTablePlan {
     pub enable_distributed: bool,
 
     pub files_collected: bool,
+
+    pub error_table: Option<CopyErrorTable>,
 }
 
 impl CopyIntoTablePlan {
@@ -161,7 +136,15 @@ impl CopyIntoTablePlan {
         let thread_num = ctx.get_settings().get_max_threads()? as usize;
         let operator = init_stage_operator(&stage_table_info.stage_info)?;
         let options = &stage_table_info.copy_into_table_options;
-        let all_source_file_infos = if operator.info().native_capability().blocking {
+        let compression = stage_table_info.stage_info.file_format_params.compression();
+        let all_source_file_infos = if ArchiveFormat::may_be_archive(compression) {
+            // Members of archives are listed as files.
+            let max_files = if options.force { max_files } else { None };
+            stage_table_info
+                .files_info
+                .list_with_archives(&operator, thread_num, max_files, compression)
+                .await
+        } else if operator.info().native_capability().blocking {
             if options.force {
                 stage_table_info
                     .files_info
@@ -267,6 +250,7 @@ impl Debug for CopyIntoTablePlan {
             validation_mode,
             stage_table_info,
             query,
+            error_table,
             ..
         } = self;
         write!(
@@ -278,6 +262,9 @@ impl Debug for CopyIntoTablePlan {
         write!(f, ", validation_mode: {validation_mode:?}")?;
         write!(f, ", from: {stage_table_info:?}")?;
         write!(f, " query: {query:?}")?;
+        if let Some(error_table) = error_table {
+            write!(f, ", error_table: {error_table:?}")?;
+        }
         Ok(())
     }
 }
@@ -300,11 +287,35 @@ impl CopyIntoTablePlan {
         ])
     }
 
+    /// Columns of the rows rejected by copy, returned by `VALIDATION_MODE = RETURN_ERRORS`.
+    /// The `ERROR_TABLE` may have a subset of them, in any order.
+    pub fn rejected_rows_schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("file_name", TableDataType::String),
+            TableField::new("line_number", TableDataType::Number(NumberDataType::Int64)),
+            TableField::new(
+                "column_name",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new("error_code", TableDataType::Number(NumberDataType::Int32)),
+            TableField::new("error_message", TableDataType::String),
+            TableField::new(
+                "raw_record",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+        ])
+    }
+
     pub fn schema(&self) -> DataSchemaRef {
         if self.from_attachment {
-            Arc::new(DataSchema::empty())
-        } else {
-            Self::copy_into_table_schema()
+            return Arc::new(DataSchema::empty());
+        }
+        match self.validation_mode {
+            ValidationMode::None => Self::copy_into_table_schema(),
+            ValidationMode::ReturnNRows(_) => self.required_values_schema.clone(),
+            ValidationMode::ReturnErrors | ValidationMode::ReturnAllErrors => {
+                Arc::new(Self::rejected_rows_schema().as_ref().into())
+            }
         }
     }
 }
@@ -54,6 +54,8 @@ pub struct CreateTablePlan {
     pub field_comments: Vec<String>,
     pub cluster_key: Option<String>,
     pub as_select: Option<Box<Plan>>,
+    /// `USING TEMPLATE (<query>)`, the schema is built from the result of the query.
+    pub template: Option<Box<Plan>>,
     pub inverted_indexes: Option<BTreeMap<String, TableIndex>>,
 }
 
@@ -200,6 +202,8 @@ pub struct AnalyzeTablePlan {
     pub catalog: String,
     pub database: String,
     pub table: String,
+    /// Columns to collect the multi-column statistics for, empty if not specified.
+    pub columns: Vec<String>,
 }
 
 impl AnalyzeTablePlan {
@@ -251,6 +251,12 @@ impl Join {
         let mut join_card_updated = false;
         let mut left_column_index = 0;
         let mut right_column_index = 0;
+        let multi_column_join_card = self.multi_column_join_cardinality(
+            *left_cardinality,
+            *right_cardinality,
+            left_statistics,
+            right_statistics,
+        );
         for condition in self.equi_conditions.iter() {
             let left_condition = &condition.left;
             let right_condition = &condition.right;
@@ -349,6 +355,11 @@ impl Join {
                 _ => continue,
             }
         }
+        if let Some(card) = multi_column_join_card
+            && card < join_card
+        {
+            join_card = card;
+        }
         if join_card_updated {
             for (idx, left) in left_statistics.column_stats.iter_mut() {
                 if *idx == left_column_index {
@@ -403,6 +414,42 @@ impl Join {
         Ok(join_card)
     }
 
+    // Evaluate the cardinality of a multi-key join by the number of distinct combinations
+    // of the join keys, which is taken from the multi-column statistics of either side.
+    fn multi_column_join_cardinality(
+        &self,
+        left_cardinality: f64,
+        right_cardinality: f64,
+        left_statistics: &Statistics,
+        right_statistics: &Statistics,
+    ) -> Option<f64> {
+        if left_statistics.multi_column_stats.is_empty()
+            && right_statistics.multi_column_stats.is_empty()
+        {
+            return None;
+        }
+        let mut left_keys = Vec::with_capacity(self.equi_conditions.len());
+        let mut right_keys = Vec::with_capacity(self.equi_conditions.len());
+        for condition in self.equi_conditions.iter() {
+            if let (ScalarExpr::BoundColumnRef(left), ScalarExpr::BoundColumnRef(right)) =
+                (&condition.left, &condition.right)
+            {
+                left_keys.push(left.column.index);
+                right_keys.push(right.column.index);
+            }
+        }
+        if left_keys.len() < 2 {
+            return None;
+        }
+        let (left_ndv, left_by_multi) = keys_ndv(&left_keys, left_cardinality, left_statistics)?;
+        let (right_ndv, right_by_multi) =
+            keys_ndv(&right_keys, right_cardinality, right_statistics)?;
+        if !left_by_multi && !right_by_multi {
+            return None;
+        }
+        Some(left_cardinality * right_cardinality / f64::max(left_ndv, right_ndv))
+    }
+
     pub fn has_null_equi_condition(&self) -> bool {
         self.equi_conditions
             .iter()
@@ -445,19 +492,25 @@ impl Join {
             JoinType::RightSingle | JoinType::LeftMark | JoinType::RightAnti => right_cardinality,
         };
         // Derive column statistics
-        let column_stats = if cardinality == 0.0 {
-            HashMap::new()
+        let (column_stats, multi_column_stats) = if cardinality == 0.0 {
+            (HashMap::new(), vec![])
         } else {
             let mut column_stats = HashMap::new();
             column_stats.extend(left_statistics.column_stats);
             column_stats.extend(right_statistics.column_stats);
-            column_stats
+            let mut multi_column_stats = left_statistics.multi_column_stats;
+            multi_column_stats.extend(right_statistics.multi_column_stats);
+            for stat in multi_column_stats.iter_mut() {
+                stat.ndv = stat.ndv.min(cardinality);
+            }
+            (column_stats, multi_column_stats)
         };
         Ok(Arc::new(StatInfo {
             cardinality,
             statistics: Statistics {
                 precise_cardinality: None,
                 column_stats,
+                multi_column_stats,
             },
         }))
     }
@@ -807,6 +860,25 @@ fn evaluate_by_histogram(
     Ok(card)
 }
 
+// The number of distinct combinations of the keys, the multi-column statistics covering the
+// most keys is used and the other keys are assumed to be independent. The returned flag
+// indicates whether the multi-column statistics is used.
+fn keys_ndv(keys: &[IndexType], cardinality: f64, statistics: &Statistics) -> Option<(f64, bool)> {
+    let multi_column_stat = statistics
+        .multi_column_stats
+        .iter()
+        .filter(|stat| stat.columns.iter().all(|col| keys.contains(col)))
+        .max_by_key(|stat| stat.columns.len());
+    let mut ndv = multi_column_stat.map_or(1.0, |stat| stat.ndv);
+    for key in keys.iter() {
+        if multi_column_stat.is_some_and(|stat| stat.columns.contains(key)) {
+            continue;
+        }
+        ndv *= statistics.column_stats.get(key)?.ndv;
+    }
+    Some((ndv.min(cardinality).max(1.0), multi_column_stat.is_some()))
+}
+
 fn evaluate_by_ndv(
     left_stat: &ColumnStat,
     right_stat: &ColumnStat,
@@ -18,6 +18,7 @@ use std::sync::Arc;
 
 use databend_common_ast::ast::SampleConfig;
 use databend_common_catalog::plan::InvertedIndexInfo;
+use databend_common_catalog::plan::VectorIndexInfo;
 use databend_common_catalog::statistics::BasicColumnStatistics;
 use databend_common_catalog::table::TableStatistics;
 use databend_common_catalog::table_context::TableContext;
@@ -35,6 +36,7 @@ use crate::optimizer::ColumnSet;
 use crate::optimizer::ColumnStat;
 use crate::optimizer::ColumnStatSet;
 use crate::optimizer::Distribution;
+use crate::optimizer::MultiColumnStat;
 use crate::optimizer::PhysicalProperty;
 use crate::optimizer::RelExpr;
 use crate::optimizer::RelationalProperty;
@@ -42,7 +44,6 @@ use crate::optimizer::RequiredProperty;
 use crate::optimizer::SelectivityEstimator;
 use crate::optimizer::StatInfo;
 use crate::optimizer::Statistics as OpStatistics;
-use crate::optimizer::MAX_SELECTIVITY;
 use crate::plans::Operator;
 use crate::plans::RelOp;
 use crate::plans::ScalarExpr;
@@ -89,6 +90,7 @@ pub struct Statistics {
     // statistics will be ignored in comparison and hashing
     pub column_stats: HashMap<IndexType, Option<BasicColumnStatistics>>,
     pub histograms: HashMap<IndexType, Option<Histogram>>,
+    pub multi_column_stats: Vec<MultiColumnStat>,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -104,6 +106,7 @@ pub struct Scan {
     // Whether to update stream columns.
     pub update_stream_columns: bool,
     pub inverted_index: Option<InvertedIndexInfo>,
+    pub vector_index: Option<VectorIndexInfo>,
     // Lazy row fetch.
     pub is_lazy_table: bool,
     pub sample: Option<SampleConfig>,
@@ -130,6 +133,14 @@ impl Scan {
             .map(|(col, hist)| (*col, hist.clone()))
             .collect();
 
+        let multi_column_stats = self
+            .statistics
+            .multi_column_stats
+            .iter()
+            .filter(|stat| stat.columns.iter().all(|col| columns.contains(col)))
+            .cloned()
+            .collect();
+
         Scan {
             table_index: self.table_index,
             columns,
@@ -140,12 +151,14 @@ impl Scan {
                 table_stats: self.statistics.table_stats,
                 column_stats,
                 histograms,
+                multi_column_stats,
             }),
             prewhere,
             agg_index: self.agg_index.clone(),
             change_type: self.change_type.clone(),
             update_stream_columns: self.update_stream_columns,
             inverted_index: self.inverted_index.clone(),
+            vector_index: self.vector_index.clone(),
             is_lazy_table: self.is_lazy_table,
             sample: self.sample.clone(),
             scan_id: self.scan_id,
@@ -268,6 +281,15 @@ impl Operator for Scan {
             }
         }
 
+        // No need to cal multi-column statistics for unused columns
+        let mut multi_column_stats = self
+            .statistics
+            .multi_column_stats
+            .iter()
+            .filter(|stat| stat.columns.iter().all(|col| used_columns.contains(col)))
+            .cloned()
+            .collect::<Vec<_>>();
+
         let precise_cardinality = self
             .statistics
             .table_stats
@@ -279,6 +301,7 @@ impl Operator for Scan {
                 let mut statistics = OpStatistics {
                     precise_cardinality: Some(precise_cardinality),
                     column_stats,
+                    multi_column_stats,
                 };
                 // Derive cardinality
                 let mut sb = SelectivityEstimator::new(
@@ -286,14 +309,11 @@ impl Operator for Scan {
                     precise_cardinality as f64,
                     HashSet::new(),
                 );
-                let mut selectivity = MAX_SELECTIVITY;
-                for pred in prewhere.predicates.iter() {
-                    // Compute selectivity for each conjunction
-                    selectivity = selectivity.min(sb.compute_selectivity(pred, true)?);
-                }
+                let selectivity = sb.compute_conjunction_selectivity(&prewhere.predicates)?;
                 // Update other columns's statistic according to selectivity.
                 sb.update_other_statistic_by_selectivity(selectivity);
                 column_stats = statistics.column_stats;
+                multi_column_stats = statistics.multi_column_stats;
                 (precise_cardinality as f64) * selectivity
             }
             (Some(precise_cardinality), None) => precise_cardinality as f64,
@@ -311,6 +331,7 @@ impl Operator for Scan {
             statistics: OpStatistics {
                 precise_cardinality,
                 column_stats,
+                multi_column_stats,
             },
         }))
     }
@@ -71,7 +71,9 @@ use databend_common_expression::types::decimal::MAX_DECIMAL256_PRECISION;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::NumberScalar;
+use databend_common_expression::types::VectorDataType;
 use databend_common_expression::types::F32;
+use databend_common_expression::types::VECTOR_MAX_DIMENSION;
 use databend_common_expression::ColumnIndex;
 use databend_common_expression::ConstantFolder;
 use databend_common_expression::DataField;
@@ -102,6 +104,7 @@ use databend_common_meta_app::schema::DictionaryIdentity;
 use databend_common_meta_app::schema::GetSequenceReq;
 use databend_common_meta_app::schema::ListVirtualColumnsReq;
 use databend_common_meta_app::schema::SequenceIdent;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_common_storage::init_stage_operator;
 use databend_common_users::UserApiProvider;
 use derive_visitor::Drive;
@@ -898,6 +901,9 @@ impl<'a> TypeChecker<'a> {
                         "score" => self.resolve_score_search_function(*span, func_name, &args)?,
                         "match" => self.resolve_match_search_function(*span, func_name, &args)?,
                         "query" => self.resolve_query_search_function(*span, func_name, &args)?,
+                        "highlight" => {
+                            self.resolve_highlight_search_function(*span, func_name, &args)?
+                        }
                         _ => {
                             return Err(ErrorCode::SemanticError(format!(
                                 "cannot find search function {}",
@@ -2311,6 +2317,8 @@ impl<'a> TypeChecker<'a> {
     /// 3. must and negative operator terms, like `title:+fox -cat`
     /// 4. phrase terms, like `title:"quick brown fox"`
     /// 5. multiple field with boost terms, like `title:fox^5 content:dog^2`
+    /// 6. phrase terms with slop, like `title:"quick fox"~1`
+    /// 7. prefix terms, like `title:"qui"*` and `title:"quick bro"*`
     fn resolve_query_search_function(
         &mut self,
         span: Span,
@@ -2392,6 +2400,99 @@ impl<'a> TypeChecker<'a> {
         self.resolve_search_function(span, column_refs, query_text, inverted_index_option)
     }
 
+    /// Resolve highlight search function.
+    /// The first argument is a string column that has inverted index,
+    /// the second argument is the query text without query syntax.
+    /// The text and the query are tokenized by the analyzer of the inverted index,
+    /// returns the snippet with matched terms and the offsets of matched terms.
+    fn resolve_highlight_search_function(
+        &mut self,
+        span: Span,
+        func_name: &str,
+        args: &[&Expr],
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        if args.len() != 2 {
+            return Err(ErrorCode::SemanticError(format!(
+                "invalid arguments for search function, {} expects 2 arguments, but got {}",
+                func_name,
+                args.len()
+            ))
+            .set_span(span));
+        }
+
+        let box (field_scalar, _) = self.resolve(args[0])?;
+        let Ok(column_ref) = BoundColumnRef::try_from(field_scalar.clone()) else {
+            return Err(ErrorCode::SemanticError(
+                "invalid arguments for search function, field must be a column".to_string(),
+            )
+            .set_span(span));
+        };
+        if column_ref.column.data_type.remove_nullable() != DataType::String {
+            return Err(ErrorCode::SemanticError(format!(
+                "invalid arguments for search function, {} only support String column, but the type of column {} is {}",
+                func_name, column_ref.column.column_name, column_ref.column.data_type
+            ))
+            .set_span(span));
+        }
+
+        let box (query_scalar, _) = self.resolve(args[1])?;
+        let Ok(query_expr) = ConstantExpr::try_from(query_scalar.clone()) else {
+            return Err(ErrorCode::SemanticError(format!(
+                "invalid arguments for search function, query text must be a constant string, but got {}",
+                args[1]
+            ))
+            .set_span(query_scalar.span()));
+        };
+        if query_expr.value.as_string().is_none() {
+            return Err(ErrorCode::SemanticError(format!(
+                "invalid arguments for search function, query text must be a constant string, but got {}",
+                args[1]
+            ))
+            .set_span(query_scalar.span()));
+        }
+
+        // use the options of inverted index, so that the text
+        // is tokenized in the same way as the index.
+        let Some(table_index) = column_ref.column.table_index else {
+            return Err(ErrorCode::SemanticError(format!(
+                "column {} doesn't have inverted index",
+                column_ref.column.column_name
+            ))
+            .set_span(span));
+        };
+        let table_entry = self.metadata.read().table(table_index).clone();
+        let table_info = table_entry.table().get_table_info().clone();
+        let column_id = table_info
+            .schema()
+            .column_id_of(&column_ref.column.column_name)?;
+        let Some(index_options) = table_info
+            .meta
+            .indexes
+            .values()
+            .find(|index| {
+                index.index_type == TableIndexType::Inverted
+                    && index.column_ids.contains(&column_id)
+            })
+            .map(|index| index.options.clone())
+        else {
+            return Err(ErrorCode::SemanticError(format!(
+                "column {} doesn't have inverted index",
+                column_ref.column.column_name
+            ))
+            .set_span(span));
+        };
+        let options_expr = ScalarExpr::ConstantExpr(ConstantExpr {
+            span,
+            value: Scalar::String(serde_json::to_string(&index_options)?),
+        });
+
+        self.resolve_scalar_function_call(span, "highlight", vec![], vec![
+            field_scalar,
+            query_scalar,
+            options_expr,
+        ])
+    }
+
     fn resolve_search_option(
         &mut self,
         option_arg: Option<&Expr>,
@@ -2534,6 +2635,9 @@ impl<'a> TypeChecker<'a> {
         let mut index_schema = None;
         let mut index_options = BTreeMap::new();
         for table_index in table_indexes.values() {
+            if table_index.index_type != TableIndexType::Inverted {
+                continue;
+            }
             if column_ids
                 .iter()
                 .all(|id| table_index.column_ids.contains(id))
@@ -4687,6 +4791,11 @@ impl<'a> TypeChecker<'a> {
                 return Ok(Some(virtual_column_name_map));
             }
         }
+        // The virtual columns of the table are discovered on write,
+        // the accessed paths are read as virtual columns before they are created.
+        if table.auto_virtual_columns_enabled() {
+            return Ok(Some(HashMap::new()));
+        }
         Ok(None)
     }
 
@@ -5316,6 +5425,27 @@ pub fn resolve_type_name(type_name: &TypeName, not_null: bool) -> Result<TableDa
         TypeName::Variant => TableDataType::Variant,
         TypeName::Geometry => TableDataType::Geometry,
         TypeName::Geography => TableDataType::Geography,
+        TypeName::Vector {
+            element_type,
+            dimension,
+        } => {
+            if *dimension == 0 || *dimension > VECTOR_MAX_DIMENSION {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Invalid vector dimension {}, it must be between 1 and {}",
+                    dimension, VECTOR_MAX_DIMENSION
+                )));
+            }
+            match element_type.as_ref() {
+                TypeName::Int8 => TableDataType::Vector(VectorDataType::Int8(*dimension)),
+                TypeName::Float32 => TableDataType::Vector(VectorDataType::Float32(*dimension)),
+                _ => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Invalid vector element type '{}', only Int8 and Float32 are supported",
+                        element_type
+                    )));
+                }
+            }
+        }
         TypeName::NotNull(inner_type) => {
             let data_type = resolve_type_name(inner_type, not_null)?;
             data_type.remove_nullable()
@@ -13,3 +13,4 @@
 // limitations under the License.
 
 mod histogram;
+mod multi_column_stats;
@@ -0,0 +1,202 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::Scalar;
+use databend_common_sql::optimizer::ColumnStat;
+use databend_common_sql::optimizer::MultiColumnStat;
+use databend_common_sql::optimizer::SelectivityEstimator;
+use databend_common_sql::optimizer::StatInfo;
+use databend_common_sql::optimizer::Statistics;
+use databend_common_sql::plans::BoundColumnRef;
+use databend_common_sql::plans::ConstantExpr;
+use databend_common_sql::plans::FunctionCall;
+use databend_common_sql::plans::Join;
+use databend_common_sql::plans::JoinEquiCondition;
+use databend_common_sql::plans::JoinType;
+use databend_common_sql::ColumnBindingBuilder;
+use databend_common_sql::IndexType;
+use databend_common_sql::ScalarExpr;
+use databend_common_sql::Visibility;
+use databend_common_storage::Datum;
+
+fn column(index: IndexType) -> ScalarExpr {
+    ScalarExpr::BoundColumnRef(BoundColumnRef {
+        span: None,
+        column: ColumnBindingBuilder::new(
+            format!("c{index}"),
+            index,
+            Box::new(DataType::Number(NumberDataType::Int32)),
+            Visibility::Visible,
+        )
+        .build(),
+    })
+}
+
+fn equal(index: IndexType, value: i32) -> ScalarExpr {
+    ScalarExpr::FunctionCall(FunctionCall {
+        span: None,
+        func_name: "eq".to_string(),
+        params: vec![],
+        arguments: vec![
+            column(index),
+            ScalarExpr::ConstantExpr(ConstantExpr {
+                span: None,
+                value: Scalar::Number(NumberScalar::Int32(value)),
+            }),
+        ],
+    })
+}
+
+// Columns with values in [0, max], without histograms.
+fn statistics(columns: &[(IndexType, f64, i64)]) -> Statistics {
+    let column_stats = columns
+        .iter()
+        .map(|(index, ndv, max)| {
+            (*index, ColumnStat {
+                min: Datum::Int(0),
+                max: Datum::Int(*max),
+                ndv: *ndv,
+                null_count: 0,
+                histogram: None,
+            })
+        })
+        .collect::<HashMap<_, _>>();
+    Statistics {
+        precise_cardinality: None,
+        column_stats,
+        multi_column_stats: vec![],
+    }
+}
+
+fn conjunction_selectivity(statistics: &mut Statistics, predicates: &[ScalarExpr]) -> f64 {
+    let mut estimator = SelectivityEstimator::new(statistics, 1000.0, HashSet::new());
+    estimator
+        .compute_conjunction_selectivity(predicates)
+        .unwrap()
+}
+
+#[test]
+fn test_multi_column_selectivity() {
+    // a and b are correlated, (1, 1) holds half of the rows.
+    let multi_column_stat = MultiColumnStat {
+        columns: vec![0, 1],
+        ndv: 10.0,
+        most_common_values: vec![(vec![Datum::Int(1), Datum::Int(1)], 0.5)],
+    };
+
+    // Without the multi-column statistics, the smallest selectivity of the columns is used.
+    let mut stats = statistics(&[(0, 10.0, 9), (1, 10.0, 9)]);
+    let selectivity = conjunction_selectivity(&mut stats, &[equal(0, 1), equal(1, 1)]);
+    assert_eq!(selectivity, 0.1);
+
+    // The combination is a most common value.
+    let mut stats = statistics(&[(0, 10.0, 9), (1, 10.0, 9)]);
+    stats.multi_column_stats = vec![multi_column_stat.clone()];
+    let selectivity = conjunction_selectivity(&mut stats, &[equal(0, 1), equal(1, 1)]);
+    assert_eq!(selectivity, 0.5);
+
+    // The other combinations share the rest of the rows uniformly.
+    let mut stats = statistics(&[(0, 10.0, 9), (1, 10.0, 9)]);
+    stats.multi_column_stats = vec![multi_column_stat.clone()];
+    let selectivity = conjunction_selectivity(&mut stats, &[equal(0, 1), equal(1, 2)]);
+    assert_eq!(selectivity, 0.5 / 9.0);
+
+    // The predicates on the other columns are still estimated one by one.
+    let mut stats = statistics(&[(0, 10.0, 9), (1, 10.0, 9), (2, 100.0, 99)]);
+    stats.multi_column_stats = vec![multi_column_stat.clone()];
+    let selectivity = conjunction_selectivity(&mut stats, &[equal(0, 1), equal(1, 1), equal(2, 1)]);
+    assert_eq!(selectivity, 0.01);
+
+    // The statistics are unused if not all of their columns are filtered.
+    let mut stats = statistics(&[(0, 10.0, 9), (1, 10.0, 9)]);
+    stats.multi_column_stats = vec![multi_column_stat];
+    let selectivity = conjunction_selectivity(&mut stats, &[equal(0, 1)]);
+    assert_eq!(selectivity, 0.1);
+}
+
+fn join_cardinality(left: Statistics, right: Statistics) -> f64 {
+    let join = Join {
+        equi_conditions: JoinEquiCondition::new_conditions(
+            vec![column(0), column(1)],
+            vec![column(2), column(3)],
+            vec![],
+        ),
+        join_type: JoinType::Inner,
+        ..Default::default()
+    };
+    let left = Arc::new(StatInfo {
+        cardinality: 1000.0,
+        statistics: left,
+    });
+    let right = Arc::new(StatInfo {
+        cardinality: 1000.0,
+        statistics: right,
+    });
+    join.derive_join_stats(left, right).unwrap().cardinality
+}
+
+#[test]
+fn test_multi_column_join_cardinality() {
+    // Without the multi-column statistics, the most selective key is used.
+    let left = statistics(&[(0, 10.0, 9), (1, 10.0, 9)]);
+    let right = statistics(&[(2, 10.0, 9), (3, 10.0, 9)]);
+    assert_eq!(join_cardinality(left, right), 100000.0);
+
+    // The keys have 100 distinct combinations on both sides.
+    let mut left = statistics(&[(0, 10.0, 9), (1, 10.0, 9)]);
+    left.multi_column_stats = vec![MultiColumnStat {
+        columns: vec![0, 1],
+        ndv: 100.0,
+        most_common_values: vec![],
+    }];
+    let mut right = statistics(&[(2, 10.0, 9), (3, 10.0, 9)]);
+    right.multi_column_stats = vec![MultiColumnStat {
+        columns: vec![2, 3],
+        ndv: 100.0,
+        most_common_values: vec![],
+    }];
+    assert_eq!(join_cardinality(left, right), 10000.0);
+
+    // The keys of the right side are assumed to be independent.
+    let mut left = statistics(&[(0, 10.0, 9), (1, 10.0, 9)]);
+    left.multi_column_stats = vec![MultiColumnStat {
+        columns: vec![0, 1],
+        ndv: 100.0,
+        most_common_values: vec![],
+    }];
+    let right = statistics(&[(2, 20.0, 19), (3, 20.0, 19)]);
+    assert_eq!(join_cardinality(left, right), 2500.0);
+
+    // Fully correlated keys don't reduce the cardinality of the most selective key.
+    let mut left = statistics(&[(0, 10.0, 9), (1, 10.0, 9)]);
+    left.multi_column_stats = vec![MultiColumnStat {
+        columns: vec![0, 1],
+        ndv: 10.0,
+        most_common_values: vec![],
+    }];
+    let mut right = statistics(&[(2, 10.0, 9), (3, 10.0, 9)]);
+    right.multi_column_stats = vec![MultiColumnStat {
+        columns: vec![2, 3],
+        ndv: 10.0,
+        most_common_values: vec![],
+    }];
+    assert_eq!(join_cardinality(left, right), 100000.0);
+}
@@ -14,11 +14,15 @@
 
 use std::sync::Arc;
 
+use arrow_schema::Field;
+use arrow_schema::Schema;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
+use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
 use databend_storages_common_table_meta::table::TableCompression;
 use parquet::arrow::ArrowWriter;
+use parquet::arrow::PARQUET_FIELD_ID_META_KEY;
 use parquet::basic::Encoding;
 use parquet::file::properties::EnabledStatistics;
 use parquet::file::properties::WriterProperties;
@@ -32,7 +36,57 @@ pub fn blocks_to_parquet(
     compression: TableCompression,
 ) -> Result<FileMetaData> {
     assert!(!blocks.is_empty());
-    let props = WriterProperties::builder()
+    let props = default_writer_properties(compression);
+    blocks_to_parquet_with_properties(table_schema, blocks, write_buffer, props)
+}
+
+/// Serialize data blocks to parquet format, `field_id` gives the parquet field id
+/// of each top level column.
+pub fn blocks_to_parquet_with_field_ids(
+    table_schema: &TableSchema,
+    blocks: Vec<DataBlock>,
+    write_buffer: &mut Vec<u8>,
+    compression: TableCompression,
+    field_id: impl Fn(&TableField) -> i32,
+) -> Result<FileMetaData> {
+    assert!(!blocks.is_empty());
+    let arrow_schema = Schema::from(table_schema);
+    let fields = arrow_schema
+        .fields()
+        .iter()
+        .zip(table_schema.fields())
+        .map(|(arrow_field, field)| {
+            let mut metadata = arrow_field.metadata().clone();
+            metadata.insert(
+                PARQUET_FIELD_ID_META_KEY.to_string(),
+                field_id(field).to_string(),
+            );
+            Field::clone(arrow_field).with_metadata(metadata)
+        })
+        .collect::<Vec<_>>();
+    let arrow_schema = Arc::new(Schema::new_with_metadata(
+        fields,
+        arrow_schema.metadata().clone(),
+    ));
+    let batches = blocks
+        .into_iter()
+        .map(|block| {
+            Ok(block
+                .to_record_batch(table_schema)?
+                .with_schema(arrow_schema.clone())?)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let props = default_writer_properties(compression);
+    let mut writer = ArrowWriter::try_new(write_buffer, arrow_schema, Some(props))?;
+    for batch in batches {
+        writer.write(&batch)?;
+    }
+    let file_meta = writer.close()?;
+    Ok(file_meta)
+}
+
+fn default_writer_properties(compression: TableCompression) -> WriterProperties {
+    WriterProperties::builder()
         .set_compression(compression.into())
         // use `usize::MAX` to effectively limit the number of row groups to 1
         .set_max_row_group_size(usize::MAX)
@@ -40,7 +94,17 @@ pub fn blocks_to_parquet(
         .set_dictionary_enabled(false)
         .set_statistics_enabled(EnabledStatistics::None)
         .set_bloom_filter_enabled(false)
-        .build();
+        .build()
+}
+
+/// Serialize data blocks to parquet format with the given writer properties.
+pub fn blocks_to_parquet_with_properties(
+    table_schema: &TableSchema,
+    blocks: Vec<DataBlock>,
+    write_buffer: &mut Vec<u8>,
+    props: WriterProperties,
+) -> Result<FileMetaData> {
+    assert!(!blocks.is_empty());
     let batches = blocks
         .into_iter()
         .map(|block| block.to_record_batch(table_schema))
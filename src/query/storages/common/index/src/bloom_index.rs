@@ -14,6 +14,7 @@
 
 use std::collections::BTreeMap;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::ops::Deref;
 use std::sync::Arc;
 
@@ -32,6 +33,9 @@ use databend_common_expression::types::MapType;
 use databend_common_expression::types::NullableType;
 use databend_common_expression::types::Number;
 use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberDomain;
+use databend_common_expression::types::SimpleDomain;
+use databend_common_expression::types::StringType;
 use databend_common_expression::types::UInt64Type;
 use databend_common_expression::types::ValueType;
 use databend_common_expression::BlockEntry;
@@ -40,6 +44,7 @@ use databend_common_expression::ColumnBuilder;
 use databend_common_expression::ConstantFolder;
 use databend_common_expression::DataBlock;
 use databend_common_expression::Domain;
+use databend_common_expression::Evaluator;
 use databend_common_expression::Expr;
 use databend_common_expression::FieldIndex;
 use databend_common_expression::FunctionContext;
@@ -60,9 +65,15 @@ use crate::filters::BlockBloomFilterIndexVersion;
 use crate::filters::BlockFilter;
 use crate::filters::Filter;
 use crate::filters::FilterBuilder;
+use crate::filters::FilterImpl;
+use crate::filters::SetFilter;
 use crate::filters::V2BloomBlock;
 use crate::filters::Xor8Builder;
 use crate::filters::Xor8Filter;
+use crate::ngram_index::add_ngram_digests;
+use crate::ngram_index::pattern_ngram_digests;
+use crate::ngram_index::regexp_case_sensitive;
+use crate::ngram_index::MAX_NGRAM_DIGESTS;
 use crate::Index;
 
 #[derive(Clone)]
@@ -130,6 +141,13 @@ impl TryFrom<FileMetaData> for BloomIndexMeta {
 ///         |  123456789abcd |  ac2345bcd   |
 ///         +----------------+--------------+
 /// ```
+///
+/// String columns specified by the `ngram_index_columns` table option also have a filter of the
+/// n-grams of their values, stored as field 'Ngram(column_id)', to prune substring searches.
+///
+/// Columns specified by the `set_index_columns` table option have a filter of their exact distinct
+/// values if there are no more than `set_index_max_values` of them, stored as field 'Set(column_id)'. Predicates that only
+/// reference such a column, like `IN`, `NOT IN`, `!=` and `IS NULL`, are evaluated on the values.
 pub struct BloomIndex {
     pub func_ctx: FunctionContext,
 
@@ -141,9 +159,10 @@ pub struct BloomIndex {
     pub version: u64,
 
     ///  filters.
-    pub filters: Vec<Arc<Xor8Filter>>,
+    pub filters: Vec<Arc<FilterImpl>>,
 
-    /// Approximate distinct count of columns generated by xor hash function.
+    /// Approximate distinct count of columns generated by xor hash function,
+    /// or exact distinct count of columns that have a set filter.
     pub column_distinct_count: HashMap<FieldIndex, usize>,
 }
 
@@ -165,7 +184,7 @@ impl BloomIndex {
     pub fn from_filter_block(
         func_ctx: FunctionContext,
         filter_schema: TableSchemaRef,
-        filters: Vec<Arc<Xor8Filter>>,
+        filters: Vec<Arc<FilterImpl>>,
         version: u64,
     ) -> Result<Self> {
         Ok(Self {
@@ -185,6 +204,9 @@ impl BloomIndex {
         version: u64,
         block: &DataBlock,
         bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+        ngram_columns_map: BTreeMap<FieldIndex, TableField>,
+        set_columns_map: BTreeMap<FieldIndex, TableField>,
+        set_max_values: usize,
     ) -> Result<Option<Self>> {
         // TODO refactor :
         // if only current version is allowed, just use the current version
@@ -300,7 +322,45 @@ impl BloomIndex {
 
             let filter_name = Self::build_filter_column_name(version, &field)?;
             filter_fields.push(TableField::new(&filter_name, TableDataType::Binary));
-            filters.push(Arc::new(filter));
+            filters.push(Arc::new(FilterImpl::Xor(filter)));
+        }
+
+        for (index, field) in ngram_columns_map.into_iter() {
+            let column = match &block.get_by_offset(index).value {
+                Value::Scalar(_) => continue,
+                Value::Column(c) => c.clone(),
+            };
+            let Some(digests) = Self::calculate_ngram_digests(&column) else {
+                continue;
+            };
+
+            let mut filter_builder = Xor8Builder::create();
+            filter_builder.add_digests(digests.iter());
+            let filter = filter_builder.build()?;
+
+            let filter_name = Self::build_ngram_filter_column_name(&field);
+            filter_fields.push(TableField::new(&filter_name, TableDataType::Binary));
+            filters.push(Arc::new(FilterImpl::Xor(filter)));
+        }
+
+        for (index, field) in set_columns_map.into_iter() {
+            let entry = block.get_by_offset(index);
+            let column = match &entry.value {
+                Value::Scalar(_) => continue,
+                Value::Column(c) => c,
+            };
+            if !SetFilter::supported_type(&entry.data_type) {
+                continue;
+            }
+            let Some(filter) = SetFilter::build(column, set_max_values) else {
+                continue;
+            };
+
+            column_distinct_count.insert(index, filter.distinct_count());
+
+            let filter_name = Self::build_set_filter_column_name(&field);
+            filter_fields.push(TableField::new(&filter_name, TableDataType::Binary));
+            filters.push(Arc::new(FilterImpl::Set(filter)));
         }
 
         if filter_fields.is_empty() {
@@ -345,6 +405,57 @@ impl BloomIndex {
         let mut new_col_id = 1;
         let mut domains = ConstantFolder::full_input_domains(&expr);
 
+        // Rewrite the expression to a new column with the given domain,
+        // `has_null` is generated based on the `null_count` in column statistics,
+        // unless the domain is evaluated from the set filter and is already nullable.
+        let mut rewrite = |span: Span, col_name: &str, domain: Domain, return_type: &DataType| {
+            let new_col_name = format!("__bloom_column_{}_{}", col_name, new_col_id);
+            new_col_id += 1;
+
+            let new_domain = if return_type.is_nullable() && !matches!(domain, Domain::Nullable(_))
+            {
+                let has_null = match data_schema.column_id_of(col_name) {
+                    Ok(col_id) => match column_stats.get(&col_id) {
+                        Some(stat) => stat.null_count > 0,
+                        None => true,
+                    },
+                    Err(_) => true,
+                };
+                Domain::Nullable(NullableDomain {
+                    has_null,
+                    value: Some(Box::new(domain)),
+                })
+            } else {
+                domain
+            };
+            domains.insert(new_col_name.clone(), new_domain);
+
+            Expr::ColumnRef {
+                span,
+                id: new_col_name.clone(),
+                data_type: return_type.clone(),
+                display_name: new_col_name,
+            }
+        };
+
+        // The set filter gives the exact domain of the predicates on the column,
+        // so they are rewritten before the predicates are split by other filters.
+        visit_expr_single_column_predicate(&mut expr, &mut |expr, col_name| {
+            let Ok(field) = data_schema.field_with_name(col_name) else {
+                return Ok(None);
+            };
+            let filter_column = &Self::build_set_filter_column_name(field);
+            match self.eval_set_filter(filter_column, expr) {
+                Some(domain) => Ok(Some(rewrite(
+                    expr.span(),
+                    col_name,
+                    domain,
+                    expr.data_type(),
+                ))),
+                None => Ok(None),
+            }
+        })?;
+
         visit_expr_column_eq_constant(
             &mut expr,
             &mut |span, col_name, scalar, ty, return_type| {
@@ -357,37 +468,36 @@ impl BloomIndex {
                 // we rewrite the expression to a new column with `false` domain.
                 if self.find(filter_column, scalar, ty, scalar_map)? == FilterEvalResult::MustFalse
                 {
-                    let new_col_name = format!("__bloom_column_{}_{}", col_name, new_col_id);
-                    new_col_id += 1;
-
                     let bool_domain = Domain::Boolean(BooleanDomain {
                         has_false: true,
                         has_true: false,
                     });
-                    let new_domain = if return_type.is_nullable() {
-                        // generate `has_null` based on the `null_count` in column statistics.
-                        let has_null = match data_schema.column_id_of(col_name) {
-                            Ok(col_id) => match column_stats.get(&col_id) {
-                                Some(stat) => stat.null_count > 0,
-                                None => true,
-                            },
-                            Err(_) => true,
-                        };
-                        Domain::Nullable(NullableDomain {
-                            has_null,
-                            value: Some(Box::new(bool_domain)),
-                        })
-                    } else {
-                        bool_domain
+                    Ok(Some(rewrite(span, col_name, bool_domain, return_type)))
+                } else {
+                    Ok(None)
+                }
+            },
+        )?;
+
+        visit_expr_column_ngram_pattern(
+            &mut expr,
+            &mut |span, col_name, func_name, pattern, return_type| {
+                let filter_column =
+                    &Self::build_ngram_filter_column_name(data_schema.field_with_name(col_name)?);
+
+                // If the column doesn't contain some grams of the pattern, `like` and `regexp`
+                // must be false and `position` must be 0, rewrite the expression to a new column.
+                if self.find_ngrams(filter_column, func_name, pattern)
+                    == FilterEvalResult::MustFalse
+                {
+                    let domain = match return_type.remove_nullable() {
+                        DataType::Boolean => Domain::Boolean(BooleanDomain {
+                            has_false: true,
+                            has_true: false,
+                        }),
+                        _ => Domain::Number(NumberDomain::UInt64(SimpleDomain { min: 0, max: 0 })),
                     };
-                    domains.insert(new_col_name.clone(), new_domain);
-
-                    Ok(Some(Expr::ColumnRef {
-                        span,
-                        id: new_col_name.clone(),
-                        data_type: return_type.clone(),
-                        display_name: new_col_name,
-                    }))
+                    Ok(Some(rewrite(span, col_name, domain, return_type)))
                 } else {
                     Ok(None)
                 }
@@ -491,6 +601,39 @@ impl BloomIndex {
         Ok(cols)
     }
 
+    /// Find all string columns searched by `like`, `regexp` or `position` with a constant pattern,
+    /// whose grams can be checked by the n-gram filter.
+    pub fn find_ngram_columns(
+        expr: &Expr<String>,
+        fields: Vec<TableField>,
+    ) -> Result<Vec<TableField>> {
+        let mut cols: Vec<TableField> = Vec::new();
+        visit_expr_column_ngram_pattern(
+            &mut expr.clone(),
+            &mut |_, col_name, func_name, pattern, _| {
+                if let Some(v) = fields.iter().find(|f| f.name() == col_name) {
+                    if !cols.contains(v) && !pattern_ngram_digests(func_name, pattern).is_empty() {
+                        cols.push(v.clone());
+                    }
+                }
+                Ok(None)
+            },
+        )?;
+        Ok(cols)
+    }
+
+    /// Find all columns in the expression that may have a set filter.
+    pub fn find_set_columns(
+        expr: &Expr<String>,
+        fields: Vec<TableField>,
+    ) -> Result<Vec<TableField>> {
+        let column_refs = expr.column_refs();
+        Ok(fields
+            .into_iter()
+            .filter(|f| column_refs.contains_key(f.name()))
+            .collect())
+    }
+
     /// For every applicable column, we will create a filter.
     /// The filter will be stored with field name 'Bloom(column_name)'
     pub fn build_filter_column_name(version: u64, field: &TableField) -> Result<String> {
@@ -506,6 +649,38 @@ impl BloomIndex {
         }
     }
 
+    /// The n-gram filter will be stored with field name 'Ngram(column_id)'
+    pub fn build_ngram_filter_column_name(field: &TableField) -> String {
+        format!("Ngram({})", field.column_id())
+    }
+
+    /// The set filter will be stored with field name 'Set(column_id)'
+    pub fn build_set_filter_column_name(field: &TableField) -> String {
+        format!("Set({})", field.column_id())
+    }
+
+    pub fn is_set_filter_column_name(name: &str) -> bool {
+        name.starts_with("Set(")
+    }
+
+    fn find_ngrams(&self, filter_column: &str, func_name: &str, pattern: &str) -> FilterEvalResult {
+        let Some(filter) = self
+            .filter_schema
+            .index_of(filter_column)
+            .ok()
+            .and_then(|idx| self.filters[idx].as_xor())
+        else {
+            // The column doesn't have a n-gram filter.
+            return FilterEvalResult::Uncertain;
+        };
+        let digests = pattern_ngram_digests(func_name, pattern);
+        if digests.iter().all(|digest| filter.contains_digest(*digest)) {
+            FilterEvalResult::Uncertain
+        } else {
+            FilterEvalResult::MustFalse
+        }
+    }
+
     fn find(
         &self,
         filter_column: &str,
@@ -522,7 +697,9 @@ impl BloomIndex {
         }
 
         let idx = self.filter_schema.index_of(filter_column)?;
-        let filter = &self.filters[idx];
+        let Some(filter) = self.filters[idx].as_xor() else {
+            return Ok(FilterEvalResult::Uncertain);
+        };
 
         let contains = if self.version == V2BloomBlock::VERSION {
             let data_value = scalar_to_datavalue(target);
@@ -540,11 +717,54 @@ impl BloomIndex {
         }
     }
 
+    /// Evaluate the predicate on the distinct values of the column,
+    /// returns None if the column doesn't have a set filter or the evaluation fails.
+    fn eval_set_filter(&self, filter_column: &str, expr: &Expr<String>) -> Option<Domain> {
+        let idx = self.filter_schema.index_of(filter_column).ok()?;
+        let values = self.filters[idx].as_set()?.values();
+        let (_, data_type) = expr.column_refs().into_iter().next()?;
+        if values.len() == 0 || values.data_type() != data_type {
+            return None;
+        }
+
+        let block = DataBlock::new(
+            vec![BlockEntry::new(data_type, Value::Column(values.clone()))],
+            values.len(),
+        );
+        let expr = expr.project_column_ref(|_| 0);
+        let evaluator = Evaluator::new(&block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let value = evaluator.run(&expr).ok()?;
+        Some(value.domain(expr.data_type()))
+    }
+
     pub fn supported_type(data_type: &TableDataType) -> bool {
         let data_type = DataType::from(data_type);
         Xor8Filter::supported_type(&data_type)
     }
 
+    pub fn supported_set_type(data_type: &TableDataType) -> bool {
+        let data_type = DataType::from(data_type);
+        SetFilter::supported_type(&data_type)
+    }
+
+    pub fn supported_ngram_type(data_type: &TableDataType) -> bool {
+        data_type.remove_nullable() == TableDataType::String
+    }
+
+    /// Calculate the digests of the distinct grams of a string column,
+    /// returns None if there are no grams or too many grams to build a useful filter.
+    fn calculate_ngram_digests(column: &Column) -> Option<HashSet<u64>> {
+        let column = StringType::try_downcast_column(&column.remove_nullable())?;
+        let mut digests = HashSet::new();
+        for value in column.iter() {
+            add_ngram_digests(value, &mut digests);
+            if digests.len() > MAX_NGRAM_DIGESTS {
+                return None;
+            }
+        }
+        (!digests.is_empty()).then_some(digests)
+    }
+
     /// Checks if the average length of a string column exceeds 256 bytes.
     /// If it does, the bloom index for the column will not be established.
     fn check_large_string(column: &Column) -> bool {
@@ -686,6 +906,102 @@ fn visit_expr_column_eq_constant(
     Ok(())
 }
 
+fn visit_expr_column_ngram_pattern(
+    expr: &mut Expr<String>,
+    visitor: &mut impl FnMut(Span, &str, &str, &str, &DataType) -> Result<Option<Expr<String>>>,
+) -> Result<()> {
+    // Find patterns like `Column LIKE <constant>`, `regexp_like(Column, <constant>, 'c')`,
+    // `position(<constant>, Column)`, `locate(<constant>, Column)` or `instr(Column, <constant>)`.
+    // `regexp` and `regexp_like` match case-insensitively by default, they can't be checked by
+    // the case-sensitive grams unless the match type is case-sensitive.
+    if let Expr::FunctionCall {
+        span,
+        id,
+        args,
+        return_type,
+        ..
+    } = expr
+    {
+        let func_name = id.name().to_string();
+        let column_and_pattern = match (func_name.as_str(), args.as_slice()) {
+            ("like" | "instr", [column, pattern]) | ("position" | "locate", [pattern, column]) => {
+                Some((column, pattern))
+            }
+            (
+                "regexp_like",
+                [column, pattern, Expr::Constant {
+                    scalar: Scalar::String(match_type),
+                    ..
+                }],
+            ) if regexp_case_sensitive(match_type) => Some((column, pattern)),
+            _ => None,
+        };
+        if let Some((
+            Expr::ColumnRef { id, data_type, .. },
+            Expr::Constant {
+                scalar: Scalar::String(pattern),
+                ..
+            },
+        )) = column_and_pattern
+        {
+            if data_type.remove_nullable() == DataType::String {
+                if let Some(new_expr) = visitor(*span, id, &func_name, pattern, return_type)? {
+                    *expr = new_expr;
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    // Otherwise, rewrite sub expressions.
+    match expr {
+        Expr::Cast { expr, .. } => {
+            visit_expr_column_ngram_pattern(expr, visitor)?;
+        }
+        Expr::FunctionCall { args, .. } => {
+            for arg in args.iter_mut() {
+                visit_expr_column_ngram_pattern(arg, visitor)?;
+            }
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
+
+fn visit_expr_single_column_predicate(
+    expr: &mut Expr<String>,
+    visitor: &mut impl FnMut(&Expr<String>, &str) -> Result<Option<Expr<String>>>,
+) -> Result<()> {
+    // Find the largest deterministic predicates that only reference one column, like
+    // `Column IN (<constant>, ..)`, `Column != <constant>` or `Column IS NULL`
+    if expr.data_type().remove_nullable() == DataType::Boolean {
+        let column_refs = expr.column_refs();
+        if column_refs.len() == 1 && expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+            let col_name = column_refs.into_keys().next().unwrap();
+            if let Some(new_expr) = visitor(expr, &col_name)? {
+                *expr = new_expr;
+                return Ok(());
+            }
+        }
+    }
+
+    // Otherwise, rewrite sub expressions.
+    match expr {
+        Expr::Cast { expr, .. } => {
+            visit_expr_single_column_predicate(expr, visitor)?;
+        }
+        Expr::FunctionCall { args, .. } => {
+            for arg in args.iter_mut() {
+                visit_expr_single_column_predicate(arg, visitor)?;
+            }
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
+
 fn visit_map_column(
     span: Span,
     args: &[Expr<String>],
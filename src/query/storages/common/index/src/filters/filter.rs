@@ -14,6 +14,11 @@
 
 use std::hash::Hash;
 
+use databend_common_exception::Result;
+
+use crate::filters::SetFilter;
+use crate::filters::Xor8Filter;
+
 // `len()` returns an Option thus `is_empty()` can not be provided.
 #[allow(clippy::len_without_is_empty)]
 pub trait Filter: Sized {
@@ -59,3 +64,50 @@ pub trait FilterBuilder {
     /// Build the filter with added keys.
     fn build(&mut self) -> Result<Self::Filter, Self::Error>;
 }
+
+/// The filter of a column in the bloom index block.
+pub enum FilterImpl {
+    Xor(Xor8Filter),
+    Set(SetFilter),
+}
+
+impl FilterImpl {
+    pub fn as_xor(&self) -> Option<&Xor8Filter> {
+        match self {
+            FilterImpl::Xor(filter) => Some(filter),
+            FilterImpl::Set(_) => None,
+        }
+    }
+
+    pub fn as_set(&self) -> Option<&SetFilter> {
+        match self {
+            FilterImpl::Set(filter) => Some(filter),
+            FilterImpl::Xor(_) => None,
+        }
+    }
+
+    /// Check if the pre-computed digest is in the filter,
+    /// a set filter doesn't keep digests and always returns true.
+    pub fn contains_digest(&self, digest: u64) -> bool {
+        match self {
+            FilterImpl::Xor(filter) => filter.contains_digest(digest),
+            FilterImpl::Set(_) => true,
+        }
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        match self {
+            FilterImpl::Xor(filter) => Ok(filter.to_bytes()?),
+            FilterImpl::Set(filter) => Ok(filter.to_bytes()),
+        }
+    }
+
+    pub fn memory_size(&self) -> usize {
+        match self {
+            FilterImpl::Xor(filter) => {
+                std::mem::size_of::<Xor8Filter>() + filter.filter.finger_prints.len()
+            }
+            FilterImpl::Set(filter) => std::mem::size_of::<SetFilter>() + filter.memory_size(),
+        }
+    }
+}
@@ -15,10 +15,14 @@
 //! Probabilistic filters
 
 mod filter;
+mod set_filter;
 mod xor8;
 
 pub use filter::Filter;
 pub use filter::FilterBuilder;
+pub use filter::FilterImpl;
+pub use set_filter::SetFilter;
+pub use set_filter::DEFAULT_SET_FILTER_MAX_VALUES;
 pub use xor8::BlockBloomFilterIndexVersion;
 pub use xor8::BlockFilter;
 pub use xor8::V2BloomBlock;
@@ -0,0 +1,98 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use databend_common_exception::Result;
+use databend_common_expression::arrow::deserialize_column;
+use databend_common_expression::arrow::serialize_column;
+use databend_common_expression::types::DataType;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
+
+use crate::Index;
+
+/// The default max number of distinct values that a set filter keeps,
+/// it can be changed by the `set_index_max_values` table option.
+pub const DEFAULT_SET_FILTER_MAX_VALUES: usize = 64;
+
+/// A filter that keeps the exact distinct values of a column in a block,
+/// NULL is also kept if the column has null values.
+///
+/// Unlike the xor filter, it can tell if a predicate is always true for the block,
+/// so `!=`, `NOT IN` and `IS NULL` can also be pruned.
+pub struct SetFilter {
+    values: Column,
+}
+
+impl SetFilter {
+    /// Build the filter from a column, returns None if the column
+    /// has more than `max_values` distinct values.
+    pub fn build(column: &Column, max_values: usize) -> Option<Self> {
+        let mut distinct = HashSet::new();
+        let mut builder = ColumnBuilder::with_capacity(&column.data_type(), 0);
+        for value in column.iter() {
+            if distinct.insert(value.clone()) {
+                if distinct.len() > max_values {
+                    return None;
+                }
+                builder.push(value);
+            }
+        }
+        Some(Self {
+            values: builder.build(),
+        })
+    }
+
+    /// The distinct values, with the data type of the source column.
+    pub fn values(&self) -> &Column {
+        &self.values
+    }
+
+    /// The number of distinct non-null values.
+    pub fn distinct_count(&self) -> usize {
+        match &self.values {
+            Column::Nullable(c) => c.len() - c.validity.null_count(),
+            Column::Null { .. } => 0,
+            c => c.len(),
+        }
+    }
+
+    pub fn memory_size(&self) -> usize {
+        self.values.memory_size()
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        serialize_column(&self.values)
+    }
+
+    pub fn from_bytes(buf: &[u8]) -> Result<Self> {
+        let values = deserialize_column(buf)?;
+        Ok(Self { values })
+    }
+}
+
+impl Index for SetFilter {
+    fn supported_type(data_type: &DataType) -> bool {
+        matches!(
+            data_type.remove_nullable(),
+            DataType::Boolean
+                | DataType::Number(_)
+                | DataType::Decimal(_)
+                | DataType::String
+                | DataType::Timestamp
+                | DataType::Date
+        )
+    }
+}
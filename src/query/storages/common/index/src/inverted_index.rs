@@ -489,7 +489,7 @@ pub struct DocIdsCollector {
     term_reader: TermReader,
     // key is phrase query, value is `doc_id` and phrase count
     query_phrase_counts_map: HashMap<String, HashMap<u64, u64>>,
-    // key is fuzzy query, value is `doc_ids`
+    // key is fuzzy query or single term prefix query, value is `doc_ids`
     query_fuzzy_map: HashMap<String, RoaringTreemap>,
 }
 
@@ -612,10 +612,11 @@ impl DocIdsCollector {
                 return Ok(false);
             }
 
-            // using regex to check prefix term, get related term ids.
+            // using regex to check prefix term, get related term ids,
+            // the term must be escaped to avoid being parsed as regex syntax.
             let (_, prefix_term) = phrase_prefix_query.prefix_term_with_offset();
             let term_str = String::from_utf8_lossy(prefix_term.serialized_value_bytes());
-            let key = format!("{}.*", term_str);
+            let key = format!("{}.*", regex::escape(&term_str));
             let re = Regex::new(&key).map_err(|_| {
                 ErrorCode::TantivyError(format!("inverted index create regex `{}` failed", key))
             })?;
@@ -705,12 +706,37 @@ impl DocIdsCollector {
     //
     // If the query is a prefix phrase query, also check if any prefix terms
     // match the positions.
+    //
+    // If the phrase has a slop, the terms must keep the order of the query,
+    // but each term can be at most `slop` positions away from the position
+    // expected after the previous term.
     pub fn collect_phrase_matched_doc_ids(
         &mut self,
         query_key: String,
         phrase_terms: Vec<(usize, Term)>,
         prefix_term: Option<(usize, &Vec<u64>)>,
+        slop: u32,
     ) -> Result<Option<RoaringTreemap>> {
+        if phrase_terms.is_empty() {
+            // A prefix query with only one term, like `"qui"*`,
+            // matches the docs containing any of the prefix terms.
+            let Some((_, prefix_term_ids)) = prefix_term else {
+                return Ok(None);
+            };
+            let mut all_doc_ids = RoaringTreemap::new();
+            for prefix_term_id in prefix_term_ids {
+                let doc_ids = self.term_reader.get_doc_ids(*prefix_term_id)?;
+                all_doc_ids.bitor_assign(doc_ids);
+            }
+            if all_doc_ids.is_empty() {
+                return Ok(None);
+            }
+            if self.term_reader.has_score {
+                self.query_fuzzy_map.insert(query_key, all_doc_ids.clone());
+            }
+            return Ok(Some(all_doc_ids));
+        }
+
         let mut query_term_poses = Vec::with_capacity(phrase_terms.len());
         for (term_pos, term) in &phrase_terms {
             // term not exist means this phrase in not matched.
@@ -816,18 +842,39 @@ impl DocIdsCollector {
                 }
                 let term_poses = term_poses_map.get(term_id).unwrap();
 
-                // Using the position of the first term and the offset of this term with the first term,
-                // calculate all possible positions for this term.
-                offset_poses.clear();
-                offset_poses
-                    .append(first_term_poses.iter().map(|pos| pos + term_pos_offset))
-                    .unwrap();
-
-                // Term possible positions subtract term actual positions,
-                // remaining positions are not matched and need to be removed in the first term.
-                offset_poses.sub_assign(term_poses);
-                for offset_pos in &offset_poses {
-                    first_term_poses.remove(offset_pos - term_pos_offset);
+                if slop == 0 {
+                    // Using the position of the first term and the offset of this term with the first term,
+                    // calculate all possible positions for this term.
+                    offset_poses.clear();
+                    offset_poses
+                        .append(first_term_poses.iter().map(|pos| pos + term_pos_offset))
+                        .unwrap();
+
+                    // Term possible positions subtract term actual positions,
+                    // remaining positions are not matched and need to be removed in the first term.
+                    offset_poses.sub_assign(term_poses);
+                    for offset_pos in &offset_poses {
+                        first_term_poses.remove(offset_pos - term_pos_offset);
+                    }
+                } else {
+                    // Shift the positions of this term by its offset with the first term,
+                    // a position is matched if the previous term has a position that is
+                    // not after it and at most `slop` before it.
+                    // The matched positions are used to check the next term.
+                    let mut matched_poses = RoaringTreemap::new();
+                    for pos in term_poses.iter().filter(|pos| pos >= term_pos_offset) {
+                        let shifted_pos = pos - term_pos_offset;
+                        let lower_pos = shifted_pos.saturating_sub(slop as u64);
+                        let lower_rank = if lower_pos > 0 {
+                            first_term_poses.rank(lower_pos - 1)
+                        } else {
+                            0
+                        };
+                        if first_term_poses.rank(shifted_pos) > lower_rank {
+                            matched_poses.insert(shifted_pos);
+                        }
+                    }
+                    first_term_poses = matched_poses;
                 }
                 if first_term_poses.is_empty() {
                     break;
@@ -1013,7 +1060,8 @@ impl DocIdsCollector {
         } else if let Some(phrase_query) = query.downcast_ref::<PhraseQuery>() {
             let query_key = format!("{:?}", phrase_query);
             let phrase_terms = phrase_query.phrase_terms_with_offsets();
-            self.collect_phrase_matched_doc_ids(query_key, phrase_terms, None)
+            let slop = phrase_query.slop();
+            self.collect_phrase_matched_doc_ids(query_key, phrase_terms, None, slop)
         } else if let Some(phrase_prefix_query) = query.downcast_ref::<PhrasePrefixQuery>() {
             let query_key = format!("{:?}", phrase_prefix_query);
             let phrase_terms = phrase_prefix_query.phrase_terms_with_offsets();
@@ -1024,7 +1072,7 @@ impl DocIdsCollector {
             };
             let prefix_term = Some((prefix_term_pos, prefix_term_ids));
 
-            self.collect_phrase_matched_doc_ids(query_key, phrase_terms, prefix_term)
+            self.collect_phrase_matched_doc_ids(query_key, phrase_terms, prefix_term, 0)
         } else if let Some(fuzzy_term_query) = query.downcast_ref::<FuzzyTermQuery>() {
             let mut all_doc_ids = RoaringTreemap::new();
             let term = fuzzy_term_query.term();
@@ -1152,7 +1200,22 @@ impl DocIdsCollector {
         } else if let Some(phrase_prefix_query) = query.downcast_ref::<PhrasePrefixQuery>() {
             let query_key = format!("{:?}", phrase_prefix_query);
             let phrase_terms = phrase_prefix_query.phrase_terms();
-            self.calculate_phrase_scores(query_key, phrase_terms, doc_ids, boost)
+            if !phrase_terms.is_empty() {
+                return self.calculate_phrase_scores(query_key, phrase_terms, doc_ids, boost);
+            }
+            // single term prefix query is scored like fuzzy query.
+            let prefix_doc_ids = self.query_fuzzy_map.remove(&query_key).unwrap_or_default();
+            let scores = doc_ids
+                .iter()
+                .map(|doc_id| {
+                    if prefix_doc_ids.contains(doc_id) {
+                        F32::from(1_f32)
+                    } else {
+                        F32::from(0_f32)
+                    }
+                })
+                .collect();
+            Ok(scores)
         } else if let Some(fuzzy_term_query) = query.downcast_ref::<FuzzyTermQuery>() {
             let query_key = format!("{:?}", fuzzy_term_query);
             let fuzzy_doc_ids = self.query_fuzzy_map.remove(&query_key).unwrap_or_default();
@@ -19,8 +19,11 @@ mod bloom_index;
 pub mod filters;
 mod index;
 mod inverted_index;
+mod ngram_index;
 mod page_index;
 mod range_index;
+mod spatial_index;
+mod vector_index;
 
 pub use bloom_index::BloomIndex;
 pub use bloom_index::BloomIndexMeta;
@@ -33,6 +36,18 @@ pub use inverted_index::InvertedIndexDirectory;
 pub use inverted_index::InvertedIndexFile;
 pub use inverted_index::InvertedIndexMeta;
 pub use inverted_index::TermReader;
+pub use ngram_index::add_ngram_digests;
+pub use ngram_index::like_pattern_fragments;
+pub use ngram_index::ngram_digest;
+pub use ngram_index::pattern_ngram_digests;
+pub use ngram_index::regexp_case_sensitive;
+pub use ngram_index::regexp_pattern_fragments;
+pub use ngram_index::MAX_NGRAM_DIGESTS;
+pub use ngram_index::NGRAM_SIZE;
 pub use page_index::PageIndex;
 pub use range_index::statistics_to_domain;
+pub use range_index::widened_column_statistics;
 pub use range_index::RangeIndex;
+pub use spatial_index::collect_geo_bbox_filters;
+pub use vector_index::VectorDistanceMetric;
+pub use vector_index::VectorIndex;
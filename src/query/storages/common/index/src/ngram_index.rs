@@ -0,0 +1,172 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::hash::Hasher;
+
+use siphasher::sip::SipHasher24;
+
+/// Number of characters of the grams kept in the n-gram filters.
+pub const NGRAM_SIZE: usize = 3;
+
+/// N-gram filters are not built for blocks with more distinct grams,
+/// as they would be large and seldom able to prune the block.
+pub const MAX_NGRAM_DIGESTS: usize = 1 << 20;
+
+/// Digest of a gram, it is persisted in the filters and must be stable across versions.
+pub fn ngram_digest(gram: &str) -> u64 {
+    let mut hasher = SipHasher24::new();
+    hasher.write(gram.as_bytes());
+    hasher.finish()
+}
+
+/// Add the digests of all the grams of `text` into `digests`,
+/// texts shorter than [`NGRAM_SIZE`] have no grams.
+pub fn add_ngram_digests(text: &str, digests: &mut HashSet<u64>) {
+    let offsets = text
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(text.len()))
+        .collect::<Vec<_>>();
+    for window in offsets.windows(NGRAM_SIZE + 1) {
+        digests.insert(ngram_digest(&text[window[0]..window[NGRAM_SIZE]]));
+    }
+}
+
+/// Digests of the grams that every value matching the pattern must contain.
+///
+/// Patterns of `like` and `regexp` are split into the literal fragments between wildcards,
+/// `position` searches the whole pattern as a literal. The grams are case-sensitive,
+/// so `regexp` patterns must be matched case-sensitively, see [`regexp_case_sensitive`].
+pub fn pattern_ngram_digests(func_name: &str, pattern: &str) -> Vec<u64> {
+    let fragments = match func_name {
+        "like" => like_pattern_fragments(pattern),
+        "regexp_like" => regexp_pattern_fragments(pattern),
+        _ => vec![pattern.to_string()],
+    };
+    let mut digests = HashSet::new();
+    for fragment in fragments {
+        add_ngram_digests(&fragment, &mut digests);
+    }
+    digests.into_iter().collect()
+}
+
+/// Split a `LIKE` pattern into the literal fragments between `%` and `_`.
+pub fn like_pattern_fragments(pattern: &str) -> Vec<String> {
+    let mut fragments = vec![];
+    let mut current = String::new();
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(c) => current.push(c),
+                None => current.push('\\'),
+            },
+            '%' | '_' => fragments.push(std::mem::take(&mut current)),
+            c => current.push(c),
+        }
+    }
+    fragments.push(current);
+    fragments.retain(|f| !f.is_empty());
+    fragments
+}
+
+/// Whether the match type of `regexp_like` matches case-sensitively,
+/// the last of `c` and `i` wins and matching is case-insensitive by default.
+pub fn regexp_case_sensitive(match_type: &str) -> bool {
+    match_type
+        .chars()
+        .all(|c| matches!(c, 'c' | 'i' | 'm' | 'n'))
+        && match_type.chars().rev().find(|c| matches!(c, 'c' | 'i')) == Some('c')
+}
+
+/// Extract the literal fragments that a regular expression requires.
+///
+/// Only simple expressions are supported, no fragment is returned if the expression
+/// has alternations or groups, since the literals may be optional.
+pub fn regexp_pattern_fragments(pattern: &str) -> Vec<String> {
+    if pattern.contains(['|', '(']) {
+        return vec![];
+    }
+
+    let mut fragments = vec![];
+    let mut current = String::new();
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                // Escaped punctuations are literals, others are classes or assertions like `\d` and `\b`.
+                Some(c) if c.is_ascii_punctuation() => current.push(c),
+                Some(c) => {
+                    fragments.push(std::mem::take(&mut current));
+                    // Skip the code point of `\x7F`, `\u{7F}` or `\U0000007F`,
+                    // and the class of `\pL` or `\P{Greek}`.
+                    let len = match c {
+                        'x' => 2,
+                        'u' => 4,
+                        'U' => 8,
+                        'p' | 'P' => 1,
+                        _ => 0,
+                    };
+                    if len > 0 {
+                        if chars.next_if_eq(&'{').is_some() {
+                            if !chars.by_ref().any(|c| c == '}') {
+                                return vec![];
+                            }
+                        } else if chars.by_ref().take(len).count() < len {
+                            return vec![];
+                        }
+                    }
+                }
+                None => return vec![],
+            },
+            '[' => {
+                fragments.push(std::mem::take(&mut current));
+                // The first `]` of a class is a literal.
+                let mut closed = false;
+                let mut first = true;
+                while let Some(c) = chars.next() {
+                    match c {
+                        '\\' => {
+                            chars.next();
+                        }
+                        ']' if !first => {
+                            closed = true;
+                            break;
+                        }
+                        _ => {}
+                    }
+                    first = c == '^' && first;
+                }
+                if !closed {
+                    return vec![];
+                }
+            }
+            // The preceding character is optional.
+            '*' | '?' | '{' => {
+                current.pop();
+                fragments.push(std::mem::take(&mut current));
+                if c == '{' && !chars.by_ref().any(|c| c == '}') {
+                    return vec![];
+                }
+            }
+            // The preceding character may be repeated.
+            '+' | '.' | '^' | '$' => fragments.push(std::mem::take(&mut current)),
+            c => current.push(c),
+        }
+    }
+    fragments.push(current);
+    fragments.retain(|f| !f.is_empty());
+    fragments
+}
@@ -12,9 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::borrow::Cow;
 use std::collections::HashMap;
 
 use databend_common_exception::Result;
+use databend_common_expression::cast_scalar;
 use databend_common_expression::is_internal_column;
 use databend_common_expression::is_stream_column;
 use databend_common_expression::types::decimal::Decimal128Type;
@@ -39,9 +41,11 @@ use databend_common_expression::FunctionContext;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableSchemaRef;
 use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_io::bounding_box_intersects;
 use databend_storages_common_table_meta::meta::ColumnStatistics;
 use databend_storages_common_table_meta::meta::StatisticsOfColumns;
 
+use crate::collect_geo_bbox_filters;
 use crate::Index;
 
 #[derive(Clone)]
@@ -52,6 +56,9 @@ pub struct RangeIndex {
 
     // Default stats for each column if no stats are available (e.g. for new-add columns)
     default_stats: StatisticsOfColumns,
+
+    // The bounding boxes that geometry columns must intersect, checked against the bbox statistics.
+    geo_bbox_filters: Vec<(String, [f64; 4])>,
 }
 
 impl RangeIndex {
@@ -61,11 +68,14 @@ impl RangeIndex {
         schema: TableSchemaRef,
         default_stats: StatisticsOfColumns,
     ) -> Result<Self> {
+        let mut geo_bbox_filters = vec![];
+        collect_geo_bbox_filters(expr, &mut geo_bbox_filters);
         Ok(Self {
             expr: expr.clone(),
             func_ctx,
             schema,
             default_stats,
+            geo_bbox_filters,
         })
     }
 
@@ -79,6 +89,10 @@ impl RangeIndex {
 
     pub fn apply<F>(&self, stats: &StatisticsOfColumns, column_is_default: F) -> Result<bool>
     where F: Fn(&ColumnId) -> bool {
+        if !self.apply_geo_bbox(stats) {
+            return Ok(false);
+        }
+
         let input_domains = self
             .expr
             .column_refs()
@@ -101,23 +115,42 @@ impl RangeIndex {
                     &self.schema
                 );
 
-                let stats = column_ids
+                let stats: Vec<Cow<ColumnStatistics>> = column_ids
                     .iter()
-                    .filter_map(|column_id| match stats.get(column_id) {
-                        None => {
-                            if column_is_default(column_id)
-                                && self.default_stats.contains_key(column_id)
+                    .filter_map(|column_id| {
+                        let widened_from = self
+                            .schema
+                            .widened_column_sources(*column_id)
+                            .iter()
+                            .map(|source| source.column_id)
+                            .collect::<Vec<_>>();
+                        if !widened_from.is_empty() {
+                            if let Some(stat) =
+                                widened_column_statistics(stats, *column_id, &widened_from, &ty)
                             {
-                                Some(&self.default_stats[column_id])
-                            } else {
-                                None
+                                return Some(Cow::Owned(stat));
+                            }
+                            // The column may be stored under a previous column id, without statistics.
+                            if !widened_from.iter().all(&column_is_default) {
+                                return None;
                             }
                         }
-                        other => other,
+                        match stats.get(column_id) {
+                            None => {
+                                if column_is_default(column_id)
+                                    && self.default_stats.contains_key(column_id)
+                                {
+                                    Some(Cow::Borrowed(&self.default_stats[column_id]))
+                                } else {
+                                    None
+                                }
+                            }
+                            Some(stat) => Some(Cow::Borrowed(stat)),
+                        }
                     })
                     .collect();
 
-                let domain = statistics_to_domain(stats, &ty);
+                let domain = statistics_to_domain(stats.iter().map(|s| s.as_ref()).collect(), &ty);
                 Ok((name, domain))
             })
             .collect::<Result<_>>()?;
@@ -135,6 +168,17 @@ impl RangeIndex {
         }))
     }
 
+    /// Return false if the bounding box of a geometry column is disjoint with the one of the predicates.
+    fn apply_geo_bbox(&self, stats: &StatisticsOfColumns) -> bool {
+        self.geo_bbox_filters.iter().all(|(name, bbox)| {
+            let column_ids = self.schema.leaf_columns_of(name);
+            column_ids
+                .iter()
+                .filter_map(|column_id| stats.get(column_id)?.bbox())
+                .all(|column_bbox| bounding_box_intersects(&column_bbox, bbox))
+        })
+    }
+
     #[fastrace::trace]
     pub fn apply_with_partition_columns(
         &self,
@@ -147,11 +191,72 @@ impl RangeIndex {
             func_ctx: self.func_ctx.clone(),
             schema: self.schema.clone(),
             default_stats: self.default_stats.clone(),
+            geo_bbox_filters: self.geo_bbox_filters.clone(),
         }
         .apply(stats, |_| false)
     }
 }
 
+/// Returns the statistics of a column whose data type was widened in place.
+///
+/// Blocks written before the change keep their statistics under the previous column ids
+/// (`widened_from`), which are cast to `data_type`. A segment may hold blocks written both
+/// before and after the change, so all the statistics found are merged.
+pub fn widened_column_statistics(
+    stats: &StatisticsOfColumns,
+    column_id: ColumnId,
+    widened_from: &[ColumnId],
+    data_type: &DataType,
+) -> Option<ColumnStatistics> {
+    let dest_type = data_type.remove_nullable();
+    let mut result = stats.get(&column_id).cloned();
+    for source_id in widened_from {
+        let Some(stat) = stats.get(source_id) else {
+            continue;
+        };
+        let cast = |scalar: &Scalar| {
+            if scalar.is_null() {
+                return Some(Scalar::Null);
+            }
+            cast_scalar(None, scalar.clone(), dest_type.clone(), &BUILTIN_FUNCTIONS).ok()
+        };
+        let (Some(min), Some(max)) = (cast(&stat.min), cast(&stat.max)) else {
+            return None;
+        };
+        let stat = ColumnStatistics {
+            min,
+            max,
+            null_count: stat.null_count,
+            in_memory_size: stat.in_memory_size,
+            distinct_of_values: None,
+        };
+        result = Some(match result {
+            None => stat,
+            Some(prev) => merge_column_statistics(prev, stat),
+        });
+    }
+    result
+}
+
+fn merge_column_statistics(a: ColumnStatistics, b: ColumnStatistics) -> ColumnStatistics {
+    // A null min/max means that all the values are null.
+    let min = match (a.min, b.min) {
+        (Scalar::Null, other) | (other, Scalar::Null) => other,
+        (x, y) => std::cmp::min(x, y),
+    };
+    let max = match (a.max, b.max) {
+        (Scalar::Null, other) | (other, Scalar::Null) => other,
+        (x, y) => std::cmp::max(x, y),
+    };
+    ColumnStatistics {
+        min,
+        max,
+        null_count: a.null_count + b.null_count,
+        in_memory_size: a.in_memory_size + b.in_memory_size,
+        distinct_of_values: None,
+    }
+}
+
 pub fn statistics_to_domain(mut stats: Vec<&ColumnStatistics>, data_type: &DataType) -> Domain {
     if stats.len() != data_type.num_leaf_columns() {
         return Domain::full(data_type);
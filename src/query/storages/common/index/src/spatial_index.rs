@@ -0,0 +1,89 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::Expr;
+use databend_common_expression::Scalar;
+use databend_common_io::ewkb_bounding_box;
+
+/// Collect the constant bounding boxes that geometry columns must intersect from the conjunctions of `expr`.
+///
+/// `st_intersects`, `st_within` and `st_contains` are all false
+/// if the bounding boxes of the two geometries are disjoint.
+/// `st_distance(column, constant) <= d` is false if the bounding box of the column
+/// is disjoint with the bounding box of the constant expanded by `d`.
+pub fn collect_geo_bbox_filters(expr: &Expr<String>, filters: &mut Vec<(String, [f64; 4])>) {
+    let Expr::FunctionCall { function, args, .. } = expr else {
+        return;
+    };
+    match function.signature.name.as_str() {
+        "and" | "and_filters" | "is_true" => {
+            for arg in args {
+                collect_geo_bbox_filters(arg, filters);
+            }
+        }
+        "st_intersects" | "st_within" | "st_contains" if args.len() == 2 => {
+            if let Some((column, bbox)) = column_and_constant_bbox(&args[0], &args[1]) {
+                filters.push((column, bbox));
+            }
+        }
+        "lt" | "lte" | "gt" | "gte" if args.len() == 2 => {
+            // `st_distance(..) < d` or `d > st_distance(..)`
+            let (distance, max) = match function.signature.name.as_str() {
+                "lt" | "lte" => (&args[0], &args[1]),
+                _ => (&args[1], &args[0]),
+            };
+            let Expr::FunctionCall { function, args, .. } = distance else {
+                return;
+            };
+            if function.signature.name != "st_distance" || args.len() != 2 {
+                return;
+            }
+            let Expr::Constant { scalar, .. } = max else {
+                return;
+            };
+            let Some(max) = scalar_to_f64(scalar).filter(|v| *v >= 0.0) else {
+                return;
+            };
+            if let Some((column, [xmin, ymin, xmax, ymax])) =
+                column_and_constant_bbox(&args[0], &args[1])
+            {
+                filters.push((column, [xmin - max, ymin - max, xmax + max, ymax + max]));
+            }
+        }
+        _ => {}
+    }
+}
+
+fn column_and_constant_bbox(l: &Expr<String>, r: &Expr<String>) -> Option<(String, [f64; 4])> {
+    let (column, constant) = match (l, r) {
+        (Expr::ColumnRef { id, .. }, Expr::Constant { scalar, .. })
+        | (Expr::Constant { scalar, .. }, Expr::ColumnRef { id, .. }) => (id, scalar),
+        _ => return None,
+    };
+    match constant {
+        Scalar::Geometry(ewkb) => Some((column.clone(), ewkb_bounding_box(ewkb)?)),
+        _ => None,
+    }
+}
+
+fn scalar_to_f64(scalar: &Scalar) -> Option<f64> {
+    match scalar {
+        Scalar::Number(NumberScalar::Float64(v)) => Some(v.0),
+        Scalar::Number(NumberScalar::Float32(v)) => Some(v.0 as f64),
+        Scalar::Number(v) if v.is_integer() => v.integer_to_i128().map(|v| v as f64),
+        Scalar::Decimal(v) => Some(v.to_float64()),
+        _ => None,
+    }
+}
@@ -0,0 +1,559 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BinaryHeap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberColumn;
+use databend_common_expression::types::VectorColumn;
+use databend_common_expression::types::F32;
+use databend_common_expression::Column;
+
+/// Number of k-means iterations to train the centroids of the lists.
+const KMEANS_ITERATIONS: usize = 8;
+/// At most this number of vectors for each list are sampled to train the centroids.
+const KMEANS_SAMPLES_PER_LIST: usize = 64;
+/// At least one in this number of lists are probed in a search.
+const PROBE_LISTS_RATIO: usize = 10;
+
+/// The distance metric of a vector index,
+/// a query can only use the index if it orders by the distance function of the metric.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VectorDistanceMetric {
+    Cosine,
+    L2,
+}
+
+impl VectorDistanceMetric {
+    /// Name of the distance function computing the metric.
+    pub fn func_name(&self) -> &'static str {
+        match self {
+            VectorDistanceMetric::Cosine => "cosine_distance",
+            VectorDistanceMetric::L2 => "l2_distance",
+        }
+    }
+
+    fn to_u8(self) -> u8 {
+        match self {
+            VectorDistanceMetric::Cosine => 0,
+            VectorDistanceMetric::L2 => 1,
+        }
+    }
+
+    fn from_u8(v: u8) -> Result<Self> {
+        match v {
+            0 => Ok(VectorDistanceMetric::Cosine),
+            1 => Ok(VectorDistanceMetric::L2),
+            _ => Err(ErrorCode::StorageOther(format!(
+                "invalid vector index metric: {}",
+                v
+            ))),
+        }
+    }
+}
+
+impl FromStr for VectorDistanceMetric {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "cosine" => Ok(VectorDistanceMetric::Cosine),
+            "l2" => Ok(VectorDistanceMetric::L2),
+            _ => Err(ErrorCode::IndexOptionInvalid(format!(
+                "value `{s}` is invalid vector index metric, must be `cosine` or `l2`",
+            ))),
+        }
+    }
+}
+
+impl Display for VectorDistanceMetric {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            VectorDistanceMetric::Cosine => write!(f, "cosine"),
+            VectorDistanceMetric::L2 => write!(f, "l2"),
+        }
+    }
+}
+
+/// An IVF (inverted file) index of the vectors in a block.
+///
+/// The vectors are clustered into lists by k-means, a search only scans the vectors
+/// in the lists whose centroids are the nearest to the query vector.
+/// The vectors are stored with 8-bit scalar quantization, so the distances computed
+/// by the index are approximate and the results must be re-ranked with exact distances.
+///
+/// For cosine metric, the vectors are normalized before indexing,
+/// rows with null, empty or zero vectors are not indexed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VectorIndex {
+    metric: VectorDistanceMetric,
+    dimension: usize,
+    // `lists * dimension` values.
+    centroids: Vec<f32>,
+    // The row ids of list `i` are `row_ids[offsets[i]..offsets[i + 1]]`.
+    offsets: Vec<u32>,
+    row_ids: Vec<u32>,
+    // The quantized value `c` of dimension `d` is decoded as `mins[d] + c * steps[d]`.
+    mins: Vec<f32>,
+    steps: Vec<f32>,
+    // `row_ids.len() * dimension` codes, in the same order as `row_ids`.
+    codes: Vec<u8>,
+}
+
+impl VectorIndex {
+    pub const VERSION: u32 = 1;
+
+    /// Build the index of an `Array(Float32)`, `Array(Float64)` or `VECTOR` column,
+    /// the column and the elements of array can be nullable,
+    /// `None` is returned if the column has no vector can be indexed.
+    ///
+    /// Vectors with a dimension different from the first one are not indexed,
+    /// the distance functions fail on them anyway.
+    pub fn try_create(
+        metric: VectorDistanceMetric,
+        lists: Option<usize>,
+        column: &Column,
+    ) -> Result<Option<Self>> {
+        let (column, validity) = match column {
+            Column::Nullable(c) => (&c.column, Some(&c.validity)),
+            c => (c, None),
+        };
+        // The values of all the rows, and the range of values of each row,
+        // rows with NULL elements are not indexed.
+        let (values, ranges) = match column {
+            Column::Array(array) => {
+                let (values, value_validity) = match &array.values {
+                    Column::Nullable(c) => (&c.column, Some(&c.validity)),
+                    c => (c, None),
+                };
+                let values = match values {
+                    Column::Number(NumberColumn::Float32(values)) => {
+                        values.iter().map(|v| v.0).collect::<Vec<_>>()
+                    }
+                    Column::Number(NumberColumn::Float64(values)) => {
+                        values.iter().map(|v| v.0 as f32).collect::<Vec<_>>()
+                    }
+                    values => {
+                        return Err(ErrorCode::Internal(format!(
+                            "vector index only supports float array column, but got array of {:?}",
+                            values.data_type()
+                        )));
+                    }
+                };
+                let ranges = array
+                    .offsets
+                    .windows(2)
+                    .map(|window| {
+                        let range = window[0] as usize..window[1] as usize;
+                        match value_validity {
+                            Some(v) if range.clone().any(|i| !v.get_bit(i)) => None,
+                            _ => Some(range),
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                (values, ranges)
+            }
+            Column::Vector(vector) => {
+                let values = match vector {
+                    VectorColumn::Int8(values, _) => {
+                        values.iter().map(|v| *v as f32).collect::<Vec<_>>()
+                    }
+                    VectorColumn::Float32(values, _) => {
+                        values.iter().map(|v| v.0).collect::<Vec<_>>()
+                    }
+                };
+                let dimension = vector.dimension();
+                let ranges = (0..vector.len())
+                    .map(|row| Some(row * dimension..(row + 1) * dimension))
+                    .collect::<Vec<_>>();
+                (values, ranges)
+            }
+            column => {
+                return Err(ErrorCode::Internal(format!(
+                    "vector index only supports array or vector column, but got {:?}",
+                    column.data_type()
+                )));
+            }
+        };
+
+        let mut dimension = 0;
+        let mut row_ids = Vec::new();
+        let mut vectors = Vec::new();
+        for (row, range) in ranges.into_iter().enumerate() {
+            if validity.is_some_and(|v| !v.get_bit(row)) {
+                continue;
+            }
+            let Some(range) = range else {
+                continue;
+            };
+            let vector = &values[range];
+            if vector.is_empty() || vector.iter().any(|v| !v.is_finite()) {
+                continue;
+            }
+            if dimension == 0 {
+                dimension = vector.len();
+            } else if vector.len() != dimension {
+                continue;
+            }
+            match metric {
+                VectorDistanceMetric::Cosine => {
+                    let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+                    if norm == 0.0 || !norm.is_finite() {
+                        continue;
+                    }
+                    vectors.extend(vector.iter().map(|v| v / norm));
+                }
+                VectorDistanceMetric::L2 => vectors.extend_from_slice(vector),
+            }
+            row_ids.push(row as u32);
+        }
+        if row_ids.is_empty() {
+            return Ok(None);
+        }
+
+        let num_vectors = row_ids.len();
+        let lists = lists
+            .unwrap_or_else(|| (num_vectors as f64).sqrt().ceil() as usize)
+            .clamp(1, num_vectors);
+        let centroids = train_centroids(&vectors, dimension, lists);
+
+        // Group the vectors by the nearest centroids.
+        let assignments = vectors
+            .chunks(dimension)
+            .map(|vector| nearest_centroid(&centroids, dimension, vector))
+            .collect::<Vec<_>>();
+        let mut offsets = vec![0u32; lists + 1];
+        for list in &assignments {
+            offsets[list + 1] += 1;
+        }
+        for i in 0..lists {
+            offsets[i + 1] += offsets[i];
+        }
+        let mut positions = offsets[..lists].to_vec();
+        let mut order = vec![0; num_vectors];
+        for (i, list) in assignments.iter().enumerate() {
+            order[positions[*list] as usize] = i;
+            positions[*list] += 1;
+        }
+
+        // Quantize each dimension into 256 levels between its min and max values.
+        let mut mins = vec![f32::MAX; dimension];
+        let mut maxs = vec![f32::MIN; dimension];
+        for vector in vectors.chunks(dimension) {
+            for (d, v) in vector.iter().enumerate() {
+                mins[d] = mins[d].min(*v);
+                maxs[d] = maxs[d].max(*v);
+            }
+        }
+        let steps = mins
+            .iter()
+            .zip(maxs.iter())
+            .map(|(min, max)| (max - min) / 255.0)
+            .collect::<Vec<_>>();
+
+        let mut codes = Vec::with_capacity(num_vectors * dimension);
+        let mut sorted_row_ids = Vec::with_capacity(num_vectors);
+        for i in order {
+            sorted_row_ids.push(row_ids[i]);
+            let vector = &vectors[i * dimension..(i + 1) * dimension];
+            for (d, v) in vector.iter().enumerate() {
+                let code = if steps[d] > 0.0 {
+                    ((v - mins[d]) / steps[d]).round().clamp(0.0, 255.0) as u8
+                } else {
+                    0
+                };
+                codes.push(code);
+            }
+        }
+
+        Ok(Some(Self {
+            metric,
+            dimension,
+            centroids,
+            offsets,
+            row_ids: sorted_row_ids,
+            mins,
+            steps,
+            codes,
+        }))
+    }
+
+    pub fn metric(&self) -> VectorDistanceMetric {
+        self.metric
+    }
+
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    pub fn num_lists(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    pub fn num_vectors(&self) -> usize {
+        self.row_ids.len()
+    }
+
+    /// Search the approximate nearest `limit` rows of the query vector,
+    /// returns the row ids and approximate distances in ascending order of distances.
+    ///
+    /// `None` is returned if the query vector can't be searched by the index,
+    /// for example, its dimension is different from the indexed vectors.
+    pub fn search(&self, query: &[f32], limit: usize) -> Option<Vec<(u32, F32)>> {
+        if query.len() != self.dimension || query.iter().any(|v| !v.is_finite()) {
+            return None;
+        }
+        let query = match self.metric {
+            VectorDistanceMetric::Cosine => {
+                let norm = query.iter().map(|v| v * v).sum::<f32>().sqrt();
+                if norm == 0.0 {
+                    return None;
+                }
+                query.iter().map(|v| v / norm).collect::<Vec<_>>()
+            }
+            VectorDistanceMetric::L2 => query.to_vec(),
+        };
+
+        let num_lists = self.num_lists();
+        let mut lists = (0..num_lists)
+            .map(|i| {
+                let centroid = &self.centroids[i * self.dimension..(i + 1) * self.dimension];
+                (F32::from(squared_l2(centroid, &query)), i)
+            })
+            .collect::<Vec<_>>();
+        lists.sort();
+        let min_probes = num_lists.div_ceil(PROBE_LISTS_RATIO);
+
+        // The decoded value is `mins[d] + code * steps[d]`,
+        // precompute the terms only depend on the query.
+        let (base, weights) = match self.metric {
+            VectorDistanceMetric::Cosine => {
+                let base = query.iter().zip(&self.mins).map(|(q, m)| q * m).sum();
+                let weights = query.iter().zip(&self.steps).map(|(q, s)| q * s).collect();
+                (base, weights)
+            }
+            VectorDistanceMetric::L2 => {
+                let diffs = query.iter().zip(&self.mins).map(|(q, m)| q - m).collect();
+                (0.0, diffs)
+            }
+        };
+
+        let mut heap = BinaryHeap::with_capacity(limit + 1);
+        let mut scanned = 0;
+        for (probed, (_, list)) in lists.into_iter().enumerate() {
+            if probed >= min_probes && scanned >= limit {
+                break;
+            }
+            let start = self.offsets[list] as usize;
+            let end = self.offsets[list + 1] as usize;
+            for i in start..end {
+                let codes = &self.codes[i * self.dimension..(i + 1) * self.dimension];
+                let distance = match self.metric {
+                    VectorDistanceMetric::Cosine => {
+                        let dot = base
+                            + codes
+                                .iter()
+                                .zip(&weights)
+                                .map(|(c, w)| *c as f32 * w)
+                                .sum::<f32>();
+                        1.0 - dot
+                    }
+                    VectorDistanceMetric::L2 => codes
+                        .iter()
+                        .zip(weights.iter().zip(&self.steps))
+                        .map(|(c, (diff, step))| {
+                            let v = diff - *c as f32 * step;
+                            v * v
+                        })
+                        .sum::<f32>()
+                        .sqrt(),
+                };
+                heap.push((F32::from(distance), self.row_ids[i]));
+                if heap.len() > limit {
+                    heap.pop();
+                }
+            }
+            scanned += end - start;
+        }
+
+        Some(
+            heap.into_sorted_vec()
+                .into_iter()
+                .map(|(distance, row)| (row, distance))
+                .collect(),
+        )
+    }
+
+    /// Serialize the index, all numbers are written in little endian.
+    ///
+    /// version | metric | dimension | lists | vectors | centroids | offsets | row_ids | mins | steps | codes
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(
+            17 + (self.centroids.len() + self.offsets.len() + self.row_ids.len()) * 4
+                + self.dimension * 8
+                + self.codes.len(),
+        );
+        buf.extend_from_slice(&Self::VERSION.to_le_bytes());
+        buf.push(self.metric.to_u8());
+        buf.extend_from_slice(&(self.dimension as u32).to_le_bytes());
+        buf.extend_from_slice(&(self.num_lists() as u32).to_le_bytes());
+        buf.extend_from_slice(&(self.num_vectors() as u32).to_le_bytes());
+        for v in &self.centroids {
+            buf.extend_from_slice(&v.to_le_bytes());
+        }
+        for v in self.offsets.iter().chain(&self.row_ids) {
+            buf.extend_from_slice(&v.to_le_bytes());
+        }
+        for v in self.mins.iter().chain(&self.steps) {
+            buf.extend_from_slice(&v.to_le_bytes());
+        }
+        buf.extend_from_slice(&self.codes);
+        buf
+    }
+
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        let mut reader = BytesReader { data };
+        let version = reader.read_u32()?;
+        if version != Self::VERSION {
+            return Err(ErrorCode::StorageOther(format!(
+                "unsupported vector index version: {}",
+                version
+            )));
+        }
+        let metric = VectorDistanceMetric::from_u8(reader.read(1)?[0])?;
+        let dimension = reader.read_u32()? as usize;
+        let lists = reader.read_u32()? as usize;
+        let num_vectors = reader.read_u32()? as usize;
+
+        let centroids = reader.read_f32s(lists * dimension)?;
+        let offsets = reader.read_u32s(lists + 1)?;
+        let row_ids = reader.read_u32s(num_vectors)?;
+        let mins = reader.read_f32s(dimension)?;
+        let steps = reader.read_f32s(dimension)?;
+        let codes = reader.read(num_vectors * dimension)?.to_vec();
+        if !reader.data.is_empty() || offsets.last() != Some(&(num_vectors as u32)) {
+            return Err(ErrorCode::StorageOther("invalid vector index data"));
+        }
+
+        Ok(Self {
+            metric,
+            dimension,
+            centroids,
+            offsets,
+            row_ids,
+            mins,
+            steps,
+            codes,
+        })
+    }
+}
+
+/// Train the centroids of the lists by k-means on the sampled vectors.
+fn train_centroids(vectors: &[f32], dimension: usize, lists: usize) -> Vec<f32> {
+    let num_vectors = vectors.len() / dimension;
+    let num_samples = num_vectors.min(lists * KMEANS_SAMPLES_PER_LIST);
+    let samples = (0..num_samples)
+        .map(|i| {
+            let row = i * num_vectors / num_samples;
+            &vectors[row * dimension..(row + 1) * dimension]
+        })
+        .collect::<Vec<_>>();
+
+    let mut centroids = Vec::with_capacity(lists * dimension);
+    for i in 0..lists {
+        centroids.extend_from_slice(samples[i * num_samples / lists]);
+    }
+
+    let mut sums = vec![0f32; lists * dimension];
+    let mut counts = vec![0usize; lists];
+    for _ in 0..KMEANS_ITERATIONS {
+        sums.fill(0.0);
+        counts.fill(0);
+        for sample in &samples {
+            let list = nearest_centroid(&centroids, dimension, sample);
+            counts[list] += 1;
+            let sum = &mut sums[list * dimension..(list + 1) * dimension];
+            for (s, v) in sum.iter_mut().zip(sample.iter()) {
+                *s += v;
+            }
+        }
+        // Keep the previous centroid if no sample is assigned to the list.
+        for (list, count) in counts.iter().enumerate() {
+            if *count == 0 {
+                continue;
+            }
+            let range = list * dimension..(list + 1) * dimension;
+            for (c, s) in centroids[range.clone()].iter_mut().zip(&sums[range]) {
+                *c = s / *count as f32;
+            }
+        }
+    }
+    centroids
+}
+
+fn nearest_centroid(centroids: &[f32], dimension: usize, vector: &[f32]) -> usize {
+    let mut nearest = 0;
+    let mut min_distance = f32::MAX;
+    for (i, centroid) in centroids.chunks(dimension).enumerate() {
+        let distance = squared_l2(centroid, vector);
+        if distance < min_distance {
+            nearest = i;
+            min_distance = distance;
+        }
+    }
+    nearest
+}
+
+fn squared_l2(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| (x - y) * (x - y)).sum()
+}
+
+struct BytesReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> BytesReader<'a> {
+    fn read(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.data.len() < len {
+            return Err(ErrorCode::StorageOther("invalid vector index data"));
+        }
+        let (bytes, remain) = self.data.split_at(len);
+        self.data = remain;
+        Ok(bytes)
+    }
+
+    fn read_u32(&mut self) -> Result<u32> {
+        Ok(u32::from_le_bytes(self.read(4)?.try_into().unwrap()))
+    }
+
+    fn read_u32s(&mut self, n: usize) -> Result<Vec<u32>> {
+        Ok(self
+            .read(n * 4)?
+            .chunks_exact(4)
+            .map(|v| u32::from_le_bytes(v.try_into().unwrap()))
+            .collect())
+    }
+
+    fn read_f32s(&mut self, n: usize) -> Result<Vec<f32>> {
+        Ok(self
+            .read(n * 4)?
+            .chunks_exact(4)
+            .map(|v| f32::from_le_bytes(v.try_into().unwrap()))
+            .collect())
+    }
+}
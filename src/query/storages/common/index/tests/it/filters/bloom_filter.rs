@@ -46,6 +46,10 @@ use databend_common_expression::Value;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_storages_common_index::filters::BlockFilter as LatestBloom;
 use databend_storages_common_index::filters::Xor8Filter;
+use databend_storages_common_index::filters::DEFAULT_SET_FILTER_MAX_VALUES;
+use databend_storages_common_index::like_pattern_fragments;
+use databend_storages_common_index::regexp_case_sensitive;
+use databend_storages_common_index::regexp_pattern_fragments;
 use databend_storages_common_index::BloomIndex;
 use databend_storages_common_index::FilterEvalResult;
 use databend_storages_common_index::Index;
@@ -154,6 +158,9 @@ fn test_bloom_filter() -> Result<()> {
         LatestBloom::VERSION,
         &block,
         bloom_columns,
+        BTreeMap::new(),
+        BTreeMap::new(),
+        DEFAULT_SET_FILTER_MAX_VALUES,
     )?
     .unwrap();
 
@@ -326,6 +333,9 @@ fn test_specify_bloom_filter() -> Result<()> {
         LatestBloom::VERSION,
         &block,
         bloom_columns,
+        BTreeMap::new(),
+        BTreeMap::new(),
+        DEFAULT_SET_FILTER_MAX_VALUES,
     )?
     .unwrap();
 
@@ -366,6 +376,9 @@ fn test_string_bloom_filter() -> Result<()> {
         LatestBloom::VERSION,
         &block,
         bloom_columns,
+        BTreeMap::new(),
+        BTreeMap::new(),
+        DEFAULT_SET_FILTER_MAX_VALUES,
     )?
     .unwrap();
 
@@ -384,6 +397,332 @@ fn test_string_bloom_filter() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_ngram_bloom_filter() -> Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![
+        TableField::new("0", TableDataType::Number(NumberDataType::UInt8)),
+        TableField::new("1", TableDataType::String),
+    ]));
+
+    let blocks = [DataBlock::new_from_columns(vec![
+        UInt8Type::from_data(vec![1, 2]),
+        StringType::from_data(vec!["databend", "snowflake"]),
+    ])];
+    let block = DataBlock::concat(&blocks)?;
+
+    let ngram_columns = BTreeMap::from([(1, schema.field(1).clone())]);
+    let index = BloomIndex::try_create(
+        FunctionContext::default(),
+        LatestBloom::VERSION,
+        &block,
+        BTreeMap::new(),
+        ngram_columns,
+        BTreeMap::new(),
+        DEFAULT_SET_FILTER_MAX_VALUES,
+    )?
+    .unwrap();
+    assert!(index
+        .filter_schema
+        .has_field(&BloomIndex::build_ngram_filter_column_name(schema.field(1))));
+
+    let column = || Expr::ColumnRef {
+        span: None,
+        id: "1".to_string(),
+        data_type: DataType::String,
+        display_name: "1".to_string(),
+    };
+    let pattern = |s: &str| Expr::Constant {
+        span: None,
+        scalar: Scalar::String(s.to_string()),
+        data_type: DataType::String,
+    };
+    let func = |name: &str, args: &[Expr<String>]| {
+        check_function(None, name, &[], args, &BUILTIN_FUNCTIONS).unwrap()
+    };
+    let position = |name: &str, args: &[Expr<String>]| {
+        let zero = Expr::Constant {
+            span: None,
+            scalar: Scalar::Number(NumberScalar::UInt64(0)),
+            data_type: DataType::Number(NumberDataType::UInt64),
+        };
+        func("gt", &[func(name, args), zero])
+    };
+
+    for (expr, expected) in [
+        (
+            func("like", &[column(), pattern("%tabe%")]),
+            FilterEvalResult::Uncertain,
+        ),
+        (
+            func("like", &[column(), pattern("%base%")]),
+            FilterEvalResult::MustFalse,
+        ),
+        (
+            func("like", &[column(), pattern("snow%lake")]),
+            FilterEvalResult::Uncertain,
+        ),
+        (
+            func("like", &[column(), pattern("snow%cake")]),
+            FilterEvalResult::MustFalse,
+        ),
+        // fragments shorter than the gram size can't be checked.
+        (
+            func("like", &[column(), pattern("%xy%")]),
+            FilterEvalResult::Uncertain,
+        ),
+        (
+            func("regexp_like", &[
+                column(),
+                pattern("^data.*end$"),
+                pattern("c"),
+            ]),
+            FilterEvalResult::Uncertain,
+        ),
+        (
+            func("regexp_like", &[
+                column(),
+                pattern("snow[a-z]+cat"),
+                pattern("c"),
+            ]),
+            FilterEvalResult::MustFalse,
+        ),
+        (
+            func("regexp_like", &[column(), pattern("cat|dog"), pattern("c")]),
+            FilterEvalResult::Uncertain,
+        ),
+        // `regexp` matches case-insensitively by default, the grams can't be checked.
+        (
+            func("regexp", &[column(), pattern("SNOW[a-z]+FLAKE")]),
+            FilterEvalResult::Uncertain,
+        ),
+        (
+            func("regexp_like", &[column(), pattern("snow[a-z]+cat")]),
+            FilterEvalResult::Uncertain,
+        ),
+        (
+            func("regexp_like", &[
+                column(),
+                pattern("snow[a-z]+cat"),
+                pattern("ci"),
+            ]),
+            FilterEvalResult::Uncertain,
+        ),
+        (
+            position("position", &[pattern("flake"), column()]),
+            FilterEvalResult::Uncertain,
+        ),
+        (
+            position("locate", &[pattern("flakes"), column()]),
+            FilterEvalResult::MustFalse,
+        ),
+        (
+            position("instr", &[column(), pattern("bender")]),
+            FilterEvalResult::MustFalse,
+        ),
+    ] {
+        let result = index.apply(
+            expr,
+            &HashMap::new(),
+            &StatisticsOfColumns::new(),
+            schema.clone(),
+        )?;
+        assert_eq!(result, expected);
+    }
+
+    let fields = vec![schema.field(1).clone()];
+    let expr = func("like", &[column(), pattern("%base%")]);
+    assert_eq!(
+        BloomIndex::find_ngram_columns(&expr, fields.clone())?.len(),
+        1
+    );
+    let expr = func("like", &[column(), pattern("%xy%")]);
+    assert!(BloomIndex::find_ngram_columns(&expr, fields)?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_set_bloom_filter() -> Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![
+        TableField::new(
+            "0",
+            TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt8))),
+        ),
+        TableField::new("1", TableDataType::String),
+    ]));
+
+    let blocks = [DataBlock::new_from_columns(vec![
+        UInt8Type::from_opt_data(vec![Some(1), Some(2), Some(1), None]),
+        StringType::from_data(vec!["a", "b", "a", "b"]),
+    ])];
+    let block = DataBlock::concat(&blocks)?;
+
+    let set_columns = BTreeMap::from([(0, schema.field(0).clone()), (1, schema.field(1).clone())]);
+    let index = BloomIndex::try_create(
+        FunctionContext::default(),
+        LatestBloom::VERSION,
+        &block,
+        BTreeMap::new(),
+        BTreeMap::new(),
+        set_columns,
+        DEFAULT_SET_FILTER_MAX_VALUES,
+    )?
+    .unwrap();
+    assert!(index
+        .filter_schema
+        .has_field(&BloomIndex::build_set_filter_column_name(schema.field(0))));
+    // the exact distinct count of non-null values
+    assert_eq!(index.column_distinct_count.get(&0), Some(&2));
+    assert_eq!(index.column_distinct_count.get(&1), Some(&2));
+
+    let column = |id: usize| Expr::ColumnRef {
+        span: None,
+        id: id.to_string(),
+        data_type: DataType::from(schema.field(id).data_type()),
+        display_name: id.to_string(),
+    };
+    let constant = |scalar: Scalar| {
+        let data_type = scalar.as_ref().infer_data_type();
+        Expr::Constant {
+            span: None,
+            scalar,
+            data_type,
+        }
+    };
+    let number = |v: u8| constant(Scalar::Number(NumberScalar::UInt8(v)));
+    let string = |v: &str| constant(Scalar::String(v.to_string()));
+    let func = |name: &str, args: &[Expr<String>]| {
+        check_function(None, name, &[], args, &BUILTIN_FUNCTIONS).unwrap()
+    };
+    let is_true = |expr: Expr<String>| func("is_true", &[expr]);
+
+    for (expr, expected) in [
+        (
+            is_true(func("eq", &[column(0), number(2)])),
+            FilterEvalResult::Uncertain,
+        ),
+        (
+            is_true(func("eq", &[column(0), number(3)])),
+            FilterEvalResult::MustFalse,
+        ),
+        // `0 IN (1, 2)`
+        (
+            is_true(func("or", &[
+                func("eq", &[column(0), number(1)]),
+                func("eq", &[column(0), number(2)]),
+            ])),
+            FilterEvalResult::Uncertain,
+        ),
+        // `0 NOT IN (1, 2)`
+        (
+            is_true(func("not", &[func("or", &[
+                func("eq", &[column(0), number(1)]),
+                func("eq", &[column(0), number(2)]),
+            ])])),
+            FilterEvalResult::MustFalse,
+        ),
+        // `0 NOT IN (1, 3)`
+        (
+            is_true(func("not", &[func("or", &[
+                func("eq", &[column(0), number(1)]),
+                func("eq", &[column(0), number(3)]),
+            ])])),
+            FilterEvalResult::Uncertain,
+        ),
+        (
+            func("noteq", &[column(1), string("a")]),
+            FilterEvalResult::Uncertain,
+        ),
+        (
+            func("and", &[
+                func("noteq", &[column(1), string("a")]),
+                func("noteq", &[column(1), string("b")]),
+            ]),
+            FilterEvalResult::MustFalse,
+        ),
+        (func("is_null", &[column(0)]), FilterEvalResult::Uncertain),
+        (func("is_null", &[column(1)]), FilterEvalResult::MustFalse),
+        // predicates of different columns are evaluated separately
+        (
+            func("and", &[
+                is_true(func("eq", &[column(0), number(1)])),
+                func("eq", &[column(1), string("c")]),
+            ]),
+            FilterEvalResult::MustFalse,
+        ),
+    ] {
+        let result = index.apply(
+            expr,
+            &HashMap::new(),
+            &StatisticsOfColumns::new(),
+            schema.clone(),
+        )?;
+        assert_eq!(result, expected);
+    }
+
+    let fields = vec![schema.field(0).clone()];
+    let expr = func("is_null", &[column(0)]);
+    assert_eq!(
+        BloomIndex::find_set_columns(&expr, fields.clone())?.len(),
+        1
+    );
+    let expr = func("is_null", &[column(1)]);
+    assert!(BloomIndex::find_set_columns(&expr, fields)?.is_empty());
+
+    // no set filter for the columns with more distinct values than the limit
+    let set_columns = BTreeMap::from([(0, schema.field(0).clone()), (1, schema.field(1).clone())]);
+    let index = BloomIndex::try_create(
+        FunctionContext::default(),
+        LatestBloom::VERSION,
+        &block,
+        BTreeMap::new(),
+        BTreeMap::new(),
+        set_columns,
+        2,
+    )?
+    .unwrap();
+    assert!(!index
+        .filter_schema
+        .has_field(&BloomIndex::build_set_filter_column_name(schema.field(0))));
+    assert!(index
+        .filter_schema
+        .has_field(&BloomIndex::build_set_filter_column_name(schema.field(1))));
+
+    Ok(())
+}
+
+#[test]
+fn test_ngram_pattern_fragments() {
+    assert_eq!(like_pattern_fragments("%ab\\%c_d%"), vec!["ab%c", "d"]);
+    assert_eq!(like_pattern_fragments("%%"), Vec::<String>::new());
+    assert_eq!(regexp_pattern_fragments("^abc.*de?f+[]xy]gh\\.i\\d"), vec![
+        "abc", "d", "f", "gh.i"
+    ]);
+    assert_eq!(regexp_pattern_fragments("ab{2,3}c"), vec!["a", "c"]);
+    assert_eq!(regexp_pattern_fragments("a(bc)"), Vec::<String>::new());
+    assert_eq!(regexp_pattern_fragments("[abc"), Vec::<String>::new());
+    assert_eq!(
+        regexp_pattern_fragments("ab\\x41cd\\u0041ef\\U00000041gh"),
+        vec!["ab", "cd", "ef", "gh"]
+    );
+    assert_eq!(
+        regexp_pattern_fragments("ab\\x{41}cd\\p{Greek}ef\\PLgh"),
+        vec!["ab", "cd", "ef", "gh"]
+    );
+    assert_eq!(
+        regexp_pattern_fragments("ab\\p{Greek"),
+        Vec::<String>::new()
+    );
+    assert_eq!(regexp_pattern_fragments("ab\\x4"), Vec::<String>::new());
+
+    assert!(regexp_case_sensitive("c"));
+    assert!(regexp_case_sensitive("imc"));
+    assert!(!regexp_case_sensitive(""));
+    assert!(!regexp_case_sensitive("ci"));
+    assert!(!regexp_case_sensitive("m"));
+    assert!(!regexp_case_sensitive("cu"));
+}
+
 fn eval_index(
     index: &BloomIndex,
     col_name: &str,
@@ -0,0 +1,177 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::array::ArrayColumn;
+use databend_common_expression::types::AnyType;
+use databend_common_expression::types::Bitmap;
+use databend_common_expression::types::Buffer;
+use databend_common_expression::types::Float32Type;
+use databend_common_expression::types::NullableColumn;
+use databend_common_expression::types::VectorColumn;
+use databend_common_expression::types::F32;
+use databend_common_expression::Column;
+use databend_common_expression::FromData;
+use databend_storages_common_index::VectorDistanceMetric;
+use databend_storages_common_index::VectorIndex;
+
+// Vectors around 8 well separated centers, generated deterministically.
+fn clustered_vectors(num: usize, dimension: usize) -> Vec<Vec<f32>> {
+    let mut seed = 42u64;
+    let mut next = move || {
+        seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+        ((seed >> 33) as f32 / (1u64 << 31) as f32) - 0.5
+    };
+    (0..num)
+        .map(|i| {
+            let center = i % 8;
+            (0..dimension)
+                .map(|d| if d % 8 == center { 10.0 } else { 0.0 } + next())
+                .collect()
+        })
+        .collect()
+}
+
+fn array_column(vectors: &[Vec<f32>]) -> Column {
+    let mut offsets = vec![0u64];
+    for vector in vectors {
+        offsets.push(offsets.last().unwrap() + vector.len() as u64);
+    }
+    let values = vectors.iter().flatten().copied().collect::<Vec<_>>();
+    Column::Array(Box::new(ArrayColumn::<AnyType> {
+        values: Float32Type::from_data(values),
+        offsets: Buffer::from(offsets),
+    }))
+}
+
+fn exact_distance(metric: VectorDistanceMetric, a: &[f32], b: &[f32]) -> f32 {
+    match metric {
+        VectorDistanceMetric::Cosine => {
+            let dot = a.iter().zip(b).map(|(x, y)| x * y).sum::<f32>();
+            let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+            let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+            1.0 - dot / (norm_a * norm_b)
+        }
+        VectorDistanceMetric::L2 => a
+            .iter()
+            .zip(b)
+            .map(|(x, y)| (x - y) * (x - y))
+            .sum::<f32>()
+            .sqrt(),
+    }
+}
+
+#[test]
+fn test_vector_index_search() -> Result<()> {
+    let vectors = clustered_vectors(1000, 16);
+    let column = array_column(&vectors);
+    let query = vectors[3].iter().map(|v| v + 0.01).collect::<Vec<_>>();
+
+    for metric in [VectorDistanceMetric::Cosine, VectorDistanceMetric::L2] {
+        let index = VectorIndex::try_create(metric, None, &column)?.unwrap();
+        assert_eq!(index.metric(), metric);
+        assert_eq!(index.dimension(), 16);
+        assert_eq!(index.num_vectors(), 1000);
+        assert_eq!(index.num_lists(), 32);
+
+        let results = index.search(&query, 10).unwrap();
+        assert_eq!(results.len(), 10);
+        assert!(results.windows(2).all(|w| w[0].1 <= w[1].1));
+
+        // The nearest rows are in the same cluster as row 3,
+        // and the approximate distances are close to the exact ones.
+        for (row, distance) in &results {
+            assert_eq!(*row % 8, 3);
+            let exact = exact_distance(metric, &vectors[*row as usize], &query);
+            assert!((exact - distance.0).abs() < 0.1, "{exact} vs {distance}");
+        }
+        assert!(results.iter().any(|(row, _)| *row == 3));
+
+        // Dimension mismatch can't be searched.
+        assert!(index.search(&query[..8], 10).is_none());
+
+        let bytes = index.to_bytes();
+        let decoded = VectorIndex::from_bytes(&bytes)?;
+        assert_eq!(decoded, index);
+        assert!(VectorIndex::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_vector_index_skip_rows() -> Result<()> {
+    let vectors = vec![
+        vec![1.0, 0.0, 0.0],
+        vec![0.0, 0.0, 0.0],
+        vec![0.0, 1.0],
+        vec![],
+        vec![0.0, 1.0, 0.0],
+        vec![0.0, 0.0, 1.0],
+    ];
+    let column = NullableColumn::new_column(
+        array_column(&vectors),
+        Bitmap::from([true, true, true, true, true, false]),
+    );
+
+    // Zero vectors can't be indexed by cosine metric.
+    let index = VectorIndex::try_create(VectorDistanceMetric::Cosine, Some(2), &column)?.unwrap();
+    assert_eq!(index.num_vectors(), 2);
+    assert_eq!(index.num_lists(), 2);
+    let results = index.search(&[0.0, 2.0, 0.0], 5).unwrap();
+    let rows = results.iter().map(|(row, _)| *row).collect::<Vec<_>>();
+    assert_eq!(rows, vec![4, 0]);
+    assert!(index.search(&[0.0, 0.0, 0.0], 5).is_none());
+
+    let index = VectorIndex::try_create(VectorDistanceMetric::L2, None, &column)?.unwrap();
+    assert_eq!(index.num_vectors(), 3);
+    let results = index.search(&[0.0, 0.0, 0.0], 1).unwrap();
+    assert_eq!(results[0].0, 1);
+
+    let column = array_column(&[vec![], vec![]]);
+    assert!(VectorIndex::try_create(VectorDistanceMetric::L2, None, &column)?.is_none());
+
+    Ok(())
+}
+
+#[test]
+fn test_vector_index_vector_column() -> Result<()> {
+    let vectors = clustered_vectors(100, 8);
+    let values = vectors
+        .iter()
+        .flatten()
+        .map(|v| F32::from(*v))
+        .collect::<Vec<_>>();
+    let column = Column::Vector(VectorColumn::Float32(Buffer::from(values), 8));
+
+    // A vector column is indexed the same as the array column of the same values.
+    let index = VectorIndex::try_create(VectorDistanceMetric::L2, None, &column)?.unwrap();
+    let expected =
+        VectorIndex::try_create(VectorDistanceMetric::L2, None, &array_column(&vectors))?.unwrap();
+    assert_eq!(index, expected);
+
+    let column = NullableColumn::new_column(column, Bitmap::from_iter((0..100).map(|i| i != 5)));
+    let index = VectorIndex::try_create(VectorDistanceMetric::L2, None, &column)?.unwrap();
+    assert_eq!(index.num_vectors(), 99);
+    let results = index.search(&vectors[5], 100).unwrap();
+    assert!(results.iter().all(|(row, _)| *row != 5));
+
+    let values = vec![1i8, 0, 0, 1, -1, 0];
+    let column = Column::Vector(VectorColumn::Int8(Buffer::from(values), 2));
+    let index = VectorIndex::try_create(VectorDistanceMetric::Cosine, None, &column)?.unwrap();
+    let results = index.search(&[1.0, 0.0], 1).unwrap();
+    assert_eq!(results[0].0, 0);
+
+    Ok(())
+}
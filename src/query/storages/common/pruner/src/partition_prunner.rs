@@ -16,11 +16,13 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use databend_common_exception::Result;
+use databend_common_expression::ConstantFolder;
 use databend_common_expression::Expr;
 use databend_common_expression::FunctionContext;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
+use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_storages_common_index::RangeIndex;
 use databend_storages_common_table_meta::meta::ColumnStatistics;
 use databend_storages_common_table_meta::meta::StatisticsOfColumns;
@@ -89,3 +91,39 @@ impl PartitionPruner {
         self.range_filter.apply(&stats, |_| false)
     }
 }
+
+/// Returns true if the partition can be eliminated by the runtime filters of a join.
+///
+/// The runtime filters carry the key domain of the build side (in-list or min/max),
+/// they are folded against the values of the partition columns. Filters referring to
+/// columns which are not partition columns are ignored.
+pub fn prune_partition_by_runtime_filters(
+    func_ctx: &FunctionContext,
+    filters: &[Expr<String>],
+    partition_fields: &[TableField],
+    partition_values: &[Scalar],
+) -> bool {
+    filters.iter().any(|filter| {
+        let column_refs = filter.column_refs();
+        let mut input_domains = HashMap::with_capacity(column_refs.len());
+        for (name, ty) in column_refs.iter() {
+            let Some(index) = partition_fields.iter().position(|f| f.name() == name) else {
+                return false;
+            };
+            let Some(value) = partition_values.get(index) else {
+                return false;
+            };
+            if value.is_null() && !ty.is_nullable_or_null() {
+                return false;
+            }
+            input_domains.insert(name.clone(), value.as_ref().domain(ty));
+        }
+
+        let (new_expr, _) =
+            ConstantFolder::fold_with_domain(filter, &input_domains, func_ctx, &BUILTIN_FUNCTIONS);
+        matches!(new_expr, Expr::Constant {
+            scalar: Scalar::Boolean(false),
+            ..
+        })
+    })
+}
@@ -16,9 +16,11 @@ use std::sync::Arc;
 
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
 use databend_common_expression::RemoteExpr;
 use databend_common_expression::TableDataType;
 use databend_common_expression::TableSchemaRef;
+use databend_storages_common_index::widened_column_statistics;
 use databend_storages_common_table_meta::meta::BlockMeta;
 
 use crate::BlockMetaIndex;
@@ -75,23 +77,43 @@ impl TopNPrunner {
         };
 
         // String Type min/max is truncated
+        // Geometry and Geography min/max is the bounding box, not an order of the values
+        let field = self.schema.field_with_name(column)?;
         if matches!(
-            self.schema.field_with_name(column)?.data_type(),
-            TableDataType::String
+            field.data_type().remove_nullable(),
+            TableDataType::String | TableDataType::Geometry | TableDataType::Geography
         ) {
             return Ok(metas);
         }
 
+        let widened_from = self
+            .schema
+            .widened_column_sources(sort_column_id)
+            .iter()
+            .map(|source| source.column_id)
+            .collect::<Vec<_>>();
+        let data_type = DataType::from(field.data_type());
+
         let mut id_stats = metas
             .iter()
             .map(|(id, meta)| {
-                let stat = meta.col_stats.get(&sort_column_id).ok_or_else(|| {
+                let stat = if widened_from.is_empty() {
+                    meta.col_stats.get(&sort_column_id).cloned()
+                } else {
+                    widened_column_statistics(
+                        &meta.col_stats,
+                        sort_column_id,
+                        &widened_from,
+                        &data_type,
+                    )
+                };
+                let stat = stat.ok_or_else(|| {
                     ErrorCode::UnknownException(format!(
                         "Unable to get the colStats by ColumnId: {}",
                         sort_column_id
                     ))
                 })?;
-                Ok((id.clone(), stat.clone(), meta.clone()))
+                Ok((id.clone(), stat, meta.clone()))
             })
             .collect::<Result<Vec<_>>>()?;
 
@@ -82,6 +82,18 @@ pub fn load_can_auto_cast_to(from_type: &DataType, to_type: &DataType) -> bool {
 
         (Array(box from_ty), Array(box to_ty)) => load_can_auto_cast_to(from_ty, to_ty),
         (EmptyArray, Array(_)) => true,
+        // [specificity] the number of elements is checked when casting each row
+        (Array(box from_ty), Vector(to_ty)) => {
+            load_can_auto_cast_to(from_ty, &Number(to_ty.inner_data_type()))
+        }
+        (Vector(from_ty), Vector(to_ty)) => {
+            from_ty.dimension() == to_ty.dimension()
+                && load_can_auto_cast_to(
+                    &Number(from_ty.inner_data_type()),
+                    &Number(to_ty.inner_data_type()),
+                )
+        }
+        (_, Vector(_)) | (Vector(_), _) => false,
         (_, Array(_)) | (Array(_), _) => false,
 
         // ==== handle primary types at last, so the _ bellow only need to consider themselves.
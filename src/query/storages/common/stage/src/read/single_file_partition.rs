@@ -21,11 +21,15 @@ use databend_common_catalog::plan::PartInfo;
 use databend_common_catalog::plan::PartInfoPtr;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_storage::ArchiveMember;
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Eq, PartialEq)]
 pub struct SingleFilePartition {
     pub path: String,
     pub size: usize,
+    /// The members to load if the file is an archive.
+    #[serde(default)]
+    pub archive_members: Vec<ArchiveMember>,
 }
 
 #[typetag::serde(name = "single_file_part")]
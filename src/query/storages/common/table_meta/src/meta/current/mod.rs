@@ -19,6 +19,7 @@ pub use v2::ColumnMeta;
 pub use v2::ColumnStatistics;
 pub use v2::MetaHLL;
 pub use v2::Statistics;
+pub use v3::MultiColumnStatistics;
 pub use v3::TableSnapshotStatistics;
 pub use v4::CompactSegmentInfo;
 pub use v4::SegmentInfo;
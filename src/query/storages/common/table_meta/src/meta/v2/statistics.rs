@@ -19,6 +19,7 @@ use std::marker::PhantomData;
 use databend_common_expression::converts::datavalues::from_scalar;
 use databend_common_expression::converts::meta::IndexScalar;
 use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberScalar;
 use databend_common_expression::ColumnId;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableDataType;
@@ -114,6 +115,51 @@ impl ColumnStatistics {
         &self.max
     }
 
+    /// Create the statistics of a Geometry column.
+    ///
+    /// The bounding box `[xmin, ymin, xmax, ymax]` of all the geometries is kept
+    /// as the tuples `(xmin, ymin)` in `min` and `(xmax, ymax)` in `max`.
+    pub fn from_bbox(
+        bbox: Option<[f64; 4]>,
+        null_count: u64,
+        in_memory_size: u64,
+        distinct_of_values: Option<u64>,
+    ) -> Self {
+        let point = |x: f64, y: f64| {
+            Scalar::Tuple(vec![
+                Scalar::Number(NumberScalar::Float64(x.into())),
+                Scalar::Number(NumberScalar::Float64(y.into())),
+            ])
+        };
+        let (min, max) = match bbox {
+            Some([xmin, ymin, xmax, ymax]) => (point(xmin, ymin), point(xmax, ymax)),
+            None => (Scalar::Null, Scalar::Null),
+        };
+        Self {
+            min,
+            max,
+            null_count,
+            in_memory_size,
+            distinct_of_values,
+        }
+    }
+
+    /// The bounding box `[xmin, ymin, xmax, ymax]` of a Geometry column, see [`Self::from_bbox`].
+    pub fn bbox(&self) -> Option<[f64; 4]> {
+        let point = |scalar: &Scalar| match scalar {
+            Scalar::Tuple(fields) => match fields.as_slice() {
+                [Scalar::Number(NumberScalar::Float64(x)), Scalar::Number(NumberScalar::Float64(y))] => {
+                    Some((x.0, y.0))
+                }
+                _ => None,
+            },
+            _ => None,
+        };
+        let (xmin, ymin) = point(&self.min)?;
+        let (xmax, ymax) = point(&self.max)?;
+        Some([xmin, ymin, xmax, ymax])
+    }
+
     pub fn from_v0(
         v0: &crate::meta::v0::statistics::ColumnStatistics,
         data_type: &TableDataType,
@@ -403,7 +449,7 @@ impl<'de> serde::de::Visitor<'de> for ColStatsVisitor {
                     map.insert(key, value);
                 } else {
                     let data_type = value.max.as_ref().infer_data_type();
-                    if supported_stat_type(&data_type) {
+                    if supported_stat_type(&data_type) || value.bbox().is_some() {
                         map.insert(key, value);
                     } else {
                         info!(
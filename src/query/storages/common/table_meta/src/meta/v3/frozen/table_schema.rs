@@ -203,6 +203,7 @@ mod converters {
                     .collect::<Vec<_>>(),
                 metadata: value.metadata,
                 next_column_id: value.next_column_id,
+                widened_columns: Default::default(),
             }
         }
     }
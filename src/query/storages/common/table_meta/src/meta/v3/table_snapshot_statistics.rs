@@ -15,6 +15,7 @@
 use std::collections::HashMap;
 
 use databend_common_expression::ColumnId;
+use databend_common_storage::Datum;
 use databend_common_storage::Histogram;
 use serde::Deserialize;
 use serde::Serialize;
@@ -36,12 +37,29 @@ pub struct TableSnapshotStatistics {
     pub snapshot_id: SnapshotId,
     pub hll: HashMap<ColumnId, MetaHLL>,
     pub histograms: HashMap<ColumnId, Histogram>,
+    #[serde(default)]
+    pub multi_column_stats: Vec<MultiColumnStatistics>,
+}
+
+/// Statistics of a group of correlated columns, collected by
+/// `ANALYZE TABLE ... COMPUTE STATISTICS FOR COLUMNS (...)`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct MultiColumnStatistics {
+    pub column_ids: Vec<ColumnId>,
+    /// Number of distinct value combinations of the columns.
+    pub ndv: u64,
+    /// Number of rows the statistics are collected from.
+    pub row_count: u64,
+    /// The most common value combinations (in the order of `column_ids`)
+    /// and their row counts, in descending order of the count.
+    pub most_common_values: Vec<(Vec<Datum>, u64)>,
 }
 
 impl TableSnapshotStatistics {
     pub fn new(
         hll: HashMap<ColumnId, MetaHLL>,
         histograms: HashMap<ColumnId, Histogram>,
+        multi_column_stats: Vec<MultiColumnStatistics>,
         snapshot_id: SnapshotId,
     ) -> Self {
         Self {
@@ -49,6 +67,7 @@ impl TableSnapshotStatistics {
             snapshot_id,
             hll,
             histograms,
+            multi_column_stats,
         }
     }
 
@@ -71,6 +90,7 @@ impl From<v2::TableSnapshotStatistics> for TableSnapshotStatistics {
             snapshot_id: value.snapshot_id,
             hll: HashMap::new(),
             histograms: HashMap::new(),
+            multi_column_stats: vec![],
         }
     }
 }
@@ -82,6 +102,50 @@ impl From<v1::TableSnapshotStatistics> for TableSnapshotStatistics {
             snapshot_id: value.snapshot_id,
             hll: HashMap::new(),
             histograms: HashMap::new(),
+            multi_column_stats: vec![],
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use databend_common_base::base::uuid::Uuid;
+
+    use super::*;
+
+    #[test]
+    fn test_deserialize_without_multi_column_stats() {
+        // written before the multi-column statistics are collected
+        let snapshot_id = Uuid::new_v4();
+        let json = format!(
+            r#"{{"format_version":3,"snapshot_id":"{}","hll":{{}},"histograms":{{}}}}"#,
+            snapshot_id
+        );
+        let stats: TableSnapshotStatistics = serde_json::from_str(&json).unwrap();
+        assert_eq!(stats.format_version(), TableSnapshotStatistics::VERSION);
+        assert_eq!(stats.snapshot_id, snapshot_id);
+        assert!(stats.multi_column_stats.is_empty());
+    }
+
+    #[test]
+    fn test_multi_column_stats_round_trip() {
+        let multi_column_stats = vec![MultiColumnStatistics {
+            column_ids: vec![0, 1],
+            ndv: 10,
+            row_count: 1000,
+            most_common_values: vec![
+                (vec![Datum::Int(1), Datum::Bytes(b"a".to_vec())], 500),
+                (vec![Datum::Int(2), Datum::Bytes(b"b".to_vec())], 100),
+            ],
+        }];
+        let stats = TableSnapshotStatistics::new(
+            HashMap::new(),
+            HashMap::new(),
+            multi_column_stats.clone(),
+            Uuid::new_v4(),
+        );
+        let json = serde_json::to_string(&stats).unwrap();
+        let stats: TableSnapshotStatistics = serde_json::from_str(&json).unwrap();
+        assert_eq!(stats.multi_column_stats, multi_column_stats);
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Mapping between Databend table schemas and iceberg schemas.
+//!
+//! Shared by the iceberg table engine, which creates iceberg tables, and the fuse engine,
+//! which exports its snapshots as iceberg metadata.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use serde_json::json;
+use serde_json::Value;
+
+/// The column id in iceberg is 1-based while the column id in Databend is 0-based.
+pub fn iceberg_field_id(field: &TableField) -> i32 {
+    field.column_id() as i32 + 1
+}
+
+/// Build the json form of the iceberg schema, as defined by the iceberg spec.
+pub fn iceberg_schema_json(schema: &TableSchema) -> Result<Value> {
+    let mut fields = Vec::with_capacity(schema.num_fields());
+    for field in schema.fields() {
+        let Some(ty) = iceberg_type(field.data_type()) else {
+            return Err(ErrorCode::StorageUnsupported(format!(
+                "column {} of type {} is not supported by iceberg",
+                field.name(),
+                field.data_type()
+            )));
+        };
+        fields.push(json!({
+            "id": iceberg_field_id(field),
+            "name": field.name(),
+            "required": !field.is_nullable(),
+            "type": ty,
+        }));
+    }
+    Ok(json!({"type": "struct", "schema-id": 0, "fields": fields}))
+}
+
+/// Returns the iceberg primitive type of `data_type`, `None` if iceberg has no such type.
+fn iceberg_type(data_type: &TableDataType) -> Option<String> {
+    let ty = match data_type.remove_nullable() {
+        TableDataType::Boolean => "boolean",
+        TableDataType::Number(num) => match num {
+            NumberDataType::Int8
+            | NumberDataType::Int16
+            | NumberDataType::Int32
+            | NumberDataType::UInt8
+            | NumberDataType::UInt16 => "int",
+            NumberDataType::Int64 => "long",
+            NumberDataType::Float32 => "float",
+            NumberDataType::Float64 => "double",
+            // iceberg has no unsigned integers, the values of UInt32 are stored as parquet
+            // INT32 by fuse, they would be read as negative numbers or rejected as a long.
+            NumberDataType::UInt32 | NumberDataType::UInt64 => return None,
+        },
+        TableDataType::Decimal(decimal) if decimal.precision() <= 38 => {
+            return Some(format!(
+                "decimal({}, {})",
+                decimal.precision(),
+                decimal.scale()
+            ));
+        }
+        TableDataType::String => "string",
+        TableDataType::Binary => "binary",
+        TableDataType::Date => "date",
+        TableDataType::Timestamp => "timestamp",
+        _ => return None,
+    };
+    Some(ty.to_string())
+}
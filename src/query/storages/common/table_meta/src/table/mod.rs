@@ -13,12 +13,15 @@
 // limitations under the License.
 
 mod dynamic_table_keys;
+mod iceberg_schema;
 mod stream_keys;
 mod table_compression;
 mod table_keys;
 mod table_prefix;
 
 pub use dynamic_table_keys::*;
+pub use iceberg_schema::iceberg_field_id;
+pub use iceberg_schema::iceberg_schema_json;
 pub use stream_keys::*;
 pub use table_compression::TableCompression;
 pub use table_keys::*;
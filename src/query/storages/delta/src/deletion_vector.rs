@@ -0,0 +1,170 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Read deletion vectors of delta table.
+//!
+//! See <https://github.com/delta-io/delta/blob/master/PROTOCOL.md#deletion-vectors>.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use deltalake::kernel::DeletionVectorDescriptor;
+use opendal::Operator;
+use roaring::RoaringTreemap;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Magic number of the portable serialization format of `RoaringBitmapArray`.
+const PORTABLE_ROARING_BITMAP_MAGIC: u32 = 1681511377;
+
+const Z85_CHARS: &[u8] =
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+/// The deletion vector of a data file, same as [`DeletionVectorDescriptor`].
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DeltaDeletionVector {
+    /// `u` for relative path, `i` for inline and `p` for absolute path.
+    pub storage_type: String,
+    pub path_or_inline_dv: String,
+    pub offset: Option<i32>,
+    pub size_in_bytes: i32,
+    pub cardinality: i64,
+}
+
+impl DeltaDeletionVector {
+    pub fn try_create(descriptor: &DeletionVectorDescriptor) -> Result<Self> {
+        let value = serde_json::to_value(descriptor)?;
+        Ok(serde_json::from_value(value)?)
+    }
+
+    /// Read the deleted row indexes, sorted in ascending order.
+    ///
+    /// `op` must be rooted at the location of the delta table.
+    #[async_backtrace::framed]
+    pub async fn read(&self, op: &Operator) -> Result<Vec<u64>> {
+        let size = self.size_in_bytes as usize;
+        let rows = match self.storage_type.as_str() {
+            "i" => {
+                let data = z85_decode(&self.path_or_inline_dv)?;
+                if data.len() < size {
+                    return Err(corrupted("inline data is too short"));
+                }
+                deserialize(&data[..size])?
+            }
+            "u" => {
+                let path = self.relative_path()?;
+                // The layout is `<size: u32 BE> <data> <checksum: u32 BE>`.
+                let offset = self.offset.unwrap_or(1) as u64;
+                let buf = op
+                    .read_with(&path)
+                    .range(offset..offset + size as u64 + 8)
+                    .await?
+                    .to_vec();
+                if buf.len() != size + 8 {
+                    return Err(corrupted("file is too short"));
+                }
+                let len = u32::from_be_bytes(buf[0..4].try_into().unwrap()) as usize;
+                if len != size {
+                    return Err(corrupted(&format!(
+                        "size mismatch, expect {size}, actual {len}"
+                    )));
+                }
+                let data = &buf[4..4 + size];
+                let checksum = u32::from_be_bytes(buf[4 + size..].try_into().unwrap());
+                if crc32fast::hash(data) != checksum {
+                    return Err(corrupted("checksum mismatch"));
+                }
+                deserialize(data)?
+            }
+            other => {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "Deletion vector with storage type '{other}' is not supported"
+                )));
+            }
+        };
+
+        if rows.len() as i64 != self.cardinality {
+            return Err(corrupted(&format!(
+                "cardinality mismatch, expect {}, actual {}",
+                self.cardinality,
+                rows.len()
+            )));
+        }
+        Ok(rows)
+    }
+
+    /// The path relative to the table root, encoded as `<random prefix><z85 encoded uuid>`.
+    fn relative_path(&self) -> Result<String> {
+        let encoded = &self.path_or_inline_dv;
+        if encoded.len() < 20 {
+            return Err(corrupted("invalid path"));
+        }
+        let (prefix, uuid) = encoded.split_at(encoded.len() - 20);
+        let uuid = z85_decode(uuid)?;
+        let hex = uuid.iter().map(|b| format!("{b:02x}")).collect::<String>();
+        let file = format!(
+            "deletion_vector_{}-{}-{}-{}-{}.bin",
+            &hex[0..8],
+            &hex[8..12],
+            &hex[12..16],
+            &hex[16..20],
+            &hex[20..32]
+        );
+        if prefix.is_empty() {
+            Ok(file)
+        } else {
+            Ok(format!("{prefix}/{file}"))
+        }
+    }
+}
+
+fn deserialize(data: &[u8]) -> Result<Vec<u64>> {
+    if data.len() < 4 {
+        return Err(corrupted("data is too short"));
+    }
+    let magic = u32::from_le_bytes(data[0..4].try_into().unwrap());
+    if magic != PORTABLE_ROARING_BITMAP_MAGIC {
+        return Err(corrupted(&format!("unknown magic number {magic}")));
+    }
+    let bitmap =
+        RoaringTreemap::deserialize_from(&data[4..]).map_err(|e| corrupted(&format!("{e}")))?;
+    Ok(bitmap.iter().collect())
+}
+
+fn z85_decode(encoded: &str) -> Result<Vec<u8>> {
+    let encoded = encoded.as_bytes();
+    if encoded.len() % 5 != 0 {
+        return Err(corrupted("invalid z85 encoding"));
+    }
+    let mut decoded = Vec::with_capacity(encoded.len() / 5 * 4);
+    for chunk in encoded.chunks(5) {
+        let mut value: u32 = 0;
+        for c in chunk {
+            let digit = Z85_CHARS
+                .iter()
+                .position(|x| x == c)
+                .ok_or_else(|| corrupted("invalid z85 encoding"))?;
+            value = value
+                .checked_mul(85)
+                .and_then(|v| v.checked_add(digit as u32))
+                .ok_or_else(|| corrupted("invalid z85 encoding"))?;
+        }
+        decoded.extend_from_slice(&value.to_be_bytes());
+    }
+    Ok(decoded)
+}
+
+fn corrupted(reason: &str) -> ErrorCode {
+    ErrorCode::StorageOther(format!("Corrupted delta deletion vector: {reason}"))
+}
@@ -15,7 +15,9 @@
 #![feature(impl_trait_in_assoc_type)]
 #![allow(clippy::diverging_sub_expression)]
 
+mod deletion_vector;
 mod partition;
+mod statistics;
 mod table;
 mod table_source;
 
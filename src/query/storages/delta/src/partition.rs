@@ -13,6 +13,9 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
 
 use databend_common_catalog::plan::PartInfo;
 use databend_common_catalog::plan::PartInfoPtr;
@@ -21,11 +24,14 @@ use databend_common_exception::Result;
 use databend_common_expression::Scalar;
 use databend_common_storages_parquet::ParquetPart;
 
+use crate::deletion_vector::DeltaDeletionVector;
+
 /// only support parquet for now: https://github.com/delta-io/delta/issues/87
 #[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
 pub struct DeltaPartInfo {
     pub data: ParquetPart,
     pub partition_values: Vec<Scalar>,
+    pub deletion_vector: Option<DeltaDeletionVector>,
 }
 
 impl DeltaPartInfo {
@@ -52,3 +58,30 @@ impl PartInfo for DeltaPartInfo {
         self.data.hash()
     }
 }
+
+/// The data files of a partition. The partition is checked by the runtime filters of
+/// the join before its files are read, the files of an eliminated partition are skipped at once.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct DeltaPartitionPartInfo {
+    pub partition_values: Vec<Scalar>,
+    pub files: Vec<DeltaPartInfo>,
+}
+
+#[typetag::serde(name = "delta_partition")]
+impl PartInfo for DeltaPartitionPartInfo {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn PartInfo>) -> bool {
+        info.as_any()
+            .downcast_ref::<DeltaPartitionPartInfo>()
+            .is_some_and(|other| self == other)
+    }
+
+    fn hash(&self) -> u64 {
+        let mut s = DefaultHasher::new();
+        self.partition_values.hash(&mut s);
+        s.finish()
+    }
+}
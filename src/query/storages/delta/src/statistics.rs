@@ -0,0 +1,91 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::NaiveDate;
+use databend_common_expression::types::number::F32;
+use databend_common_expression::types::number::F64;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_storages_common_table_meta::meta::ColumnStatistics;
+use databend_storages_common_table_meta::meta::StatisticsOfColumns;
+use deltalake::kernel::Add;
+use serde_json::Value;
+
+/// Delta writers truncate string statistics to a prefix of 32 characters, a truncated
+/// max value is not an upper bound.
+const STRING_PREFIX_LENGTH: usize = 32;
+
+/// Collect the column statistics of the data file from the `stats` of [`Add`].
+///
+/// `fields` are the top level columns with their physical names, columns without usable
+/// statistics are omitted.
+pub fn collect_file_stats(
+    add: &Add,
+    fields: &[(TableField, String)],
+) -> Option<StatisticsOfColumns> {
+    let stats: Value = serde_json::from_str(add.stats.as_ref()?).ok()?;
+    let num_records = stats["numRecords"].as_u64()?;
+
+    let mut columns = StatisticsOfColumns::new();
+    for (field, name) in fields {
+        let Some(null_count) = stats["nullCount"][name].as_u64() else {
+            continue;
+        };
+        let data_type = field.data_type();
+        let min = to_scalar(&stats["minValues"][name], data_type);
+        let max = to_scalar(&stats["maxValues"][name], data_type);
+        let column = match (min, max) {
+            (Some(min), Some(max)) => ColumnStatistics::new(min, max, null_count, 0, None),
+            _ if null_count == num_records => {
+                ColumnStatistics::new(Scalar::Null, Scalar::Null, null_count, 0, None)
+            }
+            _ => continue,
+        };
+        columns.insert(field.column_id(), column);
+    }
+    Some(columns)
+}
+
+fn to_scalar(value: &Value, data_type: &TableDataType) -> Option<Scalar> {
+    let scalar = match data_type.remove_nullable() {
+        TableDataType::Number(num) => Scalar::Number(match num {
+            NumberDataType::Int8 => NumberScalar::Int8(value.as_i64()?.try_into().ok()?),
+            NumberDataType::Int16 => NumberScalar::Int16(value.as_i64()?.try_into().ok()?),
+            NumberDataType::Int32 => NumberScalar::Int32(value.as_i64()?.try_into().ok()?),
+            NumberDataType::Int64 => NumberScalar::Int64(value.as_i64()?),
+            NumberDataType::Float32 => NumberScalar::Float32(F32::from(value.as_f64()? as f32)),
+            NumberDataType::Float64 => NumberScalar::Float64(F64::from(value.as_f64()?)),
+            // Delta has no unsigned integers.
+            _ => return None,
+        }),
+        TableDataType::String => {
+            let value = value.as_str()?;
+            if value.chars().count() >= STRING_PREFIX_LENGTH {
+                return None;
+            }
+            Scalar::String(value.to_string())
+        }
+        TableDataType::Date => {
+            let date = NaiveDate::parse_from_str(value.as_str()?, "%Y-%m-%d").ok()?;
+            let epoch = NaiveDate::from_ymd_opt(1970, 1, 1)?;
+            Scalar::Date((date - epoch).num_days() as i32)
+        }
+        // Timestamps are truncated to milliseconds in the statistics.
+        _ => return None,
+    };
+    Some(scalar)
+}
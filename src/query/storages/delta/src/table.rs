@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use arrow_schema::Schema as ArrowSchema;
@@ -22,19 +23,26 @@ use databend_common_catalog::partition_columns::get_pushdown_without_partition_c
 use databend_common_catalog::partition_columns::str_to_scalar;
 use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::ParquetReadOptions;
+use databend_common_catalog::plan::PartInfo;
+use databend_common_catalog::plan::PartInfoPtr;
 use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table::DistributionLevel;
+use databend_common_catalog::table::NavigationPoint;
 use databend_common_catalog::table::Table;
+use databend_common_catalog::table::TimeNavigation;
 use databend_common_catalog::table_args::TableArgs;
+use databend_common_catalog::table_context::AbortChecker;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataSchema;
 use databend_common_expression::FieldIndex;
+use databend_common_expression::RemoteExpr;
 use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
 use databend_common_functions::BUILTIN_FUNCTIONS;
@@ -48,16 +56,21 @@ use databend_common_storages_parquet::ParquetRSPruner;
 use databend_common_storages_parquet::ParquetRSReaderBuilder;
 use databend_storages_common_pruner::partition_prunner::FetchPartitionScalars;
 use databend_storages_common_pruner::partition_prunner::PartitionPruner;
+use databend_storages_common_pruner::RangePrunerCreator;
 use databend_storages_common_table_meta::table::OPT_KEY_ENGINE_META;
 use deltalake::kernel::Add;
 use deltalake::DeltaTableBuilder;
 use object_store_opendal::OpendalStore;
 use serde::Deserialize;
 use serde::Serialize;
+use serde_json::Value;
 use tokio::sync::OnceCell;
 use url::Url;
 
+use crate::deletion_vector::DeltaDeletionVector;
 use crate::partition::DeltaPartInfo;
+use crate::partition::DeltaPartitionPartInfo;
+use crate::statistics::collect_file_stats;
 use crate::table_source::DeltaTableSource;
 
 pub const DELTA_ENGINE: &str = "DELTA";
@@ -71,6 +84,21 @@ pub struct DeltaTable {
 #[derive(Serialize, Deserialize)]
 pub struct DeltaTableMeta {
     partition_columns: Vec<String>,
+    /// Logical name to physical name of columns, empty if column mapping is disabled.
+    #[serde(default)]
+    column_mapping: BTreeMap<String, PhysicalColumn>,
+    /// The version to read, `None` for the latest version.
+    #[serde(default)]
+    version: Option<i64>,
+}
+
+/// The physical name of a column, and of the fields if the column is a struct.
+#[derive(Serialize, Deserialize)]
+struct PhysicalColumn {
+    name: String,
+    /// Logical name to physical name of the struct fields, also for structs in arrays.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    fields: BTreeMap<String, PhysicalColumn>,
 }
 
 /// In a delta table, partition columns are not stored in parquet file.
@@ -87,6 +115,11 @@ pub struct DeltaTableMeta {
 ///   - pruner: ColumnRef of partition columns in filter expr are replace with const scalars.
 ///
 /// Type of partition columns can only be simple primitive types.
+///
+/// With column mapping (`delta.columnMapping.mode` is `name` or `id`), columns are stored
+/// with their physical names in parquet files and partition values. Both modes are resolved
+/// by the physical names, fields of struct columns are renamed as well. Structs nested in
+/// maps are not supported with column mapping.
 impl DeltaTable {
     #[async_backtrace::framed]
     pub fn try_create(info: TableInfo) -> Result<Box<dyn Table>> {
@@ -134,6 +167,17 @@ impl DeltaTable {
 
     #[async_backtrace::framed]
     pub async fn get_meta(table: &deltalake::table::DeltaTable) -> Result<(TableSchema, String)> {
+        let (schema, meta) = Self::build_meta(table, None)?;
+        let meta = serde_json::to_string(&meta).map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("fail to serialize DeltaTableMeta: {e:?}"))
+        })?;
+        Ok((schema, meta))
+    }
+
+    fn build_meta(
+        table: &deltalake::table::DeltaTable,
+        version: Option<i64>,
+    ) -> Result<(TableSchema, DeltaTableMeta)> {
         let delta_meta = table.get_schema().map_err(|e| {
             ErrorCode::ReadTableDataError(format!("Cannot convert table metadata: {e:?}"))
         })?;
@@ -146,29 +190,49 @@ impl DeltaTable {
         let state = table.metadata().map_err(|_| {
             ErrorCode::ReadTableDataError("bug: Delta table current_metadata is None.")
         })?;
+        let mapping_mode = state
+            .configuration
+            .get("delta.columnMapping.mode")
+            .cloned()
+            .flatten()
+            .unwrap_or_default();
+        let column_mapping = match mapping_mode.as_str() {
+            "" | "none" => BTreeMap::new(),
+            "name" | "id" => {
+                // Physical names are kept in the metadata of fields.
+                physical_columns(&serde_json::to_value(delta_meta)?)?
+            }
+            other => {
+                return Err(ErrorCode::ReadTableDataError(format!(
+                    "Unsupported delta column mapping mode '{other}'"
+                )));
+            }
+        };
         let meta = DeltaTableMeta {
             partition_columns: state.partition_columns.clone(),
+            column_mapping,
+            version,
         };
-        let meta = serde_json::to_string(&meta).map_err(|e| {
-            ErrorCode::ReadTableDataError(format!("fail to serialize DeltaTableMeta: {e:?}"))
-        })?;
 
         let schema = TableSchema::try_from(&arrow_schema)?;
         Ok((schema, meta))
     }
 
-    #[async_backtrace::framed]
-    pub async fn load(sp: &StorageParams) -> Result<deltalake::table::DeltaTable> {
+    fn build(sp: &StorageParams) -> Result<deltalake::table::DeltaTable> {
         let op = init_operator(sp)?;
         let opendal_store = Arc::new(OpendalStore::new(op));
 
-        let mut table = DeltaTableBuilder::from_uri(Url::from_directory_path("/").unwrap())
+        DeltaTableBuilder::from_uri(Url::from_directory_path("/").unwrap())
             .with_storage_backend(opendal_store, Url::from_directory_path("/").unwrap())
             .build()
             .map_err(|err| {
                 ErrorCode::ReadTableDataError(format!("Delta table load failed: {err:?}"))
-            })?;
+            })
+    }
 
+    #[async_backtrace::framed]
+    pub async fn load(sp: &StorageParams) -> Result<deltalake::table::DeltaTable> {
+        let mut table = Self::build(sp)?;
         table.load().await.map_err(|err| {
             ErrorCode::ReadTableDataError(format!("Delta table load failed: {err:?}"))
         })?;
@@ -180,11 +244,99 @@ impl DeltaTable {
         self.table
             .get_or_try_init(|| async {
                 let sp = self.get_storage_params()?;
-                Self::load(sp).await
+                match self.meta.version {
+                    Some(version) => {
+                        let mut table = Self::build(sp)?;
+                        table.load_version(version).await.map_err(|err| {
+                            ErrorCode::ReadTableDataError(format!(
+                                "Delta table load version {version} failed: {err:?}"
+                            ))
+                        })?;
+                        Ok(table)
+                    }
+                    None => Self::load(sp).await,
+                }
             })
             .await
     }
 
+    /// Physical name of the column, same as the logical name if column mapping is disabled.
+    fn physical_name<'a>(&'a self, name: &'a str) -> &'a str {
+        self.meta
+            .column_mapping
+            .get(name)
+            .map(|c| c.name.as_str())
+            .unwrap_or(name)
+    }
+
+    /// The field as stored in parquet files, with the physical names of the column and of
+    /// its struct fields.
+    fn physical_field(&self, field: &TableField) -> TableField {
+        let mut field = field.clone();
+        if let Some(column) = self.meta.column_mapping.get(&field.name) {
+            field.name = column.name.clone();
+            field.data_type = physical_data_type(&field.data_type, &column.fields);
+        }
+        field
+    }
+
+    /// Rename the columns in filters to the physical names.
+    fn rename_push_downs(&self, mut push_downs: PushDownInfo) -> PushDownInfo {
+        if self.meta.column_mapping.is_empty() {
+            return push_downs;
+        }
+        let rename = |expr: &RemoteExpr<String>| {
+            expr.as_expr(&BUILTIN_FUNCTIONS)
+                .project_column_ref(|name| self.physical_name(name).to_string())
+                .as_remote_expr()
+        };
+        if let Some(filters) = push_downs.filters.as_mut() {
+            filters.filter = rename(&filters.filter);
+            filters.inverted_filter = rename(&filters.inverted_filter);
+        }
+        if let Some(prewhere) = push_downs.prewhere.as_mut() {
+            prewhere.filter = rename(&prewhere.filter);
+        }
+        for (expr, _, _) in push_downs.order_by.iter_mut() {
+            *expr = rename(expr);
+        }
+        push_downs
+    }
+
+    #[async_backtrace::framed]
+    async fn do_navigate_to(&self, point: &NavigationPoint) -> Result<Arc<dyn Table>> {
+        let sp = self.get_storage_params()?;
+        let mut table = Self::build(sp)?;
+        let res = match point {
+            NavigationPoint::Version(version) => table.load_version(*version as i64).await,
+            NavigationPoint::TimePoint(time_point) => table.load_with_datetime(*time_point).await,
+            _ => {
+                return Err(ErrorCode::Unimplemented(
+                    "Delta table only supports time travel by VERSION or TIMESTAMP",
+                ));
+            }
+        };
+        res.map_err(|err| {
+            ErrorCode::TableHistoricalDataNotFound(format!(
+                "No historical data found at given point of delta table {}: {err:?}",
+                self.info.name
+            ))
+        })?;
+
+        let (schema, meta) = Self::build_meta(&table, Some(table.version()))?;
+        let mut info = self.info.clone();
+        info.meta.schema = Arc::new(schema);
+        info.meta.engine_options.insert(
+            OPT_KEY_ENGINE_META.to_string(),
+            serde_json::to_string(&meta)?,
+        );
+        Ok(Arc::new(Self {
+            info,
+            table: OnceCell::new_with(Some(table)),
+            meta,
+        }))
+    }
+
     pub fn do_read_data(
         &self,
         ctx: Arc<dyn TableContext>,
@@ -196,13 +348,18 @@ impl DeltaTable {
         let max_threads = std::cmp::min(parts_len, max_threads);
 
         let table_schema = self.schema();
+        // Columns are read from parquet files by the physical names.
         let non_partition_fields = table_schema
             .fields()
             .iter()
             .filter(|field| !self.meta.partition_columns.contains(&field.name))
-            .cloned()
+            .map(|field| self.physical_field(field))
             .collect();
         let table_schema = Arc::new(TableSchema::new(non_partition_fields));
+        let push_downs = plan
+            .push_downs
+            .as_ref()
+            .map(|p| self.rename_push_downs(p.clone()));
 
         let arrow_schema = table_schema.as_ref().into();
         let leaf_fields = Arc::new(table_schema.leaf_fields());
@@ -225,7 +382,7 @@ impl DeltaTable {
             ctx.get_function_context()?,
             table_schema.clone(),
             leaf_fields,
-            &plan.push_downs,
+            &push_downs,
             read_options,
             self.meta.partition_columns.clone(),
         )?;
@@ -239,16 +396,16 @@ impl DeltaTable {
             .map(|name| self.info.meta.schema.index_of(name))
             .collect();
         let partition_field_indexes = partition_field_indexes?;
-        let push_downs = if let Some(ref p) = plan.push_downs {
+        let push_downs = if let Some(p) = push_downs {
             Some(get_pushdown_without_partition_columns(
-                p.clone(),
+                p,
                 &partition_field_indexes[..],
             )?)
         } else {
             None
         };
         let mut builder =
-            ParquetRSReaderBuilder::create(ctx.clone(), op, table_schema, arrow_schema)?
+            ParquetRSReaderBuilder::create(ctx.clone(), op.clone(), table_schema, arrow_schema)?
                 .with_options(read_options)
                 .with_push_downs(push_downs.as_ref())
                 .with_pruner(Some(pruner))
@@ -261,9 +418,11 @@ impl DeltaTable {
             |output| {
                 DeltaTableSource::create(
                     ctx.clone(),
+                    plan,
                     output,
                     output_schema.clone(),
                     parquet_reader.clone(),
+                    op.clone(),
                     self.get_partition_fields()?,
                 )
             },
@@ -291,6 +450,25 @@ impl DeltaTable {
                 ErrorCode::ReadTableDataError(format!("Cannot read file_actions: {e:?}"))
             })?;
 
+        // Partition values are keyed by physical names, convert them to logical names.
+        if !self.meta.column_mapping.is_empty() {
+            let logical_names = self
+                .meta
+                .column_mapping
+                .iter()
+                .map(|(logical, physical)| (physical.name.as_str(), logical.as_str()))
+                .collect::<BTreeMap<_, _>>();
+            for add in adds.iter_mut() {
+                add.partition_values = std::mem::take(&mut add.partition_values)
+                    .into_iter()
+                    .map(|(name, value)| match logical_names.get(name.as_str()) {
+                        Some(logical) => (logical.to_string(), value),
+                        None => (name, value),
+                    })
+                    .collect();
+            }
+        }
+
         let filter_expression = push_downs.as_ref().and_then(|p| {
             p.filters
                 .as_ref()
@@ -312,13 +490,30 @@ impl DeltaTable {
             }
         }
 
+        // Prune data files by the column statistics in `Add`.
+        if let Some(expr) = &filter_expression {
+            let schema = self.schema();
+            let stats_fields = schema
+                .fields()
+                .iter()
+                .filter(|field| !self.meta.partition_columns.contains(&field.name))
+                .map(|field| (field.clone(), self.physical_name(&field.name).to_string()))
+                .collect::<Vec<_>>();
+            let range_pruner =
+                RangePrunerCreator::try_create(ctx.get_function_context()?, &schema, Some(expr))?;
+            adds.retain(|add| match collect_file_stats(add, &stats_fields) {
+                Some(stats) => range_pruner.should_keep(&stats, None),
+                None => true,
+            });
+        }
+
         #[derive(serde::Deserialize)]
         struct Stats {
             #[serde(rename = "numRecords")]
             pub num_records: i64,
         }
 
-        let parts = adds.iter()
+        let files = adds.iter()
             .map(|add: &Add| {
                 let num_records = add
                     .get_stats_parsed()
@@ -332,23 +527,57 @@ impl DeltaTable {
                         _ => None,
                     }
                     ).unwrap_or(1);
-                read_rows += num_records as usize;
+                let deletion_vector = add
+                    .deletion_vector
+                    .as_ref()
+                    .map(DeltaDeletionVector::try_create)
+                    .transpose()?;
+                let num_deleted = deletion_vector.as_ref().map_or(0, |dv| dv.cardinality);
+                read_rows += (num_records - num_deleted).max(0) as usize;
                 read_bytes += add.size as usize;
                 let partition_values = get_partition_values(add, &partition_fields)?;
-                Ok(Arc::new(Box::new(DeltaPartInfo {
+                Ok(DeltaPartInfo {
                         partition_values,
+                        deletion_vector,
                         data: ParquetPart::ParquetFiles(
                             ParquetFilesPart {
                                 files: vec![(add.path.clone(), add.size as u64)],
                                 estimated_uncompressed_size: add.size as u64, // This field is not used here.
                             },
                         ),
-                    }) as _))
+                    })
             })
             .collect::<Result<Vec<_>>>()?;
 
+        let scanned_files = files.len();
+        let parts: Vec<PartInfoPtr> = if partition_fields.is_empty() {
+            files
+                .into_iter()
+                .map(|part| Arc::new(Box::new(part) as Box<dyn PartInfo>))
+                .collect()
+        } else {
+            // The files are grouped by partition, so the runtime filters of the join
+            // eliminate a partition before any of its files is read.
+            let mut partitions = BTreeMap::<Vec<Scalar>, Vec<DeltaPartInfo>>::new();
+            for part in files {
+                partitions
+                    .entry(part.partition_values.clone())
+                    .or_default()
+                    .push(part);
+            }
+            partitions
+                .into_iter()
+                .map(|(partition_values, files)| {
+                    Arc::new(Box::new(DeltaPartitionPartInfo {
+                        partition_values,
+                        files,
+                    }) as Box<dyn PartInfo>)
+                })
+                .collect()
+        };
+
         Ok((
-            PartStatistics::new_estimated(None, read_rows, read_bytes, parts.len(), total_files),
+            PartStatistics::new_estimated(None, read_rows, read_bytes, scanned_files, total_files),
             Partitions::create(PartitionsShuffleKind::Mod, parts),
         ))
     }
@@ -412,6 +641,24 @@ impl Table for DeltaTable {
     fn support_prewhere(&self) -> bool {
         true
     }
+
+    fn support_runtime_filter_partition_pruning(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn navigate_to(
+        &self,
+        navigation: &TimeNavigation,
+        _abort_checker: AbortChecker,
+    ) -> Result<Arc<dyn Table>> {
+        match navigation {
+            TimeNavigation::TimeTravel(point) => self.do_navigate_to(point).await,
+            TimeNavigation::Changes { .. } => Err(ErrorCode::Unimplemented(
+                "Changes query is not supported for delta table",
+            )),
+        }
+    }
 }
 
 pub fn get_partition_values(add: &Add, fields: &[TableField]) -> Result<Vec<Scalar>> {
@@ -430,3 +677,81 @@ pub fn get_partition_values(add: &Add, fields: &[TableField]) -> Result<Vec<Scal
     }
     Ok(values)
 }
+
+/// Collect the physical names of the fields of a delta struct type.
+fn physical_columns(struct_type: &Value) -> Result<BTreeMap<String, PhysicalColumn>> {
+    let mut columns = BTreeMap::new();
+    for field in struct_type["fields"].as_array().into_iter().flatten() {
+        let (Some(name), Some(physical_name)) = (
+            field["name"].as_str(),
+            field["metadata"]["delta.columnMapping.physicalName"].as_str(),
+        ) else {
+            continue;
+        };
+        columns.insert(name.to_string(), PhysicalColumn {
+            name: physical_name.to_string(),
+            fields: nested_physical_columns(name, &field["type"])?,
+        });
+    }
+    Ok(columns)
+}
+
+fn nested_physical_columns(
+    name: &str,
+    data_type: &Value,
+) -> Result<BTreeMap<String, PhysicalColumn>> {
+    // Primitive types are plain strings, which have no `type`.
+    match data_type["type"].as_str() {
+        Some("struct") => physical_columns(data_type),
+        Some("array") => nested_physical_columns(name, &data_type["elementType"]),
+        Some("map") if contains_struct(data_type) => Err(ErrorCode::Unimplemented(format!(
+            "Delta column mapping of structs in map column '{name}' is not supported"
+        ))),
+        _ => Ok(BTreeMap::new()),
+    }
+}
+
+fn contains_struct(data_type: &Value) -> bool {
+    match data_type["type"].as_str() {
+        Some("struct") => true,
+        Some("array") => contains_struct(&data_type["elementType"]),
+        Some("map") => {
+            contains_struct(&data_type["keyType"]) || contains_struct(&data_type["valueType"])
+        }
+        _ => false,
+    }
+}
+
+/// Rename the fields of tuples to the physical names.
+fn physical_data_type(
+    data_type: &TableDataType,
+    fields: &BTreeMap<String, PhysicalColumn>,
+) -> TableDataType {
+    if fields.is_empty() {
+        return data_type.clone();
+    }
+    match data_type {
+        TableDataType::Nullable(ty) => {
+            TableDataType::Nullable(Box::new(physical_data_type(ty, fields)))
+        }
+        TableDataType::Array(ty) => TableDataType::Array(Box::new(physical_data_type(ty, fields))),
+        TableDataType::Tuple {
+            fields_name,
+            fields_type,
+        } => {
+            let (fields_name, fields_type) = fields_name
+                .iter()
+                .zip(fields_type)
+                .map(|(name, ty)| match fields.get(name) {
+                    Some(column) => (column.name.clone(), physical_data_type(ty, &column.fields)),
+                    None => (name.clone(), ty.clone()),
+                })
+                .unzip();
+            TableDataType::Tuple {
+                fields_name,
+                fields_type,
+            }
+        }
+        _ => data_type.clone(),
+    }
+}
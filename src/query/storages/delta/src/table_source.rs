@@ -19,6 +19,8 @@ use databend_common_base::base::Progress;
 use databend_common_base::base::ProgressValues;
 use databend_common_base::runtime::profile::Profile;
 use databend_common_base::runtime::profile::ProfileStatisticsName;
+use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::runtime_filter_info::RuntimeFilterReady;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -27,6 +29,7 @@ use databend_common_expression::DataBlock;
 use databend_common_expression::DataSchema;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::FieldIndex;
+use databend_common_expression::FunctionContext;
 use databend_common_expression::TableField;
 use databend_common_expression::Value;
 use databend_common_pipeline_core::processors::Event;
@@ -36,9 +39,12 @@ use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_storages_parquet::ParquetFileReader;
 use databend_common_storages_parquet::ParquetPart;
 use databend_common_storages_parquet::ParquetRSFullReader;
+use databend_storages_common_pruner::partition_prunner::prune_partition_by_runtime_filters;
+use opendal::Operator;
 use parquet::arrow::async_reader::ParquetRecordBatchStream;
 
 use crate::partition::DeltaPartInfo;
+use crate::partition::DeltaPartitionPartInfo;
 
 pub type PartitionColumnIndex = usize;
 
@@ -50,9 +56,18 @@ pub struct DeltaTableSource {
     scan_progress: Arc<Progress>,
     // Used for get partition
     ctx: Arc<dyn TableContext>,
+    // Used for get runtime filters of the scan
+    table_index: usize,
+    func_ctx: FunctionContext,
+    // The partitions are eliminated after the runtime filters are ready.
+    runtime_filter_ready: Vec<Arc<RuntimeFilterReady>>,
+    // The files of the current partition.
+    files: Vec<DeltaPartInfo>,
 
     // Used to read parquet file.
     parquet_reader: Arc<ParquetRSFullReader>,
+    // Used to read deletion vectors.
+    op: Operator,
 
     // Used to insert partition_block_entries to data block
     // FieldIndex is the index in the output_schema
@@ -71,9 +86,11 @@ pub struct DeltaTableSource {
 impl DeltaTableSource {
     pub fn create(
         ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
         output: Arc<OutputPort>,
         output_schema: DataSchemaRef,
         parquet_reader: Arc<ParquetRSFullReader>,
+        op: Operator,
         partition_fields: Vec<TableField>,
     ) -> Result<ProcessorPtr> {
         let output_partition_columns = output_schema
@@ -88,11 +105,18 @@ impl DeltaTableSource {
             })
             .collect();
         let scan_progress = ctx.get_scan_progress();
+        let func_ctx = ctx.get_function_context()?;
+        let runtime_filter_ready = ctx.get_runtime_filter_ready(plan.scan_id);
         Ok(ProcessorPtr::create(Box::new(DeltaTableSource {
             output,
             scan_progress,
             ctx,
+            table_index: plan.table_index,
+            func_ctx,
+            runtime_filter_ready,
+            files: vec![],
             parquet_reader,
+            op,
             output_schema,
             partition_fields,
             output_partition_columns,
@@ -102,6 +126,73 @@ impl DeltaTableSource {
             partition_block_entries: vec![],
         })))
     }
+
+    // Dynamic partition elimination, the files of the partition are skipped if its values
+    // are out of the key domain of the build side of the join.
+    fn prune_by_runtime_filters(&self, part: &DeltaPartitionPartInfo) -> bool {
+        let mut filters = self.ctx.get_inlist_runtime_filter_with_id(self.table_index);
+        filters.extend(
+            self.ctx
+                .get_min_max_runtime_filter_with_id(self.table_index),
+        );
+        if filters.is_empty() {
+            return false;
+        }
+        let pruned = prune_partition_by_runtime_filters(
+            &self.func_ctx,
+            &filters,
+            &self.partition_fields,
+            &part.partition_values,
+        );
+        if pruned {
+            Profile::record_usize_profile(ProfileStatisticsName::RuntimeFilterPruneParts, 1);
+        }
+        pruned
+    }
+
+    async fn read_partition(&mut self, part: &DeltaPartitionPartInfo) -> Result<()> {
+        for ready in std::mem::take(&mut self.runtime_filter_ready) {
+            ready.wait().await?;
+        }
+        if !self.prune_by_runtime_filters(part) {
+            self.files = part.files.clone();
+        }
+        Ok(())
+    }
+
+    async fn read_file(&mut self, part: &DeltaPartInfo) -> Result<()> {
+        match &part.data {
+            ParquetPart::ParquetFiles(files) => {
+                assert_eq!(files.files.len(), 1);
+                let partition_fields = self
+                    .partition_fields
+                    .iter()
+                    .cloned()
+                    .zip(part.partition_values.iter().cloned())
+                    .collect::<Vec<_>>();
+                self.partition_block_entries = partition_fields
+                    .iter()
+                    .map(|(f, v)| BlockEntry::new(f.data_type().into(), Value::Scalar(v.clone())))
+                    .collect::<Vec<_>>();
+                let deleted_rows = match &part.deletion_vector {
+                    Some(dv) => Some(dv.read(&self.op).await?),
+                    None => None,
+                };
+                let stream = self
+                    .parquet_reader
+                    .prepare_data_stream(
+                        &files.files[0].0,
+                        files.files[0].1,
+                        Some(&partition_fields),
+                        deleted_rows.as_deref(),
+                    )
+                    .await?;
+                self.stream = Some(stream);
+            }
+            _ => unreachable!(),
+        }
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -169,34 +260,13 @@ impl Processor for DeltaTableSource {
             // else:
             // If `read_block` returns `None`, it means the stream is finished.
             // And we should try to build another stream (in next event loop).
+        } else if let Some(part) = self.files.pop() {
+            self.read_file(&part).await?;
         } else if let Some(part) = self.ctx.get_partition() {
-            let part = DeltaPartInfo::from_part(&part)?;
-            match &part.data {
-                ParquetPart::ParquetFiles(files) => {
-                    assert_eq!(files.files.len(), 1);
-                    let partition_fields = self
-                        .partition_fields
-                        .iter()
-                        .cloned()
-                        .zip(part.partition_values.iter().cloned())
-                        .collect::<Vec<_>>();
-                    self.partition_block_entries = partition_fields
-                        .iter()
-                        .map(|(f, v)| {
-                            BlockEntry::new(f.data_type().into(), Value::Scalar(v.clone()))
-                        })
-                        .collect::<Vec<_>>();
-                    let stream = self
-                        .parquet_reader
-                        .prepare_data_stream(
-                            &files.files[0].0,
-                            files.files[0].1,
-                            Some(&partition_fields),
-                        )
-                        .await?;
-                    self.stream = Some(stream);
-                }
-                _ => unreachable!(),
+            if let Some(part) = part.as_any().downcast_ref::<DeltaPartitionPartInfo>() {
+                self.read_partition(part).await?;
+            } else {
+                self.read_file(DeltaPartInfo::from_part(&part)?).await?;
             }
         } else {
             self.is_finished = true;
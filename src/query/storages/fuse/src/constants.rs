@@ -19,6 +19,8 @@ pub const FUSE_OPT_KEY_ROW_PER_PAGE: &str = "row_per_page";
 pub const FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD: &str = "row_avg_depth_threshold";
 
 pub const FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS: &str = "data_retention_period_in_hours";
+pub const FUSE_OPT_KEY_ICEBERG_EXPORT: &str = "iceberg_export";
+pub const FUSE_OPT_KEY_AUTO_VIRTUAL_COLUMNS: &str = "auto_virtual_columns";
 
 pub const FUSE_TBL_BLOCK_PREFIX: &str = "_b";
 pub const FUSE_TBL_BLOCK_INDEX_PREFIX: &str = "_i";
@@ -30,6 +32,10 @@ pub const FUSE_TBL_LAST_SNAPSHOT_HINT: &str = "last_snapshot_location_hint";
 pub const FUSE_TBL_VIRTUAL_BLOCK_PREFIX: &str = "_vb";
 pub const FUSE_TBL_AGG_INDEX_PREFIX: &str = "_i_a";
 pub const FUSE_TBL_INVERTED_INDEX_PREFIX: &str = "_i_i";
+pub const FUSE_TBL_VECTOR_INDEX_PREFIX: &str = "_i_v";
+// Iceberg (hadoop table layout) metadata of the table, written if `iceberg_export` is enabled.
+pub const FUSE_TBL_ICEBERG_METADATA_PREFIX: &str = "metadata";
+pub const FUSE_TBL_ICEBERG_VERSION_HINT: &str = "version-hint.text";
 
 pub const DEFAULT_BLOCK_PER_SEGMENT: usize = 1000;
 pub const DEFAULT_ROW_PER_PAGE: usize = 131072;
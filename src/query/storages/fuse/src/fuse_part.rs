@@ -48,6 +48,9 @@ pub struct FuseBlockPartInfo {
 
     pub sort_min_max: Option<(Scalar, Scalar)>,
     pub block_meta_index: Option<BlockMetaIndex>,
+
+    pub bloom_filter_index_location: Option<Location>,
+    pub bloom_filter_index_size: u64,
 }
 
 #[typetag::serde(name = "fuse")]
@@ -84,6 +87,8 @@ impl FuseBlockPartInfo {
         sort_min_max: Option<(Scalar, Scalar)>,
         block_meta_index: Option<BlockMetaIndex>,
         create_on: Option<DateTime<Utc>>,
+        bloom_filter_index_location: Option<Location>,
+        bloom_filter_index_size: u64,
     ) -> Arc<Box<dyn PartInfo>> {
         Arc::new(Box::new(FuseBlockPartInfo {
             location,
@@ -94,6 +99,8 @@ impl FuseBlockPartInfo {
             sort_min_max,
             block_meta_index,
             columns_stat,
+            bloom_filter_index_location,
+            bloom_filter_index_size,
         }))
     }
 
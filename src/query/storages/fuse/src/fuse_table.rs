@@ -71,6 +71,7 @@ use databend_common_storage::DataOperator;
 use databend_common_storage::StorageMetrics;
 use databend_common_storage::StorageMetricsLayer;
 use databend_storages_common_cache::LoadParams;
+use databend_storages_common_index::filters::DEFAULT_SET_FILTER_MAX_VALUES;
 use databend_storages_common_io::Files;
 use databend_storages_common_table_meta::meta::parse_storage_prefix;
 use databend_storages_common_table_meta::meta::ClusterKey;
@@ -86,6 +87,9 @@ use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
 use databend_storages_common_table_meta::table::OPT_KEY_CLUSTER_TYPE;
 use databend_storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use databend_storages_common_table_meta::table::OPT_KEY_NGRAM_INDEX_COLUMNS;
+use databend_storages_common_table_meta::table::OPT_KEY_SET_INDEX_COLUMNS;
+use databend_storages_common_table_meta::table::OPT_KEY_SET_INDEX_MAX_VALUES;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION_FIXED_FLAG;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
@@ -131,6 +135,8 @@ pub struct FuseTable {
     pub(crate) storage_format: FuseStorageFormat,
     pub(crate) table_compression: TableCompression,
     pub(crate) bloom_index_cols: BloomIndexColumns,
+    pub(crate) ngram_index_cols: BloomIndexColumns,
+    pub(crate) set_index_cols: BloomIndexColumns,
 
     pub(crate) operator: Operator,
     pub(crate) data_metrics: Arc<StorageMetrics>,
@@ -220,6 +226,18 @@ impl FuseTable {
             .and_then(|s| s.parse::<BloomIndexColumns>().ok())
             .unwrap_or(BloomIndexColumns::All);
 
+        let ngram_index_cols = table_info
+            .options()
+            .get(OPT_KEY_NGRAM_INDEX_COLUMNS)
+            .and_then(|s| s.parse::<BloomIndexColumns>().ok())
+            .unwrap_or(BloomIndexColumns::None);
+
+        let set_index_cols = table_info
+            .options()
+            .get(OPT_KEY_SET_INDEX_COLUMNS)
+            .and_then(|s| s.parse::<BloomIndexColumns>().ok())
+            .unwrap_or(BloomIndexColumns::None);
+
         if !table_info.meta.part_prefix.is_empty() {
             return Err(ErrorCode::StorageOther(
                 "Location_prefix no longer supported. The last version that supports it is: https://github.com/databendlabs/databend/releases/tag/v1.2.653-nightly",
@@ -233,6 +251,8 @@ impl FuseTable {
             meta_location_generator,
             cluster_key_meta,
             bloom_index_cols,
+            ngram_index_cols,
+            set_index_cols,
             operator,
             data_metrics,
             storage_format: FuseStorageFormat::from_str(storage_format.as_str())?,
@@ -430,6 +450,18 @@ impl FuseTable {
         self.bloom_index_cols.clone()
     }
 
+    pub fn ngram_index_cols(&self) -> BloomIndexColumns {
+        self.ngram_index_cols.clone()
+    }
+
+    pub fn set_index_cols(&self) -> BloomIndexColumns {
+        self.set_index_cols.clone()
+    }
+
+    pub fn set_index_max_values(&self) -> usize {
+        self.get_option(OPT_KEY_SET_INDEX_MAX_VALUES, DEFAULT_SET_FILTER_MAX_VALUES)
+    }
+
     // Check if table is attached.
     pub fn is_table_attached(table_meta_options: &BTreeMap<String, String>) -> bool {
         table_meta_options
@@ -823,6 +855,7 @@ impl Table for FuseTable {
                 FuseTableColumnStatisticsProvider::new(
                     stats.clone(),
                     table_statistics.histograms.clone(),
+                    table_statistics.multi_column_stats.clone(),
                     Some(table_statistics.column_distinct_values()),
                     snapshot.summary.row_count,
                 )
@@ -830,6 +863,7 @@ impl Table for FuseTable {
                 FuseTableColumnStatisticsProvider::new(
                     stats.clone(),
                     HashMap::new(),
+                    vec![],
                     None,
                     snapshot.summary.row_count,
                 )
@@ -1044,6 +1078,10 @@ impl Table for FuseTable {
         true
     }
 
+    fn auto_virtual_columns_enabled(&self) -> bool {
+        FuseTable::is_auto_virtual_columns_enabled(self.table_info.options())
+    }
+
     fn result_can_be_cached(&self) -> bool {
         true
     }
@@ -1089,18 +1127,25 @@ impl Table for FuseTable {
         index_name: String,
         index_version: String,
     ) -> Result<u64> {
-        let prefix = self
-            .meta_location_generator
-            .gen_specific_inverted_index_prefix(&index_name, &index_version);
+        // Inverted and vector indexes share the names of table indexes,
+        // the files of a dropped index are under the prefix of its type.
+        let prefixes = [
+            self.meta_location_generator
+                .gen_specific_inverted_index_prefix(&index_name, &index_version),
+            self.meta_location_generator
+                .gen_specific_vector_index_prefix(&index_name, &index_version),
+        ];
         let op = &self.operator;
-        info!("remove_inverted_index_files: {}", prefix);
-        let mut lister = op.lister_with(&prefix).recursive(true).await?;
         let mut files = Vec::new();
-        while let Some(entry) = lister.try_next().await? {
-            if entry.metadata().is_dir() {
-                continue;
+        for prefix in prefixes {
+            info!("remove_inverted_index_files: {}", prefix);
+            let mut lister = op.lister_with(&prefix).recursive(true).await?;
+            while let Some(entry) = lister.try_next().await? {
+                if entry.metadata().is_dir() {
+                    continue;
+                }
+                files.push(entry.path().to_string());
             }
-            files.push(entry.path().to_string());
         }
         let op = Files::create(ctx, self.operator.clone());
         let len = files.len() as u64;
@@ -33,9 +33,13 @@ use crate::constants::FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX;
 use crate::constants::FUSE_TBL_VIRTUAL_BLOCK_PREFIX;
 use crate::index::filters::BlockFilter;
 use crate::index::InvertedIndexFile;
+use crate::index::VectorIndex;
 use crate::FUSE_TBL_AGG_INDEX_PREFIX;
+use crate::FUSE_TBL_ICEBERG_METADATA_PREFIX;
+use crate::FUSE_TBL_ICEBERG_VERSION_HINT;
 use crate::FUSE_TBL_INVERTED_INDEX_PREFIX;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
+use crate::FUSE_TBL_VECTOR_INDEX_PREFIX;
 use crate::FUSE_TBL_XOR_BLOOM_INDEX_PREFIX;
 static SNAPSHOT_V0: SnapshotVersion = SnapshotVersion::V0(PhantomData);
 static SNAPSHOT_V1: SnapshotVersion = SnapshotVersion::V1(PhantomData);
@@ -60,6 +64,7 @@ pub struct TableMetaLocationGenerator {
     bloom_index_location_prefix: String,
     agg_index_location_prefix: String,
     inverted_index_location_prefix: String,
+    vector_index_location_prefix: String,
 }
 
 impl TableMetaLocationGenerator {
@@ -71,6 +76,7 @@ impl TableMetaLocationGenerator {
         let agg_index_location_prefix = format!("{}/{}/", &prefix, FUSE_TBL_AGG_INDEX_PREFIX);
         let inverted_index_location_prefix =
             format!("{}/{}/", &prefix, FUSE_TBL_INVERTED_INDEX_PREFIX);
+        let vector_index_location_prefix = format!("{}/{}/", &prefix, FUSE_TBL_VECTOR_INDEX_PREFIX);
         Self {
             prefix,
             block_location_prefix,
@@ -78,6 +84,7 @@ impl TableMetaLocationGenerator {
             bloom_index_location_prefix,
             agg_index_location_prefix,
             inverted_index_location_prefix,
+            vector_index_location_prefix,
         }
     }
 
@@ -163,6 +170,51 @@ impl TableMetaLocationGenerator {
         format!("{}/{}", &self.prefix, FUSE_TBL_LAST_SNAPSHOT_HINT)
     }
 
+    pub fn iceberg_metadata_location_prefix(&self) -> String {
+        format!("{}/{}/", &self.prefix, FUSE_TBL_ICEBERG_METADATA_PREFIX)
+    }
+
+    pub fn gen_iceberg_version_hint_location(&self) -> String {
+        format!(
+            "{}{}",
+            self.iceberg_metadata_location_prefix(),
+            FUSE_TBL_ICEBERG_VERSION_HINT
+        )
+    }
+
+    pub fn gen_iceberg_metadata_location(&self, version: u64) -> String {
+        format!(
+            "{}v{}.metadata.json",
+            self.iceberg_metadata_location_prefix(),
+            version
+        )
+    }
+
+    // The manifest listing the blocks of a segment is named after the segment.
+    pub fn gen_iceberg_manifest_location(&self, segment_location: &str) -> String {
+        let segment_name = segment_location
+            .rsplit('/')
+            .next()
+            .unwrap_or(segment_location);
+        let segment_name = segment_name
+            .split_once('.')
+            .map_or(segment_name, |(name, _)| name);
+        format!(
+            "{}{}-m0.avro",
+            self.iceberg_metadata_location_prefix(),
+            segment_name
+        )
+    }
+
+    pub fn gen_iceberg_manifest_list_location(&self, snapshot_id: i64, id: &Uuid) -> String {
+        format!(
+            "{}snap-{}-1-{}.avro",
+            self.iceberg_metadata_location_prefix(),
+            snapshot_id,
+            id.as_simple()
+        )
+    }
+
     pub fn gen_virtual_block_location(location: &str) -> String {
         location.replace(FUSE_TBL_BLOCK_PREFIX, FUSE_TBL_VIRTUAL_BLOCK_PREFIX)
     }
@@ -213,6 +265,24 @@ impl TableMetaLocationGenerator {
         )
     }
 
+    pub fn vector_index_location_prefix(&self) -> &str {
+        &self.vector_index_location_prefix
+    }
+
+    pub fn gen_specific_vector_index_prefix(
+        &self,
+        index_name: &str,
+        index_version: &str,
+    ) -> String {
+        let short_ver: String = index_version.chars().take(7).collect();
+        format!(
+            "{}/{}/{}",
+            self.vector_index_location_prefix(),
+            index_name,
+            short_ver,
+        )
+    }
+
     pub fn gen_inverted_index_location_from_block_location(
         loc: &str,
         index_name: &str,
@@ -234,6 +304,28 @@ impl TableMetaLocationGenerator {
             InvertedIndexFile::VERSION,
         )
     }
+
+    pub fn gen_vector_index_location_from_block_location(
+        loc: &str,
+        index_name: &str,
+        index_version: &str,
+    ) -> String {
+        let splits = loc.split('/').collect::<Vec<_>>();
+        let len = splits.len();
+        let prefix = splits[..len - 2].join("/");
+        let block_name = trim_vacuum2_object_prefix(splits[len - 1]);
+        let id: String = block_name.chars().take(32).collect();
+        let short_ver: String = index_version.chars().take(7).collect();
+        format!(
+            "{}/{}/{}/{}/{}_v{}.index",
+            prefix,
+            FUSE_TBL_VECTOR_INDEX_PREFIX,
+            index_name,
+            short_ver,
+            id,
+            VectorIndex::VERSION,
+        )
+    }
 }
 
 trait SnapshotLocationCreator {
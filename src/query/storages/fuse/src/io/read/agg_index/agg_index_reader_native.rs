@@ -63,6 +63,8 @@ impl AggIndexReader {
                     None,
                     None,
                     None,
+                    None,
+                    0,
                 );
                 let res = self
                     .reader
@@ -118,6 +120,8 @@ impl AggIndexReader {
                     None,
                     None,
                     None,
+                    None,
+                    0,
                 );
                 let res = self
                     .reader
@@ -48,6 +48,8 @@ impl AggIndexReader {
                     None,
                     None,
                     None,
+                    None,
+                    0,
                 );
                 let res = self
                     .reader
@@ -95,6 +97,8 @@ impl AggIndexReader {
                     None,
                     None,
                     None,
+                    None,
+                    0,
                 );
                 Some((part, res))
             }
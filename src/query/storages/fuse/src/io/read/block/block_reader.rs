@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use arrow_schema::Field;
@@ -28,11 +29,13 @@ use databend_common_expression::DataField;
 use databend_common_expression::DataSchema;
 use databend_common_expression::FieldIndex;
 use databend_common_expression::Scalar;
+use databend_common_expression::TableField;
 use databend_common_expression::TableSchemaRef;
 use databend_common_native::read::NativeColumnsReader;
 use databend_common_sql::field_default_value;
 use databend_common_storage::ColumnNode;
 use databend_common_storage::ColumnNodes;
+use databend_storages_common_table_meta::meta::ColumnMeta;
 use opendal::Operator;
 
 use crate::BlockReadResult;
@@ -157,6 +160,39 @@ impl BlockReader {
         }))
     }
 
+    /// Returns the meta of the column chunk holding the data of `column_id` in a block.
+    ///
+    /// A block written before the data type of the column is widened stores it under a
+    /// previous column id, see `TableSchema::widened_columns`.
+    pub(crate) fn stored_column_meta<'a>(
+        &self,
+        column_id: &ColumnId,
+        columns_meta: &'a HashMap<ColumnId, ColumnMeta>,
+    ) -> Option<&'a ColumnMeta> {
+        columns_meta.get(column_id).or_else(|| {
+            self.original_schema
+                .widened_column_sources(*column_id)
+                .iter()
+                .find_map(|source| columns_meta.get(&source.column_id))
+        })
+    }
+
+    /// Returns the field that a column is stored as, if the block was written before
+    /// the data type of the column is widened.
+    pub(crate) fn stored_widened_field(
+        &self,
+        column_id: &ColumnId,
+        columns_meta: &HashMap<ColumnId, ColumnMeta>,
+    ) -> Option<&TableField> {
+        if columns_meta.contains_key(column_id) {
+            return None;
+        }
+        self.original_schema
+            .widened_column_sources(*column_id)
+            .into_iter()
+            .find(|source| columns_meta.contains_key(&source.column_id))
+    }
+
     pub fn support_blocking_api(&self) -> bool {
         self.operator.info().native_capability().blocking
     }
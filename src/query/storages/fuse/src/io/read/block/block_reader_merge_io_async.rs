@@ -58,7 +58,7 @@ impl BlockReader {
                 }
             }
 
-            if let Some(column_meta) = columns_meta.get(column_id) {
+            if let Some(column_meta) = self.stored_column_meta(column_id, columns_meta) {
                 let (offset, len) = column_meta.offset_length();
 
                 let column_cache_key = column_cache_key_builder.cache_key(column_id, column_meta);
@@ -105,7 +105,7 @@ impl BlockReader {
                 // This can lead to cache missing or INCONSISTENCIES
 
                 // Safe to unwrap here, since this column has been fetched, its meta must be present.
-                let column_meta = columns_meta.get(column_id).unwrap();
+                let column_meta = self.stored_column_meta(column_id, columns_meta).unwrap();
                 let column_cache_key = column_cache_key_builder.cache_key(column_id, column_meta);
 
                 let chunk_data = merge_io_result
@@ -47,7 +47,7 @@ impl BlockReader {
             }
             let block_path = &part.location;
 
-            if let Some(column_meta) = part.columns_meta.get(column_id) {
+            if let Some(column_meta) = self.stored_column_meta(column_id, &part.columns_meta) {
                 // first, check column array object cache
                 let (offset, len) = column_meta.offset_length();
                 let column_cache_key = TableDataCacheKey::new(block_path, *column_id, offset, len);
@@ -13,10 +13,13 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 
+use arrow::compute::cast;
 use arrow_array::ArrayRef;
 use arrow_array::RecordBatch;
 use arrow_array::StructArray;
+use arrow_schema::Field;
 use databend_common_catalog::plan::Projection;
 use databend_common_exception::ErrorCode;
 use databend_common_expression::BlockEntry;
@@ -53,8 +56,28 @@ impl BlockReader {
         if column_chunks.is_empty() {
             return self.build_default_values_block(num_rows);
         }
+        // Blocks written before a column is widened store it with the previous data type,
+        // decode them as stored and cast the arrays afterwards.
+        let mut widened_columns = HashSet::new();
+        let mut stored_schema = None;
+        for field in self.projected_schema.fields.iter() {
+            if let Some(stored_field) = self.stored_widened_field(&field.column_id, column_metas) {
+                let schema =
+                    stored_schema.get_or_insert_with(|| self.original_schema.as_ref().clone());
+                if let Some(f) = schema
+                    .fields
+                    .iter_mut()
+                    .find(|f| f.column_id == field.column_id)
+                {
+                    f.data_type = stored_field.data_type.clone();
+                    widened_columns.insert(field.column_id);
+                }
+            }
+        }
         let record_batch = column_chunks_to_record_batch(
-            &self.original_schema,
+            stored_schema
+                .as_ref()
+                .unwrap_or(self.original_schema.as_ref()),
             num_rows,
             &column_chunks,
             compression,
@@ -94,10 +117,16 @@ impl BlockReader {
             let value = match column_chunks.get(&field.column_id) {
                 Some(DataItem::RawData(data)) => {
                     // get the deserialized arrow array, which may be a nested array
-                    let arrow_array = column_by_name(&record_batch, &name_paths[i]);
+                    let mut arrow_array = column_by_name(&record_batch, &name_paths[i]);
+                    if widened_columns.contains(&field.column_id) {
+                        let arrow_field = Field::from(field);
+                        arrow_array = cast(&arrow_array, arrow_field.data_type())?;
+                    }
                     if !column_node.is_nested {
                         if let Some(cache) = &array_cache {
-                            let meta = column_metas.get(&field.column_id).unwrap();
+                            let meta = self
+                                .stored_column_meta(&field.column_id, column_metas)
+                                .unwrap();
                             let (offset, len) = meta.offset_length();
                             let key =
                                 TableDataCacheKey::new(block_path, field.column_id, offset, len);
@@ -29,7 +29,7 @@ use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
 use databend_storages_common_cache::LoadParams;
-use databend_storages_common_index::filters::Xor8Filter;
+use databend_storages_common_index::filters::FilterImpl;
 use databend_storages_common_index::BloomIndexMeta;
 use databend_storages_common_table_meta::meta::Location;
 use databend_storages_common_table_meta::meta::SingleColumnMeta;
@@ -116,9 +116,10 @@ async fn load_bloom_filter_by_columns<'a>(
 
     let futs = col_metas
         .iter()
-        .map(|(idx, (_, col_chunk_meta))| {
-            load_column_xor8_filter(
+        .map(|(idx, (name, col_chunk_meta))| {
+            load_column_filter(
                 *idx,
+                name,
                 col_chunk_meta,
                 index_path,
                 &dal,
@@ -146,18 +147,20 @@ async fn load_bloom_filter_by_columns<'a>(
 /// Loads bytes and index of the given column.
 /// read data from cache, or populate cache items if possible
 #[fastrace::trace]
-async fn load_column_xor8_filter<'a>(
+async fn load_column_filter<'a>(
     idx: ColumnId,
+    name: &'a str,
     col_chunk_meta: &'a SingleColumnMeta,
     index_path: &'a str,
     dal: &'a Operator,
     bloom_index_schema_desc: SchemaDescPtr,
-) -> Result<Arc<Xor8Filter>> {
+) -> Result<Arc<FilterImpl>> {
     let storage_runtime = GlobalIORuntime::instance();
     let bytes = {
         let column_data_reader = BloomColumnFilterReader::new(
             index_path.to_owned(),
             idx,
+            name,
             col_chunk_meta,
             dal.clone(),
             bloom_index_schema_desc,
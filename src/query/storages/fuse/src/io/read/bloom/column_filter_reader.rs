@@ -24,7 +24,10 @@ use databend_storages_common_cache::InMemoryCacheReader;
 use databend_storages_common_cache::LoadParams;
 use databend_storages_common_cache::Loader;
 use databend_storages_common_index::filters::Filter;
+use databend_storages_common_index::filters::FilterImpl;
+use databend_storages_common_index::filters::SetFilter;
 use databend_storages_common_index::filters::Xor8Filter;
+use databend_storages_common_index::BloomIndex;
 use databend_storages_common_table_meta::meta::SingleColumnMeta;
 use opendal::Operator;
 use parquet::arrow::arrow_reader::ParquetRecordBatchReader;
@@ -35,7 +38,7 @@ use parquet::schema::types::SchemaDescPtr;
 
 use crate::io::read::block::parquet::RowGroupImplBuilder;
 
-type CachedReader = InMemoryCacheReader<Xor8Filter, Xor8FilterLoader>;
+type CachedReader = InMemoryCacheReader<FilterImpl, FilterLoader>;
 
 /// Load the filter of a given bloom index column. Also
 /// - generates the proper cache key
@@ -49,6 +52,7 @@ impl BloomColumnFilterReader {
     pub fn new(
         index_path: String,
         column_id: ColumnId,
+        column_name: &str,
         column_chunk_meta: &SingleColumnMeta,
         operator: Operator,
         schema_desc: SchemaDescPtr,
@@ -61,8 +65,9 @@ impl BloomColumnFilterReader {
             num_values,
         } = column_chunk_meta;
 
-        let loader = Xor8FilterLoader {
+        let loader = FilterLoader {
             cache_key,
+            is_set_filter: BloomIndex::is_set_filter_column_name(column_name),
             operator,
             offset: *offset,
             len: *len,
@@ -71,7 +76,7 @@ impl BloomColumnFilterReader {
             column_id,
         };
 
-        let cached_reader = CachedReader::new(Xor8Filter::cache(), loader);
+        let cached_reader = CachedReader::new(FilterImpl::cache(), loader);
 
         let param = LoadParams {
             location: index_path,
@@ -87,13 +92,13 @@ impl BloomColumnFilterReader {
     }
 
     #[async_backtrace::framed]
-    pub async fn read(&self) -> Result<Arc<Xor8Filter>> {
+    pub async fn read(&self) -> Result<Arc<FilterImpl>> {
         self.cached_reader.read(&self.param).await
     }
 }
 
 /// Loader that fetch range of the target object with customized cache key
-pub struct Xor8FilterLoader {
+pub struct FilterLoader {
     pub offset: u64,
     pub len: u64,
     pub num_values: u64,
@@ -101,12 +106,14 @@ pub struct Xor8FilterLoader {
     pub column_id: u32,
     pub cache_key: String,
     pub operator: Operator,
+    /// The column is a set filter instead of a xor8 filter
+    pub is_set_filter: bool,
 }
 
 #[async_trait::async_trait]
-impl Loader<Xor8Filter> for Xor8FilterLoader {
+impl Loader<FilterImpl> for FilterLoader {
     #[async_backtrace::framed]
-    async fn load(&self, params: &LoadParams) -> Result<Xor8Filter> {
+    async fn load(&self, params: &LoadParams) -> Result<FilterImpl> {
         let bytes = self
             .operator
             .read_with(&params.location)
@@ -143,8 +150,11 @@ impl Loader<Xor8Filter> for Xor8FilterLoader {
             .index(0)
             .unwrap();
         metrics_inc_block_index_read_bytes(filter_bytes.len() as u64);
+        if self.is_set_filter {
+            return Ok(FilterImpl::Set(SetFilter::from_bytes(filter_bytes)?));
+        }
         let (filter, _size) = Xor8Filter::from_bytes(filter_bytes)?;
-        Ok(filter)
+        Ok(FilterImpl::Xor(filter))
     }
 
     fn cache_key(&self, _params: &LoadParams) -> CacheKey {
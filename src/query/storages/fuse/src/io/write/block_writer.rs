@@ -14,6 +14,7 @@
 
 use std::collections::BTreeMap;
 use std::collections::HashMap;
+use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -28,9 +29,12 @@ use databend_common_expression::DataField;
 use databend_common_expression::DataSchema;
 use databend_common_expression::FieldIndex;
 use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRef;
 use databend_common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use databend_common_io::constants::DEFAULT_BLOCK_INDEX_BUFFER_SIZE;
+use databend_common_meta_app::schema::TableIndex;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_metrics::storage::metrics_inc_block_index_write_milliseconds;
 use databend_common_metrics::storage::metrics_inc_block_index_write_nums;
@@ -38,20 +42,29 @@ use databend_common_metrics::storage::metrics_inc_block_inverted_index_generate_
 use databend_common_metrics::storage::metrics_inc_block_inverted_index_write_bytes;
 use databend_common_metrics::storage::metrics_inc_block_inverted_index_write_milliseconds;
 use databend_common_metrics::storage::metrics_inc_block_inverted_index_write_nums;
+use databend_common_metrics::storage::metrics_inc_block_virtual_column_write_bytes;
+use databend_common_metrics::storage::metrics_inc_block_virtual_column_write_milliseconds;
+use databend_common_metrics::storage::metrics_inc_block_virtual_column_write_nums;
 use databend_common_metrics::storage::metrics_inc_block_write_milliseconds;
 use databend_common_metrics::storage::metrics_inc_block_write_nums;
 use databend_common_native::write::NativeWriter;
 use databend_storages_common_blocks::blocks_to_parquet;
+use databend_storages_common_blocks::blocks_to_parquet_with_field_ids;
 use databend_storages_common_index::BloomIndex;
+use databend_storages_common_index::VectorDistanceMetric;
+use databend_storages_common_index::VectorIndex;
 use databend_storages_common_io::ReadSettings;
 use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::meta::ClusterStatistics;
 use databend_storages_common_table_meta::meta::ColumnMeta;
 use databend_storages_common_table_meta::meta::Location;
+use databend_storages_common_table_meta::table::iceberg_field_id;
 use databend_storages_common_table_meta::table::TableCompression;
 use opendal::Operator;
 
 use crate::io::block_to_inverted_index;
+use crate::io::write::VirtualColumnBuilder;
+use crate::io::write::VirtualColumnState;
 use crate::io::write::WriteSettings;
 use crate::io::BlockReader;
 use crate::io::InvertedIndexWriter;
@@ -70,8 +83,15 @@ pub fn serialize_block(
     let schema = Arc::new(schema.remove_virtual_computed_fields());
     match write_settings.storage_format {
         FuseStorageFormat::Parquet => {
-            let result =
-                blocks_to_parquet(&schema, vec![block], buf, write_settings.table_compression)?;
+            // the column ids are written as the field ids, so that the blocks can be read by
+            // column id out of databend, see `iceberg_export`.
+            let result = blocks_to_parquet_with_field_ids(
+                &schema,
+                vec![block],
+                buf,
+                write_settings.table_compression,
+                iceberg_field_id,
+            )?;
             let meta = column_parquet_metas(&result, &schema)?;
             Ok(meta)
         }
@@ -138,6 +158,9 @@ pub struct BloomIndexBuilder {
     pub table_dal: Operator,
     pub storage_format: FuseStorageFormat,
     pub bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+    pub ngram_columns_map: BTreeMap<FieldIndex, TableField>,
+    pub set_columns_map: BTreeMap<FieldIndex, TableField>,
+    pub set_max_values: usize,
 }
 
 impl BloomIndexBuilder {
@@ -151,6 +174,9 @@ impl BloomIndexBuilder {
             bloom_location.1,
             block,
             self.bloom_columns_map.clone(),
+            self.ngram_columns_map.clone(),
+            self.set_columns_map.clone(),
+            self.set_max_values,
         )?;
 
         match maybe_bloom_index {
@@ -223,6 +249,9 @@ impl BloomIndexState {
         block: &DataBlock,
         location: Location,
         bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+        ngram_columns_map: BTreeMap<FieldIndex, TableField>,
+        set_columns_map: BTreeMap<FieldIndex, TableField>,
+        set_max_values: usize,
     ) -> Result<Option<Self>> {
         // write index
         let maybe_bloom_index = BloomIndex::try_create(
@@ -230,6 +259,9 @@ impl BloomIndexState {
             location.1,
             block,
             bloom_columns_map,
+            ngram_columns_map,
+            set_columns_map,
+            set_max_values,
         )?;
         if let Some(bloom_index) = maybe_bloom_index {
             Ok(Some(Self::from_bloom_index(&bloom_index, location)?))
@@ -250,7 +282,7 @@ pub struct InvertedIndexBuilder {
 pub fn create_inverted_index_builders(table_meta: &TableMeta) -> Vec<InvertedIndexBuilder> {
     let mut inverted_index_builders = Vec::with_capacity(table_meta.indexes.len());
     for index in table_meta.indexes.values() {
-        if !index.sync_creation {
+        if !index.sync_creation || index.index_type != TableIndexType::Inverted {
             continue;
         }
         let mut index_fields = Vec::with_capacity(index.column_ids.len());
@@ -279,6 +311,52 @@ pub fn create_inverted_index_builders(table_meta: &TableMeta) -> Vec<InvertedInd
     inverted_index_builders
 }
 
+#[derive(Clone)]
+pub struct VectorIndexBuilder {
+    pub(crate) name: String,
+    pub(crate) version: String,
+    pub(crate) column_name: String,
+    pub(crate) metric: VectorDistanceMetric,
+    pub(crate) lists: Option<usize>,
+}
+
+impl VectorIndexBuilder {
+    // return None if the index is invalid, like the column is dropped.
+    pub fn create(index: &TableIndex, schema: &TableSchema) -> Option<Self> {
+        if index.index_type != TableIndexType::Vector || index.column_ids.len() != 1 {
+            return None;
+        }
+        let field = schema
+            .fields
+            .iter()
+            .find(|f| f.column_id() == index.column_ids[0])?;
+        let metric = match index.options.get("metric") {
+            Some(metric) => VectorDistanceMetric::from_str(metric).ok()?,
+            None => VectorDistanceMetric::Cosine,
+        };
+        let lists = match index.options.get("lists") {
+            Some(lists) => Some(lists.parse::<usize>().ok()?),
+            None => None,
+        };
+        Some(VectorIndexBuilder {
+            name: index.name.clone(),
+            version: index.version.clone(),
+            column_name: field.name().clone(),
+            metric,
+            lists,
+        })
+    }
+}
+
+pub fn create_vector_index_builders(table_meta: &TableMeta) -> Vec<VectorIndexBuilder> {
+    table_meta
+        .indexes
+        .values()
+        .filter(|index| index.sync_creation)
+        .filter_map(|index| VectorIndexBuilder::create(index, &table_meta.schema))
+        .collect()
+}
+
 pub struct InvertedIndexState {
     pub(crate) data: Vec<u8>,
     pub(crate) size: u64,
@@ -326,12 +404,64 @@ impl InvertedIndexState {
     }
 }
 
+pub struct VectorIndexState {
+    pub(crate) data: Vec<u8>,
+    pub(crate) size: u64,
+    pub(crate) location: Location,
+}
+
+impl VectorIndexState {
+    // return None if no vector in the block can be indexed.
+    pub fn try_create(
+        source_schema: &TableSchemaRef,
+        block: &DataBlock,
+        block_location: &Location,
+        vector_index_builder: &VectorIndexBuilder,
+    ) -> Result<Option<Self>> {
+        let start = Instant::now();
+        let field_index = source_schema.index_of(&vector_index_builder.column_name)?;
+        let column = block.get_by_offset(field_index).to_column(block.num_rows());
+        let Some(vector_index) = VectorIndex::try_create(
+            vector_index_builder.metric,
+            vector_index_builder.lists,
+            &column,
+        )?
+        else {
+            return Ok(None);
+        };
+        let data = vector_index.to_bytes();
+        let size = data.len() as u64;
+
+        // Perf.
+        {
+            metrics_inc_block_inverted_index_generate_milliseconds(
+                start.elapsed().as_millis() as u64
+            );
+        }
+
+        let vector_index_location =
+            TableMetaLocationGenerator::gen_vector_index_location_from_block_location(
+                &block_location.0,
+                &vector_index_builder.name,
+                &vector_index_builder.version,
+            );
+
+        Ok(Some(Self {
+            data,
+            size,
+            location: (vector_index_location, 0),
+        }))
+    }
+}
+
 pub struct BlockSerialization {
     pub block_raw_data: Vec<u8>,
     pub size: u64, // TODO redundancy
     pub block_meta: BlockMeta,
     pub bloom_index_state: Option<BloomIndexState>,
     pub inverted_index_states: Vec<InvertedIndexState>,
+    pub vector_index_states: Vec<VectorIndexState>,
+    pub virtual_column_state: Option<VirtualColumnState>,
 }
 
 #[derive(Clone)]
@@ -342,7 +472,12 @@ pub struct BlockBuilder {
     pub write_settings: WriteSettings,
     pub cluster_stats_gen: ClusterStatsGenerator,
     pub bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+    pub ngram_columns_map: BTreeMap<FieldIndex, TableField>,
+    pub set_columns_map: BTreeMap<FieldIndex, TableField>,
+    pub set_max_values: usize,
     pub inverted_index_builders: Vec<InvertedIndexBuilder>,
+    pub vector_index_builders: Vec<VectorIndexBuilder>,
+    pub virtual_column_builder: Option<VirtualColumnBuilder>,
 }
 
 impl BlockBuilder {
@@ -358,6 +493,9 @@ impl BlockBuilder {
             &data_block,
             bloom_index_location,
             self.bloom_columns_map.clone(),
+            self.ngram_columns_map.clone(),
+            self.set_columns_map.clone(),
+            self.set_max_values,
         )?;
         let column_distinct_count = bloom_index_state
             .as_ref()
@@ -374,6 +512,28 @@ impl BlockBuilder {
             inverted_index_states.push(inverted_index_state);
         }
 
+        let mut vector_index_states = Vec::with_capacity(self.vector_index_builders.len());
+        for vector_index_builder in &self.vector_index_builders {
+            if let Some(vector_index_state) = VectorIndexState::try_create(
+                &self.source_schema,
+                &data_block,
+                &block_location,
+                vector_index_builder,
+            )? {
+                vector_index_states.push(vector_index_state);
+            }
+        }
+
+        let virtual_column_state = match &self.virtual_column_builder {
+            Some(virtual_column_builder) => virtual_column_builder.build(
+                &self.ctx.get_function_context()?,
+                &data_block,
+                &block_location,
+                &self.write_settings,
+            )?,
+            None => None,
+        };
+
         let row_count = data_block.num_rows() as u64;
         let block_size = data_block.memory_size() as u64;
         let col_stats =
@@ -387,12 +547,14 @@ impl BlockBuilder {
             &mut buffer,
         )?;
         let file_size = buffer.len() as u64;
-        let inverted_index_size = if !inverted_index_states.is_empty() {
-            let size = inverted_index_states.iter().map(|v| v.size).sum();
-            Some(size)
-        } else {
-            None
-        };
+        let inverted_index_size =
+            if !inverted_index_states.is_empty() || !vector_index_states.is_empty() {
+                let size = inverted_index_states.iter().map(|v| v.size).sum::<u64>()
+                    + vector_index_states.iter().map(|v| v.size).sum::<u64>();
+                Some(size)
+            } else {
+                None
+            };
         let block_meta = BlockMeta {
             row_count,
             block_size,
@@ -417,6 +579,8 @@ impl BlockBuilder {
             block_meta,
             bloom_index_state,
             inverted_index_states,
+            vector_index_states,
+            virtual_column_state,
         };
         Ok(serialized)
     }
@@ -431,6 +595,8 @@ impl BlockWriter {
         Self::write_down_data_block(dal, serialized.block_raw_data, &block_meta.location.0).await?;
         Self::write_down_bloom_index_state(dal, serialized.bloom_index_state).await?;
         Self::write_down_inverted_index_state(dal, serialized.inverted_index_states).await?;
+        Self::write_down_vector_index_state(dal, serialized.vector_index_states).await?;
+        Self::write_down_virtual_column_state(dal, serialized.virtual_column_state).await?;
 
         Ok(block_meta)
     }
@@ -485,4 +651,38 @@ impl BlockWriter {
         }
         Ok(())
     }
+
+    pub async fn write_down_vector_index_state(
+        dal: &Operator,
+        vector_index_states: Vec<VectorIndexState>,
+    ) -> Result<()> {
+        for vector_index_state in vector_index_states {
+            let start = Instant::now();
+
+            let location = &vector_index_state.location.0;
+            let index_size = vector_index_state.size;
+            write_data(vector_index_state.data, dal, location).await?;
+            metrics_inc_block_inverted_index_write_nums(1);
+            metrics_inc_block_inverted_index_write_bytes(index_size);
+            metrics_inc_block_inverted_index_write_milliseconds(start.elapsed().as_millis() as u64);
+        }
+        Ok(())
+    }
+
+    pub async fn write_down_virtual_column_state(
+        dal: &Operator,
+        virtual_column_state: Option<VirtualColumnState>,
+    ) -> Result<()> {
+        if let Some(virtual_column_state) = virtual_column_state {
+            let start = Instant::now();
+
+            let location = &virtual_column_state.location.0;
+            let size = virtual_column_state.size;
+            write_data(virtual_column_state.data, dal, location).await?;
+            metrics_inc_block_virtual_column_write_nums(1);
+            metrics_inc_block_virtual_column_write_bytes(size);
+            metrics_inc_block_virtual_column_write_milliseconds(start.elapsed().as_millis() as u64);
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,281 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+
+use databend_common_functions::scalars::AnalyzedTerm;
+use databend_common_functions::scalars::SearchAnalyzer;
+use tantivy::tokenizer::Language;
+use tantivy::tokenizer::LowerCaser;
+use tantivy::tokenizer::SimpleTokenizer;
+use tantivy::tokenizer::Stemmer;
+use tantivy::tokenizer::StopWordFilter;
+use tantivy::tokenizer::TextAnalyzer;
+use tantivy::tokenizer::Token;
+use tantivy::tokenizer::TokenStream;
+use tantivy::tokenizer::Tokenizer;
+use tantivy::tokenizer::TokenizerManager;
+use tantivy_jieba::JiebaTokenizer;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_segmentation::UnicodeWordIndices;
+
+const DEFAULT_NGRAM_MIN: usize = 2;
+const DEFAULT_NGRAM_MAX: usize = 3;
+
+// Punctuation tokens to remove copied from lucene
+// https://github.com/apache/lucene/blob/main/lucene/analysis/smartcn/src/resources/org/apache/lucene/analysis/cn/smart/stopwords.txt
+const CHINESE_STOP_WORDS: [&str; 53] = [
+    ",", ".", "`", "-", "_", "=", "?", "'", "|", "\"", "(", ")", "{", "}", "[", "]", "<", ">", "*",
+    "#", "&", "^", "$", "@", "!", "~", ":", ";", "+", "/", "\\", "《", "》", "—", "－", "，", "。",
+    "、", "：", "；", "！", "·", "？", "“", "”", "）", "（", "【", "】", "［", "］", "●", "　",
+];
+
+// Create tokenizer can handle both Chinese and English
+pub(crate) fn create_tokenizer_manager(
+    index_options: &BTreeMap<String, String>,
+) -> TokenizerManager {
+    let tokenizer_manager = TokenizerManager::new();
+
+    let filters: HashSet<String> = match index_options.get("filters") {
+        Some(filters_str) => filters_str.split(',').map(|v| v.to_string()).collect(),
+        None => HashSet::new(),
+    };
+    // custom stop words separated by commas, like `a,an,the`,
+    // lowercased as they are removed after the lower case filter.
+    let stop_words: Vec<String> = match index_options.get("stopwords") {
+        Some(stop_words_str) => stop_words_str
+            .split(',')
+            .map(|v| v.trim().to_lowercase())
+            .filter(|v| !v.is_empty())
+            .collect(),
+        None => vec![],
+    };
+    let ngram_min = index_options
+        .get("ngram_min")
+        .and_then(|v| v.parse::<usize>().ok())
+        .unwrap_or(DEFAULT_NGRAM_MIN)
+        .max(1);
+    let ngram_max = index_options
+        .get("ngram_max")
+        .and_then(|v| v.parse::<usize>().ok())
+        .unwrap_or(DEFAULT_NGRAM_MAX)
+        .max(ngram_min);
+
+    let english_analyzer =
+        create_text_analyzer(SimpleTokenizer::default(), &filters, &stop_words, false);
+    let chinese_analyzer = create_text_analyzer(JiebaTokenizer {}, &filters, &stop_words, true);
+    let ngram_analyzer = create_text_analyzer(
+        NgramTokenizer::new(ngram_min, ngram_max),
+        &filters,
+        &stop_words,
+        false,
+    );
+    let unicode_analyzer =
+        create_text_analyzer(UnicodeTokenizer::default(), &filters, &stop_words, false);
+
+    tokenizer_manager.register("english", english_analyzer);
+    tokenizer_manager.register("chinese", chinese_analyzer);
+    tokenizer_manager.register("ngram", ngram_analyzer);
+    tokenizer_manager.register("unicode", unicode_analyzer);
+    tokenizer_manager
+}
+
+/// Create the analyzer of `highlight` function, which tokenizes
+/// the text in the same way as the inverted index with the options.
+pub fn create_search_analyzer(index_options: &BTreeMap<String, String>) -> Option<SearchAnalyzer> {
+    let tokenizer_name = index_options
+        .get("tokenizer")
+        .map(|v| v.as_str())
+        .unwrap_or("english");
+    let mut analyzer = create_tokenizer_manager(index_options).get(tokenizer_name)?;
+    Some(Box::new(move |text: &str| {
+        let mut terms = vec![];
+        let mut token_stream = analyzer.token_stream(text);
+        while token_stream.advance() {
+            let token = token_stream.token();
+            terms.push(AnalyzedTerm {
+                text: token.text.clone(),
+                offset_from: token.offset_from,
+                offset_to: token.offset_to,
+            });
+        }
+        terms
+    }))
+}
+
+fn create_text_analyzer<T: Tokenizer>(
+    tokenizer: T,
+    filters: &HashSet<String>,
+    stop_words: &[String],
+    is_chinese: bool,
+) -> TextAnalyzer {
+    // add lower case filter by default, so that the search can match
+    // all the rows regardless of whether it is uppercase or lowercase
+    if filters.is_empty() && stop_words.is_empty() {
+        return TextAnalyzer::builder(tokenizer).filter(LowerCaser).build();
+    }
+    let mut analyzer = TextAnalyzer::builder(tokenizer).filter_dynamic(LowerCaser);
+
+    // add optional filters
+    // remove English stop words, like "a", "an", "and", etc.
+    if filters.contains("english_stop") {
+        analyzer = analyzer.filter_dynamic(StopWordFilter::new(Language::English).unwrap());
+    }
+    // English stemmer maps different forms of the same word to a common word.
+    // for example, "walking" and "walked" will be mapped to "walk".
+    if filters.contains("english_stemmer") {
+        analyzer = analyzer.filter_dynamic(Stemmer::new(Language::English));
+    }
+    // remove Chinese stop words, which currently only supports Chinese punctuation is supported.
+    if is_chinese && filters.contains("chinese_stop") {
+        let chinese_stop_words = CHINESE_STOP_WORDS.iter().map(|v| v.to_string()).collect();
+        analyzer = analyzer.filter_dynamic(StopWordFilter::remove(chinese_stop_words));
+    }
+    // remove the stop words specified by user.
+    if !stop_words.is_empty() {
+        analyzer = analyzer.filter_dynamic(StopWordFilter::remove(stop_words.to_vec()));
+    }
+    analyzer.build()
+}
+
+/// Split the text into words by the Unicode word boundaries (UAX #29),
+/// like the ICU word segmentation, each CJK ideograph is a word.
+#[derive(Clone, Default)]
+pub struct UnicodeTokenizer {
+    token: Token,
+}
+
+pub struct UnicodeTokenStream<'a> {
+    words: UnicodeWordIndices<'a>,
+    token: &'a mut Token,
+}
+
+impl Tokenizer for UnicodeTokenizer {
+    type TokenStream<'a> = UnicodeTokenStream<'a>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> UnicodeTokenStream<'a> {
+        self.token.reset();
+        UnicodeTokenStream {
+            words: text.unicode_word_indices(),
+            token: &mut self.token,
+        }
+    }
+}
+
+impl TokenStream for UnicodeTokenStream<'_> {
+    fn advance(&mut self) -> bool {
+        let Some((offset, word)) = self.words.next() else {
+            return false;
+        };
+        self.token.text.clear();
+        self.token.text.push_str(word);
+        self.token.offset_from = offset;
+        self.token.offset_to = offset + word.len();
+        self.token.position = self.token.position.wrapping_add(1);
+        true
+    }
+
+    fn token(&self) -> &Token {
+        self.token
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.token
+    }
+}
+
+/// Split the text into n-grams of `min_gram` to `max_gram` characters.
+/// The position of a n-gram is the position of its first character,
+/// so the n-grams of a query are matched as a phrase, like a substring search.
+#[derive(Clone)]
+pub struct NgramTokenizer {
+    min_gram: usize,
+    max_gram: usize,
+    token: Token,
+}
+
+impl NgramTokenizer {
+    pub fn new(min_gram: usize, max_gram: usize) -> Self {
+        Self {
+            min_gram,
+            max_gram,
+            token: Token::default(),
+        }
+    }
+}
+
+pub struct NgramTokenStream<'a> {
+    text: &'a str,
+    // byte offsets of the characters, ends with the length of the text.
+    char_offsets: Vec<usize>,
+    min_gram: usize,
+    max_gram: usize,
+    start: usize,
+    gram_len: usize,
+    token: &'a mut Token,
+}
+
+impl Tokenizer for NgramTokenizer {
+    type TokenStream<'a> = NgramTokenStream<'a>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> NgramTokenStream<'a> {
+        self.token.reset();
+        let mut char_offsets: Vec<usize> = text.char_indices().map(|(i, _)| i).collect();
+        char_offsets.push(text.len());
+        NgramTokenStream {
+            text,
+            char_offsets,
+            min_gram: self.min_gram,
+            max_gram: self.max_gram,
+            start: 0,
+            gram_len: self.min_gram,
+            token: &mut self.token,
+        }
+    }
+}
+
+impl TokenStream for NgramTokenStream<'_> {
+    fn advance(&mut self) -> bool {
+        let num_chars = self.char_offsets.len() - 1;
+        loop {
+            if self.start + self.min_gram > num_chars {
+                return false;
+            }
+            let end = self.start + self.gram_len;
+            if self.gram_len > self.max_gram || end > num_chars {
+                self.start += 1;
+                self.gram_len = self.min_gram;
+                continue;
+            }
+            let offset_from = self.char_offsets[self.start];
+            let offset_to = self.char_offsets[end];
+            self.token.text.clear();
+            self.token.text.push_str(&self.text[offset_from..offset_to]);
+            self.token.offset_from = offset_from;
+            self.token.offset_to = offset_to;
+            self.token.position = self.start;
+            self.gram_len += 1;
+            return true;
+        }
+    }
+
+    fn token(&self) -> &Token {
+        self.token
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.token
+    }
+}
@@ -13,7 +13,6 @@
 // limitations under the License.
 
 use std::collections::BTreeMap;
-use std::collections::HashSet;
 use std::sync::Arc;
 
 use arrow_ipc::writer::write_message;
@@ -43,18 +42,12 @@ use tantivy::schema::Schema;
 use tantivy::schema::TantivyDocument;
 use tantivy::schema::TextFieldIndexing;
 use tantivy::schema::TextOptions;
-use tantivy::tokenizer::Language;
-use tantivy::tokenizer::LowerCaser;
-use tantivy::tokenizer::SimpleTokenizer;
-use tantivy::tokenizer::Stemmer;
-use tantivy::tokenizer::StopWordFilter;
-use tantivy::tokenizer::TextAnalyzer;
-use tantivy::tokenizer::TokenizerManager;
 use tantivy::IndexBuilder;
 use tantivy::IndexSettings;
 use tantivy::IndexWriter;
 use tantivy::SegmentComponent;
-use tantivy_jieba::JiebaTokenizer;
+
+use crate::io::create_tokenizer_manager;
 
 pub struct InvertedIndexWriter {
     schema: DataSchemaRef,
@@ -223,116 +216,6 @@ pub(crate) fn block_to_inverted_index(
     Ok(())
 }
 
-// Create tokenizer can handle both Chinese and English
-pub(crate) fn create_tokenizer_manager(
-    index_options: &BTreeMap<String, String>,
-) -> TokenizerManager {
-    let tokenizer_manager = TokenizerManager::new();
-
-    let filters: HashSet<String> = match index_options.get("filters") {
-        Some(filters_str) => filters_str.split(',').map(|v| v.to_string()).collect(),
-        None => HashSet::new(),
-    };
-
-    // add lower case filter by default, so that the search can match
-    // all the rows regardless of whether it is uppercase or lowercase
-    let (english_analyzer, chinese_analyzer) = if filters.is_empty() {
-        let english_analyzer = TextAnalyzer::builder(SimpleTokenizer::default())
-            .filter(LowerCaser)
-            .build();
-        let chinese_analyzer = TextAnalyzer::builder(JiebaTokenizer {})
-            .filter(LowerCaser)
-            .build();
-
-        (english_analyzer, chinese_analyzer)
-    } else {
-        let mut english_analyzer =
-            TextAnalyzer::builder(SimpleTokenizer::default()).filter_dynamic(LowerCaser);
-        let mut chinese_analyzer =
-            TextAnalyzer::builder(JiebaTokenizer {}).filter_dynamic(LowerCaser);
-
-        // add optional filters
-        // remove English stop words, like "a", "an", "and", etc.
-        if filters.contains("english_stop") {
-            english_analyzer =
-                english_analyzer.filter_dynamic(StopWordFilter::new(Language::English).unwrap());
-            chinese_analyzer =
-                chinese_analyzer.filter_dynamic(StopWordFilter::new(Language::English).unwrap());
-        }
-        // English stemmer maps different forms of the same word to a common word.
-        // for example, "walking" and "walked" will be mapped to "walk".
-        if filters.contains("english_stemmer") {
-            english_analyzer = english_analyzer.filter_dynamic(Stemmer::new(Language::English));
-            chinese_analyzer = chinese_analyzer.filter_dynamic(Stemmer::new(Language::English));
-        }
-        // remove Chinese stop words, which currently only supports Chinese punctuation is supported.
-        if filters.contains("chinese_stop") {
-            // Punctuation tokens to remove copied from lucene
-            // https://github.com/apache/lucene/blob/main/lucene/analysis/smartcn/src/resources/org/apache/lucene/analysis/cn/smart/stopwords.txt
-            chinese_analyzer = chinese_analyzer.filter_dynamic(StopWordFilter::remove(vec![
-                ",".to_string(),
-                ".".to_string(),
-                "`".to_string(),
-                "-".to_string(),
-                "_".to_string(),
-                "=".to_string(),
-                "?".to_string(),
-                "'".to_string(),
-                "|".to_string(),
-                "\"".to_string(),
-                "(".to_string(),
-                ")".to_string(),
-                "{".to_string(),
-                "}".to_string(),
-                "[".to_string(),
-                "]".to_string(),
-                "<".to_string(),
-                ">".to_string(),
-                "*".to_string(),
-                "#".to_string(),
-                "&".to_string(),
-                "^".to_string(),
-                "$".to_string(),
-                "@".to_string(),
-                "!".to_string(),
-                "~".to_string(),
-                ":".to_string(),
-                ";".to_string(),
-                "+".to_string(),
-                "/".to_string(),
-                "\\".to_string(),
-                "《".to_string(),
-                "》".to_string(),
-                "—".to_string(),
-                "－".to_string(),
-                "，".to_string(),
-                "。".to_string(),
-                "、".to_string(),
-                "：".to_string(),
-                "；".to_string(),
-                "！".to_string(),
-                "·".to_string(),
-                "？".to_string(),
-                "“".to_string(),
-                "”".to_string(),
-                "）".to_string(),
-                "（".to_string(),
-                "【".to_string(),
-                "】".to_string(),
-                "［".to_string(),
-                "］".to_string(),
-                "●".to_string(),
-                "　".to_string(),
-            ]));
-        }
-        (english_analyzer.build(), chinese_analyzer.build())
-    };
-
-    tokenizer_manager.register("english", english_analyzer);
-    tokenizer_manager.register("chinese", chinese_analyzer);
-    tokenizer_manager
-}
-
 pub(crate) fn create_index_schema(
     schema: DataSchemaRef,
     index_options: &BTreeMap<String, String>,
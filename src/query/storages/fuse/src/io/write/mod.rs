@@ -13,11 +13,14 @@
 // limitations under the License.
 
 mod block_writer;
+mod inverted_index_tokenizer;
 mod inverted_index_writer;
 mod meta_writer;
+mod virtual_column_builder;
 mod write_settings;
 
 pub(crate) use block_writer::create_inverted_index_builders;
+pub(crate) use block_writer::create_vector_index_builders;
 pub use block_writer::serialize_block;
 pub use block_writer::write_data;
 pub use block_writer::BlockBuilder;
@@ -26,10 +29,16 @@ pub use block_writer::BlockWriter;
 pub use block_writer::BloomIndexBuilder;
 pub use block_writer::BloomIndexState;
 pub use block_writer::InvertedIndexBuilder;
+pub use block_writer::VectorIndexBuilder;
+pub use block_writer::VectorIndexState;
+pub use inverted_index_tokenizer::create_search_analyzer;
+pub(crate) use inverted_index_tokenizer::create_tokenizer_manager;
 pub(crate) use inverted_index_writer::block_to_inverted_index;
 pub(crate) use inverted_index_writer::create_index_schema;
-pub(crate) use inverted_index_writer::create_tokenizer_manager;
 pub use inverted_index_writer::InvertedIndexWriter;
 pub use meta_writer::CachedMetaWriter;
 pub use meta_writer::MetaWriter;
+pub use virtual_column_builder::serialize_virtual_block;
+pub use virtual_column_builder::VirtualColumnBuilder;
+pub use virtual_column_builder::VirtualColumnState;
 pub use write_settings::WriteSettings;
@@ -0,0 +1,401 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use databend_common_base::runtime::block_on;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::eval_function;
+use databend_common_expression::infer_schema_type;
+use databend_common_expression::type_check::get_simple_cast_function;
+use databend_common_expression::types::AnyType;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FieldIndex;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
+use databend_storages_common_blocks::blocks_to_parquet_with_properties;
+use databend_storages_common_table_meta::meta::Location;
+use jsonb::keypath::KeyPath;
+use jsonb::keypath::KeyPaths;
+use jsonb::Number as JsonbNumber;
+use jsonb::Value as JsonbValue;
+use log::warn;
+use parquet::basic::Encoding;
+use parquet::file::properties::EnabledStatistics;
+use parquet::file::properties::WriterProperties;
+
+use crate::io::write::serialize_block;
+use crate::io::write::WriteSettings;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseStorageFormat;
+use crate::FuseTable;
+
+/// The max number of rows sampled from a variant column to discover the virtual columns.
+const MAX_SAMPLE_ROWS: usize = 1024;
+/// The max number of keys in the path of a discovered virtual column.
+const MAX_PATH_DEPTH: usize = 4;
+/// The max number of paths tracked while sampling a variant column,
+/// objects with lots of distinct keys are rarely accessed by fixed paths.
+const MAX_TRACKED_PATHS: usize = 1024;
+/// The max number of virtual columns materialized for a block.
+pub const MAX_AUTO_VIRTUAL_COLUMNS: usize = 32;
+
+/// Serialize the virtual columns of a block.
+///
+/// Unlike the blocks, the parquet file keeps the min/max statistics of the column chunks,
+/// which are used to prune the blocks by the predicates on virtual columns.
+pub fn serialize_virtual_block(
+    write_settings: &WriteSettings,
+    schema: &TableSchemaRef,
+    block: DataBlock,
+    buf: &mut Vec<u8>,
+) -> Result<()> {
+    match write_settings.storage_format {
+        FuseStorageFormat::Parquet => {
+            let props = WriterProperties::builder()
+                .set_compression(write_settings.table_compression.into())
+                // use `usize::MAX` to effectively limit the number of row groups to 1
+                .set_max_row_group_size(usize::MAX)
+                .set_encoding(Encoding::PLAIN)
+                .set_dictionary_enabled(false)
+                .set_statistics_enabled(EnabledStatistics::Chunk)
+                .set_bloom_filter_enabled(false)
+                .build();
+            let _ = blocks_to_parquet_with_properties(schema, vec![block], buf, props)?;
+        }
+        FuseStorageFormat::Native => {
+            let _ = serialize_block(write_settings, schema, block, buf)?;
+        }
+    }
+    Ok(())
+}
+
+pub struct VirtualColumnState {
+    pub(crate) data: Vec<u8>,
+    pub(crate) size: u64,
+    pub(crate) location: Location,
+}
+
+/// Discover the inner fields of variant columns that are present in most of the rows
+/// with the same scalar type, and materialize them as typed virtual columns.
+///
+/// The registered virtual columns of the table, including the paths observed in
+/// query predicates, are materialized if they are present in the block.
+///
+/// A path is only materialized if all of its values in the block have the scalar type,
+/// so that the statistics of the typed column cover all the values of the path.
+#[derive(Clone)]
+pub struct VirtualColumnBuilder {
+    variant_fields: Vec<(FieldIndex, String)>,
+    registered_columns: HashSet<String>,
+}
+
+impl VirtualColumnBuilder {
+    // return None if the table doesn't enable auto virtual columns or has no variant columns.
+    pub fn try_create(
+        ctx: &dyn TableContext,
+        table: &FuseTable,
+        source_schema: &TableSchema,
+    ) -> Option<Self> {
+        if !FuseTable::is_auto_virtual_columns_enabled(table.table_info.options()) {
+            return None;
+        }
+        let variant_fields = source_schema
+            .fields()
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| field.data_type().remove_nullable() == TableDataType::Variant)
+            .map(|(index, field)| (index, field.name().clone()))
+            .collect::<Vec<_>>();
+        if variant_fields.is_empty() {
+            return None;
+        }
+        let registered_columns = match block_on(table.list_registered_virtual_columns(ctx)) {
+            Ok(names) => names,
+            Err(e) => {
+                warn!("list registered virtual columns failed. {:?}", e);
+                HashSet::new()
+            }
+        };
+        Some(Self {
+            variant_fields,
+            registered_columns,
+        })
+    }
+
+    // return None if no virtual column is discovered in the block.
+    pub fn build(
+        &self,
+        func_ctx: &FunctionContext,
+        block: &DataBlock,
+        block_location: &Location,
+        write_settings: &WriteSettings,
+    ) -> Result<Option<VirtualColumnState>> {
+        let num_rows = block.num_rows();
+        if num_rows == 0 {
+            return Ok(None);
+        }
+        let mut candidates = Vec::new();
+        for (offset, source_name) in &self.variant_fields {
+            let column = block.get_by_offset(*offset).to_column(num_rows);
+            let (sampled, paths) = sample_paths(&column);
+            for (keys, stats) in paths {
+                let Some(kind) = stats.kind else {
+                    continue;
+                };
+                let name = virtual_column_name(source_name, &keys);
+                let registered = self.registered_columns.contains(&name);
+                if stats.count * 2 < sampled && !registered {
+                    continue;
+                }
+                candidates.push((registered, stats.count, name, *offset, keys, kind));
+            }
+        }
+        if candidates.is_empty() {
+            return Ok(None);
+        }
+        // The registered virtual columns are preferred to the others.
+        candidates.sort_by(|a, b| {
+            b.0.cmp(&a.0)
+                .then_with(|| b.1.cmp(&a.1))
+                .then_with(|| a.2.cmp(&b.2))
+        });
+        candidates.truncate(MAX_AUTO_VIRTUAL_COLUMNS);
+        candidates.sort_by(|a, b| a.2.cmp(&b.2));
+
+        let mut fields = Vec::with_capacity(candidates.len());
+        let mut entries = Vec::with_capacity(candidates.len());
+        for (_, _, name, offset, keys, kind) in candidates {
+            let dest_type = kind.data_type();
+            let Some(cast_func_name) =
+                get_simple_cast_function(true, &DataType::Variant, &dest_type)
+            else {
+                continue;
+            };
+            // Same as the virtual columns extracted from the source column on read.
+            let key_paths = KeyPaths {
+                paths: keys
+                    .iter()
+                    .map(|key| KeyPath::QuotedName(Cow::Borrowed(key.as_str())))
+                    .collect(),
+            };
+            let source = block.get_by_offset(offset);
+            let (value, data_type) = eval_function(
+                None,
+                "get_by_keypath",
+                [
+                    (source.value.clone(), source.data_type.clone()),
+                    (
+                        Value::Scalar(Scalar::String(format!("{}", key_paths))),
+                        DataType::String,
+                    ),
+                ],
+                func_ctx,
+                num_rows,
+                &BUILTIN_FUNCTIONS,
+            )?;
+            let num_nulls = null_count(&value, num_rows);
+            let (value, data_type) = eval_function(
+                None,
+                &cast_func_name,
+                [(value, data_type)],
+                func_ctx,
+                num_rows,
+                &BUILTIN_FUNCTIONS,
+            )?;
+            // The type is inferred from the sampled rows, the values of the other rows
+            // that are null or can't be cast to the type are not covered by the statistics.
+            if null_count(&value, num_rows) != num_nulls {
+                continue;
+            }
+            fields.push(TableField::new(&name, infer_schema_type(&data_type)?));
+            entries.push(BlockEntry::new(data_type, value));
+        }
+        if entries.is_empty() {
+            return Ok(None);
+        }
+
+        let virtual_schema = TableSchemaRefExt::create(fields);
+        let virtual_block = DataBlock::new(entries, num_rows);
+        let mut data = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
+        serialize_virtual_block(write_settings, &virtual_schema, virtual_block, &mut data)?;
+        let size = data.len() as u64;
+        let location = TableMetaLocationGenerator::gen_virtual_block_location(&block_location.0);
+        Ok(Some(VirtualColumnState {
+            data,
+            size,
+            location: (location, 0),
+        }))
+    }
+}
+
+fn null_count(value: &Value<AnyType>, num_rows: usize) -> usize {
+    match value {
+        Value::Scalar(Scalar::Null) => num_rows,
+        Value::Scalar(_) => 0,
+        Value::Column(Column::Null { len }) => *len,
+        Value::Column(Column::Nullable(column)) => column.validity.null_count(),
+        Value::Column(_) => 0,
+    }
+}
+
+fn virtual_column_name(source_name: &str, keys: &[String]) -> String {
+    let mut name = source_name.to_string();
+    for key in keys {
+        name.push_str("['");
+        name.push_str(key);
+        name.push_str("']");
+    }
+    name
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ValueKind {
+    Boolean,
+    UInt64,
+    Int64,
+    Float64,
+    String,
+}
+
+impl ValueKind {
+    fn of(value: &JsonbValue) -> Option<Self> {
+        match value {
+            JsonbValue::Bool(_) => Some(ValueKind::Boolean),
+            JsonbValue::String(_) => Some(ValueKind::String),
+            JsonbValue::Number(JsonbNumber::UInt64(_)) => Some(ValueKind::UInt64),
+            JsonbValue::Number(JsonbNumber::Int64(_)) => Some(ValueKind::Int64),
+            JsonbValue::Number(_) => Some(ValueKind::Float64),
+            _ => None,
+        }
+    }
+
+    // numbers are widened to the type that can hold both of them.
+    fn merge(self, other: Self) -> Option<Self> {
+        use ValueKind::*;
+        match (self, other) {
+            (a, b) if a == b => Some(a),
+            (UInt64 | Int64, UInt64 | Int64) => Some(Int64),
+            (UInt64 | Int64 | Float64, UInt64 | Int64 | Float64) => Some(Float64),
+            _ => None,
+        }
+    }
+
+    fn data_type(self) -> DataType {
+        match self {
+            ValueKind::Boolean => DataType::Boolean,
+            ValueKind::UInt64 => DataType::Number(NumberDataType::UInt64),
+            ValueKind::Int64 => DataType::Number(NumberDataType::Int64),
+            ValueKind::Float64 => DataType::Number(NumberDataType::Float64),
+            ValueKind::String => DataType::String,
+        }
+    }
+}
+
+#[derive(Default)]
+struct PathStats {
+    // the number of sampled rows that have a non-null value in the path.
+    count: usize,
+    // None if the values are not scalars of the same kind.
+    kind: Option<ValueKind>,
+}
+
+impl PathStats {
+    fn add(&mut self, kind: Option<ValueKind>) {
+        self.kind = match (self.count, self.kind, kind) {
+            (0, _, kind) => kind,
+            (_, Some(a), Some(b)) => a.merge(b),
+            _ => None,
+        };
+        self.count += 1;
+    }
+}
+
+/// Sample the rows of a variant column, returns the number of sampled values
+/// and the statistics of the scalar values in each path.
+fn sample_paths(column: &Column) -> (usize, HashMap<Vec<String>, PathStats>) {
+    let mut paths = HashMap::new();
+    let (column, validity) = match column {
+        Column::Nullable(c) => (&c.column, Some(&c.validity)),
+        c => (c, None),
+    };
+    let Column::Variant(values) = column else {
+        return (0, paths);
+    };
+    let step = values.len().div_ceil(MAX_SAMPLE_ROWS).max(1);
+    let mut sampled = 0;
+    let mut keys = Vec::new();
+    for row in (0..values.len()).step_by(step) {
+        if validity.is_some_and(|v| !v.get_bit(row)) {
+            continue;
+        }
+        let Ok(value) = jsonb::from_slice(values.value(row)) else {
+            continue;
+        };
+        sampled += 1;
+        collect_paths(&value, &mut keys, &mut paths);
+    }
+    (sampled, paths)
+}
+
+fn collect_paths(
+    value: &JsonbValue,
+    keys: &mut Vec<String>,
+    paths: &mut HashMap<Vec<String>, PathStats>,
+) {
+    match value {
+        JsonbValue::Null => {}
+        JsonbValue::Object(object) => {
+            if keys.len() >= MAX_PATH_DEPTH {
+                return;
+            }
+            for (key, value) in object {
+                // The key is quoted in the name of the virtual column.
+                if key.is_empty() || key.contains(['\'', '"', '\\']) {
+                    continue;
+                }
+                keys.push(key.clone());
+                collect_paths(value, keys, paths);
+                keys.pop();
+            }
+        }
+        _ if keys.is_empty() => {}
+        value => {
+            if !paths.contains_key(keys.as_slice()) {
+                if paths.len() >= MAX_TRACKED_PATHS {
+                    return;
+                }
+                paths.insert(keys.clone(), PathStats::default());
+            }
+            if let Some(stats) = paths.get_mut(keys.as_slice()) {
+                stats.add(ValueKind::of(value));
+            }
+        }
+    }
+}
@@ -41,6 +41,7 @@ use databend_common_storage::Histogram;
 use databend_common_storage::HistogramBucket;
 use databend_storages_common_table_meta::meta::ClusterStatistics;
 use databend_storages_common_table_meta::meta::MetaHLL;
+use databend_storages_common_table_meta::meta::MultiColumnStatistics;
 use databend_storages_common_table_meta::meta::SegmentInfo;
 use databend_storages_common_table_meta::meta::SnapshotId;
 use databend_storages_common_table_meta::meta::StatisticsOfColumns;
@@ -56,6 +57,7 @@ use crate::FuseTable;
 enum AnalyzeStep {
     CollectNDV,
     CollectHistogram,
+    CollectMultiColumnStatistics,
     CommitStatistics,
 }
 
@@ -70,6 +72,7 @@ impl FuseTable {
         snapshot_id: SnapshotId,
         pipeline: &mut Pipeline,
         histogram_info_receivers: HashMap<u32, Receiver<DataBlock>>,
+        multi_column_info_receivers: Vec<(Vec<ColumnId>, Receiver<DataBlock>)>,
     ) -> Result<()> {
         pipeline.add_sink(|input| {
             SinkAnalyzeState::create(
@@ -81,6 +84,7 @@ impl FuseTable {
                 snapshot_id,
                 input,
                 histogram_info_receivers.clone(),
+                multi_column_info_receivers.clone(),
             )
         })?;
         Ok(())
@@ -97,10 +101,12 @@ struct SinkAnalyzeState {
     table: String,
     snapshot_id: SnapshotId,
     histogram_info_receivers: HashMap<u32, Receiver<DataBlock>>,
+    multi_column_info_receivers: Vec<(Vec<ColumnId>, Receiver<DataBlock>)>,
     input_data: Option<DataBlock>,
     committed: bool,
     ndv_states: HashMap<ColumnId, MetaHLL>,
     histograms: HashMap<ColumnId, Histogram>,
+    multi_column_stats: Vec<MultiColumnStatistics>,
     step: AnalyzeStep,
 }
 
@@ -115,6 +121,7 @@ impl SinkAnalyzeState {
         snapshot_id: SnapshotId,
         input: Arc<InputPort>,
         histogram_info_receivers: HashMap<u32, Receiver<DataBlock>>,
+        multi_column_info_receivers: Vec<(Vec<ColumnId>, Receiver<DataBlock>)>,
     ) -> Result<ProcessorPtr> {
         Ok(ProcessorPtr::create(Box::new(SinkAnalyzeState {
             ctx,
@@ -125,10 +132,12 @@ impl SinkAnalyzeState {
             table: table.to_string(),
             snapshot_id,
             histogram_info_receivers,
+            multi_column_info_receivers,
             input_data: None,
             committed: false,
             ndv_states: Default::default(),
             histograms: Default::default(),
+            multi_column_stats: Default::default(),
             step: AnalyzeStep::CollectNDV,
         })))
     }
@@ -237,6 +246,46 @@ impl SinkAnalyzeState {
         Ok(())
     }
 
+    // Each row of the data block is a value combination of the columns followed by
+    // its count, the ndv of the combinations and the row count of the table.
+    fn create_multi_column_statistics(&mut self, column_ids: &[ColumnId], data_block: DataBlock) {
+        if data_block.num_rows() == 0 {
+            return;
+        }
+        let num_columns = column_ids.len();
+        let pos = match self
+            .multi_column_stats
+            .iter()
+            .position(|s| s.column_ids == column_ids)
+        {
+            Some(pos) => pos,
+            None => {
+                self.multi_column_stats.push(MultiColumnStatistics {
+                    column_ids: column_ids.to_vec(),
+                    ndv: 0,
+                    row_count: 0,
+                    most_common_values: vec![],
+                });
+                self.multi_column_stats.len() - 1
+            }
+        };
+        let stats = &mut self.multi_column_stats[pos];
+        for row in 0..data_block.num_rows() {
+            let values = data_block.columns()[..num_columns]
+                .iter()
+                .map(|c| Datum::from_scalar(c.value.index(row).unwrap().to_owned()))
+                .collect::<Option<Vec<_>>>();
+            let count = u64_value(&data_block, num_columns, row);
+            stats.ndv = u64_value(&data_block, num_columns + 1, row);
+            stats.row_count = u64_value(&data_block, num_columns + 2, row);
+            // The combinations contain NULL are not kept.
+            if let Some(values) = values {
+                stats.most_common_values.push((values, count));
+            }
+        }
+        stats.most_common_values.sort_by(|a, b| b.1.cmp(&a.1));
+    }
+
     async fn commit_statistics(&self) -> Result<()> {
         let table = self.get_table().await?;
         let table = FuseTable::try_from_table(table.as_ref())?;
@@ -249,6 +298,7 @@ impl SinkAnalyzeState {
         let table_statistics = TableSnapshotStatistics::new(
             self.ndv_states.clone(),
             self.histograms.clone(),
+            self.multi_column_stats.clone(),
             self.snapshot_id,
         );
         let table_statistics_location = table
@@ -303,7 +353,7 @@ impl Processor for SinkAnalyzeState {
                     self.step = AnalyzeStep::CollectHistogram;
                     return Ok(Event::Async);
                 }
-                AnalyzeStep::CollectHistogram => {
+                AnalyzeStep::CollectHistogram | AnalyzeStep::CollectMultiColumnStatistics => {
                     return Ok(Event::Async);
                 }
                 AnalyzeStep::CommitStatistics => {
@@ -344,6 +394,20 @@ impl Processor for SinkAnalyzeState {
                     }
                 }
                 if finished_count == self.histogram_info_receivers.len() {
+                    self.step = AnalyzeStep::CollectMultiColumnStatistics;
+                }
+            }
+            AnalyzeStep::CollectMultiColumnStatistics => {
+                let mut finished_count = 0;
+                let receivers = self.multi_column_info_receivers.clone();
+                for (column_ids, receiver) in receivers.iter() {
+                    if let Ok(res) = receiver.recv().await {
+                        self.create_multi_column_statistics(column_ids, res);
+                    } else {
+                        finished_count += 1;
+                    }
+                }
+                if finished_count == self.multi_column_info_receivers.len() {
                     self.step = AnalyzeStep::CommitStatistics;
                 }
             }
@@ -421,6 +485,14 @@ pub async fn regenerate_statistics(
     Ok((col_stats, cluster_stats))
 }
 
+fn u64_value(data_block: &DataBlock, column: usize, row: usize) -> u64 {
+    data_block.columns()[column]
+        .value
+        .index(row)
+        .and_then(|v| v.as_number().and_then(|n| n.as_u_int64()).copied())
+        .unwrap_or_default()
+}
+
 pub struct HistogramInfoSink {
     sender: Option<Sender<DataBlock>>,
 }
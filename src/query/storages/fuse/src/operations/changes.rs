@@ -306,6 +306,8 @@ impl FuseTable {
             cluster_key_meta,
             cluster_keys,
             bloom_index_cols,
+            self.ngram_index_cols(),
+            self.set_index_cols(),
             None,
             self.get_storage_format(),
         )?;
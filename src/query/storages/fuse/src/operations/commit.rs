@@ -263,6 +263,18 @@ impl FuseTable {
             })
             .await?;
 
+        // keep the exported iceberg metadata in sync with the new snapshot, the data is
+        // committed already, so the failure is reported as a warning of the query.
+        if Self::is_iceberg_export_enabled(&table_info.meta.options) {
+            if let Err(e) =
+                Self::write_iceberg_metadata(operator, location_generator, table_info, &snapshot)
+                    .await
+            {
+                warn!("write iceberg metadata failure. {}", e);
+                ctx.push_warning(format!("failed to export iceberg metadata: {}", e));
+            }
+        }
+
         // update_table_meta succeed, populate the snapshot cache item and try keeping a hit file of last snapshot
         TableSnapshot::cache().insert(snapshot_location.clone(), snapshot);
         Self::write_last_snapshot_hint(ctx, operator, location_generator, &snapshot_location).await;
@@ -38,6 +38,7 @@ use databend_storages_common_table_meta::meta::Versioned;
 use log::debug;
 use log::error;
 use log::info;
+use log::warn;
 
 use crate::operations::set_backoff;
 use crate::operations::AppendGenerator;
@@ -160,6 +161,21 @@ impl AsyncSink for CommitMultiTableInsert {
                     table_descriptions, stream_descriptions
                 );
 
+                for table in self.tables.values() {
+                    if !FuseTable::is_iceberg_export_enabled(table.options()) {
+                        continue;
+                    }
+                    let table = table.refresh(self.ctx.as_ref()).await?;
+                    if let Err(e) = FuseTable::try_from_table(table.as_ref())?
+                        .export_iceberg_metadata()
+                        .await
+                    {
+                        warn!("write iceberg metadata failure. {}", e);
+                        self.ctx
+                            .push_warning(format!("failed to export iceberg metadata: {}", e));
+                    }
+                }
+
                 return Ok(());
             };
             let update_failed_tbl_descriptions: Vec<_> = update_failed_tbls
@@ -36,9 +36,11 @@ use databend_storages_common_index::BloomIndex;
 use opendal::Operator;
 
 use crate::io::create_inverted_index_builders;
+use crate::io::create_vector_index_builders;
 use crate::io::BlockBuilder;
 use crate::io::BlockSerialization;
 use crate::io::BlockWriter;
+use crate::io::VirtualColumnBuilder;
 use crate::operations::common::BlockMetaIndex;
 use crate::operations::common::MutationLogEntry;
 use crate::operations::common::MutationLogs;
@@ -127,8 +129,17 @@ impl TransformSerializeBlock {
         let bloom_columns_map = table
             .bloom_index_cols
             .bloom_index_fields(source_schema.clone(), BloomIndex::supported_type)?;
+        let ngram_columns_map = table
+            .ngram_index_cols
+            .bloom_index_fields(source_schema.clone(), BloomIndex::supported_ngram_type)?;
+        let set_columns_map = table
+            .set_index_cols
+            .bloom_index_fields(source_schema.clone(), BloomIndex::supported_set_type)?;
 
         let inverted_index_builders = create_inverted_index_builders(&table.table_info.meta);
+        let vector_index_builders = create_vector_index_builders(&table.table_info.meta);
+        let virtual_column_builder =
+            VirtualColumnBuilder::try_create(ctx.as_ref(), table, &source_schema);
 
         let block_builder = BlockBuilder {
             ctx,
@@ -137,7 +148,12 @@ impl TransformSerializeBlock {
             write_settings: table.get_write_settings(),
             cluster_stats_gen,
             bloom_columns_map,
+            ngram_columns_map,
+            set_columns_map,
+            set_max_values: table.set_index_max_values(),
             inverted_index_builders,
+            vector_index_builders,
+            virtual_column_builder,
         };
         Ok(TransformSerializeBlock {
             state: State::Consume,
@@ -23,6 +23,7 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::schema::ListIndexesByIdReq;
 use databend_common_meta_app::schema::TableIndex;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_storages_common_cache::CacheAccessor;
 use databend_storages_common_cache::CachedObject;
 use databend_storages_common_cache::LoadParams;
@@ -431,13 +432,7 @@ impl FuseTable {
                 }
 
                 for idx in inverted_indexes.values() {
-                    inverted_indexes_to_be_purged.insert(
-                        TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
-                            loc,
-                            idx.name.as_str(),
-                            idx.version.as_str(),
-                        ),
-                    );
+                    inverted_indexes_to_be_purged.insert(table_index_location(loc, idx));
                 }
             }
 
@@ -517,15 +512,12 @@ impl FuseTable {
         // such as, different versions of same (in the sense of name) inverted index.
         // we do not handle this one block multiple inverted indexes case now.
         for idx in inverted_indexes.values() {
-            inverted_indexes_to_be_purged.extend(root_location_tuple.block_location.iter().map(
-                |loc| {
-                    TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
-                        loc,
-                        idx.name.as_str(),
-                        idx.version.as_str(),
-                    )
-                },
-            ));
+            inverted_indexes_to_be_purged.extend(
+                root_location_tuple
+                    .block_location
+                    .iter()
+                    .map(|loc| table_index_location(loc, idx)),
+            );
         }
 
         self.purge_block_segments(
@@ -807,3 +799,23 @@ impl PurgeCounter {
         }
     }
 }
+
+/// Location of the file of a table index accompanying the block.
+fn table_index_location(block_location: &str, index: &TableIndex) -> String {
+    match index.index_type {
+        TableIndexType::Inverted => {
+            TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+                block_location,
+                &index.name,
+                &index.version,
+            )
+        }
+        TableIndexType::Vector => {
+            TableMetaLocationGenerator::gen_vector_index_location_from_block_location(
+                block_location,
+                &index.name,
+                &index.version,
+            )
+        }
+    }
+}
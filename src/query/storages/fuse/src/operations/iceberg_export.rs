@@ -0,0 +1,585 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Export the snapshots of a fuse table as Apache Iceberg (format v2) metadata.
+//!
+//! The metadata is written in the hadoop table layout under `<table prefix>/metadata/`,
+//! and the manifests point at the parquet blocks of the snapshot, no data is copied.
+//! Columns are mapped to iceberg fields by `column id + 1`, which is also written as the
+//! parquet field id of the blocks. For the blocks written without field ids, a default name
+//! mapping is recorded in the table properties.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::fmt::Debug;
+
+use chrono::Utc;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableSchema;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_meta_app::storage::StorageParams;
+use databend_common_storage::DataOperator;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::meta::TableSnapshot;
+use databend_storages_common_table_meta::table::iceberg_field_id;
+use databend_storages_common_table_meta::table::iceberg_schema_json;
+use iceberg::io::FileIO;
+use iceberg::io::FileIOBuilder;
+use iceberg::spec::DataContentType;
+use iceberg::spec::DataFile;
+use iceberg::spec::DataFileBuilder;
+use iceberg::spec::DataFileFormat;
+use iceberg::spec::Datum;
+use iceberg::spec::FormatVersion;
+use iceberg::spec::Manifest;
+use iceberg::spec::ManifestContentType;
+use iceberg::spec::ManifestEntry;
+use iceberg::spec::ManifestListWriter;
+use iceberg::spec::ManifestMetadata;
+use iceberg::spec::ManifestStatus;
+use iceberg::spec::ManifestWriter;
+use iceberg::spec::PartitionSpec;
+use iceberg::spec::Schema;
+use iceberg::spec::Struct;
+use iceberg::spec::TableMetadata;
+use log::info;
+use opendal::Operator;
+use serde_json::json;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::io::SegmentsIO;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseTable;
+use crate::FUSE_OPT_KEY_ICEBERG_EXPORT;
+
+// Same as the default of iceberg `write.metadata.previous-versions-max`.
+const MAX_PREVIOUS_METADATA_VERSIONS: usize = 100;
+const PROPERTY_NAME_MAPPING: &str = "schema.name-mapping.default";
+const PROPERTY_FUSE_SNAPSHOT_ID: &str = "databend.fuse.snapshot-id";
+const PROPERTY_EXPORT_VERSION: &str = "databend.fuse.export-version";
+
+impl FuseTable {
+    pub fn is_iceberg_export_enabled(options: &BTreeMap<String, String>) -> bool {
+        options
+            .get(FUSE_OPT_KEY_ICEBERG_EXPORT)
+            .and_then(|v| v.to_lowercase().parse::<bool>().ok())
+            .unwrap_or(false)
+    }
+
+    /// Exports the current snapshot of the table as iceberg metadata.
+    #[async_backtrace::framed]
+    pub async fn export_iceberg_metadata(&self) -> Result<()> {
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(());
+        };
+        Self::do_write_iceberg_metadata(
+            &self.operator,
+            &self.meta_location_generator,
+            &self.table_info.meta,
+            &snapshot,
+            iceberg_export_version(&self.table_info, false),
+        )
+        .await
+    }
+
+    /// Writes iceberg metadata describing `snapshot`, which is committed on top of the
+    /// table version of `table_info`.
+    #[async_backtrace::framed]
+    pub async fn write_iceberg_metadata(
+        operator: &Operator,
+        location_generator: &TableMetaLocationGenerator,
+        table_info: &TableInfo,
+        snapshot: &TableSnapshot,
+    ) -> Result<()> {
+        Self::do_write_iceberg_metadata(
+            operator,
+            location_generator,
+            &table_info.meta,
+            snapshot,
+            iceberg_export_version(table_info, true),
+        )
+        .await
+    }
+
+    /// Writes iceberg metadata describing `snapshot`, and points the version hint at it.
+    ///
+    /// The blocks of a segment are listed by a manifest named after the segment. Segments are
+    /// immutable, so the manifests written by the previous exports are reused and only the
+    /// manifests of the new segments are written.
+    ///
+    /// Commits of the table may export concurrently. A metadata version is taken by creating
+    /// its file exclusively, the loser retries with the next version; and the export is skipped
+    /// if a newer version of the table has been exported, exports are ordered by `export_version`.
+    #[async_backtrace::framed]
+    async fn do_write_iceberg_metadata(
+        operator: &Operator,
+        location_generator: &TableMetaLocationGenerator,
+        table_meta: &TableMeta,
+        snapshot: &TableSnapshot,
+        export_version: u64,
+    ) -> Result<()> {
+        let storage_params = table_meta
+            .storage_params
+            .clone()
+            .unwrap_or_else(|| DataOperator::instance().params());
+        let (file_io, storage_root) = iceberg_file_io(&storage_params)?;
+        loop {
+            let (version, previous) =
+                Self::read_iceberg_metadata(operator, location_generator).await?;
+            if let Some(previous) = &previous {
+                let exported_version = previous["properties"][PROPERTY_EXPORT_VERSION]
+                    .as_str()
+                    .and_then(|v| v.parse::<u64>().ok())
+                    .unwrap_or_default();
+                if exported_version > export_version {
+                    info!(
+                        "skip exporting iceberg metadata of export version {}, version {} has been exported",
+                        export_version, exported_version
+                    );
+                    return Ok(());
+                }
+            }
+
+            let version = version + 1;
+            let metadata = Self::build_iceberg_metadata(
+                &file_io,
+                &storage_root,
+                operator,
+                location_generator,
+                export_version,
+                snapshot,
+                version,
+                previous,
+            )
+            .await?;
+            let metadata_location = location_generator.gen_iceberg_metadata_location(version);
+            if !write_if_not_exists(operator, &metadata_location, serde_json::to_vec(&metadata)?)
+                .await?
+            {
+                info!(
+                    "iceberg metadata version {} has been written concurrently, retry with the next version",
+                    version
+                );
+                continue;
+            }
+            operator
+                .write(
+                    &location_generator.gen_iceberg_version_hint_location(),
+                    version.to_string(),
+                )
+                .await?;
+            return Ok(());
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[async_backtrace::framed]
+    async fn build_iceberg_metadata(
+        file_io: &FileIO,
+        storage_root: &str,
+        operator: &Operator,
+        location_generator: &TableMetaLocationGenerator,
+        export_version: u64,
+        snapshot: &TableSnapshot,
+        version: u64,
+        previous: Option<Value>,
+    ) -> Result<TableMetadata> {
+        let uri = |path: &str| {
+            format!(
+                "{}/{}",
+                storage_root.trim_end_matches('/'),
+                path.trim_start_matches('/')
+            )
+        };
+
+        let schema = &snapshot.schema;
+        let schema_json = iceberg_schema_json(schema)?;
+        let iceberg_schema: Schema = serde_json::from_value(schema_json.clone())?;
+        let partition_spec: PartitionSpec =
+            serde_json::from_value(json!({"spec-id": 0, "fields": []}))?;
+
+        let sequence_number = version as i64;
+        // Iceberg snapshot ids are positive longs.
+        let snapshot_id = (snapshot.snapshot_id.as_u64_pair().0 >> 1) as i64;
+        let timestamp_ms = snapshot
+            .timestamp
+            .unwrap_or_else(Utc::now)
+            .timestamp_millis();
+
+        // 1. collect the manifests of the previous export, they are reused by the unchanged segments
+        let mut previous_manifests = HashMap::new();
+        if let Some(previous) = &previous {
+            let previous_metadata: TableMetadata = serde_json::from_value(previous.clone())?;
+            if let Some(previous_snapshot) = previous_metadata.current_snapshot() {
+                let manifest_list = previous_snapshot
+                    .load_manifest_list(file_io, &previous_metadata)
+                    .await
+                    .map_err(iceberg_error)?;
+                for manifest_file in manifest_list.entries() {
+                    previous_manifests
+                        .insert(manifest_file.manifest_path.clone(), manifest_file.clone());
+                }
+            }
+        }
+
+        // 2. write down the manifests of the new segments
+        let mut manifest_files = Vec::with_capacity(snapshot.segments.len());
+        for segment_location in &snapshot.segments {
+            let manifest_location =
+                uri(&location_generator.gen_iceberg_manifest_location(&segment_location.0));
+            if let Some(manifest_file) = previous_manifests.remove(&manifest_location) {
+                manifest_files.push(manifest_file);
+                continue;
+            }
+
+            let segment = SegmentsIO::read_compact_segment(
+                operator.clone(),
+                segment_location.clone(),
+                snapshot.schema.clone().into(),
+                false,
+            )
+            .await?;
+            let entries = segment
+                .block_metas()?
+                .iter()
+                .map(|block| {
+                    let data_file = iceberg_data_file(schema, block, uri(&block.location.0))?;
+                    Ok(ManifestEntry::builder()
+                        .status(ManifestStatus::Added)
+                        .snapshot_id(snapshot_id)
+                        .data_file(data_file)
+                        .build())
+                })
+                .collect::<Result<Vec<_>>>()?;
+            if entries.is_empty() {
+                continue;
+            }
+            let manifest_metadata = ManifestMetadata::builder()
+                .schema(iceberg_schema.clone())
+                .schema_id(0)
+                .partition_spec(partition_spec.clone())
+                .format_version(FormatVersion::V2)
+                .content(ManifestContentType::Data)
+                .build();
+            let output = file_io
+                .new_output(&manifest_location)
+                .map_err(iceberg_error)?;
+            let manifest_file = ManifestWriter::new(output, snapshot_id, vec![])
+                .write(Manifest::new(manifest_metadata, entries))
+                .await
+                .map_err(iceberg_error)?;
+            manifest_files.push(manifest_file);
+        }
+
+        // 3. write down the manifest list of the iceberg snapshot
+        let manifest_list_location =
+            uri(&location_generator
+                .gen_iceberg_manifest_list_location(snapshot_id, &Uuid::new_v4()));
+        let output = file_io
+            .new_output(&manifest_list_location)
+            .map_err(iceberg_error)?;
+        let mut manifest_list_writer =
+            ManifestListWriter::v2(output, snapshot_id, None, sequence_number);
+        manifest_list_writer
+            .add_manifests(manifest_files.into_iter())
+            .map_err(iceberg_error)?;
+        manifest_list_writer.close().await.map_err(iceberg_error)?;
+
+        // 4. build the table metadata, it replaces the previous version as a whole
+        let table_uuid = previous
+            .as_ref()
+            .and_then(|v| v["table-uuid"].as_str())
+            .map(|v| v.to_string())
+            .unwrap_or_else(|| Uuid::new_v4().to_string());
+        let mut metadata_log = previous
+            .as_ref()
+            .and_then(|v| v["metadata-log"].as_array().cloned())
+            .unwrap_or_default();
+        if let Some(previous) = &previous {
+            metadata_log.push(json!({
+                "metadata-file": uri(&location_generator.gen_iceberg_metadata_location(version - 1)),
+                "timestamp-ms": previous["last-updated-ms"],
+            }));
+        }
+        if metadata_log.len() > MAX_PREVIOUS_METADATA_VERSIONS {
+            metadata_log.drain(..metadata_log.len() - MAX_PREVIOUS_METADATA_VERSIONS);
+        }
+
+        // Blocks written by the elder versions do not carry parquet field ids, the names of
+        // the columns when they are written are kept in the name mapping, so that renamed
+        // columns can still be resolved.
+        let mut previous_names: HashMap<i64, Vec<Value>> = previous
+            .as_ref()
+            .and_then(|v| v["properties"][PROPERTY_NAME_MAPPING].as_str())
+            .and_then(|v| serde_json::from_str::<Vec<Value>>(v).ok())
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|v| {
+                let field_id = v["field-id"].as_i64()?;
+                Some((field_id, v["names"].as_array().cloned().unwrap_or_default()))
+            })
+            .collect();
+        let name_mapping = schema
+            .fields()
+            .iter()
+            .map(|field| {
+                let field_id = iceberg_field_id(field);
+                let mut names = vec![json!(field.name())];
+                for name in previous_names
+                    .remove(&(field_id as i64))
+                    .unwrap_or_default()
+                {
+                    if !names.contains(&name) {
+                        names.push(name);
+                    }
+                }
+                json!({"field-id": field_id, "names": names})
+            })
+            .collect::<Vec<_>>();
+
+        let metadata = json!({
+            "format-version": 2,
+            "table-uuid": table_uuid,
+            "location": uri(location_generator.prefix()),
+            "last-sequence-number": sequence_number,
+            "last-updated-ms": Utc::now().timestamp_millis(),
+            "last-column-id": schema.next_column_id(),
+            "current-schema-id": 0,
+            "schemas": [schema_json],
+            "default-spec-id": 0,
+            "partition-specs": [{"spec-id": 0, "fields": []}],
+            "last-partition-id": 999,
+            "default-sort-order-id": 0,
+            "sort-orders": [{"order-id": 0, "fields": []}],
+            "properties": {
+                PROPERTY_NAME_MAPPING: serde_json::to_string(&name_mapping)?,
+                PROPERTY_FUSE_SNAPSHOT_ID: snapshot.snapshot_id.simple().to_string(),
+                PROPERTY_EXPORT_VERSION: export_version.to_string(),
+            },
+            "current-snapshot-id": snapshot_id,
+            "snapshots": [{
+                "snapshot-id": snapshot_id,
+                "sequence-number": sequence_number,
+                "timestamp-ms": timestamp_ms,
+                "manifest-list": manifest_list_location,
+                "summary": {
+                    "operation": "overwrite",
+                    "total-records": snapshot.summary.row_count.to_string(),
+                    "total-data-files": snapshot.summary.block_count.to_string(),
+                    "total-files-size": snapshot.summary.compressed_byte_size.to_string(),
+                },
+                "schema-id": 0,
+            }],
+            "snapshot-log": [{"snapshot-id": snapshot_id, "timestamp-ms": timestamp_ms}],
+            "metadata-log": metadata_log,
+            "refs": {"main": {"snapshot-id": snapshot_id, "type": "branch"}},
+        });
+        // make sure what we write down is a valid iceberg table metadata
+        Ok(serde_json::from_value(metadata)?)
+    }
+
+    // Returns the current version of exported iceberg metadata, and its content.
+    #[async_backtrace::framed]
+    async fn read_iceberg_metadata(
+        operator: &Operator,
+        location_generator: &TableMetaLocationGenerator,
+    ) -> Result<(u64, Option<Value>)> {
+        let hint_location = location_generator.gen_iceberg_version_hint_location();
+        let mut version = match operator.read(&hint_location).await {
+            Ok(data) => String::from_utf8(data.to_vec())?
+                .trim()
+                .parse::<u64>()
+                .map_err(|e| {
+                    ErrorCode::StorageOther(format!(
+                        "invalid iceberg version hint {}: {}",
+                        hint_location, e
+                    ))
+                })?,
+            Err(e) if e.kind() == opendal::ErrorKind::NotFound => 0,
+            Err(e) => return Err(e.into()),
+        };
+        // The version hint may fall behind if it is written concurrently, probe the later
+        // versions like the iceberg hadoop catalog does.
+        while operator
+            .exists(&location_generator.gen_iceberg_metadata_location(version + 1))
+            .await?
+        {
+            version += 1;
+        }
+        if version == 0 {
+            return Ok((0, None));
+        }
+        let metadata_location = location_generator.gen_iceberg_metadata_location(version);
+        match operator.read(&metadata_location).await {
+            Ok(data) => Ok((version, Some(serde_json::from_slice(&data.to_vec())?))),
+            Err(e) if e.kind() == opendal::ErrorKind::NotFound => Ok((version, None)),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+// Exports are ordered by the version of the table. A snapshot committed on top of a table
+// version is newer than the snapshot of that version, though the next table version is unknown.
+fn iceberg_export_version(table_info: &TableInfo, committed_on_top: bool) -> u64 {
+    table_info.ident.seq * 2 + committed_on_top as u64
+}
+
+// Writes `data` to `path` if the file does not exist, returns false if it exists.
+async fn write_if_not_exists(operator: &Operator, path: &str, data: Vec<u8>) -> Result<bool> {
+    if operator.info().full_capability().write_with_if_not_exists {
+        return match operator.write_with(path, data).if_not_exists(true).await {
+            Ok(_) => Ok(true),
+            Err(e) if e.kind() == opendal::ErrorKind::ConditionNotMatch => Ok(false),
+            Err(e) => Err(e.into()),
+        };
+    }
+    // the storage is not able to write conditionally, check the existence as the best effort
+    if operator.exists(path).await? {
+        return Ok(false);
+    }
+    operator.write(path, data).await?;
+    Ok(true)
+}
+
+/// Checks that the table can be exported as iceberg: all the columns of `schema` have a
+/// corresponding iceberg type, and iceberg is able to write to the storage of the table.
+pub fn check_iceberg_export(
+    schema: &TableSchema,
+    storage_params: Option<&StorageParams>,
+) -> Result<()> {
+    iceberg_schema_json(schema)?;
+    match storage_params {
+        Some(params) => iceberg_file_io(params)?,
+        None => iceberg_file_io(&DataOperator::instance().params())?,
+    };
+    Ok(())
+}
+
+fn iceberg_datum(scalar: &Scalar) -> Option<Datum> {
+    let datum = match scalar {
+        Scalar::Boolean(v) => Datum::bool(*v),
+        Scalar::Number(num) => match num {
+            NumberScalar::Int8(v) => Datum::int(*v as i32),
+            NumberScalar::Int16(v) => Datum::int(*v as i32),
+            NumberScalar::Int32(v) => Datum::int(*v),
+            NumberScalar::UInt8(v) => Datum::int(*v as i32),
+            NumberScalar::UInt16(v) => Datum::int(*v as i32),
+            NumberScalar::Int64(v) => Datum::long(*v),
+            NumberScalar::UInt32(v) => Datum::long(*v as i64),
+            NumberScalar::Float32(v) => Datum::float(v.into_inner()),
+            NumberScalar::Float64(v) => Datum::double(v.into_inner()),
+            NumberScalar::UInt64(_) => return None,
+        },
+        Scalar::String(v) => Datum::string(v),
+        Scalar::Date(v) => Datum::date(*v),
+        Scalar::Timestamp(v) => Datum::timestamp_micros(*v),
+        _ => return None,
+    };
+    Some(datum)
+}
+
+fn iceberg_data_file(
+    schema: &TableSchema,
+    block: &BlockMeta,
+    file_path: String,
+) -> Result<DataFile> {
+    let mut column_sizes = HashMap::new();
+    let mut value_counts = HashMap::new();
+    let mut null_value_counts = HashMap::new();
+    let mut lower_bounds = HashMap::new();
+    let mut upper_bounds = HashMap::new();
+    for field in schema.fields() {
+        let field_id = iceberg_field_id(field);
+        if let Some(column_meta) = block.col_metas.get(&field.column_id()) {
+            column_sizes.insert(field_id, column_meta.offset_length().1);
+            value_counts.insert(field_id, column_meta.total_rows() as u64);
+        }
+        if let Some(stat) = block.col_stats.get(&field.column_id()) {
+            null_value_counts.insert(field_id, stat.null_count);
+            if let Some(min) = iceberg_datum(stat.min()) {
+                lower_bounds.insert(field_id, min);
+            }
+            if let Some(max) = iceberg_datum(stat.max()) {
+                upper_bounds.insert(field_id, max);
+            }
+        }
+    }
+
+    DataFileBuilder::default()
+        .content(DataContentType::Data)
+        .file_path(file_path)
+        .file_format(DataFileFormat::Parquet)
+        .partition(Struct::empty())
+        .record_count(block.row_count)
+        .file_size_in_bytes(block.file_size)
+        .column_sizes(column_sizes)
+        .value_counts(value_counts)
+        .null_value_counts(null_value_counts)
+        .lower_bounds(lower_bounds)
+        .upper_bounds(upper_bounds)
+        .build()
+        .map_err(iceberg_error)
+}
+
+// Iceberg writes manifests through its own `FileIO`, which is built from the storage params
+// of the table. Returns the file io and the uri of the storage root.
+fn iceberg_file_io(params: &StorageParams) -> Result<(FileIO, String)> {
+    match params {
+        StorageParams::Fs(cfg) => {
+            let root = std::path::absolute(&cfg.root)?;
+            let file_io = FileIOBuilder::new("file").build().map_err(iceberg_error)?;
+            Ok((file_io, format!("file://{}", root.display())))
+        }
+        StorageParams::S3(cfg) => {
+            let mut props = vec![
+                ("s3.endpoint".to_string(), cfg.endpoint_url.clone()),
+                ("s3.access-key-id".to_string(), cfg.access_key_id.clone()),
+                (
+                    "s3.secret-access-key".to_string(),
+                    cfg.secret_access_key.clone(),
+                ),
+            ];
+            if !cfg.region.is_empty() {
+                props.push(("s3.region".to_string(), cfg.region.clone()));
+            }
+            if !cfg.security_token.is_empty() {
+                props.push(("s3.session-token".to_string(), cfg.security_token.clone()));
+            }
+            let file_io = FileIOBuilder::new("s3")
+                .with_props(props)
+                .build()
+                .map_err(iceberg_error)?;
+            let root = cfg.root.trim_matches('/');
+            let storage_root = if root.is_empty() {
+                format!("s3://{}", cfg.bucket)
+            } else {
+                format!("s3://{}/{}", cfg.bucket, root)
+            };
+            Ok((file_io, storage_root))
+        }
+        other => Err(ErrorCode::StorageUnsupported(format!(
+            "iceberg export is not supported on storage {}",
+            other
+        ))),
+    }
+}
+
+fn iceberg_error(e: impl Debug) -> ErrorCode {
+    ErrorCode::StorageOther(format!("export iceberg metadata failed: {e:?}"))
+}
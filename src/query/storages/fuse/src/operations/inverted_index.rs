@@ -46,6 +46,7 @@ use databend_storages_common_cache::LoadParams;
 use databend_storages_common_io::ReadSettings;
 use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::meta::Location;
+use databend_storages_common_table_meta::meta::TableSnapshot;
 use opendal::Operator;
 
 use crate::io::block_to_inverted_index;
@@ -102,52 +103,13 @@ impl FuseTable {
         let block_reader =
             self.create_block_reader(ctx.clone(), projection, false, false, false)?;
 
-        let segment_reader =
-            MetaReaders::segment_info_reader(self.get_operator(), table_schema.clone());
-
-        // If no segment locations are specified, iterates through all segments
-        let segment_locs = if let Some(segment_locs) = segment_locs {
-            segment_locs
-                .into_iter()
-                .filter(|s| snapshot.segments.contains(s))
-                .collect()
-        } else {
-            snapshot.segments.clone()
-        };
-
-        if segment_locs.is_empty() {
-            return Ok(());
-        }
-        let operator = self.get_operator_ref();
-
-        // Read the segment infos and collect the block metas that need to generate the index.
-        let mut block_metas = VecDeque::new();
-        for (segment_loc, ver) in &segment_locs {
-            let segment_info = segment_reader
-                .read(&LoadParams {
-                    location: segment_loc.to_string(),
-                    len_hint: None,
-                    ver: *ver,
-                    put_cache: false,
-                })
-                .await?;
-
-            for block_meta in segment_info.block_metas()? {
-                let index_location =
-                    TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
-                        &block_meta.location.0,
-                        &index_name,
-                        &index_version,
-                    );
-                // only generate inverted index if it is not exist.
-                if (operator.stat(&index_location).await).is_err() {
-                    block_metas.push_back(block_meta);
-                }
-            }
-        }
+        let block_metas = self
+            .collect_unindexed_block_metas(&snapshot, segment_locs, &index_name, &index_version)
+            .await?;
         if block_metas.is_empty() {
             return Ok(());
         }
+        let operator = self.get_operator_ref();
 
         let data_schema = Arc::new(DataSchema::from(index_schema.as_ref()));
         let settings = ReadSettings::from_ctx(&ctx)?;
@@ -187,6 +149,57 @@ impl FuseTable {
 
         Ok(())
     }
+
+    // Collect the block metas that don't have the index file in the segments,
+    // if no segment locations are specified, iterates through all segments.
+    #[async_backtrace::framed]
+    pub(crate) async fn collect_unindexed_block_metas(
+        &self,
+        snapshot: &TableSnapshot,
+        segment_locs: Option<Vec<Location>>,
+        index_name: &str,
+        index_version: &str,
+    ) -> Result<VecDeque<Arc<BlockMeta>>> {
+        let table_schema = &self.get_table_info().meta.schema;
+        let segment_reader =
+            MetaReaders::segment_info_reader(self.get_operator(), table_schema.clone());
+
+        let segment_locs = if let Some(segment_locs) = segment_locs {
+            segment_locs
+                .into_iter()
+                .filter(|s| snapshot.segments.contains(s))
+                .collect()
+        } else {
+            snapshot.segments.clone()
+        };
+
+        let operator = self.get_operator_ref();
+        let mut block_metas = VecDeque::new();
+        for (segment_loc, ver) in &segment_locs {
+            let segment_info = segment_reader
+                .read(&LoadParams {
+                    location: segment_loc.to_string(),
+                    len_hint: None,
+                    ver: *ver,
+                    put_cache: false,
+                })
+                .await?;
+
+            for block_meta in segment_info.block_metas()? {
+                let index_location =
+                    TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+                        &block_meta.location.0,
+                        index_name,
+                        index_version,
+                    );
+                // only generate index if it is not exist.
+                if (operator.stat(&index_location).await).is_err() {
+                    block_metas.push_back(block_meta);
+                }
+            }
+        }
+        Ok(block_metas)
+    }
 }
 
 /// `InvertedIndexSource` is used to read data blocks that need generate inverted indexes.
@@ -26,7 +26,9 @@ use databend_storages_common_table_meta::meta::Location;
 use super::merge_into::MatchedAggregator;
 use super::mutation::SegmentIndex;
 use crate::io::create_inverted_index_builders;
+use crate::io::create_vector_index_builders;
 use crate::io::BlockBuilder;
+use crate::io::VirtualColumnBuilder;
 use crate::statistics::ClusterStatsGenerator;
 use crate::FuseTable;
 
@@ -90,7 +92,16 @@ impl FuseTable {
         let bloom_columns_map = self
             .bloom_index_cols()
             .bloom_index_fields(new_schema.clone(), BloomIndex::supported_type)?;
+        let ngram_columns_map = self
+            .ngram_index_cols()
+            .bloom_index_fields(new_schema.clone(), BloomIndex::supported_ngram_type)?;
+        let set_columns_map = self
+            .set_index_cols()
+            .bloom_index_fields(new_schema.clone(), BloomIndex::supported_set_type)?;
         let inverted_index_builders = create_inverted_index_builders(&self.table_info.meta);
+        let vector_index_builders = create_vector_index_builders(&self.table_info.meta);
+        let virtual_column_builder =
+            VirtualColumnBuilder::try_create(ctx.as_ref(), self, &new_schema);
 
         let block_builder = BlockBuilder {
             ctx: ctx.clone(),
@@ -99,7 +110,12 @@ impl FuseTable {
             write_settings: self.get_write_settings(),
             cluster_stats_gen,
             bloom_columns_map,
+            ngram_columns_map,
+            set_columns_map,
+            set_max_values: self.set_index_max_values(),
             inverted_index_builders,
+            vector_index_builders,
+            virtual_column_builder,
         };
         let aggregator = MatchedAggregator::create(
             ctx,
@@ -20,6 +20,7 @@ mod commit;
 mod common;
 mod compact;
 mod gc;
+mod iceberg_export;
 mod inverted_index;
 mod merge;
 mod merge_into;
@@ -35,12 +36,15 @@ mod replace_into;
 mod revert;
 mod truncate;
 mod util;
+mod vector_index;
+mod virtual_column;
 
 pub use agg_index_sink::AggIndexSink;
 pub use analyze::HistogramInfoSink;
 pub use changes::ChangesDesc;
 pub use common::*;
 pub use compact::CompactOptions;
+pub use iceberg_export::check_iceberg_export;
 pub use merge_into::*;
 pub use mutation::*;
 pub use mutation_source::*;
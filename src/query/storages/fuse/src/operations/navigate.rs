@@ -60,6 +60,9 @@ impl FuseTable {
                     .await
             }
             NavigationPoint::StreamInfo(info) => self.navigate_to_stream(info).await,
+            NavigationPoint::Version(_) => Err(ErrorCode::Unimplemented(
+                "Time travel by VERSION is not supported for fuse table, use SNAPSHOT instead",
+            )),
         }
     }
 
@@ -241,6 +244,9 @@ impl FuseTable {
                     .await
             }
             Some(NavigationPoint::StreamInfo(info)) => self.list_by_stream(info, time_point).await,
+            Some(NavigationPoint::Version(_)) => Err(ErrorCode::Unimplemented(
+                "Purge by VERSION is not supported for fuse table, use SNAPSHOT instead",
+            )),
             None => self.list_by_time_point(time_point).await,
         }?;
 
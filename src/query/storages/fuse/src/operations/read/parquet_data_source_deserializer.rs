@@ -25,7 +25,6 @@ use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::PartInfoPtr;
 use databend_common_catalog::runtime_filter_info::RuntimeFilterReady;
 use databend_common_catalog::table_context::TableContext;
-use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::Bitmap;
 use databend_common_expression::types::DataType;
@@ -357,14 +356,7 @@ impl Processor for DeserializeDataTransform {
 
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
-        let runtime_filter_ready = self.runtime_filter_ready.as_mut().unwrap();
-        let mut rx = runtime_filter_ready.runtime_filter_watcher.subscribe();
-        if (*rx.borrow()).is_some() {
-            return Ok(());
-        }
-        rx.changed()
-            .await
-            .map_err(|_| ErrorCode::TokioError("watcher's sender is dropped"))?;
-        Ok(())
+        let runtime_filter_ready = self.runtime_filter_ready.as_ref().unwrap();
+        runtime_filter_ready.wait().await
     }
 }
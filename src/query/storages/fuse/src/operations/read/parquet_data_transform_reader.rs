@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use databend_common_base::runtime::GlobalIORuntime;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -40,6 +41,7 @@ use crate::io::TableMetaLocationGenerator;
 use crate::io::VirtualColumnReader;
 use crate::operations::read::block_partition_meta::BlockPartitionMeta;
 use crate::operations::read::data_source_with_meta::DataSourceWithMeta;
+use crate::operations::read::runtime_filter_prunner::runtime_filter_bloom_digest_pruner;
 use crate::operations::read::runtime_filter_prunner::runtime_filter_pruner;
 
 pub struct ReadParquetDataTransform<const BLOCKING_IO: bool> {
@@ -134,6 +136,19 @@ impl Transform for ReadParquetDataTransform<true> {
                 )? {
                     return Ok(DataBlock::empty());
                 }
+                let digests = self
+                    .context
+                    .get_bloom_digest_runtime_filter_with_id(self.table_index);
+                if !digests.is_empty()
+                    && GlobalIORuntime::instance().block_on(runtime_filter_bloom_digest_pruner(
+                        self.table_schema.clone(),
+                        &part,
+                        &digests,
+                        self.block_reader.operator.clone(),
+                    ))?
+                {
+                    return Ok(DataBlock::empty());
+                }
 
                 if let Some(index_reader) = self.index_reader.as_ref() {
                     let fuse_part = FuseBlockPartInfo::from_part(&part)?;
@@ -210,6 +225,9 @@ impl AsyncTransform for ReadParquetDataTransform<false> {
                         self.context
                             .get_min_max_runtime_filter_with_id(self.table_index),
                     );
+                    let digests = self
+                        .context
+                        .get_bloom_digest_runtime_filter_with_id(self.table_index);
                     let mut fuse_part_infos = Vec::with_capacity(parts.len());
                     for part in parts.into_iter() {
                         if runtime_filter_pruner(
@@ -220,6 +238,16 @@ impl AsyncTransform for ReadParquetDataTransform<false> {
                         )? {
                             continue;
                         }
+                        if runtime_filter_bloom_digest_pruner(
+                            self.table_schema.clone(),
+                            &part,
+                            &digests,
+                            self.block_reader.operator.clone(),
+                        )
+                        .await?
+                        {
+                            continue;
+                        }
 
                         fuse_part_infos.push(part.clone());
                         let block_reader = self.block_reader.clone();
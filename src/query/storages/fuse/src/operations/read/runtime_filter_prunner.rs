@@ -35,12 +35,18 @@ use databend_common_expression::Scalar;
 use databend_common_expression::TableSchema;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_hashtable::FastHash;
+use databend_storages_common_index::filters::V2BloomBlock;
 use databend_storages_common_index::statistics_to_domain;
+use databend_storages_common_index::BloomIndex;
+use databend_storages_common_table_meta::meta::Versioned;
 use log::debug;
 use log::info;
+use log::warn;
+use opendal::Operator;
 use xorf::BinaryFuse16;
 use xorf::Filter;
 
+use crate::io::BloomBlockFilterReader;
 use crate::FuseBlockPartInfo;
 
 pub fn runtime_filter_pruner(
@@ -100,6 +106,90 @@ pub fn runtime_filter_pruner(
     Ok(pruned)
 }
 
+/// Prune the partition if none of the build keys of a runtime filter is contained by the
+/// bloom index of the block, the build keys are given as the digests of the bloom index.
+pub async fn runtime_filter_bloom_digest_pruner(
+    table_schema: Arc<TableSchema>,
+    part: &PartInfoPtr,
+    digests: &[(String, Vec<u64>)],
+    dal: Operator,
+) -> Result<bool> {
+    if digests.is_empty() {
+        return Ok(false);
+    }
+    let part = FuseBlockPartInfo::from_part(part)?;
+    let Some(location) = &part.bloom_filter_index_location else {
+        return Ok(false);
+    };
+    // The digests of the V2 bloom filters are hashed in another way.
+    if location.1 == V2BloomBlock::VERSION {
+        return Ok(false);
+    }
+
+    let mut filter_columns = Vec::with_capacity(digests.len());
+    for (name, digests) in digests {
+        let Ok(field) = table_schema.field_with_name(name) else {
+            continue;
+        };
+        if !BloomIndex::supported_type(field.data_type()) {
+            continue;
+        }
+        // The block is stored before the column is widened, its filter is built from the
+        // values of the narrower type.
+        if table_schema
+            .widened_column_sources(field.column_id())
+            .iter()
+            .any(|source| part.columns_meta.contains_key(&source.column_id))
+        {
+            return Ok(false);
+        }
+        let Ok(filter_column) = BloomIndex::build_filter_column_name(location.1, field) else {
+            return Ok(false);
+        };
+        filter_columns.push((filter_column, digests));
+    }
+    if filter_columns.is_empty() {
+        return Ok(false);
+    }
+
+    let columns = filter_columns
+        .iter()
+        .map(|(column, _)| column.clone())
+        .collect::<Vec<_>>();
+    let block_filter = match location
+        .read_block_filter(dal, &columns, part.bloom_filter_index_size)
+        .await
+    {
+        Ok(block_filter) => block_filter,
+        Err(e) => {
+            // broken index should not stop us, just do not prune
+            warn!("failed to load bloom filter of {}: {}", location.0, e);
+            return Ok(false);
+        }
+    };
+
+    // The filter may be missing, e.g. the column is not in the bloom index columns.
+    let pruned = filter_columns.iter().any(|(column, digests)| {
+        block_filter
+            .filter_schema
+            .index_of(column)
+            .is_ok_and(|idx| {
+                let filter = &block_filter.filters[idx];
+                !digests.iter().any(|digest| filter.contains_digest(*digest))
+            })
+    });
+
+    if pruned {
+        info!(
+            "Pruned partition with {:?} rows by bloom digest runtime filter",
+            part.nums_rows
+        );
+        Profile::record_usize_profile(ProfileStatisticsName::RuntimeFilterPruneParts, 1);
+    }
+
+    Ok(pruned)
+}
+
 pub(crate) fn update_bitmap_with_bloom_filter(
     column: Column,
     filter: &BinaryFuse16,
@@ -35,6 +35,8 @@ use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::ColumnId;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableSchemaRef;
 use databend_common_pipeline_core::ExecutionInfo;
@@ -43,6 +45,7 @@ use databend_common_sql::field_default_value;
 use databend_common_storage::ColumnNodes;
 use databend_storages_common_cache::CacheAccessor;
 use databend_storages_common_cache::CachedObject;
+use databend_storages_common_index::widened_column_statistics;
 use databend_storages_common_index::BloomIndex;
 use databend_storages_common_pruner::BlockMetaIndex;
 use databend_storages_common_pruner::TopNPrunner;
@@ -51,6 +54,7 @@ use databend_storages_common_table_meta::meta::ColumnStatistics;
 use databend_storages_common_table_meta::table::ChangeType;
 use databend_storages_common_table_meta::table::ClusterType;
 use log::info;
+use log::warn;
 use opendal::Operator;
 use sha2::Digest;
 use sha2::Sha256;
@@ -72,6 +76,7 @@ use crate::pruning_pipeline::SendPartInfoSink;
 use crate::pruning_pipeline::SendPartState;
 use crate::pruning_pipeline::SyncBlockPruneTransform;
 use crate::pruning_pipeline::TopNPruneTransform;
+use crate::pruning_pipeline::VectorIndexPruneTransform;
 use crate::FuseLazyPartInfo;
 use crate::FuseTable;
 
@@ -103,6 +108,14 @@ impl FuseTable {
             push_downs,
             snapshot.as_ref().map(|sn| sn.snapshot_id)
         );
+
+        // Register the paths in the predicates, which are materialized by the following writes.
+        if !dry_run && self.auto_virtual_columns_enabled() {
+            let names = Self::predicate_virtual_columns(push_downs.as_ref());
+            if let Err(e) = self.register_auto_virtual_columns(ctx.clone(), names).await {
+                warn!("register predicate virtual columns failed. {:?}", e);
+            }
+        }
         match snapshot {
             Some(snapshot) => {
                 // To optimize the Hilbert clustering logic, it is necessary to pre-set the selected segments.
@@ -387,13 +400,14 @@ impl FuseTable {
         let block_pruner = Arc::new(BlockPruner::create(pruner.pruning_ctx.clone())?);
         if pruner.pruning_ctx.bloom_pruner.is_some()
             || pruner.pruning_ctx.inverted_index_pruner.is_some()
+            || pruner.pruning_ctx.vector_index_pruner.is_some()
         {
-            // async pruning with bloom index or inverted index.
+            // async pruning with bloom index, inverted index or vector index.
             prune_pipeline.add_transform(|input, output| {
                 AsyncBlockPruneTransform::create(input, output, block_pruner.clone())
             })?;
         } else {
-            // sync pruning without a bloom index and inverted index.
+            // sync pruning without a bloom index, inverted index and vector index.
             prune_pipeline.add_transform(|input, output| {
                 SyncBlockPruneTransform::create(input, output, block_pruner.clone())
             })?;
@@ -401,6 +415,14 @@ impl FuseTable {
 
         let push_down = pruner.push_down.clone();
 
+        if let Some(vector_index_pruner) = &pruner.pruning_ctx.vector_index_pruner {
+            // keep the blocks contain the nearest candidates after all the blocks are searched
+            prune_pipeline.resize(1, false)?;
+            prune_pipeline.add_transform(|input, output| {
+                VectorIndexPruneTransform::create(input, output, vector_index_pruner.clone())
+            })?;
+        }
+
         if push_down
             .as_ref()
             .filter(|p| !p.order_by.is_empty() && p.limit.is_some() && p.filters.is_none())
@@ -478,6 +500,12 @@ impl FuseTable {
             let bloom_columns_map = self
                 .bloom_index_cols()
                 .bloom_index_fields(table_schema.clone(), BloomIndex::supported_type)?;
+            let ngram_columns_map = self
+                .ngram_index_cols()
+                .bloom_index_fields(table_schema.clone(), BloomIndex::supported_ngram_type)?;
+            let set_columns_map = self
+                .set_index_cols()
+                .bloom_index_fields(table_schema.clone(), BloomIndex::supported_set_type)?;
 
             Some(BloomIndexBuilder {
                 table_ctx: ctx.clone(),
@@ -485,6 +513,9 @@ impl FuseTable {
                 table_dal: dal.clone(),
                 storage_format,
                 bloom_columns_map,
+                ngram_columns_map,
+                set_columns_map,
+                set_max_values: self.set_index_max_values(),
             })
         } else {
             None
@@ -498,6 +529,8 @@ impl FuseTable {
                     table_schema.clone(),
                     &push_downs,
                     self.bloom_index_cols(),
+                    self.ngram_index_cols(),
+                    self.set_index_cols(),
                     bloom_index_builder,
                     self.get_storage_format(),
                 )?
@@ -512,6 +545,8 @@ impl FuseTable {
                     self.cluster_key_meta.clone(),
                     cluster_keys,
                     self.bloom_index_cols(),
+                    self.ngram_index_cols(),
+                    self.set_index_cols(),
                     bloom_index_builder,
                     self.get_storage_format(),
                 )?
@@ -801,7 +836,9 @@ impl FuseTable {
         for column_id in meta.col_metas.keys() {
             // ignore all deleted field
             if let Some(schema) = schema {
-                if schema.is_column_deleted(*column_id) {
+                if schema.is_column_deleted(*column_id)
+                    && !schema.is_widened_column_source(*column_id)
+                {
                     continue;
                 }
             }
@@ -816,15 +853,29 @@ impl FuseTable {
             }
         }
 
+        // the block may be written before the data type of columns are widened,
+        // adapt their statistics to the current data type.
+        if let Some(schema) = schema {
+            for column_id in schema.widened_columns.keys() {
+                if meta.col_stats.contains_key(column_id) || schema.is_column_deleted(*column_id) {
+                    continue;
+                }
+                if let Some(stat) = Self::widened_column_stat(schema, meta, *column_id) {
+                    columns_stats.insert(*column_id, stat);
+                }
+            }
+        }
+
         let rows_count = meta.row_count;
         let location = meta.location.0.clone();
         let create_on = meta.create_on;
 
         let sort_min_max = top_k.as_ref().map(|(top_k, default)| {
-            meta.col_stats
-                .get(&top_k.field.column_id)
-                .map(|stat| (stat.min().clone(), stat.max().clone()))
-                .unwrap_or((default.clone(), default.clone()))
+            let column_id = top_k.field.column_id;
+            match columns_stats.get(&column_id) {
+                Some(stat) => (stat.min().clone(), stat.max().clone()),
+                None => (default.clone(), default.clone()),
+            }
         });
 
         FuseBlockPartInfo::create(
@@ -836,6 +887,28 @@ impl FuseTable {
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            meta.bloom_filter_index_location.clone(),
+            meta.bloom_filter_index_size,
+        )
+    }
+
+    /// Returns the statistics of a column whose data type was widened after the block was written.
+    fn widened_column_stat(
+        schema: &TableSchemaRef,
+        meta: &BlockMeta,
+        column_id: ColumnId,
+    ) -> Option<ColumnStatistics> {
+        let field = schema.field_of_column_id(column_id).ok()?;
+        let widened_from = schema
+            .widened_column_sources(column_id)
+            .iter()
+            .map(|source| source.column_id)
+            .collect::<Vec<_>>();
+        widened_column_statistics(
+            &meta.col_stats,
+            column_id,
+            &widened_from,
+            &DataType::from(field.data_type()),
         )
     }
 
@@ -852,11 +925,26 @@ impl FuseTable {
         let columns = projection.project_column_nodes(column_nodes).unwrap();
         for column in &columns {
             for column_id in &column.leaf_column_ids {
+                // the block may store the column under a previous column id,
+                // if it was written before the data type of the column is widened.
+                let stored_column_id = column
+                    .stored_column_id(*column_id, |id| meta.col_metas.contains_key(id))
+                    .unwrap_or(*column_id);
                 // ignore column this block dose not exist
-                if let Some(column_meta) = meta.col_metas.get(column_id) {
-                    columns_meta.insert(*column_id, column_meta.clone());
+                if let Some(column_meta) = meta.col_metas.get(&stored_column_id) {
+                    columns_meta.insert(stored_column_id, column_meta.clone());
                 }
-                if let Some(column_stat) = meta.col_stats.get(column_id) {
+                if stored_column_id != *column_id {
+                    let data_type = DataType::from(column.table_field.data_type());
+                    if let Some(column_stat) = widened_column_statistics(
+                        &meta.col_stats,
+                        *column_id,
+                        &column.widened_from,
+                        &data_type,
+                    ) {
+                        columns_stat.insert(*column_id, column_stat);
+                    }
+                } else if let Some(column_stat) = meta.col_stats.get(column_id) {
                     columns_stat.insert(*column_id, column_stat.clone());
                 }
             }
@@ -867,7 +955,22 @@ impl FuseTable {
         let create_on = meta.create_on;
 
         let sort_min_max = top_k.map(|(top_k, default)| {
-            let stat = meta.col_stats.get(&top_k.field.column_id);
+            let column_id = top_k.field.column_id;
+            let stat = match columns_stat.get(&column_id) {
+                Some(stat) => Some(stat.clone()),
+                None => column_nodes
+                    .column_nodes
+                    .iter()
+                    .find(|column| column.leaf_column_ids == [column_id])
+                    .and_then(|column| {
+                        widened_column_statistics(
+                            &meta.col_stats,
+                            column_id,
+                            &column.widened_from,
+                            &DataType::from(top_k.field.data_type()),
+                        )
+                    }),
+            };
             stat.map(|stat| (stat.min().clone(), stat.max().clone()))
                 .unwrap_or((default.clone(), default))
         });
@@ -884,6 +987,8 @@ impl FuseTable {
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            meta.bloom_filter_index_location.clone(),
+            meta.bloom_filter_index_size,
         )
     }
 }
@@ -252,6 +252,8 @@ impl FuseTable {
             None,
             vec![],
             BloomIndexColumns::None,
+            BloomIndexColumns::None,
+            BloomIndexColumns::None,
             max_concurrency,
             bloom_index_builder,
             storage_format,
@@ -44,8 +44,7 @@ use databend_common_sql::evaluator::BlockOperator;
 use databend_common_sql::executor::physical_plans::OnConflictField;
 use databend_common_sql::StreamContext;
 use databend_storages_common_cache::LoadParams;
-use databend_storages_common_index::filters::Filter;
-use databend_storages_common_index::filters::Xor8Filter;
+use databend_storages_common_index::filters::FilterImpl;
 use databend_storages_common_index::BloomIndex;
 use databend_storages_common_io::ReadSettings;
 use databend_storages_common_table_meta::meta::BlockMeta;
@@ -724,7 +723,7 @@ impl AggregationContext {
         location: &Location,
         index_len: u64,
         bloom_on_conflict_field_index: &[FieldIndex],
-    ) -> Result<Vec<Option<Arc<Xor8Filter>>>> {
+    ) -> Result<Vec<Option<Arc<FilterImpl>>>> {
         // different block may have different version of bloom filter index
         let mut col_names = Vec::with_capacity(bloom_on_conflict_field_index.len());
 
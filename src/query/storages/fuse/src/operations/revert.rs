@@ -21,6 +21,7 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_types::MatchSeq;
+use log::warn;
 
 use crate::FuseTable;
 
@@ -74,6 +75,24 @@ impl FuseTable {
                 &snapshot_location,
             )
             .await;
+            if Self::is_iceberg_export_enabled(&self.table_info.meta.options) {
+                let export = async {
+                    if let Some(snapshot) = table_reverting_to.read_table_snapshot().await? {
+                        Self::write_iceberg_metadata(
+                            &table_reverting_to.operator,
+                            &table_reverting_to.meta_location_generator,
+                            &self.table_info,
+                            &snapshot,
+                        )
+                        .await?;
+                    }
+                    Ok::<_, ErrorCode>(())
+                };
+                if let Err(e) = export.await {
+                    warn!("write iceberg metadata failure. {}", e);
+                    ctx.push_warning(format!("failed to export iceberg metadata: {}", e));
+                }
+            }
         };
 
         reply.map(|_| ())
@@ -0,0 +1,161 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::Projection;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sources::AsyncSourcer;
+use databend_common_pipeline_transforms::processors::AsyncTransform;
+use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
+use databend_storages_common_io::ReadSettings;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::meta::Location;
+use opendal::Operator;
+
+use crate::io::BlockWriter;
+use crate::io::VectorIndexBuilder;
+use crate::io::VectorIndexState;
+use crate::operations::inverted_index::InvertedIndexSink;
+use crate::operations::inverted_index::InvertedIndexSource;
+use crate::FuseTable;
+
+impl FuseTable {
+    // Refresh the vector index of the blocks without index file,
+    // the pipeline is the same as refresh inverted index:
+    //
+    // InvertedIndexSource -> VectorIndexTransform * N -> InvertedIndexSink
+    //
+    #[async_backtrace::framed]
+    pub async fn do_refresh_vector_index(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        vector_index_builder: VectorIndexBuilder,
+        segment_locs: Option<Vec<Location>>,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(());
+        };
+
+        let table_schema = &self.get_table_info().meta.schema;
+        let field_index = table_schema.index_of(&vector_index_builder.column_name)?;
+        let source_schema =
+            TableSchemaRefExt::create(vec![table_schema.field(field_index).clone()]);
+
+        let projection = Projection::Columns(vec![field_index]);
+        let block_reader =
+            self.create_block_reader(ctx.clone(), projection, false, false, false)?;
+
+        let block_metas = self
+            .collect_unindexed_block_metas(
+                &snapshot,
+                segment_locs,
+                &vector_index_builder.name,
+                &vector_index_builder.version,
+            )
+            .await?;
+        if block_metas.is_empty() {
+            return Ok(());
+        }
+        let operator = self.get_operator();
+
+        let settings = ReadSettings::from_ctx(&ctx)?;
+        let storage_format = self.get_write_settings().storage_format;
+
+        pipeline.add_source(
+            |output| {
+                let inner = InvertedIndexSource::new(
+                    settings,
+                    storage_format,
+                    block_reader.clone(),
+                    block_metas.clone(),
+                );
+                AsyncSourcer::create(ctx.clone(), output, inner)
+            },
+            1,
+        )?;
+
+        let block_nums = block_metas.len();
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let max_threads = std::cmp::min(block_nums, max_threads);
+        pipeline.try_resize(max_threads)?;
+        pipeline.add_async_transformer(|| {
+            VectorIndexTransform::new(
+                vector_index_builder.clone(),
+                source_schema.clone(),
+                operator.clone(),
+            )
+        });
+
+        pipeline.try_resize(1)?;
+        pipeline.add_sink(|input| InvertedIndexSink::try_create(input, block_nums))?;
+
+        Ok(())
+    }
+}
+
+/// `VectorIndexTransform` is used to generate vector index for each blocks.
+pub struct VectorIndexTransform {
+    vector_index_builder: VectorIndexBuilder,
+    source_schema: TableSchemaRef,
+    operator: Operator,
+}
+
+impl VectorIndexTransform {
+    pub fn new(
+        vector_index_builder: VectorIndexBuilder,
+        source_schema: TableSchemaRef,
+        operator: Operator,
+    ) -> Self {
+        Self {
+            vector_index_builder,
+            source_schema,
+            operator,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncTransform for VectorIndexTransform {
+    const NAME: &'static str = "VectorIndexTransform";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, data_block: DataBlock) -> Result<DataBlock> {
+        let block_meta = data_block
+            .get_meta()
+            .and_then(BlockMeta::downcast_ref_from)
+            .unwrap();
+
+        let vector_index_state = VectorIndexState::try_create(
+            &self.source_schema,
+            &data_block,
+            &block_meta.location,
+            &self.vector_index_builder,
+        )?;
+        BlockWriter::write_down_vector_index_state(
+            &self.operator,
+            vector_index_state.into_iter().collect(),
+        )
+        .await?;
+
+        Ok(DataBlock::new(vec![], 0))
+    }
+}
@@ -0,0 +1,209 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableSchema;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::CreateVirtualColumnReq;
+use databend_common_meta_app::schema::ListVirtualColumnsReq;
+use databend_common_meta_app::schema::UpdateVirtualColumnReq;
+use databend_common_meta_app::schema::VirtualColumnIdent;
+use databend_common_storage::read_parquet_schema_async_rs;
+use databend_storages_common_cache::LoadParams;
+use databend_storages_common_table_meta::meta::Location;
+
+use crate::io::BlockReader;
+use crate::io::MetaReaders;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseStorageFormat;
+use crate::FuseTable;
+use crate::FUSE_OPT_KEY_AUTO_VIRTUAL_COLUMNS;
+
+/// The max number of virtual columns registered for a table with auto virtual columns.
+const MAX_AUTO_VIRTUAL_COLUMNS_PER_TABLE: usize = 256;
+
+impl FuseTable {
+    pub fn is_auto_virtual_columns_enabled(options: &BTreeMap<String, String>) -> bool {
+        options
+            .get(FUSE_OPT_KEY_AUTO_VIRTUAL_COLUMNS)
+            .and_then(|v| v.to_lowercase().parse::<bool>().ok())
+            .unwrap_or(false)
+    }
+
+    /// Collect the names of the virtual columns written along with the blocks of the segments.
+    #[async_backtrace::framed]
+    pub async fn read_virtual_columns(&self, segment_locs: Vec<Location>) -> Result<Vec<String>> {
+        let segment_reader = MetaReaders::segment_info_reader(self.get_operator(), self.schema());
+
+        let mut names = HashSet::new();
+        let mut virtual_columns = Vec::new();
+        for (location, ver) in segment_locs {
+            let segment_info = segment_reader
+                .read(&LoadParams {
+                    location: location.to_string(),
+                    len_hint: None,
+                    ver,
+                    put_cache: false,
+                })
+                .await?;
+
+            for block_meta in segment_info.block_metas()? {
+                let virtual_loc =
+                    TableMetaLocationGenerator::gen_virtual_block_location(&block_meta.location.0);
+                let arrow_schema = match self.storage_format {
+                    FuseStorageFormat::Parquet => {
+                        read_parquet_schema_async_rs(&self.operator, &virtual_loc, None)
+                            .await
+                            .ok()
+                    }
+                    FuseStorageFormat::Native => {
+                        BlockReader::async_read_native_schema(&self.operator, &virtual_loc)
+                            .await
+                            .map(|(_, schema)| schema)
+                    }
+                };
+                let Some(arrow_schema) = arrow_schema else {
+                    continue;
+                };
+                let virtual_schema = TableSchema::try_from(&arrow_schema)?;
+                for field in virtual_schema.fields() {
+                    if names.insert(field.name().clone()) {
+                        virtual_columns.push(field.name().clone());
+                    }
+                }
+            }
+        }
+        Ok(virtual_columns)
+    }
+
+    /// List the names of the virtual columns registered for the table.
+    #[async_backtrace::framed]
+    pub async fn list_registered_virtual_columns(
+        &self,
+        ctx: &dyn TableContext,
+    ) -> Result<HashSet<String>> {
+        let catalog = ctx.get_catalog(self.table_info.catalog()).await?;
+        let req = ListVirtualColumnsReq::new(ctx.get_tenant(), Some(self.get_id()));
+        let metas = catalog.list_virtual_columns(req).await?;
+        Ok(metas
+            .first()
+            .map(|meta| {
+                meta.virtual_columns
+                    .iter()
+                    .map(|(name, _)| name.clone())
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    /// Collect the virtual columns referenced by the filters of a table scan
+    /// that are not registered yet, they are registered so that the following writes
+    /// materialize them even if they are not present in most of the rows.
+    pub fn predicate_virtual_columns(push_downs: Option<&PushDownInfo>) -> Vec<String> {
+        let Some(push_downs) = push_downs else {
+            return vec![];
+        };
+        let (Some(filters), Some(virtual_column)) =
+            (&push_downs.filters, &push_downs.virtual_column)
+        else {
+            return vec![];
+        };
+        let column_refs = filters.filter.as_expr(&BUILTIN_FUNCTIONS).column_refs();
+        virtual_column
+            .virtual_column_fields
+            .iter()
+            .filter(|field| !field.is_created && column_refs.contains_key(&field.name))
+            .map(|field| field.name.clone())
+            .collect()
+    }
+
+    /// Register the virtual columns discovered on write or observed in query predicates.
+    ///
+    /// They are registered as variant, the same type as the paths read without virtual columns,
+    /// the types inferred on write are only used to prune blocks.
+    #[async_backtrace::framed]
+    pub async fn register_auto_virtual_columns(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        names: Vec<String>,
+    ) -> Result<()> {
+        if names.is_empty() {
+            return Ok(());
+        }
+        let tenant = ctx.get_tenant();
+        let table_id = self.get_id();
+        let catalog = ctx.get_catalog(self.table_info.catalog()).await?;
+        let req = ListVirtualColumnsReq::new(tenant.clone(), Some(table_id));
+        let metas = catalog.list_virtual_columns(req).await?;
+
+        let registered = metas
+            .first()
+            .map(|meta| {
+                meta.virtual_columns
+                    .iter()
+                    .map(|(name, _)| name.clone())
+                    .collect::<HashSet<_>>()
+            })
+            .unwrap_or_default();
+        let mut virtual_columns = names
+            .into_iter()
+            .filter(|name| !registered.contains(name))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        virtual_columns.sort();
+        virtual_columns
+            .truncate(MAX_AUTO_VIRTUAL_COLUMNS_PER_TABLE.saturating_sub(registered.len()));
+        if virtual_columns.is_empty() {
+            return Ok(());
+        }
+        let virtual_columns = virtual_columns
+            .into_iter()
+            .map(|name| {
+                (
+                    name,
+                    TableDataType::Nullable(Box::new(TableDataType::Variant)),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let name_ident = VirtualColumnIdent::new(&tenant, table_id);
+        if metas.is_empty() {
+            let req = CreateVirtualColumnReq {
+                create_option: CreateOption::CreateIfNotExists,
+                name_ident: name_ident.clone(),
+                virtual_columns: virtual_columns.clone(),
+            };
+            catalog.create_virtual_column(req).await?;
+        }
+        // The virtual columns may be created or updated by concurrent queries,
+        // they are appended by the meta service, without overwriting the others.
+        let req = UpdateVirtualColumnReq {
+            if_exists: true,
+            name_ident,
+            virtual_columns,
+            append_only: true,
+        };
+        catalog.update_virtual_column(req).await
+    }
+}
@@ -54,9 +54,10 @@ impl BlockPruner {
         // Apply block pruning.
         if self.pruning_ctx.bloom_pruner.is_some()
             || self.pruning_ctx.inverted_index_pruner.is_some()
+            || self.pruning_ctx.vector_index_pruner.is_some()
             || self.pruning_ctx.virtual_column_pruner.is_some()
         {
-            // async pruning with bloom index, inverted index or virtual columns.
+            // async pruning with bloom index, inverted index, vector index or virtual columns.
             self.block_pruning(segment_location, block_metas, block_meta_indexes)
                 .await
         } else {
@@ -103,6 +104,7 @@ impl BlockPruner {
         let page_pruner = self.pruning_ctx.page_pruner.clone();
         let bloom_pruner = self.pruning_ctx.bloom_pruner.clone();
         let inverted_index_pruner = self.pruning_ctx.inverted_index_pruner.clone();
+        let vector_index_pruner = self.pruning_ctx.vector_index_pruner.clone();
         let virtual_column_pruner = self.pruning_ctx.virtual_column_pruner.clone();
 
         let mut block_meta_indexes = block_meta_indexes.into_iter();
@@ -147,6 +149,7 @@ impl BlockPruner {
                     let limit_pruner = limit_pruner.clone();
                     let page_pruner = page_pruner.clone();
                     let inverted_index_pruner = inverted_index_pruner.clone();
+                    let vector_index_pruner = vector_index_pruner.clone();
                     let virtual_column_pruner = virtual_column_pruner.clone();
                     let block_location = block_meta.location.clone();
                     let index_location = block_meta.bloom_filter_index_location.clone();
@@ -234,12 +237,21 @@ impl BlockPruner {
                                 if let Some(virtual_column_pruner) = virtual_column_pruner {
                                     // Check whether can read virtual columns,
                                     // and ignore the source columns.
-                                    let virtual_block_meta = virtual_column_pruner
-                                        .prune_virtual_columns(&block_location.0)
+                                    // The block is also pruned by the statistics of virtual columns.
+                                    let (keep, virtual_block_meta) = virtual_column_pruner
+                                        .prune_virtual_columns(&block_location.0, &block_meta)
                                         .await?;
+                                    prune_result.keep = keep;
                                     prune_result.virtual_block_meta = virtual_block_meta;
                                 }
                             }
+                            if prune_result.keep {
+                                if let Some(vector_index_pruner) = vector_index_pruner {
+                                    // The candidates are collected to prune the blocks
+                                    // after all the blocks are searched.
+                                    vector_index_pruner.search(&block_location.0).await?;
+                                }
+                            }
                             Ok(prune_result)
                         })
                     });
@@ -60,6 +60,12 @@ pub struct BloomPrunerCreator {
     /// indices that should be loaded from filter block
     index_fields: Vec<TableField>,
 
+    /// n-gram indices that should be loaded from filter block
+    ngram_index_fields: Vec<TableField>,
+
+    /// set indices that should be loaded from filter block
+    set_index_fields: Vec<TableField>,
+
     /// the expression that would be evaluate
     filter_expression: Expr<String>,
 
@@ -83,6 +89,8 @@ impl BloomPrunerCreator {
         dal: Operator,
         filter_expr: Option<&Expr<String>>,
         bloom_index_cols: BloomIndexColumns,
+        ngram_index_cols: BloomIndexColumns,
+        set_index_cols: BloomIndexColumns,
         bloom_index_builder: Option<BloomIndexBuilder>,
     ) -> Result<Option<Arc<dyn BloomPruner + Send + Sync>>> {
         if let Some(expr) = filter_expr {
@@ -91,7 +99,20 @@ impl BloomPrunerCreator {
             let bloom_column_fields = bloom_columns_map.values().cloned().collect::<Vec<_>>();
             let point_query_cols = BloomIndex::find_eq_columns(expr, bloom_column_fields)?;
 
-            if !point_query_cols.is_empty() {
+            let ngram_columns_map = ngram_index_cols
+                .bloom_index_fields(schema.clone(), BloomIndex::supported_ngram_type)?;
+            let ngram_column_fields = ngram_columns_map.values().cloned().collect::<Vec<_>>();
+            let ngram_query_cols = BloomIndex::find_ngram_columns(expr, ngram_column_fields)?;
+
+            let set_columns_map = set_index_cols
+                .bloom_index_fields(schema.clone(), BloomIndex::supported_set_type)?;
+            let set_column_fields = set_columns_map.values().cloned().collect::<Vec<_>>();
+            let set_query_cols = BloomIndex::find_set_columns(expr, set_column_fields)?;
+
+            if !point_query_cols.is_empty()
+                || !ngram_query_cols.is_empty()
+                || !set_query_cols.is_empty()
+            {
                 // convert to filter column names
                 let mut filter_fields = Vec::with_capacity(point_query_cols.len());
                 let mut scalar_map = HashMap::<Scalar, u64>::new();
@@ -106,6 +127,8 @@ impl BloomPrunerCreator {
                 let creator = BloomPrunerCreator {
                     func_ctx,
                     index_fields: filter_fields,
+                    ngram_index_fields: ngram_query_cols,
+                    set_index_fields: set_query_cols,
                     filter_expression: expr.clone(),
                     scalar_map,
                     dal,
@@ -130,16 +153,42 @@ impl BloomPrunerCreator {
     ) -> Result<bool> {
         let version = index_location.1;
 
-        // filter out columns that no longer exist in the indexed block
-        let index_columns = self.index_fields.iter().try_fold(
-            Vec::with_capacity(self.index_fields.len()),
+        // filter out columns that no longer exist in the indexed block, or are stored
+        // before their data type is widened: the filters of them are built from the values
+        // of the previous data type, which can't be probed with the current one.
+        let is_indexed = |field: &TableField| {
+            column_ids_of_indexed_block.contains(&field.column_id())
+                && !self
+                    .data_schema
+                    .widened_column_sources(field.column_id())
+                    .iter()
+                    .any(|source| column_ids_of_indexed_block.contains(&source.column_id))
+        };
+        let mut index_columns = self.index_fields.iter().try_fold(
+            Vec::with_capacity(
+                self.index_fields.len()
+                    + self.ngram_index_fields.len()
+                    + self.set_index_fields.len(),
+            ),
             |mut acc, field| {
-                if column_ids_of_indexed_block.contains(&field.column_id()) {
+                if is_indexed(field) {
                     acc.push(BloomIndex::build_filter_column_name(version, field)?);
                 }
                 Ok::<_, ErrorCode>(acc)
             },
         )?;
+        // n-gram and set filters may be missing, e.g. the block is written before the index
+        // is specified, or has too many distinct values, they are ignored while loading the filters.
+        for field in &self.ngram_index_fields {
+            if is_indexed(field) {
+                index_columns.push(BloomIndex::build_ngram_filter_column_name(field));
+            }
+        }
+        for field in &self.set_index_fields {
+            if is_indexed(field) {
+                index_columns.push(BloomIndex::build_set_filter_column_name(field));
+            }
+        }
 
         // load the relevant index columns
         let maybe_filter = index_location
@@ -56,6 +56,7 @@ use rand::thread_rng;
 
 use crate::io::BloomIndexBuilder;
 use crate::operations::DeletedSegmentInfo;
+use crate::pruning::schema_with_virtual_columns;
 use crate::pruning::segment_pruner::SegmentPruner;
 use crate::pruning::BlockPruner;
 use crate::pruning::BloomPruner;
@@ -63,6 +64,7 @@ use crate::pruning::BloomPrunerCreator;
 use crate::pruning::FusePruningStatistics;
 use crate::pruning::InvertedIndexPruner;
 use crate::pruning::SegmentLocation;
+use crate::pruning::VectorIndexPruner;
 use crate::pruning::VirtualColumnPruner;
 use crate::FuseStorageFormat;
 
@@ -80,6 +82,7 @@ pub struct PruningContext {
     pub page_pruner: Arc<dyn PagePruner + Send + Sync>,
     pub internal_column_pruner: Option<Arc<InternalColumnPruner>>,
     pub inverted_index_pruner: Option<Arc<InvertedIndexPruner>>,
+    pub vector_index_pruner: Option<Arc<VectorIndexPruner>>,
     pub virtual_column_pruner: Option<Arc<VirtualColumnPruner>>,
 
     pub pruning_stats: Arc<FusePruningStatistics>,
@@ -95,6 +98,8 @@ impl PruningContext {
         cluster_key_meta: Option<ClusterKey>,
         cluster_keys: Vec<RemoteExpr<String>>,
         bloom_index_cols: BloomIndexColumns,
+        ngram_index_cols: BloomIndexColumns,
+        set_index_cols: BloomIndexColumns,
         max_concurrency: usize,
         bloom_index_builder: Option<BloomIndexBuilder>,
         storage_format: FuseStorageFormat,
@@ -132,11 +137,17 @@ impl PruningContext {
             })
             .collect();
 
+        // The filter may reference the typed virtual columns, which are not in the table schema.
+        let range_schema = schema_with_virtual_columns(
+            &table_schema,
+            push_down.as_ref().and_then(|p| p.virtual_column.as_ref()),
+        );
+
         // Range filter.
         // if filter_expression is none, an dummy pruner will be returned, which prunes nothing
         let range_pruner = RangePrunerCreator::try_create_with_default_stats(
             func_ctx.clone(),
-            &table_schema,
+            &range_schema,
             filter_expr.as_ref(),
             default_stats,
         )?;
@@ -149,6 +160,8 @@ impl PruningContext {
             dal.clone(),
             filter_expr.as_ref(),
             bloom_index_cols,
+            ngram_index_cols,
+            set_index_cols,
             bloom_index_builder,
         )?;
 
@@ -164,9 +177,20 @@ impl PruningContext {
         // inverted index pruner, used to search matched rows in block
         let inverted_index_pruner = InvertedIndexPruner::try_create(ctx, dal.clone(), push_down)?;
 
+        let pruning_stats = Arc::new(FusePruningStatistics::default());
+
+        // vector index pruner, used to search the nearest candidates in block
+        let vector_index_pruner =
+            VectorIndexPruner::try_create(dal.clone(), push_down, pruning_stats.clone())?;
+
         // virtual column pruner, used to read virtual column metas and ignore source columns.
-        let virtual_column_pruner =
-            VirtualColumnPruner::try_create(dal.clone(), push_down, storage_format)?;
+        let virtual_column_pruner = VirtualColumnPruner::try_create(
+            func_ctx.clone(),
+            dal.clone(),
+            &table_schema,
+            push_down,
+            storage_format,
+        )?;
 
         // Internal column pruner, if there are predicates using internal columns,
         // we can use them to prune segments and blocks.
@@ -182,7 +206,6 @@ impl PruningContext {
             Some("pruning-worker".to_owned()),
         )?);
         let pruning_semaphore = Arc::new(Semaphore::new(max_concurrency));
-        let pruning_stats = Arc::new(FusePruningStatistics::default());
 
         let pruning_ctx = Arc::new(PruningContext {
             ctx: ctx.clone(),
@@ -195,6 +218,7 @@ impl PruningContext {
             page_pruner,
             internal_column_pruner,
             inverted_index_pruner,
+            vector_index_pruner,
             virtual_column_pruner,
             pruning_stats,
         });
@@ -220,6 +244,8 @@ impl FusePruner {
         table_schema: TableSchemaRef,
         push_down: &Option<PushDownInfo>,
         bloom_index_cols: BloomIndexColumns,
+        ngram_index_cols: BloomIndexColumns,
+        set_index_cols: BloomIndexColumns,
         bloom_index_builder: Option<BloomIndexBuilder>,
         storage_format: FuseStorageFormat,
     ) -> Result<Self> {
@@ -231,6 +257,8 @@ impl FusePruner {
             None,
             vec![],
             bloom_index_cols,
+            ngram_index_cols,
+            set_index_cols,
             bloom_index_builder,
             storage_format,
         )
@@ -245,6 +273,8 @@ impl FusePruner {
         cluster_key_meta: Option<ClusterKey>,
         cluster_keys: Vec<RemoteExpr<String>>,
         bloom_index_cols: BloomIndexColumns,
+        ngram_index_cols: BloomIndexColumns,
+        set_index_cols: BloomIndexColumns,
         bloom_index_builder: Option<BloomIndexBuilder>,
         storage_format: FuseStorageFormat,
     ) -> Result<Self> {
@@ -271,6 +301,8 @@ impl FusePruner {
             cluster_key_meta,
             cluster_keys,
             bloom_index_cols,
+            ngram_index_cols,
+            set_index_cols,
             max_concurrency,
             bloom_index_builder,
             storage_format,
@@ -517,6 +549,10 @@ impl FusePruner {
         &self,
         metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
     ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        let metas = match &self.pruning_ctx.vector_index_pruner {
+            Some(vector_index_pruner) => vector_index_pruner.prune_top_k(metas),
+            None => metas,
+        };
         let push_down = self.push_down.clone();
         if push_down
             .as_ref()
@@ -551,6 +587,11 @@ impl FusePruner {
         let blocks_inverted_index_pruning_after =
             stats.get_blocks_inverted_index_pruning_after() as usize;
 
+        let blocks_vector_index_pruning_before =
+            stats.get_blocks_vector_index_pruning_before() as usize;
+        let blocks_vector_index_pruning_after =
+            stats.get_blocks_vector_index_pruning_after() as usize;
+
         databend_common_catalog::plan::PruningStatistics {
             segments_range_pruning_before,
             segments_range_pruning_after,
@@ -560,6 +601,8 @@ impl FusePruner {
             blocks_bloom_pruning_after,
             blocks_inverted_index_pruning_before,
             blocks_inverted_index_pruning_after,
+            blocks_vector_index_pruning_before,
+            blocks_vector_index_pruning_after,
         }
     }
 
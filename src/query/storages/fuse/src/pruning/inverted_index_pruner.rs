@@ -95,6 +95,12 @@ impl InvertedIndexPruner {
                     need_position = true;
                 }
             });
+            // the prefix term of a single term prefix query, like `"qui"*`,
+            // is not visited, also read the fsts of all the query fields.
+            for (field_name, _) in &inverted_index_info.query_fields {
+                let i = inverted_index_info.index_schema.index_of(field_name)?;
+                field_ids.insert(i as u32);
+            }
 
             // whether need to generate score internl column
             let has_score = inverted_index_info.has_score;
@@ -0,0 +1,135 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_exception::Result;
+use databend_common_expression::types::F32;
+use databend_storages_common_index::VectorIndex;
+use databend_storages_common_pruner::BlockMetaIndex;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use opendal::Operator;
+use parking_lot::Mutex;
+
+use crate::io::TableMetaLocationGenerator;
+use crate::pruning::FusePruningStatistics;
+
+/// The approximate distances of the vector index are not exact,
+/// so more candidates than the limit are kept for the exact re-ranking by the sort.
+pub const VECTOR_INDEX_RERANK_FACTOR: usize = 4;
+
+// Each block file may have a corresponding vector index file.
+// The pruner searches the approximate nearest candidates of the query vector in each block,
+// after all the blocks are searched, only the blocks contain the global nearest candidates
+// are kept, the blocks without index file are always kept.
+pub struct VectorIndexPruner {
+    dal: Operator,
+    index_name: String,
+    index_version: String,
+    query_values: Vec<f32>,
+    num_candidates: usize,
+    // The approximate distances of the candidates, keyed by block location.
+    candidates: Mutex<HashMap<String, Vec<F32>>>,
+    pruning_stats: Arc<FusePruningStatistics>,
+}
+
+impl VectorIndexPruner {
+    pub fn try_create(
+        dal: Operator,
+        push_down: &Option<PushDownInfo>,
+        pruning_stats: Arc<FusePruningStatistics>,
+    ) -> Result<Option<Arc<VectorIndexPruner>>> {
+        let vector_index_info = push_down.as_ref().and_then(|p| p.vector_index.as_ref());
+        let Some(vector_index_info) = vector_index_info else {
+            return Ok(None);
+        };
+        Ok(Some(Arc::new(VectorIndexPruner {
+            dal,
+            index_name: vector_index_info.index_name.clone(),
+            index_version: vector_index_info.index_version.clone(),
+            query_values: vector_index_info.query_values.iter().map(|v| v.0).collect(),
+            num_candidates: vector_index_info.limit * VECTOR_INDEX_RERANK_FACTOR,
+            candidates: Mutex::new(HashMap::new()),
+            pruning_stats,
+        })))
+    }
+
+    /// Search the candidates in the block, the block is always kept,
+    /// it will be pruned by `prune_top_k` after all the blocks are searched.
+    #[async_backtrace::framed]
+    pub async fn search(&self, block_loc: &str) -> Result<()> {
+        let index_loc = TableMetaLocationGenerator::gen_vector_index_location_from_block_location(
+            block_loc,
+            &self.index_name,
+            &self.index_version,
+        );
+        let data = match self.dal.read(&index_loc).await {
+            Ok(data) => data.to_vec(),
+            // The index of the block is not refreshed yet.
+            Err(e) if e.kind() == opendal::ErrorKind::NotFound => return Ok(()),
+            Err(e) => return Err(e.into()),
+        };
+        let vector_index = VectorIndex::from_bytes(&data)?;
+        if let Some(results) = vector_index.search(&self.query_values, self.num_candidates) {
+            let distances = results.into_iter().map(|(_, distance)| distance).collect();
+            self.candidates
+                .lock()
+                .insert(block_loc.to_string(), distances);
+        }
+        Ok(())
+    }
+
+    /// Keep the blocks contain the global nearest candidates,
+    /// and the blocks can't be searched by the index.
+    pub fn prune_top_k(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Vec<(BlockMetaIndex, Arc<BlockMeta>)> {
+        self.pruning_stats
+            .set_blocks_vector_index_pruning_before(metas.len() as u64);
+        let metas = self.prune_candidates(metas);
+        self.pruning_stats
+            .set_blocks_vector_index_pruning_after(metas.len() as u64);
+        metas
+    }
+
+    fn prune_candidates(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Vec<(BlockMetaIndex, Arc<BlockMeta>)> {
+        let candidates = self.candidates.lock();
+        let mut distances = metas
+            .iter()
+            .filter_map(|(index, _)| candidates.get(&index.block_location))
+            .flatten()
+            .copied()
+            .collect::<Vec<_>>();
+        // Rows are not indexed, like NULL values, may be needed if there are not enough candidates.
+        if self.num_candidates == 0 || distances.len() <= self.num_candidates {
+            return metas;
+        }
+        let (_, threshold, _) = distances.select_nth_unstable(self.num_candidates - 1);
+        let threshold = *threshold;
+
+        metas
+            .into_iter()
+            .filter(|(index, _)| match candidates.get(&index.block_location) {
+                Some(distances) => distances.first().is_some_and(|d| *d <= threshold),
+                None => true,
+            })
+            .collect()
+    }
+}
@@ -21,11 +21,27 @@ use arrow_schema::Schema as ArrowSchema;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::plan::VirtualColumnInfo;
 use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableSchema;
+use databend_common_expression::TableSchemaRef;
+use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_storage::parquet_rs::infer_schema_with_extension;
 use databend_common_storage::parquet_rs::read_metadata_async;
+use databend_storages_common_index::RangeIndex;
 use databend_storages_common_pruner::VirtualBlockMetaIndex;
+use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::meta::ColumnMeta;
+use databend_storages_common_table_meta::meta::ColumnStatistics;
+use databend_storages_common_table_meta::meta::StatisticsOfColumns;
 use opendal::Operator;
+use parking_lot::Mutex;
+use parquet::data_type::AsBytes;
+use parquet::file::statistics::Statistics;
 
 use crate::io::read::build_columns_meta;
 use crate::io::BlockReader;
@@ -36,20 +52,82 @@ pub struct VirtualColumnPruner {
     dal: Operator,
     virtual_column: VirtualColumnInfo,
     storage_format: FuseStorageFormat,
+    // Used to prune blocks by the statistics of the virtual columns in the filter.
+    range_pruner: Option<VirtualColumnRangePruner>,
+}
+
+/// Prune blocks by the statistics of the virtual columns in the filter.
+///
+/// The variant virtual columns may be stored with a scalar type in a block,
+/// the casts of them in the filter are rewritten to the casts of the stored type,
+/// so that the statistics of the stored columns can be used.
+struct VirtualColumnRangePruner {
+    func_ctx: FunctionContext,
+    filter: Expr<String>,
+    schema: TableSchemaRef,
+    // Keyed by the stored types of the variant virtual columns in the filter.
+    range_indexes: Mutex<HashMap<Vec<(String, DataType)>, Arc<RangeIndex>>>,
+}
+
+impl VirtualColumnRangePruner {
+    fn range_index(&self, stored_types: Vec<(String, DataType)>) -> Result<Arc<RangeIndex>> {
+        if let Some(range_index) = self.range_indexes.lock().get(&stored_types) {
+            return Ok(range_index.clone());
+        }
+        let mut filter = self.filter.clone();
+        for (name, stored_type) in &stored_types {
+            if let Some(rewritten) = rewrite_variant_casts(&filter, name, stored_type) {
+                filter = rewritten;
+            }
+        }
+        let range_index = Arc::new(RangeIndex::try_create(
+            self.func_ctx.clone(),
+            &filter,
+            self.schema.clone(),
+            StatisticsOfColumns::new(),
+        )?);
+        self.range_indexes
+            .lock()
+            .insert(stored_types, range_index.clone());
+        Ok(range_index)
+    }
 }
 
 impl VirtualColumnPruner {
     pub fn try_create(
+        func_ctx: FunctionContext,
         dal: Operator,
+        table_schema: &TableSchemaRef,
         push_down: &Option<PushDownInfo>,
         storage_format: FuseStorageFormat,
     ) -> Result<Option<Arc<VirtualColumnPruner>>> {
         let virtual_column = push_down.as_ref().and_then(|p| p.virtual_column.as_ref());
         if let Some(virtual_column) = virtual_column {
+            let filter_expr = push_down
+                .as_ref()
+                .and_then(|p| p.filters.as_ref())
+                .map(|f| f.filter.as_expr(&BUILTIN_FUNCTIONS));
+            let mut range_pruner = None;
+            if let Some(filter_expr) = filter_expr {
+                let column_refs = filter_expr.column_refs();
+                if virtual_column
+                    .virtual_column_fields
+                    .iter()
+                    .any(|f| column_refs.contains_key(&f.name))
+                {
+                    range_pruner = Some(VirtualColumnRangePruner {
+                        func_ctx,
+                        filter: filter_expr,
+                        schema: schema_with_virtual_columns(table_schema, Some(virtual_column)),
+                        range_indexes: Mutex::new(HashMap::new()),
+                    });
+                }
+            }
             return Ok(Some(Arc::new(VirtualColumnPruner {
                 dal,
                 virtual_column: virtual_column.clone(),
                 storage_format,
+                range_pruner,
             })));
         }
         Ok(None)
@@ -59,8 +137,12 @@ impl VirtualColumnPruner {
     pub async fn read_metas_schema(
         &self,
         virtual_loc: &str,
-    ) -> Option<(HashMap<u32, ColumnMeta>, ArrowSchema)> {
-        let (metas, schema) = match self.storage_format {
+    ) -> Option<(
+        HashMap<u32, ColumnMeta>,
+        ArrowSchema,
+        HashMap<u32, Statistics>,
+    )> {
+        let (metas, schema, statistics) = match self.storage_format {
             FuseStorageFormat::Parquet => {
                 let metadata = read_metadata_async(virtual_loc, &self.dal, None)
                     .await
@@ -69,7 +151,13 @@ impl VirtualColumnPruner {
                 let row_group = &metadata.row_groups()[0];
                 let schema = infer_schema_with_extension(metadata.file_metadata()).ok()?;
                 let columns_meta = build_columns_meta(row_group);
-                (columns_meta, schema)
+                let statistics = row_group
+                    .columns()
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(index, c)| Some((index as u32, c.statistics()?.clone())))
+                    .collect();
+                (columns_meta, schema, statistics)
             }
             FuseStorageFormat::Native => {
                 let (metas, schema) =
@@ -78,27 +166,59 @@ impl VirtualColumnPruner {
                 for (index, meta) in metas.into_iter().enumerate() {
                     columns_meta.insert(index as u32, meta);
                 }
-                (columns_meta, schema)
+                (columns_meta, schema, HashMap::new())
             }
         };
-        Some((metas, schema))
+        Some((metas, schema, statistics))
     }
 
     #[async_backtrace::framed]
     pub async fn prune_virtual_columns(
         &self,
         block_loc: &str,
-    ) -> Result<Option<VirtualBlockMetaIndex>> {
+        block_meta: &BlockMeta,
+    ) -> Result<(bool, Option<VirtualBlockMetaIndex>)> {
         let virtual_loc = TableMetaLocationGenerator::gen_virtual_block_location(block_loc);
 
-        if let Some((mut metas, schema)) = self.read_metas_schema(&virtual_loc).await {
+        if let Some((mut metas, schema, statistics)) = self.read_metas_schema(&virtual_loc).await {
+            let virtual_schema = TableSchema::try_from(&schema).ok();
             let mut virtual_column_metas = BTreeMap::new();
+            let mut virtual_column_stats = StatisticsOfColumns::new();
+            let mut stored_types = Vec::new();
             let mut need_source_column_ids = HashSet::new();
             for virtual_column_field in &self.virtual_column.virtual_column_fields {
-                if let Ok(idx) = schema.index_of(&virtual_column_field.name) {
-                    if let Some(meta) = metas.remove(&(idx as u32)) {
-                        virtual_column_metas.insert(virtual_column_field.column_id, meta);
-                        continue;
+                let data_type = virtual_column_field.data_type.remove_nullable();
+                let stored_type =
+                    schema
+                        .index_of(&virtual_column_field.name)
+                        .ok()
+                        .and_then(|idx| {
+                            let field = virtual_schema.as_ref()?.fields().get(idx)?;
+                            Some((idx as u32, field.data_type().remove_nullable()))
+                        });
+                if let Some((idx, stored_type)) = stored_type {
+                    let stats = statistics
+                        .get(&idx)
+                        .and_then(|s| convert_column_statistics(s, &stored_type));
+                    if stored_type == data_type {
+                        if let Some(meta) = metas.remove(&idx) {
+                            if let Some(stats) = stats {
+                                virtual_column_stats.insert(virtual_column_field.column_id, stats);
+                            }
+                            virtual_column_metas.insert(virtual_column_field.column_id, meta);
+                            continue;
+                        }
+                    } else if data_type == TableDataType::Variant {
+                        // The virtual columns discovered on write are stored with the type
+                        // of the values, they are read from the source column as variant,
+                        // only the statistics are used to prune the block.
+                        if let Some(stats) = stats {
+                            virtual_column_stats.insert(virtual_column_field.column_id, stats);
+                            stored_types.push((
+                                virtual_column_field.name.clone(),
+                                DataType::from(&stored_type),
+                            ));
+                        }
                     }
                 }
                 // The virtual column does not exist and must be generated from the source column.
@@ -114,15 +234,165 @@ impl VirtualColumnPruner {
                 ignored_source_column_ids.insert(*column_id);
             }
 
+            if let Some(range_pruner) = &self.range_pruner {
+                if !virtual_column_stats.is_empty() {
+                    let range_index = range_pruner.range_index(stored_types)?;
+                    let mut stats = block_meta.col_stats.clone();
+                    stats.extend(virtual_column_stats);
+                    if !range_index.apply(&stats, |id| !block_meta.col_metas.contains_key(id))? {
+                        return Ok((false, None));
+                    }
+                }
+            }
+
             if !virtual_column_metas.is_empty() {
                 let virtual_block_meta = VirtualBlockMetaIndex {
                     virtual_block_location: virtual_loc,
                     virtual_column_metas,
                     ignored_source_column_ids,
                 };
-                return Ok(Some(virtual_block_meta));
+                return Ok((true, Some(virtual_block_meta)));
             }
         }
-        Ok(None)
+        Ok((true, None))
+    }
+}
+
+/// Append the fields of virtual columns to the table schema,
+/// so that the filters on virtual columns can be evaluated by the range index.
+pub fn schema_with_virtual_columns(
+    table_schema: &TableSchemaRef,
+    virtual_column: Option<&VirtualColumnInfo>,
+) -> TableSchemaRef {
+    let Some(virtual_column) = virtual_column else {
+        return table_schema.clone();
+    };
+    let mut schema = table_schema.as_ref().clone();
+    schema
+        .fields
+        .extend(virtual_column.schema.fields().iter().cloned());
+    Arc::new(schema)
+}
+
+/// Rewrite the casts of a variant virtual column in the filter to the casts of the stored type.
+///
+/// Return None if the column is not only used by the casts, or is cast to a type
+/// that the stored values may be cast to other values than the variant values.
+fn rewrite_variant_casts(
+    expr: &Expr<String>,
+    name: &str,
+    stored_type: &DataType,
+) -> Option<Expr<String>> {
+    match expr {
+        Expr::ColumnRef { id, .. } if id == name => None,
+        Expr::Cast {
+            span,
+            is_try,
+            expr: inner,
+            dest_type,
+        } => {
+            let inner = match inner.as_ref() {
+                Expr::ColumnRef {
+                    span,
+                    id,
+                    display_name,
+                    ..
+                } if id == name => {
+                    let same_values = match (stored_type, dest_type.remove_nullable()) {
+                        (DataType::Boolean, DataType::Boolean)
+                        | (DataType::String, DataType::String) => true,
+                        (DataType::Number(src), DataType::Number(dest)) => {
+                            !src.is_float() || dest.is_float()
+                        }
+                        _ => false,
+                    };
+                    if !same_values {
+                        return None;
+                    }
+                    Expr::ColumnRef {
+                        span: *span,
+                        id: id.clone(),
+                        data_type: stored_type.wrap_nullable(),
+                        display_name: display_name.clone(),
+                    }
+                }
+                inner => rewrite_variant_casts(inner, name, stored_type)?,
+            };
+            Some(Expr::Cast {
+                span: *span,
+                is_try: *is_try,
+                expr: Box::new(inner),
+                dest_type: dest_type.clone(),
+            })
+        }
+        Expr::FunctionCall {
+            span,
+            id,
+            function,
+            generics,
+            args,
+            return_type,
+        } => Some(Expr::FunctionCall {
+            span: *span,
+            id: id.clone(),
+            function: function.clone(),
+            generics: generics.clone(),
+            args: args
+                .iter()
+                .map(|arg| rewrite_variant_casts(arg, name, stored_type))
+                .collect::<Option<Vec<_>>>()?,
+            return_type: return_type.clone(),
+        }),
+        Expr::LambdaFunctionCall { args, .. } => {
+            if args.iter().any(|arg| arg.column_refs().contains_key(name)) {
+                None
+            } else {
+                Some(expr.clone())
+            }
+        }
+        _ => Some(expr.clone()),
+    }
+}
+
+/// Convert the statistics of a parquet column chunk,
+/// only the scalar types of virtual columns are supported.
+fn convert_column_statistics(s: &Statistics, typ: &TableDataType) -> Option<ColumnStatistics> {
+    if !s.has_min_max_set() {
+        return None;
     }
+    let (min, max) = match (s, typ) {
+        (Statistics::Boolean(s), TableDataType::Boolean) => {
+            (Scalar::Boolean(*s.min()), Scalar::Boolean(*s.max()))
+        }
+        (Statistics::Int32(s), TableDataType::Number(number_type)) => {
+            let (min, max) = (*s.min(), *s.max());
+            match number_type {
+                NumberDataType::Int8 => (Scalar::from(min as i8), Scalar::from(max as i8)),
+                NumberDataType::Int16 => (Scalar::from(min as i16), Scalar::from(max as i16)),
+                NumberDataType::Int32 => (Scalar::from(min), Scalar::from(max)),
+                NumberDataType::UInt8 => (Scalar::from(min as u8), Scalar::from(max as u8)),
+                NumberDataType::UInt16 => (Scalar::from(min as u16), Scalar::from(max as u16)),
+                NumberDataType::UInt32 => (Scalar::from(min as u32), Scalar::from(max as u32)),
+                _ => return None,
+            }
+        }
+        (Statistics::Int64(s), TableDataType::Number(NumberDataType::Int64)) => {
+            (Scalar::from(*s.min()), Scalar::from(*s.max()))
+        }
+        (Statistics::Int64(s), TableDataType::Number(NumberDataType::UInt64)) => {
+            (Scalar::from(*s.min() as u64), Scalar::from(*s.max() as u64))
+        }
+        (Statistics::Float(s), TableDataType::Number(NumberDataType::Float32)) => {
+            (Scalar::from(*s.min()), Scalar::from(*s.max()))
+        }
+        (Statistics::Double(s), TableDataType::Number(NumberDataType::Float64)) => {
+            (Scalar::from(*s.min()), Scalar::from(*s.max()))
+        }
+        (Statistics::ByteArray(s), TableDataType::String) => (
+            Scalar::String(String::from_utf8(s.min().as_bytes().to_vec()).ok()?),
+            Scalar::String(String::from_utf8(s.max().as_bytes().to_vec()).ok()?),
+        ),
+        _ => return None,
+    };
+    Some(ColumnStatistics::new(min, max, s.null_count(), 0, None))
 }
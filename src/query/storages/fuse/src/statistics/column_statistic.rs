@@ -23,10 +23,13 @@ use databend_common_expression::Column;
 use databend_common_expression::DataBlock;
 use databend_common_expression::FieldIndex;
 use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
 use databend_common_expression::TableSchemaRef;
 use databend_common_expression::Value;
 use databend_common_expression::ORIGIN_BLOCK_ROW_NUM_COLUMN_ID;
 use databend_common_functions::aggregates::eval_aggr;
+use databend_common_io::ewkb_bounding_box;
+use databend_common_io::merge_bounding_box;
 use databend_storages_common_index::Index;
 use databend_storages_common_index::RangeIndex;
 use databend_storages_common_table_meta::meta::ColumnStatistics;
@@ -64,6 +67,15 @@ pub fn gen_columns_statistics(
     let leaves = get_traverse_columns_dfs(data_block)?;
     let leaf_column_ids = schema.to_leaf_column_ids();
     for ((col_idx, col, data_type), column_id) in leaves.iter().zip(leaf_column_ids) {
+        // Geometry columns only keep the bounding box of all the geometries.
+        if data_type.remove_nullable() == DataType::Geometry {
+            let col = col.convert_to_full_column(data_type, rows);
+            if let Some(col_stats) = gen_geometry_statistics(&col, rows)? {
+                statistics.insert(column_id, col_stats);
+            }
+            continue;
+        }
+
         // Ignore the range index does not supported type.
         if !RangeIndex::supported_type(data_type) {
             continue;
@@ -177,6 +189,38 @@ pub fn gen_columns_statistics(
     Ok(statistics)
 }
 
+/// Generate the statistics of a Geometry column, see [`ColumnStatistics::from_bbox`].
+///
+/// Returns `None` if there are non-NULL geometries without bounding box (e.g. empty geometries),
+/// as the statistics would indicate that the column only contains NULL values.
+fn gen_geometry_statistics(col: &Column, rows: usize) -> Result<Option<ColumnStatistics>> {
+    let bbox = col
+        .iter()
+        .filter_map(|value| match value {
+            ScalarRef::Geometry(ewkb) => ewkb_bounding_box(ewkb),
+            _ => None,
+        })
+        .reduce(merge_bounding_box);
+
+    let (is_all_null, bitmap) = col.validity();
+    let unset_bits = match (is_all_null, bitmap) {
+        (true, _) => rows,
+        (false, Some(bitmap)) => bitmap.null_count(),
+        (false, None) => 0,
+    };
+    if bbox.is_none() && unset_bits < rows {
+        return Ok(None);
+    }
+    let distinct_of_values = calc_column_distinct_of_values(col, rows)?;
+
+    Ok(Some(ColumnStatistics::from_bbox(
+        bbox,
+        unset_bits as u64,
+        col.memory_size() as u64,
+        Some(distinct_of_values),
+    )))
+}
+
 pub fn scalar_min_max(data_type: &DataType, scalar: Scalar) -> Option<(Scalar, Scalar)> {
     if RangeIndex::supported_type(data_type) {
         if let Some((min, Some(max))) = scalar
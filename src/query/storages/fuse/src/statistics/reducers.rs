@@ -18,6 +18,7 @@ use std::collections::HashMap;
 use databend_common_expression::BlockThresholds;
 use databend_common_expression::ColumnId;
 use databend_common_expression::Scalar;
+use databend_common_io::merge_bounding_box;
 use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::meta::ClusterStatistics;
 use databend_storages_common_table_meta::meta::ColumnStatistics;
@@ -49,6 +50,21 @@ pub fn reduce_block_statistics<T: Borrow<StatisticsOfColumns>>(
     col_to_stats_lit
         .iter()
         .fold(HashMap::with_capacity(len), |mut acc, (id, stats)| {
+            // Statistics of Geometry columns keep the bounding box, which is merged componentwise.
+            if stats.iter().any(|s| s.bbox().is_some()) {
+                let bbox = stats
+                    .iter()
+                    .filter_map(|s| s.bbox())
+                    .reduce(merge_bounding_box);
+                let null_count = stats.iter().map(|s| s.null_count).sum();
+                let in_memory_size = stats.iter().map(|s| s.in_memory_size).sum();
+                acc.insert(
+                    *id,
+                    ColumnStatistics::from_bbox(bbox, null_count, in_memory_size, None),
+                );
+                return acc;
+            }
+
             let mut min_stats = Vec::with_capacity(stats.len());
             let mut max_stats = Vec::with_capacity(stats.len());
             let mut null_count = 0;
@@ -74,6 +74,51 @@ impl HivePartInfo {
     }
 }
 
+/// The directory of a partition, its files are listed when the partition is read,
+/// so the partitions eliminated by the runtime filters of a join are never listed.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct HiveDirPartInfo {
+    // partition location, like /usr/hive/warehouse/ssb.db/customer.table/c_region=ASIA/c_nation=CHINA/
+    pub location: String,
+    // partition values, like 'c_region=ASIA/c_nation=CHINA'
+    pub partitions: Vec<Scalar>,
+    // total size of the partition files in the metastore, 0 if unknown
+    pub size: u64,
+}
+
+#[typetag::serde(name = "hive_dir")]
+impl PartInfo for HiveDirPartInfo {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn PartInfo>) -> bool {
+        info.as_any()
+            .downcast_ref::<HiveDirPartInfo>()
+            .is_some_and(|other| self == other)
+    }
+
+    fn hash(&self) -> u64 {
+        let mut s = DefaultHasher::new();
+        self.location.hash(&mut s);
+        s.finish()
+    }
+}
+
+impl HiveDirPartInfo {
+    pub fn create(location: String, partitions: Vec<Scalar>, size: u64) -> Self {
+        HiveDirPartInfo {
+            location,
+            partitions,
+            size,
+        }
+    }
+
+    pub fn into_part_ptr(self) -> PartInfoPtr {
+        Arc::new(Box::new(self))
+    }
+}
+
 // partitions like 'c_region=ASIA/c_nation=CHINA'
 pub fn parse_hive_partitions(partitions: &str) -> HashMap<String, String> {
     let mut partition_map = HashMap::new();
@@ -56,6 +56,7 @@ use databend_common_storages_parquet::ParquetRSReaderBuilder;
 use databend_storages_common_pruner::partition_prunner::PartitionPruner;
 use databend_storages_common_table_meta::meta::SnapshotId;
 use databend_storages_common_table_meta::table::ChangeType;
+use faststr::FastStr;
 use futures::TryStreamExt;
 use log::info;
 use log::trace;
@@ -66,6 +67,7 @@ use super::hive_catalog::HiveCatalog;
 use super::hive_table_options::HiveTableOptions;
 use crate::hive_table_source::HiveTableSource;
 use crate::utils::HiveFetchPartitionScalars;
+use crate::HiveDirPartInfo;
 use crate::HivePartInfo;
 use crate::HivePartitionFiller;
 
@@ -202,9 +204,11 @@ impl HiveTable {
             |output| {
                 HiveTableSource::create(
                     ctx.clone(),
+                    plan,
                     output,
                     output_schema.clone(),
                     parquet_reader.clone(),
+                    self.dal.clone(),
                     self.partition_fields(),
                 )
             },
@@ -229,7 +233,7 @@ impl HiveTable {
         ctx: Arc<dyn TableContext>,
         partition_keys: Vec<String>,
         filter_expression: Option<Expr<String>>,
-    ) -> Result<Vec<(String, Option<String>)>> {
+    ) -> Result<Vec<(String, Option<String>, u64)>> {
         let hive_catalog = ctx.get_catalog(CATALOG_HIVE).await?;
         let hive_catalog = hive_catalog.as_any().downcast_ref::<HiveCatalog>().unwrap();
 
@@ -275,21 +279,32 @@ impl HiveTable {
                 partition_names.clone(),
             )
             .await?;
+        let total_size_key = FastStr::from_static_str("totalSize");
         let res = partitions
             .into_iter()
-            .map(|p| convert_hdfs_path(&p.sd.unwrap().location.unwrap(), true))
-            .zip(partition_names.into_iter().map(Some))
+            .zip(partition_names)
+            .map(|(p, name)| {
+                let size = p
+                    .parameters
+                    .as_ref()
+                    .and_then(|params| params.get(&total_size_key))
+                    .and_then(|size| size.parse::<u64>().ok())
+                    .unwrap_or_default();
+                let location = convert_hdfs_path(&p.sd.unwrap().location.unwrap(), true);
+                (location, Some(name), size)
+            })
             .collect::<Vec<_>>();
         Ok(res)
     }
 
-    // return items: (hdfs_location, option<part info>) where part info likes 'c_region=Asia/c_nation=China'
+    // return items: (hdfs_location, option<part info>, size) where part info likes 'c_region=Asia/c_nation=China',
+    // the size of the partition is taken from the metastore, 0 if unknown.
     #[async_backtrace::framed]
     async fn get_query_locations(
         &self,
         ctx: Arc<dyn TableContext>,
         push_downs: &Option<PushDownInfo>,
-    ) -> Result<Vec<(String, Option<String>)>> {
+    ) -> Result<Vec<(String, Option<String>, u64)>> {
         let path = self.table_options.location.as_ref().ok_or_else(|| {
             ErrorCode::TableInfoError(format!("{}, table location is empty", self.table_info.name))
         })?;
@@ -313,19 +328,19 @@ impl HiveTable {
         }
 
         let location = convert_hdfs_path(path, true);
-        Ok(vec![(location, None)])
+        Ok(vec![(location, None, 0)])
     }
 
     #[fastrace::trace]
     #[async_backtrace::framed]
     async fn list_files_from_dirs(
         &self,
-        dirs: Vec<(String, Option<String>)>,
+        dirs: Vec<(String, Option<String>, u64)>,
     ) -> Result<Vec<HivePartInfo>> {
         let sem = Arc::new(Semaphore::new(60));
 
         let mut tasks = Vec::with_capacity(dirs.len());
-        for (dir, partition) in dirs {
+        for (dir, partition, _) in dirs {
             let sem_t = sem.clone();
             let operator_t = self.dal.clone();
             let dir_t = dir.to_string();
@@ -357,6 +372,10 @@ impl HiveTable {
 
         let dir_len = dirs.len();
         let filler = HivePartitionFiller::create(self.partition_fields());
+        if dirs.iter().any(|(_, partition, _)| partition.is_some()) {
+            return self.read_partition_dirs(dirs, &filler, start);
+        }
+
         let mut partitions = self.list_files_from_dirs(dirs).await?;
         for partition in partitions.iter_mut() {
             partition.partitions = filler.extract_scalars(&partition.filename)?;
@@ -393,6 +412,47 @@ impl HiveTable {
             Partitions::create(PartitionsShuffleKind::Seq, partitions),
         ))
     }
+
+    // The partitions are read as directories, their files are listed by the source
+    // after the partitions out of the runtime filters of the join are eliminated.
+    fn read_partition_dirs(
+        &self,
+        dirs: Vec<(String, Option<String>, u64)>,
+        filler: &HivePartitionFiller,
+        start: Instant,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let mut partitions = Vec::with_capacity(dirs.len());
+        for (location, partition, size) in dirs {
+            let values =
+                filler.extract_scalars(partition.as_deref().unwrap_or(location.as_str()))?;
+            partitions.push(HiveDirPartInfo::create(location, values, size));
+        }
+
+        info!(
+            "read partition dirs, partition num:{}, elapsed:{:?}",
+            partitions.len(),
+            start.elapsed()
+        );
+
+        let read_bytes: usize = partitions.iter().map(|s| s.size as usize).sum();
+        let estimated_read_rows = read_bytes as f64 / (self.schema().num_fields() * 8) as f64;
+        let stats = PartStatistics::new_estimated(
+            None,
+            estimated_read_rows as _,
+            read_bytes,
+            partitions.len(),
+            partitions.len(),
+        );
+        let partitions = partitions
+            .into_iter()
+            .map(HiveDirPartInfo::into_part_ptr)
+            .collect();
+
+        Ok((
+            stats,
+            Partitions::create(PartitionsShuffleKind::Seq, partitions),
+        ))
+    }
 }
 
 #[async_trait::async_trait]
@@ -490,6 +550,10 @@ impl Table for HiveTable {
     fn support_prewhere(&self) -> bool {
         true
     }
+
+    fn support_runtime_filter_partition_pruning(&self) -> bool {
+        true
+    }
 }
 
 // Dummy Impl
@@ -566,7 +630,7 @@ pub fn convert_hdfs_path(hdfs_path: &str, is_dir: bool) -> String {
 }
 
 #[async_recursion(#[recursive::recursive])]
-async fn list_files_from_dir(
+pub(crate) async fn list_files_from_dir(
     operator: Operator,
     location: String,
     sem: Arc<Semaphore>,
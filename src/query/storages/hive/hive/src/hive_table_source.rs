@@ -15,10 +15,13 @@
 use std::any::Any;
 use std::sync::Arc;
 
+use databend_common_base::base::tokio::sync::Semaphore;
 use databend_common_base::base::Progress;
 use databend_common_base::base::ProgressValues;
 use databend_common_base::runtime::profile::Profile;
 use databend_common_base::runtime::profile::ProfileStatisticsName;
+use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::runtime_filter_info::RuntimeFilterReady;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -27,6 +30,7 @@ use databend_common_expression::DataBlock;
 use databend_common_expression::DataSchema;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::FieldIndex;
+use databend_common_expression::FunctionContext;
 use databend_common_expression::TableField;
 use databend_common_expression::Value;
 use databend_common_pipeline_core::processors::Event;
@@ -35,8 +39,13 @@ use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_storages_parquet::ParquetFileReader;
 use databend_common_storages_parquet::ParquetRSFullReader;
+use databend_storages_common_pruner::partition_prunner::prune_partition_by_runtime_filters;
+use log::info;
+use opendal::Operator;
 use parquet::arrow::async_reader::ParquetRecordBatchStream;
 
+use crate::hive_table::list_files_from_dir;
+use crate::HiveDirPartInfo;
 use crate::HivePartInfo;
 
 pub type PartitionColumnIndex = usize;
@@ -49,6 +58,17 @@ pub struct HiveTableSource {
     scan_progress: Arc<Progress>,
     // Used for get partition
     ctx: Arc<dyn TableContext>,
+    // Used for get runtime filters of the scan
+    table_index: usize,
+    func_ctx: FunctionContext,
+    // The partitions are eliminated after the runtime filters are ready.
+    runtime_filter_ready: Vec<Arc<RuntimeFilterReady>>,
+
+    // Used to list the files of the partition directories.
+    op: Operator,
+    list_sem: Arc<Semaphore>,
+    // The files of the current partition directory.
+    files: Vec<HivePartInfo>,
 
     // Used to read parquet file.
     parquet_reader: Arc<ParquetRSFullReader>,
@@ -70,9 +90,11 @@ pub struct HiveTableSource {
 impl HiveTableSource {
     pub fn create(
         ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
         output: Arc<OutputPort>,
         output_schema: DataSchemaRef,
         parquet_reader: Arc<ParquetRSFullReader>,
+        op: Operator,
         partition_fields: Vec<TableField>,
     ) -> Result<ProcessorPtr> {
         let output_partition_columns = output_schema
@@ -87,10 +109,18 @@ impl HiveTableSource {
             })
             .collect();
         let scan_progress = ctx.get_scan_progress();
+        let func_ctx = ctx.get_function_context()?;
+        let runtime_filter_ready = ctx.get_runtime_filter_ready(plan.scan_id);
         Ok(ProcessorPtr::create(Box::new(HiveTableSource {
             output,
             scan_progress,
             ctx,
+            table_index: plan.table_index,
+            func_ctx,
+            runtime_filter_ready,
+            op,
+            list_sem: Arc::new(Semaphore::new(60)),
+            files: vec![],
             parquet_reader,
             output_schema,
             partition_fields,
@@ -101,6 +131,69 @@ impl HiveTableSource {
             partition_block_entries: vec![],
         })))
     }
+
+    // Dynamic partition elimination, the partition is skipped before listing its files
+    // if its values are out of the key domain of the build side of the join.
+    fn prune_by_runtime_filters(&self, part: &HiveDirPartInfo) -> bool {
+        let mut filters = self.ctx.get_inlist_runtime_filter_with_id(self.table_index);
+        filters.extend(
+            self.ctx
+                .get_min_max_runtime_filter_with_id(self.table_index),
+        );
+        if filters.is_empty() {
+            return false;
+        }
+        let pruned = prune_partition_by_runtime_filters(
+            &self.func_ctx,
+            &filters,
+            &self.partition_fields,
+            &part.partitions,
+        );
+        if pruned {
+            info!("Pruned hive partition {} by runtime filter", part.location);
+            Profile::record_usize_profile(ProfileStatisticsName::RuntimeFilterPruneParts, 1);
+        }
+        pruned
+    }
+
+    async fn read_partition_dir(&mut self, part: &HiveDirPartInfo) -> Result<()> {
+        for ready in std::mem::take(&mut self.runtime_filter_ready) {
+            ready.wait().await?;
+        }
+        if self.prune_by_runtime_filters(part) {
+            return Ok(());
+        }
+        let mut files = list_files_from_dir(
+            self.op.clone(),
+            part.location.clone(),
+            self.list_sem.clone(),
+        )
+        .await?;
+        for file in files.iter_mut() {
+            file.partitions = part.partitions.clone();
+        }
+        self.files = files;
+        Ok(())
+    }
+
+    async fn read_file(&mut self, part: &HivePartInfo) -> Result<()> {
+        let partition_fields = self
+            .partition_fields
+            .iter()
+            .cloned()
+            .zip(part.partitions.iter().cloned())
+            .collect::<Vec<_>>();
+        self.partition_block_entries = partition_fields
+            .iter()
+            .map(|(f, v)| BlockEntry::new(f.data_type().into(), Value::Scalar(v.clone())))
+            .collect::<Vec<_>>();
+        let stream = self
+            .parquet_reader
+            .prepare_data_stream(&part.filename, part.filesize, Some(&partition_fields), None)
+            .await?;
+        self.stream = Some(stream);
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -168,23 +261,14 @@ impl Processor for HiveTableSource {
             // else:
             // If `read_block` returns `None`, it means the stream is finished.
             // And we should try to build another stream (in next event loop).
+        } else if let Some(part) = self.files.pop() {
+            self.read_file(&part).await?;
         } else if let Some(part) = self.ctx.get_partition() {
-            let part = HivePartInfo::from_part(&part)?;
-            let partition_fields = self
-                .partition_fields
-                .iter()
-                .cloned()
-                .zip(part.partitions.iter().cloned())
-                .collect::<Vec<_>>();
-            self.partition_block_entries = partition_fields
-                .iter()
-                .map(|(f, v)| BlockEntry::new(f.data_type().into(), Value::Scalar(v.clone())))
-                .collect::<Vec<_>>();
-            let stream = self
-                .parquet_reader
-                .prepare_data_stream(&part.filename, part.filesize, Some(&partition_fields))
-                .await?;
-            self.stream = Some(stream);
+            if let Some(part) = part.as_any().downcast_ref::<HiveDirPartInfo>() {
+                self.read_partition_dir(part).await?;
+            } else {
+                self.read_file(HivePartInfo::from_part(&part)?).await?;
+            }
         } else {
             self.is_finished = true;
         }
@@ -28,6 +28,7 @@ mod utils;
 
 pub use hive_catalog::HiveCatalog;
 pub use hive_catalog::HiveCreator;
+pub use hive_partition::HiveDirPartInfo;
 pub use hive_partition::HivePartInfo;
 pub use hive_partition_filler::HivePartitionFiller;
 pub use hive_table::HiveTable;
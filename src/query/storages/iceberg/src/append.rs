@@ -0,0 +1,870 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::time::Duration;
+
+use arrow::compute::take_record_batch;
+use arrow::row::RowConverter;
+use arrow::row::SortField;
+use arrow_array::cast::AsArray;
+use arrow_array::types::Date32Type;
+use arrow_array::types::Decimal128Type;
+use arrow_array::types::Float32Type;
+use arrow_array::types::Float64Type;
+use arrow_array::types::Int32Type;
+use arrow_array::types::Int64Type;
+use arrow_array::types::TimestampMicrosecondType;
+use arrow_array::Array;
+use arrow_array::ArrayRef;
+use arrow_array::RecordBatch;
+use arrow_array::UInt32Array;
+use arrow_cast::display::array_value_to_string;
+use arrow_schema::DataType;
+use arrow_schema::Schema as ArrowSchema;
+use arrow_schema::TimeUnit;
+use async_trait::async_trait;
+use bytes::Bytes;
+use chrono::Utc;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::local_block_meta_serde;
+use databend_common_expression::BlockMetaInfo;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableSchemaRef;
+use databend_common_pipeline_sinks::AsyncSink;
+use databend_common_pipeline_transforms::processors::AsyncAccumulatingTransform;
+use iceberg::spec::DataContentType;
+use iceberg::spec::DataFile;
+use iceberg::spec::DataFileBuilder;
+use iceberg::spec::DataFileFormat;
+use iceberg::spec::Datum;
+use iceberg::spec::FormatVersion;
+use iceberg::spec::Literal;
+use iceberg::spec::Manifest;
+use iceberg::spec::ManifestContentType;
+use iceberg::spec::ManifestEntry;
+use iceberg::spec::ManifestFile;
+use iceberg::spec::ManifestListWriter;
+use iceberg::spec::ManifestMetadata;
+use iceberg::spec::ManifestStatus;
+use iceberg::spec::ManifestWriter;
+use iceberg::spec::PartitionSpec;
+use iceberg::spec::PrimitiveType;
+use iceberg::spec::Schema;
+use iceberg::spec::Struct;
+use iceberg::spec::Type;
+use iceberg::transform::create_transform_function;
+use iceberg::transform::BoxedTransformFunction;
+use iceberg::TableRequirement;
+use iceberg::TableUpdate;
+use log::info;
+use log::warn;
+use parquet::arrow::ArrowWriter;
+use parquet::arrow::PARQUET_FIELD_ID_META_KEY;
+use parquet::basic::Compression;
+use parquet::basic::ZstdLevel;
+use parquet::file::properties::WriterProperties;
+use parquet::format::FileMetaData;
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::IcebergCatalog;
+use crate::IcebergTable;
+
+// Same as the default of iceberg `write.target-file-size-bytes`.
+const DEFAULT_TARGET_FILE_SIZE: usize = 512 * 1024 * 1024;
+const MAX_COMMIT_RETRIES: usize = 4;
+
+/// Data files written by [`IcebergDataWriter`], or position delete files written by
+/// [`IcebergDeleteSource`](crate::delete::IcebergDeleteSource), waiting to be committed.
+#[derive(Debug)]
+pub struct IcebergDataFiles {
+    pub data_files: Vec<DataFile>,
+}
+
+local_block_meta_serde!(IcebergDataFiles);
+
+#[typetag::serde(name = "iceberg_data_files")]
+impl BlockMetaInfo for IcebergDataFiles {}
+
+/// Write blocks into parquet data files of the iceberg table.
+///
+/// Rows are split by the default partition spec of the table, each partition is written into
+/// its own files. Files are rolled over once reaching `write.target-file-size-bytes`, the
+/// written data files are sent downstream to [`IcebergCommitSink`] when all blocks are consumed.
+pub struct IcebergDataWriter {
+    table: IcebergTable,
+    schema: TableSchemaRef,
+    arrow_schema: Arc<ArrowSchema>,
+    iceberg_schema: Arc<Schema>,
+    data_path: String,
+    target_file_size: usize,
+    partitioner: Option<Partitioner>,
+
+    /// Open writers, keyed by the encoded partition values.
+    writers: HashMap<Vec<u8>, PartitionWriter>,
+    data_files: Vec<DataFile>,
+}
+
+struct PartitionWriter {
+    /// Relative directory of the partition, empty for unpartitioned tables.
+    path: String,
+    partition: Struct,
+    writer: ArrowWriter<Vec<u8>>,
+    num_rows: u64,
+}
+
+/// Computes the partition values of the rows with the transforms of the partition spec.
+struct Partitioner {
+    /// (partition field name, index of the source column, transform)
+    fields: Vec<(String, usize, BoxedTransformFunction)>,
+    converter: Option<RowConverter>,
+}
+
+impl IcebergDataWriter {
+    pub fn try_create(table: IcebergTable) -> Result<Self> {
+        let metadata = table.table.metadata();
+        if metadata.format_version() == FormatVersion::V1 {
+            return Err(ErrorCode::Unimplemented(format!(
+                "writing iceberg table {} of format version 1 is not supported",
+                table.name()
+            )));
+        }
+        let data_path = data_path(&table.table);
+        let target_file_size = match metadata.properties().get("write.target-file-size-bytes") {
+            Some(v) => v.parse::<usize>()?,
+            None => DEFAULT_TARGET_FILE_SIZE,
+        };
+        let arrow_schema = Arc::new(IcebergTable::get_arrow_schema(&table.table)?);
+        let partitioner = match metadata.default_partition_spec() {
+            Some(spec) if !spec.fields.is_empty() => {
+                Some(Partitioner::try_create(spec, &arrow_schema)?)
+            }
+            _ => None,
+        };
+
+        Ok(Self {
+            schema: table.schema(),
+            iceberg_schema: metadata.current_schema().clone(),
+            table,
+            arrow_schema,
+            data_path,
+            target_file_size,
+            partitioner,
+            writers: HashMap::new(),
+            data_files: vec![],
+        })
+    }
+
+    /// Convert the block into the arrow types of the iceberg schema, so that
+    /// the written parquet files carry the iceberg field ids.
+    fn to_record_batch(&self, block: DataBlock) -> Result<RecordBatch> {
+        let batch = block.to_record_batch(&self.schema)?;
+        let columns = batch
+            .columns()
+            .iter()
+            .zip(self.arrow_schema.fields())
+            .map(|(column, field)| {
+                if column.data_type() == field.data_type() {
+                    Ok(column.clone())
+                } else {
+                    arrow_cast::cast(column, field.data_type())
+                }
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(RecordBatch::try_new(self.arrow_schema.clone(), columns)?)
+    }
+
+    fn write_partition(
+        &mut self,
+        key: Vec<u8>,
+        path: String,
+        partition: Struct,
+        batch: &RecordBatch,
+    ) -> Result<Option<PartitionWriter>> {
+        if !self.writers.contains_key(&key) {
+            let props = WriterProperties::builder()
+                .set_compression(Compression::ZSTD(ZstdLevel::default()))
+                .build();
+            let writer = ArrowWriter::try_new(vec![], self.arrow_schema.clone(), Some(props))?;
+            self.writers.insert(key.clone(), PartitionWriter {
+                path,
+                partition,
+                writer,
+                num_rows: 0,
+            });
+        }
+        let writer = self.writers.get_mut(&key).unwrap();
+        writer.writer.write(batch)?;
+        writer.num_rows += batch.num_rows() as u64;
+
+        // Roll over to a new file once reaching the target file size.
+        let size = writer.writer.bytes_written() + writer.writer.in_progress_size();
+        if size >= self.target_file_size {
+            return Ok(self.writers.remove(&key));
+        }
+        Ok(None)
+    }
+
+    async fn flush(&mut self, mut writer: PartitionWriter) -> Result<()> {
+        let metadata = writer.writer.finish()?;
+        let buf = std::mem::take(writer.writer.inner_mut());
+        let file_size = buf.len() as u64;
+        let file_path = if writer.path.is_empty() {
+            format!("{}/{}.parquet", self.data_path, Uuid::now_v7())
+        } else {
+            format!(
+                "{}/{}/{}.parquet",
+                self.data_path,
+                writer.path,
+                Uuid::now_v7()
+            )
+        };
+
+        self.table
+            .table
+            .file_io()
+            .new_output(&file_path)
+            .map_err(|err| {
+                ErrorCode::StorageOther(format!("Iceberg create output file failed: {err:?}"))
+            })?
+            .write(Bytes::from(buf))
+            .await
+            .map_err(|err| {
+                ErrorCode::StorageOther(format!("Iceberg write data file failed: {err:?}"))
+            })?;
+
+        let stats = ColumnStats::from_parquet_metadata(&metadata, &self.iceberg_schema);
+        let data_file = DataFileBuilder::default()
+            .content(DataContentType::Data)
+            .file_path(file_path)
+            .file_format(DataFileFormat::Parquet)
+            .partition(writer.partition)
+            .record_count(writer.num_rows)
+            .file_size_in_bytes(file_size)
+            .column_sizes(stats.column_sizes)
+            .value_counts(stats.value_counts)
+            .null_value_counts(stats.null_value_counts)
+            .lower_bounds(stats.lower_bounds)
+            .upper_bounds(stats.upper_bounds)
+            .build()
+            .map_err(|err| {
+                ErrorCode::Internal(format!("Iceberg build data file failed: {err:?}"))
+            })?;
+        self.data_files.push(data_file);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AsyncAccumulatingTransform for IcebergDataWriter {
+    const NAME: &'static str = "IcebergDataWriter";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, data: DataBlock) -> Result<Option<DataBlock>> {
+        if data.is_empty() {
+            return Ok(None);
+        }
+
+        let batch = self.to_record_batch(data)?;
+        let partitions = match &mut self.partitioner {
+            Some(partitioner) => partitioner.partition(&batch)?,
+            None => vec![(vec![], String::new(), Struct::empty(), None)],
+        };
+        for (key, path, partition, indices) in partitions {
+            let batch = match indices {
+                Some(indices) => take_record_batch(&batch, &indices)?,
+                None => batch.clone(),
+            };
+            if let Some(writer) = self.write_partition(key, path, partition, &batch)? {
+                self.flush(writer).await?;
+            }
+        }
+        Ok(None)
+    }
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self, output: bool) -> Result<Option<DataBlock>> {
+        for (_, writer) in std::mem::take(&mut self.writers) {
+            self.flush(writer).await?;
+        }
+        if !output || self.data_files.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(DataBlock::empty_with_meta(Box::new(
+            IcebergDataFiles {
+                data_files: std::mem::take(&mut self.data_files),
+            },
+        ))))
+    }
+}
+
+impl Partitioner {
+    fn try_create(spec: &PartitionSpec, arrow_schema: &ArrowSchema) -> Result<Self> {
+        let fields = spec
+            .fields
+            .iter()
+            .map(|field| {
+                // Partitioning by nested fields is not supported, so the source must be a
+                // top-level column.
+                let index = arrow_schema
+                    .fields()
+                    .iter()
+                    .position(|f| {
+                        f.metadata().get(PARQUET_FIELD_ID_META_KEY)
+                            == Some(&field.source_id.to_string())
+                    })
+                    .ok_or_else(|| {
+                        ErrorCode::Unimplemented(format!(
+                            "iceberg partition field {} of nested column is not supported",
+                            field.name
+                        ))
+                    })?;
+                let transform = create_transform_function(&field.transform).map_err(|err| {
+                    ErrorCode::Unimplemented(format!(
+                        "iceberg partition transform {} is not supported: {err:?}",
+                        field.transform
+                    ))
+                })?;
+                Ok((field.name.clone(), index, transform))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            fields,
+            converter: None,
+        })
+    }
+
+    /// Split the batch by the partition values.
+    ///
+    /// Returns (encoded partition values, partition path, partition values, row indices).
+    #[allow(clippy::type_complexity)]
+    fn partition(
+        &mut self,
+        batch: &RecordBatch,
+    ) -> Result<Vec<(Vec<u8>, String, Struct, Option<UInt32Array>)>> {
+        let values = self
+            .fields
+            .iter()
+            .map(|(_, index, transform)| {
+                transform
+                    .transform(batch.column(*index).clone())
+                    .map_err(|err| {
+                        ErrorCode::Internal(format!("Iceberg partition transform failed: {err:?}"))
+                    })
+            })
+            .collect::<Result<Vec<ArrayRef>>>()?;
+
+        if self.converter.is_none() {
+            let fields = values
+                .iter()
+                .map(|v| SortField::new(v.data_type().clone()))
+                .collect();
+            self.converter = Some(RowConverter::new(fields)?);
+        }
+        let rows = self.converter.as_mut().unwrap().convert_columns(&values)?;
+
+        let mut groups: HashMap<_, Vec<u32>> = HashMap::new();
+        for (i, row) in rows.iter().enumerate() {
+            groups.entry(row).or_default().push(i as u32);
+        }
+
+        let single_partition = groups.len() == 1;
+        groups
+            .into_iter()
+            .map(|(row, indices)| {
+                let first = indices[0] as usize;
+                let mut literals = Vec::with_capacity(values.len());
+                let mut path = Vec::with_capacity(values.len());
+                for ((name, _, _), value) in self.fields.iter().zip(values.iter()) {
+                    literals.push(partition_literal(value, first)?);
+                    let value = match value.is_null(first) {
+                        true => "null".to_string(),
+                        false => escape_path(&array_value_to_string(value, first)?),
+                    };
+                    path.push(format!("{}={}", escape_path(name), value));
+                }
+                let indices = match single_partition {
+                    true => None,
+                    false => Some(UInt32Array::from(indices)),
+                };
+                Ok((
+                    row.as_ref().to_vec(),
+                    path.join("/"),
+                    Struct::from_iter(literals),
+                    indices,
+                ))
+            })
+            .collect()
+    }
+}
+
+fn partition_literal(array: &ArrayRef, index: usize) -> Result<Option<Literal>> {
+    if array.is_null(index) {
+        return Ok(None);
+    }
+    let literal = match array.data_type() {
+        DataType::Boolean => Literal::bool(array.as_boolean().value(index)),
+        DataType::Int32 => Literal::int(array.as_primitive::<Int32Type>().value(index)),
+        DataType::Int64 => Literal::long(array.as_primitive::<Int64Type>().value(index)),
+        DataType::Float32 => Literal::float(array.as_primitive::<Float32Type>().value(index)),
+        DataType::Float64 => Literal::double(array.as_primitive::<Float64Type>().value(index)),
+        DataType::Date32 => Literal::date(array.as_primitive::<Date32Type>().value(index)),
+        DataType::Timestamp(TimeUnit::Microsecond, _) => Literal::timestamp(
+            array
+                .as_primitive::<TimestampMicrosecondType>()
+                .value(index),
+        ),
+        DataType::Decimal128(_, _) => {
+            Literal::decimal(array.as_primitive::<Decimal128Type>().value(index))
+        }
+        DataType::Utf8 => Literal::string(array.as_string::<i32>().value(index)),
+        DataType::LargeUtf8 => Literal::string(array.as_string::<i64>().value(index)),
+        DataType::Binary => Literal::binary(array.as_binary::<i32>().value(index).to_vec()),
+        DataType::LargeBinary => Literal::binary(array.as_binary::<i64>().value(index).to_vec()),
+        other => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "iceberg partition value of type {other} is not supported"
+            )));
+        }
+    };
+    Ok(Some(literal))
+}
+
+/// Escape the partition name or value to be a path segment, like what hive does.
+fn escape_path(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') {
+            escaped.push(c);
+        } else {
+            let mut buf = [0; 4];
+            for b in c.encode_utf8(&mut buf).bytes() {
+                escaped.push_str(&format!("%{:02X}", b));
+            }
+        }
+    }
+    escaped
+}
+
+/// Column statistics of a data file, keyed by the iceberg field id.
+#[derive(Default)]
+struct ColumnStats {
+    column_sizes: HashMap<i32, u64>,
+    value_counts: HashMap<i32, u64>,
+    null_value_counts: HashMap<i32, u64>,
+    lower_bounds: HashMap<i32, Datum>,
+    upper_bounds: HashMap<i32, Datum>,
+}
+
+impl ColumnStats {
+    /// Collect the statistics from the footer of the written parquet file.
+    fn from_parquet_metadata(metadata: &FileMetaData, schema: &Schema) -> Self {
+        // The column chunks of a row group are in the order of the leaves of the schema.
+        let leaf_field_ids = metadata
+            .schema
+            .iter()
+            .skip(1)
+            .filter(|element| element.num_children.unwrap_or_default() == 0)
+            .map(|element| element.field_id)
+            .collect::<Vec<_>>();
+
+        let mut stats = ColumnStats::default();
+        for row_group in &metadata.row_groups {
+            for (column, field_id) in row_group.columns.iter().zip(&leaf_field_ids) {
+                let (Some(field_id), Some(meta)) = (field_id, &column.meta_data) else {
+                    continue;
+                };
+                *stats.column_sizes.entry(*field_id).or_default() +=
+                    meta.total_compressed_size as u64;
+                *stats.value_counts.entry(*field_id).or_default() += meta.num_values as u64;
+
+                let Some(statistics) = &meta.statistics else {
+                    continue;
+                };
+                if let Some(null_count) = statistics.null_count {
+                    *stats.null_value_counts.entry(*field_id).or_default() += null_count as u64;
+                }
+                let Some(ty) = bound_type(schema, *field_id) else {
+                    continue;
+                };
+                if let Some(min) = statistics
+                    .min_value
+                    .as_ref()
+                    .and_then(|v| Datum::try_from_bytes(v, ty.clone()).ok())
+                {
+                    match stats.lower_bounds.get(field_id) {
+                        Some(bound) if *bound <= min => {}
+                        _ => {
+                            stats.lower_bounds.insert(*field_id, min);
+                        }
+                    }
+                }
+                if let Some(max) = statistics
+                    .max_value
+                    .as_ref()
+                    .and_then(|v| Datum::try_from_bytes(v, ty).ok())
+                {
+                    match stats.upper_bounds.get(field_id) {
+                        Some(bound) if *bound >= max => {}
+                        _ => {
+                            stats.upper_bounds.insert(*field_id, max);
+                        }
+                    }
+                }
+            }
+        }
+        stats
+    }
+}
+
+/// Returns the type of the field if the parquet statistics of the field share the binary
+/// form of the iceberg bounds.
+///
+/// Decimals are stored as fixed length byte arrays in parquet, while iceberg bounds use the
+/// minimal number of bytes, so they are skipped.
+fn bound_type(schema: &Schema, field_id: i32) -> Option<PrimitiveType> {
+    let field = schema.field_by_id(field_id)?;
+    match field.field_type.as_ref() {
+        Type::Primitive(
+            ty @ (PrimitiveType::Boolean
+            | PrimitiveType::Int
+            | PrimitiveType::Long
+            | PrimitiveType::Float
+            | PrimitiveType::Double
+            | PrimitiveType::Date
+            | PrimitiveType::Timestamp
+            | PrimitiveType::Timestamptz
+            | PrimitiveType::String
+            | PrimitiveType::Binary),
+        ) => Some(ty.clone()),
+        _ => None,
+    }
+}
+
+/// How the files are committed by [`IcebergCommitSink`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IcebergOperation {
+    /// Add the data files to the current snapshot.
+    Append,
+    /// Replace all the data of the table with the data files.
+    Overwrite,
+    /// Add the position delete files to the current snapshot.
+    Delete,
+}
+
+impl IcebergOperation {
+    fn as_str(&self) -> &'static str {
+        match self {
+            IcebergOperation::Append => "append",
+            IcebergOperation::Overwrite => "overwrite",
+            IcebergOperation::Delete => "delete",
+        }
+    }
+}
+
+/// Commit the written files as a new snapshot of the iceberg table.
+///
+/// The snapshot appends the data files or the position delete files to the current snapshot
+/// of the table, or replaces all the data of the table for an overwrite.
+///
+/// The commit is an optimistic one: the catalog rejects it if the table has been changed
+/// concurrently. An append is retried on top of the reloaded table, while an overwrite or a
+/// delete fails since the data it replaces or deletes may have been changed.
+pub struct IcebergCommitSink {
+    ctx: Arc<dyn TableContext>,
+    table: IcebergTable,
+    operation: IcebergOperation,
+    data_files: Vec<DataFile>,
+}
+
+impl IcebergCommitSink {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        table: IcebergTable,
+        operation: IcebergOperation,
+    ) -> Self {
+        Self {
+            ctx,
+            table,
+            operation,
+            data_files: vec![],
+        }
+    }
+
+    /// Write down the manifest of the data files or the delete files.
+    async fn write_manifest(&self, snapshot_id: i64) -> Result<ManifestFile> {
+        let table = &self.table.table;
+        let metadata = table.metadata();
+        let partition_spec = match metadata.default_partition_spec() {
+            Some(spec) => spec.as_ref().clone(),
+            None => serde_json::from_value(json!({"spec-id": 0, "fields": []}))?,
+        };
+        let manifest_metadata = ManifestMetadata::builder()
+            .schema(metadata.current_schema().as_ref().clone())
+            .schema_id(metadata.current_schema_id())
+            .partition_spec(partition_spec)
+            .format_version(FormatVersion::V2)
+            .content(match self.operation {
+                IcebergOperation::Delete => ManifestContentType::Deletes,
+                _ => ManifestContentType::Data,
+            })
+            .build();
+        let entries = self
+            .data_files
+            .iter()
+            .map(|data_file| {
+                ManifestEntry::builder()
+                    .status(ManifestStatus::Added)
+                    .snapshot_id(snapshot_id)
+                    .data_file(data_file.clone())
+                    .build()
+            })
+            .collect();
+
+        let location = format!("{}/{}-m0.avro", metadata_path(table), Uuid::now_v7());
+        let output = table
+            .file_io()
+            .new_output(&location)
+            .map_err(iceberg_error)?;
+        ManifestWriter::new(output, snapshot_id, vec![])
+            .write(Manifest::new(manifest_metadata, entries))
+            .await
+            .map_err(iceberg_error)
+    }
+
+    /// Commit a snapshot with the manifest on top of the current snapshot of `table`.
+    async fn commit(
+        &self,
+        catalog: &IcebergCatalog,
+        table: &iceberg::table::Table,
+        snapshot_id: i64,
+        manifest: Option<&ManifestFile>,
+    ) -> Result<()> {
+        let metadata = table.metadata();
+        let parent = metadata.current_snapshot();
+        let sequence_number = metadata.last_sequence_number() + 1;
+
+        // 1. collect the manifests of the snapshot, an overwrite drops all the existing ones
+        let mut manifests = vec![];
+        if let (false, Some(parent)) = (self.operation == IcebergOperation::Overwrite, parent) {
+            let manifest_list = parent
+                .load_manifest_list(table.file_io(), metadata)
+                .await
+                .map_err(iceberg_error)?;
+            manifests.extend(manifest_list.entries().iter().cloned());
+        }
+        manifests.extend(manifest.cloned());
+
+        // 2. write down the manifest list
+        let manifest_list_location = format!(
+            "{}/snap-{}-1-{}.avro",
+            metadata_path(table),
+            snapshot_id,
+            Uuid::now_v7()
+        );
+        let output = table
+            .file_io()
+            .new_output(&manifest_list_location)
+            .map_err(iceberg_error)?;
+        let mut writer = ManifestListWriter::v2(
+            output,
+            snapshot_id,
+            parent.map(|s| s.snapshot_id()),
+            sequence_number,
+        );
+        writer
+            .add_manifests(manifests.into_iter())
+            .map_err(iceberg_error)?;
+        writer.close().await.map_err(iceberg_error)?;
+
+        // 3. commit the snapshot, the requirements reject the commit if the table has been
+        // changed since it's loaded
+        let added_records = self
+            .data_files
+            .iter()
+            .map(|f| f.record_count())
+            .sum::<u64>()
+            .to_string();
+        let added_files_size = self
+            .data_files
+            .iter()
+            .map(|f| f.file_size_in_bytes())
+            .sum::<u64>()
+            .to_string();
+        let summary = match self.operation {
+            IcebergOperation::Delete => json!({
+                "operation": self.operation.as_str(),
+                "added-delete-files": self.data_files.len().to_string(),
+                "added-position-delete-files": self.data_files.len().to_string(),
+                "added-position-deletes": added_records,
+                "added-files-size": added_files_size,
+            }),
+            _ => json!({
+                "operation": self.operation.as_str(),
+                "added-data-files": self.data_files.len().to_string(),
+                "added-records": added_records,
+                "added-files-size": added_files_size,
+            }),
+        };
+        let snapshot = json!({
+            "snapshot-id": snapshot_id,
+            "parent-snapshot-id": parent.map(|s| s.snapshot_id()),
+            "sequence-number": sequence_number,
+            "timestamp-ms": Utc::now().timestamp_millis(),
+            "manifest-list": manifest_list_location,
+            "summary": summary,
+            "schema-id": metadata.current_schema_id(),
+        });
+        let requirements: Vec<TableRequirement> = serde_json::from_value(json!([
+            {"type": "assert-table-uuid", "uuid": metadata.uuid()},
+            {
+                "type": "assert-ref-snapshot-id",
+                "ref": "main",
+                "snapshot-id": parent.map(|s| s.snapshot_id()),
+            },
+        ]))?;
+        let updates: Vec<TableUpdate> = serde_json::from_value(json!([
+            {"action": "add-snapshot", "snapshot": snapshot},
+            {
+                "action": "set-snapshot-ref",
+                "ref-name": "main",
+                "type": "branch",
+                "snapshot-id": snapshot_id,
+            },
+        ]))?;
+        catalog
+            .commit_table(table.identifier(), requirements, updates)
+            .await
+    }
+}
+
+#[async_trait]
+impl AsyncSink for IcebergCommitSink {
+    const NAME: &'static str = "IcebergCommitSink";
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        // An overwrite commits even if nothing is written, which empties the table.
+        if self.data_files.is_empty() && self.operation != IcebergOperation::Overwrite {
+            return Ok(());
+        }
+
+        let catalog = self
+            .ctx
+            .get_catalog(self.table.get_table_info().catalog())
+            .await?;
+        let catalog = catalog
+            .as_any()
+            .downcast_ref::<IcebergCatalog>()
+            .ok_or_else(|| {
+                ErrorCode::Internal(format!(
+                    "catalog of iceberg table {} must be an iceberg catalog",
+                    self.table.name()
+                ))
+            })?;
+
+        // Iceberg snapshot ids are positive longs.
+        let snapshot_id = (Uuid::new_v4().as_u64_pair().0 >> 1) as i64;
+        // The manifest does not depend on the parent snapshot, so it's reused by the retries.
+        let manifest = match self.data_files.is_empty() {
+            true => None,
+            false => Some(self.write_manifest(snapshot_id).await?),
+        };
+
+        let mut table = self.table.table.clone();
+        let mut retries = 0;
+        loop {
+            match self
+                .commit(catalog, &table, snapshot_id, manifest.as_ref())
+                .await
+            {
+                Ok(_) => {
+                    info!(
+                        "iceberg table {} committed {} files, operation: {}",
+                        self.table.name(),
+                        self.data_files.len(),
+                        self.operation.as_str()
+                    );
+                    return Ok(());
+                }
+                Err(e)
+                    if e.code() == ErrorCode::TABLE_VERSION_MISMATCHED
+                        && self.operation == IcebergOperation::Append
+                        && retries < MAX_COMMIT_RETRIES =>
+                {
+                    retries += 1;
+                    warn!(
+                        "commit iceberg table {} conflicts, retrying {} times. {}",
+                        self.table.name(),
+                        retries,
+                        e
+                    );
+                    databend_common_base::base::tokio::time::sleep(Duration::from_millis(
+                        100 << retries,
+                    ))
+                    .await;
+                    table = catalog
+                        .iceberg_catalog()
+                        .load_table(table.identifier())
+                        .await
+                        .map_err(|err| {
+                            ErrorCode::ReadTableDataError(format!(
+                                "Iceberg catalog load failed: {err:?}"
+                            ))
+                        })?;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<bool> {
+        if let Some(meta) = data_block
+            .get_owned_meta()
+            .and_then(IcebergDataFiles::downcast_from)
+        {
+            self.data_files.extend(meta.data_files);
+        }
+        Ok(false)
+    }
+}
+
+pub fn data_path(table: &iceberg::table::Table) -> String {
+    let metadata = table.metadata();
+    match metadata.properties().get("write.data.path") {
+        Some(path) => path.trim_end_matches('/').to_string(),
+        None => format!("{}/data", metadata.location().trim_end_matches('/')),
+    }
+}
+
+fn metadata_path(table: &iceberg::table::Table) -> String {
+    let metadata = table.metadata();
+    match metadata.properties().get("write.metadata.path") {
+        Some(path) => path.trim_end_matches('/').to_string(),
+        None => format!("{}/metadata", metadata.location().trim_end_matches('/')),
+    }
+}
+
+fn iceberg_error(e: impl Debug) -> ErrorCode {
+    ErrorCode::StorageOther(format!("Iceberg commit failed: {e:?}"))
+}
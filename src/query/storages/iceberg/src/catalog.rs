@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use async_trait::async_trait;
@@ -40,12 +41,14 @@ use databend_common_meta_app::schema::CreateIndexReply;
 use databend_common_meta_app::schema::CreateIndexReq;
 use databend_common_meta_app::schema::CreateLockRevReply;
 use databend_common_meta_app::schema::CreateLockRevReq;
+use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::schema::CreateSequenceReply;
 use databend_common_meta_app::schema::CreateSequenceReq;
 use databend_common_meta_app::schema::CreateTableIndexReq;
 use databend_common_meta_app::schema::CreateTableReply;
 use databend_common_meta_app::schema::CreateTableReq;
 use databend_common_meta_app::schema::CreateVirtualColumnReq;
+use databend_common_meta_app::schema::DatabaseId;
 use databend_common_meta_app::schema::DeleteLockRevReq;
 use databend_common_meta_app::schema::DictionaryMeta;
 use databend_common_meta_app::schema::DropDatabaseReply;
@@ -103,6 +106,8 @@ use databend_common_meta_app::tenant::Tenant;
 use databend_common_meta_store::MetaStore;
 use databend_common_meta_types::seq_value::SeqV;
 use databend_common_meta_types::MetaId;
+use iceberg::TableRequirement;
+use iceberg::TableUpdate;
 use iceberg_catalog_glue::GlueCatalog;
 use iceberg_catalog_glue::GlueCatalogConfig;
 use iceberg_catalog_hms::HmsCatalog;
@@ -112,6 +117,9 @@ use iceberg_catalog_rest::RestCatalog;
 use iceberg_catalog_rest::RestCatalogConfig;
 
 use crate::database::IcebergDatabase;
+use crate::fs_catalog::FsCatalog;
+use crate::rest::RestCommitClient;
+use crate::schema::to_iceberg_schema;
 use crate::IcebergTable;
 
 pub const ICEBERG_CATALOG: &str = "iceberg";
@@ -144,7 +152,18 @@ pub struct IcebergCatalog {
     info: Arc<CatalogInfo>,
 
     /// iceberg catalogs
-    ctl: Arc<dyn iceberg::Catalog>,
+    ctl: IcebergCatalogClient,
+}
+
+/// The underlying iceberg catalog.
+///
+/// Table updates are committed through the concrete catalog, see [`IcebergCatalog::commit_table`].
+#[derive(Clone, Debug)]
+enum IcebergCatalogClient {
+    Hms(Arc<HmsCatalog>),
+    Rest(Arc<RestCatalog>, Arc<RestCommitClient>),
+    Glue(Arc<GlueCatalog>),
+    Fs(Arc<FsCatalog>),
 }
 
 impl IcebergCatalog {
@@ -164,7 +183,7 @@ impl IcebergCatalog {
         // `"s3.region"`, but it's stored as is. We need to remove the quotes here.
         //
         // We only do this while building catalog so this won't affect existing catalogs.
-        let ctl: Arc<dyn iceberg::Catalog> = match opt {
+        let ctl = match opt {
             IcebergCatalogOption::Hms(hms) => {
                 let cfg = HmsCatalogConfig::builder()
                     .address(hms.address.clone())
@@ -181,22 +200,24 @@ impl IcebergCatalog {
                 let ctl = HmsCatalog::new(cfg).map_err(|err| {
                     ErrorCode::BadArguments(format!("Iceberg build hms catalog failed: {err:?}"))
                 })?;
-                Arc::new(ctl)
+                IcebergCatalogClient::Hms(Arc::new(ctl))
             }
             IcebergCatalogOption::Rest(rest) => {
+                let props: HashMap<String, String> = rest
+                    .props
+                    .clone()
+                    .into_iter()
+                    .map(|(k, v)| (k.trim_matches('"').to_string(), v))
+                    .collect();
                 let cfg = RestCatalogConfig::builder()
                     .uri(rest.uri.clone())
                     .warehouse(rest.warehouse.clone())
-                    .props(
-                        rest.props
-                            .clone()
-                            .into_iter()
-                            .map(|(k, v)| (k.trim_matches('"').to_string(), v))
-                            .collect(),
-                    )
+                    .props(props.clone())
                     .build();
                 let ctl = RestCatalog::new(cfg);
-                Arc::new(ctl)
+                let committer =
+                    RestCommitClient::new(rest.uri.clone(), rest.warehouse.clone(), props);
+                IcebergCatalogClient::Rest(Arc::new(ctl), Arc::new(committer))
             }
             IcebergCatalogOption::Glue(glue) => {
                 let cfg = GlueCatalogConfig::builder()
@@ -218,7 +239,10 @@ impl IcebergCatalog {
                         ))
                     },
                 )?;
-                Arc::new(ctl)
+                IcebergCatalogClient::Glue(Arc::new(ctl))
+            }
+            IcebergCatalogOption::Fs(fs) => {
+                IcebergCatalogClient::Fs(Arc::new(FsCatalog::try_create(&fs.storage_params)?))
             }
         };
 
@@ -227,7 +251,60 @@ impl IcebergCatalog {
 
     /// Get the iceberg catalog.
     pub fn iceberg_catalog(&self) -> Arc<dyn iceberg::Catalog> {
-        self.ctl.clone()
+        match &self.ctl {
+            IcebergCatalogClient::Hms(ctl) => ctl.clone(),
+            IcebergCatalogClient::Rest(ctl, _) => ctl.clone(),
+            IcebergCatalogClient::Glue(ctl) => ctl.clone(),
+            IcebergCatalogClient::Fs(ctl) => ctl.clone(),
+        }
+    }
+
+    /// Drop the table from the iceberg catalog.
+    ///
+    /// Iceberg tables have no table id, so they are dropped by name instead of
+    /// [`Catalog::drop_table_by_id`].
+    #[async_backtrace::framed]
+    pub async fn drop_table(&self, db_name: &str, table_name: &str) -> Result<()> {
+        let table_ident = iceberg::TableIdent::new(
+            iceberg::NamespaceIdent::new(db_name.to_string()),
+            table_name.to_string(),
+        );
+        self.iceberg_catalog()
+            .drop_table(&table_ident)
+            .await
+            .map_err(|err| {
+                ErrorCode::StorageOther(format!("Iceberg catalog drop table failed: {err:?}"))
+            })
+    }
+
+    /// Commit the updates to the iceberg table if all the requirements are met.
+    ///
+    /// Fails with [`ErrorCode::TableVersionMismatched`] if the table has been changed
+    /// concurrently, the caller may reload the table and retry.
+    #[async_backtrace::framed]
+    pub async fn commit_table(
+        &self,
+        table: &iceberg::TableIdent,
+        requirements: Vec<TableRequirement>,
+        updates: Vec<TableUpdate>,
+    ) -> Result<()> {
+        match &self.ctl {
+            IcebergCatalogClient::Rest(_, committer) => {
+                committer.commit_table(table, requirements, updates).await
+            }
+            IcebergCatalogClient::Fs(ctl) => {
+                ctl.commit_table(table, requirements, updates).await?;
+                Ok(())
+            }
+            IcebergCatalogClient::Hms(_) => Err(ErrorCode::Unimplemented(format!(
+                "writing iceberg table {} is not supported by hms catalog",
+                table.name()
+            ))),
+            IcebergCatalogClient::Glue(_) => Err(ErrorCode::Unimplemented(format!(
+                "writing iceberg table {} is not supported by glue catalog",
+                table.name()
+            ))),
+        }
     }
 }
 
@@ -275,13 +352,67 @@ impl Catalog for IcebergCatalog {
     }
 
     #[async_backtrace::framed]
-    async fn create_database(&self, _req: CreateDatabaseReq) -> Result<CreateDatabaseReply> {
-        unimplemented!()
+    async fn create_database(&self, req: CreateDatabaseReq) -> Result<CreateDatabaseReply> {
+        let db_name = req.name_ident.database_name();
+        let db_ident = iceberg::NamespaceIdent::new(db_name.to_string());
+        let exists = self
+            .iceberg_catalog()
+            .namespace_exists(&db_ident)
+            .await
+            .map_err(|err| {
+                ErrorCode::StorageOther(format!("Iceberg catalog check database failed: {err:?}"))
+            })?;
+        let reply = CreateDatabaseReply {
+            db_id: DatabaseId::new(0),
+        };
+        if exists {
+            return match req.create_option {
+                CreateOption::CreateIfNotExists => Ok(reply),
+                CreateOption::Create => Err(ErrorCode::DatabaseAlreadyExists(format!(
+                    "Database '{db_name}' already exists"
+                ))),
+                CreateOption::CreateOrReplace => Err(ErrorCode::Unimplemented(
+                    "CREATE OR REPLACE DATABASE is not supported for iceberg catalog",
+                )),
+            };
+        }
+
+        self.iceberg_catalog()
+            .create_namespace(&db_ident, HashMap::new())
+            .await
+            .map_err(|err| {
+                ErrorCode::StorageOther(format!("Iceberg catalog create database failed: {err:?}"))
+            })?;
+        Ok(reply)
     }
 
     #[async_backtrace::framed]
-    async fn drop_database(&self, _req: DropDatabaseReq) -> Result<DropDatabaseReply> {
-        unimplemented!()
+    async fn drop_database(&self, req: DropDatabaseReq) -> Result<DropDatabaseReply> {
+        let db_name = req.name_ident.database_name();
+        let db_ident = iceberg::NamespaceIdent::new(db_name.to_string());
+        let exists = self
+            .iceberg_catalog()
+            .namespace_exists(&db_ident)
+            .await
+            .map_err(|err| {
+                ErrorCode::StorageOther(format!("Iceberg catalog check database failed: {err:?}"))
+            })?;
+        if !exists {
+            return match req.if_exists {
+                true => Ok(DropDatabaseReply { db_id: 0 }),
+                false => Err(ErrorCode::UnknownDatabase(format!(
+                    "Unknown database '{db_name}'"
+                ))),
+            };
+        }
+
+        self.iceberg_catalog()
+            .drop_namespace(&db_ident)
+            .await
+            .map_err(|err| {
+                ErrorCode::StorageOther(format!("Iceberg catalog drop database failed: {err:?}"))
+            })?;
+        Ok(DropDatabaseReply { db_id: 0 })
     }
 
     #[async_backtrace::framed]
@@ -386,13 +517,64 @@ impl Catalog for IcebergCatalog {
     }
 
     #[async_backtrace::framed]
-    async fn create_table(&self, _req: CreateTableReq) -> Result<CreateTableReply> {
-        unimplemented!()
+    async fn create_table(&self, req: CreateTableReq) -> Result<CreateTableReply> {
+        let db_name = &req.name_ident.db_name;
+        let table_name = &req.name_ident.table_name;
+        let db_ident = iceberg::NamespaceIdent::new(db_name.clone());
+        let table_ident = iceberg::TableIdent::new(db_ident.clone(), table_name.clone());
+
+        // Tables are visible in iceberg catalog once created, so `as_dropped` is ignored here.
+        let reply = CreateTableReply {
+            table_id: 0,
+            table_id_seq: Some(0),
+            db_id: 0,
+            new_table: true,
+            spec_vec: None,
+            prev_table_id: None,
+            orphan_table_name: None,
+        };
+        let exists = self
+            .iceberg_catalog()
+            .table_exists(&table_ident)
+            .await
+            .map_err(|err| {
+                ErrorCode::StorageOther(format!("Iceberg catalog check table failed: {err:?}"))
+            })?;
+        if exists {
+            return match req.create_option {
+                CreateOption::CreateIfNotExists => Ok(CreateTableReply {
+                    new_table: false,
+                    ..reply
+                }),
+                CreateOption::Create => Err(ErrorCode::TableAlreadyExists(format!(
+                    "Table '{db_name}.{table_name}' already exists"
+                ))),
+                CreateOption::CreateOrReplace => Err(ErrorCode::Unimplemented(
+                    "CREATE OR REPLACE TABLE is not supported for iceberg catalog",
+                )),
+            };
+        }
+
+        let schema = to_iceberg_schema(&req.table_meta.schema)?;
+        let creation = iceberg::TableCreation::builder()
+            .name(table_name.clone())
+            .schema(schema)
+            .build();
+        self.iceberg_catalog()
+            .create_table(&db_ident, creation)
+            .await
+            .map_err(|err| {
+                ErrorCode::StorageOther(format!("Iceberg catalog create table failed: {err:?}"))
+            })?;
+        Ok(reply)
     }
 
     #[async_backtrace::framed]
     async fn drop_table_by_id(&self, _req: DropTableByIdReq) -> Result<DropTableReply> {
-        unimplemented!()
+        // Tables of iceberg catalog have no id, see `IcebergCatalog::drop_table`.
+        Err(ErrorCode::Unimplemented(
+            "Cannot drop table by id in ICEBERG catalog",
+        ))
     }
 
     #[async_backtrace::framed]
@@ -402,7 +584,8 @@ impl Catalog for IcebergCatalog {
 
     #[async_backtrace::framed]
     async fn commit_table_meta(&self, _req: CommitTableMetaReq) -> Result<CommitTableMetaReply> {
-        unimplemented!()
+        // Nothing to do, see `create_table`.
+        Ok(CommitTableMetaReply {})
     }
 
     #[async_backtrace::framed]
@@ -69,6 +69,25 @@ impl Database for IcebergDatabase {
 
     #[async_backtrace::framed]
     async fn get_table(&self, table_name: &str) -> Result<Arc<dyn Table>> {
+        let exists = self
+            .ctl
+            .iceberg_catalog()
+            .table_exists(&iceberg::TableIdent::new(
+                self.ident.clone(),
+                table_name.to_string(),
+            ))
+            .await
+            .map_err(|err| {
+                ErrorCode::StorageOther(format!("Iceberg catalog check table failed: {err:?}"))
+            })?;
+        if !exists {
+            return Err(ErrorCode::UnknownTable(format!(
+                "Unknown table '{}.{}'",
+                self.name(),
+                table_name
+            )));
+        }
+
         let tbl = IcebergTable::try_create_from_iceberg_catalog(
             self.ctl.clone(),
             self.info.name_ident.database_name(),
@@ -0,0 +1,377 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use arrow_array::ArrayRef;
+use arrow_array::Int64Array;
+use arrow_array::RecordBatch;
+use arrow_array::StringArray;
+use arrow_schema::DataType as ArrowDataType;
+use arrow_schema::Field as ArrowField;
+use arrow_schema::Schema as ArrowSchema;
+use bytes::Bytes;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchema;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::RemoteExpr;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sinks::AsyncSinker;
+use databend_common_pipeline_sources::AsyncSource;
+use databend_common_pipeline_sources::AsyncSourcer;
+use databend_common_storage::MutationStatus;
+use databend_common_storages_parquet::transform_record_batch;
+use futures::stream;
+use futures::TryStreamExt;
+use iceberg::spec::DataContentType;
+use iceberg::spec::DataFile;
+use iceberg::spec::DataFileBuilder;
+use iceberg::spec::DataFileFormat;
+use iceberg::spec::Datum;
+use iceberg::spec::FormatVersion;
+use parquet::arrow::ArrowWriter;
+use parquet::arrow::PARQUET_FIELD_ID_META_KEY;
+use parquet::basic::Compression;
+use parquet::basic::ZstdLevel;
+use parquet::file::properties::WriterProperties;
+use uuid::Uuid;
+
+use crate::append::data_path;
+use crate::append::IcebergCommitSink;
+use crate::append::IcebergDataFiles;
+use crate::append::IcebergOperation;
+use crate::scan::load_deleted_positions;
+use crate::scan::plan_data_files;
+use crate::scan::IcebergScanFile;
+use crate::scan::DELETE_FILE_PATH_FIELD_ID;
+use crate::scan::DELETE_FILE_POS_FIELD_ID;
+use crate::IcebergTable;
+
+impl IcebergTable {
+    /// Delete the rows matching the filter.
+    ///
+    /// The positions of the matched rows are written into a position delete file for each
+    /// data file, and committed as a new snapshot of the table. Without a filter, all the data
+    /// of the table is dropped by an overwrite instead.
+    #[async_backtrace::framed]
+    pub async fn delete(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        filter: Option<RemoteExpr<String>>,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let metadata = self.table.metadata();
+        if metadata.format_version() == FormatVersion::V1 {
+            return Err(ErrorCode::Unimplemented(format!(
+                "deleting from iceberg table {} of format version 1 is not supported",
+                self.name()
+            )));
+        }
+
+        let mut filter = filter.map(|filter| filter.as_expr(&BUILTIN_FUNCTIONS));
+        // A constant filter deletes all the rows or none of them.
+        let mut delete_nothing = false;
+        if let Some(expr) = &filter {
+            if expr.column_refs().is_empty() {
+                delete_nothing = !eval_const_filter(ctx.as_ref(), expr)?;
+                filter = None;
+            }
+        }
+
+        // Only the columns of the filter are read from the data files.
+        let columns = filter
+            .as_ref()
+            .map(|expr| expr.column_refs())
+            .unwrap_or_default();
+        let filter_schema = Arc::new(DataSchema::new(
+            self.schema()
+                .fields()
+                .iter()
+                .filter(|f| columns.contains_key(f.name()))
+                .map(DataField::from)
+                .collect(),
+        ));
+        let projection = filter_schema
+            .fields()
+            .iter()
+            .map(|f| f.name().clone())
+            .collect::<Vec<_>>();
+        let files = match delete_nothing {
+            true => vec![],
+            false => plan_data_files(&self.table, None, Some(projection)).await?,
+        };
+
+        // The delete files are written into the manifest of the default partition spec.
+        let default_spec_id = metadata
+            .default_partition_spec()
+            .map(|spec| spec.spec_id)
+            .unwrap_or_default();
+        if filter.is_some() && files.iter().any(|f| f.spec_id != default_spec_id) {
+            return Err(ErrorCode::Unimplemented(format!(
+                "deleting from iceberg table {} with data files of an old partition spec is not supported",
+                self.name()
+            )));
+        }
+
+        let operation = match filter.is_none() && !files.is_empty() {
+            true => IcebergOperation::Overwrite,
+            false => IcebergOperation::Delete,
+        };
+        let filter = filter
+            .map(|expr| expr.project_column_ref(|name| filter_schema.index_of(name).unwrap()));
+
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let num_sources = std::cmp::min(files.len(), max_threads).max(1);
+        let files = Arc::new(Mutex::new(VecDeque::from(files)));
+        let delete_path = data_path(&self.table);
+        pipeline.add_source(
+            |output| {
+                AsyncSourcer::create(ctx.clone(), output, IcebergDeleteSource {
+                    ctx: ctx.clone(),
+                    table: self.clone(),
+                    filter: filter.clone(),
+                    filter_schema: filter_schema.clone(),
+                    files: files.clone(),
+                    delete_path: delete_path.clone(),
+                })
+            },
+            num_sources,
+        )?;
+
+        pipeline.try_resize(1)?;
+        pipeline.add_sink(|input| {
+            Ok(ProcessorPtr::create(AsyncSinker::create(
+                input,
+                IcebergCommitSink::create(ctx.clone(), self.clone(), operation),
+            )))
+        })
+    }
+}
+
+fn eval_const_filter(ctx: &dyn TableContext, expr: &Expr<String>) -> Result<bool> {
+    let expr = expr.project_column_ref(|_| 0);
+    let block = DataBlock::new(vec![], 1);
+    let func_ctx = ctx.get_function_context()?;
+    let evaluator = Evaluator::new(&block, &func_ctx, &BUILTIN_FUNCTIONS);
+    Ok(match evaluator.run(&expr)?.try_downcast::<BooleanType>() {
+        Some(Value::Scalar(v)) => v,
+        Some(Value::Column(bitmap)) => bitmap.get_bit(0),
+        None => false,
+    })
+}
+
+/// Find the rows of the data files matching the filter, and write their positions into
+/// position delete files, which are sent downstream to [`IcebergCommitSink`].
+///
+/// Without a filter, all the rows are deleted, only the deleted rows are counted.
+pub struct IcebergDeleteSource {
+    ctx: Arc<dyn TableContext>,
+    table: IcebergTable,
+    filter: Option<Expr>,
+    // The columns of the filter, read from the data files.
+    filter_schema: Arc<DataSchema>,
+    // Shared by the sources, each data file is taken by one of them.
+    files: Arc<Mutex<VecDeque<IcebergScanFile>>>,
+    delete_path: String,
+}
+
+impl IcebergDeleteSource {
+    /// Returns the positions of the rows matching the filter, skipping the deleted ones.
+    async fn matched_positions(
+        &self,
+        file: &IcebergScanFile,
+        filter: &Expr,
+        deleted: &HashSet<i64>,
+    ) -> Result<Vec<i64>> {
+        let mut task = file.task.clone();
+        task.predicate = None;
+        let reader = self
+            .table
+            .table
+            .reader_builder()
+            .with_batch_size(self.ctx.get_settings().get_parquet_max_block_size()? as usize)
+            .with_row_group_filtering_enabled(false)
+            .build();
+        let mut stream = reader
+            .read(Box::pin(stream::iter([Ok(task)])))
+            .await
+            .map_err(|err| ErrorCode::Internal(format!("iceberg data stream read: {err:?}")))?;
+
+        let func_ctx = self.ctx.get_function_context()?;
+        let mut positions = vec![];
+        let mut offset = 0;
+        while let Some(batch) = stream
+            .try_next()
+            .await
+            .map_err(|err| ErrorCode::Internal(format!("iceberg data stream read: {err:?}")))?
+        {
+            let num_rows = batch.num_rows() as i64;
+            let block = transform_record_batch(&self.filter_schema, &batch, &None)?;
+            let evaluator = Evaluator::new(&block, &func_ctx, &BUILTIN_FUNCTIONS);
+            let predicates = evaluator
+                .run(filter)
+                .map_err(|e| e.add_message("eval filter failed:"))?
+                .try_downcast::<BooleanType>()
+                .unwrap();
+            match predicates {
+                Value::Scalar(true) => positions
+                    .extend((offset..offset + num_rows).filter(|pos| !deleted.contains(pos))),
+                Value::Scalar(false) => {}
+                Value::Column(bitmap) => positions.extend(
+                    bitmap
+                        .iter()
+                        .zip(offset..offset + num_rows)
+                        .filter(|(matched, pos)| *matched && !deleted.contains(pos))
+                        .map(|(_, pos)| pos),
+                ),
+            }
+            offset += num_rows;
+        }
+        Ok(positions)
+    }
+
+    /// Write the positions into a position delete file of the data file.
+    async fn write_delete_file(
+        &self,
+        file: &IcebergScanFile,
+        positions: Vec<i64>,
+    ) -> Result<DataFile> {
+        let field = |name: &str, data_type: ArrowDataType, field_id: i32| {
+            ArrowField::new(name, data_type, false).with_metadata(HashMap::from([(
+                PARQUET_FIELD_ID_META_KEY.to_string(),
+                field_id.to_string(),
+            )]))
+        };
+        let schema = Arc::new(ArrowSchema::new(vec![
+            field("file_path", ArrowDataType::Utf8, DELETE_FILE_PATH_FIELD_ID),
+            field("pos", ArrowDataType::Int64, DELETE_FILE_POS_FIELD_ID),
+        ]));
+        let num_rows = positions.len();
+        let (min_pos, max_pos) = (positions[0], positions[num_rows - 1]);
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(StringArray::from(vec![
+                file.task.data_file_path.as_str();
+                num_rows
+            ])),
+            Arc::new(Int64Array::from(positions)),
+        ];
+        let batch = RecordBatch::try_new(schema.clone(), columns)?;
+
+        let props = WriterProperties::builder()
+            .set_compression(Compression::ZSTD(ZstdLevel::default()))
+            .build();
+        let mut writer = ArrowWriter::try_new(vec![], schema, Some(props))?;
+        writer.write(&batch)?;
+        let buf = writer.into_inner()?;
+        let file_size = buf.len() as u64;
+        let file_path = format!("{}/{}-deletes.parquet", self.delete_path, Uuid::now_v7());
+
+        self.table
+            .table
+            .file_io()
+            .new_output(&file_path)
+            .map_err(|err| {
+                ErrorCode::StorageOther(format!("Iceberg create output file failed: {err:?}"))
+            })?
+            .write(Bytes::from(buf))
+            .await
+            .map_err(|err| {
+                ErrorCode::StorageOther(format!("Iceberg write delete file failed: {err:?}"))
+            })?;
+
+        // The bounds of `file_path` tell the readers which data file the deletes apply to.
+        let data_file_path = Datum::string(&file.task.data_file_path);
+        DataFileBuilder::default()
+            .content(DataContentType::PositionDeletes)
+            .file_path(file_path)
+            .file_format(DataFileFormat::Parquet)
+            .partition(file.partition.clone())
+            .record_count(num_rows as u64)
+            .file_size_in_bytes(file_size)
+            .lower_bounds(HashMap::from([
+                (DELETE_FILE_PATH_FIELD_ID, data_file_path.clone()),
+                (DELETE_FILE_POS_FIELD_ID, Datum::long(min_pos)),
+            ]))
+            .upper_bounds(HashMap::from([
+                (DELETE_FILE_PATH_FIELD_ID, data_file_path),
+                (DELETE_FILE_POS_FIELD_ID, Datum::long(max_pos)),
+            ]))
+            .build()
+            .map_err(|err| {
+                ErrorCode::Internal(format!("Iceberg build delete file failed: {err:?}"))
+            })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for IcebergDeleteSource {
+    const NAME: &'static str = "IcebergDeleteSource";
+    const SKIP_EMPTY_DATA_BLOCK: bool = false;
+
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        loop {
+            let Some(file) = self.files.lock().unwrap().pop_front() else {
+                return Ok(None);
+            };
+            let deleted = load_deleted_positions(
+                &self.table.table,
+                &file.task.data_file_path,
+                &file.delete_files,
+            )
+            .await?;
+            let positions = match &self.filter {
+                Some(filter) => self.matched_positions(&file, filter, &deleted).await?,
+                None => {
+                    let record_count = file.task.record_count.unwrap_or_default();
+                    self.ctx.add_mutation_status(MutationStatus {
+                        insert_rows: 0,
+                        deleted_rows: record_count.saturating_sub(deleted.len() as u64),
+                        update_rows: 0,
+                    });
+                    continue;
+                }
+            };
+            if positions.is_empty() {
+                continue;
+            }
+
+            self.ctx.add_mutation_status(MutationStatus {
+                insert_rows: 0,
+                deleted_rows: positions.len() as u64,
+                update_rows: 0,
+            });
+            let delete_file = self.write_delete_file(&file, positions).await?;
+            return Ok(Some(DataBlock::empty_with_meta(Box::new(
+                IcebergDataFiles {
+                    data_files: vec![delete_file],
+                },
+            ))));
+        }
+    }
+}
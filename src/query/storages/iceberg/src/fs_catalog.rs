@@ -0,0 +1,725 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Filesystem (hadoop-style) iceberg catalog.
+//!
+//! Namespaces and tables are plain directories under the warehouse, the current metadata
+//! version of a table is tracked by `version-hint.text`:
+//!
+//! ```text
+//! <warehouse>/<namespace>/<table>/metadata/version-hint.text
+//! <warehouse>/<namespace>/<table>/metadata/v<N>.metadata.json
+//! ```
+//!
+//! Committing a new version is NOT atomic on object storage: two writers may race between
+//! checking and writing `v<N+1>.metadata.json`. Use a REST or HMS catalog for concurrent writes.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use databend_common_exception::ErrorCode;
+use databend_common_meta_app::storage::StorageParams;
+use databend_common_storage::init_operator;
+use iceberg::io::FileIO;
+use iceberg::io::FileIOBuilder;
+use iceberg::spec::TableMetadata;
+use iceberg::spec::TableMetadataBuilder;
+use iceberg::table::Table;
+use iceberg::Catalog;
+use iceberg::Error;
+use iceberg::ErrorKind;
+use iceberg::Namespace;
+use iceberg::NamespaceIdent;
+use iceberg::TableCommit;
+use iceberg::TableCreation;
+use iceberg::TableIdent;
+use iceberg::TableRequirement;
+use iceberg::TableUpdate;
+use opendal::Operator;
+use serde_json::json;
+use serde_json::Value;
+
+const VERSION_HINT: &str = "version-hint.text";
+
+pub struct FsCatalog {
+    operator: Operator,
+    file_io: FileIO,
+    /// Uri of the warehouse root, like `file:///path/to/warehouse` or `s3://bucket/root`.
+    warehouse: String,
+}
+
+impl Debug for FsCatalog {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FsCatalog")
+            .field("warehouse", &self.warehouse)
+            .finish_non_exhaustive()
+    }
+}
+
+impl FsCatalog {
+    pub fn try_create(params: &StorageParams) -> databend_common_exception::Result<Self> {
+        let (file_io, warehouse) = match params {
+            StorageParams::Fs(cfg) => {
+                let root = std::path::absolute(&cfg.root)?;
+                let file_io = FileIOBuilder::new("file").build().map_err(build_error)?;
+                (
+                    file_io,
+                    format!(
+                        "file://{}",
+                        root.display().to_string().trim_end_matches('/')
+                    ),
+                )
+            }
+            StorageParams::S3(cfg) => {
+                let mut props = vec![
+                    ("s3.endpoint".to_string(), cfg.endpoint_url.clone()),
+                    ("s3.access-key-id".to_string(), cfg.access_key_id.clone()),
+                    (
+                        "s3.secret-access-key".to_string(),
+                        cfg.secret_access_key.clone(),
+                    ),
+                ];
+                if !cfg.region.is_empty() {
+                    props.push(("s3.region".to_string(), cfg.region.clone()));
+                }
+                if !cfg.security_token.is_empty() {
+                    props.push(("s3.session-token".to_string(), cfg.security_token.clone()));
+                }
+                let file_io = FileIOBuilder::new("s3")
+                    .with_props(props)
+                    .build()
+                    .map_err(build_error)?;
+                let root = cfg.root.trim_matches('/');
+                let warehouse = if root.is_empty() {
+                    format!("s3://{}", cfg.bucket)
+                } else {
+                    format!("s3://{}/{}", cfg.bucket, root)
+                };
+                (file_io, warehouse)
+            }
+            other => {
+                return Err(ErrorCode::StorageUnsupported(format!(
+                    "iceberg fs catalog is not supported on storage {}",
+                    other
+                )));
+            }
+        };
+
+        Ok(Self {
+            operator: init_operator(params)?,
+            file_io,
+            warehouse,
+        })
+    }
+
+    fn namespace_path(namespace: &NamespaceIdent) -> String {
+        format!("{}/", namespace.clone().inner().join("/"))
+    }
+
+    fn table_path(table: &TableIdent) -> String {
+        format!(
+            "{}{}/",
+            Self::namespace_path(table.namespace()),
+            table.name()
+        )
+    }
+
+    fn metadata_path(table_path: &str, version: u64) -> String {
+        format!("{table_path}metadata/v{version}.metadata.json")
+    }
+
+    fn uri(&self, path: &str) -> String {
+        format!("{}/{}", self.warehouse, path.trim_end_matches('/'))
+    }
+
+    async fn is_table_dir(&self, path: &str) -> iceberg::Result<bool> {
+        self.operator
+            .exists(&format!("{path}metadata/"))
+            .await
+            .map_err(storage_error)
+    }
+
+    /// List the sub directories of `path`, returns `(name, path)` pairs.
+    async fn list_dirs(&self, path: &str) -> iceberg::Result<Vec<(String, String)>> {
+        let entries = match self.operator.list(path).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == opendal::ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => return Err(storage_error(e)),
+        };
+        Ok(entries
+            .into_iter()
+            .filter(|entry| entry.metadata().is_dir() && entry.path() != path)
+            .map(|entry| {
+                (
+                    entry.name().trim_end_matches('/').to_string(),
+                    entry.path().to_string(),
+                )
+            })
+            .collect())
+    }
+
+    /// Read the current metadata version of the table, `None` if the table does not exist.
+    ///
+    /// Falls back to the largest `v<N>.metadata.json` if the version hint is missing.
+    async fn current_version(&self, table_path: &str) -> iceberg::Result<Option<u64>> {
+        match self
+            .operator
+            .read(&format!("{table_path}metadata/{VERSION_HINT}"))
+            .await
+        {
+            Ok(buf) => {
+                let hint = String::from_utf8(buf.to_vec()).map_err(|e| {
+                    Error::new(ErrorKind::DataInvalid, "invalid iceberg version hint")
+                        .with_source(e)
+                })?;
+                let version = hint.trim().parse::<u64>().map_err(|e| {
+                    Error::new(ErrorKind::DataInvalid, "invalid iceberg version hint")
+                        .with_source(e)
+                })?;
+                return Ok(Some(version));
+            }
+            Err(e) if e.kind() == opendal::ErrorKind::NotFound => {}
+            Err(e) => return Err(storage_error(e)),
+        }
+
+        let entries = match self.operator.list(&format!("{table_path}metadata/")).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == opendal::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(storage_error(e)),
+        };
+        Ok(entries
+            .iter()
+            .filter_map(|entry| {
+                entry
+                    .name()
+                    .strip_prefix('v')?
+                    .strip_suffix(".metadata.json")?
+                    .parse::<u64>()
+                    .ok()
+            })
+            .max())
+    }
+
+    async fn read_metadata(
+        &self,
+        table: &TableIdent,
+    ) -> iceberg::Result<(u64, String, TableMetadata)> {
+        let table_path = Self::table_path(table);
+        let Some(version) = self.current_version(&table_path).await? else {
+            return Err(Error::new(
+                ErrorKind::DataInvalid,
+                format!("iceberg table {:?} does not exist", table),
+            ));
+        };
+        let path = Self::metadata_path(&table_path, version);
+        let buf = self.operator.read(&path).await.map_err(storage_error)?;
+        let metadata = serde_json::from_slice(&buf.to_vec())?;
+        Ok((version, self.uri(&path), metadata))
+    }
+
+    /// Write `v<version>.metadata.json` and point the version hint to it.
+    ///
+    /// Returns `None` if the version has been written by others.
+    async fn write_metadata(
+        &self,
+        table: &TableIdent,
+        version: u64,
+        metadata: &TableMetadata,
+    ) -> iceberg::Result<Option<String>> {
+        let table_path = Self::table_path(table);
+        let path = Self::metadata_path(&table_path, version);
+        let data = serde_json::to_vec(metadata)?;
+        if self
+            .operator
+            .info()
+            .full_capability()
+            .write_with_if_not_exists
+        {
+            match self
+                .operator
+                .write_with(&path, data)
+                .if_not_exists(true)
+                .await
+            {
+                Ok(_) => {}
+                Err(e) if e.kind() == opendal::ErrorKind::ConditionNotMatch => return Ok(None),
+                Err(e) => return Err(storage_error(e)),
+            }
+        } else {
+            // Best effort for the storages that can not write conditionally.
+            if self.operator.exists(&path).await.map_err(storage_error)? {
+                return Ok(None);
+            }
+            self.operator
+                .write(&path, data)
+                .await
+                .map_err(storage_error)?;
+        }
+        self.operator
+            .write(
+                &format!("{table_path}metadata/{VERSION_HINT}"),
+                version.to_string(),
+            )
+            .await
+            .map_err(storage_error)?;
+        Ok(Some(self.uri(&path)))
+    }
+
+    fn build_table(
+        &self,
+        table: TableIdent,
+        metadata_location: String,
+        metadata: TableMetadata,
+    ) -> iceberg::Result<Table> {
+        Table::builder()
+            .file_io(self.file_io.clone())
+            .metadata_location(metadata_location)
+            .metadata(metadata)
+            .identifier(table)
+            .build()
+    }
+}
+
+#[async_trait]
+impl Catalog for FsCatalog {
+    async fn list_namespaces(
+        &self,
+        parent: Option<&NamespaceIdent>,
+    ) -> iceberg::Result<Vec<NamespaceIdent>> {
+        let (parent_names, path) = match parent {
+            Some(parent) => (parent.clone().inner(), Self::namespace_path(parent)),
+            None => (vec![], "/".to_string()),
+        };
+        let mut namespaces = vec![];
+        for (name, path) in self.list_dirs(&path).await? {
+            if self.is_table_dir(&path).await? {
+                continue;
+            }
+            let mut names = parent_names.clone();
+            names.push(name);
+            namespaces.push(NamespaceIdent::from_vec(names)?);
+        }
+        Ok(namespaces)
+    }
+
+    async fn create_namespace(
+        &self,
+        namespace: &NamespaceIdent,
+        properties: HashMap<String, String>,
+    ) -> iceberg::Result<Namespace> {
+        if !properties.is_empty() {
+            return Err(Error::new(
+                ErrorKind::FeatureUnsupported,
+                "iceberg fs catalog does not support namespace properties",
+            ));
+        }
+        if self.namespace_exists(namespace).await? {
+            return Err(Error::new(
+                ErrorKind::DataInvalid,
+                format!("namespace {:?} already exists", namespace),
+            ));
+        }
+        self.operator
+            .create_dir(&Self::namespace_path(namespace))
+            .await
+            .map_err(storage_error)?;
+        Ok(Namespace::new(namespace.clone()))
+    }
+
+    async fn get_namespace(&self, namespace: &NamespaceIdent) -> iceberg::Result<Namespace> {
+        if !self.namespace_exists(namespace).await? {
+            return Err(Error::new(
+                ErrorKind::DataInvalid,
+                format!("namespace {:?} does not exist", namespace),
+            ));
+        }
+        Ok(Namespace::new(namespace.clone()))
+    }
+
+    async fn namespace_exists(&self, namespace: &NamespaceIdent) -> iceberg::Result<bool> {
+        let path = Self::namespace_path(namespace);
+        Ok(self.operator.exists(&path).await.map_err(storage_error)?
+            && !self.is_table_dir(&path).await?)
+    }
+
+    async fn update_namespace(
+        &self,
+        _namespace: &NamespaceIdent,
+        _properties: HashMap<String, String>,
+    ) -> iceberg::Result<()> {
+        Err(Error::new(
+            ErrorKind::FeatureUnsupported,
+            "iceberg fs catalog does not support namespace properties",
+        ))
+    }
+
+    async fn drop_namespace(&self, namespace: &NamespaceIdent) -> iceberg::Result<()> {
+        let path = Self::namespace_path(namespace);
+        if !self.list_dirs(&path).await?.is_empty() {
+            return Err(Error::new(
+                ErrorKind::DataInvalid,
+                format!("namespace {:?} is not empty", namespace),
+            ));
+        }
+        self.operator.remove_all(&path).await.map_err(storage_error)
+    }
+
+    async fn list_tables(&self, namespace: &NamespaceIdent) -> iceberg::Result<Vec<TableIdent>> {
+        let mut tables = vec![];
+        for (name, path) in self.list_dirs(&Self::namespace_path(namespace)).await? {
+            if self.is_table_dir(&path).await? {
+                tables.push(TableIdent::new(namespace.clone(), name));
+            }
+        }
+        Ok(tables)
+    }
+
+    async fn create_table(
+        &self,
+        namespace: &NamespaceIdent,
+        mut creation: TableCreation,
+    ) -> iceberg::Result<Table> {
+        let table = TableIdent::new(namespace.clone(), creation.name.clone());
+        if self.table_exists(&table).await? {
+            return Err(Error::new(
+                ErrorKind::DataInvalid,
+                format!("iceberg table {:?} already exists", table),
+            ));
+        }
+
+        // The location of a table is decided by its name in a hadoop-style catalog.
+        let location = self.uri(&Self::table_path(&table));
+        match &creation.location {
+            Some(l) if l.trim_end_matches('/') != location => {
+                return Err(Error::new(
+                    ErrorKind::FeatureUnsupported,
+                    "iceberg fs catalog does not support custom table location",
+                ));
+            }
+            _ => creation.location = Some(location),
+        }
+
+        let metadata = TableMetadataBuilder::from_table_creation(creation)?.build()?;
+        let Some(metadata_location) = self.write_metadata(&table, 1, &metadata).await? else {
+            return Err(Error::new(
+                ErrorKind::DataInvalid,
+                format!("iceberg table {:?} already exists", table),
+            ));
+        };
+        self.build_table(table, metadata_location, metadata)
+    }
+
+    async fn load_table(&self, table: &TableIdent) -> iceberg::Result<Table> {
+        let (_, metadata_location, metadata) = self.read_metadata(table).await?;
+        self.build_table(table.clone(), metadata_location, metadata)
+    }
+
+    async fn drop_table(&self, table: &TableIdent) -> iceberg::Result<()> {
+        if !self.table_exists(table).await? {
+            return Err(Error::new(
+                ErrorKind::DataInvalid,
+                format!("iceberg table {:?} does not exist", table),
+            ));
+        }
+        self.operator
+            .remove_all(&Self::table_path(table))
+            .await
+            .map_err(storage_error)
+    }
+
+    async fn table_exists(&self, table: &TableIdent) -> iceberg::Result<bool> {
+        Ok(self
+            .current_version(&Self::table_path(table))
+            .await?
+            .is_some())
+    }
+
+    async fn rename_table(&self, _src: &TableIdent, _dest: &TableIdent) -> iceberg::Result<()> {
+        Err(Error::new(
+            ErrorKind::FeatureUnsupported,
+            "iceberg fs catalog does not support renaming tables",
+        ))
+    }
+
+    async fn update_table(&self, mut commit: TableCommit) -> iceberg::Result<Table> {
+        let table = commit.identifier().clone();
+        self.commit_table(&table, commit.take_requirements(), commit.take_updates())
+            .await
+            .map_err(|e| Error::new(ErrorKind::DataInvalid, e.message()))
+    }
+}
+
+impl FsCatalog {
+    /// Commit the updates to the table if all the requirements are met.
+    ///
+    /// Fails with [`ErrorCode::TableVersionMismatched`] if the requirements are not met or
+    /// the table has been updated concurrently, so that the caller is able to retry.
+    pub async fn commit_table(
+        &self,
+        table: &TableIdent,
+        requirements: Vec<TableRequirement>,
+        updates: Vec<TableUpdate>,
+    ) -> databend_common_exception::Result<Table> {
+        let (version, metadata_location, metadata) =
+            self.read_metadata(table).await.map_err(commit_error)?;
+
+        // Requirements and updates are applied on the json form of the metadata, which is
+        // the form defined by the iceberg spec.
+        let mut value = serde_json::to_value(&metadata)?;
+        for requirement in requirements {
+            check_requirement(&value, serde_json::to_value(requirement)?)?;
+        }
+        let now = Utc::now().timestamp_millis();
+        for update in updates {
+            apply_update(&mut value, serde_json::to_value(update)?, now).map_err(commit_error)?;
+        }
+        let log_entry = json!({
+            "metadata-file": metadata_location,
+            "timestamp-ms": value["last-updated-ms"],
+        });
+        push(&mut value, "metadata-log", log_entry);
+        value["last-updated-ms"] = json!(now);
+
+        let metadata: TableMetadata = serde_json::from_value(value)?;
+        let Some(metadata_location) = self
+            .write_metadata(table, version + 1, &metadata)
+            .await
+            .map_err(commit_error)?
+        else {
+            return Err(ErrorCode::TableVersionMismatched(format!(
+                "iceberg table {:?} has been updated concurrently, metadata version {} already exists",
+                table,
+                version + 1
+            )));
+        };
+        self.build_table(table.clone(), metadata_location, metadata)
+            .map_err(commit_error)
+    }
+}
+
+fn check_requirement(
+    metadata: &Value,
+    requirement: Value,
+) -> databend_common_exception::Result<()> {
+    let ty = requirement["type"].as_str().unwrap_or_default();
+    let (actual, expected) = match ty {
+        "assert-create" => {
+            return Err(ErrorCode::TableAlreadyExists(
+                "requirement failed: iceberg table already exists",
+            ));
+        }
+        "assert-table-uuid" => (&metadata["table-uuid"], &requirement["uuid"]),
+        "assert-ref-snapshot-id" => {
+            let name = requirement["ref"].as_str().unwrap_or_default();
+            (
+                &metadata["refs"][name]["snapshot-id"],
+                &requirement["snapshot-id"],
+            )
+        }
+        "assert-last-assigned-field-id" => (
+            &metadata["last-column-id"],
+            &requirement["last-assigned-field-id"],
+        ),
+        "assert-current-schema-id" => (
+            &metadata["current-schema-id"],
+            &requirement["current-schema-id"],
+        ),
+        "assert-last-assigned-partition-id" => (
+            &metadata["last-partition-id"],
+            &requirement["last-assigned-partition-id"],
+        ),
+        "assert-default-spec-id" => (
+            &metadata["default-spec-id"],
+            &requirement["default-spec-id"],
+        ),
+        "assert-default-sort-order-id" => (
+            &metadata["default-sort-order-id"],
+            &requirement["default-sort-order-id"],
+        ),
+        _ => {
+            return Err(ErrorCode::StorageUnsupported(format!(
+                "unsupported iceberg table requirement {}",
+                ty
+            )));
+        }
+    };
+    if actual != expected {
+        return Err(ErrorCode::TableVersionMismatched(format!(
+            "requirement {} failed: expected {}, actual {}",
+            ty, expected, actual
+        )));
+    }
+    Ok(())
+}
+
+fn apply_update(metadata: &mut Value, update: Value, now: i64) -> iceberg::Result<()> {
+    let action = update["action"].as_str().unwrap_or_default();
+    match action {
+        "assign-uuid" => metadata["table-uuid"] = update["uuid"].clone(),
+        "upgrade-format-version" => {
+            if metadata["format-version"] != update["format-version"] {
+                return Err(Error::new(
+                    ErrorKind::FeatureUnsupported,
+                    "iceberg fs catalog does not support upgrading format version",
+                ));
+            }
+        }
+        "add-schema" => {
+            let last_column_id = update["last-column-id"]
+                .as_i64()
+                .unwrap_or_default()
+                .max(metadata["last-column-id"].as_i64().unwrap_or_default());
+            push(metadata, "schemas", update["schema"].clone());
+            metadata["last-column-id"] = json!(last_column_id);
+        }
+        "set-current-schema" => {
+            let id = last_added_id(metadata, "schemas", "schema-id", &update["schema-id"]);
+            metadata["current-schema-id"] = id;
+        }
+        "set-default-spec" => {
+            let id = last_added_id(metadata, "partition-specs", "spec-id", &update["spec-id"]);
+            metadata["default-spec-id"] = id;
+        }
+        "add-sort-order" => push(metadata, "sort-orders", update["sort-order"].clone()),
+        "set-default-sort-order" => {
+            let id = last_added_id(
+                metadata,
+                "sort-orders",
+                "order-id",
+                &update["sort-order-id"],
+            );
+            metadata["default-sort-order-id"] = id;
+        }
+        "add-snapshot" => {
+            let snapshot = update["snapshot"].clone();
+            if let Some(sequence_number) = snapshot.get("sequence-number") {
+                metadata["last-sequence-number"] = sequence_number.clone();
+            }
+            push(metadata, "snapshots", snapshot);
+        }
+        "set-snapshot-ref" => {
+            let Some(name) = update["ref-name"].as_str() else {
+                return Err(Error::new(ErrorKind::DataInvalid, "ref-name is missing"));
+            };
+            let mut reference = update.clone();
+            if let Some(reference) = reference.as_object_mut() {
+                reference.remove("action");
+                reference.remove("ref-name");
+            }
+            if name == "main" {
+                let snapshot_id = update["snapshot-id"].clone();
+                let timestamp = metadata["snapshots"]
+                    .as_array()
+                    .and_then(|snapshots| {
+                        snapshots
+                            .iter()
+                            .find(|s| s["snapshot-id"] == snapshot_id)
+                            .map(|s| s["timestamp-ms"].clone())
+                    })
+                    .unwrap_or_else(|| json!(now));
+                metadata["current-snapshot-id"] = snapshot_id.clone();
+                push(
+                    metadata,
+                    "snapshot-log",
+                    json!({ "snapshot-id": snapshot_id, "timestamp-ms": timestamp }),
+                );
+            }
+            if !metadata["refs"].is_object() {
+                metadata["refs"] = json!({});
+            }
+            metadata["refs"][name] = reference;
+        }
+        "remove-snapshot-ref" => {
+            let name = update["ref-name"].as_str().unwrap_or_default();
+            if let Some(refs) = metadata["refs"].as_object_mut() {
+                refs.remove(name);
+            }
+            if name == "main" {
+                if let Some(metadata) = metadata.as_object_mut() {
+                    metadata.remove("current-snapshot-id");
+                }
+            }
+        }
+        "remove-snapshots" => {
+            let ids = update["snapshot-ids"]
+                .as_array()
+                .cloned()
+                .unwrap_or_default();
+            if let Some(snapshots) = metadata["snapshots"].as_array_mut() {
+                snapshots.retain(|s| !ids.contains(&s["snapshot-id"]));
+            }
+        }
+        "set-location" => metadata["location"] = update["location"].clone(),
+        "set-properties" => {
+            if !metadata["properties"].is_object() {
+                metadata["properties"] = json!({});
+            }
+            if let (Some(properties), Some(updates)) = (
+                metadata["properties"].as_object_mut(),
+                update["updates"].as_object(),
+            ) {
+                properties.extend(updates.clone());
+            }
+        }
+        "remove-properties" => {
+            let removals = update["removals"].as_array().cloned().unwrap_or_default();
+            if let Some(properties) = metadata["properties"].as_object_mut() {
+                for key in removals.iter().filter_map(|k| k.as_str()) {
+                    properties.remove(key);
+                }
+            }
+        }
+        _ => {
+            return Err(Error::new(
+                ErrorKind::FeatureUnsupported,
+                format!("unsupported iceberg table update {}", action),
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn push(metadata: &mut Value, key: &str, value: Value) {
+    match metadata[key].as_array_mut() {
+        Some(array) => array.push(value),
+        None => metadata[key] = json!([value]),
+    }
+}
+
+/// Resolve the `-1` id of updates, which means the last added one.
+fn last_added_id(metadata: &Value, list: &str, key: &str, id: &Value) -> Value {
+    if id.as_i64() != Some(-1) {
+        return id.clone();
+    }
+    metadata[list]
+        .as_array()
+        .and_then(|items| items.last())
+        .map(|item| item[key].clone())
+        .unwrap_or_else(|| id.clone())
+}
+
+fn storage_error(e: opendal::Error) -> Error {
+    Error::new(ErrorKind::Unexpected, "iceberg fs catalog storage failure").with_source(e)
+}
+
+fn commit_error(e: Error) -> ErrorCode {
+    ErrorCode::StorageOther(format!("Iceberg fs catalog commit failed: {e:?}"))
+}
+
+fn build_error(e: Error) -> ErrorCode {
+    ErrorCode::BadArguments(format!("Iceberg build fs catalog failed: {e:?}"))
+}
@@ -19,10 +19,16 @@
 #![feature(impl_trait_in_assoc_type)]
 #![allow(clippy::diverging_sub_expression)]
 
+mod append;
 mod catalog;
 mod database;
+mod delete;
+mod fs_catalog;
 mod partition;
 mod predicate;
+mod rest;
+mod scan;
+mod schema;
 mod table;
 mod table_source;
 
@@ -23,22 +23,27 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
-pub struct IcebergPartInfo(iceberg::scan::FileScanTask);
+pub struct IcebergPartInfo {
+    task: iceberg::scan::FileScanTask,
+    /// The position delete files applied to the data file.
+    delete_files: Vec<String>,
+}
 
 impl PartialEq for IcebergPartInfo {
     fn eq(&self, other: &Self) -> bool {
-        self.0.data_file_path == other.0.data_file_path
-            && self.0.start == other.0.start
-            && self.0.length == other.0.length
-            && self.0.predicate == other.0.predicate
-            && self.0.schema == other.0.schema
-            && self.0.project_field_ids == other.0.project_field_ids
+        self.task.data_file_path == other.task.data_file_path
+            && self.task.start == other.task.start
+            && self.task.length == other.task.length
+            && self.task.predicate == other.task.predicate
+            && self.task.schema == other.task.schema
+            && self.task.project_field_ids == other.task.project_field_ids
+            && self.delete_files == other.delete_files
     }
 }
 
 impl IcebergPartInfo {
-    pub fn new(task: iceberg::scan::FileScanTask) -> Self {
-        Self(task)
+    pub fn new(task: iceberg::scan::FileScanTask, delete_files: Vec<String>) -> Self {
+        Self { task, delete_files }
     }
 
     pub fn from_part(info: &PartInfoPtr) -> Result<&IcebergPartInfo> {
@@ -48,7 +53,11 @@ impl IcebergPartInfo {
     }
 
     pub fn to_task(&self) -> iceberg::scan::FileScanTask {
-        self.0.clone()
+        self.task.clone()
+    }
+
+    pub fn delete_files(&self) -> &[String] {
+        &self.delete_files
     }
 }
 
@@ -66,9 +75,9 @@ impl PartInfo for IcebergPartInfo {
 
     fn hash(&self) -> u64 {
         let mut s = DefaultHasher::new();
-        self.0.data_file_path.hash(&mut s);
-        self.0.start.hash(&mut s);
-        self.0.length.hash(&mut s);
+        self.task.data_file_path.hash(&mut s);
+        self.task.start.hash(&mut s);
+        self.task.length.hash(&mut s);
         s.finish()
     }
 }
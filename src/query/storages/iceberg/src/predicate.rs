@@ -117,6 +117,35 @@ impl PredicateBuilder {
                 }
             }
 
+            // contains(array, a), the in-list runtime filter of join
+            RemoteExpr::FunctionCall {
+                span: _,
+                id,
+                generics: _,
+                args,
+                return_type: _,
+            } if args.len() == 2
+                && id.name().as_ref() == "contains"
+                && matches!(args[0], RemoteExpr::Constant { .. })
+                && matches!(args[1], RemoteExpr::ColumnRef { .. }) =>
+            {
+                let (_, scalar, _) = args[0].as_constant().unwrap();
+                if let Scalar::Array(column) = scalar {
+                    let datums = column
+                        .iter()
+                        .filter(|v| !v.is_null())
+                        .map(|v| scalar_to_datatum(&v.to_owned()))
+                        .collect::<Option<Vec<_>>>();
+                    if let Some(datums) = datums {
+                        let (_, name, _, _) = args[1].as_column_ref().unwrap();
+                        let r = Reference::new(name);
+                        return r.is_in(datums);
+                    }
+                }
+                self.uncertain = true;
+                Predicate::AlwaysTrue
+            }
+
             // binary {a op datum}
             RemoteExpr::FunctionCall {
                 span: _,
@@ -188,11 +217,11 @@ fn build_unary(r: Reference, op: &str) -> Option<Predicate> {
 fn build_binary(r: Reference, op: &str, datum: Datum) -> Option<Predicate> {
     let op = match op {
         "lt" | "<" => r.less_than(datum),
-        "le" | "<=" => r.less_than_or_equal_to(datum),
+        "le" | "lte" | "<=" => r.less_than_or_equal_to(datum),
         "gt" | ">" => r.greater_than(datum),
-        "ge" | ">=" => r.greater_than_or_equal_to(datum),
+        "ge" | "gte" | ">=" => r.greater_than_or_equal_to(datum),
         "eq" | "=" => r.equal_to(datum),
-        "ne" | "!=" => r.not_equal_to(datum),
+        "ne" | "noteq" | "!=" => r.not_equal_to(datum),
         _ => return None,
     };
     Some(op)
@@ -202,11 +231,11 @@ fn build_binary(r: Reference, op: &str, datum: Datum) -> Option<Predicate> {
 fn build_reverse_binary(r: Reference, op: &str, datum: Datum) -> Option<Predicate> {
     let op = match op {
         "lt" | "<" => r.greater_than(datum),
-        "le" | "<=" => r.greater_than_or_equal_to(datum),
+        "le" | "lte" | "<=" => r.greater_than_or_equal_to(datum),
         "gt" | ">" => r.less_than(datum),
-        "ge" | ">=" => r.less_than_or_equal_to(datum),
+        "ge" | "gte" | ">=" => r.less_than_or_equal_to(datum),
         "eq" | "=" => r.equal_to(datum),
-        "ne" | "!=" => r.not_equal_to(datum),
+        "ne" | "noteq" | "!=" => r.not_equal_to(datum),
         _ => return None,
     };
     Some(op)
@@ -236,3 +265,90 @@ fn scalar_to_datatum(scalar: &Scalar) -> Option<Datum> {
     };
     Some(val)
 }
+
+#[cfg(test)]
+mod tests {
+    use databend_common_expression::types::DataType;
+    use databend_common_expression::types::NumberDataType;
+    use databend_common_expression::types::NumberScalar;
+    use databend_common_expression::FunctionID;
+    use databend_common_expression::RemoteExpr;
+    use databend_common_expression::Scalar;
+    use iceberg::expr::Predicate;
+    use iceberg::expr::Reference;
+    use iceberg::spec::Datum;
+
+    use super::PredicateBuilder;
+
+    fn column() -> RemoteExpr<String> {
+        RemoteExpr::ColumnRef {
+            span: None,
+            id: "a".to_string(),
+            data_type: DataType::Number(NumberDataType::Int32),
+            display_name: "a".to_string(),
+        }
+    }
+
+    fn constant() -> RemoteExpr<String> {
+        RemoteExpr::Constant {
+            span: None,
+            scalar: Scalar::Number(NumberScalar::Int32(1)),
+            data_type: DataType::Number(NumberDataType::Int32),
+        }
+    }
+
+    fn build(name: &str, args: Vec<RemoteExpr<String>>) -> Predicate {
+        let expr = RemoteExpr::FunctionCall {
+            span: None,
+            id: FunctionID::Builtin {
+                name: name.to_string(),
+                id: 0,
+            },
+            generics: vec![],
+            args,
+            return_type: DataType::Boolean,
+        };
+        PredicateBuilder::default().build(&expr)
+    }
+
+    #[test]
+    fn test_comparison_functions() {
+        let r = Reference::new("a");
+        let cases = [
+            ("lt", r.clone().less_than(Datum::int(1))),
+            ("lte", r.clone().less_than_or_equal_to(Datum::int(1))),
+            ("gt", r.clone().greater_than(Datum::int(1))),
+            ("gte", r.clone().greater_than_or_equal_to(Datum::int(1))),
+            ("eq", r.clone().equal_to(Datum::int(1))),
+            ("noteq", r.clone().not_equal_to(Datum::int(1))),
+        ];
+        for (name, expected) in cases {
+            assert_eq!(build(name, vec![column(), constant()]), expected, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_reverse_comparison_functions() {
+        // 1 op a is rewritten to a op_v 1
+        let r = Reference::new("a");
+        let cases = [
+            ("lt", r.clone().greater_than(Datum::int(1))),
+            ("lte", r.clone().greater_than_or_equal_to(Datum::int(1))),
+            ("gt", r.clone().less_than(Datum::int(1))),
+            ("gte", r.clone().less_than_or_equal_to(Datum::int(1))),
+            ("eq", r.clone().equal_to(Datum::int(1))),
+            ("noteq", r.clone().not_equal_to(Datum::int(1))),
+        ];
+        for (name, expected) in cases {
+            assert_eq!(build(name, vec![constant(), column()]), expected, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_unknown_function() {
+        assert_eq!(
+            build("like", vec![column(), constant()]),
+            Predicate::AlwaysTrue
+        );
+    }
+}
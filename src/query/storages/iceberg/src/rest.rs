@@ -0,0 +1,213 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use databend_common_base::base::tokio::sync::OnceCell;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use iceberg::TableIdent;
+use iceberg::TableRequirement;
+use iceberg::TableUpdate;
+use reqwest::Client;
+use reqwest::RequestBuilder;
+use reqwest::StatusCode;
+use reqwest::Url;
+use serde_json::json;
+use serde_json::Value;
+
+/// Commits table updates to an iceberg rest catalog.
+///
+/// `iceberg-rust` only commits the table updates built by its own transactions, which can
+/// not replace the data of a table. So we post the updates to the `updateTable` endpoint
+/// of the rest catalog ourselves.
+#[derive(Debug)]
+pub struct RestCommitClient {
+    client: Client,
+    uri: String,
+    warehouse: String,
+    props: HashMap<String, String>,
+
+    /// The endpoint prefix and the bearer token, resolved on the first commit.
+    config: OnceCell<(String, Option<String>)>,
+}
+
+impl RestCommitClient {
+    pub fn new(uri: String, warehouse: String, props: HashMap<String, String>) -> Self {
+        Self {
+            client: Client::new(),
+            uri: uri.trim_end_matches('/').to_string(),
+            warehouse,
+            props,
+            config: OnceCell::new(),
+        }
+    }
+
+    /// Commit the updates to the table if all the requirements are met.
+    ///
+    /// Fails with [`ErrorCode::TableVersionMismatched`] if the catalog rejects the commit
+    /// because of a conflict, so that the caller is able to retry.
+    pub async fn commit_table(
+        &self,
+        table: &TableIdent,
+        requirements: Vec<TableRequirement>,
+        updates: Vec<TableUpdate>,
+    ) -> Result<()> {
+        let (prefix, token) = self.config.get_or_try_init(|| self.load_config()).await?;
+
+        let mut url = self.url(&[])?;
+        {
+            let mut segments = url
+                .path_segments_mut()
+                .map_err(|_| ErrorCode::BadArguments(format!("invalid uri {}", self.uri)))?;
+            segments.push("v1");
+            if !prefix.is_empty() {
+                segments.extend(prefix.split('/'));
+            }
+            // Multipart namespaces are separated by the unit separator.
+            segments.extend([
+                "namespaces",
+                table.namespace().clone().inner().join("\u{1f}").as_str(),
+                "tables",
+                table.name(),
+            ]);
+        }
+
+        let body = json!({
+            "identifier": table,
+            "requirements": requirements,
+            "updates": updates,
+        });
+        let resp = with_token(self.client.post(url), token.as_deref())
+            .json(&body)
+            .send()
+            .await
+            .map_err(rest_error)?;
+
+        let status = resp.status();
+        if status.is_success() {
+            return Ok(());
+        }
+        let message = resp.text().await.unwrap_or_default();
+        match status {
+            StatusCode::CONFLICT => Err(ErrorCode::TableVersionMismatched(format!(
+                "iceberg table {:?} has been updated concurrently: {}",
+                table, message
+            ))),
+            _ => Err(ErrorCode::StorageOther(format!(
+                "Iceberg rest catalog commit failed with status {}: {}",
+                status, message
+            ))),
+        }
+    }
+
+    /// Resolve the endpoint prefix of the warehouse, and the bearer token if configured.
+    async fn load_config(&self) -> Result<(String, Option<String>)> {
+        let token = match (self.props.get("token"), self.props.get("credential")) {
+            (Some(token), _) => Some(token.clone()),
+            (None, Some(credential)) => Some(self.exchange_token(credential).await?),
+            (None, None) => None,
+        };
+
+        let mut url = self.url(&["v1", "config"])?;
+        if !self.warehouse.is_empty() {
+            url.query_pairs_mut()
+                .append_pair("warehouse", &self.warehouse);
+        }
+        let resp = with_token(self.client.get(url), token.as_deref())
+            .send()
+            .await
+            .map_err(rest_error)?
+            .error_for_status()
+            .map_err(rest_error)?;
+        let config: Value = resp.json().await.map_err(rest_error)?;
+
+        // Overrides take precedence over the client config, which takes precedence over
+        // the defaults.
+        let prefix = [
+            &config["overrides"]["prefix"],
+            &json!(self.props.get("prefix")),
+            &config["defaults"]["prefix"],
+        ]
+        .into_iter()
+        .find_map(|v| v.as_str())
+        .unwrap_or_default()
+        .trim_matches('/')
+        .to_string();
+        Ok((prefix, token))
+    }
+
+    /// Exchange the `client_id:client_secret` credential for an oauth2 token.
+    async fn exchange_token(&self, credential: &str) -> Result<String> {
+        let (client_id, client_secret) = match credential.split_once(':') {
+            Some((id, secret)) => (Some(id), secret),
+            None => (None, credential),
+        };
+        let scope = self
+            .props
+            .get("scope")
+            .map(|s| s.as_str())
+            .unwrap_or("catalog");
+        let mut params = vec![
+            ("grant_type", "client_credentials"),
+            ("client_secret", client_secret),
+            ("scope", scope),
+        ];
+        if let Some(client_id) = client_id {
+            params.push(("client_id", client_id));
+        }
+
+        let url = match self.props.get("oauth2-server-uri") {
+            Some(uri) => Url::parse(uri).map_err(|e| {
+                ErrorCode::BadArguments(format!("invalid oauth2-server-uri {}: {}", uri, e))
+            })?,
+            None => self.url(&["v1", "oauth", "tokens"])?,
+        };
+        let resp = self
+            .client
+            .post(url)
+            .form(&params)
+            .send()
+            .await
+            .map_err(rest_error)?
+            .error_for_status()
+            .map_err(rest_error)?;
+        let token: Value = resp.json().await.map_err(rest_error)?;
+        token["access_token"]
+            .as_str()
+            .map(|v| v.to_string())
+            .ok_or_else(|| ErrorCode::StorageOther("Iceberg rest catalog returns no access token"))
+    }
+
+    fn url(&self, segments: &[&str]) -> Result<Url> {
+        let mut url = Url::parse(&self.uri)
+            .map_err(|e| ErrorCode::BadArguments(format!("invalid uri {}: {}", self.uri, e)))?;
+        url.path_segments_mut()
+            .map_err(|_| ErrorCode::BadArguments(format!("invalid uri {}", self.uri)))?
+            .pop_if_empty()
+            .extend(segments);
+        Ok(url)
+    }
+}
+
+fn with_token(req: RequestBuilder, token: Option<&str>) -> RequestBuilder {
+    match token {
+        Some(token) => req.bearer_auth(token),
+        None => req,
+    }
+}
+
+fn rest_error(e: reqwest::Error) -> ErrorCode {
+    ErrorCode::StorageOther(format!("Iceberg rest catalog request failed: {e:?}"))
+}
@@ -0,0 +1,255 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::fmt::Debug;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::Int64Type;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use futures::TryStreamExt;
+use iceberg::expr::Bind;
+use iceberg::expr::Predicate;
+use iceberg::scan::FileScanTask;
+use iceberg::spec::DataContentType;
+use iceberg::spec::DataFile;
+use iceberg::spec::Datum;
+use iceberg::spec::ManifestContentType;
+use iceberg::spec::Struct;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+/// Reserved field id of the `file_path` column of position delete files.
+pub const DELETE_FILE_PATH_FIELD_ID: i32 = 2147483546;
+/// Reserved field id of the `pos` column of position delete files.
+pub const DELETE_FILE_POS_FIELD_ID: i32 = 2147483545;
+
+/// A data file of the current snapshot, with the position delete files applied to it.
+#[derive(Debug, Clone)]
+pub struct IcebergScanFile {
+    pub task: FileScanTask,
+    pub partition: Struct,
+    pub spec_id: i32,
+    pub delete_files: Vec<String>,
+}
+
+/// Plan the data files to read from the current snapshot of the table.
+///
+/// The files are planned by the iceberg scan, which prunes them by the partition summaries
+/// and the column bounds. The scan only supports data files, so the snapshots with delete
+/// files are planned by [`plan_data_files`] instead, which returns all the data files.
+pub async fn plan_files(
+    table: &iceberg::table::Table,
+    predicate: Option<Predicate>,
+    projection: Option<Vec<String>>,
+) -> Result<Vec<(FileScanTask, Vec<String>)>> {
+    let metadata = table.metadata();
+    let Some(snapshot) = metadata.current_snapshot() else {
+        return Ok(vec![]);
+    };
+    let manifest_list = snapshot
+        .load_manifest_list(table.file_io(), metadata)
+        .await
+        .map_err(scan_error)?;
+    let has_deletes = manifest_list
+        .entries()
+        .iter()
+        .any(|manifest| manifest.content == ManifestContentType::Deletes);
+
+    if has_deletes {
+        let files = plan_data_files(table, predicate, projection).await?;
+        return Ok(files
+            .into_iter()
+            .map(|file| (file.task, file.delete_files))
+            .collect());
+    }
+
+    let mut scan = table.scan();
+    if let Some(projection) = projection {
+        scan = scan.select(projection);
+    }
+    if let Some(predicate) = predicate {
+        scan = scan.with_filter(predicate);
+    }
+    let tasks: Vec<FileScanTask> = scan
+        .build()
+        .map_err(|err| ErrorCode::Internal(format!("iceberg table scan build: {err:?}")))?
+        .plan_files()
+        .await
+        .map_err(|err| ErrorCode::Internal(format!("iceberg table scan plan: {err:?}")))?
+        .try_collect()
+        .await
+        .map_err(|err| ErrorCode::Internal(format!("iceberg table scan collect: {err:?}")))?;
+    Ok(tasks.into_iter().map(|task| (task, vec![])).collect())
+}
+
+/// Plan all the live data files of the current snapshot, with the position delete files
+/// applied to them.
+///
+/// A position delete file applies to the data files of the same partition whose sequence
+/// numbers are not greater than its own, and whose paths are within its `file_path` bounds.
+pub async fn plan_data_files(
+    table: &iceberg::table::Table,
+    predicate: Option<Predicate>,
+    projection: Option<Vec<String>>,
+) -> Result<Vec<IcebergScanFile>> {
+    let metadata = table.metadata();
+    let Some(snapshot) = metadata.current_snapshot() else {
+        return Ok(vec![]);
+    };
+    let schema = metadata.current_schema().clone();
+    let predicate = predicate
+        .map(|predicate| predicate.bind(schema.clone(), true))
+        .transpose()
+        .map_err(scan_error)?;
+    let project_field_ids = match projection {
+        Some(names) => names
+            .iter()
+            .map(|name| {
+                schema.field_id_by_name(name).ok_or_else(|| {
+                    ErrorCode::Internal(format!("iceberg table scan: unknown column {name}"))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?,
+        None => schema.as_struct().fields().iter().map(|f| f.id).collect(),
+    };
+
+    // (partition spec id, sequence number, file)
+    let mut data_files = vec![];
+    let mut delete_files = vec![];
+    let manifest_list = snapshot
+        .load_manifest_list(table.file_io(), metadata)
+        .await
+        .map_err(scan_error)?;
+    for manifest_file in manifest_list.entries() {
+        let manifest = manifest_file
+            .load_manifest(table.file_io())
+            .await
+            .map_err(scan_error)?;
+        for entry in manifest.entries() {
+            if !entry.is_alive() {
+                continue;
+            }
+            let sequence_number = entry
+                .sequence_number()
+                .unwrap_or(manifest_file.sequence_number);
+            let file = (
+                manifest_file.partition_spec_id,
+                sequence_number,
+                entry.data_file().clone(),
+            );
+            match entry.content_type() {
+                DataContentType::Data => data_files.push(file),
+                DataContentType::PositionDeletes => delete_files.push(file),
+                DataContentType::EqualityDeletes => {
+                    return Err(ErrorCode::Unimplemented(format!(
+                        "equality delete file {} of iceberg table {} is not supported",
+                        entry.file_path(),
+                        table.identifier()
+                    )));
+                }
+            }
+        }
+    }
+
+    Ok(data_files
+        .into_iter()
+        .map(|(spec_id, sequence_number, data_file)| {
+            let delete_files = delete_files
+                .iter()
+                .filter(|(delete_spec_id, delete_sequence_number, delete_file)| {
+                    *delete_spec_id == spec_id
+                        && *delete_sequence_number >= sequence_number
+                        && delete_file.partition() == data_file.partition()
+                        && references(delete_file, data_file.file_path())
+                })
+                .map(|(_, _, delete_file)| delete_file.file_path().to_string())
+                .collect();
+            IcebergScanFile {
+                task: FileScanTask {
+                    start: 0,
+                    length: data_file.file_size_in_bytes(),
+                    record_count: Some(data_file.record_count()),
+                    data_file_path: data_file.file_path().to_string(),
+                    data_file_content: DataContentType::Data,
+                    data_file_format: data_file.file_format(),
+                    schema: schema.clone(),
+                    project_field_ids: project_field_ids.clone(),
+                    predicate: predicate.clone(),
+                },
+                partition: data_file.partition().clone(),
+                spec_id,
+                delete_files,
+            }
+        })
+        .collect())
+}
+
+/// Returns false if the `file_path` bounds of the position delete file exclude the data file.
+fn references(delete_file: &DataFile, data_file_path: &str) -> bool {
+    let path = Datum::string(data_file_path);
+    match (
+        delete_file.lower_bounds().get(&DELETE_FILE_PATH_FIELD_ID),
+        delete_file.upper_bounds().get(&DELETE_FILE_PATH_FIELD_ID),
+    ) {
+        (Some(lower), Some(upper)) => *lower <= path && path <= *upper,
+        _ => true,
+    }
+}
+
+/// Load the positions of the rows of the data file deleted by the position delete files.
+pub async fn load_deleted_positions(
+    table: &iceberg::table::Table,
+    data_file_path: &str,
+    delete_files: &[String],
+) -> Result<HashSet<i64>> {
+    let mut positions = HashSet::new();
+    for delete_file in delete_files {
+        let bytes = table
+            .file_io()
+            .new_input(delete_file)
+            .map_err(scan_error)?
+            .read()
+            .await
+            .map_err(scan_error)?;
+        let reader = ParquetRecordBatchReaderBuilder::try_new(bytes)?.build()?;
+        for batch in reader {
+            let batch = batch?;
+            let (Some(file_paths), Some(pos)) = (
+                batch
+                    .column_by_name("file_path")
+                    .and_then(|c| c.as_string_opt::<i32>()),
+                batch
+                    .column_by_name("pos")
+                    .and_then(|c| c.as_primitive_opt::<Int64Type>()),
+            ) else {
+                return Err(ErrorCode::StorageOther(format!(
+                    "Iceberg position delete file {delete_file} is invalid"
+                )));
+            };
+            for (file_path, pos) in file_paths.iter().zip(pos.iter()) {
+                if let (Some(file_path), Some(pos)) = (file_path, pos) {
+                    if file_path == data_file_path {
+                        positions.insert(pos);
+                    }
+                }
+            }
+        }
+    }
+    Ok(positions)
+}
+
+fn scan_error(e: impl Debug) -> ErrorCode {
+    ErrorCode::ReadTableDataError(format!("Iceberg table scan failed: {e:?}"))
+}
@@ -0,0 +1,22 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::TableSchema;
+use databend_storages_common_table_meta::table::iceberg_schema_json;
+
+/// Convert [`TableSchema`] into iceberg [`iceberg::spec::Schema`].
+pub fn to_iceberg_schema(schema: &TableSchema) -> Result<iceberg::spec::Schema> {
+    Ok(serde_json::from_value(iceberg_schema_json(schema)?)?)
+}
@@ -40,14 +40,24 @@ use databend_common_expression::TableSchema;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
+use databend_common_meta_app::schema::UpdateStreamMetaReq;
+use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
+use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sinks::AsyncSinker;
+use databend_common_pipeline_transforms::processors::AsyncAccumulatingTransformer;
+use databend_storages_common_table_meta::meta::SnapshotId;
 use databend_storages_common_table_meta::table::ChangeType;
-use futures::TryStreamExt;
 use iceberg::io::FileIOBuilder;
 
+use crate::append::IcebergCommitSink;
+use crate::append::IcebergDataWriter;
+use crate::append::IcebergOperation;
 use crate::partition::IcebergPartInfo;
 use crate::predicate::PredicateBuilder;
+use crate::scan::plan_files;
 use crate::table_source::IcebergTableSource;
+use crate::table_source::RuntimeFilteredFiles;
 use crate::IcebergCatalog;
 
 pub const ICEBERG_ENGINE: &str = "ICEBERG";
@@ -92,13 +102,17 @@ impl IcebergTable {
     }
 
     pub fn get_schema(table: &iceberg::table::Table) -> Result<TableSchema> {
-        let meta = table.metadata();
+        let arrow_schema = Self::get_arrow_schema(table)?;
+        TableSchema::try_from(&arrow_schema)
+    }
 
-        // Build arrow schema from iceberg metadata.
-        let arrow_schema: ArrowSchema = meta.current_schema().as_ref().try_into().map_err(|e| {
+    /// Build arrow schema from iceberg metadata, the iceberg field ids are kept in the
+    /// metadata of arrow fields.
+    pub fn get_arrow_schema(table: &iceberg::table::Table) -> Result<ArrowSchema> {
+        let meta = table.metadata();
+        meta.current_schema().as_ref().try_into().map_err(|e| {
             ErrorCode::ReadTableDataError(format!("Cannot convert table metadata: {e:?}"))
-        })?;
-        TableSchema::try_from(&arrow_schema)
+        })
     }
 
     /// build_engine_options will generate `engine_options` from [`iceberg::table::Table`] so that
@@ -224,10 +238,25 @@ impl IcebergTable {
         let max_threads = ctx.get_settings().get_max_threads()? as usize;
         let max_threads = std::cmp::min(parts_len, max_threads);
 
+        let predicate = plan
+            .push_downs
+            .as_ref()
+            .and_then(|p| p.filters.as_ref())
+            .map(|filter| PredicateBuilder::default().build(&filter.filter));
+
+        let runtime_filtered_files = RuntimeFilteredFiles::default();
         let output_schema = Arc::new(DataSchema::from(plan.schema()));
         pipeline.add_source(
             |output| {
-                IcebergTableSource::create(ctx.clone(), output, output_schema.clone(), self.clone())
+                IcebergTableSource::create(
+                    ctx.clone(),
+                    plan,
+                    output,
+                    output_schema.clone(),
+                    self.clone(),
+                    predicate.clone(),
+                    runtime_filtered_files.clone(),
+                )
             },
             max_threads.max(1),
         )
@@ -240,43 +269,34 @@ impl IcebergTable {
         _: Arc<dyn TableContext>,
         push_downs: Option<PushDownInfo>,
     ) -> Result<(PartStatistics, Partitions)> {
-        let mut scan = self.table.scan();
-
+        let mut projection = None;
+        let mut predicate = None;
         if let Some(push_downs) = &push_downs {
-            if let Some(projection) = &push_downs.projection {
-                scan = scan.select(
-                    projection
-                        .project_schema(&self.schema())
+            if let Some(p) = &push_downs.projection {
+                projection = Some(
+                    p.project_schema(&self.schema())
                         .fields
                         .iter()
-                        .map(|v| v.name.clone()),
+                        .map(|v| v.name.clone())
+                        .collect(),
                 );
             }
             if let Some(filter) = &push_downs.filters {
-                let predicate = PredicateBuilder::default().build(&filter.filter);
-                scan = scan.with_filter(predicate)
+                predicate = Some(PredicateBuilder::default().build(&filter.filter));
             }
         }
 
-        let tasks: Vec<_> = scan
-            .build()
-            .map_err(|err| ErrorCode::Internal(format!("iceberg table scan build: {err:?}")))?
-            .plan_files()
-            .await
-            .map_err(|err| ErrorCode::Internal(format!("iceberg table scan plan: {err:?}")))?
-            .try_collect()
-            .await
-            .map_err(|err| ErrorCode::Internal(format!("iceberg table scan collect: {err:?}")))?;
+        let tasks = plan_files(&self.table, predicate, projection).await?;
 
         let mut read_rows = 0;
         let mut read_bytes = 0;
         let total_files = tasks.len();
         let parts: Vec<_> = tasks
             .into_iter()
-            .map(|v: iceberg::scan::FileScanTask| {
+            .map(|(v, delete_files)| {
                 read_rows += v.record_count.unwrap_or_default() as usize;
                 read_bytes += v.length as usize;
-                Arc::new(Box::new(IcebergPartInfo::new(v)) as Box<dyn PartInfo>)
+                Arc::new(Box::new(IcebergPartInfo::new(v, delete_files)) as Box<dyn PartInfo>)
             })
             .collect();
 
@@ -336,6 +356,37 @@ impl Table for IcebergTable {
         self.do_read_data(ctx, plan, pipeline)
     }
 
+    fn append_data(&self, _ctx: Arc<dyn TableContext>, pipeline: &mut Pipeline) -> Result<()> {
+        pipeline.add_transform(|input, output| {
+            let writer = IcebergDataWriter::try_create(self.clone())?;
+            Ok(ProcessorPtr::create(AsyncAccumulatingTransformer::create(
+                input, output, writer,
+            )))
+        })
+    }
+
+    fn commit_insertion(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        _copied_files: Option<UpsertTableCopiedFileReq>,
+        _update_stream_meta: Vec<UpdateStreamMetaReq>,
+        overwrite: bool,
+        _prev_snapshot_id: Option<SnapshotId>,
+        _deduplicated_label: Option<String>,
+    ) -> Result<()> {
+        pipeline.try_resize(1)?;
+        pipeline.add_sink(|input| {
+            Ok(ProcessorPtr::create(AsyncSinker::create(
+                input,
+                IcebergCommitSink::create(ctx.clone(), self.clone(), match overwrite {
+                    true => IcebergOperation::Overwrite,
+                    false => IcebergOperation::Append,
+                }),
+            )))
+        })
+    }
+
     fn table_args(&self) -> Option<TableArgs> {
         None
     }
@@ -347,4 +398,8 @@ impl Table for IcebergTable {
     fn support_prewhere(&self) -> bool {
         false
     }
+
+    fn support_runtime_filter_partition_pruning(&self) -> bool {
+        true
+    }
 }
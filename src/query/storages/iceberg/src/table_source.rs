@@ -13,12 +13,20 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 
+use arrow::compute::filter_record_batch;
+use arrow_array::BooleanArray;
+use arrow_array::RecordBatch;
+use databend_common_base::base::tokio::sync::OnceCell;
 use databend_common_base::base::Progress;
 use databend_common_base::base::ProgressValues;
 use databend_common_base::runtime::profile::Profile;
 use databend_common_base::runtime::profile::ProfileStatisticsName;
+use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::runtime_filter_info::RuntimeFilterReady;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -32,11 +40,20 @@ use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_storages_parquet::transform_record_batch;
 use futures::stream;
 use futures::StreamExt;
+use iceberg::expr::BoundPredicate;
+use iceberg::expr::Predicate;
 use iceberg::scan::ArrowRecordBatchStream;
 
 use crate::partition::IcebergPartInfo;
+use crate::predicate::PredicateBuilder;
+use crate::scan::load_deleted_positions;
+use crate::scan::plan_files;
 use crate::IcebergTable;
 
+/// The data files left by the runtime filters of the join, with their predicates.
+/// `None` if there are no runtime filters for the scan.
+pub type RuntimeFilteredFiles = Arc<OnceCell<Option<HashMap<String, Option<BoundPredicate>>>>>;
+
 pub struct IcebergTableSource {
     // Source processor related fields.
     table: IcebergTable,
@@ -48,30 +65,109 @@ pub struct IcebergTableSource {
     generated_data: Option<DataBlock>,
     is_finished: bool,
 
+    // Used for runtime filters of the scan.
+    table_index: usize,
+    runtime_filter_ready: Vec<Arc<RuntimeFilterReady>>,
+    // The pushed down predicate, runtime filters are appended to it.
+    predicate: Option<Predicate>,
+    // Shared by the sources of the scan, the files are planned once.
+    runtime_filtered_files: RuntimeFilteredFiles,
+
     // Used to read parquet.
     output_schema: DataSchemaRef,
     stream: Option<ArrowRecordBatchStream>,
+    // The rows of the file being read deleted by the position delete files.
+    deleted_rows: Option<DeletedRows>,
+}
+
+struct DeletedRows {
+    positions: HashSet<i64>,
+    // The position of the next row to read.
+    offset: i64,
+}
+
+impl DeletedRows {
+    fn filter(&mut self, batch: RecordBatch) -> Result<RecordBatch> {
+        let num_rows = batch.num_rows() as i64;
+        let keep = (self.offset..self.offset + num_rows)
+            .map(|pos| Some(!self.positions.contains(&pos)))
+            .collect::<BooleanArray>();
+        self.offset += num_rows;
+        Ok(filter_record_batch(&batch, &keep)?)
+    }
 }
 
 impl IcebergTableSource {
     pub fn create(
         ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
         output: Arc<OutputPort>,
         output_schema: DataSchemaRef,
         table: IcebergTable,
+        predicate: Option<Predicate>,
+        runtime_filtered_files: RuntimeFilteredFiles,
     ) -> Result<ProcessorPtr> {
         let scan_progress = ctx.get_scan_progress();
+        let runtime_filter_ready = ctx.get_runtime_filter_ready(plan.scan_id);
         Ok(ProcessorPtr::create(Box::new(IcebergTableSource {
             table,
             output,
             scan_progress,
+            table_index: plan.table_index,
+            runtime_filter_ready,
             ctx,
+            predicate,
+            runtime_filtered_files,
             output_schema,
             stream: None,
+            deleted_rows: None,
             generated_data: None,
             is_finished: false,
         })))
     }
+
+    // Dynamic partition elimination, the files are planned again with the runtime filters
+    // of the join, the partitions and the files out of the key domain of the build side are
+    // eliminated by the partition summaries and the column bounds in the manifests.
+    async fn plan_runtime_filtered_files(
+        &self,
+    ) -> Result<Option<HashMap<String, Option<BoundPredicate>>>> {
+        for ready in self.runtime_filter_ready.iter() {
+            ready.wait().await?;
+        }
+        let mut filters = self.ctx.get_inlist_runtime_filter_with_id(self.table_index);
+        filters.extend(
+            self.ctx
+                .get_min_max_runtime_filter_with_id(self.table_index),
+        );
+
+        let mut predicate = None;
+        for filter in filters.iter() {
+            let p = PredicateBuilder::default().build(&filter.as_remote_expr());
+            if matches!(p, Predicate::AlwaysTrue) {
+                continue;
+            }
+            predicate = Some(match predicate {
+                Some(predicate) => p.and(predicate),
+                None => p,
+            });
+        }
+        let Some(predicate) = predicate else {
+            return Ok(None);
+        };
+        let predicate = match &self.predicate {
+            Some(pushdown) => pushdown.clone().and(predicate),
+            None => predicate,
+        };
+
+        let tasks = plan_files(&self.table.table, Some(predicate), None).await?;
+        Ok(Some(
+            tasks
+                .into_iter()
+                .map(|(task, _)| (task.data_file_path, task.predicate))
+                .collect(),
+        ))
+    }
 }
 
 #[async_trait::async_trait]
@@ -124,6 +220,10 @@ impl Processor for IcebergTableSource {
                     ErrorCode::Internal(format!("iceberg data stream read: {err:?}"))
                 })?
             {
+                let batch = match &mut self.deleted_rows {
+                    Some(deleted_rows) => deleted_rows.filter(batch)?,
+                    None => batch,
+                };
                 let block = transform_record_batch(&self.output_schema, &batch, &None)?;
                 let block = check_block_schema(&self.output_schema, block)?;
 
@@ -135,16 +235,51 @@ impl Processor for IcebergTableSource {
             // And we should try to build another stream (in next event loop).
         } else if let Some(part) = self.ctx.get_partition() {
             let part = IcebergPartInfo::from_part(&part)?;
+            let mut task = part.to_task();
+            let runtime_filtered_files = self.runtime_filtered_files.clone();
+            let files = runtime_filtered_files
+                .get_or_try_init(|| self.plan_runtime_filtered_files())
+                .await?;
+            if let Some(files) = files {
+                match files.get(&task.data_file_path) {
+                    // The runtime filters are also used to skip the row groups of the file.
+                    Some(predicate) => task.predicate = predicate.clone(),
+                    None => {
+                        Profile::record_usize_profile(
+                            ProfileStatisticsName::RuntimeFilterPruneParts,
+                            1,
+                        );
+                        return Ok(());
+                    }
+                }
+            }
+            // The deleted rows are located by their positions in the file, so the file is read
+            // as a whole, the pushed down filters are evaluated after the scan anyway.
+            self.deleted_rows = None;
+            let has_deletes = !part.delete_files().is_empty();
+            if has_deletes {
+                let positions = load_deleted_positions(
+                    &self.table.table,
+                    &task.data_file_path,
+                    part.delete_files(),
+                )
+                .await?;
+                task.predicate = None;
+                self.deleted_rows = Some(DeletedRows {
+                    positions,
+                    offset: 0,
+                });
+            }
             let reader = self
                 .table
                 .table
                 .reader_builder()
                 .with_batch_size(self.ctx.get_settings().get_parquet_max_block_size()? as usize)
-                .with_row_group_filtering_enabled(true)
+                .with_row_group_filtering_enabled(!has_deletes)
                 .build();
             // TODO: don't use stream here.
             let stream = reader
-                .read(Box::pin(stream::iter([Ok(part.to_task())])))
+                .read(Box::pin(stream::iter([Ok(task)])))
                 .await
                 .map_err(|err| ErrorCode::Internal(format!("iceberg data stream read: {err:?}")))?;
             self.stream = Some(stream);
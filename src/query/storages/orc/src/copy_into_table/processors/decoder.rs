@@ -243,6 +243,7 @@ impl Processor for StripeDecoderForCopy {
                     copy_status.add_chunk(&stripe.path, FileStatus {
                         num_rows_loaded: block.num_rows(),
                         error: None,
+                        rejected_rows: vec![],
                     })
                 }
                 log::info!(
@@ -52,6 +52,7 @@ pub async fn read_partitions_simple(
             let part = SingleFilePartition {
                 path: v.path.clone(),
                 size: v.size as usize,
+                archive_members: vec![],
             };
             let part_info: Box<dyn PartInfo> = Box::new(part);
             Arc::new(part_info)
@@ -40,6 +40,7 @@ use crate::ReadSettings;
 pub struct RowGroupReaderForCopy {
     row_group_reader_builder: Box<dyn ReadPolicyBuilder>,
     output_projection: Vec<Expr>,
+    geo_column_srids: Vec<(usize, i32)>,
 }
 
 impl RowGroupReaderForCopy {
@@ -68,6 +69,10 @@ impl RowGroupReaderForCopy {
         &self.output_projection
     }
 
+    pub fn geo_column_srids(&self) -> &[(usize, i32)] {
+        &self.geo_column_srids
+    }
+
     pub fn try_create(
         location: &str,
         ctx: Arc<dyn TableContext>,
@@ -127,6 +132,7 @@ impl RowGroupReaderForCopy {
         let reader = RowGroupReaderForCopy {
             row_group_reader_builder,
             output_projection,
+            geo_column_srids: reader_builder.output_geo_column_srids()?,
         };
         Ok(reader)
     }
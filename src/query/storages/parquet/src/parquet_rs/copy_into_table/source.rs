@@ -35,6 +35,7 @@ use databend_common_pipeline_core::processors::ProcessorPtr;
 use opendal::Operator;
 
 use crate::parquet_rs::copy_into_table::reader::RowGroupReaderForCopy;
+use crate::parquet_rs::geo::set_geo_column_srids;
 use crate::parquet_rs::parquet_reader::policy::ReadPolicyImpl;
 use crate::ParquetPart;
 use crate::ReadSettings;
@@ -144,11 +145,9 @@ impl Processor for ParquetCopySource {
         match std::mem::replace(&mut self.state, State::Init) {
             State::ReadRowGroup((schema_index, mut reader)) => {
                 if let Some(block) = reader.as_mut().read_block()? {
-                    let projection = self
-                        .row_group_readers
-                        .get(&schema_index)
-                        .unwrap()
-                        .output_projection();
+                    let reader_for_copy = self.row_group_readers.get(&schema_index).unwrap();
+                    let block = set_geo_column_srids(block, reader_for_copy.geo_column_srids())?;
+                    let projection = reader_for_copy.output_projection();
                     let evaluator = Evaluator::new(&block, &self.func_ctx, &BUILTIN_FUNCTIONS);
                     let mut columns = Vec::with_capacity(projection.len());
                     for (field, expr) in self.schema.fields().iter().zip(projection.iter()) {
@@ -0,0 +1,90 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use arrow_schema::Schema as ArrowSchema;
+use databend_common_exception::Result;
+use databend_common_expression::types::binary::BinaryColumnBuilder;
+use databend_common_expression::types::NullableColumn;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableSchema;
+use databend_common_expression::Value;
+use databend_common_io::ewkb_set_srid;
+use databend_common_storage::geo_parquet::GeoParquetMetadata;
+
+/// The WKB values of GeoParquet files do not contain the SRID, which is kept in the CRS of the column.
+///
+/// Return the offsets of geometry columns in `output_schema` whose values should be set with a SRID,
+/// the default SRID 4326 is omitted since it is what `st_srid` returns for values without a SRID.
+pub(crate) fn geo_column_srids(
+    arrow_schema: Option<&ArrowSchema>,
+    output_schema: &TableSchema,
+) -> Result<Vec<(usize, i32)>> {
+    let Some(geo) = arrow_schema
+        .map(GeoParquetMetadata::from_arrow_schema)
+        .transpose()?
+        .flatten()
+    else {
+        return Ok(vec![]);
+    };
+    Ok(output_schema
+        .fields()
+        .iter()
+        .enumerate()
+        .filter(|(_, f)| f.data_type().remove_nullable() == TableDataType::Geometry)
+        .filter_map(|(i, f)| {
+            let srid = geo.column(f.name())?.srid()?;
+            (srid != 4326).then_some((i, srid))
+        })
+        .collect())
+}
+
+/// Set the SRID of the geometry columns returned by [`geo_column_srids`].
+pub(crate) fn set_geo_column_srids(
+    mut block: DataBlock,
+    srids: &[(usize, i32)],
+) -> Result<DataBlock> {
+    if srids.is_empty() {
+        return Ok(block);
+    }
+    let num_rows = block.num_rows();
+    for (offset, srid) in srids {
+        let entry = block.get_by_offset(*offset);
+        let column = entry
+            .value
+            .convert_to_full_column(&entry.data_type, num_rows);
+        let validity = column.validity().1.cloned();
+        let Column::Geometry(geometries) = column.remove_nullable() else {
+            continue;
+        };
+
+        let mut builder = BinaryColumnBuilder::with_capacity(num_rows, geometries.data().len());
+        for (i, value) in geometries.iter().enumerate() {
+            if validity.as_ref().map_or(true, |v| v.get_bit(i)) {
+                builder.put_slice(&ewkb_set_srid(value, Some(*srid))?);
+            }
+            builder.commit_row();
+        }
+        let column = Column::Geometry(builder.build());
+        let column = match validity {
+            Some(validity) => NullableColumn::new_column(column, validity),
+            None => column,
+        };
+        let data_type = entry.data_type.clone();
+        block.columns_mut()[*offset] = BlockEntry::new(data_type, Value::Column(column));
+    }
+    Ok(block)
+}
@@ -20,6 +20,7 @@ mod pruning;
 mod source;
 mod statistics;
 
+mod geo;
 mod meta;
 mod schema;
 
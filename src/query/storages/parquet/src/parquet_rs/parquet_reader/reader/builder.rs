@@ -28,6 +28,7 @@ use parquet::arrow::ProjectionMask;
 use parquet::schema::types::SchemaDescPtr;
 
 use super::ParquetRSRowGroupReader;
+use crate::parquet_rs::geo::geo_column_srids;
 use crate::parquet_rs::parquet_reader::policy::default_policy_builders;
 use crate::parquet_rs::parquet_reader::policy::ReadPolicyBuilder;
 use crate::parquet_rs::parquet_reader::policy::POLICY_NO_PREFETCH;
@@ -209,6 +210,12 @@ impl<'a> ParquetRSReaderBuilder<'a> {
         Ok(())
     }
 
+    /// Offsets and SRIDs of the output geometry columns read from GeoParquet files.
+    pub(crate) fn output_geo_column_srids(&self) -> Result<Vec<(usize, i32)>> {
+        let (_, _, output_schema, _) = self.built_output.as_ref().unwrap();
+        geo_column_srids(self.arrow_schema.as_ref(), output_schema)
+    }
+
     pub fn build_full_reader(&mut self) -> Result<ParquetRSFullReader> {
         let batch_size = self.ctx.get_settings().get_parquet_max_block_size()? as usize;
 
@@ -281,6 +288,7 @@ impl<'a> ParquetRSReaderBuilder<'a> {
             batch_size,
             policy_builders,
             default_policy,
+            geo_column_srids: self.output_geo_column_srids()?,
         })
     }
 
@@ -35,6 +35,8 @@ use parquet::arrow::arrow_reader::ArrowPredicateFn;
 use parquet::arrow::arrow_reader::ArrowReaderOptions;
 use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
 use parquet::arrow::arrow_reader::RowFilter;
+use parquet::arrow::arrow_reader::RowSelection;
+use parquet::arrow::arrow_reader::RowSelector;
 use parquet::arrow::async_reader::AsyncFileReader;
 use parquet::arrow::async_reader::MetadataLoader;
 use parquet::arrow::async_reader::ParquetRecordBatchStream;
@@ -77,12 +79,13 @@ pub struct ParquetRSFullReader {
 }
 
 impl ParquetRSFullReader {
-    // partition_fields is only used for delta table engine.
+    // partition_fields and deleted_rows are only used for delta table engine.
     pub async fn prepare_data_stream(
         &self,
         loc: &str,
         size: u64,
         partition_fields: Option<&[(TableField, Scalar)]>,
+        deleted_rows: Option<&[u64]>,
     ) -> Result<ParquetRecordBatchStream<ParquetFileReader>> {
         let partition_values_map = partition_fields.map(|arr| {
             arr.iter()
@@ -102,30 +105,42 @@ impl ParquetRSFullReader {
         let mut all_pruned = false;
 
         let file_meta = builder.metadata().clone();
+        let mut selected_row_groups = (0..file_meta.num_row_groups()).collect::<Vec<_>>();
+        let mut row_selection = None;
 
         // Prune row groups.
         if let Some(pruner) = &self.pruner {
-            let (selected_row_groups, omits) =
+            let (row_groups, omits) =
                 pruner.prune_row_groups(&file_meta, None, partition_values_map.as_ref())?;
+            selected_row_groups = row_groups;
             all_pruned = omits.iter().all(|x| *x);
             builder = builder.with_row_groups(selected_row_groups.clone());
 
             if !all_pruned {
-                let row_selection = pruner.prune_pages(
+                row_selection = pruner.prune_pages(
                     &file_meta,
                     &selected_row_groups,
                     partition_values_map.as_ref(),
                 )?;
-
-                if let Some(row_selection) = row_selection {
-                    builder = builder.with_row_selection(row_selection);
-                }
             } else {
                 metrics_inc_omit_filter_rowgroups(file_meta.num_row_groups() as u64);
                 metrics_inc_omit_filter_rows(file_meta.file_metadata().num_rows() as u64);
             }
         }
 
+        // Skip the deleted rows.
+        if let Some(deleted_rows) = deleted_rows {
+            let deletes = deleted_row_selection(&file_meta, &selected_row_groups, deleted_rows);
+            row_selection = Some(match row_selection {
+                Some(selection) => selection.intersection(&deletes),
+                None => deletes,
+            });
+        }
+
+        if let Some(row_selection) = row_selection {
+            builder = builder.with_row_selection(row_selection);
+        }
+
         if !all_pruned {
             if let Some(predicate) = self.predicate.as_ref() {
                 let projection = predicate.projection().clone();
@@ -249,6 +264,42 @@ impl ParquetRSFullReader {
     }
 }
 
+/// Build the [`RowSelection`] of `row_groups` which skips `deleted_rows`.
+///
+/// `deleted_rows` are the sorted row indexes in the whole file.
+fn deleted_row_selection(
+    meta: &ParquetMetaData,
+    row_groups: &[usize],
+    deleted_rows: &[u64],
+) -> RowSelection {
+    let mut first_rows = Vec::with_capacity(meta.num_row_groups());
+    let mut num_rows = 0;
+    for row_group in meta.row_groups() {
+        first_rows.push(num_rows);
+        num_rows += row_group.num_rows() as u64;
+    }
+
+    let mut selectors = vec![];
+    for &i in row_groups {
+        let start = first_rows[i];
+        let end = start + meta.row_group(i).num_rows() as u64;
+        let lo = deleted_rows.partition_point(|row| *row < start);
+        let hi = deleted_rows.partition_point(|row| *row < end);
+        let mut pos = start;
+        for &row in &deleted_rows[lo..hi] {
+            if row > pos {
+                selectors.push(RowSelector::select((row - pos) as usize));
+            }
+            selectors.push(RowSelector::skip(1));
+            pos = row + 1;
+        }
+        if end > pos {
+            selectors.push(RowSelector::select((end - pos) as usize));
+        }
+    }
+    RowSelection::from(selectors)
+}
+
 /// ParquetFileReader is a wrapper around a Reader that impls parquet AsyncFileReader.
 ///
 /// # TODO
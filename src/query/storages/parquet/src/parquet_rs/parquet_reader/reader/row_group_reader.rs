@@ -38,6 +38,9 @@ pub struct ParquetRSRowGroupReader {
 
     // Options
     pub(super) batch_size: usize,
+
+    /// Geometry columns of GeoParquet files to set SRID, see [`geo_column_srids`](crate::parquet_rs::geo::geo_column_srids).
+    pub(super) geo_column_srids: Vec<(usize, i32)>,
 }
 
 impl ParquetRSRowGroupReader {
@@ -45,6 +48,10 @@ impl ParquetRSRowGroupReader {
         self.op.clone()
     }
 
+    pub fn geo_column_srids(&self) -> &[(usize, i32)] {
+        &self.geo_column_srids
+    }
+
     /// Read a row group and return a reader with certain policy.
     /// If return [None], it means the whole row group is skipped (by eval push down predicate).
     pub async fn create_read_policy(
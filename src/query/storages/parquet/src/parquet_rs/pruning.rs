@@ -18,20 +18,27 @@ use std::sync::Arc;
 use databend_common_catalog::plan::ParquetReadOptions;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_exception::Result;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::Expr;
 use databend_common_expression::FunctionContext;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchemaRef;
 use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_io::bounding_box_intersects;
+use databend_common_storage::geo_parquet::GeoParquetMetadata;
+use databend_storages_common_index::collect_geo_bbox_filters;
 use databend_storages_common_pruner::RangePruner;
 use databend_storages_common_pruner::RangePrunerCreator;
 use databend_storages_common_table_meta::meta::StatisticsOfColumns;
 use parquet::arrow::arrow_reader::RowSelection;
 use parquet::arrow::arrow_reader::RowSelector;
 use parquet::file::metadata::ParquetMetaData;
+use parquet::file::metadata::RowGroupMetaData;
 use parquet::format::PageLocation;
 
 use super::statistics::collect_row_group_stats;
+use super::statistics::collect_single_row_group_stats;
 use crate::parquet_rs::statistics::convert_index_to_column_statistics;
 
 /// A pruner to prune row groups and pages of a parquet files.
@@ -49,6 +56,11 @@ pub struct ParquetRSPruner {
 
     /// Leaf ids of columns contained in filter predicates.
     predicate_columns: Vec<usize>,
+
+    /// Geometry columns and the bounding boxes `[xmin, ymin, xmax, ymax]` they must intersect,
+    /// from predicates like `st_intersects(geom, <constant>)`.
+    /// They are checked against the bbox covering columns of GeoParquet files.
+    geo_bbox_filters: Vec<(String, [f64; 4])>,
 }
 
 impl ParquetRSPruner {
@@ -64,6 +76,7 @@ impl ParquetRSPruner {
         let filter = push_down.as_ref().and_then(|p| p.filters.as_ref());
 
         let mut predicate_columns = vec![];
+        let mut geo_bbox_filters = vec![];
         let range_pruner =
             if filter.is_some() && (options.prune_row_groups() || options.prune_pages()) {
                 let filter_expr = filter.as_ref().unwrap().filter.as_expr(&BUILTIN_FUNCTIONS);
@@ -89,6 +102,7 @@ impl ParquetRSPruner {
                     })
                     .collect::<Vec<_>>();
                 predicate_columns.sort();
+                collect_geo_bbox_filters(&filter_expr, &mut geo_bbox_filters);
                 let pruner =
                     RangePrunerCreator::try_create(func_ctx.clone(), &schema, Some(&filter_expr))?;
                 let inverted_pruner =
@@ -104,6 +118,7 @@ impl ParquetRSPruner {
             prune_row_groups: options.prune_row_groups(),
             prune_pages: options.prune_pages(),
             predicate_columns,
+            geo_bbox_filters,
         })
     }
 
@@ -119,6 +134,19 @@ impl ParquetRSPruner {
         meta: &ParquetMetaData,
         stats: Option<&[StatisticsOfColumns]>,
         partition_values: Option<&HashMap<String, Scalar>>,
+    ) -> Result<(Vec<usize>, Vec<bool>)> {
+        let (selection, omits) = self.prune_row_groups_by_range(meta, stats, partition_values)?;
+        if !self.prune_row_groups || self.geo_bbox_filters.is_empty() {
+            return Ok((selection, omits));
+        }
+        self.prune_row_groups_by_geo_bbox(meta, selection, omits)
+    }
+
+    fn prune_row_groups_by_range(
+        &self,
+        meta: &ParquetMetaData,
+        stats: Option<&[StatisticsOfColumns]>,
+        partition_values: Option<&HashMap<String, Scalar>>,
     ) -> Result<(Vec<usize>, Vec<bool>)> {
         let default_selection = (0..meta.num_row_groups()).collect();
         let default_omits = vec![false; meta.num_row_groups()];
@@ -165,6 +193,64 @@ impl ParquetRSPruner {
         }
     }
 
+    /// Prune row groups whose bbox covering columns show that no geometry intersects the bounding boxes of predicates.
+    fn prune_row_groups_by_geo_bbox(
+        &self,
+        meta: &ParquetMetaData,
+        selection: Vec<usize>,
+        omits: Vec<bool>,
+    ) -> Result<(Vec<usize>, Vec<bool>)> {
+        let Some(geo) =
+            GeoParquetMetadata::from_key_value_metadata(meta.file_metadata().key_value_metadata())?
+        else {
+            return Ok((selection, omits));
+        };
+
+        // The leaf ids of [xmin, ymin, xmax, ymax] covering columns and the bounding box to intersect.
+        let mut filters = Vec::with_capacity(self.geo_bbox_filters.len());
+        for (column, bbox) in self.geo_bbox_filters.iter() {
+            let Some(covering) = geo.column(column).and_then(|c| c.covering.as_ref()) else {
+                continue;
+            };
+            let covering = &covering.bbox;
+            let leaves = [
+                &covering.xmin,
+                &covering.ymin,
+                &covering.xmax,
+                &covering.ymax,
+            ]
+            .map(|path| {
+                let name = path.join(":");
+                self.leaf_fields
+                    .iter()
+                    .position(|f| f.name.eq_ignore_ascii_case(&name))
+            });
+            if let [Some(xmin), Some(ymin), Some(xmax), Some(ymax)] = leaves {
+                filters.push(([xmin, ymin, xmax, ymax], *bbox));
+            }
+        }
+        if filters.is_empty() {
+            return Ok((selection, omits));
+        }
+
+        let mut new_selection = Vec::with_capacity(selection.len());
+        let mut new_omits = Vec::with_capacity(omits.len());
+        for (rg_idx, omit) in selection.into_iter().zip(omits) {
+            let rg = meta.row_group(rg_idx);
+            let keep = filters.iter().all(|(leaves, bbox)| {
+                match row_group_bbox(rg, &self.leaf_fields, leaves) {
+                    Some(rg_bbox) => bounding_box_intersects(&rg_bbox, bbox),
+                    None => true,
+                }
+            });
+            if keep {
+                new_selection.push(rg_idx);
+                new_omits.push(omit);
+            }
+        }
+        Ok((new_selection, new_omits))
+    }
+
     /// Prune pages of a parquet file.
     ///
     /// Return a vector of [`RowSelection`] to represent rows to read.
@@ -263,3 +349,26 @@ fn merge_row_selectors(row_selections: Vec<Vec<RowSelector>>) -> Vec<RowSelector
         .unwrap()
         .into()
 }
+
+/// The bounding box of all the geometries in the row group, from the statistics of the covering columns.
+fn row_group_bbox(
+    rg: &RowGroupMetaData,
+    leaf_fields: &[TableField],
+    leaves: &[usize; 4],
+) -> Option<[f64; 4]> {
+    if leaves.iter().any(|i| rg.column(*i).statistics().is_none()) {
+        return None;
+    }
+    let stats = collect_single_row_group_stats(rg, leaf_fields, Some(leaves))?;
+    let min = |i: usize| scalar_to_f64(&stats.get(&(leaves[i] as u32))?.min);
+    let max = |i: usize| scalar_to_f64(&stats.get(&(leaves[i] as u32))?.max);
+    Some([min(0)?, min(1)?, max(2)?, max(3)?])
+}
+
+fn scalar_to_f64(scalar: &Scalar) -> Option<f64> {
+    match scalar {
+        Scalar::Number(NumberScalar::Float64(v)) => Some(v.0),
+        Scalar::Number(NumberScalar::Float32(v)) => Some(v.0 as f64),
+        _ => None,
+    }
+}
@@ -33,6 +33,7 @@ use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_storage::CopyStatus;
 use databend_common_storage::FileStatus;
 
+use super::geo::set_geo_column_srids;
 use super::parquet_reader::policy::ReadPolicyImpl;
 use crate::ParquetPart;
 use crate::ParquetRSFullReader;
@@ -152,6 +153,8 @@ impl Processor for ParquetSource {
         match std::mem::replace(&mut self.state, State::Init) {
             State::ReadRowGroup(mut reader) => {
                 if let Some(block) = reader.as_mut().read_block()? {
+                    let block =
+                        set_geo_column_srids(block, self.row_group_reader.geo_column_srids())?;
                     self.generated_data = Some(block);
                     self.state = State::ReadRowGroup(reader);
                 }
@@ -171,6 +174,7 @@ impl Processor for ParquetSource {
                         self.copy_status.add_chunk(path.as_str(), FileStatus {
                             num_rows_loaded: num_rows,
                             error: None,
+                            rejected_rows: vec![],
                         });
                         blocks.extend(bs);
                     }
@@ -186,7 +190,10 @@ impl Processor for ParquetSource {
                 }
 
                 if !blocks.is_empty() {
-                    self.generated_data = Some(DataBlock::concat(&blocks)?);
+                    let block = DataBlock::concat(&blocks)?;
+                    let block =
+                        set_geo_column_srids(block, self.row_group_reader.geo_column_srids())?;
+                    self.generated_data = Some(block);
                 }
                 // Else: no output data is generated.
             }
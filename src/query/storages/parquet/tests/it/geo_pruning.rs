@@ -0,0 +1,255 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use arrow_array::ArrayRef;
+use arrow_array::BinaryArray;
+use arrow_array::Float64Array;
+use arrow_array::Int32Array;
+use arrow_array::RecordBatch;
+use arrow_array::StructArray;
+use arrow_schema::DataType as ArrowDataType;
+use arrow_schema::Field;
+use arrow_schema::Fields;
+use arrow_schema::Schema;
+use bytes::Bytes;
+use databend_common_catalog::plan::Filters;
+use databend_common_catalog::plan::ParquetReadOptions;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::RawExpr;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_io::geometry::geometry_from_str;
+use databend_common_storage::geo_parquet::GeoParquetBboxCovering;
+use databend_common_storage::geo_parquet::GeoParquetColumn;
+use databend_common_storage::geo_parquet::GeoParquetCovering;
+use databend_common_storage::geo_parquet::GeoParquetMetadata;
+use databend_common_storage::geo_parquet::GEO_PARQUET_ENCODING_WKB;
+use databend_common_storage::geo_parquet::GEO_PARQUET_VERSION;
+use databend_common_storages_parquet::ParquetRSPruner;
+use parquet::arrow::ArrowWriter;
+use parquet::file::metadata::ParquetMetaData;
+use parquet::file::properties::WriterProperties;
+use parquet::file::reader::FileReader;
+use parquet::file::reader::SerializedFileReader;
+
+/// Points of the rows, two rows in each row group.
+const POINTS: [(f64, f64); 6] = [
+    (0.0, 0.0),
+    (1.0, 1.0),
+    (10.0, 10.0),
+    (11.0, 11.0),
+    (20.0, 20.0),
+    (21.0, 21.0),
+];
+
+fn wkb_point(x: f64, y: f64) -> Vec<u8> {
+    let mut wkb = vec![1u8];
+    wkb.extend_from_slice(&1u32.to_le_bytes());
+    wkb.extend_from_slice(&x.to_le_bytes());
+    wkb.extend_from_slice(&y.to_le_bytes());
+    wkb
+}
+
+/// A GeoParquet file with a bbox covering column and 3 row groups.
+fn geo_parquet_file(with_covering: bool) -> ParquetMetaData {
+    let bbox_fields = Fields::from(
+        ["xmin", "ymin", "xmax", "ymax"]
+            .map(|name| Field::new(name, ArrowDataType::Float64, false))
+            .to_vec(),
+    );
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("id", ArrowDataType::Int32, false),
+        Field::new("geom", ArrowDataType::Binary, true),
+        Field::new("bbox", ArrowDataType::Struct(bbox_fields.clone()), false),
+    ]));
+    let xs = Arc::new(Float64Array::from_iter_values(POINTS.iter().map(|p| p.0))) as ArrayRef;
+    let ys = Arc::new(Float64Array::from_iter_values(POINTS.iter().map(|p| p.1))) as ArrayRef;
+    let wkbs = POINTS
+        .iter()
+        .map(|(x, y)| wkb_point(*x, *y))
+        .collect::<Vec<_>>();
+    let batch = RecordBatch::try_new(schema.clone(), vec![
+        Arc::new(Int32Array::from_iter_values(0..POINTS.len() as i32)),
+        Arc::new(BinaryArray::from_iter_values(wkbs.iter())),
+        Arc::new(StructArray::new(
+            bbox_fields,
+            vec![xs.clone(), ys.clone(), xs, ys],
+            None,
+        )),
+    ])
+    .unwrap();
+
+    let path = |name: &str| vec!["bbox".to_string(), name.to_string()];
+    let covering = with_covering.then(|| GeoParquetCovering {
+        bbox: GeoParquetBboxCovering {
+            xmin: path("xmin"),
+            ymin: path("ymin"),
+            xmax: path("xmax"),
+            ymax: path("ymax"),
+        },
+    });
+    let geo = GeoParquetMetadata {
+        version: GEO_PARQUET_VERSION.to_string(),
+        primary_column: "geom".to_string(),
+        columns: BTreeMap::from([("geom".to_string(), GeoParquetColumn {
+            encoding: GEO_PARQUET_ENCODING_WKB.to_string(),
+            geometry_types: vec!["Point".to_string()],
+            covering,
+            ..Default::default()
+        })]),
+    };
+
+    let props = WriterProperties::builder()
+        .set_max_row_group_size(2)
+        .set_key_value_metadata(Some(vec![geo.to_key_value().unwrap()]))
+        .build();
+    let mut buf = vec![];
+    let mut writer = ArrowWriter::try_new(&mut buf, schema, Some(props)).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+
+    let reader = SerializedFileReader::new(Bytes::from(buf)).unwrap();
+    let meta = reader.metadata().clone();
+    assert_eq!(meta.num_row_groups(), 3);
+    meta
+}
+
+fn table_schema() -> TableSchemaRef {
+    let float64 = TableDataType::Number(NumberDataType::Float64);
+    TableSchemaRefExt::create(vec![
+        TableField::new("id", TableDataType::Number(NumberDataType::Int32)),
+        TableField::new(
+            "geom",
+            TableDataType::Nullable(Box::new(TableDataType::Geometry)),
+        ),
+        TableField::new("bbox", TableDataType::Tuple {
+            fields_name: ["xmin", "ymin", "xmax", "ymax"].map(String::from).to_vec(),
+            fields_type: vec![float64; 4],
+        }),
+    ])
+}
+
+fn geom_column() -> RawExpr<String> {
+    RawExpr::ColumnRef {
+        span: None,
+        id: "geom".to_string(),
+        data_type: DataType::Nullable(Box::new(DataType::Geometry)),
+        display_name: "geom".to_string(),
+    }
+}
+
+fn geometry(wkt: &str) -> RawExpr<String> {
+    RawExpr::Constant {
+        span: None,
+        scalar: Scalar::Geometry(geometry_from_str(wkt, None).unwrap()),
+    }
+}
+
+fn function(name: &str, args: Vec<RawExpr<String>>) -> RawExpr<String> {
+    RawExpr::FunctionCall {
+        span: None,
+        name: name.to_string(),
+        params: vec![],
+        args,
+    }
+}
+
+fn prune(
+    meta: &ParquetMetaData,
+    filter: RawExpr<String>,
+    options: ParquetReadOptions,
+) -> Result<Vec<usize>> {
+    let schema = table_schema();
+    let leaf_fields = Arc::new(schema.leaf_fields());
+    let inverted_filter = function("not", vec![filter.clone()]);
+    let push_down = Some(PushDownInfo {
+        filters: Some(Filters {
+            filter: check(&filter, &BUILTIN_FUNCTIONS)?.as_remote_expr(),
+            inverted_filter: check(&inverted_filter, &BUILTIN_FUNCTIONS)?.as_remote_expr(),
+        }),
+        ..Default::default()
+    });
+    let pruner = ParquetRSPruner::try_create(
+        FunctionContext::default(),
+        schema,
+        leaf_fields,
+        &push_down,
+        options,
+        vec![],
+    )?;
+    let (selection, _) = pruner.prune_row_groups(meta, None, None)?;
+    Ok(selection)
+}
+
+#[test]
+fn test_prune_row_groups_by_geo_bbox() -> Result<()> {
+    let meta = geo_parquet_file(true);
+    let options = ParquetReadOptions::default();
+
+    let intersects = function("st_intersects", vec![
+        geom_column(),
+        geometry("POINT(10.5 10.5)"),
+    ]);
+    assert_eq!(prune(&meta, intersects.clone(), options)?, vec![1]);
+
+    let intersects = function("st_intersects", vec![
+        geom_column(),
+        geometry("POLYGON((-1 -1, 10 -1, 10 10, -1 10, -1 -1))"),
+    ]);
+    assert_eq!(prune(&meta, intersects, options)?, vec![0, 1]);
+
+    let outside = function("st_intersects", vec![
+        geometry("POINT(100 100)"),
+        geom_column(),
+    ]);
+    assert_eq!(prune(&meta, outside, options)?, Vec::<usize>::new());
+
+    // `st_distance(geom, POINT(25 25)) < 5` only needs the last row group
+    let distance = function("lt", vec![
+        function("st_distance", vec![geom_column(), geometry("POINT(25 25)")]),
+        RawExpr::Constant {
+            span: None,
+            scalar: Scalar::Number(NumberScalar::Float64(5.0.into())),
+        },
+    ]);
+    assert_eq!(prune(&meta, distance, options)?, vec![2]);
+
+    // nothing is pruned if pruning row groups is disabled
+    let point = function("st_intersects", vec![
+        geom_column(),
+        geometry("POINT(10.5 10.5)"),
+    ]);
+    assert_eq!(
+        prune(&meta, point.clone(), options.with_prune_row_groups(false))?,
+        vec![0, 1, 2]
+    );
+
+    // or if the file has no bbox covering columns
+    let meta = geo_parquet_file(false);
+    assert_eq!(prune(&meta, point, options)?, vec![0, 1, 2]);
+    Ok(())
+}
@@ -0,0 +1,443 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unload to Avro object container files.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use apache_avro::types::Value;
+use apache_avro::Codec;
+use apache_avro::Schema;
+use apache_avro::Writer;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::decimal::DecimalScalar;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::types::VectorDataType;
+use databend_common_expression::types::VectorScalarRef;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableSchema;
+use databend_common_meta_app::principal::StageFileCompression;
+use serde_json::json;
+
+use crate::append::file_encoder::FileEncoder;
+
+/// Encode blocks to Avro object container files with [`apache_avro::Writer`].
+///
+/// The writer borrows the schema, so the blocks are kept until the file is finished.
+pub struct AvroFileEncoder {
+    field_names: Vec<String>,
+    field_types: Vec<TableDataType>,
+    schema: Schema,
+    codec: Codec,
+
+    blocks: Vec<DataBlock>,
+    buffered_size: usize,
+}
+
+impl AvroFileEncoder {
+    pub fn try_create(schema: &TableSchema, compression: StageFileCompression) -> Result<Self> {
+        let fields = schema
+            .fields()
+            .iter()
+            .map(|f| avro_field(f.name(), f.data_type(), "row"))
+            .collect::<Result<Vec<_>>>()?;
+        let schema_json = json!({
+            "type": "record",
+            "name": "row",
+            "fields": fields,
+        });
+        let avro_schema = Schema::parse(&schema_json).map_err(avro_error)?;
+        Ok(Self {
+            field_names: schema
+                .fields()
+                .iter()
+                .map(|f| avro_name(f.name()))
+                .collect(),
+            field_types: schema
+                .fields()
+                .iter()
+                .map(|f| f.data_type().clone())
+                .collect(),
+            schema: avro_schema,
+            codec: avro_codec(compression)?,
+            blocks: vec![],
+            buffered_size: 0,
+        })
+    }
+}
+
+impl FileEncoder for AvroFileEncoder {
+    fn write(&mut self, block: &DataBlock) -> Result<()> {
+        if block.num_rows() > 0 {
+            self.buffered_size += block.memory_size();
+            self.blocks.push(block.clone());
+        }
+        Ok(())
+    }
+
+    fn file_size(&self) -> usize {
+        self.buffered_size
+    }
+
+    fn finish(&mut self) -> Result<Vec<u8>> {
+        let mut writer = Writer::with_codec(&self.schema, Vec::new(), self.codec);
+        for block in std::mem::take(&mut self.blocks) {
+            let num_rows = block.num_rows();
+            let columns = block
+                .columns()
+                .iter()
+                .map(|c| c.to_column(num_rows))
+                .collect::<Vec<_>>();
+            for row in 0..num_rows {
+                let fields = self
+                    .field_names
+                    .iter()
+                    .zip(self.field_types.iter())
+                    .zip(columns.iter())
+                    .map(|((name, ty), column)| {
+                        Ok((name.clone(), to_avro_value(ty, column.index(row).unwrap())?))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                writer.append(Value::Record(fields)).map_err(avro_error)?;
+            }
+        }
+        self.buffered_size = 0;
+        writer.into_inner().map_err(avro_error)
+    }
+}
+
+fn avro_codec(compression: StageFileCompression) -> Result<Codec> {
+    let name = match compression {
+        StageFileCompression::Auto | StageFileCompression::None => "null",
+        StageFileCompression::Deflate | StageFileCompression::RawDeflate => "deflate",
+        StageFileCompression::Snappy => "snappy",
+        StageFileCompression::Zstd => "zstandard",
+        StageFileCompression::Bz2 => "bzip2",
+        StageFileCompression::Xz => "xz",
+        other => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "compression {other} is not supported by AVRO"
+            )));
+        }
+    };
+    Codec::from_str(name).map_err(|e| ErrorCode::Internal(format!("invalid avro codec: {e}")))
+}
+
+fn avro_error(e: apache_avro::Error) -> ErrorCode {
+    ErrorCode::Internal(format!("fail to write avro file: {e}"))
+}
+
+/// Names of avro records and fields must match `[A-Za-z_][A-Za-z0-9_]*`.
+fn avro_name(name: &str) -> String {
+    let mut s = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+    if !s.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+        s.insert(0, '_');
+    }
+    s
+}
+
+fn avro_field(name: &str, ty: &TableDataType, parent: &str) -> Result<serde_json::Value> {
+    let name = avro_name(name);
+    // record names must be unique in a schema
+    let record_name = format!("{parent}_{name}");
+    let field = if ty.is_nullable() {
+        json!({"name": name, "type": avro_type(ty, &record_name)?, "default": null})
+    } else {
+        json!({"name": name, "type": avro_type(ty, &record_name)?})
+    };
+    Ok(field)
+}
+
+fn avro_type(ty: &TableDataType, record_name: &str) -> Result<serde_json::Value> {
+    let decimal = |precision: u8, scale: u8| json!({"type": "bytes", "logicalType": "decimal", "precision": precision, "scale": scale});
+    let t = match ty {
+        TableDataType::Nullable(inner) => json!(["null", avro_type(inner, record_name)?]),
+        TableDataType::Boolean => json!("boolean"),
+        TableDataType::Number(n) => match n {
+            NumberDataType::Int8
+            | NumberDataType::Int16
+            | NumberDataType::Int32
+            | NumberDataType::UInt8
+            | NumberDataType::UInt16 => json!("int"),
+            NumberDataType::Int64 | NumberDataType::UInt32 => json!("long"),
+            // Avro has no unsigned types, UInt64 does not fit in long.
+            NumberDataType::UInt64 => decimal(20, 0),
+            NumberDataType::Float32 => json!("float"),
+            NumberDataType::Float64 => json!("double"),
+        },
+        TableDataType::Decimal(d) => decimal(d.precision(), d.scale()),
+        TableDataType::String | TableDataType::Variant => json!("string"),
+        TableDataType::Binary
+        | TableDataType::Bitmap
+        | TableDataType::Geometry
+        | TableDataType::Geography => json!("bytes"),
+        TableDataType::Date => json!({"type": "int", "logicalType": "date"}),
+        TableDataType::Timestamp => json!({"type": "long", "logicalType": "timestamp-micros"}),
+        TableDataType::Array(inner) => {
+            json!({"type": "array", "items": avro_type(inner, record_name)?})
+        }
+        TableDataType::Vector(VectorDataType::Int8(_)) => json!({"type": "array", "items": "int"}),
+        TableDataType::Vector(VectorDataType::Float32(_)) => {
+            json!({"type": "array", "items": "float"})
+        }
+        TableDataType::Map(inner) => match inner.as_ref() {
+            TableDataType::Tuple { fields_type, .. } if fields_type.len() == 2 => {
+                if fields_type[0] == TableDataType::String {
+                    json!({"type": "map", "values": avro_type(&fields_type[1], record_name)?})
+                } else {
+                    // keys of avro maps must be strings, other maps are written as arrays of entries
+                    json!({"type": "array", "items": {
+                        "type": "record",
+                        "name": record_name,
+                        "fields": [
+                            avro_field("key", &fields_type[0], record_name)?,
+                            avro_field("value", &fields_type[1], record_name)?,
+                        ],
+                    }})
+                }
+            }
+            _ => unreachable!("inner type of map must be a tuple of key and value"),
+        },
+        TableDataType::Tuple {
+            fields_name,
+            fields_type,
+        } => {
+            let fields = fields_name
+                .iter()
+                .zip(fields_type)
+                .map(|(name, ty)| avro_field(name, ty, record_name))
+                .collect::<Result<Vec<_>>>()?;
+            json!({"type": "record", "name": record_name, "fields": fields})
+        }
+        TableDataType::Null
+        | TableDataType::EmptyArray
+        | TableDataType::EmptyMap
+        | TableDataType::Interval => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "unload {} to AVRO is not supported",
+                ty
+            )));
+        }
+    };
+    Ok(t)
+}
+
+fn to_avro_value(ty: &TableDataType, value: ScalarRef) -> Result<Value> {
+    let v = match (ty, value) {
+        // nullable types are unions of null and the inner type
+        (TableDataType::Nullable(_), ScalarRef::Null) => Value::Union(0, Box::new(Value::Null)),
+        (TableDataType::Nullable(inner), value) => {
+            Value::Union(1, Box::new(to_avro_value(inner, value)?))
+        }
+        (_, ScalarRef::Boolean(v)) => Value::Boolean(v),
+        (_, ScalarRef::Number(n)) => match n {
+            NumberScalar::Int8(v) => Value::Int(v as i32),
+            NumberScalar::Int16(v) => Value::Int(v as i32),
+            NumberScalar::Int32(v) => Value::Int(v),
+            NumberScalar::Int64(v) => Value::Long(v),
+            NumberScalar::UInt8(v) => Value::Int(v as i32),
+            NumberScalar::UInt16(v) => Value::Int(v as i32),
+            NumberScalar::UInt32(v) => Value::Long(v as i64),
+            NumberScalar::UInt64(v) => decimal(&(v as i128).to_be_bytes()),
+            NumberScalar::Float32(v) => Value::Float(v.0),
+            NumberScalar::Float64(v) => Value::Double(v.0),
+        },
+        (_, ScalarRef::Decimal(d)) => match d {
+            DecimalScalar::Decimal128(v, _) => decimal(&v.to_be_bytes()),
+            DecimalScalar::Decimal256(v, _) => decimal(&v.to_be_bytes()),
+        },
+        (_, ScalarRef::String(v)) => Value::String(v.to_string()),
+        (_, ScalarRef::Variant(v)) => Value::String(jsonb::to_string(v)),
+        (_, ScalarRef::Binary(v) | ScalarRef::Bitmap(v) | ScalarRef::Geometry(v)) => {
+            Value::Bytes(v.to_vec())
+        }
+        (_, ScalarRef::Geography(v)) => Value::Bytes(v.0.to_vec()),
+        (_, ScalarRef::Date(v)) => Value::Date(v),
+        (_, ScalarRef::Timestamp(v)) => Value::TimestampMicros(v),
+        (TableDataType::Array(inner), ScalarRef::Array(values)) => Value::Array(
+            values
+                .iter()
+                .map(|v| to_avro_value(inner, v))
+                .collect::<Result<_>>()?,
+        ),
+        (TableDataType::Vector(_), ScalarRef::Vector(v)) => Value::Array(match v {
+            VectorScalarRef::Int8(values) => values.iter().map(|v| Value::Int(*v as i32)).collect(),
+            VectorScalarRef::Float32(values) => values.iter().map(|v| Value::Float(v.0)).collect(),
+        }),
+        (TableDataType::Map(inner), ScalarRef::Map(Column::Tuple(kv))) => {
+            let TableDataType::Tuple { fields_type, .. } = inner.as_ref() else {
+                unreachable!("inner type of map must be a tuple of key and value");
+            };
+            let entries = (0..kv[0].len()).map(|i| {
+                Ok((
+                    to_avro_value(&fields_type[0], kv[0].index(i).unwrap())?,
+                    to_avro_value(&fields_type[1], kv[1].index(i).unwrap())?,
+                ))
+            });
+            if fields_type[0] == TableDataType::String {
+                Value::Map(
+                    entries
+                        .map(|e| match e? {
+                            (Value::String(key), value) => Ok((key, value)),
+                            _ => unreachable!("keys of the map are strings"),
+                        })
+                        .collect::<Result<HashMap<_, _>>>()?,
+                )
+            } else {
+                Value::Array(
+                    entries
+                        .map(|e| {
+                            let (key, value) = e?;
+                            Ok(Value::Record(vec![
+                                ("key".to_string(), key),
+                                ("value".to_string(), value),
+                            ]))
+                        })
+                        .collect::<Result<_>>()?,
+                )
+            }
+        }
+        (
+            TableDataType::Tuple {
+                fields_name,
+                fields_type,
+            },
+            ScalarRef::Tuple(values),
+        ) => Value::Record(
+            fields_name
+                .iter()
+                .zip(fields_type)
+                .zip(values)
+                .map(|((name, ty), v)| Ok((avro_name(name), to_avro_value(ty, v)?)))
+                .collect::<Result<_>>()?,
+        ),
+        (_, ScalarRef::EmptyArray) => Value::Array(vec![]),
+        (TableDataType::Map(inner), ScalarRef::EmptyMap) => match inner.as_ref() {
+            TableDataType::Tuple { fields_type, .. } if fields_type[0] != TableDataType::String => {
+                Value::Array(vec![])
+            }
+            _ => Value::Map(HashMap::new()),
+        },
+        (_, ScalarRef::EmptyMap) => Value::Map(HashMap::new()),
+        (ty, value) => {
+            return Err(ErrorCode::Internal(format!(
+                "unexpected value {:?} for AVRO type {}",
+                value, ty
+            )));
+        }
+    };
+    Ok(v)
+}
+
+/// Decimals are two's-complement big-endian integers, the redundant leading sign bytes are trimmed.
+fn decimal(be_bytes: &[u8]) -> Value {
+    let mut start = 0;
+    while start + 1 < be_bytes.len() {
+        let (b, next) = (be_bytes[start], be_bytes[start + 1]);
+        if (b == 0 && next & 0x80 == 0) || (b == 0xff && next & 0x80 != 0) {
+            start += 1;
+        } else {
+            break;
+        }
+    }
+    Value::Decimal(be_bytes[start..].to_vec().into())
+}
+
+#[cfg(test)]
+mod tests {
+    use apache_avro::types::Value;
+    use apache_avro::Reader;
+    use databend_common_expression::types::decimal::DecimalColumn;
+    use databend_common_expression::types::decimal::DecimalSize;
+    use databend_common_expression::types::DecimalDataType;
+    use databend_common_expression::types::Int32Type;
+    use databend_common_expression::types::StringType;
+    use databend_common_expression::FromData;
+    use databend_common_expression::TableField;
+
+    use super::*;
+
+    #[test]
+    fn test_avro_file_encoder() -> Result<()> {
+        let schema = TableSchema::new(vec![
+            TableField::new("id", TableDataType::Number(NumberDataType::Int32)),
+            TableField::new(
+                "name",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new(
+                "price",
+                TableDataType::Decimal(DecimalDataType::Decimal128(DecimalSize {
+                    precision: 10,
+                    scale: 2,
+                })),
+            ),
+        ]);
+        let block = DataBlock::new_from_columns(vec![
+            Int32Type::from_data(vec![1, 2]),
+            StringType::from_opt_data(vec![Some("a"), None]),
+            Column::Decimal(DecimalColumn::Decimal128(
+                vec![12345i128, -1].into(),
+                DecimalSize {
+                    precision: 10,
+                    scale: 2,
+                },
+            )),
+        ]);
+
+        for compression in [
+            StageFileCompression::None,
+            StageFileCompression::Snappy,
+            StageFileCompression::Zstd,
+        ] {
+            let mut encoder = AvroFileEncoder::try_create(&schema, compression)?;
+            encoder.write(&block)?;
+            encoder.write(&block)?;
+            let data = encoder.finish()?;
+
+            let reader = Reader::new(data.as_slice()).unwrap();
+            let rows = reader.map(|r| r.unwrap()).collect::<Vec<_>>();
+            assert_eq!(rows.len(), 4);
+            let Value::Record(fields) = &rows[1] else {
+                unreachable!()
+            };
+            assert_eq!(fields[0], ("id".to_string(), Value::Int(2)));
+            assert_eq!(
+                fields[1],
+                ("name".to_string(), Value::Union(0, Box::new(Value::Null)))
+            );
+            assert_eq!(
+                fields[2],
+                ("price".to_string(), Value::Decimal(vec![0xffu8].into()))
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_avro_name() {
+        assert_eq!(avro_name("a"), "a");
+        assert_eq!(avro_name("1"), "_1");
+        assert_eq!(avro_name("a b-c"), "a_b_c");
+    }
+}
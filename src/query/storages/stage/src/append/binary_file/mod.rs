@@ -0,0 +1,48 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unload to binary formats whose files can not be concatenated from blocks
+//! serialized separately, each writer encodes whole files.
+
+mod avro;
+mod orc;
+
+use databend_common_catalog::plan::StageTableInfo;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::FileFormatParams;
+
+use self::avro::AvroFileEncoder;
+use self::orc::OrcFileEncoder;
+use crate::append::file_encoder::FileEncoder;
+
+pub(crate) fn create_binary_file_encoder(
+    table_info: &StageTableInfo,
+) -> Result<Box<dyn FileEncoder>> {
+    let schema = table_info.schema();
+    match &table_info.stage_info.file_format_params {
+        FileFormatParams::Orc(params) => Ok(Box::new(OrcFileEncoder::try_create(
+            &schema,
+            params.compression,
+        )?)),
+        FileFormatParams::Avro(params) => Ok(Box::new(AvroFileEncoder::try_create(
+            &schema,
+            params.compression,
+        )?)),
+        other => Err(ErrorCode::Internal(format!(
+            "unexpected file format {} for binary file writer",
+            other
+        ))),
+    }
+}
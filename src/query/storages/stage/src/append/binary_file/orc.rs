@@ -0,0 +1,186 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Unload to ORC files.
+
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use arrow_cast::cast;
+use arrow_schema::DataType as ArrowDataType;
+use arrow_schema::Field;
+use arrow_schema::Schema;
+use arrow_schema::SchemaRef;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableSchema;
+use databend_common_meta_app::principal::StageFileCompression;
+use orc_rust::error::OrcError;
+use orc_rust::ArrowWriterBuilder;
+
+use crate::append::file_encoder::FileEncoder;
+
+/// Encode blocks to ORC files with the arrow writer of `orc-rust`.
+///
+/// The writer owns its output until it is closed, so the record batches are kept until
+/// the file is finished.
+pub struct OrcFileEncoder {
+    schema: TableSchema,
+    orc_schema: SchemaRef,
+
+    batches: Vec<RecordBatch>,
+    buffered_size: usize,
+}
+
+impl OrcFileEncoder {
+    pub fn try_create(schema: &TableSchema, compression: StageFileCompression) -> Result<Self> {
+        if !matches!(
+            compression,
+            StageFileCompression::Auto | StageFileCompression::None
+        ) {
+            return Err(ErrorCode::Unimplemented(format!(
+                "compression {compression} is not supported when unloading ORC files"
+            )));
+        }
+        let fields = schema
+            .fields()
+            .iter()
+            .map(|f| {
+                let Some(ty) = orc_data_type(f.data_type().remove_nullable()) else {
+                    return Err(ErrorCode::Unimplemented(format!(
+                        "unload {} to ORC is not supported",
+                        f.data_type()
+                    )));
+                };
+                Ok(Field::new(f.name(), ty, f.is_nullable()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            schema: schema.clone(),
+            orc_schema: Arc::new(Schema::new(fields)),
+            batches: vec![],
+            buffered_size: 0,
+        })
+    }
+}
+
+impl FileEncoder for OrcFileEncoder {
+    fn write(&mut self, block: &DataBlock) -> Result<()> {
+        if block.num_rows() == 0 {
+            return Ok(());
+        }
+        let batch = block.clone().to_record_batch(&self.schema)?;
+        let columns = batch
+            .columns()
+            .iter()
+            .zip(self.orc_schema.fields())
+            .map(|(column, field)| cast(column, field.data_type()))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let batch = RecordBatch::try_new(self.orc_schema.clone(), columns)?;
+        self.buffered_size += batch.get_array_memory_size();
+        self.batches.push(batch);
+        Ok(())
+    }
+
+    fn file_size(&self) -> usize {
+        self.buffered_size
+    }
+
+    fn finish(&mut self) -> Result<Vec<u8>> {
+        let mut buf = vec![];
+        let mut writer = ArrowWriterBuilder::new(&mut buf, self.orc_schema.clone())
+            .try_build()
+            .map_err(orc_error)?;
+        for batch in std::mem::take(&mut self.batches) {
+            writer.write(&batch).map_err(orc_error)?;
+        }
+        writer.close().map_err(orc_error)?;
+        self.buffered_size = 0;
+        Ok(buf)
+    }
+}
+
+/// The arrow type written by the ORC writer, unsigned integers are widened to the signed ones.
+fn orc_data_type(ty: &TableDataType) -> Option<ArrowDataType> {
+    let ty = match ty {
+        TableDataType::Boolean => ArrowDataType::Boolean,
+        TableDataType::Number(n) => match n {
+            NumberDataType::Int8 => ArrowDataType::Int8,
+            NumberDataType::Int16 | NumberDataType::UInt8 => ArrowDataType::Int16,
+            NumberDataType::Int32 | NumberDataType::UInt16 => ArrowDataType::Int32,
+            NumberDataType::Int64 | NumberDataType::UInt32 => ArrowDataType::Int64,
+            NumberDataType::Float32 => ArrowDataType::Float32,
+            NumberDataType::Float64 => ArrowDataType::Float64,
+            // ORC has no unsigned types, UInt64 does not fit in long.
+            NumberDataType::UInt64 => return None,
+        },
+        TableDataType::String => ArrowDataType::Utf8,
+        TableDataType::Binary => ArrowDataType::Binary,
+        _ => return None,
+    };
+    Some(ty)
+}
+
+fn orc_error(e: OrcError) -> ErrorCode {
+    ErrorCode::Internal(format!("fail to write orc file: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use databend_common_expression::types::Int32Type;
+    use databend_common_expression::types::StringType;
+    use databend_common_expression::types::UInt8Type;
+    use databend_common_expression::FromData;
+    use databend_common_expression::TableField;
+    use orc_rust::ArrowReaderBuilder;
+
+    use super::*;
+
+    #[test]
+    fn test_orc_file_encoder() -> Result<()> {
+        let schema = TableSchema::new(vec![
+            TableField::new("id", TableDataType::Number(NumberDataType::Int32)),
+            TableField::new(
+                "name",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new("flag", TableDataType::Number(NumberDataType::UInt8)),
+        ]);
+        let block = DataBlock::new_from_columns(vec![
+            Int32Type::from_data(vec![1, 2]),
+            StringType::from_opt_data(vec![Some("a"), None]),
+            UInt8Type::from_data(vec![255, 0]),
+        ]);
+
+        let mut encoder = OrcFileEncoder::try_create(&schema, StageFileCompression::None)?;
+        encoder.write(&block)?;
+        encoder.write(&block)?;
+        let data = encoder.finish()?;
+
+        let reader = ArrowReaderBuilder::try_new(Bytes::from(data))
+            .unwrap()
+            .build();
+        let batches = reader.collect::<std::result::Result<Vec<_>, _>>().unwrap();
+        assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 4);
+
+        assert!(OrcFileEncoder::try_create(&schema, StageFileCompression::Zstd).is_err());
+        let schema = TableSchema::new(vec![TableField::new("d", TableDataType::Date)]);
+        assert!(OrcFileEncoder::try_create(&schema, StageFileCompression::None).is_err());
+        Ok(())
+    }
+}
@@ -16,11 +16,14 @@ use std::sync::atomic::AtomicUsize;
 use std::sync::Arc;
 
 use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_meta_app::principal::FileFormatParams;
+use databend_common_meta_app::principal::StageFileCompression;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
 
-use super::parquet_file::append_data_to_parquet_files;
+use super::file_writer::append_data_to_files;
+use super::partitioned_file::append_data_to_partitioned_files;
 use super::row_based_file::append_data_to_row_based_files;
 use crate::append::output::SumSummaryTransform;
 use crate::StageTable;
@@ -34,15 +37,28 @@ impl StageTable {
         let settings = ctx.get_settings();
 
         let fmt = self.table_info.stage_info.file_format_params.clone();
+        if let compression @ (StageFileCompression::Zip | StageFileCompression::Tar) =
+            fmt.compression()
+        {
+            return Err(ErrorCode::Unimplemented(format!(
+                "compression {compression} is not supported for unloading"
+            )));
+        }
         let mem_limit = settings.get_max_memory_usage()? as usize;
         let max_threads = settings.get_max_threads()? as usize;
 
         let op = StageTable::get_op(&self.table_info.stage_info)?;
         let query_id = ctx.get_id();
         let group_id = AtomicUsize::new(0);
+        let partitioned = !self
+            .table_info
+            .copy_into_location_options
+            .partition_keys
+            .is_empty();
         match fmt {
-            FileFormatParams::Parquet(_) => append_data_to_parquet_files(
+            _ if partitioned => append_data_to_partitioned_files(
                 pipeline,
+                ctx.clone(),
                 self.table_info.clone(),
                 op,
                 query_id,
@@ -50,6 +66,18 @@ impl StageTable {
                 mem_limit,
                 max_threads,
             )?,
+            FileFormatParams::Parquet(_) | FileFormatParams::Orc(_) | FileFormatParams::Avro(_) => {
+                append_data_to_files(
+                    pipeline,
+                    ctx.clone(),
+                    self.table_info.clone(),
+                    op,
+                    query_id,
+                    &group_id,
+                    mem_limit,
+                    max_threads,
+                )?
+            }
             _ => append_data_to_row_based_files(
                 pipeline,
                 ctx.clone(),
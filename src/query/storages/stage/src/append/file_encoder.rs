@@ -0,0 +1,54 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::StageTableInfo;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_meta_app::principal::FileFormatParams;
+
+use super::binary_file::create_binary_file_encoder;
+use super::parquet_file::ParquetFileEncoder;
+use super::row_based_file::RowBasedFileEncoder;
+
+/// Encode blocks to whole files, for writers which write many files at the same time.
+pub(crate) trait FileEncoder: Send {
+    fn write(&mut self, block: &DataBlock) -> Result<()>;
+
+    /// Size of the file encoded so far, including the buffered data not encoded yet.
+    fn file_size(&self) -> usize;
+
+    /// Finish the current file and return its content,
+    /// the encoder is ready to write the next file after that.
+    fn finish(&mut self) -> Result<Vec<u8>>;
+}
+
+pub(crate) fn create_file_encoder(
+    ctx: &Arc<dyn TableContext>,
+    table_info: &StageTableInfo,
+    target_file_size: Option<usize>,
+) -> Result<Box<dyn FileEncoder>> {
+    match &table_info.stage_info.file_format_params {
+        FileFormatParams::Parquet(_) => Ok(Box::new(ParquetFileEncoder::try_create(
+            table_info,
+            target_file_size,
+        )?)),
+        FileFormatParams::Orc(_) | FileFormatParams::Avro(_) => {
+            create_binary_file_encoder(table_info)
+        }
+        _ => Ok(Box::new(RowBasedFileEncoder::try_create(ctx, table_info)?)),
+    }
+}
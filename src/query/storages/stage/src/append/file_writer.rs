@@ -17,10 +17,9 @@ use std::collections::VecDeque;
 use std::mem;
 use std::sync::Arc;
 
-use arrow_schema::Schema;
 use async_trait::async_trait;
 use databend_common_catalog::plan::StageTableInfo;
-use databend_common_config::DATABEND_SEMVER;
+use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_expression::BlockMetaInfoDowncast;
 use databend_common_expression::DataBlock;
@@ -29,30 +28,80 @@ use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
-use databend_storages_common_table_meta::table::TableCompression;
+use databend_common_pipeline_core::Pipeline;
 use opendal::Operator;
-use parquet::arrow::ArrowWriter;
-use parquet::basic::Encoding;
-use parquet::file::properties::EnabledStatistics;
-use parquet::file::properties::WriterProperties;
 
-use super::block_batch::BlockBatch;
+use crate::append::file_encoder::create_file_encoder;
+use crate::append::file_encoder::FileEncoder;
 use crate::append::output::DataSummary;
+use crate::append::parquet_file::block_batch::BlockBatch;
+use crate::append::parquet_file::limit_file_size_processor::LimitFileSizeProcessor;
 use crate::append::path::unload_path;
 use crate::append::UnloadOutput;
 
-pub struct ParquetFileWriter {
+/// - LimitFileSizeProcessor * 1: slice/group block to batches (as a block meta) to avoid files being too small when there are many threads.
+/// - FileWriter * N: encode incoming blocks to whole files in memory, and flush when they are large enough.
+///
+/// For the formats whose files are encoded as a whole, i.e. Parquet, ORC and Avro.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn append_data_to_files(
+    pipeline: &mut Pipeline,
+    ctx: Arc<dyn TableContext>,
+    table_info: StageTableInfo,
+    op: Operator,
+    query_id: String,
+    group_id: &std::sync::atomic::AtomicUsize,
+    mem_limit: usize,
+    max_threads: usize,
+) -> Result<()> {
+    let is_single = table_info.copy_into_location_options.single;
+    let max_file_size = table_info.copy_into_location_options.max_file_size;
+    // when encoding blocks to files, the memory may be doubled
+    let mem_limit = mem_limit / 2;
+    pipeline.try_resize(1)?;
+    let max_file_size = if is_single {
+        None
+    } else {
+        let max_file_size = if max_file_size == 0 {
+            64 * 1024 * 1024
+        } else {
+            max_file_size.min(mem_limit)
+        };
+        pipeline.add_transform(|input, output| {
+            LimitFileSizeProcessor::try_create(input, output, max_file_size)
+        })?;
+
+        let max_threads = max_threads.min(mem_limit / max_file_size).max(1);
+        pipeline.try_resize(max_threads)?;
+        Some(max_file_size)
+    };
+    pipeline.add_transform(|input, output| {
+        let gid = group_id.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        FileWriter::try_create(
+            input,
+            output,
+            &ctx,
+            table_info.clone(),
+            op.clone(),
+            query_id.clone(),
+            gid,
+            max_file_size,
+        )
+    })?;
+    Ok(())
+}
+
+pub struct FileWriter {
     input: Arc<InputPort>,
     output: Arc<OutputPort>,
 
     table_info: StageTableInfo,
-    arrow_schema: Arc<Schema>,
+    encoder: Box<dyn FileEncoder>,
 
-    input_data: Vec<DataBlock>,
+    input_data: VecDeque<DataBlock>,
 
     input_bytes: usize,
     row_counts: usize,
-    writer: ArrowWriter<Vec<u8>>,
 
     file_to_write: Option<(Vec<u8>, DataSummary)>,
     data_accessor: Operator,
@@ -65,110 +114,62 @@ pub struct ParquetFileWriter {
     group_id: usize,
     batch_id: usize,
 
-    targe_file_size: Option<usize>,
+    target_file_size: Option<usize>,
 }
 
-const MAX_BUFFER_SIZE: usize = 64 * 1024 * 1024;
-// this is number of rows, not size
-const MAX_ROW_GROUP_SIZE: usize = 1024 * 1024;
-const CREATE_BY_LEN: usize = 24; // "Databend 1.2.333-nightly".len();
-
-fn create_writer(
-    arrow_schema: Arc<Schema>,
-    targe_file_size: Option<usize>,
-) -> Result<ArrowWriter<Vec<u8>>> {
-    // example:  1.2.333-nightly
-    // tags may contain other items like `1.2.680-p2`, we will fill it with `1.2.680-p2.....`
-    let mut create_by = format!(
-        "Databend {}.{}.{}-{:.<7}",
-        DATABEND_SEMVER.major,
-        DATABEND_SEMVER.minor,
-        DATABEND_SEMVER.patch,
-        DATABEND_SEMVER.pre.as_str()
-    );
-
-    if create_by.len() != CREATE_BY_LEN {
-        create_by = format!("{:.<24}", create_by);
-        create_by.truncate(24);
-    }
-
-    let props = WriterProperties::builder()
-        .set_compression(TableCompression::Zstd.into())
-        .set_max_row_group_size(MAX_ROW_GROUP_SIZE)
-        .set_encoding(Encoding::PLAIN)
-        .set_dictionary_enabled(false)
-        .set_statistics_enabled(EnabledStatistics::Chunk)
-        .set_bloom_filter_enabled(false)
-        .set_created_by(create_by)
-        .build();
-    let buf_size = match targe_file_size {
-        Some(n) if n < MAX_BUFFER_SIZE => n,
-        _ => MAX_BUFFER_SIZE,
-    };
-    let writer = ArrowWriter::try_new(Vec::with_capacity(buf_size), arrow_schema, Some(props))?;
-    Ok(writer)
-}
-
-impl ParquetFileWriter {
+impl FileWriter {
+    #[allow(clippy::too_many_arguments)]
     pub fn try_create(
         input: Arc<InputPort>,
         output: Arc<OutputPort>,
+        ctx: &Arc<dyn TableContext>,
         table_info: StageTableInfo,
         data_accessor: Operator,
         query_id: String,
         group_id: usize,
-        targe_file_size: Option<usize>,
+        target_file_size: Option<usize>,
     ) -> Result<ProcessorPtr> {
         let unload_output =
             UnloadOutput::create(table_info.copy_into_location_options.detailed_output);
+        let encoder = create_file_encoder(ctx, &table_info, target_file_size)?;
 
-        let arrow_schema = Arc::new(Schema::from(table_info.schema.as_ref()));
-        let writer = create_writer(arrow_schema.clone(), targe_file_size)?;
-
-        Ok(ProcessorPtr::create(Box::new(ParquetFileWriter {
+        Ok(ProcessorPtr::create(Box::new(FileWriter {
             input,
             output,
             table_info,
-            arrow_schema,
-            unload_output,
-            unload_output_blocks: None,
-            writer,
-            input_data: Vec::new(),
+            encoder,
+            input_data: VecDeque::new(),
             input_bytes: 0,
+            row_counts: 0,
             file_to_write: None,
             data_accessor,
+            unload_output,
+            unload_output_blocks: None,
             query_id,
             group_id,
             batch_id: 0,
-            targe_file_size,
-            row_counts: 0,
+            target_file_size,
         })))
     }
-    pub fn reinit_writer(&mut self) -> Result<()> {
-        self.writer = create_writer(self.arrow_schema.clone(), self.targe_file_size)?;
-        self.row_counts = 0;
-        self.input_bytes = 0;
-        Ok(())
-    }
 
     fn flush(&mut self) -> Result<()> {
-        _ = self.writer.finish();
-        let buf = mem::take(self.writer.inner_mut());
+        let buf = self.encoder.finish()?;
         let output_bytes = buf.len();
         self.file_to_write = Some((buf, DataSummary {
             row_counts: self.row_counts,
             input_bytes: self.input_bytes,
             output_bytes,
         }));
-        self.reinit_writer()?;
+        self.row_counts = 0;
+        self.input_bytes = 0;
         Ok(())
     }
 }
 
 #[async_trait]
-impl Processor for ParquetFileWriter {
+impl Processor for FileWriter {
     fn name(&self) -> String {
-        "ParquetFileWriter".to_string()
+        "FileWriter".to_string()
     }
 
     fn as_any(&mut self) -> &mut dyn Any {
@@ -209,12 +210,12 @@ impl Processor for ParquetFileWriter {
             }
         } else if self.input.has_data() {
             let block = self.input.pull_data().unwrap()?;
-            if self.targe_file_size.is_none() {
-                self.input_data.push(block);
+            if self.target_file_size.is_none() {
+                self.input_data.push_back(block);
             } else {
                 let block_meta = block.get_owned_meta().unwrap();
                 let blocks = BlockBatch::downcast_from(block_meta).unwrap();
-                self.input_data.extend_from_slice(&blocks.blocks);
+                self.input_data.extend(blocks.blocks);
             }
 
             self.input.set_not_need_data();
@@ -226,23 +227,16 @@ impl Processor for ParquetFileWriter {
     }
 
     fn process(&mut self) -> Result<()> {
-        while let Some(b) = self.input_data.pop() {
+        // keep the order of rows, which matters for `single` files of ordered queries
+        while let Some(b) = self.input_data.pop_front() {
             self.input_bytes += b.memory_size();
             self.row_counts += b.num_rows();
-            let batch = b.to_record_batch(&self.table_info.schema)?;
-            self.writer.write(&batch)?;
+            self.encoder.write(&b)?;
 
-            if let Some(target) = self.targe_file_size {
-                if self.row_counts > 0 {
-                    // written row groups: compressed, controlled by MAX_ROW_GROUP_SIZE
-                    let file_size = self.writer.bytes_written();
-                    // in_progress row group: each column leaf has an at most 1MB uncompressed buffer and multi compressed pages
-                    // may result in small file for schema with many columns
-                    let in_progress = self.writer.in_progress_size();
-                    if file_size + in_progress >= target {
-                        self.flush()?;
-                        return Ok(());
-                    }
+            if let Some(target) = self.target_file_size {
+                if self.row_counts > 0 && self.encoder.file_size() >= target {
+                    self.flush()?;
+                    return Ok(());
                 }
             }
         }
@@ -12,9 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod binary_file;
 mod do_append;
+mod file_encoder;
+mod file_writer;
 mod output;
 mod parquet_file;
+mod partitioned_file;
 mod path;
 mod row_based_file;
 
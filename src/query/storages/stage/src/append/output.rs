@@ -98,6 +98,8 @@ pub enum UnloadOutput {
 pub struct OutputFileInfo {
     file_name: String,
     summary: DataSummary,
+    // the `key=value/` directory of partitioned unload
+    partition: Option<String>,
 }
 
 impl UnloadOutput {
@@ -117,6 +119,14 @@ impl UnloadOutput {
     }
 
     pub fn add_file(&mut self, file_name: &str, summary: DataSummary) {
+        self.add_file_info(file_name, summary, None)
+    }
+
+    pub fn add_partition_file(&mut self, partition: &str, file_name: &str, summary: DataSummary) {
+        self.add_file_info(file_name, summary, Some(partition.to_string()))
+    }
+
+    fn add_file_info(&mut self, file_name: &str, summary: DataSummary, partition: Option<String>) {
         match self {
             UnloadOutput::Summary(s) => {
                 s.add(&summary);
@@ -125,6 +135,7 @@ impl UnloadOutput {
                 files.push(OutputFileInfo {
                     file_name: file_name.to_string(),
                     summary,
+                    partition,
                 });
             }
         }
@@ -158,11 +169,20 @@ fn file_infos_to_block(files: &[OutputFileInfo]) -> DataBlock {
         rows.push(file.summary.row_counts as u64);
         sizes.push(file.summary.output_bytes as u64);
     }
-    DataBlock::new_from_columns(vec![
+    let mut columns = vec![
         StringType::from_data(paths),
         UInt64Type::from_data(sizes),
         UInt64Type::from_data(rows),
-    ])
+    ];
+    // files of a partitioned unload are all in partitions
+    if files.first().is_some_and(|file| file.partition.is_some()) {
+        let partitions = files
+            .iter()
+            .map(|file| file.partition.as_deref().unwrap_or_default())
+            .collect::<Vec<_>>();
+        columns.push(StringType::from_data(partitions));
+    }
+    DataBlock::new_from_columns(columns)
 }
 
 #[derive(Default)]
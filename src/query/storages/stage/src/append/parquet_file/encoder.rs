@@ -0,0 +1,157 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::mem;
+use std::sync::Arc;
+
+use arrow_schema::Schema;
+use databend_common_catalog::plan::StageTableInfo;
+use databend_common_config::DATABEND_SEMVER;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableSchemaRef;
+use databend_common_formats::output_format::set_parquet_write_options;
+use databend_common_meta_app::principal::FileFormatParams;
+use databend_storages_common_table_meta::table::TableCompression;
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Encoding;
+use parquet::file::properties::EnabledStatistics;
+use parquet::file::properties::WriterProperties;
+
+use super::geo::GeoParquetWriter;
+use super::sorting::SortingChecker;
+use crate::append::file_encoder::FileEncoder;
+
+const MAX_BUFFER_SIZE: usize = 64 * 1024 * 1024;
+// this is number of rows, not size
+const MAX_ROW_GROUP_SIZE: usize = 1024 * 1024;
+const CREATE_BY_LEN: usize = 24; // "Databend 1.2.333-nightly".len();
+
+/// Properties of the parquet writer, with the unloading options of the file format applied.
+fn writer_properties(table_info: &StageTableInfo) -> Result<WriterProperties> {
+    // example:  1.2.333-nightly
+    // tags may contain other items like `1.2.680-p2`, we will fill it with `1.2.680-p2.....`
+    let mut create_by = format!(
+        "Databend {}.{}.{}-{:.<7}",
+        DATABEND_SEMVER.major,
+        DATABEND_SEMVER.minor,
+        DATABEND_SEMVER.patch,
+        DATABEND_SEMVER.pre.as_str()
+    );
+
+    if create_by.len() != CREATE_BY_LEN {
+        create_by = format!("{:.<24}", create_by);
+        create_by.truncate(24);
+    }
+
+    let builder = WriterProperties::builder()
+        .set_compression(TableCompression::Zstd.into())
+        .set_max_row_group_size(MAX_ROW_GROUP_SIZE)
+        .set_encoding(Encoding::PLAIN)
+        .set_dictionary_enabled(false)
+        .set_statistics_enabled(EnabledStatistics::Chunk)
+        .set_bloom_filter_enabled(false)
+        .set_created_by(create_by);
+    let builder = match &table_info.stage_info.file_format_params {
+        FileFormatParams::Parquet(params) => {
+            set_parquet_write_options(builder, params, &table_info.schema)?
+        }
+        _ => builder,
+    };
+    Ok(builder.build())
+}
+
+fn create_writer(
+    arrow_schema: Arc<Schema>,
+    props: WriterProperties,
+    target_file_size: Option<usize>,
+) -> Result<ArrowWriter<Vec<u8>>> {
+    let buf_size = match target_file_size {
+        Some(n) if n < MAX_BUFFER_SIZE => n,
+        _ => MAX_BUFFER_SIZE,
+    };
+    let writer = ArrowWriter::try_new(Vec::with_capacity(buf_size), arrow_schema, Some(props))?;
+    Ok(writer)
+}
+
+pub(crate) struct ParquetFileEncoder {
+    schema: TableSchemaRef,
+    arrow_schema: Arc<Schema>,
+    props: WriterProperties,
+    target_file_size: Option<usize>,
+    writer: ArrowWriter<Vec<u8>>,
+    geo_writer: GeoParquetWriter,
+    sorting_checker: Option<SortingChecker>,
+}
+
+impl ParquetFileEncoder {
+    pub(crate) fn try_create(
+        table_info: &StageTableInfo,
+        target_file_size: Option<usize>,
+    ) -> Result<Self> {
+        let schema = table_info.schema();
+        let arrow_schema = Arc::new(Schema::from(schema.as_ref()));
+        let props = writer_properties(table_info)?;
+        let writer = create_writer(arrow_schema.clone(), props.clone(), target_file_size)?;
+        let geo_writer = GeoParquetWriter::create(&schema);
+        let sorting_checker = match &table_info.stage_info.file_format_params {
+            FileFormatParams::Parquet(params) => {
+                SortingChecker::try_create(&schema, &params.sorting_columns)?
+            }
+            _ => None,
+        };
+        Ok(ParquetFileEncoder {
+            schema,
+            arrow_schema,
+            props,
+            target_file_size,
+            writer,
+            geo_writer,
+            sorting_checker,
+        })
+    }
+}
+
+impl FileEncoder for ParquetFileEncoder {
+    fn write(&mut self, block: &DataBlock) -> Result<()> {
+        let block = self.geo_writer.convert(block.clone())?;
+        let batch = block.to_record_batch(&self.schema)?;
+        if let Some(checker) = &mut self.sorting_checker {
+            checker.check(&batch)?;
+        }
+        self.writer.write(&batch)?;
+        Ok(())
+    }
+
+    fn file_size(&self) -> usize {
+        self.writer.bytes_written() + self.writer.in_progress_size()
+    }
+
+    fn finish(&mut self) -> Result<Vec<u8>> {
+        if let Some(geo) = self.geo_writer.finish()? {
+            self.writer.append_key_value_metadata(geo);
+        }
+        self.writer.finish()?;
+        if let Some(checker) = &mut self.sorting_checker {
+            checker.reset();
+        }
+        let buf = mem::take(self.writer.inner_mut());
+        self.writer = create_writer(
+            self.arrow_schema.clone(),
+            self.props.clone(),
+            self.target_file_size,
+        )?;
+        Ok(buf)
+    }
+}
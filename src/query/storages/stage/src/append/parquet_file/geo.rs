@@ -0,0 +1,180 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::binary::BinaryColumnBuilder;
+use databend_common_expression::types::GeographyColumn;
+use databend_common_expression::types::NullableColumn;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableSchema;
+use databend_common_expression::Value;
+use databend_common_io::ewkb_to_geo;
+use databend_common_io::geo_bounding_box;
+use databend_common_io::geo_to_wkb;
+use databend_common_io::geometry_type_name;
+use databend_common_storage::geo_parquet::GeoParquetColumn;
+use databend_common_storage::geo_parquet::GeoParquetMetadata;
+use databend_common_storage::geo_parquet::GEO_PARQUET_ENCODING_WKB;
+use databend_common_storage::geo_parquet::GEO_PARQUET_VERSION;
+use geozero::wkb::Ewkb;
+use parquet::format::KeyValue;
+
+/// Geometries are stored as EWKB, but GeoParquet requires WKB and keeps the SRID in the CRS of the column.
+///
+/// `GeoParquetWriter` converts the geometry and geography columns of blocks to WKB,
+/// and collects the `geo` metadata of the file being written.
+pub(super) struct GeoParquetWriter {
+    columns: Vec<GeoColumn>,
+}
+
+struct GeoColumn {
+    offset: usize,
+    name: String,
+    is_geography: bool,
+
+    bbox: Option<[f64; 4]>,
+    geometry_types: BTreeSet<&'static str>,
+    /// [None] if no geometry is written, `Some(None)` if geometries have different SRIDs.
+    srid: Option<Option<i32>>,
+}
+
+impl GeoParquetWriter {
+    pub(super) fn create(schema: &TableSchema) -> Self {
+        let columns = schema
+            .fields()
+            .iter()
+            .enumerate()
+            .filter_map(|(offset, f)| {
+                let is_geography = match f.data_type().remove_nullable() {
+                    TableDataType::Geometry => false,
+                    TableDataType::Geography => true,
+                    _ => return None,
+                };
+                Some(GeoColumn {
+                    offset,
+                    name: f.name().clone(),
+                    is_geography,
+                    bbox: None,
+                    geometry_types: BTreeSet::new(),
+                    srid: None,
+                })
+            })
+            .collect();
+        Self { columns }
+    }
+
+    pub(super) fn convert(&mut self, mut block: DataBlock) -> Result<DataBlock> {
+        let num_rows = block.num_rows();
+        for column in self.columns.iter_mut() {
+            let entry = block.get_by_offset(column.offset);
+            let data_type = entry.data_type.clone();
+            let full_column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, num_rows);
+            let validity = full_column.validity().1.cloned();
+            let values = match full_column.remove_nullable() {
+                Column::Geometry(values) => values,
+                Column::Geography(GeographyColumn(values)) => values,
+                _ => continue,
+            };
+
+            let mut builder = BinaryColumnBuilder::with_capacity(num_rows, values.data().len());
+            for (i, value) in values.iter().enumerate() {
+                if validity.as_ref().map_or(true, |v| v.get_bit(i)) {
+                    let (geo, srid) = ewkb_to_geo(&mut Ewkb(value))?;
+                    column.update(geometry_type_name(&geo), geo_bounding_box(&geo), srid);
+                    builder.put_slice(&geo_to_wkb(geo)?);
+                }
+                builder.commit_row();
+            }
+            let values = if column.is_geography {
+                Column::Geography(GeographyColumn(builder.build()))
+            } else {
+                Column::Geometry(builder.build())
+            };
+            let values = match validity {
+                Some(validity) => NullableColumn::new_column(values, validity),
+                None => values,
+            };
+            block.columns_mut()[column.offset] = BlockEntry::new(data_type, Value::Column(values));
+        }
+        Ok(block)
+    }
+
+    /// Take the `geo` metadata of the written geometries, and start collecting for the next file.
+    pub(super) fn finish(&mut self) -> Result<Option<KeyValue>> {
+        if self.columns.is_empty() {
+            return Ok(None);
+        }
+        let mut columns = BTreeMap::new();
+        for column in self.columns.iter_mut() {
+            let mut meta = GeoParquetColumn {
+                encoding: GEO_PARQUET_ENCODING_WKB.to_string(),
+                geometry_types: column
+                    .geometry_types
+                    .iter()
+                    .map(|t| t.to_string())
+                    .collect(),
+                bbox: column.bbox.map(|b| b.to_vec()),
+                ..Default::default()
+            };
+            if column.is_geography {
+                meta.edges = Some("spherical".to_string());
+            } else if let Some(srid) = column.srid {
+                meta.set_srid(srid);
+            }
+            columns.insert(column.name.clone(), meta);
+
+            column.bbox = None;
+            column.geometry_types.clear();
+            column.srid = None;
+        }
+        let meta = GeoParquetMetadata {
+            version: GEO_PARQUET_VERSION.to_string(),
+            primary_column: self.columns[0].name.clone(),
+            columns,
+        };
+        Ok(Some(meta.to_key_value()?))
+    }
+}
+
+impl GeoColumn {
+    fn update(&mut self, geometry_type: &'static str, bbox: Option<[f64; 4]>, srid: Option<i32>) {
+        self.geometry_types.insert(geometry_type);
+        if let Some(b) = bbox {
+            self.bbox = Some(match self.bbox {
+                Some(a) => [
+                    a[0].min(b[0]),
+                    a[1].min(b[1]),
+                    a[2].max(b[2]),
+                    a[3].max(b[3]),
+                ],
+                None => b,
+            });
+        }
+        // Geometries without SRID have the default SRID 4326.
+        let srid = srid.unwrap_or(4326);
+        self.srid = match self.srid {
+            None => Some(Some(srid)),
+            Some(Some(s)) if s == srid => Some(Some(s)),
+            _ => Some(None),
+        };
+    }
+}
@@ -25,7 +25,7 @@ use databend_common_pipeline_core::processors::ProcessorPtr;
 
 use super::block_batch::BlockBatch;
 
-pub(super) struct LimitFileSizeProcessor {
+pub(crate) struct LimitFileSizeProcessor {
     input: Arc<InputPort>,
     output: Arc<OutputPort>,
 
@@ -40,7 +40,7 @@ pub(super) struct LimitFileSizeProcessor {
 }
 
 impl LimitFileSizeProcessor {
-    pub(super) fn try_create(
+    pub(crate) fn try_create(
         input: Arc<InputPort>,
         output: Arc<OutputPort>,
         threshold: usize,
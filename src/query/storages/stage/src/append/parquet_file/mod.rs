@@ -12,8 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-mod block_batch;
-mod limit_file_size_processor;
-mod pipeline;
-mod writer_processor;
-pub(crate) use pipeline::append_data_to_parquet_files;
+pub(crate) mod block_batch;
+mod encoder;
+mod geo;
+pub(crate) mod limit_file_size_processor;
+mod sorting;
+pub(crate) use encoder::ParquetFileEncoder;
@@ -0,0 +1,130 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+
+use arrow_array::Array;
+use arrow_array::ArrayRef;
+use arrow_array::RecordBatch;
+use arrow_ord::ord::make_comparator;
+use arrow_ord::ord::DynComparator;
+use arrow_schema::SortOptions;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::TableSchema;
+use databend_common_meta_app::principal::ParquetSortingColumn;
+
+/// Check that the rows of a file are sorted by the `SORTING_COLUMNS`,
+/// which are recorded in the metadata of its row groups, so readers may rely on them.
+pub(super) struct SortingChecker {
+    /// Offsets of the sorting columns in the record batches.
+    columns: Vec<(usize, SortOptions)>,
+    sorting_columns: Vec<String>,
+    /// The sorting columns of the last row written to the file.
+    last_row: Option<Vec<ArrayRef>>,
+}
+
+impl SortingChecker {
+    pub fn try_create(schema: &TableSchema, sorting_columns: &[String]) -> Result<Option<Self>> {
+        if sorting_columns.is_empty() {
+            return Ok(None);
+        }
+        let columns = sorting_columns
+            .iter()
+            .map(|s| {
+                let column = ParquetSortingColumn::parse(s)?;
+                let offset = schema
+                    .fields()
+                    .iter()
+                    .position(|f| f.name().eq_ignore_ascii_case(&column.name))
+                    .ok_or_else(|| {
+                        ErrorCode::IllegalFileFormat(format!(
+                            "column {} of SORTING_COLUMNS not found",
+                            column.name
+                        ))
+                    })?;
+                Ok((offset, SortOptions {
+                    descending: column.descending,
+                    nulls_first: column.nulls_first,
+                }))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Some(Self {
+            columns,
+            sorting_columns: sorting_columns.to_vec(),
+            last_row: None,
+        }))
+    }
+
+    pub fn check(&mut self, batch: &RecordBatch) -> Result<()> {
+        let num_rows = batch.num_rows();
+        if num_rows == 0 {
+            return Ok(());
+        }
+        let arrays = self
+            .columns
+            .iter()
+            .map(|(offset, _)| batch.column(*offset).clone())
+            .collect::<Vec<_>>();
+
+        if let Some(last_row) = &self.last_row {
+            let comparators = self.comparators(last_row, &arrays)?;
+            if compare(&comparators, 0, 0) == Ordering::Greater {
+                return Err(self.not_sorted());
+            }
+        }
+        let comparators = self.comparators(&arrays, &arrays)?;
+        if (1..num_rows).any(|i| compare(&comparators, i - 1, i) == Ordering::Greater) {
+            return Err(self.not_sorted());
+        }
+
+        self.last_row = Some(arrays.iter().map(|a| a.slice(num_rows - 1, 1)).collect());
+        Ok(())
+    }
+
+    /// Start checking a new file.
+    pub fn reset(&mut self) {
+        self.last_row = None;
+    }
+
+    fn comparators(&self, left: &[ArrayRef], right: &[ArrayRef]) -> Result<Vec<DynComparator>> {
+        let comparators = left
+            .iter()
+            .zip(right)
+            .zip(&self.columns)
+            .map(|((l, r), (_, options))| make_comparator(l.as_ref(), r.as_ref(), *options))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(comparators)
+    }
+
+    fn not_sorted(&self) -> ErrorCode {
+        let columns = self
+            .sorting_columns
+            .iter()
+            .map(|c| format!("'{c}'"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        ErrorCode::BadArguments(format!(
+            "the data to unload is not sorted by SORTING_COLUMNS ({columns}), add a matching ORDER BY to the query"
+        ))
+    }
+}
+
+fn compare(comparators: &[DynComparator], l: usize, r: usize) -> Ordering {
+    comparators
+        .iter()
+        .map(|cmp| cmp(l, r))
+        .find(|o| o.is_ne())
+        .unwrap_or(Ordering::Equal)
+}
@@ -0,0 +1,88 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::StageTableInfo;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::principal::FileFormatParams;
+use databend_common_meta_app::principal::StageFileCompression;
+use databend_common_pipeline_core::Pipeline;
+use opendal::Operator;
+
+use super::writer_processor::PartitionedFileWriter;
+use crate::compression::get_compression_alg_copy;
+
+/// PartitionedFileWriter * N: split blocks by partitions and encode them in files of each partition,
+/// flush a file when it is large enough or when there are too many data buffered.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn append_data_to_partitioned_files(
+    pipeline: &mut Pipeline,
+    ctx: Arc<dyn TableContext>,
+    table_info: StageTableInfo,
+    op: Operator,
+    query_id: String,
+    group_id: &std::sync::atomic::AtomicUsize,
+    mem_limit: usize,
+    max_threads: usize,
+) -> Result<()> {
+    let fmt = &table_info.stage_info.file_format_params;
+    let max_file_size = table_info.copy_into_location_options.max_file_size;
+    let (compression, default_file_size) = match fmt {
+        FileFormatParams::Parquet(_) | FileFormatParams::Orc(_) | FileFormatParams::Avro(_) => {
+            (None, 64 * 1024 * 1024)
+        }
+        _ if fmt.compression() == StageFileCompression::None => (None, 16 * 1024 * 1024),
+        _ => (
+            get_compression_alg_copy(fmt.compression(), "")?,
+            64 * 1024 * 1024,
+        ),
+    };
+    // the encoded files are buffered in memory until they are written out
+    let mem_limit = mem_limit / 2;
+    let max_file_size = if max_file_size == 0 {
+        default_file_size
+    } else {
+        max_file_size.min(mem_limit)
+    };
+    let max_threads = max_threads.min(mem_limit / max_file_size).max(1);
+    let mem_limit = (mem_limit / max_threads).max(max_file_size);
+    pipeline.try_resize(max_threads)?;
+
+    // values of partitions are the trailing columns, which are not written to files
+    let num_partition_columns = table_info.copy_into_location_options.partition_keys.len();
+    let fields = table_info.schema.fields();
+    let mut file_table_info = table_info.clone();
+    file_table_info.schema =
+        TableSchemaRefExt::create(fields[..fields.len() - num_partition_columns].to_vec());
+
+    pipeline.add_transform(|input, output| {
+        let gid = group_id.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        PartitionedFileWriter::try_create(
+            input,
+            output,
+            ctx.clone(),
+            file_table_info.clone(),
+            op.clone(),
+            query_id.clone(),
+            gid,
+            max_file_size,
+            mem_limit,
+            compression,
+        )
+    })?;
+    Ok(())
+}
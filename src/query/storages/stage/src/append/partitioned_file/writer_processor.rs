@@ -0,0 +1,364 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::mem;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use databend_common_catalog::plan::StageTableInfo;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_compress::CompressAlgorithm;
+use databend_common_exception::Result;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::ScalarRef;
+use databend_common_pipeline_core::processors::Event;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::Processor;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use opendal::Operator;
+
+use crate::append::file_encoder::create_file_encoder;
+use crate::append::file_encoder::FileEncoder;
+use crate::append::output::DataSummary;
+use crate::append::path::unload_partition_path;
+use crate::append::UnloadOutput;
+
+/// Directory name of NULL or empty partition values, the same as Hive.
+const DEFAULT_PARTITION_NAME: &str = "__HIVE_DEFAULT_PARTITION__";
+
+struct PartitionFile {
+    encoder: Box<dyn FileEncoder>,
+    input_bytes: usize,
+    row_counts: usize,
+    batch_id: usize,
+}
+
+struct FileToWrite {
+    partition: String,
+    path: String,
+    data: Vec<u8>,
+    summary: DataSummary,
+}
+
+pub struct PartitionedFileWriter {
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+
+    ctx: Arc<dyn TableContext>,
+    // schema of files, without the partition columns
+    table_info: StageTableInfo,
+
+    input_data: Option<DataBlock>,
+
+    // files in progress, by partition directory like `k1=v1/k2=v2/`
+    partitions: HashMap<String, PartitionFile>,
+
+    files_to_write: Vec<FileToWrite>,
+    data_accessor: Operator,
+
+    // the result of statement
+    unload_output: UnloadOutput,
+    unload_output_blocks: Option<VecDeque<DataBlock>>,
+
+    query_id: String,
+    group_id: usize,
+
+    targe_file_size: usize,
+    // max size of files buffered in all partitions
+    mem_limit: usize,
+    compression: Option<CompressAlgorithm>,
+}
+
+impl PartitionedFileWriter {
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        ctx: Arc<dyn TableContext>,
+        table_info: StageTableInfo,
+        data_accessor: Operator,
+        query_id: String,
+        group_id: usize,
+        targe_file_size: usize,
+        mem_limit: usize,
+        compression: Option<CompressAlgorithm>,
+    ) -> Result<ProcessorPtr> {
+        let unload_output =
+            UnloadOutput::create(table_info.copy_into_location_options.detailed_output);
+
+        Ok(ProcessorPtr::create(Box::new(PartitionedFileWriter {
+            input,
+            output,
+            ctx,
+            table_info,
+            input_data: None,
+            partitions: HashMap::new(),
+            files_to_write: vec![],
+            data_accessor,
+            unload_output,
+            unload_output_blocks: None,
+            query_id,
+            group_id,
+            targe_file_size,
+            mem_limit,
+            compression,
+        })))
+    }
+
+    fn partition_dir(&self, partition_columns: &[Column], row: usize) -> String {
+        let keys = &self.table_info.copy_into_location_options.partition_keys;
+        let mut dir = String::new();
+        for (key, column) in keys.iter().zip(partition_columns) {
+            let value = match column.index(row) {
+                Some(ScalarRef::String(s)) if !s.is_empty() => escape_path_name(s),
+                _ => DEFAULT_PARTITION_NAME.to_string(),
+            };
+            dir.push_str(&escape_path_name(key));
+            dir.push('=');
+            dir.push_str(&value);
+            dir.push('/');
+        }
+        dir
+    }
+
+    fn write_block(&mut self, mut block: DataBlock) -> Result<()> {
+        let num_rows = block.num_rows();
+        if num_rows == 0 {
+            return Ok(());
+        }
+        let num_partition_columns = self
+            .table_info
+            .copy_into_location_options
+            .partition_keys
+            .len();
+        let num_columns = block.num_columns() - num_partition_columns;
+        let partition_columns = block.columns()[num_columns..]
+            .iter()
+            .map(|entry| entry.to_column(num_rows))
+            .collect::<Vec<_>>();
+        block.pop_columns(num_partition_columns);
+
+        // group rows by partitions, keep the order of rows in each partition
+        let mut groups: Vec<(String, Vec<u32>)> = vec![];
+        let mut group_index: HashMap<String, usize> = HashMap::new();
+        for row in 0..num_rows {
+            let dir = self.partition_dir(&partition_columns, row);
+            match group_index.get(&dir) {
+                Some(i) => groups[*i].1.push(row as u32),
+                None => {
+                    group_index.insert(dir.clone(), groups.len());
+                    groups.push((dir, vec![row as u32]));
+                }
+            }
+        }
+
+        if groups.len() == 1 {
+            let (dir, _) = groups.pop().unwrap();
+            self.write_partition(dir, &block)?;
+        } else {
+            for (dir, rows) in groups {
+                let partition_block = block.take(&rows)?;
+                self.write_partition(dir, &partition_block)?;
+            }
+        }
+
+        // flush the largest files until the buffered data is under the memory limit
+        let mut buffered_size = self
+            .partitions
+            .values()
+            .map(|p| p.encoder.file_size())
+            .sum::<usize>();
+        while buffered_size > self.mem_limit {
+            let Some((dir, size)) = self
+                .partitions
+                .iter()
+                .filter(|(_, p)| p.row_counts > 0)
+                .map(|(dir, p)| (dir.clone(), p.encoder.file_size()))
+                .max_by_key(|(_, size)| *size)
+            else {
+                break;
+            };
+            self.flush(&dir)?;
+            buffered_size -= size;
+        }
+        Ok(())
+    }
+
+    fn write_partition(&mut self, dir: String, block: &DataBlock) -> Result<()> {
+        if !self.partitions.contains_key(&dir) {
+            let encoder =
+                create_file_encoder(&self.ctx, &self.table_info, Some(self.targe_file_size))?;
+            self.partitions.insert(dir.clone(), PartitionFile {
+                encoder,
+                input_bytes: 0,
+                row_counts: 0,
+                batch_id: 0,
+            });
+        }
+        let partition = self.partitions.get_mut(&dir).unwrap();
+        partition.input_bytes += block.memory_size();
+        partition.row_counts += block.num_rows();
+        partition.encoder.write(block)?;
+        if partition.encoder.file_size() >= self.targe_file_size {
+            self.flush(&dir)?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self, dir: &str) -> Result<()> {
+        let partition = self.partitions.get_mut(dir).unwrap();
+        let data = partition.encoder.finish()?;
+        let summary = DataSummary {
+            row_counts: partition.row_counts,
+            input_bytes: partition.input_bytes,
+            output_bytes: data.len(),
+        };
+        let path = unload_partition_path(
+            &self.table_info,
+            dir,
+            &self.query_id,
+            self.group_id,
+            partition.batch_id,
+            self.compression,
+        );
+        partition.batch_id += 1;
+        partition.row_counts = 0;
+        partition.input_bytes = 0;
+        self.files_to_write.push(FileToWrite {
+            partition: dir.trim_end_matches('/').to_string(),
+            path,
+            data,
+            summary,
+        });
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Processor for PartitionedFileWriter {
+    fn name(&self) -> String {
+        "PartitionedFileWriter".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            Ok(Event::Finished)
+        } else if !self.files_to_write.is_empty() {
+            self.input.set_not_need_data();
+            Ok(Event::Async)
+        } else if self.input_data.is_some() {
+            self.input.set_not_need_data();
+            Ok(Event::Sync)
+        } else if self.input.is_finished() {
+            if !self.partitions.is_empty() {
+                return Ok(Event::Sync);
+            }
+            if self.unload_output.is_empty() {
+                self.output.finish();
+                return Ok(Event::Finished);
+            }
+            if self.unload_output_blocks.is_none() {
+                self.unload_output_blocks = Some(self.unload_output.to_block_partial().into());
+            }
+            if self.output.can_push() {
+                if let Some(block) = self.unload_output_blocks.as_mut().unwrap().pop_front() {
+                    self.output.push_data(Ok(block));
+                    Ok(Event::NeedConsume)
+                } else {
+                    self.output.finish();
+                    Ok(Event::Finished)
+                }
+            } else {
+                Ok(Event::NeedConsume)
+            }
+        } else if self.input.has_data() {
+            self.input_data = Some(self.input.pull_data().unwrap()?);
+            self.input.set_not_need_data();
+            Ok(Event::Sync)
+        } else {
+            self.input.set_need_data();
+            Ok(Event::NeedData)
+        }
+    }
+
+    fn process(&mut self) -> Result<()> {
+        if let Some(block) = self.input_data.take() {
+            self.write_block(block)?;
+        } else if self.input.is_finished() {
+            let dirs = self
+                .partitions
+                .iter()
+                .filter(|(_, p)| p.row_counts > 0)
+                .map(|(dir, _)| dir.clone())
+                .collect::<Vec<_>>();
+            for dir in dirs {
+                self.flush(&dir)?;
+            }
+            self.partitions.clear();
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        for file in mem::take(&mut self.files_to_write) {
+            self.unload_output
+                .add_partition_file(&file.partition, &file.path, file.summary);
+            self.data_accessor.write(&file.path, file.data).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Escape characters not allowed in paths of Hive partitions to `%XX`.
+fn escape_path_name(name: &str) -> String {
+    let mut escaped = String::with_capacity(name.len());
+    for c in name.chars() {
+        if c.is_ascii_control()
+            || matches!(
+                c,
+                '"' | '#' | '%' | '\'' | '*' | '/' | ':' | '=' | '?' | '\\' | '{' | '[' | ']' | '^'
+            )
+        {
+            escaped.push_str(&format!("%{:02X}", c as u32));
+        } else {
+            escaped.push(c);
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::escape_path_name;
+
+    #[test]
+    fn test_escape_path_name() {
+        assert_eq!(escape_path_name("2024-01-02"), "2024-01-02");
+        assert_eq!(escape_path_name("a/b=c"), "a%2Fb%3Dc");
+        assert_eq!(escape_path_name("10:30 50%"), "10%3A30 50%25");
+        assert_eq!(escape_path_name("x\ny"), "x%0Ay");
+        assert_eq!(escape_path_name("数据"), "数据");
+    }
+}
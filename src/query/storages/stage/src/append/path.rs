@@ -21,6 +21,26 @@ pub fn unload_path(
     group_id: usize,
     batch_id: usize,
     compression: Option<CompressAlgorithm>,
+) -> String {
+    unload_partition_path(
+        stage_table_info,
+        "",
+        query_id,
+        group_id,
+        batch_id,
+        compression,
+    )
+}
+
+/// Path of a file in the `partition` directory (like `k1=v1/k2=v2/`) under the unload path,
+/// an empty `partition` for unpartitioned unload.
+pub fn unload_partition_path(
+    stage_table_info: &StageTableInfo,
+    partition: &str,
+    query_id: &str,
+    group_id: usize,
+    batch_id: usize,
+    compression: Option<CompressAlgorithm>,
 ) -> String {
     let format_name = format!(
         "{:?}",
@@ -42,9 +62,14 @@ pub fn unload_path(
             "".to_string()
         };
         if path.ends_with("data_") {
+            // the last segment of path is the prefix of file names
+            let (dir, prefix) = match path.rfind('/') {
+                Some(pos) => path.split_at(pos + 1),
+                None => ("", path.as_str()),
+            };
             format!(
-                "{}{}{:0>4}_{:0>8}.{}{}",
-                path, query_id, group_id, batch_id, format_name, suffix
+                "{}{}{}{}{:0>4}_{:0>8}.{}{}",
+                dir, partition, prefix, query_id, group_id, batch_id, format_name, suffix
             )
         } else {
             let (path, sep) = if path == "/" {
@@ -55,8 +80,8 @@ pub fn unload_path(
                 (path.as_str(), "/")
             };
             format!(
-                "{}{}data_{}{:0>4}_{:0>8}.{}{}",
-                path, sep, query_id, group_id, batch_id, format_name, suffix
+                "{}{}{}data_{}{:0>4}_{:0>8}.{}{}",
+                path, sep, partition, query_id, group_id, batch_id, format_name, suffix
             )
         }
     }
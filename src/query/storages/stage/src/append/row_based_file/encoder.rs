@@ -0,0 +1,84 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::mem;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::StageTableInfo;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_compress::CompressAlgorithm;
+use databend_common_compress::CompressCodec;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_formats::output_format::OutputFormat;
+use databend_common_formats::FileFormatOptionsExt;
+
+use crate::append::file_encoder::FileEncoder;
+use crate::compression::get_compression_alg_copy;
+
+pub(crate) struct RowBasedFileEncoder {
+    output_format: Box<dyn OutputFormat>,
+    prefix: Vec<u8>,
+    compression: Option<CompressAlgorithm>,
+    // uncompressed content of the current file
+    buf: Vec<u8>,
+}
+
+impl RowBasedFileEncoder {
+    pub(crate) fn try_create(
+        ctx: &Arc<dyn TableContext>,
+        table_info: &StageTableInfo,
+    ) -> Result<Self> {
+        let mut options_ext =
+            FileFormatOptionsExt::create_from_settings(&ctx.get_settings(), false)?;
+        let output_format = options_ext.get_output_format(
+            table_info.schema(),
+            table_info.stage_info.file_format_params.clone(),
+        )?;
+        let prefix = output_format.serialize_prefix()?;
+        let compression =
+            get_compression_alg_copy(table_info.stage_info.file_format_params.compression(), "")?;
+        Ok(RowBasedFileEncoder {
+            output_format,
+            prefix,
+            compression,
+            buf: vec![],
+        })
+    }
+}
+
+impl FileEncoder for RowBasedFileEncoder {
+    fn write(&mut self, block: &DataBlock) -> Result<()> {
+        if self.buf.is_empty() {
+            self.buf.extend_from_slice(&self.prefix);
+        }
+        let data = self.output_format.serialize_block(block)?;
+        self.buf.extend_from_slice(&data);
+        Ok(())
+    }
+
+    fn file_size(&self) -> usize {
+        self.buf.len()
+    }
+
+    fn finish(&mut self) -> Result<Vec<u8>> {
+        let buf = mem::take(&mut self.buf);
+        match self.compression {
+            Some(compression) if !buf.is_empty() => {
+                CompressCodec::from(compression).compress_all(&buf)
+            }
+            _ => Ok(buf),
+        }
+    }
+}
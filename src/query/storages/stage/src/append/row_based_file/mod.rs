@@ -13,8 +13,10 @@
 // limitations under the License.
 
 mod buffers;
+mod encoder;
 pub(crate) mod limit_file_size_processor;
 mod pipeline;
 mod serialize_processor;
 pub(crate) mod writer_processor;
+pub(crate) use encoder::RowBasedFileEncoder;
 pub(crate) use pipeline::append_data_to_row_based_files;
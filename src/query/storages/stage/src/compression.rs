@@ -39,6 +39,8 @@ pub fn get_compression_alg_copy(
                 "compress type snappy is unimplemented",
             ));
         }
+        // Members of archives are unpacked by the `Decompressor` of the read pipeline.
+        StageFileCompression::Zip | StageFileCompression::Tar => None,
         StageFileCompression::None => None,
     };
     Ok(compression_algo)
@@ -23,4 +23,5 @@ mod compression;
 mod read;
 mod stage_table;
 
+pub use compression::get_compression_alg_copy;
 pub use stage_table::StageTable;
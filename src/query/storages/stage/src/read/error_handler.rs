@@ -13,9 +13,11 @@
 // limitations under the License.
 
 use std::sync::atomic::AtomicU64;
+use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 
 use databend_common_ast::ast::OnErrorMode;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::ColumnBuilder;
 use databend_common_storage::FileParseError;
@@ -24,6 +26,11 @@ use databend_common_storage::FileStatus;
 pub struct ErrorHandler {
     pub on_error_mode: OnErrorMode,
     pub on_error_count: AtomicU64,
+    /// Keep the rejected rows for the `ERROR_TABLE` of copy.
+    pub keep_rejected_rows: bool,
+    /// The max number of rejected rows kept in memory by each node, set by `max_copy_rejected_rows`.
+    pub max_rejected_rows: usize,
+    pub num_rejected_rows: AtomicUsize,
 }
 
 impl ErrorHandler {
@@ -34,6 +41,7 @@ impl ErrorHandler {
         file_status: &mut FileStatus,
         file_path: &str,
         line: usize,
+        record: Option<&[u8]>,
     ) -> Result<()> {
         if let Some((columns, num_rows)) = columns {
             columns.iter_mut().for_each(|c| {
@@ -48,7 +56,21 @@ impl ErrorHandler {
 
         match &self.on_error_mode {
             OnErrorMode::Continue => {
-                file_status.add_error(e, line);
+                if self.keep_rejected_rows {
+                    if self.num_rejected_rows.fetch_add(1, Ordering::Relaxed)
+                        >= self.max_rejected_rows
+                    {
+                        return Err(ErrorCode::BadBytes(format!(
+                            "more than {} rows are rejected, please increase the setting max_copy_rejected_rows! the last error: {e}",
+                            self.max_rejected_rows
+                        ))
+                        .add_detail_back(format!("at file '{file_path}', line {line}")));
+                    }
+                    let record = record.map(|r| String::from_utf8_lossy(r).into_owned());
+                    file_status.add_rejected_row(e, line, record);
+                } else {
+                    file_status.add_error(e, line);
+                }
                 Ok(())
             }
             OnErrorMode::AbortNum(abort_num) => {
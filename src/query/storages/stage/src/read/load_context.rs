@@ -13,8 +13,10 @@
 // limitations under the License.
 
 use std::sync::atomic::AtomicU64;
+use std::sync::atomic::AtomicUsize;
 use std::sync::Arc;
 
+use databend_common_ast::ast::ValidationMode;
 use databend_common_catalog::plan::StageTableInfo;
 use databend_common_catalog::query_kind::QueryKind;
 use databend_common_catalog::table_context::TableContext;
@@ -66,6 +68,11 @@ impl LoadContext {
             .copy_into_table_options
             .disable_variant_check;
         let on_error_mode = stage_table_info.copy_into_table_options.on_error.clone();
+        // The validation returns all the rejected rows instead of keeping them for the ERROR_TABLE.
+        let max_rejected_rows = match stage_table_info.copy_into_table_options.validation_mode {
+            ValidationMode::ReturnErrors | ValidationMode::ReturnAllErrors => usize::MAX,
+            _ => settings.get_max_copy_rejected_rows()? as usize,
+        };
         let fields = stage_table_info
             .schema
             .fields()
@@ -88,6 +95,9 @@ impl LoadContext {
             error_handler: ErrorHandler {
                 on_error_mode,
                 on_error_count: AtomicU64::new(0),
+                keep_rejected_rows: stage_table_info.copy_into_table_options.keep_rejected_rows,
+                max_rejected_rows,
+                num_rejected_rows: AtomicUsize::new(0),
             },
         })
     }
@@ -83,6 +83,8 @@ impl RowBatchWithPosition {
 pub enum RowBatch {
     Csv(CSVRowBatch),
     NDJson(NdjsonRowBatch),
+    Avro(AvroRowBatch),
+    Xml(XmlRowBatch),
 }
 
 impl RowBatch {
@@ -90,6 +92,8 @@ impl RowBatch {
         match self {
             RowBatch::Csv(b) => b.rows(),
             RowBatch::NDJson(b) => b.rows(),
+            RowBatch::Avro(b) => b.num_rows,
+            RowBatch::Xml(b) => b.rows(),
         }
     }
 
@@ -97,6 +101,8 @@ impl RowBatch {
         match self {
             RowBatch::Csv(b) => b.size(),
             RowBatch::NDJson(b) => b.size(),
+            RowBatch::Avro(b) => b.data.len(),
+            RowBatch::Xml(b) => b.size(),
         }
     }
 }
@@ -110,6 +116,10 @@ pub struct CSVRowBatch {
     pub row_ends: Vec<usize>,
     pub field_ends: Vec<usize>,
     pub num_fields: Vec<usize>,
+    /// the raw bytes of rows, only kept for the `ERROR_TABLE` of copy.
+    /// row[i] starts at raw_row_ends[i-1] and ends at raw_row_ends[i]
+    pub raw_data: Vec<u8>,
+    pub raw_row_ends: Vec<usize>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Default)]
@@ -123,6 +133,39 @@ pub struct NdjsonRowBatch {
     pub start: usize,
     pub row_ends: Vec<usize>,
 }
+#[derive(serde::Serialize, serde::Deserialize, Debug, Default)]
+pub struct AvroRowBatch {
+    /// header of the object container file, including the writer schema and codec.
+    pub header: Vec<u8>,
+    /// complete data blocks following the header.
+    pub data: Vec<u8>,
+    pub num_rows: usize,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Default)]
+pub struct XmlRowBatch {
+    /// row[i] is the element starts at row_ends[i-1] and ends at row_ends[i]
+    pub data: Vec<u8>,
+    pub row_ends: Vec<usize>,
+}
+
+impl XmlRowBatch {
+    pub fn rows(&self) -> usize {
+        self.row_ends.len()
+    }
+
+    pub fn size(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &[u8]> {
+        let starts = std::iter::once(0).chain(self.row_ends.iter().copied());
+        starts
+            .zip(self.row_ends.iter())
+            .map(|(start, end)| &self.data[start..*end])
+    }
+}
+
 pub struct NdJsonRowBatchIter<'a> {
     first_row: &'a [u8],
     data: &'a [u8],
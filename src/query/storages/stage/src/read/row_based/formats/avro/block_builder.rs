@@ -0,0 +1,222 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use apache_avro::schema::RecordSchema;
+use apache_avro::schema::ResolvedSchema;
+use apache_avro::types::Value;
+use apache_avro::Reader;
+use apache_avro::Schema;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_meta_app::principal::NullAs;
+use databend_common_storage::FileParseError;
+
+use crate::read::load_context::LoadContext;
+use crate::read::row_based::batch::RowBatchWithPosition;
+use crate::read::row_based::format::RowDecoder;
+use crate::read::row_based::formats::avro::field_decoder::AvroFieldDecoder;
+use crate::read::row_based::formats::avro::format::AvroInputFormat;
+use crate::read::row_based::processors::BlockBuilderState;
+use crate::read::row_based::utils::truncate_column_data;
+
+pub struct AvroDecoder {
+    pub load_context: Arc<LoadContext>,
+    pub fmt: AvroInputFormat,
+}
+
+impl AvroDecoder {
+    pub fn create(fmt: AvroInputFormat, load_context: Arc<LoadContext>) -> Self {
+        Self { load_context, fmt }
+    }
+
+    /// Match the columns of the table with the fields of the writer schema by name,
+    /// so that files written with different versions of the schema can be loaded together.
+    fn match_fields(&self, record: &RecordSchema) -> Vec<Option<usize>> {
+        let case_sensitive = self
+            .load_context
+            .file_format_options_ext
+            .ident_case_sensitive;
+        self.load_context
+            .schema
+            .fields()
+            .iter()
+            .map(|field| {
+                record.fields.iter().position(|f| {
+                    if case_sensitive {
+                        f.name == field.name()
+                    } else {
+                        f.name.eq_ignore_ascii_case(field.name())
+                    }
+                })
+            })
+            .collect()
+    }
+
+    fn read_row(
+        &self,
+        decoder: &AvroFieldDecoder,
+        record: &RecordSchema,
+        positions: &[Option<usize>],
+        value: Value,
+        columns: &mut [ColumnBuilder],
+        null_if: &[&str],
+    ) -> std::result::Result<(), FileParseError> {
+        let Value::Record(values) = value else {
+            return Err(FileParseError::Unexpected {
+                message: format!("avro record expected, but got {value:?}"),
+            });
+        };
+
+        for (((column_index, field), column), position) in self
+            .load_context
+            .schema
+            .fields()
+            .iter()
+            .enumerate()
+            .zip(columns.iter_mut())
+            .zip(positions.iter())
+        {
+            let Some(position) = position else {
+                match self.fmt.params.missing_field_as {
+                    NullAs::Null if field.is_nullable_or_null() => {
+                        column.push_default();
+                    }
+                    NullAs::FieldDefault => {
+                        self.load_context
+                            .push_default_value(column, column_index, false)?;
+                    }
+                    _ => {
+                        return Err(FileParseError::ColumnMissingError {
+                            column_index,
+                            column_name: field.name().to_owned(),
+                            column_type: field.data_type.to_string(),
+                        });
+                    }
+                }
+                continue;
+            };
+
+            let decode_error = |e: ErrorCode, value: &Value| FileParseError::ColumnDecodeError {
+                column_index,
+                column_name: field.name().to_owned(),
+                column_type: field.data_type.to_string(),
+                decode_error: e.message(),
+                error_code: e.code(),
+                column_data: truncate_column_data(format!("{value:?}")),
+            };
+            let value = &values[*position].1;
+            let schema = &record.fields[*position].schema;
+            let (unwrapped, _) = decoder
+                .resolve_union(value, schema)
+                .map_err(|e| decode_error(e, value))?;
+            match unwrapped {
+                Value::Null if !matches!(column, ColumnBuilder::Nullable(_)) => {
+                    return Err(decode_error(
+                        ErrorCode::BadBytes("null value is not allowed for non-nullable field"),
+                        value,
+                    ));
+                }
+                Value::String(s)
+                    if matches!(column, ColumnBuilder::Nullable(_))
+                        && null_if.contains(&s.as_str()) =>
+                {
+                    column.push_default();
+                }
+                _ => {
+                    decoder
+                        .read_field(column, value, schema)
+                        .map_err(|e| decode_error(e, value))?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl RowDecoder for AvroDecoder {
+    fn add(
+        &self,
+        state: &mut BlockBuilderState,
+        batch: RowBatchWithPosition,
+    ) -> Result<Vec<DataBlock>> {
+        let columns = &mut state.mutable_columns;
+        let path = &batch.start_pos.path;
+        let data = batch.data.into_avro().unwrap();
+        let null_if = self
+            .fmt
+            .params
+            .null_if
+            .iter()
+            .map(|x| x.as_str())
+            .collect::<Vec<_>>();
+
+        let mut buf = data.header;
+        buf.extend_from_slice(&data.data);
+        let invalid_file =
+            |e: apache_avro::Error| ErrorCode::BadBytes(format!("Invalid avro file '{path}': {e}"));
+        let reader = Reader::new(buf.as_slice()).map_err(invalid_file)?;
+        let schema = reader.writer_schema().clone();
+        let resolved = ResolvedSchema::try_from(&schema).map_err(invalid_file)?;
+        let decoder = AvroFieldDecoder {
+            names: resolved.get_names(),
+        };
+        let Schema::Record(record) = decoder.resolve(&schema)? else {
+            return Err(ErrorCode::BadBytes(format!(
+                "Invalid avro file '{path}': the schema must be a record, but got {:?}",
+                schema
+            )));
+        };
+        let positions = self.match_fields(record);
+
+        for (row_id, value) in reader.enumerate() {
+            let res = value
+                .map_err(|e| FileParseError::Unexpected {
+                    message: format!("fail to decode avro record: {e}"),
+                })
+                .and_then(|value| {
+                    if self.load_context.file_format_options_ext.is_select {
+                        // select from stage reads the whole record as variant into `$1`.
+                        let ColumnBuilder::Variant(column) = &mut columns[0] else {
+                            unreachable!("select from avro expect a single variant column");
+                        };
+                        decoder.read_variant(column, &value, &schema).map_err(|e| {
+                            FileParseError::Unexpected {
+                                message: e.message(),
+                            }
+                        })
+                    } else {
+                        self.read_row(&decoder, record, &positions, value, columns, &null_if)
+                    }
+                });
+            if let Err(e) = res {
+                self.load_context.error_handler.on_error(
+                    e,
+                    Some((columns, state.num_rows)),
+                    &mut state.file_status,
+                    path,
+                    batch.start_pos.rows + row_id,
+                    None,
+                )?
+            } else {
+                state.num_rows += 1;
+                state.file_status.num_rows_loaded += 1;
+            }
+        }
+        Ok(vec![])
+    }
+}
@@ -0,0 +1,442 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use apache_avro::schema::Name;
+use apache_avro::types::Value;
+use apache_avro::Schema;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::array::ArrayColumnBuilder;
+use databend_common_expression::types::binary::BinaryColumnBuilder;
+use databend_common_expression::types::date::clamp_date;
+use databend_common_expression::types::decimal::Decimal;
+use databend_common_expression::types::decimal::DecimalColumnBuilder;
+use databend_common_expression::types::decimal::DecimalSize;
+use databend_common_expression::types::nullable::NullableColumnBuilder;
+use databend_common_expression::types::number::Number;
+use databend_common_expression::types::string::StringColumnBuilder;
+use databend_common_expression::types::timestamp::clamp_timestamp;
+use databend_common_expression::types::AnyType;
+use databend_common_expression::types::MutableBitmap;
+use databend_common_expression::types::NumberColumnBuilder;
+use databend_common_expression::with_decimal_type;
+use databend_common_expression::with_number_mapped_type;
+use databend_common_expression::ColumnBuilder;
+use num_bigint::BigInt;
+use num_traits::NumCast;
+
+/// Decode avro values into columns, guided by the writer schema.
+///
+/// The writer schema is needed to resolve named references, unions and the scale of decimals.
+pub struct AvroFieldDecoder<'a> {
+    pub names: &'a HashMap<Name, &'a Schema>,
+}
+
+impl AvroFieldDecoder<'_> {
+    pub fn resolve<'s>(&'s self, schema: &'s Schema) -> Result<&'s Schema> {
+        match schema {
+            Schema::Ref { name } => self.names.get(name).copied().ok_or_else(|| {
+                ErrorCode::BadBytes(format!("Unknown avro named type {}", name.fullname(None)))
+            }),
+            _ => Ok(schema),
+        }
+    }
+
+    /// Unwrap the union to the branch the value is written with.
+    pub fn resolve_union<'v>(
+        &'v self,
+        value: &'v Value,
+        schema: &'v Schema,
+    ) -> Result<(&'v Value, &'v Schema)> {
+        let schema = self.resolve(schema)?;
+        match (value, schema) {
+            (Value::Union(i, value), Schema::Union(union)) => {
+                let branch = union
+                    .variants()
+                    .get(*i as usize)
+                    .ok_or_else(|| ErrorCode::BadBytes(format!("Invalid avro union branch {i}")))?;
+                self.resolve_union(value, branch)
+            }
+            _ => Ok((value, schema)),
+        }
+    }
+
+    pub fn read_field(
+        &self,
+        column: &mut ColumnBuilder,
+        value: &Value,
+        schema: &Schema,
+    ) -> Result<()> {
+        let (value, schema) = self.resolve_union(value, schema)?;
+        match column {
+            ColumnBuilder::Null { len } => {
+                *len += 1;
+                Ok(())
+            }
+            ColumnBuilder::Nullable(c) => self.read_nullable(c, value, schema),
+            ColumnBuilder::Boolean(c) => self.read_bool(c, value),
+            ColumnBuilder::Number(c) => with_number_mapped_type!(|NUM_TYPE| match c {
+                NumberColumnBuilder::NUM_TYPE(c) => self.read_number(c, value),
+            }),
+            ColumnBuilder::Decimal(c) => with_decimal_type!(|DECIMAL_TYPE| match c {
+                DecimalColumnBuilder::DECIMAL_TYPE(c, size) => {
+                    self.read_decimal(c, *size, value, schema)
+                }
+            }),
+            ColumnBuilder::Date(c) => self.read_date(c, value),
+            ColumnBuilder::Timestamp(c) => self.read_timestamp(c, value),
+            ColumnBuilder::String(c) => self.read_string(c, value),
+            ColumnBuilder::Binary(c) => self.read_binary(c, value),
+            ColumnBuilder::Array(c) => self.read_array(c, value, schema),
+            ColumnBuilder::Map(c) => self.read_map(c, value, schema),
+            ColumnBuilder::Tuple(fields) => self.read_tuple(fields, value, schema),
+            ColumnBuilder::Variant(c) => self.read_variant(c, value, schema),
+            _ => Err(ErrorCode::BadBytes(format!(
+                "Unsupported data type {} for avro",
+                column.data_type()
+            ))),
+        }
+    }
+
+    fn read_nullable(
+        &self,
+        column: &mut NullableColumnBuilder<AnyType>,
+        value: &Value,
+        schema: &Schema,
+    ) -> Result<()> {
+        match value {
+            Value::Null => {
+                column.push_null();
+            }
+            other => {
+                self.read_field(&mut column.builder, other, schema)?;
+                column.validity.push(true);
+            }
+        }
+        Ok(())
+    }
+
+    fn read_bool(&self, column: &mut MutableBitmap, value: &Value) -> Result<()> {
+        match value {
+            Value::Boolean(v) => {
+                column.push(*v);
+                Ok(())
+            }
+            _ => Err(type_mismatch("boolean", value)),
+        }
+    }
+
+    fn read_number<T>(&self, column: &mut Vec<T>, value: &Value) -> Result<()>
+    where
+        T: Number + From<T::Native>,
+        T::Native: NumCast,
+    {
+        let v: Option<T::Native> = match value {
+            Value::Int(v) => num_traits::cast::cast(*v),
+            Value::Long(v) => num_traits::cast::cast(*v),
+            Value::Float(v) => num_traits::cast::cast(*v),
+            Value::Double(v) => num_traits::cast::cast(*v),
+            _ => return Err(type_mismatch("number", value)),
+        };
+        match v {
+            Some(v) => {
+                column.push(v.into());
+                Ok(())
+            }
+            None => Err(ErrorCode::BadBytes(format!(
+                "Number {value:?} is out of range"
+            ))),
+        }
+    }
+
+    fn read_decimal<D: Decimal>(
+        &self,
+        column: &mut Vec<D>,
+        size: DecimalSize,
+        value: &Value,
+        schema: &Schema,
+    ) -> Result<()> {
+        let (unscaled, scale) = match (value, schema) {
+            (Value::Decimal(v), Schema::Decimal(s)) => {
+                let bytes: Vec<u8> = v
+                    .try_into()
+                    .map_err(|e| ErrorCode::BadBytes(format!("Invalid avro decimal: {e}")))?;
+                (BigInt::from_signed_bytes_be(&bytes), s.scale as u32)
+            }
+            (Value::Int(v), _) => (BigInt::from(*v), 0),
+            (Value::Long(v), _) => (BigInt::from(*v), 0),
+            _ => return Err(type_mismatch("decimal", value)),
+        };
+        let target_scale = size.scale as u32;
+        // Digits beyond the scale of the column are truncated.
+        let unscaled = if target_scale >= scale {
+            unscaled * BigInt::from(10).pow(target_scale - scale)
+        } else {
+            unscaled / BigInt::from(10).pow(scale - target_scale)
+        };
+        match D::from_bigint(unscaled) {
+            Some(v)
+                if v >= D::min_for_precision(size.precision)
+                    && v <= D::max_for_precision(size.precision) =>
+            {
+                column.push(v);
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes(format!(
+                "Decimal {value:?} is out of range of Decimal({}, {})",
+                size.precision, size.scale
+            ))),
+        }
+    }
+
+    fn read_date(&self, column: &mut Vec<i32>, value: &Value) -> Result<()> {
+        match value {
+            Value::Date(v) | Value::Int(v) => {
+                column.push(clamp_date(*v as i64));
+                Ok(())
+            }
+            _ => Err(type_mismatch("date", value)),
+        }
+    }
+
+    fn read_timestamp(&self, column: &mut Vec<i64>, value: &Value) -> Result<()> {
+        let mut micros = match value {
+            Value::TimestampMillis(v) | Value::LocalTimestampMillis(v) => v.saturating_mul(1000),
+            Value::TimestampMicros(v) | Value::LocalTimestampMicros(v) | Value::Long(v) => *v,
+            Value::TimestampNanos(v) | Value::LocalTimestampNanos(v) => *v / 1000,
+            _ => return Err(type_mismatch("timestamp", value)),
+        };
+        clamp_timestamp(&mut micros);
+        column.push(micros);
+        Ok(())
+    }
+
+    fn read_string(&self, column: &mut StringColumnBuilder, value: &Value) -> Result<()> {
+        match value {
+            Value::String(v) | Value::Enum(_, v) => column.put_str(v),
+            Value::Uuid(v) => column.put_str(&v.to_string()),
+            Value::Bytes(v) | Value::Fixed(_, v) => {
+                let v = std::str::from_utf8(v)
+                    .map_err(|_| ErrorCode::BadBytes("Invalid utf8 bytes for string"))?;
+                column.put_str(v)
+            }
+            _ => return Err(type_mismatch("string", value)),
+        }
+        column.commit_row();
+        Ok(())
+    }
+
+    fn read_binary(&self, column: &mut BinaryColumnBuilder, value: &Value) -> Result<()> {
+        match value {
+            Value::Bytes(v) | Value::Fixed(_, v) => column.put_slice(v),
+            Value::String(v) => column.put_slice(v.as_bytes()),
+            _ => return Err(type_mismatch("binary", value)),
+        }
+        column.commit_row();
+        Ok(())
+    }
+
+    fn read_array(
+        &self,
+        column: &mut ArrayColumnBuilder<AnyType>,
+        value: &Value,
+        schema: &Schema,
+    ) -> Result<()> {
+        match (value, schema) {
+            (Value::Array(items), Schema::Array(array)) => {
+                for item in items {
+                    self.read_field(&mut column.builder, item, &array.items)?;
+                }
+                column.commit_row();
+                Ok(())
+            }
+            _ => Err(type_mismatch("array", value)),
+        }
+    }
+
+    fn read_map(
+        &self,
+        column: &mut ArrayColumnBuilder<AnyType>,
+        value: &Value,
+        schema: &Schema,
+    ) -> Result<()> {
+        const KEY: usize = 0;
+        const VALUE: usize = 1;
+        let map_builder = column.builder.as_tuple_mut().unwrap();
+        match (value, schema) {
+            (Value::Map(entries), Schema::Map(map)) => {
+                let mut entries = entries.iter().collect::<Vec<_>>();
+                entries.sort_by(|a, b| a.0.cmp(b.0));
+                for (key, val) in entries {
+                    let key = Value::String(key.clone());
+                    self.read_field(&mut map_builder[KEY], &key, &Schema::String)?;
+                    self.read_field(&mut map_builder[VALUE], val, &map.types)?;
+                }
+                column.commit_row();
+                Ok(())
+            }
+            _ => Err(type_mismatch("map", value)),
+        }
+    }
+
+    fn read_tuple(
+        &self,
+        fields: &mut [ColumnBuilder],
+        value: &Value,
+        schema: &Schema,
+    ) -> Result<()> {
+        match (value, schema) {
+            (Value::Record(values), Schema::Record(record)) => {
+                if fields.len() != values.len() {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "Incorrect avro record, expect {} fields, but get {} fields",
+                        fields.len(),
+                        values.len()
+                    )));
+                }
+                for ((field, (_, val)), record_field) in fields
+                    .iter_mut()
+                    .zip(values.iter())
+                    .zip(record.fields.iter())
+                {
+                    self.read_field(field, val, &record_field.schema)?;
+                }
+                Ok(())
+            }
+            _ => Err(type_mismatch("record", value)),
+        }
+    }
+
+    pub fn read_variant(
+        &self,
+        column: &mut BinaryColumnBuilder,
+        value: &Value,
+        schema: &Schema,
+    ) -> Result<()> {
+        let json = self.to_json(value, schema)?;
+        let v = jsonb::Value::from(&json);
+        v.write_to_vec(&mut column.data);
+        column.commit_row();
+        Ok(())
+    }
+
+    fn to_json(&self, value: &Value, schema: &Schema) -> Result<serde_json::Value> {
+        let (value, schema) = self.resolve_union(value, schema)?;
+        let json = match value {
+            Value::Null => serde_json::Value::Null,
+            Value::Boolean(v) => serde_json::Value::Bool(*v),
+            Value::Int(v) | Value::Date(v) | Value::TimeMillis(v) => serde_json::Value::from(*v),
+            Value::Long(v)
+            | Value::TimeMicros(v)
+            | Value::TimestampMillis(v)
+            | Value::TimestampMicros(v)
+            | Value::TimestampNanos(v)
+            | Value::LocalTimestampMillis(v)
+            | Value::LocalTimestampMicros(v)
+            | Value::LocalTimestampNanos(v) => serde_json::Value::from(*v),
+            Value::Float(v) => serde_json::Value::from(*v as f64),
+            Value::Double(v) => serde_json::Value::from(*v),
+            Value::String(v) | Value::Enum(_, v) => serde_json::Value::String(v.clone()),
+            Value::Uuid(v) => serde_json::Value::String(v.to_string()),
+            Value::Bytes(v) | Value::Fixed(_, v) => serde_json::Value::from(v.clone()),
+            Value::Decimal(v) => {
+                let Schema::Decimal(s) = schema else {
+                    return Err(type_mismatch("decimal", value));
+                };
+                let bytes: Vec<u8> = v
+                    .try_into()
+                    .map_err(|e| ErrorCode::BadBytes(format!("Invalid avro decimal: {e}")))?;
+                let unscaled = BigInt::from_signed_bytes_be(&bytes);
+                serde_json::Value::String(decimal_to_string(unscaled, s.scale))
+            }
+            Value::BigDecimal(v) => serde_json::Value::String(v.to_string()),
+            Value::Array(items) => {
+                let Schema::Array(array) = schema else {
+                    return Err(type_mismatch("array", value));
+                };
+                let items = items
+                    .iter()
+                    .map(|item| self.to_json(item, &array.items))
+                    .collect::<Result<Vec<_>>>()?;
+                serde_json::Value::Array(items)
+            }
+            Value::Map(entries) => {
+                let Schema::Map(map) = schema else {
+                    return Err(type_mismatch("map", value));
+                };
+                let entries = entries
+                    .iter()
+                    .map(|(k, v)| Ok((k.clone(), self.to_json(v, &map.types)?)))
+                    .collect::<Result<serde_json::Map<_, _>>>()?;
+                serde_json::Value::Object(entries)
+            }
+            Value::Record(values) => {
+                let Schema::Record(record) = schema else {
+                    return Err(type_mismatch("record", value));
+                };
+                let entries = values
+                    .iter()
+                    .zip(record.fields.iter())
+                    .map(|((k, v), field)| Ok((k.clone(), self.to_json(v, &field.schema)?)))
+                    .collect::<Result<serde_json::Map<_, _>>>()?;
+                serde_json::Value::Object(entries)
+            }
+            _ => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "Unsupported avro value {value:?} for variant"
+                )));
+            }
+        };
+        Ok(json)
+    }
+}
+
+fn decimal_to_string(unscaled: BigInt, scale: usize) -> String {
+    let digits = unscaled.magnitude().to_string();
+    let sign = if unscaled.sign() == num_bigint::Sign::Minus {
+        "-"
+    } else {
+        ""
+    };
+    if scale == 0 {
+        format!("{sign}{digits}")
+    } else if digits.len() > scale {
+        let (int, frac) = digits.split_at(digits.len() - scale);
+        format!("{sign}{int}.{frac}")
+    } else {
+        format!("{sign}0.{}{digits}", "0".repeat(scale - digits.len()))
+    }
+}
+
+fn type_mismatch(expected: &str, value: &Value) -> ErrorCode {
+    ErrorCode::BadBytes(format!("Expect avro {expected}, but got {value:?}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigInt;
+
+    use super::decimal_to_string;
+
+    #[test]
+    fn test_decimal_to_string() {
+        assert_eq!(decimal_to_string(BigInt::from(12345), 0), "12345");
+        assert_eq!(decimal_to_string(BigInt::from(12345), 2), "123.45");
+        assert_eq!(decimal_to_string(BigInt::from(-12345), 2), "-123.45");
+        assert_eq!(decimal_to_string(BigInt::from(5), 3), "0.005");
+        assert_eq!(decimal_to_string(BigInt::from(-5), 1), "-0.5");
+    }
+}
@@ -0,0 +1,292 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_storage::FileStatus;
+
+use crate::read::row_based::batch::AvroRowBatch;
+use crate::read::row_based::batch::BytesBatch;
+use crate::read::row_based::batch::Position;
+use crate::read::row_based::batch::RowBatch;
+use crate::read::row_based::batch::RowBatchWithPosition;
+use crate::read::row_based::format::SeparatorState;
+
+const MAGIC: &[u8] = b"Obj\x01";
+const SYNC_SIZE: usize = 16;
+
+/// Cut an avro object container file into batches of complete data blocks.
+///
+/// The layout of the file is `<header> (<count> <size> <data> <sync marker>)*`, every batch
+/// carries a copy of the header (the writer schema and codec), so that it can be decoded as a
+/// standalone container file.
+pub struct AvroRowSeparator {
+    header: Option<Vec<u8>>,
+    // remain from last read batch
+    buf: Vec<u8>,
+    pos: Position,
+}
+
+impl SeparatorState for AvroRowSeparator {
+    fn append(&mut self, batch: BytesBatch) -> Result<(Vec<RowBatchWithPosition>, FileStatus)> {
+        self.separate(batch)
+    }
+}
+
+impl AvroRowSeparator {
+    pub fn try_create(path: &str) -> Result<Self> {
+        Ok(Self {
+            header: None,
+            buf: vec![],
+            pos: Position::new(path.to_string()),
+        })
+    }
+
+    fn separate(&mut self, batch: BytesBatch) -> Result<(Vec<RowBatchWithPosition>, FileStatus)> {
+        self.buf.extend_from_slice(&batch.data);
+
+        if self.header.is_none() {
+            match parse_header(&self.buf).map_err(|e| self.error(&e.message()))? {
+                Some(len) => {
+                    self.header = Some(self.buf.drain(..len).collect());
+                    self.pos.offset += len;
+                }
+                None if batch.is_eof => {
+                    return Err(self.error("incomplete file header"));
+                }
+                None => return Ok((vec![], FileStatus::default())),
+            }
+        }
+        let header = self.header.as_ref().unwrap();
+        let sync = &header[header.len() - SYNC_SIZE..];
+
+        let mut end = 0;
+        let mut num_rows = 0;
+        while let Some((rows, len)) = parse_block(&self.buf[end..], sync).map_err(|e| {
+            self.error(&format!(
+                "{} at offset {}",
+                e.message(),
+                self.pos.offset + end
+            ))
+        })? {
+            num_rows += rows;
+            end += len;
+        }
+        if batch.is_eof && end != self.buf.len() {
+            return Err(self.error("incomplete data block at the end of file"));
+        }
+        if end == 0 {
+            return Ok((vec![], FileStatus::default()));
+        }
+
+        let rows = AvroRowBatch {
+            header: header.clone(),
+            data: self.buf.drain(..end).collect(),
+            num_rows,
+        };
+        let out_pos = self.pos.clone();
+        self.pos.rows += num_rows;
+        self.pos.offset += end;
+        Ok((
+            vec![RowBatchWithPosition::new(RowBatch::Avro(rows), out_pos)],
+            FileStatus::default(),
+        ))
+    }
+
+    fn error(&self, message: &str) -> ErrorCode {
+        ErrorCode::BadBytes(format!("Invalid avro file '{}': {message}", self.pos.path))
+    }
+}
+
+/// Returns the length of the header, or `None` if more data is needed.
+fn parse_header(buf: &[u8]) -> Result<Option<usize>> {
+    if buf.len() < MAGIC.len() {
+        return Ok(None);
+    }
+    if &buf[..MAGIC.len()] != MAGIC {
+        return Err(ErrorCode::BadBytes("bad magic number"));
+    }
+    let mut cursor = MAGIC.len();
+    // The metadata is a map of bytes, encoded as a series of blocks.
+    loop {
+        let Some((count, n)) = read_long(&buf[cursor..])? else {
+            return Ok(None);
+        };
+        cursor += n;
+        if count == 0 {
+            break;
+        }
+        if count < 0 {
+            // A negative count is followed by the size of the block in bytes.
+            let Some((_, n)) = read_long(&buf[cursor..])? else {
+                return Ok(None);
+            };
+            cursor += n;
+        }
+        // Both keys and values are length prefixed.
+        for _ in 0..count.unsigned_abs() * 2 {
+            let Some((len, n)) = read_long(&buf[cursor..])? else {
+                return Ok(None);
+            };
+            if len < 0 {
+                return Err(ErrorCode::BadBytes("negative length in metadata"));
+            }
+            cursor += n + len as usize;
+            if cursor > buf.len() {
+                return Ok(None);
+            }
+        }
+    }
+    cursor += SYNC_SIZE;
+    if cursor > buf.len() {
+        Ok(None)
+    } else {
+        Ok(Some(cursor))
+    }
+}
+
+/// Returns the number of rows and the length of the data block, or `None` if more data is needed.
+fn parse_block(buf: &[u8], sync: &[u8]) -> Result<Option<(usize, usize)>> {
+    let Some((count, n1)) = read_long(buf)? else {
+        return Ok(None);
+    };
+    let Some((size, n2)) = read_long(&buf[n1..])? else {
+        return Ok(None);
+    };
+    if count < 0 || size < 0 {
+        return Err(ErrorCode::BadBytes("negative size of data block"));
+    }
+    let end = n1 + n2 + size as usize + SYNC_SIZE;
+    if end > buf.len() {
+        return Ok(None);
+    }
+    if &buf[end - SYNC_SIZE..end] != sync {
+        return Err(ErrorCode::BadBytes("sync marker mismatch"));
+    }
+    Ok(Some((count as usize, end)))
+}
+
+/// Read a zigzag encoded long, returns the value and the number of bytes consumed.
+fn read_long(buf: &[u8]) -> Result<Option<(i64, usize)>> {
+    let mut n: u64 = 0;
+    for (i, b) in buf.iter().enumerate() {
+        if i >= 10 {
+            return Err(ErrorCode::BadBytes("too many bytes of long"));
+        }
+        n |= ((b & 0x7f) as u64) << (i * 7);
+        if b & 0x80 == 0 {
+            let v = ((n >> 1) as i64) ^ -((n & 1) as i64);
+            return Ok(Some((v, i + 1)));
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_long(buf: &mut Vec<u8>, v: i64) {
+        let mut n = ((v << 1) ^ (v >> 63)) as u64;
+        while n & !0x7f != 0 {
+            buf.push((n & 0x7f) as u8 | 0x80);
+            n >>= 7;
+        }
+        buf.push(n as u8);
+    }
+
+    fn write_block(buf: &mut Vec<u8>, rows: i64, data: &[u8], sync: &[u8]) {
+        write_long(buf, rows);
+        write_long(buf, data.len() as i64);
+        buf.extend_from_slice(data);
+        buf.extend_from_slice(sync);
+    }
+
+    fn header(sync: &[u8]) -> Vec<u8> {
+        let mut buf = MAGIC.to_vec();
+        write_long(&mut buf, 1);
+        for s in ["avro.schema", "\"long\""] {
+            write_long(&mut buf, s.len() as i64);
+            buf.extend_from_slice(s.as_bytes());
+        }
+        write_long(&mut buf, 0);
+        buf.extend_from_slice(sync);
+        buf
+    }
+
+    fn bytes_batch(data: &[u8], is_eof: bool) -> BytesBatch {
+        BytesBatch {
+            data: data.to_vec(),
+            path: "test".to_string(),
+            offset: 0,
+            is_eof,
+        }
+    }
+
+    #[test]
+    fn test_read_long() -> Result<()> {
+        for v in [0, -1, 1, 63, -64, 64, 1 << 40, i64::MAX, i64::MIN] {
+            let mut buf = vec![];
+            write_long(&mut buf, v);
+            assert_eq!(read_long(&buf)?, Some((v, buf.len())));
+            assert_eq!(read_long(&buf[..buf.len() - 1])?, None);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_avro_row_separator() -> Result<()> {
+        let sync = [7u8; SYNC_SIZE];
+        let header = header(&sync);
+        let mut file = header.clone();
+        write_block(&mut file, 2, &[2, 4], &sync);
+        let block_end = file.len();
+        write_block(&mut file, 3, &[2, 4, 6], &sync);
+
+        // the whole file in one batch
+        let mut sep = AvroRowSeparator::try_create("test")?;
+        let (batches, _) = sep.append(bytes_batch(&file, true))?;
+        assert_eq!(batches.len(), 1);
+        let batch = batches[0].data.as_avro().unwrap();
+        assert_eq!(batch.header, header);
+        assert_eq!(batch.num_rows, 5);
+        assert_eq!(batch.data, file[header.len()..]);
+
+        // cut in the middle of the header and the second block
+        let mut sep = AvroRowSeparator::try_create("test")?;
+        let (batches, _) = sep.append(bytes_batch(&file[..5], false))?;
+        assert!(batches.is_empty());
+        let (batches, _) = sep.append(bytes_batch(&file[5..block_end + 3], false))?;
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].data.rows(), 2);
+        assert_eq!(batches[0].start_pos.rows, 0);
+        let (batches, _) = sep.append(bytes_batch(&file[block_end + 3..], true))?;
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].data.rows(), 3);
+        assert_eq!(batches[0].start_pos.rows, 2);
+
+        // truncated file
+        let mut sep = AvroRowSeparator::try_create("test")?;
+        assert!(sep
+            .append(bytes_batch(&file[..file.len() - 1], true))
+            .is_err());
+
+        // corrupted sync marker
+        let mut corrupted = file.clone();
+        corrupted[block_end - 1] = 0;
+        let mut sep = AvroRowSeparator::try_create("test")?;
+        assert!(sep.append(bytes_batch(&corrupted, true)).is_err());
+        Ok(())
+    }
+}
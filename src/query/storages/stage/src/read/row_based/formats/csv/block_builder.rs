@@ -110,12 +110,7 @@ impl CsvDecoder {
         self.field_decoder
             .read_field(builder, col_data)
             .map_err(|e| {
-                get_decode_error_by_pos(
-                    column_index,
-                    &self.load_context.schema,
-                    &e.message(),
-                    col_data,
-                )
+                get_decode_error_by_pos(column_index, &self.load_context.schema, &e, col_data)
             })
     }
 
@@ -162,17 +157,20 @@ impl RowDecoder for CsvDecoder {
         for (i, end) in data.row_ends.iter().enumerate() {
             let num_fields = data.num_fields[i];
             let buf = &data.data[start..*end];
-            if let Err(e) = self.read_row(
-                buf,
-                columns,
-                &data.field_ends[field_end_idx..field_end_idx + num_fields],
-            ) {
+            let field_ends = &data.field_ends[field_end_idx..field_end_idx + num_fields];
+            if let Err(e) = self.read_row(buf, columns, field_ends) {
+                // the raw rows are only kept for the ERROR_TABLE of copy
+                let record = data.raw_row_ends.get(i).map(|raw_end| {
+                    let raw_start = if i == 0 { 0 } else { data.raw_row_ends[i - 1] };
+                    &data.raw_data[raw_start..*raw_end]
+                });
                 self.load_context.error_handler.on_error(
                     e,
                     Some((columns, state.num_rows)),
                     &mut state.file_status,
                     &batch.start_pos.path,
                     i + batch.start_pos.rows,
+                    record,
                 )?
             } else {
                 state.num_rows += 1;
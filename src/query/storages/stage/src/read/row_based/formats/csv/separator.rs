@@ -43,6 +43,9 @@ pub struct CsvReader {
     num_fields: usize,
 
     reader: csv_core::Reader,
+    terminator: csv_core::Terminator,
+    // the raw bytes of the current row, only kept for the ERROR_TABLE of copy
+    raw_record: Option<Vec<u8>>,
     // remain from last read batch
     last_partial_row: Vec<u8>,
 
@@ -63,9 +66,15 @@ impl SeparatorState for CsvReader {
 }
 
 enum ReadRecordOutput {
-    Record { num_fields: usize, bytes: usize },
+    Record {
+        num_fields: usize,
+        bytes: usize,
+        raw: Option<Vec<u8>>,
+    },
     RecordSkipped,
-    PartialRecord { bytes: usize },
+    PartialRecord {
+        bytes: usize,
+    },
 }
 
 impl CsvReader {
@@ -79,15 +88,17 @@ impl CsvReader {
         } else {
             Some(format.params.escape.as_bytes()[0])
         };
+        let terminator = match format.params.record_delimiter.as_str().try_into()? {
+            RecordDelimiter::Crlf => csv_core::Terminator::CRLF,
+            RecordDelimiter::Any(v) => csv_core::Terminator::Any(v),
+        };
         let reader = csv_core::ReaderBuilder::new()
             .delimiter(format.params.field_delimiter.as_bytes()[0])
             .quote(format.params.quote.as_bytes()[0])
             .escape(escape)
-            .terminator(match format.params.record_delimiter.as_str().try_into()? {
-                RecordDelimiter::Crlf => csv_core::Terminator::CRLF,
-                RecordDelimiter::Any(v) => csv_core::Terminator::Any(v),
-            })
+            .terminator(terminator)
             .build();
+        let raw_record = load_ctx.error_handler.keep_rejected_rows.then(Vec::new);
         let projection = load_ctx.pos_projection.clone();
         let max_fields = match &projection {
             Some(p) => p.iter().copied().max().unwrap_or(1),
@@ -101,6 +112,8 @@ impl CsvReader {
             error_on_column_count_mismatch: format.params.error_on_column_count_mismatch,
             num_fields,
             reader,
+            terminator,
+            raw_record,
             pos: Position::new(path.to_string()),
             rows_to_skip: format.params.headers as usize,
             field_ends: vec![0; max_fields],
@@ -121,6 +134,9 @@ impl CsvReader {
         self.n_end += n_end;
         // shadow the n_end return from reader to avoid misuse
         let n_end = self.n_end;
+        if let Some(raw) = &mut self.raw_record {
+            raw.extend_from_slice(&input[..n_in]);
+        }
 
         match result {
             ReadRecordResult::InputEmpty => {
@@ -134,12 +150,14 @@ impl CsvReader {
             ReadRecordResult::OutputFull => Err(self.error_output_full()),
             ReadRecordResult::OutputEndsFull => Err(self.error_output_ends_full()),
             ReadRecordResult::Record => {
+                let raw = self.take_raw_record();
                 let output = {
                     if self.projection.is_some() {
                         // select $1, $2, $3 ..  from csv, not check num of fields here
                         ReadRecordOutput::Record {
                             num_fields: n_end,
                             bytes: n_out,
+                            raw,
                         }
                     } else {
                         // copy
@@ -158,6 +176,7 @@ impl CsvReader {
                             ReadRecordOutput::Record {
                                 num_fields: self.num_fields,
                                 bytes: n_out,
+                                raw,
                             }
                         } else {
                             // check num of fields strictly
@@ -169,12 +188,14 @@ impl CsvReader {
                                     file_status,
                                     &self.pos.path,
                                     self.pos.rows,
+                                    raw.as_deref(),
                                 )?;
                                 ReadRecordOutput::RecordSkipped
                             } else {
                                 ReadRecordOutput::Record {
                                     num_fields: self.num_fields,
                                     bytes: n_out,
+                                    raw,
                                 }
                             }
                         }
@@ -239,7 +260,15 @@ impl CsvReader {
             let (res, n_in) =
                 self.read_record(buf_in, &mut buf_out[buf_out_pos..], &mut file_status)?;
             match res {
-                ReadRecordOutput::Record { num_fields, bytes } => {
+                ReadRecordOutput::Record {
+                    num_fields,
+                    bytes,
+                    raw,
+                } => {
+                    if let Some(raw) = raw {
+                        row_batch.raw_data.extend_from_slice(&raw);
+                        row_batch.raw_row_ends.push(row_batch.raw_data.len());
+                    }
                     buf_out_pos += bytes;
                     row_batch.num_fields.push(num_fields);
                     row_batch
@@ -299,6 +328,32 @@ impl CsvReader {
         }
     }
 
+    /// Take the raw bytes of the row just read, without the record delimiter.
+    fn take_raw_record(&mut self) -> Option<Vec<u8>> {
+        let mut raw = mem::take(self.raw_record.as_mut()?);
+        match self.terminator {
+            // `\n` of the last `\r\n` is consumed at the start of the next row
+            csv_core::Terminator::CRLF => {
+                let start = raw.iter().take_while(|b| **b == b'\n').count();
+                let end = raw.len()
+                    - raw[start..]
+                        .iter()
+                        .rev()
+                        .take_while(|b| matches!(**b, b'\r' | b'\n'))
+                        .count();
+                raw.truncate(end);
+                raw.drain(..start);
+            }
+            csv_core::Terminator::Any(b) => {
+                if raw.last() == Some(&b) {
+                    raw.pop();
+                }
+            }
+            _ => {}
+        }
+        Some(raw)
+    }
+
     fn check_num_field(&self) -> std::result::Result<(), FileParseError> {
         let expected = self.num_fields;
         let found = self.n_end;
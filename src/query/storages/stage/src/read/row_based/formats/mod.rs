@@ -12,10 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod avro;
 mod csv;
 mod ndjson;
 mod tsv;
+mod xml;
 
+pub use avro::AvroInputFormat;
 pub use csv::CsvInputFormat;
 pub use ndjson::NdJsonInputFormat;
 pub use tsv::TsvInputFormat;
+pub use xml::XmlInputFormat;
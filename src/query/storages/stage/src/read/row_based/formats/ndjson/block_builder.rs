@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use bstr::ByteSlice;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::ColumnBuilder;
 use databend_common_expression::DataBlock;
@@ -118,6 +119,7 @@ impl NdJsonDecoder {
                                     column_name: field.name().to_owned(),
                                     column_type: field.data_type.to_string(),
                                     decode_error: "null value is not allowed for non-nullable field, when NULL_FIELDS_AS=NULL".to_owned(),
+                                    error_code: ErrorCode::BAD_BYTES,
                                     column_data: "null".to_owned(),
                                 });
                             }
@@ -141,6 +143,7 @@ impl NdJsonDecoder {
                                     column_name: field.name().to_owned(),
                                     column_type: field.data_type.to_string(),
                                     decode_error: e.to_string(),
+                                    error_code: e.code(),
                                     column_data: truncate_column_data(value.to_string()),
                                 }
                             })?;
@@ -179,6 +182,7 @@ impl RowDecoder for NdJsonDecoder {
                         &mut state.file_status,
                         &batch.start_pos.path,
                         batch.start_pos.rows + row_id,
+                        Some(row),
                     )?
                 } else {
                     state.num_rows += 1;
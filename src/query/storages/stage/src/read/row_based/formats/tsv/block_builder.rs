@@ -83,18 +83,13 @@ impl TsvDecoder {
             let mut cursor = Cursor::new(col_data);
             let mut data = vec![];
             cursor.read_escaped_string_text(&mut data).map_err(|e| {
-                get_decode_error_by_pos(
-                    column_index,
-                    &self.load_context.schema,
-                    &e.to_string(),
-                    col_data,
-                )
+                get_decode_error_by_pos(column_index, &self.load_context.schema, &e, col_data)
             })?;
             if let Err(e) = self.field_decoder.read_field(builder, &data) {
                 return Err(get_decode_error_by_pos(
                     column_index,
                     &self.load_context.schema,
-                    &e.message(),
+                    &e,
                     col_data,
                 ));
             }
@@ -207,6 +202,7 @@ impl RowDecoder for TsvDecoder {
                         &mut state.file_status,
                         &batch.start_pos.path,
                         batch.start_pos.rows + row_id,
+                        Some(row),
                     )?
                 } else {
                     state.num_rows += 1;
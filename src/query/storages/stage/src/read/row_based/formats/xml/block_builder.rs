@@ -0,0 +1,200 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_formats::SeparatedTextDecoder;
+use databend_common_storage::FileParseError;
+
+use crate::read::load_context::LoadContext;
+use crate::read::row_based::batch::RowBatchWithPosition;
+use crate::read::row_based::format::RowDecoder;
+use crate::read::row_based::formats::xml::format::XmlInputFormat;
+use crate::read::row_based::formats::xml::node::XmlNode;
+use crate::read::row_based::formats::xml::node::XmlValue;
+use crate::read::row_based::processors::BlockBuilderState;
+use crate::read::row_based::utils::truncate_column_data;
+
+pub struct XmlDecoder {
+    pub load_context: Arc<LoadContext>,
+    pub fmt: XmlInputFormat,
+    pub text_decoder: SeparatedTextDecoder,
+}
+
+impl XmlDecoder {
+    pub fn create(fmt: XmlInputFormat, load_context: Arc<LoadContext>) -> Self {
+        let text_decoder = SeparatedTextDecoder::create_xml(&load_context.file_format_options_ext);
+        Self {
+            load_context,
+            fmt,
+            text_decoder,
+        }
+    }
+
+    fn case_sensitive(&self) -> bool {
+        self.load_context
+            .file_format_options_ext
+            .ident_case_sensitive
+    }
+
+    fn read_row(
+        &self,
+        buf: &[u8],
+        columns: &mut [ColumnBuilder],
+    ) -> std::result::Result<(), FileParseError> {
+        let row = XmlNode::parse(buf).map_err(|e| FileParseError::Unexpected {
+            message: format!("fail to parse xml row: {}", e.message()),
+        })?;
+        if self.load_context.file_format_options_ext.is_select {
+            // select from stage reads the whole row as variant into `$1`.
+            let ColumnBuilder::Variant(column) = &mut columns[0] else {
+                unreachable!("select from xml expect a single variant column");
+            };
+            jsonb::Value::from(&row.to_json()).write_to_vec(&mut column.data);
+            column.commit_row();
+            return Ok(());
+        }
+
+        for ((column_index, field), column) in self
+            .load_context
+            .schema
+            .fields()
+            .iter()
+            .enumerate()
+            .zip(columns.iter_mut())
+        {
+            // missing values and empty elements of non-nullable columns take the default value
+            match row.get(field.name(), self.case_sensitive()) {
+                Some(value) if !value.is_empty() || field.is_nullable_or_null() => {
+                    self.read_value(column, &field.data_type, &value)
+                        .map_err(|e| FileParseError::ColumnDecodeError {
+                            column_index,
+                            column_name: field.name().to_owned(),
+                            column_type: field.data_type.to_string(),
+                            decode_error: e.message(),
+                            error_code: e.code(),
+                            column_data: truncate_column_data(value.to_string()),
+                        })?;
+                }
+                _ => {
+                    self.load_context
+                        .push_default_value(column, column_index, false)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn read_value(
+        &self,
+        column: &mut ColumnBuilder,
+        data_type: &TableDataType,
+        value: &XmlValue,
+    ) -> Result<()> {
+        match (column, data_type, value) {
+            (ColumnBuilder::Nullable(c), TableDataType::Nullable(inner), _) => {
+                if value.is_empty() {
+                    c.push_null();
+                } else {
+                    self.read_value(&mut c.builder, inner, value)?;
+                    c.validity.push(true);
+                }
+            }
+            (ColumnBuilder::Variant(c), _, _) => {
+                jsonb::Value::from(&value.to_json()).write_to_vec(&mut c.data);
+                c.commit_row();
+            }
+            (
+                ColumnBuilder::Tuple(fields),
+                TableDataType::Tuple {
+                    fields_name,
+                    fields_type,
+                },
+                XmlValue::Element(node),
+            ) => {
+                for ((field, name), ty) in fields.iter_mut().zip(fields_name).zip(fields_type) {
+                    match node.get(name, self.case_sensitive()) {
+                        Some(value) => self.read_value(field, ty, &value)?,
+                        None if ty.is_nullable_or_null() => field.push_default(),
+                        None => {
+                            return Err(ErrorCode::BadBytes(format!(
+                                "missing field '{name}' of tuple"
+                            )));
+                        }
+                    }
+                }
+            }
+            (ColumnBuilder::Array(c), TableDataType::Array(inner), XmlValue::Element(node))
+                if !node.children.is_empty() =>
+            {
+                // the children of the element are the items, whatever their names are
+                for (_, child) in &node.children {
+                    self.read_value(&mut c.builder, inner, &XmlValue::Element(child))?;
+                }
+                c.commit_row();
+            }
+            (ColumnBuilder::Map(c), TableDataType::Map(inner), XmlValue::Element(node))
+                if !node.children.is_empty() =>
+            {
+                let TableDataType::Tuple { fields_type, .. } = inner.as_ref() else {
+                    unreachable!("the inner type of map must be tuple");
+                };
+                let kv = c.builder.as_tuple_mut().unwrap();
+                for (name, child) in &node.children {
+                    self.text_decoder.read_field(&mut kv[0], name.as_bytes())?;
+                    self.read_value(&mut kv[1], &fields_type[1], &XmlValue::Element(child))?;
+                }
+                c.commit_row();
+            }
+            // scalars and nested values in text form
+            (column, _, _) => {
+                self.text_decoder
+                    .read_field(column, value.text().as_bytes())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl RowDecoder for XmlDecoder {
+    fn add(
+        &self,
+        state: &mut BlockBuilderState,
+        batch: RowBatchWithPosition,
+    ) -> Result<Vec<DataBlock>> {
+        let columns = &mut state.mutable_columns;
+        let data = batch.data.into_xml().unwrap();
+        for (row_id, row) in data.iter().enumerate() {
+            if let Err(e) = self.read_row(row, columns) {
+                self.load_context.error_handler.on_error(
+                    e,
+                    Some((columns, state.num_rows)),
+                    &mut state.file_status,
+                    &batch.start_pos.path,
+                    batch.start_pos.rows + row_id,
+                    Some(row),
+                )?
+            } else {
+                state.num_rows += 1;
+                state.file_status.num_rows_loaded += 1;
+            }
+        }
+        Ok(vec![])
+    }
+}
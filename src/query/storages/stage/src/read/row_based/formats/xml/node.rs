@@ -0,0 +1,264 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use quick_xml::events::BytesStart;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+/// An element of the row, namespace prefixes are dropped from the names.
+#[derive(Debug, Default, PartialEq)]
+pub struct XmlNode {
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<(String, XmlNode)>,
+    pub text: String,
+}
+
+pub enum XmlValue<'a> {
+    Attribute(&'a str),
+    Element(&'a XmlNode),
+}
+
+impl XmlValue<'_> {
+    pub fn is_empty(&self) -> bool {
+        match self {
+            XmlValue::Attribute(_) => false,
+            XmlValue::Element(node) => node.is_empty(),
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        match self {
+            XmlValue::Attribute(value) => value,
+            XmlValue::Element(node) => node.text.trim(),
+        }
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            XmlValue::Attribute(value) => serde_json::Value::String(value.to_string()),
+            XmlValue::Element(node) => node.to_json(),
+        }
+    }
+}
+
+impl Display for XmlValue<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            XmlValue::Attribute(value) => write!(f, "{value}"),
+            XmlValue::Element(node) => write!(f, "{}", node.to_json()),
+        }
+    }
+}
+
+impl XmlNode {
+    /// Parse a complete row element.
+    pub fn parse(data: &[u8]) -> Result<XmlNode> {
+        let mut reader = Reader::from_reader(data);
+        let mut stack: Vec<XmlNode> = vec![];
+        loop {
+            let event = reader
+                .read_event()
+                .map_err(|e| ErrorCode::BadBytes(format!("invalid xml: {e}")))?;
+            let (name, node) = match event {
+                Event::Start(e) => {
+                    stack.push(Self::from_start(&e)?);
+                    continue;
+                }
+                Event::Empty(e) => (local_name(&e)?, Self::from_start(&e)?),
+                Event::End(e) => {
+                    let name = std::str::from_utf8(e.local_name().as_ref())
+                        .map_err(|e| ErrorCode::BadBytes(format!("invalid xml: {e}")))?
+                        .to_string();
+                    let node = stack
+                        .pop()
+                        .ok_or_else(|| ErrorCode::BadBytes("invalid xml: unexpected end tag"))?;
+                    (name, node)
+                }
+                Event::Text(e) => {
+                    if let Some(node) = stack.last_mut() {
+                        let text = e
+                            .unescape()
+                            .map_err(|e| ErrorCode::BadBytes(format!("invalid xml: {e}")))?;
+                        node.text.push_str(&text);
+                    }
+                    continue;
+                }
+                Event::CData(e) => {
+                    if let Some(node) = stack.last_mut() {
+                        let text = std::str::from_utf8(&e)
+                            .map_err(|e| ErrorCode::BadBytes(format!("invalid xml: {e}")))?;
+                        node.text.push_str(text);
+                    }
+                    continue;
+                }
+                Event::Eof => {
+                    return Err(ErrorCode::BadBytes("invalid xml: unexpected end of row"));
+                }
+                _ => continue,
+            };
+            match stack.last_mut() {
+                Some(parent) => parent.children.push((name, node)),
+                None => return Ok(node),
+            }
+        }
+    }
+
+    fn from_start(e: &BytesStart) -> Result<XmlNode> {
+        let mut attributes = vec![];
+        for attr in e.attributes() {
+            let attr = attr.map_err(|e| ErrorCode::BadBytes(format!("invalid xml: {e}")))?;
+            let key = std::str::from_utf8(attr.key.local_name().as_ref())
+                .map_err(|e| ErrorCode::BadBytes(format!("invalid xml: {e}")))?
+                .to_string();
+            let value = attr
+                .unescape_value()
+                .map_err(|e| ErrorCode::BadBytes(format!("invalid xml: {e}")))?;
+            attributes.push((key, value.to_string()));
+        }
+        Ok(XmlNode {
+            attributes,
+            ..Default::default()
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.attributes.is_empty() && self.children.is_empty() && self.text.trim().is_empty()
+    }
+
+    pub fn child(&self, name: &str, case_sensitive: bool) -> Option<&XmlNode> {
+        self.children
+            .iter()
+            .find(|(n, _)| name_eq(n, name, case_sensitive))
+            .map(|(_, node)| node)
+    }
+
+    pub fn attribute(&self, name: &str, case_sensitive: bool) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(n, _)| name_eq(n, name, case_sensitive))
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Find the value of a column by its path relative to this element, e.g. `name`,
+    /// `address/city` or `@id`. Child elements take precedence over attributes with the
+    /// same name, unless the last segment is prefixed with `@`.
+    pub fn get(&self, path: &str, case_sensitive: bool) -> Option<XmlValue<'_>> {
+        let mut node = self;
+        let mut segments = path.split('/').peekable();
+        while let Some(segment) = segments.next() {
+            if segments.peek().is_none() {
+                if let Some(name) = segment.strip_prefix('@') {
+                    return node
+                        .attribute(name, case_sensitive)
+                        .map(XmlValue::Attribute);
+                }
+                return match node.child(segment, case_sensitive) {
+                    Some(child) => Some(XmlValue::Element(child)),
+                    None => node
+                        .attribute(segment, case_sensitive)
+                        .map(XmlValue::Attribute),
+                };
+            }
+            node = node.child(segment, case_sensitive)?;
+        }
+        None
+    }
+
+    /// Convert to json, attributes are prefixed by `@`, repeated children become an array,
+    /// and the text of a element with attributes or children is kept as `#text`.
+    pub fn to_json(&self) -> serde_json::Value {
+        if self.attributes.is_empty() && self.children.is_empty() {
+            return serde_json::Value::String(self.text.trim().to_string());
+        }
+        let mut object = serde_json::Map::new();
+        for (name, value) in &self.attributes {
+            object.insert(format!("@{name}"), serde_json::Value::String(value.clone()));
+        }
+        for (name, child) in &self.children {
+            let value = child.to_json();
+            match object.get_mut(name) {
+                Some(serde_json::Value::Array(values)) => values.push(value),
+                Some(old) => *old = serde_json::Value::Array(vec![old.take(), value]),
+                None => {
+                    object.insert(name.clone(), value);
+                }
+            }
+        }
+        let text = self.text.trim();
+        if !text.is_empty() {
+            object.insert(
+                "#text".to_string(),
+                serde_json::Value::String(text.to_string()),
+            );
+        }
+        serde_json::Value::Object(object)
+    }
+}
+
+fn local_name(e: &BytesStart) -> Result<String> {
+    Ok(std::str::from_utf8(e.local_name().as_ref())
+        .map_err(|e| ErrorCode::BadBytes(format!("invalid xml: {e}")))?
+        .to_string())
+}
+
+fn name_eq(a: &str, b: &str, case_sensitive: bool) -> bool {
+    if case_sensitive {
+        a == b
+    } else {
+        a.eq_ignore_ascii_case(b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_xml_row() -> Result<()> {
+        let row = br#"<row id="1"><name>a &amp; b</name><tags><tag>x</tag><tag>y</tag></tags><note><![CDATA[<raw>]]></note><empty/></row>"#;
+        let node = XmlNode::parse(row)?;
+        assert_eq!(node.attribute("ID", false), Some("1"));
+        assert_eq!(node.attribute("ID", true), None);
+        assert!(matches!(node.get("name", true), Some(XmlValue::Element(n)) if n.text == "a & b"));
+        assert!(matches!(
+            node.get("@id", true),
+            Some(XmlValue::Attribute("1"))
+        ));
+        assert!(matches!(node.get("tags/tag", true), Some(XmlValue::Element(n)) if n.text == "x"));
+        assert!(matches!(node.get("note", true), Some(XmlValue::Element(n)) if n.text == "<raw>"));
+        assert!(matches!(node.get("empty", true), Some(XmlValue::Element(n)) if n.is_empty()));
+        assert!(node.get("missing", true).is_none());
+
+        let json = node.to_json();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "@id": "1",
+                "name": "a & b",
+                "tags": {"tag": ["x", "y"]},
+                "note": "<raw>",
+                "empty": "",
+            })
+        );
+
+        assert!(XmlNode::parse(b"<row><a></b></row>").is_err());
+        assert!(XmlNode::parse(b"<row><a>").is_err());
+        Ok(())
+    }
+}
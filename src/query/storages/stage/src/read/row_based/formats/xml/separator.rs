@@ -0,0 +1,290 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_storage::FileStatus;
+
+use crate::read::row_based::batch::BytesBatch;
+use crate::read::row_based::batch::Position;
+use crate::read::row_based::batch::RowBatch;
+use crate::read::row_based::batch::RowBatchWithPosition;
+use crate::read::row_based::batch::XmlRowBatch;
+use crate::read::row_based::format::SeparatorState;
+
+#[derive(Debug, PartialEq)]
+enum TagKind {
+    Start,
+    End,
+    Empty,
+    // comment, CDATA, processing instruction or doctype
+    Other,
+}
+
+struct Tag {
+    kind: TagKind,
+    name_start: usize,
+    name_end: usize,
+    end: usize,
+}
+
+/// Cut the xml document into the elements with the row tag.
+///
+/// Only the markups are scanned here, the elements are parsed in the decoder. The content outside
+/// of the rows (the root element, declaration and so on) is skipped.
+pub struct XmlRowSeparator {
+    row_tag: Vec<u8>,
+    // remain from last read batch, starts with an incomplete row or markup
+    buf: Vec<u8>,
+    pos: Position,
+}
+
+impl SeparatorState for XmlRowSeparator {
+    fn append(&mut self, batch: BytesBatch) -> Result<(Vec<RowBatchWithPosition>, FileStatus)> {
+        self.separate(batch)
+    }
+}
+
+impl XmlRowSeparator {
+    pub fn try_create(row_tag: &str, path: &str) -> Result<Self> {
+        if row_tag.is_empty() {
+            return Err(ErrorCode::BadArguments(
+                "ROW_TAG of XML format can not be empty",
+            ));
+        }
+        Ok(Self {
+            row_tag: row_tag.as_bytes().to_vec(),
+            buf: vec![],
+            pos: Position::new(path.to_string()),
+        })
+    }
+
+    fn is_row_tag(&self, name: &[u8]) -> bool {
+        if name == self.row_tag {
+            return true;
+        }
+        // ignore the namespace prefix
+        match name.iter().position(|c| *c == b':') {
+            Some(p) => name[p + 1..] == self.row_tag,
+            None => false,
+        }
+    }
+
+    fn separate(&mut self, batch: BytesBatch) -> Result<(Vec<RowBatchWithPosition>, FileStatus)> {
+        self.buf.extend_from_slice(&batch.data);
+
+        let mut rows = XmlRowBatch::default();
+        let mut cursor = 0;
+        let mut row_start = None;
+        let mut depth = 0;
+        let mut incomplete = None;
+        while let Some(i) = self.buf[cursor..].iter().position(|c| *c == b'<') {
+            let start = cursor + i;
+            let Some(tag) = scan_tag(&self.buf, start) else {
+                incomplete = Some(start);
+                break;
+            };
+            cursor = tag.end;
+            if tag.kind == TagKind::Other
+                || !self.is_row_tag(&self.buf[tag.name_start..tag.name_end])
+            {
+                continue;
+            }
+            match (row_start, tag.kind) {
+                (None, TagKind::Empty) => {
+                    rows.data.extend_from_slice(&self.buf[start..tag.end]);
+                    rows.row_ends.push(rows.data.len());
+                }
+                (None, TagKind::Start) => {
+                    row_start = Some(start);
+                    depth = 1;
+                }
+                (Some(_), TagKind::Start) => depth += 1,
+                (Some(s), TagKind::End) => {
+                    depth -= 1;
+                    if depth == 0 {
+                        rows.data.extend_from_slice(&self.buf[s..tag.end]);
+                        rows.row_ends.push(rows.data.len());
+                        row_start = None;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let keep_from = row_start.or(incomplete).unwrap_or(self.buf.len());
+        if batch.is_eof && keep_from < self.buf.len() {
+            return Err(ErrorCode::BadBytes(format!(
+                "Invalid xml file '{}': unexpected end of file at offset {}",
+                self.pos.path,
+                self.pos.offset + keep_from
+            )));
+        }
+        self.buf.drain(..keep_from);
+        self.pos.offset += keep_from;
+        if rows.row_ends.is_empty() {
+            return Ok((vec![], FileStatus::default()));
+        }
+        let out_pos = self.pos.clone();
+        self.pos.rows += rows.rows();
+        Ok((
+            vec![RowBatchWithPosition::new(RowBatch::Xml(rows), out_pos)],
+            FileStatus::default(),
+        ))
+    }
+}
+
+/// Scan the markup starts at `buf[start]` which is `<`, returns `None` if it is incomplete.
+fn scan_tag(buf: &[u8], start: usize) -> Option<Tag> {
+    let rest = &buf[start..];
+    let other = |prefix: &[u8], terminator: &[u8]| {
+        find(&rest[prefix.len()..], terminator).map(|p| Tag {
+            kind: TagKind::Other,
+            name_start: start,
+            name_end: start,
+            end: start + prefix.len() + p + terminator.len(),
+        })
+    };
+    for (prefix, terminator) in [
+        (&b"<!--"[..], &b"-->"[..]),
+        (&b"<![CDATA["[..], &b"]]>"[..]),
+        (&b"<?"[..], &b"?>"[..]),
+    ] {
+        if rest.starts_with(prefix) {
+            return other(prefix, terminator);
+        }
+        if prefix.starts_with(rest) {
+            // can not tell which kind of markup it is yet
+            return None;
+        }
+    }
+    if rest.starts_with(b"<!") {
+        // doctype, may contain an internal subset in brackets
+        let mut in_brackets = false;
+        for (i, c) in rest.iter().enumerate() {
+            match c {
+                b'[' => in_brackets = true,
+                b']' => in_brackets = false,
+                b'>' if !in_brackets => {
+                    return Some(Tag {
+                        kind: TagKind::Other,
+                        name_start: start,
+                        name_end: start,
+                        end: start + i + 1,
+                    });
+                }
+                _ => {}
+            }
+        }
+        return None;
+    }
+
+    let is_end = rest.get(1) == Some(&b'/');
+    let name_start = if is_end { 2 } else { 1 };
+    let name_len = rest[name_start..]
+        .iter()
+        .position(|c| c.is_ascii_whitespace() || *c == b'/' || *c == b'>')?;
+    // attribute values may contain `>`
+    let mut quote = None;
+    for (i, c) in rest.iter().enumerate().skip(name_start + name_len) {
+        match (quote, c) {
+            (None, b'"' | b'\'') => quote = Some(*c),
+            (Some(q), c) if q == *c => quote = None,
+            (None, b'>') => {
+                let kind = if is_end {
+                    TagKind::End
+                } else if rest[i - 1] == b'/' {
+                    TagKind::Empty
+                } else {
+                    TagKind::Start
+                };
+                return Some(Tag {
+                    kind,
+                    name_start: start + name_start,
+                    name_end: start + name_start + name_len,
+                    end: start + i + 1,
+                });
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bytes_batch(data: &[u8], is_eof: bool) -> BytesBatch {
+        BytesBatch {
+            data: data.to_vec(),
+            path: "test".to_string(),
+            offset: 0,
+            is_eof,
+        }
+    }
+
+    fn rows_of(batches: &[RowBatchWithPosition]) -> Vec<String> {
+        batches
+            .iter()
+            .flat_map(|b| b.data.as_xml().unwrap().iter())
+            .map(|r| String::from_utf8(r.to_vec()).unwrap())
+            .collect()
+    }
+
+    const DOC: &[u8] = br#"<?xml version="1.0"?>
+<!DOCTYPE rows [<!ENTITY x "y">]>
+<rows>
+  <!-- <row>commented</row> -->
+  <row id="1"><a>1</a><row>nested</row></row>
+  <row id="a>b"/>
+  <ns:row><a><![CDATA[</row>]]></a></ns:row>
+  <rowx>not a row</rowx>
+</rows>"#;
+
+    #[test]
+    fn test_xml_row_separator() -> Result<()> {
+        let expected = vec![
+            r#"<row id="1"><a>1</a><row>nested</row></row>"#,
+            r#"<row id="a>b"/>"#,
+            r#"<ns:row><a><![CDATA[</row>]]></a></ns:row>"#,
+        ];
+
+        let mut sep = XmlRowSeparator::try_create("row", "test")?;
+        let (batches, _) = sep.append(bytes_batch(DOC, true))?;
+        assert_eq!(batches.len(), 1);
+        assert_eq!(rows_of(&batches), expected);
+
+        // cut at every position
+        for i in 0..DOC.len() {
+            let mut sep = XmlRowSeparator::try_create("row", "test")?;
+            let (mut batches, _) = sep.append(bytes_batch(&DOC[..i], false))?;
+            let (more, _) = sep.append(bytes_batch(&DOC[i..], true))?;
+            if let (Some(first), Some(second)) = (batches.first(), more.first()) {
+                assert_eq!(second.start_pos.rows, first.data.rows());
+            }
+            batches.extend(more);
+            assert_eq!(rows_of(&batches), expected, "cut at {i}");
+        }
+
+        // truncated file
+        let mut sep = XmlRowSeparator::try_create("row", "test")?;
+        assert!(sep.append(bytes_batch(&DOC[..100], true)).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,268 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+
+use databend_common_compress::CompressAlgorithm;
+use databend_common_compress::DecompressDecoder;
+use databend_common_compress::DecompressState;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_storage::parse_zip_local_header;
+use databend_common_storage::ArchiveFormat;
+use databend_common_storage::ArchiveMember;
+
+use crate::read::row_based::batch::BytesBatch;
+
+struct MemberState {
+    path: String,
+    /// Bytes of the member data not consumed yet.
+    remaining: u64,
+    /// Inflater of deflated zip member.
+    decoder: Option<DecompressDecoder>,
+    offset: usize,
+}
+
+/// Unpack the selected members from the bytes of an archive read in sequence.
+///
+/// The members are split into [`BytesBatch`]es of their own logical path, as if
+/// they are read from separate files.
+pub struct ArchiveUnpacker {
+    archive_path: String,
+    format: ArchiveFormat,
+    /// Decoder of the tar.gz stream.
+    gzip: Option<DecompressDecoder>,
+    /// Members to unpack, ordered by offset.
+    members: VecDeque<ArchiveMember>,
+    current: Option<MemberState>,
+    buf: Vec<u8>,
+    /// Offset of `buf[0]` in the archive (the decompressed stream for tar.gz).
+    pos: u64,
+}
+
+impl ArchiveUnpacker {
+    pub fn create(archive_path: String, mut members: Vec<ArchiveMember>) -> Self {
+        members.sort_by_key(|m| m.offset);
+        let format = members
+            .first()
+            .map(|m| m.format)
+            .unwrap_or(ArchiveFormat::Zip);
+        let gzip = match format {
+            ArchiveFormat::TarGz => Some(DecompressDecoder::new(CompressAlgorithm::Gzip)),
+            _ => None,
+        };
+        Self {
+            archive_path,
+            format,
+            gzip,
+            members: members.into(),
+            current: None,
+            buf: vec![],
+            pos: 0,
+        }
+    }
+
+    pub fn unpack(&mut self, data: &[u8], is_eof: bool) -> Result<Vec<BytesBatch>> {
+        match &mut self.gzip {
+            Some(decoder) => {
+                let mut data = decoder.decompress_batch(data)?;
+                if is_eof {
+                    data.extend(decoder.decompress_batch(&[])?);
+                }
+                self.buf.extend_from_slice(&data);
+            }
+            None => self.buf.extend_from_slice(data),
+        }
+
+        let mut batches = vec![];
+        let mut consumed = 0;
+        loop {
+            if let Some(member) = &mut self.current {
+                let n = member.remaining.min((self.buf.len() - consumed) as u64) as usize;
+                if n == 0 && member.remaining > 0 {
+                    // An empty input means the end of data to the decoder.
+                    break;
+                }
+                let chunk = &self.buf[consumed..consumed + n];
+                consumed += n;
+                member.remaining -= n as u64;
+                let is_member_eof = member.remaining == 0;
+                let data = match &mut member.decoder {
+                    Some(decoder) => {
+                        let mut data = decoder.decompress_batch(chunk)?;
+                        if is_member_eof {
+                            data.extend(decoder.decompress_batch(&[])?);
+                            let state = decoder.state();
+                            if !matches!(state, DecompressState::Done) {
+                                return Err(ErrorCode::BadBytes(format!(
+                                    "decompressor state is {:?} after decompressing all data of {}",
+                                    state, member.path
+                                )));
+                            }
+                        }
+                        data
+                    }
+                    None => chunk.to_vec(),
+                };
+                if !data.is_empty() || is_member_eof {
+                    let len = data.len();
+                    batches.push(BytesBatch {
+                        data,
+                        path: member.path.clone(),
+                        offset: member.offset,
+                        is_eof: is_member_eof,
+                    });
+                    member.offset += len;
+                }
+                if !is_member_eof {
+                    break;
+                }
+                self.current = None;
+            }
+
+            let Some(next) = self.members.front() else {
+                // The rest of the archive is not needed.
+                consumed = self.buf.len();
+                break;
+            };
+            let pos = self.pos + consumed as u64;
+            if pos < next.offset {
+                let skip = (next.offset - pos).min((self.buf.len() - consumed) as u64);
+                consumed += skip as usize;
+                if pos + skip < next.offset {
+                    break;
+                }
+            } else if pos > next.offset {
+                return Err(ErrorCode::BadBytes(format!(
+                    "member {} overlaps with others in archive {}",
+                    next.name, self.archive_path
+                )));
+            }
+
+            let decoder = if self.format == ArchiveFormat::Zip {
+                let Some(header_len) = parse_zip_local_header(&self.buf[consumed..])? else {
+                    break;
+                };
+                consumed += header_len;
+                match next.method {
+                    0 => None,
+                    8 => Some(DecompressDecoder::new(CompressAlgorithm::Deflate)),
+                    method => {
+                        return Err(ErrorCode::BadBytes(format!(
+                            "compression method {method} of member {} is not supported",
+                            next.name
+                        )));
+                    }
+                }
+            } else {
+                None
+            };
+            let next = self.members.pop_front().unwrap();
+            self.current = Some(MemberState {
+                path: next.path(),
+                remaining: next.compressed_size,
+                decoder,
+                offset: 0,
+            });
+        }
+        self.buf.drain(..consumed);
+        self.pos += consumed as u64;
+
+        if is_eof && (self.current.is_some() || !self.members.is_empty()) {
+            return Err(ErrorCode::BadBytes(format!(
+                "unexpected end of archive {}",
+                self.archive_path
+            )));
+        }
+        Ok(batches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member(name: &str, offset: u64, compressed_size: u64, method: u16) -> ArchiveMember {
+        ArchiveMember {
+            archive_path: "a.zip".to_string(),
+            archive_size: 0,
+            format: ArchiveFormat::Zip,
+            name: name.to_string(),
+            offset,
+            compressed_size,
+            method,
+        }
+    }
+
+    fn zip_local_header(name: &str, size: usize) -> Vec<u8> {
+        let mut header = vec![0x50, 0x4b, 0x03, 0x04];
+        header.extend([0u8; 14]);
+        header.extend((size as u32).to_le_bytes());
+        header.extend((size as u32).to_le_bytes());
+        header.extend((name.len() as u16).to_le_bytes());
+        header.extend([0u8; 2]);
+        header.extend(name.as_bytes());
+        header
+    }
+
+    #[test]
+    fn test_unpack_zip_stored() -> Result<()> {
+        let mut zip = vec![];
+        let mut members = vec![];
+        for (name, data) in [("a.csv", "1,2\n3,4\n"), ("b.csv", ""), ("c.csv", "5,6\n")] {
+            members.push(member(name, zip.len() as u64, data.len() as u64, 0));
+            zip.extend(zip_local_header(name, data.len()));
+            zip.extend(data.as_bytes());
+        }
+        // central directory is ignored.
+        zip.extend([0x50, 0x4b, 0x01, 0x02, 0, 0]);
+
+        // skip b.csv
+        members.remove(1);
+        let mut unpacker = ArchiveUnpacker::create("a.zip".to_string(), members);
+        let mut batches = vec![];
+        let chunks = zip.chunks(7).collect::<Vec<_>>();
+        for (i, chunk) in chunks.iter().enumerate() {
+            batches.extend(unpacker.unpack(chunk, i + 1 == chunks.len())?);
+        }
+
+        let mut files: Vec<(String, Vec<u8>)> = vec![];
+        for batch in batches {
+            match files.last_mut() {
+                Some((path, data)) if path == &batch.path => {
+                    assert_eq!(batch.offset, data.len());
+                    data.extend(batch.data)
+                }
+                _ => {
+                    assert_eq!(batch.offset, 0);
+                    files.push((batch.path.clone(), batch.data))
+                }
+            }
+        }
+        assert_eq!(files, vec![
+            ("a.zip/a.csv".to_string(), b"1,2\n3,4\n".to_vec()),
+            ("a.zip/c.csv".to_string(), b"5,6\n".to_vec()),
+        ]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_unpack_truncated() {
+        let mut zip = zip_local_header("a.csv", 4);
+        zip.extend(b"1,2");
+        let mut unpacker =
+            ArchiveUnpacker::create("a.zip".to_string(), vec![member("a.csv", 0, 4, 0)]);
+        assert!(unpacker.unpack(&zip, true).is_err());
+    }
+}
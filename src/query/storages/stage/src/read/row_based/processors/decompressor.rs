@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use databend_common_compress::CompressAlgorithm;
@@ -22,30 +23,49 @@ use databend_common_exception::Result;
 use databend_common_expression::BlockMetaInfoDowncast;
 use databend_common_expression::DataBlock;
 use databend_common_pipeline_transforms::processors::AccumulatingTransform;
+use databend_common_storage::ArchiveMember;
 
+use super::archive::ArchiveUnpacker;
 use crate::read::load_context::LoadContext;
 use crate::read::row_based::batch::BytesBatch;
 
+/// The members to load of each archive.
+pub type ArchiveMembers = HashMap<String, Vec<ArchiveMember>>;
+
 pub struct Decompressor {
     #[allow(dead_code)]
     ctx: Arc<LoadContext>,
     algo: Option<CompressAlgorithm>,
     decompressor: Option<(DecompressDecoder, usize)>,
+    archives: Arc<ArchiveMembers>,
+    unpacker: Option<ArchiveUnpacker>,
     path: Option<String>,
 }
 
 impl Decompressor {
-    pub fn try_create(ctx: Arc<LoadContext>, algo: Option<CompressAlgorithm>) -> Result<Self> {
+    pub fn try_create(
+        ctx: Arc<LoadContext>,
+        algo: Option<CompressAlgorithm>,
+        archives: Arc<ArchiveMembers>,
+    ) -> Result<Self> {
         Ok(Decompressor {
             ctx,
             algo,
             path: None,
             decompressor: None,
+            archives,
+            unpacker: None,
         })
     }
 
     fn new_file(&mut self, path: String) {
         assert!(self.decompressor.is_none());
+        assert!(self.unpacker.is_none());
+        if let Some(members) = self.archives.get(&path) {
+            self.unpacker = Some(ArchiveUnpacker::create(path.clone(), members.clone()));
+            self.path = Some(path);
+            return;
+        }
         let algo = if let Some(algo) = &self.algo {
             Some(algo.to_owned())
         } else {
@@ -79,6 +99,17 @@ impl AccumulatingTransform for Decompressor {
             }
         }
 
+        if let Some(unpacker) = &mut self.unpacker {
+            let batches = unpacker.unpack(&batch.data, batch.is_eof)?;
+            if batch.is_eof {
+                self.unpacker = None;
+            }
+            return Ok(batches
+                .into_iter()
+                .map(|b| DataBlock::empty_with_meta(Box::new(b)))
+                .collect());
+        }
+
         if let Some((de, offset)) = &mut self.decompressor {
             let mut data = de.decompress_batch(&batch.data)?;
             if batch.is_eof {
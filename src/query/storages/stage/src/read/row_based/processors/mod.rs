@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod archive;
 mod block_builder;
 mod decompressor;
 mod reader;
@@ -19,6 +20,7 @@ mod separator;
 
 pub use block_builder::BlockBuilder;
 pub use block_builder::BlockBuilderState;
+pub use decompressor::ArchiveMembers;
 pub use decompressor::Decompressor;
 pub use reader::BytesReader;
 pub use separator::Separator;
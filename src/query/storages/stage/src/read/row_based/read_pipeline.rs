@@ -28,10 +28,12 @@ use databend_common_pipeline_sources::PrefetchAsyncSourcer;
 use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
 use databend_common_settings::Settings;
 use databend_common_storage::init_stage_operator;
+use databend_storages_common_stage::SingleFilePartition;
 
 use crate::compression::get_compression_alg_copy;
 use crate::read::load_context::LoadContext;
 use crate::read::row_based::format::create_row_based_file_format;
+use crate::read::row_based::processors::ArchiveMembers;
 use crate::read::row_based::processors::BlockBuilder;
 use crate::read::row_based::processors::BytesReader;
 use crate::read::row_based::processors::Decompressor;
@@ -64,7 +66,7 @@ impl RowBasedReadPipelineBuilder<'_> {
 
     // processors:
     // 1. BytesReader
-    // 2. (optional) Decompressor
+    // 2. (optional) Decompressor: also unpacks the members of archives
     // 3. Separator: cut file into RowBatches(bytes with row/field ends)
     // 4. (resize to threads): so row batches can be processed in parallel, regardless of the file it from.
     // 5. BlockBuilder: the slow part most of the time
@@ -116,8 +118,16 @@ impl RowBasedReadPipelineBuilder<'_> {
             StageFileCompression::None => {}
             compression => {
                 let algo = get_compression_alg_copy(compression, "")?;
+                let mut archives = ArchiveMembers::new();
+                for part in plan.parts.partitions.iter() {
+                    let part = SingleFilePartition::from_part(part)?;
+                    if !part.archive_members.is_empty() {
+                        archives.insert(part.path.clone(), part.archive_members.clone());
+                    }
+                }
+                let archives = Arc::new(archives);
                 pipeline.try_add_accumulating_transformer(|| {
-                    Decompressor::try_create(load_ctx.clone(), algo)
+                    Decompressor::try_create(load_ctx.clone(), algo, archives.clone())
                 })?;
             }
         }
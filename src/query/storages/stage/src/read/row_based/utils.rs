@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_exception::ErrorCode;
 use databend_common_expression::TableSchemaRef;
 use databend_common_storage::FileParseError;
 
@@ -26,14 +27,15 @@ pub fn truncate_column_data(s: String) -> String {
 pub fn get_decode_error_by_pos(
     column_index: usize,
     schema: &TableSchemaRef,
-    decode_error: &str,
+    decode_error: &ErrorCode,
     column_data: &[u8],
 ) -> FileParseError {
     let field = &schema.fields()[column_index];
     let column_data = String::from_utf8_lossy(column_data).to_string();
     FileParseError::ColumnDecodeError {
         column_index,
-        decode_error: decode_error.to_string(),
+        decode_error: decode_error.message(),
+        error_code: decode_error.code(),
         column_name: field.name().to_string(),
         column_type: field.data_type().to_string(),
         column_data: truncate_column_data(column_data),
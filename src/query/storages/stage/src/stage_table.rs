@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use databend_common_catalog::plan::DataSourceInfo;
@@ -104,13 +106,32 @@ impl StageTable {
             pruning_stats: Default::default(),
         };
 
-        let partitions = files
+        // The members of an archive are read from one partition of the archive.
+        let mut parts: Vec<SingleFilePartition> = Vec::with_capacity(files.len());
+        let mut archives = HashMap::new();
+        for file in files {
+            match file.archive_member {
+                Some(member) => match archives.entry(member.archive_path.clone()) {
+                    Entry::Occupied(entry) => parts[*entry.get()].archive_members.push(member),
+                    Entry::Vacant(entry) => {
+                        entry.insert(parts.len());
+                        parts.push(SingleFilePartition {
+                            path: member.archive_path.clone(),
+                            size: member.archive_size as usize,
+                            archive_members: vec![member],
+                        });
+                    }
+                },
+                None => parts.push(SingleFilePartition {
+                    path: file.path,
+                    size: file.size as usize,
+                    archive_members: vec![],
+                }),
+            }
+        }
+        let partitions = parts
             .into_iter()
-            .map(|v| {
-                let part = SingleFilePartition {
-                    path: v.path.clone(),
-                    size: v.size as usize,
-                };
+            .map(|part| {
                 let part_info: Box<dyn PartInfo> = Box::new(part);
                 Arc::new(part_info)
             })
@@ -154,9 +175,11 @@ impl Table for StageTable {
             FileFormatParams::Orc(_) => {
                 OrcTableForCopy::do_read_partitions(stage_table_info, ctx, _push_downs).await
             }
-            FileFormatParams::Csv(_) | FileFormatParams::NdJson(_) | FileFormatParams::Tsv(_) => {
-                self.read_partitions_simple(ctx, stage_table_info).await
-            }
+            FileFormatParams::Csv(_)
+            | FileFormatParams::NdJson(_)
+            | FileFormatParams::Tsv(_)
+            | FileFormatParams::Avro(_)
+            | FileFormatParams::Xml(_) => self.read_partitions_simple(ctx, stage_table_info).await,
             _ => unreachable!(
                 "unexpected format {} in StageTable::read_partition",
                 stage_table_info.stage_info.file_format_params
@@ -188,7 +211,11 @@ impl Table for StageTable {
             FileFormatParams::Orc(_) => {
                 OrcTableForCopy::do_read_data(ctx, plan, pipeline, _put_cache)
             }
-            FileFormatParams::Csv(_) | FileFormatParams::NdJson(_) | FileFormatParams::Tsv(_) => {
+            FileFormatParams::Csv(_)
+            | FileFormatParams::NdJson(_)
+            | FileFormatParams::Tsv(_)
+            | FileFormatParams::Avro(_)
+            | FileFormatParams::Xml(_) => {
                 let compact_threshold = ctx.get_read_block_thresholds();
                 RowBasedReadPipelineBuilder {
                     stage_table_info,
@@ -80,7 +80,7 @@ impl AsyncSystemTable for IndexesTable {
         for table in inverted_index_tables {
             for (name, index) in &table.meta.indexes {
                 names.push(name.clone());
-                types.push("INVERTED".to_string());
+                types.push(index.index_type.to_string());
                 originals.push("".to_string());
 
                 let schema = table.schema();
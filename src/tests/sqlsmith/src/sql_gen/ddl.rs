@@ -312,6 +312,15 @@ fn gen_default_expr(type_name: &TypeName) -> Expr {
             span: None,
             value: Literal::String("1 month 1 hour".to_string()),
         },
+        TypeName::Vector { dimension, .. } => Expr::Array {
+            span: None,
+            exprs: (0..*dimension)
+                .map(|_| Expr::Literal {
+                    span: None,
+                    value: Literal::Float64(0.0),
+                })
+                .collect(),
+        },
         TypeName::Nullable(_) => Expr::Literal {
             span: None,
             value: Literal::Null,
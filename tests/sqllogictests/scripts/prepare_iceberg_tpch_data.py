@@ -167,4 +167,13 @@ for table_name, (schema, file_path) in tables.items():
     df.write.format("iceberg").mode("overwrite").save(full_table_name)
     print(f"table {full_table_name} has been created")
 
+# an empty partitioned table for the write tests
+spark.sql(
+    """
+    CREATE OR REPLACE TABLE iceberg.tpch.t_partitioned (a int, b string, c date)
+    USING iceberg
+    PARTITIONED BY (b, days(c));
+    """
+)
+
 spark.stop()
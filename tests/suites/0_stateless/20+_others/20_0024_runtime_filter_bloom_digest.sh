@@ -0,0 +1,40 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+cat <<EOF |  $BENDSQL_CLIENT_CONNECT
+create or replace database db_20_0024;
+use db_20_0024;
+create table probe(id int, user_id int null, name string);
+insert into probe values (1, 100, 'a'), (2, 900, 'b'), (3, null, 'c');
+insert into probe values (4, 150, 'd'), (5, 850, 'e');
+insert into probe values (6, 120, 'f'), (7, 880, 'g'), (8, 500, 'h');
+create table build(user_id int, tag string);
+insert into build values (500, 'x'), (850, 'y'), (null, 'z');
+EOF
+
+# the min/max of user_id overlap between the blocks, only the first block is pruned by the bloom index digests
+echo "explain analyze select p.id from db_20_0024.probe p join db_20_0024.build b on p.user_id = b.user_id" \
+  | $BENDSQL_CLIENT_CONNECT | grep -o "parts pruned by runtime filter: [0-9]*"
+
+echo "expects no parts pruned with enable_bloom_digest_runtime_filter = 0"
+cat <<EOF |  $BENDSQL_CLIENT_CONNECT | grep -c "parts pruned by runtime filter"
+set enable_bloom_digest_runtime_filter = 0;
+explain analyze select p.id from db_20_0024.probe p join db_20_0024.build b on p.user_id = b.user_id;
+EOF
+
+echo "expects no parts pruned of the blocks stored before the column is widened"
+cat <<EOF |  $BENDSQL_CLIENT_CONNECT
+use db_20_0024;
+create table probe_widen(id int, k int not null);
+insert into probe_widen values (1, 1), (2, 2);
+insert into probe_widen values (3, 3), (4, 4);
+alter table probe_widen modify column k bigint not null;
+create table build_widen(k bigint not null);
+insert into build_widen values (2), (5);
+EOF
+echo "explain analyze select p.id from db_20_0024.probe_widen p join db_20_0024.build_widen b on p.k = b.k" \
+  | $BENDSQL_CLIENT_CONNECT | grep -c "parts pruned by runtime filter"
+
+echo "drop database db_20_0024;" | $BENDSQL_CLIENT_CONNECT
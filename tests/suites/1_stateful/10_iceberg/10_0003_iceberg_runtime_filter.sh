@@ -0,0 +1,30 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+rm -rf /tmp/iceberg_fs_10_0003/
+
+stmt "drop catalog if exists ctl_10_0003;"
+stmt "create catalog ctl_10_0003 type=iceberg connection=(type='fs' warehouse='fs:///tmp/iceberg_fs_10_0003/');"
+stmt "create database ctl_10_0003.db;"
+stmt "create table ctl_10_0003.db.t(a int not null, b string);"
+# each insert writes a data file, with the bounds of the columns in the manifest
+stmt "insert into ctl_10_0003.db.t values (1, 'a'), (2, 'b');"
+stmt "insert into ctl_10_0003.db.t values (10, 'c'), (20, 'd');"
+stmt "insert into ctl_10_0003.db.t values (100, 'e'), (200, 'f');"
+stmt "drop table if exists default.iceberg_keys;"
+stmt "create table default.iceberg_keys(k int not null);"
+stmt "insert into default.iceberg_keys values (20);"
+
+# keep the iceberg table on the probe side of the join
+query "settings (disable_join_reorder = 1) select t.a, t.b from ctl_10_0003.db.t t join default.iceberg_keys on t.a = iceberg_keys.k;"
+
+# the files out of the key domain are eliminated by the manifests and never opened
+echo "set disable_join_reorder = 1; explain analyze select t.b from ctl_10_0003.db.t t join default.iceberg_keys on t.a = iceberg_keys.k;" \
+  | $BENDSQL_CLIENT_CONNECT | grep -o "parts pruned by runtime filter: [0-9]*"
+
+stmt "drop table default.iceberg_keys;"
+stmt "drop table ctl_10_0003.db.t;"
+stmt "drop database ctl_10_0003.db;"
+stmt "drop catalog ctl_10_0003;"
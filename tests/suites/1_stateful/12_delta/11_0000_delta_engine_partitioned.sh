@@ -25,5 +25,9 @@ query "select count() from test_delta where p0 = 10 and p2 = 12;"
 
 query "select c5, p4 from test_delta where c1 - p0 = 11 order by c5;"
 
+## time travel
+query "select c1 from test_delta at (version => 3) order by c1;"
+query "select count() from test_delta at (timestamp => '2023-12-19 12:27:11'::timestamp);"
+
 stmt "drop table test_delta;"
 
@@ -0,0 +1,16 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+ROOT=$(realpath "$CURDIR"/../../../data/delta/deletion_vector/)
+
+stmt "drop table if exists test_delta;"
+
+echo ">>>> create table test_delta engine = delta location = 'fs://\${ROOT}/';"
+echo "create table test_delta engine = delta location = 'fs://${ROOT}/';" | $BENDSQL_CLIENT_CONNECT
+# one file deletes row 1 by an inline deletion vector, the other deletes row 0 and 2 by a deletion vector file
+query "select * from test_delta order by id;"
+query "select count(*) from test_delta;"
+query "select id from test_delta where t:a = 3 order by id;"
+stmt "drop table test_delta;"
@@ -0,0 +1,16 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+ROOT=$(realpath "$CURDIR"/../../../data/delta/column_mapping/)
+
+stmt "drop table if exists test_delta;"
+
+echo ">>>> create table test_delta engine = delta location = 'fs://\${ROOT}/';"
+echo "create table test_delta engine = delta location = 'fs://${ROOT}/';" | $BENDSQL_CLIENT_CONNECT
+# columns `key` and `info` and the fields `x` and `y` of `info` are stored as `id`, `t`, `A` and `B`
+query "select * from test_delta order by key;"
+query "select key, info:x, info:y from test_delta where key > 1 order by key;"
+query "select key from test_delta where info:x = 3 and info:y = 'c';"
+stmt "drop table test_delta;"
@@ -0,0 +1,24 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+ROOT=$(realpath "$CURDIR"/../../../data/delta/partitioned/)
+
+stmt "drop table if exists test_delta;"
+stmt "drop table if exists test_delta_dim;"
+
+echo ">>>> create table test_delta engine = delta location = 'fs://\${ROOT}/';"
+echo "create table test_delta engine = delta location = 'fs://${ROOT}/';" | $BENDSQL_CLIENT_CONNECT
+stmt "create table test_delta_dim(k int);"
+stmt "insert into test_delta_dim values (24), (44);"
+
+# keep the delta table on the probe side of the join
+query "settings (disable_join_reorder = 1) select d.c1, d.p4 from test_delta d join test_delta_dim on d.p4 = test_delta_dim.k order by d.c1;"
+
+# the partitions p4=14 and p4=34 are eliminated by the runtime filters before their files are read
+echo "set disable_join_reorder = 1; explain analyze select d.c1 from test_delta d join test_delta_dim on d.p4 = test_delta_dim.k;" \
+  | $BENDSQL_CLIENT_CONNECT | grep -o "parts pruned by runtime filter: [0-9]*"
+
+stmt "drop table test_delta;"
+stmt "drop table test_delta_dim;"
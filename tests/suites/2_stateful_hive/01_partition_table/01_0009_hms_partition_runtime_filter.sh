@@ -0,0 +1,17 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+stmt "drop table if exists default.hive_nations;"
+stmt "create table default.hive_nations(name string);"
+stmt "insert into default.hive_nations values ('CHINA');"
+
+# keep the hive table on the probe side of the join
+query "settings (disable_join_reorder = 1) select c.foo, c.c_nation2 from hive.default.customer_p2 c join default.hive_nations n on c.c_nation = n.name order by c.foo;"
+
+# 4 of the 5 partitions are eliminated by the runtime filters before their files are listed
+echo "set disable_join_reorder = 1; explain analyze select c.foo from hive.default.customer_p2 c join default.hive_nations n on c.c_nation = n.name;" \
+  | $BENDSQL_CLIENT_CONNECT | grep -o "parts pruned by runtime filter: [0-9]*"
+
+stmt "drop table default.hive_nations;"